@@ -0,0 +1,126 @@
+//! Generates the configuration types of the `dart-wit-generator` world in
+//! `wit/dart-wit-generator.wit` for the builds without the `component`
+//! feature, where the bindings of `wit-bindgen` are not available.
+//! The types match the ones generated by `wit-bindgen`, see `src/config.rs`.
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use std::path::Path;
+use wit_parser::*;
+
+const WIT_PATH: &str = "wit/dart-wit-generator.wit";
+
+fn main() {
+    println!("cargo:rerun-if-changed={WIT_PATH}");
+    if std::env::var_os("CARGO_FEATURE_COMPONENT").is_some() {
+        return;
+    }
+    let mut resolve = Resolve::new();
+    UnresolvedPackage::parse_file(Path::new(WIT_PATH))
+        .and_then(|pkg| resolve.push(pkg))
+        .unwrap_or_else(|e| panic!("failed to parse `{WIT_PATH}`: {e:?}"));
+
+    let mut s = String::new();
+    for (_id, ty) in resolve.types.iter() {
+        if let TypeOwner::World(_) = ty.owner {
+            type_definition(&resolve, ty, &mut s);
+        }
+    }
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("config.rs"), s).unwrap();
+}
+
+fn type_definition(resolve: &Resolve, ty: &TypeDef, s: &mut String) {
+    let name = ty.name.as_ref().unwrap().to_upper_camel_case();
+    add_docs(s, &ty.docs, "");
+    match &ty.kind {
+        TypeDefKind::Record(record) => {
+            s.push_str(&format!("#[derive(Debug, Clone)]\npub struct {name} {{\n"));
+            for field in &record.fields {
+                add_docs(s, &field.docs, "    ");
+                s.push_str(&format!(
+                    "    pub {}: {},\n",
+                    field.name.to_snake_case(),
+                    type_name(resolve, &field.ty)
+                ));
+            }
+            s.push_str("}\n\n");
+        }
+        TypeDefKind::Union(union) => {
+            s.push_str(&format!("#[derive(Debug, Clone)]\npub enum {name} {{\n"));
+            for (i, case) in union.cases.iter().enumerate() {
+                add_docs(s, &case.docs, "    ");
+                let ty = type_name(resolve, &case.ty);
+                // Named cases use the name of their type, as in `wit-bindgen`
+                let case_name = match case.ty {
+                    Type::Id(id) if resolve.types[id].name.is_some() => ty.clone(),
+                    _ => format!("V{i}"),
+                };
+                s.push_str(&format!("    {case_name}({ty}),\n"));
+            }
+            s.push_str("}\n\n");
+        }
+        TypeDefKind::Enum(enum_) => {
+            s.push_str(&format!(
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub enum {name} {{\n"
+            ));
+            for case in &enum_.cases {
+                add_docs(s, &case.docs, "    ");
+                s.push_str(&format!("    {},\n", case.name.to_upper_camel_case()));
+            }
+            s.push_str("}\n\n");
+        }
+        TypeDefKind::Type(inner) => {
+            s.push_str(&format!(
+                "pub type {name} = {};\n\n",
+                type_name(resolve, inner)
+            ));
+        }
+        kind => panic!("the kind of the type `{name}` in `{WIT_PATH}` is not supported: {kind:?}"),
+    }
+}
+
+fn type_name(resolve: &Resolve, ty: &Type) -> String {
+    match ty {
+        Type::Bool => "bool".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U16 => "u16".to_string(),
+        Type::U32 => "u32".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::S8 => "i8".to_string(),
+        Type::S16 => "i16".to_string(),
+        Type::S32 => "i32".to_string(),
+        Type::S64 => "i64".to_string(),
+        Type::Float32 => "f32".to_string(),
+        Type::Float64 => "f64".to_string(),
+        Type::Char => "char".to_string(),
+        Type::String => "String".to_string(),
+        Type::Id(id) => {
+            let ty = &resolve.types[*id];
+            if let Some(name) = &ty.name {
+                return name.to_upper_camel_case();
+            }
+            match &ty.kind {
+                TypeDefKind::Option(inner) => format!("Option<{}>", type_name(resolve, inner)),
+                TypeDefKind::List(inner) => format!("Vec<{}>", type_name(resolve, inner)),
+                TypeDefKind::Tuple(tuple) => format!(
+                    "({})",
+                    tuple
+                        .types
+                        .iter()
+                        .map(|t| format!("{}, ", type_name(resolve, t)))
+                        .collect::<String>()
+                ),
+                TypeDefKind::Type(inner) => type_name(resolve, inner),
+                kind => panic!("the anonymous type in `{WIT_PATH}` is not supported: {kind:?}"),
+            }
+        }
+    }
+}
+
+fn add_docs(s: &mut String, docs: &Docs, indent: &str) {
+    if let Some(contents) = &docs.contents {
+        for line in contents.trim_end().lines() {
+            s.push_str(&format!("{indent}/// {line}\n").replace("/// \n", "///\n"));
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! The configuration types of the `dart-wit-generator` world in
+//! `wit/dart-wit-generator.wit`, used when the bindings generated
+//! by `wit-bindgen` are not available, without the `component` feature.
+//! They are generated from the WIT definitions by `build.rs`.
+
+include!(concat!(env!("OUT_DIR"), "/config.rs"));
@@ -1,9 +1,9 @@
 use wit_parser::*;
 
 use crate::{
-    generate::add_docs,
+    generate::{add_docs, Output},
     strings::Normalize,
-    types::{function_resource, Parsed},
+    types::{function_resource, is_bare_result, Parsed},
 };
 
 pub enum FuncKind {
@@ -14,6 +14,69 @@ pub enum FuncKind {
 }
 
 impl Parsed<'_> {
+    /// Whether the exported functions return a `Future`, when using
+    /// [WitGeneratorConfig::async_worker] or [WitGeneratorConfig::async_calls].
+    pub fn is_async(&self) -> bool {
+        self.2.async_worker || self.2.async_calls
+    }
+
+    /// The `WasmLibrary` method used to retrieve the exported functions.
+    pub fn component_function_getter(&self) -> &'static str {
+        if self.2.async_worker {
+            "getComponentFunctionWorker"
+        } else if self.2.async_calls {
+            "getComponentFunctionAsync"
+        } else {
+            "getComponentFunction"
+        }
+    }
+
+    /// The `result` returned by the exported function [f] when the error
+    /// is thrown instead of returned, see [WitGeneratorConfig::raw_results].
+    /// Bare results are generated as a `bool` and are not thrown.
+    pub fn thrown_result(&self, f: &Function) -> Option<&Result_> {
+        if self.2.raw_results {
+            return None;
+        }
+        match &f.results {
+            Results::Anon(Type::Id(id)) => match &self.0.types[*id].kind {
+                TypeDefKind::Result(r) if !is_bare_result(r) => Some(r),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The name of the exception class thrown with an [error] of a named type.
+    /// Other errors are thrown with the `ResultException` of the `Result`.
+    pub fn error_exception(&self, error: &Type) -> Option<String> {
+        match error {
+            Type::Id(id) if self.0.types[*id].name.is_some() => {
+                Some(format!("{}Exception", self.type_to_str(error)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The exception class thrown with the [error] returned by exported functions.
+    pub fn exception_class(&self, exception: &str, error: &Type) -> String {
+        let error = self.type_to_str(error);
+        format!(
+            "/// Thrown by the exported functions returning a [{error}] error.
+            class {exception} implements Exception {{
+                /// The error returned by the function.
+                final {error} error;
+
+                /// An exception with the [error] returned by a function.
+                const {exception}(this.error);
+
+                @override
+                String toString() => '{exception}($error)';
+            }}
+            "
+        )
+    }
+
     pub fn function_import(&self, key: Option<&WorldKey>, id: &str, f: &Function) -> String {
         let interface_name_m = match key {
             Some(k) => self.0.name_world_key(k),
@@ -78,7 +141,7 @@ impl Parsed<'_> {
             .iter()
             .enumerate()
             .map(|(i, (name, ty))| {
-                let value = self.type_from_json(&format!("args{i}"), ty);
+                let value = self.param_from_json(&format!("args{i}"), ty);
                 format!("{}: {value}", name.as_var())
             })
             .collect::<Vec<_>>()
@@ -123,7 +186,7 @@ impl Parsed<'_> {
 
     pub fn add_interfaces(
         &self,
-        mut s: &mut String,
+        out: &mut Output,
         map: &mut dyn Iterator<Item = (&WorldKey, &WorldItem)>,
         is_export: bool,
         mut func_imports: Option<&mut String>,
@@ -131,7 +194,8 @@ impl Parsed<'_> {
         map.for_each(|(key, item)| match item {
             WorldItem::Interface(interface_id) => {
                 let interface = self.0.interfaces.get(*interface_id).unwrap();
-                self.add_interface(&mut s, key, interface, is_export, &mut func_imports)
+                let s = out.interface(*interface_id);
+                self.add_interface(s, key, interface, is_export, &mut func_imports)
             }
             _ => {}
         });
@@ -147,10 +211,10 @@ impl Parsed<'_> {
     ) {
         let world_prefix = self.0.name_world_key(key);
         let interface_id = self.world_key_type_name(key);
-        let world_name = heck::AsPascalCase(&self.0.worlds.iter().next().unwrap().1.name);
+        let world_name = heck::AsPascalCase(self.world_name());
         let name = heck::AsPascalCase(interface_id);
-        add_docs(&mut s, &interface.docs);
         if is_export {
+            add_docs(&mut s, &interface.docs);
             s.push_str(&format!(
                 "class {name} {{ final {world_name}World _world; {name}(this._world)"
             ));
@@ -163,11 +227,7 @@ impl Parsed<'_> {
                     .iter()
                     .enumerate()
                     .for_each(|(index, (id, f))| {
-                        let fn_name = if self.2.async_worker {
-                            "getComponentFunctionWorker"
-                        } else {
-                            "getComponentFunction"
-                        };
+                        let fn_name = self.component_function_getter();
                         s.push_str(&format!(
                             "_{} = _world.library.{fn_name}('{world_prefix}#{id}', const {},)!",
                             id.as_var(),
@@ -188,6 +248,7 @@ impl Parsed<'_> {
             if interface.functions.is_empty() {
                 return;
             }
+            add_docs(&mut s, &interface.docs);
             s.push_str(&format!("abstract class {name}Import {{",));
             interface.functions.iter().for_each(|(id, f)| {
                 self.add_function(&mut s, f, FuncKind::Method, false);
@@ -226,6 +287,17 @@ impl Parsed<'_> {
         }
     }
 
+    /// A named function parameter. `option` parameters are nullable
+    /// and default to `null`, unless [WitGeneratorConfig::required_option].
+    pub fn function_param(&self, name: &str, ty: &Type) -> String {
+        let ty_str = self.param_to_str(ty);
+        if !self.2.required_option && self.is_option(ty) {
+            format!("{ty_str} {},", name.as_var())
+        } else {
+            format!("required {ty_str} {},", name.as_var())
+        }
+    }
+
     pub fn is_unit(&self, ty: &Type) -> bool {
         match ty {
             Type::Id(ty_id) => {
@@ -253,7 +325,7 @@ impl Parsed<'_> {
             .params
             .iter()
             .filter(|(name, _)| !is_resource || name != "self")
-            .map(|(name, ty)| self.type_param(name, ty, false))
+            .map(|(name, ty)| self.function_param(name, ty))
             .collect::<String>();
         if params.len() > 0 {
             params = format!("{{{params}}}");
@@ -275,6 +347,17 @@ impl Parsed<'_> {
             }
         };
 
+        // The success value is returned, the error is thrown
+        let thrown_result = match kind {
+            FuncKind::MethodCall | FuncKind::Resource(_) => self.thrown_result(f),
+            FuncKind::Method | FuncKind::Field => None,
+        };
+        if let Some(r) = thrown_result {
+            results =
+                r.ok.map(|ty| self.type_to_str(&ty))
+                    .unwrap_or("void".to_string());
+        }
+
         let name = &f.name.as_fn();
         match kind {
             FuncKind::Field => {
@@ -287,10 +370,9 @@ impl Parsed<'_> {
             }
             FuncKind::Resource(owner) => {
                 add_docs(&mut s, &f.docs);
-                let async_ = if self.2.async_worker { "async " } else { "" };
+                let async_ = if self.is_async() { "async " } else { "" };
                 let static_ = if let (FunctionKind::Static(_), _)
-                | (FunctionKind::Constructor(_), true) =
-                    (&f.kind, self.2.async_worker)
+                | (FunctionKind::Constructor(_), true) = (&f.kind, self.is_async())
                 {
                     "static "
                 } else {
@@ -318,7 +400,7 @@ impl Parsed<'_> {
                     TypeOwner::None => "".to_string(),
                 };
                 if is_static {
-                    let w_name = heck::AsPascalCase(&self.0.worlds.iter().next().unwrap().1.name);
+                    let w_name = heck::AsPascalCase(self.world_name());
                     match owner {
                         TypeOwner::Interface(i) => {
                             let n = self.0.interfaces[i].name.as_ref().unwrap();
@@ -329,7 +411,7 @@ impl Parsed<'_> {
                         }
                     }
                 }
-                if let (FunctionKind::Constructor(_), false) = (&f.kind, self.2.async_worker) {
+                if let (FunctionKind::Constructor(_), false) = (&f.kind, self.is_async()) {
                     s.push_str(&format!("factory {results}.{name}({params}) {{"));
                 } else {
                     let n = if let Some(r) = function_resource(&f.kind) {
@@ -356,13 +438,20 @@ impl Parsed<'_> {
                         })
                         .collect::<Vec<_>>()
                         .join(", ");
-                    s.push_str(&format!("return {owner_getter}{name}({args});"));
+                    if f.results.len() == 0 {
+                        // Fire-and-forget functions do not return a value
+                        let await_ = if self.is_async() { "await " } else { "" };
+                        s.push_str(&format!("{await_}{owner_getter}{name}({args});"));
+                    } else {
+                        s.push_str(&format!("return {owner_getter}{name}({args});"));
+                    }
                 }
                 s.push_str("}");
             }
             FuncKind::MethodCall => {
-                // s.push_str(&format!("late final _{} = lookup('{}');", f.name, f.name));
-                if self.2.async_worker {
+                // The field is assigned in the constructor with the typed
+                // function returned by `WasmLibrary.getComponentFunction`.
+                if self.is_async() {
                     results = format!("Future<{results}>");
                     s.push_str(&format!(
                         "final Future<ListValue> Function(ListValue) _{name};"
@@ -372,10 +461,10 @@ impl Parsed<'_> {
                 }
 
                 add_docs(&mut s, &f.docs);
-                let async_ = if self.2.async_worker { "async " } else { "" };
+                let async_ = if self.is_async() { "async " } else { "" };
                 s.push_str(&format!("{results} {name}({params}) {async_}{{"));
                 {
-                    let await_ = if self.2.async_worker { "await " } else { "" };
+                    let await_ = if self.is_async() { "await " } else { "" };
                     let results_assignments = if f.results.len() == 0
                         || (f.results.len() == 1
                             && self.is_unit(f.results.iter_types().next().unwrap()))
@@ -387,7 +476,7 @@ impl Parsed<'_> {
                     let params = f
                         .params
                         .iter()
-                        .map(|(name, ty)| self.type_to_wasm(&name.as_var(), ty))
+                        .map(|(name, ty)| self.param_to_wasm(&name.as_var(), ty))
                         .collect::<Vec<_>>()
                         .join(", ");
                     let results_with_ctx = match &f.results {
@@ -400,7 +489,19 @@ impl Parsed<'_> {
                             if self.is_unit(&a) {
                                 "return ();".to_string()
                             } else {
-                                if results_with_ctx {
+                                if let Some(r) = thrown_result {
+                                    let parse_prefix = if from_world { "" } else { "_world." };
+                                    let unwrap = match r.err.and_then(|e| self.error_exception(&e)) {
+                                        Some(exception) => format!(
+                                            "switch (value) {{ Ok(:final ok) => ok, Err(:final error) => throw {exception}(error), }}"
+                                        ),
+                                        None => "value.unwrap()".to_string(),
+                                    };
+                                    format!(
+                                        "final result = results[0];final value = {parse_prefix}withContext(() => {});return {unwrap};",
+                                        self.type_from_json("result", a)
+                                    )
+                                } else if results_with_ctx {
                                     let parse_prefix = if from_world { "" } else { "_world." };
                                     format!(
                                         "final result = results[0];return {parse_prefix}withContext(() => {});",
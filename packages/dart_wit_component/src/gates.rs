@@ -0,0 +1,245 @@
+//! WIT feature gates: `@since`, `@unstable` and `@deprecated`.
+//!
+//! The pinned `wit-parser` can not parse the gates, so they are applied to the
+//! WIT sources before parsing them with [apply_feature_gates]. The gates of the
+//! generated items are kept as doc comments and [gate_annotation] returns
+//! the Dart annotation for them.
+
+/// Returns [source] without the items gated by an `@unstable` feature that
+/// is not in [enabled_features], the doc comments of the removed items are
+/// also removed. The gates of the other items are converted into doc
+/// comments, so that they are part of the docs of the parsed items.
+/// Removed items are replaced with whitespace to preserve the line numbers
+/// of the parsing errors.
+#[cfg(any(feature = "component", test))]
+pub(crate) fn apply_feature_gates(
+    path: &str,
+    source: &str,
+    enabled_features: &[String],
+) -> Result<String, String> {
+    let bytes = source.as_bytes();
+    let mut out = String::with_capacity(source.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if matches!(bytes.get(i + 1), Some(b'/' | b'*')) => {
+                i = comment_end(source, i);
+            }
+            // Not the `@` of a package version
+            b'@' if i == 0
+                || bytes[i - 1].is_ascii_whitespace()
+                || matches!(bytes[i - 1], b'{' | b',' | b';') =>
+            {
+                i = apply_gate(path, source, i, enabled_features, &mut out, &mut copied)?;
+            }
+            _ => i += 1,
+        }
+    }
+    out.push_str(&source[copied..]);
+    Ok(out)
+}
+
+/// Applies the gate at [start] and returns the index after it.
+#[cfg(any(feature = "component", test))]
+fn apply_gate(
+    path: &str,
+    source: &str,
+    start: usize,
+    enabled_features: &[String],
+    out: &mut String,
+    copied: &mut usize,
+) -> Result<usize, String> {
+    let (name, args, gate_end) = parse_gate(source, start).map_err(|e| {
+        let line = source[..start].matches('\n').count() + 1;
+        format!("{path}:{line}: {e}")
+    })?;
+    let feature = gate_arg(args, "feature");
+    if name == "unstable" && feature.is_none() {
+        let line = source[..start].matches('\n').count() + 1;
+        return Err(format!(
+            "{path}:{line}: the `@unstable` feature gate requires a `feature` argument."
+        ));
+    }
+
+    if name == "unstable" && !enabled_features.iter().any(|f| Some(f.as_str()) == feature) {
+        let item_start = docs_start(source, start).max(*copied);
+        let end = item_end(source, gate_end);
+        out.push_str(&source[*copied..item_start]);
+        out.extend(
+            source[item_start..end]
+                .chars()
+                .map(|c| if c == '\n' { '\n' } else { ' ' }),
+        );
+        *copied = end;
+        Ok(end)
+    } else {
+        out.push_str(&source[*copied..start]);
+        out.push_str("/**");
+        out.push_str(&source[start..gate_end]);
+        out.push_str("*/");
+        *copied = gate_end;
+        Ok(gate_end)
+    }
+}
+
+/// Returns the name and the arguments of the gate at [start],
+/// and the index after it.
+#[cfg(any(feature = "component", test))]
+fn parse_gate(source: &str, start: usize) -> Result<(&str, &str, usize), String> {
+    let rest = &source[start + 1..];
+    let name_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        .unwrap_or(rest.len());
+    let name = &rest[..name_len];
+    if !matches!(name, "since" | "unstable" | "deprecated") {
+        return Err(format!("unknown feature gate `@{name}`."));
+    }
+    let after_name = rest[name_len..].trim_start();
+    let close = after_name
+        .strip_prefix('(')
+        .and_then(|args| args.find(')'))
+        .ok_or_else(|| format!("expected the arguments of the `@{name}` feature gate."))?;
+    let args_start = source.len() - after_name.len() + 1;
+    Ok((
+        name,
+        &source[args_start..args_start + close],
+        args_start + close + 1,
+    ))
+}
+
+/// Returns the value of the [key] argument in the [args] of a gate,
+/// for example, `fancy` for `feature` in `feature = fancy`.
+fn gate_arg<'a>(args: &'a str, key: &str) -> Option<&'a str> {
+    args.split(',').find_map(|arg| {
+        let (k, v) = arg.split_once('=')?;
+        (k.trim() == key).then(|| v.trim())
+    })
+}
+
+/// Returns the start of the `///` doc comments before the gate at [start].
+/// The gate is returned if it is not at the start of a line.
+#[cfg(any(feature = "component", test))]
+fn docs_start(source: &str, start: usize) -> usize {
+    let line_start = source[..start].rfind('\n').map_or(0, |n| n + 1);
+    if !source[line_start..start].trim().is_empty() {
+        return start;
+    }
+    let mut docs_start = line_start;
+    while docs_start > 0 {
+        let prev_start = source[..docs_start - 1].rfind('\n').map_or(0, |n| n + 1);
+        if !source[prev_start..docs_start]
+            .trim_start()
+            .starts_with("///")
+        {
+            break;
+        }
+        docs_start = prev_start;
+    }
+    docs_start
+}
+
+/// Returns the end of the item that starts at [start]. Items end with
+/// a separator, `,` or `;`, a block, a new line or the end of their parent.
+#[cfg(any(feature = "component", test))]
+fn item_end(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut depth = 0usize;
+    let mut has_tokens = false;
+    let mut j = start;
+    while j < bytes.len() {
+        match bytes[j] {
+            b'/' if matches!(bytes.get(j + 1), Some(b'/' | b'*')) => {
+                j = comment_end(source, j);
+                continue;
+            }
+            b'(' | b'<' => depth += 1,
+            // The `->` of the function results
+            b'>' if bytes[j - 1] == b'-' => {}
+            b')' | b'>' => depth = depth.saturating_sub(1),
+            b'{' if depth == 0 => return separator_end(source, block_end(source, j)),
+            b'}' if depth == 0 => return j,
+            b',' | b';' if depth == 0 => return j + 1,
+            b'\n' if depth == 0 && has_tokens => return j,
+            _ => {}
+        }
+        has_tokens |= !bytes[j].is_ascii_whitespace();
+        j += 1;
+    }
+    bytes.len()
+}
+
+/// Returns the index after the `}` closing the block at [start].
+#[cfg(any(feature = "component", test))]
+fn block_end(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut depth = 0usize;
+    let mut j = start;
+    while j < bytes.len() {
+        match bytes[j] {
+            b'/' if matches!(bytes.get(j + 1), Some(b'/' | b'*')) => {
+                j = comment_end(source, j);
+                continue;
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return j + 1;
+                }
+            }
+            _ => {}
+        }
+        j += 1;
+    }
+    bytes.len()
+}
+
+/// Returns the index after the `,` or `;` separator at [start], if any.
+#[cfg(any(feature = "component", test))]
+fn separator_end(source: &str, start: usize) -> usize {
+    let rest = &source[start..];
+    let trimmed = rest.trim_start_matches([' ', '\t']);
+    if trimmed.starts_with([',', ';']) {
+        start + rest.len() - trimmed.len() + 1
+    } else {
+        start
+    }
+}
+
+/// Returns the index after the comment at [start],
+/// a line comment ends before the new line.
+#[cfg(any(feature = "component", test))]
+fn comment_end(source: &str, start: usize) -> usize {
+    if source[start..].starts_with("//") {
+        source[start..]
+            .find('\n')
+            .map_or(source.len(), |n| start + n)
+    } else {
+        source[start + 2..]
+            .find("*/")
+            .map_or(source.len(), |n| start + 2 + n + 2)
+    }
+}
+
+/// Returns the Dart annotation for a doc [line] with an `@unstable` or
+/// `@deprecated` feature gate, see [apply_feature_gates].
+pub(crate) fn gate_annotation(line: &str) -> Option<String> {
+    let line = line.trim();
+    let (name, args) = line.strip_prefix('@')?.strip_suffix(')')?.split_once('(')?;
+    match name.trim() {
+        "unstable" => {
+            let feature = gate_arg(args, "feature")?;
+            Some(format!(
+                "@Deprecated('Unstable WIT feature `{feature}`.')\n"
+            ))
+        }
+        "deprecated" => {
+            let version = gate_arg(args, "version")?;
+            Some(format!(
+                "@Deprecated('Deprecated since version {version}.')\n"
+            ))
+        }
+        _ => None,
+    }
+}
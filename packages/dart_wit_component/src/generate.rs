@@ -1,5 +1,6 @@
 use crate::{
-    function::FuncKind, strings::Normalize, types::*, Int64TypeConfig, WitGeneratorConfig,
+    function::FuncKind, gates::gate_annotation, strings::Normalize, types::*, Int64TypeConfig,
+    OutputMode, StringEncodingConfig, WitGeneratorConfig,
 };
 use std::collections::{HashMap, HashSet};
 use wit_parser::*;
@@ -8,37 +9,195 @@ pub fn document_to_dart(
     parsed: &UnresolvedPackage,
     config: WitGeneratorConfig,
 ) -> Result<String, String> {
-    let mut s = String::new();
+    document_to_dart_with_diagnostics(parsed, config).map(|(s, _)| s)
+}
+
+/// Same as [document_to_dart], but also returns the [Diagnostic]s
+/// for the WIT constructs that could not be generated.
+pub fn document_to_dart_with_diagnostics(
+    parsed: &UnresolvedPackage,
+    config: WitGeneratorConfig,
+) -> Result<(String, Vec<Diagnostic>), String> {
+    let (mut files, diagnostics) = generate_dart(parsed, config, false)?;
+    Ok((files.remove(0).1, diagnostics))
+}
+
+/// Generates one Dart file per named WIT interface, returned as a map
+/// from file name to file contents.
+///
+/// The main file, named after the world, contains the imports, the world classes
+/// and a `part` directive for each interface file. The interface files are
+/// `part of` the main file so that they can share the library-private type specs.
+pub fn document_to_dart_files(
+    parsed: &UnresolvedPackage,
+    config: WitGeneratorConfig,
+) -> Result<HashMap<String, String>, String> {
+    generate_dart(parsed, config, true).map(|(files, _)| files.into_iter().collect())
+}
+
+/// The generated Dart code, optionally split into a `part` file per interface.
+pub(crate) struct Output {
+    main: String,
+    parts: Option<HashMap<InterfaceId, String>>,
+}
 
-    s.push_str(&format!(
-        "{HEADER}{}",
-        config.file_header.as_deref().unwrap_or("")
-    ));
+impl Output {
+    /// The code buffer for the types and classes of the interface [id].
+    pub(crate) fn interface(&mut self, id: InterfaceId) -> &mut String {
+        match &mut self.parts {
+            Some(parts) if parts.contains_key(&id) => parts.get_mut(&id).unwrap(),
+            _ => &mut self.main,
+        }
+    }
 
+    fn owner(&mut self, owner: TypeOwner) -> &mut String {
+        match owner {
+            TypeOwner::Interface(id) => self.interface(id),
+            TypeOwner::World(_) | TypeOwner::None => &mut self.main,
+        }
+    }
+}
+
+/// Returns the name of the Dart file for each named interface.
+/// The package name is used as prefix when the interface name is not unique.
+fn interface_file_names(resolve: &Resolve) -> HashMap<InterfaceId, String> {
+    let mut counts = HashMap::<&str, usize>::new();
+    resolve.interfaces.iter().for_each(|(_id, i)| {
+        if let Some(name) = &i.name {
+            *counts.entry(name).or_default() += 1;
+        }
+    });
+    resolve
+        .interfaces
+        .iter()
+        .filter_map(|(id, i)| {
+            let name = i.name.as_ref()?;
+            let file_name = match i.package {
+                Some(package) if counts[name.as_str()] > 1 => {
+                    let package = &resolve.packages.get(package).unwrap().name;
+                    format!(
+                        "{}_{}_{}.dart",
+                        heck::AsSnakeCase(&package.namespace),
+                        heck::AsSnakeCase(&package.name),
+                        heck::AsSnakeCase(name)
+                    )
+                }
+                _ => format!("{}.dart", heck::AsSnakeCase(name)),
+            };
+            Some((id, file_name))
+        })
+        .collect()
+}
+
+/// Returns a JSON description of the worlds in [parsed], with their
+/// interfaces, functions and types, for documentation generators and
+/// binding generators of other languages. See [Parsed::to_json_schema].
+///
+/// The `dart` names are the ones of the classes generated by
+/// [document_to_dart] with the same [config].
+pub fn document_to_json_schema(
+    parsed: &UnresolvedPackage,
+    config: WitGeneratorConfig,
+) -> Result<String, String> {
+    let (resolve, worlds) = resolve_worlds(parsed)?;
+    let names = duplicated_type_names(&resolve);
+    let p = Parsed(
+        &resolve,
+        names,
+        config,
+        HashMap::new(),
+        Default::default(),
+        worlds,
+    );
+    Ok(p.to_json_schema())
+}
+
+/// Resolves [parsed] and returns the worlds to generate.
+///
+/// The imports and exports of included worlds are already merged
+/// into the including world by [Resolve], only the latter are returned.
+fn resolve_worlds(parsed: &UnresolvedPackage) -> Result<(Resolve, Vec<WorldId>), String> {
     let mut resolve = Resolve::new();
     resolve
         .push(parsed.clone())
         .map_err(|err| err.to_string())?;
 
-    let names = HashMap::<&str, Vec<&TypeDef>>::new();
-    let unions = HashMap::<String, Vec<String>>::new();
-    let mut p = Parsed(&resolve, names, config, unions);
-
-    // parsed.documents
-    // parsed.foreign_deps
-    // parsed.interfaces
-    // parsed.types
+    let included = parsed
+        .worlds
+        .iter()
+        .flat_map(|(_id, w)| w.includes.iter())
+        .filter_map(|id| parsed.worlds.get(*id))
+        .map(|w| w.name.as_str())
+        .collect::<HashSet<_>>();
+    let worlds = resolve
+        .worlds
+        .iter()
+        .filter(|(_id, w)| !included.contains(w.name.as_str()))
+        .map(|(id, _w)| id)
+        .collect::<Vec<_>>();
+    Ok((resolve, worlds))
+}
 
+/// The named types defined more than once, by name.
+/// Their Dart names are suffixed with their owner.
+fn duplicated_type_names(resolve: &Resolve) -> HashMap<&str, Vec<&TypeDef>> {
+    let mut names = HashMap::<&str, Vec<&TypeDef>>::new();
     resolve.types.iter().for_each(|(_id, ty)| {
         if matches!(ty.kind, TypeDefKind::Type(_)) {
             return;
         }
         if let Some(name) = &ty.name {
-            let entry = p.1.entry(name);
+            let entry = names.entry(name);
             entry.or_insert(vec![]).push(ty);
         }
     });
-    p.1.retain(|_k, v| v.len() > 1);
+    names.retain(|_k, v| v.len() > 1);
+    names
+}
+
+/// Returns the generated files, the main file first,
+/// and the [Diagnostic]s for the WIT constructs that could not be generated.
+fn generate_dart(
+    parsed: &UnresolvedPackage,
+    config: WitGeneratorConfig,
+    split_interfaces: bool,
+) -> Result<(Vec<(String, String)>, Vec<Diagnostic>), String> {
+    let file_header = config.file_header.clone().unwrap_or_default();
+    let output_mode = config.output_mode.unwrap_or(OutputMode::Library);
+    let library_name = config.library_name.clone();
+    if split_interfaces && output_mode != OutputMode::Library {
+        return Err(
+            "Only the `library` output mode is supported when generating a file per interface."
+                .to_string(),
+        );
+    }
+
+    let (resolve, worlds) = resolve_worlds(parsed)?;
+    if worlds.is_empty() {
+        return Err(format!(
+            "The package `{}` does not define a world to generate.",
+            parsed.name
+        ));
+    }
+
+    let file_names = if split_interfaces {
+        interface_file_names(&resolve)
+    } else {
+        HashMap::new()
+    };
+    let mut out = Output {
+        main: String::new(),
+        parts: split_interfaces.then(|| file_names.keys().map(|id| (*id, String::new())).collect()),
+    };
+
+    let names = duplicated_type_names(&resolve);
+    let unions = HashMap::<String, Vec<String>>::new();
+    let mut p = Parsed(&resolve, names, config, unions, Default::default(), worlds);
+
+    // parsed.documents
+    // parsed.foreign_deps
+    // parsed.interfaces
+    // parsed.types
 
     if p.2.same_class_union {
         // Find all unions
@@ -59,6 +218,7 @@ pub fn document_to_dart(
     }
     resolve.types.iter().for_each(|(id, ty)| {
         let docs = &ty.docs;
+        let s = out.owner(ty.owner);
         if let (TypeDefKind::Type(ty), Some(name)) =
             (&ty.kind, p.type_def_to_name_definition(ty).as_ref())
         {
@@ -67,13 +227,13 @@ pub fn document_to_dart(
                 let ty = resolve.types.get(*ref_id).unwrap();
                 if let Some(ref_name) = p.type_def_to_name_definition(ty).as_ref() {
                     if ref_name != name {
-                        add_docs(&mut s, docs);
+                        add_docs(s, docs);
                         s.push_str(&format!("typedef {name} = {ref_name};"));
                     }
                 }
             } else {
                 let ref_name = p.type_to_str(ty);
-                add_docs(&mut s, docs);
+                add_docs(s, docs);
                 s.push_str(&format!("typedef {name} = {ref_name};"));
             }
             return;
@@ -86,17 +246,40 @@ pub fn document_to_dart(
         }
     });
 
-    resolve.worlds.iter().for_each(|(_id, w)| {
+    // The exceptions thrown by the exported functions, next to their error types
+    let mut exceptions = HashSet::<String>::new();
+    p.5.iter()
+        .flat_map(|id| resolve.worlds[*id].exports.values())
+        .flat_map(|item| match item {
+            WorldItem::Interface(id) => resolve.interfaces[*id].functions.values().collect(),
+            WorldItem::Function(f) => vec![f],
+            WorldItem::Type(_) => vec![],
+        })
+        .for_each(|f| {
+            let Some(error) = p.thrown_result(f).and_then(|r| r.err) else {
+                return;
+            };
+            if let (Some(exception), Type::Id(id)) = (p.error_exception(&error), error) {
+                if exceptions.insert(exception.clone()) {
+                    let s = out.owner(resolve.types[id].owner);
+                    s.push_str(&p.exception_class(&exception, &error));
+                }
+            }
+        });
+
+    p.5.iter().for_each(|&_id| {
+        let w = &resolve.worlds[_id];
         let w_name = heck::AsPascalCase(&w.name);
         let mut func_imports = String::new();
         let mut world_resource_finalizer = String::new();
         // Imports Interfaces as Dart classes
         p.add_interfaces(
-            &mut s,
+            &mut out,
             &mut w.imports.iter(),
             false,
             Some(&mut func_imports),
         );
+        let s = &mut out.main;
         let mut interfaces = HashSet::<InterfaceId>::new();
         w.exports.iter().for_each(|(k, _v)| {
             if let WorldKey::Interface(i) = k {
@@ -159,7 +342,7 @@ pub fn document_to_dart(
                     WorldItem::Type(_type_id) => {}
                     WorldItem::Function(f) => {
                         constructor.push_str(&format!("required this.{id_name},"));
-                        p.add_function(&mut s, f, FuncKind::Field, false);
+                        p.add_function(s, f, FuncKind::Field, false);
 
                         func_imports.push_str(&p.function_import(None, id, f));
                     }
@@ -178,15 +361,19 @@ pub fn document_to_dart(
         // World Exports
         //TODO: separate per document?
 
-        p.add_interfaces(&mut s, &mut w.exports.iter(), true, None);
+        p.add_interfaces(&mut out, &mut w.exports.iter(), true, None);
 
-        add_docs(&mut s, &w.docs);
+        let s = &mut out.main;
+        add_docs(s, &w.docs);
         s.push_str(&format!(
             "class {w_name}World {{
             final {w_name}WorldImports imports;
             final WasmLibrary library;
             {world_resource_finalizer}",
         ));
+        if !world_resource_finalizer.is_empty() {
+            s.push_str(RESOURCE_SCOPE_METHODS);
+        }
         let mut constructor: Vec<String> = vec![];
         let mut constructor_body: Vec<String> = vec![];
         let mut methods = String::new();
@@ -200,11 +387,7 @@ pub fn document_to_dart(
                 }
                 WorldItem::Type(_type_id) => {}
                 WorldItem::Function(f) => {
-                    let fn_name = if p.2.async_worker {
-                        "getComponentFunctionWorker"
-                    } else {
-                        "getComponentFunction"
-                    };
+                    let fn_name = p.component_function_getter();
                     constructor.push(format!(
                         "_{id_name} = library.{fn_name}('{id}', const {},)!",
                         p.function_spec(f)
@@ -241,15 +424,21 @@ pub fn document_to_dart(
             Int64TypeConfig::CoreInt => "Int64TypeConfig.coreInt",
             Int64TypeConfig::NativeObject => "Int64TypeConfig.nativeObject",
         };
+        // UTF-8 is the default of `WasmLibrary`
+        let string_encoding = match p.2.string_encoding {
+            StringEncodingConfig::Utf8 => "",
+            StringEncodingConfig::Utf16 => ", stringEncoding: StringEncoding.utf16",
+            StringEncodingConfig::Latin1Utf16 => ", stringEncoding: StringEncoding.latin1utf16",
+        };
         let instantiate = if p.2.async_worker {
-            worker_instantiation(int64_type)
+            worker_instantiation(int64_type, string_encoding)
         } else {
             let package = resolve.packages.get(w.package.unwrap()).unwrap();
             let component_id = format!("{}/{}", package.name, w.name);
             format!(
                 "final instance = await builder.build();
 
-library = WasmLibrary(instance, componentId: '{component_id}', int64Type: {int64_type});"
+library = WasmLibrary(instance, componentId: '{component_id}', int64Type: {int64_type}{string_encoding});"
             )
         };
 
@@ -277,10 +466,150 @@ library = WasmLibrary(instance, componentId: '{component_id}', int64Type: {int64
         ));
         s.push_str("}");
     });
-    Ok(s)
+
+    let main_name =
+        p.5.first()
+            .map(|id| format!("{}.dart", heck::AsSnakeCase(&resolve.worlds[*id].name)))
+            .unwrap_or_else(|| "world.dart".to_string());
+    let imports =
+        dart_imports(std::iter::once(&out.main).chain(out.parts.iter().flat_map(|p| p.values())));
+    let mut part_files = vec![];
+    let mut main = match (output_mode, library_name) {
+        (OutputMode::Library, library_name) => {
+            let library = library_name
+                .map(|name| format!("\nlibrary {name};\n"))
+                .unwrap_or_default();
+            format!(
+                "{HEADER}{library}\n{imports}\nimport 'package:wasm_wit_component/wasm_wit_component.dart';\n{file_header}"
+            )
+        }
+        (OutputMode::PartOf, Some(library_name)) => {
+            let part_of = if library_name.ends_with(".dart") {
+                format!("'{library_name}'")
+            } else {
+                library_name
+            };
+            let imports =
+                format!("{imports}import 'package:wasm_wit_component/wasm_wit_component.dart';\n");
+            let imports = imports
+                .lines()
+                .map(|l| format!("// {l}\n"))
+                .collect::<String>();
+            format!(
+                "{PART_HEADER}\npart of {part_of};\n\n// The imports required in the library:\n{imports}{file_header}"
+            )
+        }
+        (OutputMode::PartOf, None) => {
+            return Err("The `part-of` output mode requires a library name.".to_string());
+        }
+        (OutputMode::Declarations, _) => file_header,
+    };
+    if let Some(mut parts) = out.parts {
+        resolve.interfaces.iter().for_each(|(id, _i)| {
+            match (parts.remove(&id), file_names.get(&id)) {
+                (Some(code), Some(file_name)) if !code.is_empty() => {
+                    main.push_str(&format!("\npart '{file_name}';\n"));
+                    part_files.push((
+                        file_name.clone(),
+                        format!("{PART_HEADER}\npart of '{main_name}';\n\n{code}"),
+                    ));
+                }
+                _ => {}
+            }
+        });
+    }
+    main.push_str(&out.main);
+
+    let mut files = vec![(main_name, main)];
+    files.extend(part_files);
+    Ok((files, p.4.into_inner()))
+}
+
+/// The `dart:` libraries that may be referenced by the generated code,
+/// with the identifiers that require the import.
+const DART_LIBRARIES: &[(&str, &[&str])] = &[
+    (
+        "dart:async",
+        &[
+            "Completer",
+            "FutureOr",
+            "StreamController",
+            "Timer",
+            "Zone",
+            "runZoned",
+            "scheduleMicrotask",
+            "unawaited",
+        ],
+    ),
+    (
+        "dart:ffi",
+        &[
+            "Pointer",
+            "NativeFunction",
+            "Int8",
+            "Int16",
+            "Int32",
+            "Int64",
+            "Uint8",
+            "Uint16",
+            "Uint32",
+            "Uint64",
+            "Float",
+            "Double",
+        ],
+    ),
+    (
+        "dart:typed_data",
+        &[
+            "ByteData",
+            "ByteBuffer",
+            "Endian",
+            "TypedData",
+            "Int8List",
+            "Int16List",
+            "Int32List",
+            "Int64List",
+            "Uint8List",
+            "Uint16List",
+            "Uint32List",
+            "Uint64List",
+            "Float32List",
+            "Float64List",
+        ],
+    ),
+];
+
+/// Returns the import statements for the `dart:` libraries referenced in [code].
+/// Comments and the identifiers of the classes declared in [code] are ignored,
+/// for example, a WIT record named `pointer` does not import `dart:ffi`.
+fn dart_imports<'a>(code: impl Iterator<Item = &'a String>) -> String {
+    let is_identifier = |c: char| c.is_alphanumeric() || c == '_';
+    let mut used = HashSet::new();
+    let mut declared = HashSet::new();
+    code.flat_map(|c| c.lines())
+        .map(str::trim)
+        .filter(|l| !l.starts_with("//"))
+        .for_each(|line| {
+            let mut previous = "";
+            for word in line.split(|c| !is_identifier(c)).filter(|w| !w.is_empty()) {
+                if matches!(previous, "class" | "enum" | "typedef") {
+                    declared.insert(word);
+                }
+                used.insert(word);
+                previous = word;
+            }
+        });
+    DART_LIBRARIES
+        .iter()
+        .filter(|(_, ids)| {
+            ids.iter()
+                .any(|id| used.contains(id) && !declared.contains(id))
+        })
+        .map(|(library, _)| format!("import '{library}';\n"))
+        .collect()
 }
 
-fn worker_instantiation(int64_type: &str) -> String {
+fn worker_instantiation(int64_type: &str, string_encoding: &str) -> String {
     format!(
         "
 var memType = MemoryTy(minimum: 1, maximum: 2, shared: true);
@@ -330,7 +659,7 @@ while (instance == null) {{
 library = WasmLibrary(
     instance,
     int64Type: {int64_type},
-    wasmMemory: wasmMemory,
+    wasmMemory: wasmMemory{string_encoding},
 );"
     )
 }
@@ -348,9 +677,11 @@ pub fn extract_dart_docs(docs: &Docs) -> Option<String> {
             m.replace_range(m.len() - 1.., "");
         }
 
+        let annotation = m.split('\n').find_map(gate_annotation);
         Some(
             m.split("\n")
                 .map(|l| format!("/// {}\n", l))
+                .chain(annotation)
                 .collect::<Vec<_>>()
                 .join(""),
         )
@@ -359,22 +690,50 @@ pub fn extract_dart_docs(docs: &Docs) -> Option<String> {
     }
 }
 
+/// The methods of a world with resources that dispose
+/// a resource at the end of a scope, even if it throws.
+const RESOURCE_SCOPE_METHODS: &str = "
+/// Calls [scope] with [resource] and disposes the resource when [scope]
+/// returns or throws, like an owned handle dropped at the end of a Rust scope.
+T withResource<R extends WitResource, T>(R resource, T Function(R resource) scope) {
+    try {
+        return scope(resource);
+    } finally {
+        resource.dispose();
+    }
+}
+
+/// Same as [withResource] for an asynchronous [scope],
+/// the resource is disposed when the returned future completes.
+Future<T> withResourceAsync<R extends WitResource, T>(
+    R resource,
+    Future<T> Function(R resource) scope,
+) async {
+    try {
+        return await scope(resource);
+    } finally {
+        resource.dispose();
+    }
+}
+";
+
 const HEADER: &str = "
 // FILE GENERATED FROM WIT
 
 // ignore: lines_longer_than_80_chars
 // ignore_for_file: require_trailing_commas, unnecessary_raw_strings, unnecessary_non_null_assertion, unused_element, avoid_returning_null_for_void
+";
 
-import 'dart:async';
-// ignore: unused_import
-import 'dart:typed_data';
+const PART_HEADER: &str = "
+// FILE GENERATED FROM WIT
 
-import 'package:wasm_wit_component/wasm_wit_component.dart';
+// ignore: lines_longer_than_80_chars
+// ignore_for_file: require_trailing_commas, unnecessary_raw_strings, unnecessary_non_null_assertion, unused_element, avoid_returning_null_for_void
 ";
 
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::Write, path::Path};
+    use std::{collections::HashMap, fs::File, io::Write, path::Path};
 
     use crate::{Int64TypeConfig, WitGeneratorConfig};
 
@@ -398,6 +757,14 @@ mod tests {
             async_worker: false,
             same_class_union: true,
             int64_type,
+            fixed_width_ints: false,
+            async_calls: false,
+            output_mode: None,
+            library_name: None,
+            string_encoding: crate::StringEncodingConfig::Utf8,
+            positional_record_constructor: false,
+            raw_results: false,
+            enabled_features: None,
         }
     }
 
@@ -420,6 +787,945 @@ default world host {
         println!("{}", s);
     }
 
+    #[test]
+    pub fn tuple_results_are_dart_records() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("tuples.wit"),
+            "
+package example:tuples
+
+world tuples {
+    export pair: func() -> tuple<u32, string>
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains("(int /*U32*/, String, ) pair("));
+        assert!(s.contains("[final v0,final v1] || (final v0,final v1)"));
+    }
+
+    #[test]
+    pub fn unsupported_types_are_diagnostics() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("futures.wit"),
+            "
+package example:futures
+
+world futures {
+    export f: func(value: future<u32>)
+}
+",
+        )
+        .unwrap();
+
+        let (s, diagnostics) = super::document_to_dart_with_diagnostics(
+            &parsed,
+            default_wit_config(Int64TypeConfig::BigInt),
+        )
+        .unwrap();
+        assert!(s.contains("/* Unsupported WIT type kind `future` */"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Unsupported WIT type kind `future`");
+    }
+
+    #[test]
+    pub fn generate_one_file_per_interface() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("files.wit"),
+            "
+package example:files
+
+interface types {
+    record point {
+        x: u32,
+        y: u32,
+    }
+}
+
+interface api {
+    use types.{point}
+
+    get-point: func() -> point
+}
+
+world files {
+    export api
+}
+",
+        )
+        .unwrap();
+
+        let files =
+            super::document_to_dart_files(&parsed, default_wit_config(Int64TypeConfig::BigInt))
+                .unwrap();
+        let mut names = files.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["api.dart", "files.dart", "types.dart"]);
+
+        let main = &files["files.dart"];
+        assert!(main.contains("import 'package:wasm_wit_component/wasm_wit_component.dart';"));
+        assert!(main.contains("part 'types.dart';"));
+        assert!(main.contains("part 'api.dart';"));
+        assert!(main.contains("class FilesWorld {"));
+        assert!(!main.contains("class Point "));
+
+        let types = &files["types.dart"];
+        assert!(types.contains("part of 'files.dart';"));
+        assert!(types.contains("class Point "));
+        assert!(!types.contains("import "));
+
+        let api = &files["api.dart"];
+        assert!(api.contains("part of 'files.dart';"));
+        assert!(api.contains("class Api {"));
+
+        let single =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(!single.contains("part '"));
+        assert!(single.contains("class Point ") && single.contains("class Api {"));
+    }
+
+    #[test]
+    pub fn generate_dart_imports() {
+        let generate = |types: &str| {
+            let wit = format!(
+                "
+package example:imports
+
+interface types {{
+{types}
+}}
+
+world imports {{
+    export types
+}}
+"
+            );
+            let parsed =
+                wit_parser::UnresolvedPackage::parse(Path::new("imports.wit"), &wit).unwrap();
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap()
+        };
+
+        let s = generate(
+            "
+    record pointer { x: u32 }
+    get-bytes: func() -> list<u8>
+",
+        );
+        assert!(s.contains("import 'dart:async';"));
+        assert!(s.contains("import 'dart:typed_data';"));
+        // The generated `Pointer` class is not the one from `dart:ffi`.
+        assert!(!s.contains("import 'dart:ffi';"));
+
+        let s = generate(
+            "
+    record named { name: string }
+    get-named: func() -> named
+",
+        );
+        assert!(s.contains("import 'dart:async';"));
+        assert!(!s.contains("import 'dart:typed_data';"));
+        assert!(!s.contains("import 'dart:ffi';"));
+    }
+
+    #[test]
+    pub fn generate_output_modes() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("modes.wit"),
+            "
+package example:modes
+
+interface types {
+    record point { x: u32, y: u32 }
+    get-bytes: func(p: point) -> list<u8>
+}
+
+world modes {
+    export types
+}
+",
+        )
+        .unwrap();
+        let generate = |output_mode, library_name: Option<&str>| {
+            let mut config = default_wit_config(Int64TypeConfig::BigInt);
+            config.output_mode = Some(output_mode);
+            config.library_name = library_name.map(str::to_string);
+            super::document_to_dart(&parsed, config)
+        };
+
+        let s = generate(crate::OutputMode::Library, None).unwrap();
+        assert!(!s
+            .lines()
+            .any(|l| l.starts_with("library ") && !l.contains('=')));
+        assert!(s.contains("\nimport 'dart:typed_data';"));
+        let s = generate(crate::OutputMode::Library, Some("example.modes")).unwrap();
+        assert!(s.contains("library example.modes;"));
+        assert!(s.contains("\nimport 'dart:typed_data';"));
+
+        let s = generate(crate::OutputMode::PartOf, Some("bindings.dart")).unwrap();
+        assert!(s.contains("part of 'bindings.dart';"));
+        assert!(s.contains("// import 'dart:typed_data';"));
+        assert!(s.contains("// import 'package:wasm_wit_component/wasm_wit_component.dart';"));
+        assert!(!s.contains("\nimport "));
+        assert!(s.contains("class Point "));
+        let s = generate(crate::OutputMode::PartOf, Some("example.modes")).unwrap();
+        assert!(s.contains("part of example.modes;"));
+        assert!(generate(crate::OutputMode::PartOf, None).is_err());
+
+        let s = generate(crate::OutputMode::Declarations, None).unwrap();
+        assert!(!s.contains("import "));
+        assert!(!s.contains("FILE GENERATED FROM WIT"));
+        assert!(s.contains("class Point "));
+    }
+
+    #[test]
+    pub fn generate_string_encodings() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("encodings.wit"),
+            "
+package example:encodings
+
+world encodings {
+    export echo: func(text: string) -> string
+}
+",
+        )
+        .unwrap();
+        let generate = |string_encoding, async_worker| {
+            let mut config = default_wit_config(Int64TypeConfig::BigInt);
+            config.string_encoding = string_encoding;
+            config.async_worker = async_worker;
+            super::document_to_dart(&parsed, config).unwrap()
+        };
+
+        let s = generate(crate::StringEncodingConfig::Utf8, false);
+        assert!(!s.contains("stringEncoding:"));
+        let s = generate(crate::StringEncodingConfig::Utf16, false);
+        assert!(
+            s.contains("int64Type: Int64TypeConfig.bigInt, stringEncoding: StringEncoding.utf16);")
+        );
+        let s = generate(crate::StringEncodingConfig::Latin1Utf16, true);
+        assert!(s.contains("wasmMemory: wasmMemory, stringEncoding: StringEncoding.latin1utf16,"));
+    }
+
+    #[test]
+    pub fn generate_bare_result() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("bare.wit"),
+            "
+package example:bare
+
+interface api {
+    record outcome { done: result }
+    try-run: func() -> result
+    report: func(r: result) -> result<_, string>
+}
+
+world bare {
+    export api
+}
+",
+        )
+        .unwrap();
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(!s.contains("Result<void, void>"));
+        assert!(s.contains("final bool done;"));
+        assert!(s.contains("bool tryRun()"));
+        assert!(s.contains("void report({required bool r,})"));
+        assert!(s.contains("(ok) => null, (error) => null).isOk"));
+        assert!(s.contains("(r ? 0 : 1, null)"));
+        assert!(s.contains("ResultType(null, null)"));
+    }
+
+    #[test]
+    pub fn generate_no_results() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("fire.wit"),
+            "
+package example:fire
+
+interface api {
+    resource counter {
+        constructor()
+        increment: func()
+    }
+    log: func(message: string)
+}
+
+world fire {
+    export api
+    export notify: func()
+}
+",
+        )
+        .unwrap();
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains("void log({required String message,}) {_log([message]);}"));
+        assert!(s.contains("void notify() {_notify([]);}"));
+        assert!(s.contains("void increment() {_world.api.methodCounterIncrement(self: this);}"));
+        assert!(!s.contains("return _log("));
+        assert!(!s.contains("return _world.api.methodCounterIncrement("));
+    }
+
+    #[test]
+    pub fn generate_resource_scope() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("scope.wit"),
+            "
+package example:scope
+
+interface api {
+    resource counter {
+        constructor()
+        increment: func()
+        merge: func(other: borrow<counter>) -> counter
+    }
+}
+
+world scope {
+    export api
+}
+",
+        )
+        .unwrap();
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains("class CounterBorrow implements ToJsonSerializable {"));
+        assert!(s.contains("class Counter extends CounterBorrow implements WitResource {"));
+        // Borrowed handles are not disposed nor attached to the finalizer.
+        assert!(s.contains("CounterBorrow._(this._rep, this._world);"));
+        assert!(s.contains("Counter merge({required CounterBorrow other,})"));
+        assert!(s.contains("_world._counterFinalizer.attach(this, _rep, detach: this);"));
+        assert!(s.contains("void dispose() {"));
+        // Disposing twice only drops the resource once.
+        assert!(s.contains("if (_isDisposed) return;"));
+        assert!(s.contains("_world._counterFinalizer.detach(this);"));
+        assert!(s.contains("throw StateError('The resource Counter was disposed.');"));
+        assert!(s.contains("T withResource<R extends WitResource, T>("));
+        assert!(s.contains("return scope(resource);"));
+        assert!(s.contains("resource.dispose();"));
+        assert!(s.contains("Future<T> withResourceAsync<R extends WitResource, T>("));
+
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("calc.wit"),
+            "
+package example:calc
+
+world calc {
+    export add: func(a: u32, b: u32) -> u32
+}
+",
+        )
+        .unwrap();
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(!s.contains("withResource"));
+    }
+
+    #[test]
+    pub fn generate_two_arg_function_call() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("calc.wit"),
+            "
+package example:calc
+
+interface api {
+    add: func(a: u32, b: u32) -> u32
+}
+
+world calc {
+    export api
+}
+",
+        )
+        .unwrap();
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        // The exports are called through the typed `ListValue` functions
+        // of the `WasmLibrary`, not through `dart:ffi` symbol lookups.
+        assert!(s.contains("final ListValue Function(ListValue) _add;"));
+        assert!(s.contains("getComponentFunction('example:calc/api#add',"));
+        assert!(s.contains("final results = _add(["));
+        assert!(!s.contains("lookup("));
+    }
+
+    #[test]
+    pub fn generate_optional_params() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("greet.wit"),
+            "
+package example:greet
+
+interface api {
+    greet: func(name: string, title: option<string>) -> string
+}
+
+world greet {
+    import api
+    export api
+}
+",
+        )
+        .unwrap();
+
+        for use_null_for_option in [true, false] {
+            let mut config = default_wit_config(Int64TypeConfig::BigInt);
+            config.use_null_for_option = use_null_for_option;
+            let s = super::document_to_dart(&parsed, config).unwrap();
+            assert!(s.contains("String greet({required String name,String? title,})"));
+            assert!(!s.contains("Option<String> title"));
+            assert!(s.contains(
+                "_greet([name, (title == null ? const None().toWasm() : Option.fromValue(title).toWasm())]);"
+            ));
+            assert!(s.contains("title: Option.fromJson(args1, (some) => "));
+        }
+    }
+
+    #[test]
+    pub fn generate_feature_gates() {
+        let wit = "
+package example:gates
+
+world gates {
+    record options {
+        size: u32,
+        @unstable(feature = fancy)
+        fancy-size: u32,
+    }
+
+    /// Fancy docs
+    @unstable(feature = fancy)
+    export fancy: func(options: options)
+    @since(version = 0.1.0)
+    export plain: func() -> u32
+    @deprecated(version = 0.2.0)
+    export old: func()
+    @unstable(feature = other)
+    export other: func()
+}
+";
+        let generate = |enabled_features: &[&str]| {
+            let enabled_features: Vec<_> = enabled_features.iter().map(|f| f.to_string()).collect();
+            let source =
+                crate::gates::apply_feature_gates("gates.wit", wit, &enabled_features).unwrap();
+            let parsed =
+                wit_parser::UnresolvedPackage::parse(Path::new("gates.wit"), &source).unwrap();
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap()
+        };
+
+        let s = generate(&[]);
+        assert!(!s.contains("fancySize"));
+        assert!(!s.contains("fancy("));
+        assert!(!s.contains("Fancy docs"));
+        assert!(!s.contains("other("));
+        assert!(s.contains("/// @since(version = 0.1.0)\nint /*U32*/ plain()"));
+        assert!(s.contains(
+            "/// @deprecated(version = 0.2.0)\n@Deprecated('Deprecated since version 0.2.0.')\nvoid old()"
+        ));
+
+        let s = generate(&["fancy"]);
+        assert!(s.contains(
+            "/// @unstable(feature = fancy)\n@Deprecated('Unstable WIT feature `fancy`.')\nfinal int /*U32*/ fancySize;"
+        ));
+        assert!(s.contains("/// Fancy docs\n/// @unstable(feature = fancy)\n@Deprecated("));
+        assert!(!s.contains("other("));
+
+        let err = crate::gates::apply_feature_gates("gates.wit", "world w {\n  @unstable\n}", &[])
+            .unwrap_err();
+        assert!(err.starts_with("gates.wit:2: expected the arguments of the `@unstable`"));
+    }
+
+    #[test]
+    pub fn generate_world_include() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("composed.wit"),
+            "
+package example:composed
+
+interface logger {
+    log: func(message: string)
+}
+
+interface api {
+    run: func() -> u32
+}
+
+world base {
+    import logger
+    export api
+}
+
+world composed {
+    include base
+    export extra: func() -> string
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert_eq!(s.matches("abstract class LoggerImport {").count(), 1);
+        assert_eq!(s.matches("class Api {").count(), 1);
+        assert!(s.contains("final ComposedWorld _world;"));
+        assert!(s.contains("class ComposedWorld {"));
+        assert!(s.contains("late final Api api;"));
+        assert!(!s.contains("BaseWorld"));
+
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("interfaces.wit"),
+            "
+package example:interfaces
+
+interface api {
+    run: func() -> u32
+}
+",
+        )
+        .unwrap();
+        let err = super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "The package `example:interfaces` does not define a world to generate."
+        );
+    }
+
+    #[test]
+    pub fn generate_imported_interfaces() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("host.wit"),
+            "
+package example:host
+
+interface storage {
+    record entry { key: string, value: list<u8> }
+
+    load: func(key: string) -> option<entry>
+    save: func(entry: entry)
+}
+
+world app {
+    import storage
+    export run: func() -> u32
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        // The host implements the abstract class.
+        assert!(s.contains("abstract class StorageImport {"));
+        assert!(s.contains("Entry? load({required String key,});"));
+        assert!(s.contains("void save({required Entry entry,});"));
+        assert!(s.contains("final StorageImport storage;"));
+        assert!(s.contains("required this.storage,"));
+        // Its methods are registered as imports of the module.
+        assert!(s.contains("imports.storage.load(key: "));
+        assert!(s.contains("imports.storage.save(entry: "));
+        assert!(s.contains(
+            "loweredImportFunction(r'example:host/storage#load', ft, execImportsStorageLoad, getLib);"
+        ));
+        assert!(s.contains("builder.addImport(r'example:host/storage', 'save', lowered);"));
+        assert!(!s.contains("class Storage {"));
+    }
+
+    #[test]
+    pub fn generate_async_calls() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("calls.wit"),
+            "
+package example:calls
+
+interface api {
+    sum: func(a: u32, b: u32) -> u32
+}
+
+world calls {
+    export api
+    export run: func() -> string
+}
+",
+        )
+        .unwrap();
+
+        let mut config = default_wit_config(Int64TypeConfig::BigInt);
+        config.async_calls = true;
+        let s = super::document_to_dart(&parsed, config).unwrap();
+        assert!(s.contains("_world.library.getComponentFunctionAsync('example:calls/api#sum'"));
+        assert!(s.contains("library.getComponentFunctionAsync('run'"));
+        assert!(!s.contains("getComponentFunctionWorker"));
+        assert!(!s.contains("getComponentFunction("));
+        assert!(s.contains("final Future<ListValue> Function(ListValue) _sum;"));
+        assert!(s.contains(
+            "Future<int /*U32*/> sum({required int /*U32*/ a,required int /*U32*/ b,}) async {"
+        ));
+        assert!(s.contains("Future<String> run() async {"));
+        // The instance is not executed in workers
+        assert!(s.contains("final instance = await builder.build();"));
+    }
+
+    #[test]
+    pub fn generate_enum_discriminants() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("colors.wit"),
+            "
+package example:colors
+
+world colors {
+    enum color { red, green, blue }
+    export paint: func(color: color) -> color
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains("red(0),green(1),blue(2);"));
+        assert!(s.contains("final int discriminant;"));
+        assert!(s.contains("const Color(this.discriminant);"));
+        assert!(s.contains("static const _byDiscriminant = [red, green, blue];"));
+        assert!(s.contains("int toWasm() => discriminant;"));
+        assert!(s.contains("ToJsonSerializable.enumFromJson(json, _byDiscriminant, _spec);"));
+        assert!(!s.contains("=> index;"));
+    }
+
+    #[test]
+    pub fn generate_flags_operations() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("permissions.wit"),
+            "
+package example:permissions
+
+world permissions {
+    flags permission { read, write, exec }
+    export check: func(p: permission) -> permission
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains(
+            "factory Permission.fromBool({bool read = false, bool write = false, bool exec = false})"
+        ));
+        assert!(s.contains(
+            "Permission operator |(Permission other) => Permission(flagsBits | other.flagsBits);"
+        ));
+        assert!(s.contains("Permission operator ~() => Permission(~flagsBits);"));
+        assert!(
+            s.contains("bool contains(Permission other) => flagsBits.contains(other.flagsBits);")
+        );
+        assert!(s.contains("bool get isEmpty => flagsBits.isEmpty;"));
+        assert!(s.contains(
+            "String toString() => 'Permission(${[if (read) 'read',if (write) 'write',if (exec) 'exec',].join(', ')})';"
+        ));
+    }
+
+    #[test]
+    pub fn generate_sealed_class_when_map() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("shapes.wit"),
+            "
+package example:shapes
+
+world shapes {
+    variant shape { circle(float64), empty }
+    union id { u32, string }
+    export area: func(shape: shape, id: id) -> float64
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains(
+            "T when<T>({required T Function(double /*F64*/ value) circle,required T Function() empty,}) => switch (this) { ShapeCircle(value: final v_) => circle(v_),ShapeEmpty() => empty(), };"
+        ));
+        assert!(s.contains(
+            "T map<T>({required T Function(ShapeCircle value) circle,required T Function(ShapeEmpty value) empty,}) => switch (this) { final ShapeCircle c_ => circle(c_),final ShapeEmpty c_ => empty(c_), };"
+        ));
+        assert!(s.contains("T when<T>({required T Function(int /*U32*/ value) intU32,"));
+        assert!(s.contains("IdString(value: final v_) => string(v_),"));
+    }
+
+    #[test]
+    pub fn generate_record_lift_lower() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("layout.wit"),
+            "
+package example:layout
+
+interface types {
+    enum kind { a, b }
+    record mixed {
+        small: u8,
+        big: u64,
+        half: u16,
+        ratio: float32,
+        flag: bool,
+        kind: kind,
+        signed: s32,
+    }
+    record outer {
+        first: bool,
+        inner: mixed,
+    }
+    record named {
+        name: string,
+    }
+}
+
+world layout {
+    export types
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains("factory Mixed.lift(ByteData data, int ptr) => Mixed.fromJson(["));
+        for read in [
+            "data.getUint8(ptr + 0),",
+            "i64.getUint64(data, ptr + 8, Endian.little),",
+            "data.getUint16(ptr + 16, Endian.little),",
+            "data.getFloat32(ptr + 20, Endian.little),",
+            "data.getUint8(ptr + 24) != 0,",
+            "data.getUint8(ptr + 25),",
+            "data.getInt32(ptr + 28, Endian.little),",
+        ] {
+            assert!(s.contains(read), "{read}");
+        }
+        assert!(s.contains("i64.setUint64(data, ptr + 8, i64.fromBigInt(big), Endian.little);"));
+        assert!(s.contains("data.setUint8(ptr + 24, flag ? 1 : 0);"));
+        assert!(s.contains("data.setUint8(ptr + 25, kind.discriminant);"));
+        // The nested record is aligned to its largest field.
+        assert!(s.contains("Mixed.lift(data, ptr + 8).toWasm(),"));
+        assert!(s.contains("inner.lower(data, ptr + 8);"));
+        // Strings do not have a fixed layout.
+        assert!(!s.contains("factory Named.lift("));
+    }
+
+    #[test]
+    pub fn generate_record_constructors() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("constructors.wit"),
+            "
+package example:constructors
+
+world constructors {
+    record person {
+        first-name: string,
+        last-name: string,
+        nickname: option<string>,
+    }
+
+    export greet: func(person: person) -> string
+}
+",
+        )
+        .unwrap();
+        let generate = |positional_record_constructor| {
+            let mut config = default_wit_config(Int64TypeConfig::BigInt);
+            config.positional_record_constructor = positional_record_constructor;
+            super::document_to_dart(&parsed, config).unwrap()
+        };
+
+        let copy_with =
+            "Person copyWith({String? firstName,String? lastName,Option<String>? nickname,}) => \
+            Person(firstName: firstName ?? this.firstName,lastName: lastName ?? this.lastName,\
+            nickname: nickname != null ? nickname.value : this.nickname);";
+        let s = generate(false);
+        assert!(s.contains(copy_with));
+        assert!(!s.contains("Person.positional("));
+
+        let s = generate(true);
+        assert!(s.contains(copy_with));
+        assert!(
+            s.contains("const Person.positional(this.firstName, this.lastName, this.nickname);")
+        );
+    }
+
+    #[test]
+    pub fn generate_throwing_results() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("errors.wit"),
+            "
+package example:errors
+
+interface store {
+    enum my-error { not-found, invalid-key }
+
+    load: func(key: string) -> result<string, my-error>
+    check: func() -> result<_, string>
+}
+
+world errors {
+    export store
+}
+",
+        )
+        .unwrap();
+        let generate = |raw_results| {
+            let mut config = default_wit_config(Int64TypeConfig::BigInt);
+            config.raw_results = raw_results;
+            super::document_to_dart(&parsed, config).unwrap()
+        };
+
+        let s = generate(false);
+        assert!(s.contains("class MyErrorException implements Exception {"));
+        assert!(s.contains("final MyError error;"));
+        assert!(s.contains("const MyErrorException(this.error);"));
+        assert!(s.contains("String load({required String key,}) {"));
+        assert!(s.contains("final value = _world.withContext(() => Result.fromJson(result, "));
+        assert!(s.contains("(error) => MyError.fromJson(error)));"));
+        assert!(s.contains(
+            "return switch (value) { Ok(:final ok) => ok, \
+            Err(:final error) => throw MyErrorException(error), };"
+        ));
+        assert!(s.contains("void check() {"));
+        assert!(s.contains("return value.unwrap();"));
+
+        let s = generate(true);
+        assert!(!s.contains("MyErrorException"));
+        assert!(s.contains("Result<String, MyError> load({required String key,}) {"));
+        assert!(s.contains("Result<void, String> check() {"));
+    }
+
+    #[test]
+    pub fn generate_nested_lists() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("nested.wit"),
+            "
+package example:nested
+
+interface nested {
+    record point { x: string, y: string }
+    variant shape { circle(float64), empty }
+    record grid {
+        names: list<list<string>>,
+        points: list<list<point>>,
+        shapes: list<shape>,
+    }
+
+    transpose: func(rows: list<list<string>>) -> list<list<string>>
+}
+
+world nested-lists {
+    export nested
+}
+",
+        )
+        .unwrap();
+
+        let s =
+            super::document_to_dart(&parsed, default_wit_config(Int64TypeConfig::BigInt)).unwrap();
+        assert!(s.contains("List<List<String>> names"));
+        assert!(s.contains("List<List<Point>> points"));
+        assert!(s.contains("List<Shape> shapes"));
+        assert!(s.contains("('rows', ListType(ListType(StringType())))"));
+        assert!(s.contains("('', ListType(ListType(StringType())))"));
+        assert!(s.contains("ListType(ListType(Point._spec))"));
+        // Each level lifts its own elements.
+        assert!(s.contains(
+            "(names! as Iterable).map((e) => (e! as Iterable).map((e) => e is String ? e : (e! as ParsedString).value).toList()).toList()"
+        ));
+        assert!(s.contains(
+            "(result! as Iterable).map((e) => (e! as Iterable).map((e) => e is String ? e : (e! as ParsedString).value).toList()).toList()"
+        ));
+        assert!(s.contains("(points! as Iterable).map((e) => (e! as Iterable).map(Point.fromJson).toList()).toList()"));
+        assert!(s.contains("(shapes! as Iterable).map(Shape.fromJson).toList()"));
+        // And lowers them.
+        assert!(s.contains("names.map((e) => e.toList()).toList()"));
+        assert!(s.contains("points.map((e) => e.map((e) => e.toJson()).toList()).toList()"));
+        assert!(s.contains(
+            "points.map((e) => e.map((e) => e.toWasm()).toList(growable: false)).toList(growable: false)"
+        ));
+        assert!(s.contains("shapes.map((e) => e.toWasm()).toList(growable: false)"));
+    }
+
+    #[test]
+    pub fn generate_json_schema() {
+        let path = format!("{}/fixtures/drawing.wit", PACKAGE_DIR);
+        let parsed = wit_parser::UnresolvedPackage::parse_file(Path::new(&path)).unwrap();
+
+        let schema =
+            super::document_to_json_schema(&parsed, default_wit_config(Int64TypeConfig::BigInt))
+                .unwrap();
+        let expected =
+            std::fs::read_to_string(format!("{}/fixtures/drawing.schema.json", PACKAGE_DIR))
+                .unwrap();
+        assert_eq!(schema, expected);
+    }
+
+    #[test]
+    pub fn canonical_abi_size_and_alignment() {
+        let parsed = wit_parser::UnresolvedPackage::parse(
+            Path::new("layout.wit"),
+            "
+package example:layout
+
+interface types {
+    record small { a: u8, b: u32 }
+    record padded { a: u64, b: u8 }
+    type bytes = list<u8>
+    type maybe = option<u64>
+    type outcome = result<u8, u32>
+    type pair = tuple<u8, u16, u8>
+    flags few { a, b, c }
+    flags many { f0, f1, f2, f3, f4, f5, f6, f7, f8, f9, f10, f11, f12, f13, f14, f15, f16, f17, f18, f19, f20, f21, f22, f23, f24, f25, f26, f27, f28, f29, f30, f31, f32, f33, f34, f35, f36, f37, f38, f39 }
+    variant shape { empty, point(u8), line(tuple<u16, u16>), named(string) }
+}
+
+world layout {
+    export types
+}
+",
+        )
+        .unwrap();
+        let mut resolve = wit_parser::Resolve::new();
+        resolve.push(parsed).unwrap();
+        let p = super::Parsed(
+            &resolve,
+            HashMap::new(),
+            default_wit_config(Int64TypeConfig::BigInt),
+            HashMap::new(),
+            Default::default(),
+            vec![],
+        );
+        let layout = |name: &str| {
+            let (id, _) = resolve
+                .types
+                .iter()
+                .find(|(_, ty)| ty.name.as_deref() == Some(name))
+                .unwrap();
+            let ty = wit_parser::Type::Id(id);
+            (p.size_of(&ty), p.align_of(&ty))
+        };
+
+        let string = wit_parser::Type::String;
+        assert_eq!((p.size_of(&string), p.align_of(&string)), (8, 4));
+        assert_eq!(layout("small"), (8, 4));
+        assert_eq!(layout("padded"), (16, 8));
+        assert_eq!(layout("bytes"), (8, 4));
+        assert_eq!(layout("maybe"), (16, 8));
+        assert_eq!(layout("outcome"), (8, 4));
+        assert_eq!(layout("pair"), (6, 2));
+        assert_eq!(layout("few"), (1, 1));
+        assert_eq!(layout("many"), (8, 4));
+        // The largest payload is the string, a pointer and a length aligned to 4.
+        assert_eq!(layout("shape"), (12, 4));
+    }
+
     fn parse_and_write_generation(path: &str, output_path: &str, config: WitGeneratorConfig) {
         let parsed = wit_parser::UnresolvedPackage::parse_file(Path::new(path)).unwrap();
 
@@ -0,0 +1,252 @@
+use crate::{strings::Normalize, types::Parsed};
+use wit_parser::*;
+
+/// Rounds [offset] up to a multiple of [align].
+fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// The size in bytes of the discriminant for a type with [cases] cases.
+fn discriminant_size(cases: usize) -> usize {
+    match cases {
+        0..=256 => 1,
+        257..=65536 => 2,
+        _ => 4,
+    }
+}
+
+impl Parsed<'_> {
+    /// Returns the size in bytes of [ty] in the canonical ABI.
+    pub fn size_of(&self, ty: &Type) -> usize {
+        self.layout(ty).0
+    }
+
+    /// Returns the alignment in bytes of [ty] in the canonical ABI.
+    pub fn align_of(&self, ty: &Type) -> usize {
+        self.layout(ty).1
+    }
+
+    /// Returns the size and alignment of [ty] in the canonical ABI.
+    fn layout(&self, ty: &Type) -> (usize, usize) {
+        match ty {
+            Type::Bool | Type::U8 | Type::S8 => (1, 1),
+            Type::U16 | Type::S16 => (2, 2),
+            Type::U32 | Type::S32 | Type::Float32 | Type::Char => (4, 4),
+            Type::U64 | Type::S64 | Type::Float64 => (8, 8),
+            // A pointer and a length
+            Type::String => (8, 4),
+            Type::Id(ty_id) => match &self.0.types.get(*ty_id).unwrap().kind {
+                TypeDefKind::Record(r) => {
+                    self.record_layout(&r.fields.iter().map(|f| f.ty).collect::<Vec<_>>())
+                }
+                TypeDefKind::Tuple(t) => self.record_layout(&t.types),
+                TypeDefKind::Flags(f) => match f.flags.len() {
+                    0 => (0, 1),
+                    1..=8 => (1, 1),
+                    9..=16 => (2, 2),
+                    n => (4 * n.div_ceil(32), 4),
+                },
+                TypeDefKind::Enum(e) => {
+                    let size = discriminant_size(e.cases.len());
+                    (size, size)
+                }
+                TypeDefKind::Variant(v) => {
+                    self.variant_layout(&v.cases.iter().map(|c| c.ty).collect::<Vec<_>>())
+                }
+                TypeDefKind::Option(ty) => self.variant_layout(&[None, Some(*ty)]),
+                TypeDefKind::Result(r) => self.variant_layout(&[r.ok, r.err]),
+                TypeDefKind::Union(u) => {
+                    self.variant_layout(&u.cases.iter().map(|c| Some(c.ty)).collect::<Vec<_>>())
+                }
+                // A pointer and a length
+                TypeDefKind::List(_) => (8, 4),
+                // An `i32` index into a table
+                TypeDefKind::Handle(_)
+                | TypeDefKind::Resource
+                | TypeDefKind::Future(_)
+                | TypeDefKind::Stream(_) => (4, 4),
+                TypeDefKind::Type(ty) => self.layout(ty),
+                TypeDefKind::Unknown => unreachable!("unknown types are not resolved"),
+            },
+        }
+    }
+
+    /// Returns the size and alignment of a record with fields of [types],
+    /// each field is aligned to its own alignment.
+    fn record_layout(&self, types: &[Type]) -> (usize, usize) {
+        let mut size = 0;
+        let mut align = 1;
+        for ty in types {
+            let (field_size, field_align) = self.layout(ty);
+            size = align_to(size, field_align) + field_size;
+            align = align.max(field_align);
+        }
+        (align_to(size, align), align)
+    }
+
+    /// Returns the size and alignment of a variant with the payloads in [cases].
+    /// The payload is stored after the discriminant,
+    /// aligned to the largest alignment of the cases.
+    fn variant_layout(&self, cases: &[Option<Type>]) -> (usize, usize) {
+        let discriminant = discriminant_size(cases.len());
+        let (payload_size, payload_align) = cases
+            .iter()
+            .flatten()
+            .map(|ty| self.layout(ty))
+            .fold((0, 1), |(size, align), (s, a)| (size.max(s), align.max(a)));
+        let align = discriminant.max(payload_align);
+        let size = align_to(discriminant, payload_align) + payload_size;
+        (align_to(size, align), align)
+    }
+
+    /// Whether [ty] has a fixed layout supported by the generated `lift` and `lower` methods:
+    /// numbers, booleans, chars, enums and records of them.
+    fn has_fixed_layout(&self, ty: &Type) -> bool {
+        match ty {
+            Type::String => false,
+            Type::Id(ty_id) => match &self.0.types.get(*ty_id).unwrap().kind {
+                TypeDefKind::Enum(_) => true,
+                TypeDefKind::Record(r) => r.fields.iter().all(|f| self.has_fixed_layout(&f.ty)),
+                TypeDefKind::Type(ty) => self.has_fixed_layout(ty),
+                _ => false,
+            },
+            _ => true,
+        }
+    }
+
+    /// Returns the canonical ABI offsets of the fields in [r],
+    /// or `None` if a field does not have a fixed layout.
+    fn fixed_field_offsets(&self, r: &Record) -> Option<Vec<usize>> {
+        let mut offset = 0;
+        r.fields
+            .iter()
+            .map(|f| {
+                if !self.has_fixed_layout(&f.ty) {
+                    return None;
+                }
+                let (size, align) = self.layout(&f.ty);
+                let field_offset = align_to(offset, align);
+                offset = field_offset + size;
+                Some(field_offset)
+            })
+            .collect()
+    }
+
+    /// Returns the generated `lift` factory and `lower` method for the record,
+    /// which read and write the fields at their canonical ABI offsets from
+    /// a pointer into linear memory.
+    /// Returns `None` if a field does not have a fixed layout, for example strings or lists.
+    pub fn record_lift_lower(&self, name: &str, r: &Record) -> Option<String> {
+        let offsets = self.fixed_field_offsets(r)?;
+        let lifted = r
+            .fields
+            .iter()
+            .zip(&offsets)
+            .map(|(f, offset)| format!("{},", self.lift_field(&f.ty, *offset)))
+            .collect::<String>();
+        let lowered = r
+            .fields
+            .iter()
+            .zip(&offsets)
+            .map(|(f, offset)| self.lower_field(&f.name.as_var(), &f.ty, *offset))
+            .collect::<String>();
+        Some(format!(
+            "/// Reads the record from the linear memory [data] at [ptr],
+            /// with the fields at their canonical ABI offsets.
+            factory {name}.lift(ByteData data, int ptr) => {name}.fromJson([{lifted}]);
+            /// Writes the record to the linear memory [data] at [ptr],
+            /// with the fields at their canonical ABI offsets.
+            void lower(ByteData data, int ptr) {{{lowered}}}"
+        ))
+    }
+
+    /// Returns the expression that reads the canonical value
+    /// of type [ty] at [offset] from `ptr`.
+    fn lift_field(&self, ty: &Type, offset: usize) -> String {
+        let at = format!("ptr + {offset}");
+        match ty {
+            Type::Bool => format!("data.getUint8({at}) != 0"),
+            Type::U8 => format!("data.getUint8({at})"),
+            Type::S8 => format!("data.getInt8({at})"),
+            Type::U16 => format!("data.getUint16({at}, Endian.little)"),
+            Type::S16 => format!("data.getInt16({at}, Endian.little)"),
+            Type::U32 => format!("data.getUint32({at}, Endian.little)"),
+            Type::S32 => format!("data.getInt32({at}, Endian.little)"),
+            Type::U64 | Type::S64 => {
+                let method = if matches!(ty, Type::U64) {
+                    "getUint64"
+                } else {
+                    "getInt64"
+                };
+                let value = format!("i64.{method}(data, {at}, Endian.little)");
+                if self.type_to_str(ty).starts_with("int") {
+                    format!("i64.toInt({value})")
+                } else {
+                    value
+                }
+            }
+            Type::Float32 => format!("data.getFloat32({at}, Endian.little)"),
+            Type::Float64 => format!("data.getFloat64({at}, Endian.little)"),
+            Type::Char => format!("String.fromCharCode(data.getUint32({at}, Endian.little))"),
+            Type::String => unreachable!("strings do not have a fixed layout"),
+            Type::Id(ty_id) => match &self.0.types.get(*ty_id).unwrap().kind {
+                TypeDefKind::Enum(e) => match discriminant_size(e.cases.len()) {
+                    1 => format!("data.getUint8({at})"),
+                    2 => format!("data.getUint16({at}, Endian.little)"),
+                    _ => format!("data.getUint32({at}, Endian.little)"),
+                },
+                TypeDefKind::Record(_) => {
+                    format!("{}.lift(data, {at}).toWasm()", self.type_to_str(ty))
+                }
+                TypeDefKind::Type(ty) => self.lift_field(ty, offset),
+                _ => unreachable!("the type does not have a fixed layout"),
+            },
+        }
+    }
+
+    /// Returns the statement that writes the value in [getter]
+    /// of type [ty] at [offset] from `ptr`.
+    fn lower_field(&self, getter: &str, ty: &Type, offset: usize) -> String {
+        let at = format!("ptr + {offset}");
+        let value = self.type_to_wasm(getter, ty);
+        match ty {
+            Type::Bool => format!("data.setUint8({at}, {value} ? 1 : 0);"),
+            Type::U8 => format!("data.setUint8({at}, {value});"),
+            Type::S8 => format!("data.setInt8({at}, {value});"),
+            Type::U16 => format!("data.setUint16({at}, {value}, Endian.little);"),
+            Type::S16 => format!("data.setInt16({at}, {value}, Endian.little);"),
+            Type::U32 => format!("data.setUint32({at}, {value}, Endian.little);"),
+            Type::S32 => format!("data.setInt32({at}, {value}, Endian.little);"),
+            Type::U64 | Type::S64 => {
+                let method = if matches!(ty, Type::U64) {
+                    "setUint64"
+                } else {
+                    "setInt64"
+                };
+                let dart_type = self.type_to_str(ty);
+                let value = if dart_type.starts_with("int") {
+                    format!("i64.fromInt({value})")
+                } else if dart_type.starts_with("BigInt") {
+                    format!("i64.fromBigInt({value})")
+                } else {
+                    value
+                };
+                format!("i64.{method}(data, {at}, {value}, Endian.little);")
+            }
+            Type::Float32 => format!("data.setFloat32({at}, {value}, Endian.little);"),
+            Type::Float64 => format!("data.setFloat64({at}, {value}, Endian.little);"),
+            Type::Char => format!("data.setUint32({at}, {value}.runes.first, Endian.little);"),
+            Type::String => unreachable!("strings do not have a fixed layout"),
+            Type::Id(ty_id) => match &self.0.types.get(*ty_id).unwrap().kind {
+                TypeDefKind::Enum(e) => match discriminant_size(e.cases.len()) {
+                    1 => format!("data.setUint8({at}, {getter}.discriminant);"),
+                    2 => format!("data.setUint16({at}, {getter}.discriminant, Endian.little);"),
+                    _ => format!("data.setUint32({at}, {getter}.discriminant, Endian.little);"),
+                },
+                TypeDefKind::Record(_) => format!("{getter}.lower(data, {at});"),
+                TypeDefKind::Type(ty) => self.lower_field(getter, ty, offset),
+                _ => unreachable!("the type does not have a fixed layout"),
+            },
+        }
+    }
+}
@@ -1,20 +1,32 @@
-use std::path::Path;
+#[cfg(feature = "component")]
+use std::path::{Path, PathBuf};
 
+#[cfg(not(feature = "component"))]
+mod config;
 mod function;
+mod gates;
 pub mod generate;
+mod layout;
 mod methods;
+mod schema;
 mod strings;
 mod types;
 
+#[cfg(not(feature = "component"))]
+pub use config::*;
+
 // Use a procedural macro to generate bindings for the world we specified in
 // `with/dart-wit-generator.wit`
+#[cfg(feature = "component")]
 wit_bindgen::generate!("dart-wit-generator");
 
 // Define a custom type and implement the generated `Host` trait for it which
 // represents implementing all the necessary exported interfaces for this
 // component.
+#[cfg(feature = "component")]
 struct GeneratorImpl;
 
+#[cfg(feature = "component")]
 impl DartWitGenerator for GeneratorImpl {
     fn generate_to_file(config: WitGeneratorConfig, file_path: String) -> Result<(), String> {
         let file = Self::generate(config)?;
@@ -23,32 +35,89 @@ impl DartWitGenerator for GeneratorImpl {
     }
 
     fn generate(config: WitGeneratorConfig) -> Result<WitFile, String> {
-        let base_config = WitGeneratorConfig {
-            inputs: WitGeneratorInput::FileSystemPaths(FileSystemPaths {
-                input_path: "".to_string(),
-            }),
-            ..config
-        };
-        let (path, pkg) = match config.inputs {
-            WitGeneratorInput::InMemoryFiles(inputs) => {
-                let mut source_map = wit_parser::SourceMap::new();
-                let world_path = inputs.world_file.path.clone();
-                for input in inputs.pkg_files.into_iter().chain([inputs.world_file]) {
-                    let path = Path::new(&input.path);
-                    source_map.push(&path, input.contents);
-                }
-                let parsed = source_map.parse().map_err(|e| e.to_string())?;
-                (world_path, parsed)
-            }
-            WitGeneratorInput::FileSystemPaths(p) => {
-                let parsed = wit_parser::UnresolvedPackage::parse_path(Path::new(&p.input_path))
-                    .map_err(|e| e.to_string())?;
-                (p.input_path, parsed)
-            }
-        };
+        let (path, pkg, base_config) = parse_package(config)?;
         let contents = generate::document_to_dart(&pkg, base_config)?;
         Ok(WitFile { path, contents })
     }
+
+    fn generate_files(config: WitGeneratorConfig) -> Result<Vec<WitFile>, String> {
+        let (_path, pkg, base_config) = parse_package(config)?;
+        let files = generate::document_to_dart_files(&pkg, base_config)?;
+        Ok(files
+            .into_iter()
+            .map(|(path, contents)| WitFile { path, contents })
+            .collect())
+    }
+}
+
+/// Parses the WIT package from the [WitGeneratorConfig] inputs.
+/// Returns the path of the input, the package and the config without the inputs.
+#[cfg(feature = "component")]
+fn parse_package(
+    config: WitGeneratorConfig,
+) -> Result<(String, wit_parser::UnresolvedPackage, WitGeneratorConfig), String> {
+    let base_config = WitGeneratorConfig {
+        inputs: WitGeneratorInput::FileSystemPaths(FileSystemPaths {
+            input_path: "".to_string(),
+        }),
+        ..config
+    };
+    let (path, files) = match config.inputs {
+        WitGeneratorInput::InMemoryFiles(inputs) => {
+            let world_path = inputs.world_file.path.clone();
+            let files = inputs
+                .pkg_files
+                .into_iter()
+                .chain([inputs.world_file])
+                .map(|input| (PathBuf::from(input.path), input.contents))
+                .collect();
+            (world_path, files)
+        }
+        WitGeneratorInput::FileSystemPaths(p) => {
+            let files = read_wit_files(Path::new(&p.input_path))?;
+            (p.input_path, files)
+        }
+    };
+    let enabled_features = base_config.enabled_features.as_deref().unwrap_or_default();
+    let mut source_map = wit_parser::SourceMap::new();
+    for (file_path, contents) in files {
+        let contents =
+            gates::apply_feature_gates(&file_path.to_string_lossy(), &contents, enabled_features)?;
+        source_map.push(&file_path, contents);
+    }
+    let pkg = source_map.parse().map_err(|e| e.to_string())?;
+    Ok((path, pkg, base_config))
+}
+
+/// Reads the WIT files of the package in [path], a directory
+/// or a single file, like [wit_parser::UnresolvedPackage::parse_path].
+#[cfg(feature = "component")]
+fn read_wit_files(path: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    let paths = if path.is_dir() {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(path).map_err(|e| e.to_string())? {
+            let path = entry.map_err(|e| e.to_string())?.path();
+            let name = path
+                .file_name()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            if path.is_file() && (name.ends_with(".wit") || name.ends_with(".wit.md")) {
+                paths.push(path);
+            }
+        }
+        paths
+    } else {
+        vec![path.to_path_buf()]
+    };
+    paths
+        .into_iter()
+        .map(|path| {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| format!("failed to read file {path:?}: {e}"))?;
+            Ok((path, contents))
+        })
+        .collect()
 }
 
+#[cfg(feature = "component")]
 export_dart_wit_generator!(GeneratorImpl);
@@ -123,15 +123,15 @@ impl GeneratedMethodsTrait for Record {
 
 impl GeneratedMethodsTrait for Enum {
     fn to_wasm(&self, _name: &str, _p: &Parsed) -> String {
-        "int toWasm() => index;".to_string()
+        "int toWasm() => discriminant;".to_string()
     }
     fn to_json(&self, name: &str, _p: &Parsed) -> String {
-        format!("Map<String, Object?> toJson() => {{'runtimeType':'{name}', _spec.labels[index]: null}};\n")
+        format!("Map<String, Object?> toJson() => {{'runtimeType':'{name}', _spec.labels[discriminant]: null}};\n")
     }
     fn from_json(&self, name: &str, _p: &Parsed) -> Option<String> {
         Some(format!(
             "factory {name}.fromJson(Object? json) {{
-                return ToJsonSerializable.enumFromJson(json, values, _spec);
+                return ToJsonSerializable.enumFromJson(json, _byDiscriminant, _spec);
             }}",
         ))
     }
@@ -0,0 +1,361 @@
+use crate::types::{function_resource, Parsed};
+use wit_parser::*;
+
+/// A JSON value. The keys of an object are written in insertion order,
+/// so that the output is stable across runs.
+enum Json {
+    Null,
+    Number(usize),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn str(s: impl Into<String>) -> Self {
+        Json::String(s.into())
+    }
+
+    /// Writes this value indented with two spaces per level.
+    fn write(&self, out: &mut String, indent: usize) {
+        let newline = |out: &mut String, indent: usize| {
+            out.push('\n');
+            out.push_str(&"  ".repeat(indent));
+        };
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Number(n) => out.push_str(&n.to_string()),
+            Json::String(s) => write_json_string(out, s),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(out, indent + 1);
+                    item.write(out, indent + 1);
+                }
+                newline(out, indent);
+                out.push(']');
+            }
+            Json::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            Json::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(out, indent + 1);
+                    write_json_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                }
+                newline(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Adds the `docs` entry to [entries], if there are docs.
+fn push_docs(entries: &mut Vec<(&'static str, Json)>, docs: &Docs) {
+    if let Some(docs) = &docs.contents {
+        entries.push(("docs", Json::str(docs.trim())));
+    }
+}
+
+impl Parsed<'_> {
+    /// Returns a JSON description of the worlds to generate:
+    /// their imported and exported interfaces, functions and types.
+    ///
+    /// Each world has a `name` and the `imports` and `exports` items.
+    /// An item has a `kind`, one of `interface`, `function` or `type`.
+    /// Interfaces list their `types` and `functions`.
+    /// Named types are described once, with their canonical ABI `size` and `align`
+    /// and their `definition`, and referenced elsewhere as `{ "ref", "owner" }`.
+    /// Primitive types are their WIT name, for example, `"u32"` or `"string"`.
+    pub fn to_json_schema(&self) -> String {
+        let worlds = self.5.iter().map(|id| self.world_json(*id)).collect();
+        let package = match self.0.packages.iter().last() {
+            Some((_, package)) => Json::str(package.name.to_string()),
+            None => Json::Null,
+        };
+        let mut out = String::new();
+        Json::Object(vec![("package", package), ("worlds", Json::Array(worlds))])
+            .write(&mut out, 0);
+        out.push('\n');
+        out
+    }
+
+    fn world_json(&self, id: WorldId) -> Json {
+        let world = &self.0.worlds[id];
+        let mut entries = vec![("name", Json::str(&world.name))];
+        push_docs(&mut entries, &world.docs);
+        entries.push(("imports", self.world_items_json(world.imports.iter())));
+        entries.push(("exports", self.world_items_json(world.exports.iter())));
+        Json::Object(entries)
+    }
+
+    fn world_items_json<'b>(
+        &self,
+        items: impl Iterator<Item = (&'b WorldKey, &'b WorldItem)>,
+    ) -> Json {
+        let with_kind = |kind: &str, mut entries: Vec<(&'static str, Json)>| {
+            entries.insert(0, ("kind", Json::str(kind)));
+            Json::Object(entries)
+        };
+        Json::Array(
+            items
+                .map(|(key, item)| match item {
+                    WorldItem::Interface(id) => self.interface_json(key, *id),
+                    WorldItem::Function(f) => with_kind("function", self.function_entries(f)),
+                    WorldItem::Type(id) => with_kind("type", self.type_def_entries(*id)),
+                })
+                .collect(),
+        )
+    }
+
+    fn interface_json(&self, key: &WorldKey, id: InterfaceId) -> Json {
+        let interface = &self.0.interfaces[id];
+        let mut entries = vec![
+            ("kind", Json::str("interface")),
+            ("name", Json::str(self.0.name_world_key(key))),
+        ];
+        push_docs(&mut entries, &interface.docs);
+        let types = interface
+            .types
+            .values()
+            .map(|id| Json::Object(self.type_def_entries(*id)))
+            .collect();
+        let functions = interface
+            .functions
+            .values()
+            .map(|f| Json::Object(self.function_entries(f)))
+            .collect();
+        entries.push(("types", Json::Array(types)));
+        entries.push(("functions", Json::Array(functions)));
+        Json::Object(entries)
+    }
+
+    fn function_entries(&self, f: &Function) -> Vec<(&'static str, Json)> {
+        let function_kind = match f.kind {
+            FunctionKind::Freestanding => "freestanding",
+            FunctionKind::Method(_) => "method",
+            FunctionKind::Static(_) => "static",
+            FunctionKind::Constructor(_) => "constructor",
+        };
+        let mut entries = vec![("name", Json::str(&f.name))];
+        push_docs(&mut entries, &f.docs);
+        entries.push(("function_kind", Json::str(function_kind)));
+        if let Some(resource) = function_resource(&f.kind) {
+            entries.push(("resource", self.type_ref_json(*resource)));
+        }
+        let param = |name: &str, ty: &Type| {
+            Json::Object(vec![
+                ("name", Json::str(name)),
+                ("type", self.type_json(ty)),
+            ])
+        };
+        let params = f.params.iter().map(|(name, ty)| param(name, ty)).collect();
+        let results = match &f.results {
+            Results::Anon(ty) => vec![Json::Object(vec![("type", self.type_json(ty))])],
+            Results::Named(results) => results.iter().map(|(name, ty)| param(name, ty)).collect(),
+        };
+        entries.push(("params", Json::Array(params)));
+        entries.push(("results", Json::Array(results)));
+        entries
+    }
+
+    /// The description of the named type [id], with its definition.
+    fn type_def_entries(&self, id: TypeId) -> Vec<(&'static str, Json)> {
+        let ty = &self.0.types[id];
+        let mut entries = vec![
+            ("name", ty.name.as_ref().map_or(Json::Null, Json::str)),
+            (
+                "owner",
+                self.type_owner_name(ty.owner).map_or(Json::Null, Json::str),
+            ),
+            (
+                "dart",
+                self.type_def_to_name_definition(ty)
+                    .map_or(Json::Null, Json::str),
+            ),
+        ];
+        push_docs(&mut entries, &ty.docs);
+        if !matches!(ty.kind, TypeDefKind::Unknown) {
+            let id = Type::Id(id);
+            entries.push(("size", Json::Number(self.size_of(&id))));
+            entries.push(("align", Json::Number(self.align_of(&id))));
+        }
+        entries.push(("definition", self.type_def_kind_json(&ty.kind)));
+        entries
+    }
+
+    /// A reference to the named type [id], described in its owner.
+    fn type_ref_json(&self, id: TypeId) -> Json {
+        let ty = &self.0.types[id];
+        Json::Object(vec![
+            ("ref", ty.name.as_ref().map_or(Json::Null, Json::str)),
+            (
+                "owner",
+                self.type_owner_name(ty.owner).map_or(Json::Null, Json::str),
+            ),
+        ])
+    }
+
+    /// The type [ty], a reference for named types,
+    /// otherwise its definition inline.
+    fn type_json(&self, ty: &Type) -> Json {
+        let primitive = match ty {
+            Type::Id(id) => {
+                let ty_def = &self.0.types[*id];
+                return if ty_def.name.is_some() {
+                    self.type_ref_json(*id)
+                } else {
+                    self.type_def_kind_json(&ty_def.kind)
+                };
+            }
+            Type::Bool => "bool",
+            Type::U8 => "u8",
+            Type::U16 => "u16",
+            Type::U32 => "u32",
+            Type::U64 => "u64",
+            Type::S8 => "s8",
+            Type::S16 => "s16",
+            Type::S32 => "s32",
+            Type::S64 => "s64",
+            Type::Float32 => "float32",
+            Type::Float64 => "float64",
+            Type::Char => "char",
+            Type::String => "string",
+        };
+        Json::str(primitive)
+    }
+
+    fn type_option_json(&self, ty: &Option<Type>) -> Json {
+        ty.as_ref().map_or(Json::Null, |ty| self.type_json(ty))
+    }
+
+    fn type_def_kind_json(&self, kind: &TypeDefKind) -> Json {
+        let kind_entry = |name: &str| ("kind", Json::str(name));
+        let named = |name: &str, docs: &Docs, ty: Option<Json>| {
+            let mut entries = vec![("name", Json::str(name))];
+            push_docs(&mut entries, docs);
+            if let Some(ty) = ty {
+                entries.push(("type", ty));
+            }
+            Json::Object(entries)
+        };
+        let entries = match kind {
+            TypeDefKind::Record(r) => vec![
+                kind_entry("record"),
+                (
+                    "fields",
+                    Json::Array(
+                        r.fields
+                            .iter()
+                            .map(|f| named(&f.name, &f.docs, Some(self.type_json(&f.ty))))
+                            .collect(),
+                    ),
+                ),
+            ],
+            TypeDefKind::Flags(f) => vec![
+                kind_entry("flags"),
+                (
+                    "flags",
+                    Json::Array(
+                        f.flags
+                            .iter()
+                            .map(|f| named(&f.name, &f.docs, None))
+                            .collect(),
+                    ),
+                ),
+            ],
+            TypeDefKind::Enum(e) => vec![
+                kind_entry("enum"),
+                (
+                    "cases",
+                    Json::Array(
+                        e.cases
+                            .iter()
+                            .map(|c| named(&c.name, &c.docs, None))
+                            .collect(),
+                    ),
+                ),
+            ],
+            TypeDefKind::Variant(v) => vec![
+                kind_entry("variant"),
+                (
+                    "cases",
+                    Json::Array(
+                        v.cases
+                            .iter()
+                            .map(|c| named(&c.name, &c.docs, Some(self.type_option_json(&c.ty))))
+                            .collect(),
+                    ),
+                ),
+            ],
+            TypeDefKind::Union(u) => vec![
+                kind_entry("union"),
+                (
+                    "cases",
+                    Json::Array(
+                        u.cases
+                            .iter()
+                            .map(|c| Json::Object(vec![("type", self.type_json(&c.ty))]))
+                            .collect(),
+                    ),
+                ),
+            ],
+            TypeDefKind::Tuple(t) => vec![
+                kind_entry("tuple"),
+                (
+                    "types",
+                    Json::Array(t.types.iter().map(|ty| self.type_json(ty)).collect()),
+                ),
+            ],
+            TypeDefKind::Option(ty) => vec![kind_entry("option"), ("type", self.type_json(ty))],
+            TypeDefKind::Result(r) => vec![
+                kind_entry("result"),
+                ("ok", self.type_option_json(&r.ok)),
+                ("err", self.type_option_json(&r.err)),
+            ],
+            TypeDefKind::List(ty) => vec![kind_entry("list"), ("type", self.type_json(ty))],
+            TypeDefKind::Future(ty) => {
+                vec![kind_entry("future"), ("type", self.type_option_json(ty))]
+            }
+            TypeDefKind::Stream(s) => vec![
+                kind_entry("stream"),
+                ("element", self.type_option_json(&s.element)),
+                ("end", self.type_option_json(&s.end)),
+            ],
+            TypeDefKind::Type(ty) => vec![kind_entry("alias"), ("type", self.type_json(ty))],
+            TypeDefKind::Resource => vec![kind_entry("resource")],
+            TypeDefKind::Handle(Handle::Own(id)) => {
+                vec![kind_entry("own"), ("resource", self.type_ref_json(*id))]
+            }
+            TypeDefKind::Handle(Handle::Borrow(id)) => {
+                vec![kind_entry("borrow"), ("resource", self.type_ref_json(*id))]
+            }
+            TypeDefKind::Unknown => vec![kind_entry("unknown")],
+        };
+        Json::Object(entries)
+    }
+}
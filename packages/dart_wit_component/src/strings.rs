@@ -152,6 +152,7 @@ const RESERVED: &[&str] = &[
     "runtimeType",
     "props",
     "copyWith",
+    "positional",
     "builder",
     "compareTo",
     "flagBits",
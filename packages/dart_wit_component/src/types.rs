@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap};
 
 use crate::{
     function::FuncKind, generate::*, strings::Normalize, Int64TypeConfig, WitGeneratorConfig,
@@ -10,20 +10,57 @@ pub struct Parsed<'a>(
     pub HashMap<&'a str, Vec<&'a TypeDef>>,
     pub WitGeneratorConfig,
     pub HashMap<String, Vec<String>>,
+    pub RefCell<Vec<Diagnostic>>,
+    /// The worlds to generate, the ones that are not included in another world.
+    pub Vec<WorldId>,
 );
 
+/// A WIT construct that could not be generated.
+/// A placeholder with a comment is emitted in its place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The name of the WIT type, if it has one.
+    pub type_name: Option<String>,
+    pub message: String,
+}
+
 const FROM_JSON_COMMENT: &str = "/// Returns a new instance from a JSON value.
 /// May throw if the value does not have the expected structure.\n";
+const FROM_TAG_COMMENT: &str =
+    "/// Returns a new instance from the case index [tag] and its [payload].
+/// Throws if the [tag] does not correspond to a case.\n";
 const TO_JSON_COMMENT: &str = "/// Returns this as a serializable JSON value.\n";
 const TO_WASM_COMMENT: &str = "/// Returns this as a WASM canonical abi value.\n";
 const COPY_WITH_COMMENT: &str =
     "/// Returns a new instance by overriding the values passed as arguments\n";
+const WHEN_COMMENT: &str =
+    "/// Returns the result of calling the callback for the case of this value
+/// with the case's payload.\n";
+const MAP_COMMENT: &str =
+    "/// Returns the result of calling the callback for the case of this value
+/// with the case's instance.\n";
 
 enum MethodComment {
     FromJson,
+    FromTag,
     ToJson,
     ToWasm,
     CopyWith,
+    When,
+    Map,
+}
+
+/// A case of a sealed class generated for a variant or union.
+struct SealedCase {
+    /// The name of the callback parameter in `when` and `map`.
+    param: String,
+    class_name: String,
+    /// The type of the payload passed to the `when` callback.
+    /// `None` if the case does not have a payload.
+    payload: Option<String>,
+    /// Whether the payload is the `value` field of the case class,
+    /// otherwise it is the case instance itself.
+    payload_is_field: bool,
 }
 
 fn mapper_func(getter: &str, current: &str, is_required: bool) -> String {
@@ -42,6 +79,12 @@ fn mapper_func(getter: &str, current: &str, is_required: bool) -> String {
     }
 }
 
+/// Whether the result has neither an ok nor an error payload.
+/// A bare `result` is generated as a `bool` that is `true` on success.
+pub fn is_bare_result(r: &Result_) -> bool {
+    r.ok.is_none() && r.err.is_none()
+}
+
 impl Parsed<'_> {
     pub fn type_to_str(&self, ty: &Type) -> String {
         match ty {
@@ -50,13 +93,7 @@ impl Parsed<'_> {
 
                 if let (TypeDefKind::Option(ty), true) = (&ty_def.kind, self.2.use_null_for_option)
                 {
-                    let value = format!("{}?", self.type_to_str_inner(ty));
-                    // TODO: remove when this ships https://github.com/dart-lang/sdk/issues/52591
-                    if value == "()?" {
-                        "Object?".to_string()
-                    } else {
-                        value
-                    }
+                    self.nullable_to_str(ty)
                 } else {
                     self.type_def_to_name(ty_def, true)
                 }
@@ -64,7 +101,56 @@ impl Parsed<'_> {
             _ => self.type_to_str_inner(ty),
         }
     }
+
+    fn nullable_to_str(&self, ty: &Type) -> String {
+        let value = format!("{}?", self.type_to_str_inner(ty));
+        // TODO: remove when this ships https://github.com/dart-lang/sdk/issues/52591
+        if value == "()?" {
+            "Object?".to_string()
+        } else {
+            value
+        }
+    }
+
+    /// The payload of an `option` type, if [ty] is an `option`.
+    fn option_payload(&self, ty: &Type) -> Option<Type> {
+        match ty {
+            Type::Id(ty_id) => match &self.0.types.get(*ty_id).unwrap().kind {
+                TypeDefKind::Option(ty) => Some(*ty),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The type of a function parameter. `option` parameters are always
+    /// nullable, even without [WitGeneratorConfig::use_null_for_option].
+    pub fn param_to_str(&self, ty: &Type) -> String {
+        match self.option_payload(ty) {
+            Some(payload) => self.nullable_to_str(&payload),
+            None => self.type_to_str(ty),
+        }
+    }
+
+    /// Lowers a function parameter of type [ty] passed as [getter].
+    pub fn param_to_wasm(&self, getter: &str, ty: &Type) -> String {
+        match self.option_payload(ty) {
+            Some(payload) => self.nullable_to_wasm(getter, &payload),
+            None => self.type_to_wasm(getter, ty),
+        }
+    }
+
+    /// Lifts a function parameter of type [ty] from the JSON value in [getter].
+    pub fn param_from_json(&self, getter: &str, ty: &Type) -> String {
+        match (self.option_payload(ty), self.2.use_null_for_option) {
+            (Some(_), false) => format!("{}.value", self.type_from_json(getter, ty)),
+            _ => self.type_from_json(getter, ty),
+        }
+    }
     fn type_to_str_inner(&self, ty: &Type) -> String {
+        if let Some(class_name) = self.fixed_width_int(ty) {
+            return class_name.to_string();
+        }
         match ty {
             Type::Id(ty_id) => {
                 let ty_def = self.0.types.get(*ty_id).unwrap();
@@ -96,6 +182,29 @@ impl Parsed<'_> {
         }
     }
 
+    /// The name of the generated world, used for the world class.
+    /// A package without worlds is rejected before generating it.
+    pub fn world_name(&self) -> &str {
+        &self.0.worlds[self.5[0]].name
+    }
+
+    /// The range checked class for integers with a fixed width
+    /// when [WitGeneratorConfig::fixed_width_ints] is enabled.
+    fn fixed_width_int(&self, ty: &Type) -> Option<&'static str> {
+        if !self.2.fixed_width_ints {
+            return None;
+        }
+        match ty {
+            Type::S8 => Some("S8Int"),
+            Type::S16 => Some("S16Int"),
+            Type::S32 => Some("S32Int"),
+            Type::U8 => Some("U8Int"),
+            Type::U16 => Some("U16Int"),
+            Type::U32 => Some("U32Int"),
+            _ => None,
+        }
+    }
+
     fn list_typed_data(&self, ty: &Type) -> Option<String> {
         if !self.2.typed_number_lists {
             return None;
@@ -124,6 +233,9 @@ impl Parsed<'_> {
     }
 
     fn type_to_json_inner(&self, getter: &str, ty: &Type) -> String {
+        if self.fixed_width_int(ty).is_some() {
+            return format!("{getter}.value");
+        }
         match ty {
             Type::Id(ty_id) => {
                 let ty_def = self.0.types.get(*ty_id).unwrap();
@@ -183,6 +295,9 @@ impl Parsed<'_> {
                 let inner = mapper_func("some", &inner, false);
                 format!("{getter}.toJson({inner})")
             }
+            TypeDefKind::Result(r) if is_bare_result(r) => {
+                format!("({getter} ? const {{'ok': null}} : const {{'error': null}})")
+            }
             TypeDefKind::Result(r) => {
                 let map_ok = r.ok.map_or_else(
                     || "null".to_string(),
@@ -211,7 +326,11 @@ impl Parsed<'_> {
                 format!("[{list}]")
             }
             TypeDefKind::List(ty) => {
-                let inner = self.type_to_json("e", &ty);
+                let inner = if self.list_typed_data(ty).is_some() {
+                    "e".to_string()
+                } else {
+                    self.type_to_json("e", &ty)
+                };
                 if inner == "e" {
                     format!("{getter}.toList()")
                 } else {
@@ -219,10 +338,10 @@ impl Parsed<'_> {
                     format!("{getter}.map({inner}).toList()")
                 }
             }
-            TypeDefKind::Future(_ty) => unreachable!("Future"),
-            TypeDefKind::Stream(_s) => unreachable!("Stream"),
+            TypeDefKind::Future(_) | TypeDefKind::Stream(_) | TypeDefKind::Unknown => {
+                format!("{getter} {}", self.unsupported(ty))
+            }
             TypeDefKind::Type(ty) => self.type_to_json_inner(getter, &ty),
-            TypeDefKind::Unknown => unimplemented!("Unknown type"),
         }
     }
 
@@ -237,6 +356,9 @@ impl Parsed<'_> {
     }
 
     fn type_to_wasm_inner(&self, getter: &str, ty: &Type) -> String {
+        if self.fixed_width_int(ty).is_some() {
+            return format!("{getter}.value");
+        }
         match ty {
             Type::Id(ty_id) => {
                 let ty_def = self.0.types.get(*ty_id).unwrap();
@@ -261,11 +383,11 @@ impl Parsed<'_> {
     fn type_def_to_wasm(&self, getter: &str, ty: &TypeDef) -> String {
         match &ty.kind {
             TypeDefKind::Option(ty) => {
-                let mapper = self.type_to_wasm_inner("some", &ty);
-                let mapper = mapper_func("some", &mapper, false);
                 if self.2.use_null_for_option {
-                    format!("({getter} == null ? const None().toWasm() : Option.fromValue({getter}).toWasm({mapper}))")
+                    self.nullable_to_wasm(getter, ty)
                 } else {
+                    let mapper = self.type_to_wasm_inner("some", &ty);
+                    let mapper = mapper_func("some", &mapper, false);
                     format!("{getter}.toWasm({mapper})")
                 }
             }
@@ -273,6 +395,14 @@ impl Parsed<'_> {
         }
     }
 
+    /// Lowers a nullable value as an `option` with the [ty] payload,
+    /// the discriminant is `none` when [getter] is `null`.
+    fn nullable_to_wasm(&self, getter: &str, ty: &Type) -> String {
+        let mapper = self.type_to_wasm_inner("some", ty);
+        let mapper = mapper_func("some", &mapper, false);
+        format!("({getter} == null ? const None().toWasm() : Option.fromValue({getter}).toWasm({mapper}))")
+    }
+
     fn type_def_to_wasm_inner(&self, getter: &str, ty: &TypeDef) -> String {
         match &ty.kind {
             TypeDefKind::Record(_record) => format!("{getter}.toWasm()"),
@@ -296,6 +426,7 @@ impl Parsed<'_> {
                     mapper_func("some", &self.type_to_wasm_inner("some", &ty), false)
                 )
             }
+            TypeDefKind::Result(r) if is_bare_result(r) => format!("({getter} ? 0 : 1, null)"),
             TypeDefKind::Result(r) => {
                 let map_ok = r.ok.map_or_else(
                     || "null".to_string(),
@@ -324,7 +455,11 @@ impl Parsed<'_> {
                 format!("[{list}]")
             }
             TypeDefKind::List(ty) => {
-                let inner = self.type_to_wasm("e", &ty);
+                let inner = if self.list_typed_data(ty).is_some() {
+                    "e".to_string()
+                } else {
+                    self.type_to_wasm("e", &ty)
+                };
                 // In toJson() we use toList(), but in toWasm() we just use the list directly
                 // If we need to map, we use toList(growable: false).
                 if inner == "e" {
@@ -334,10 +469,10 @@ impl Parsed<'_> {
                     format!("{getter}.map({inner}).toList(growable: false)")
                 }
             }
-            TypeDefKind::Future(_ty) => unreachable!("Future"),
-            TypeDefKind::Stream(_s) => unreachable!("Stream"),
+            TypeDefKind::Future(_) | TypeDefKind::Stream(_) | TypeDefKind::Unknown => {
+                format!("{getter} {}", self.unsupported(ty))
+            }
             TypeDefKind::Type(ty) => self.type_to_wasm_inner(getter, &ty),
-            TypeDefKind::Unknown => unimplemented!("Unknown type"),
         }
     }
 
@@ -492,7 +627,7 @@ impl Parsed<'_> {
                     )
                 }
             },
-            TypeDefKind::Unknown => unimplemented!("Unknown type"),
+            TypeDefKind::Unknown => format!("Tuple([]) {}", self.unsupported(ty)),
         }
     }
 
@@ -529,6 +664,9 @@ impl Parsed<'_> {
     }
 
     fn type_from_json_inner(&self, getter: &str, ty: &Type) -> String {
+        if let Some(class_name) = self.fixed_width_int(ty) {
+            return format!("{class_name}({getter}! as int)");
+        }
         match ty {
             Type::Id(ty_id) => {
                 let ty_def = self.0.types.get(*ty_id).unwrap();
@@ -607,6 +745,9 @@ impl Parsed<'_> {
                 "Option.fromJson({getter}, (some) => {})",
                 self.type_from_json_inner("some", &ty)
             ),
+            TypeDefKind::Result(r) if is_bare_result(r) => {
+                format!("Result.fromJson({getter}, (ok) => null, (error) => null).isOk")
+            }
             TypeDefKind::Result(r) => format!(
                 "Result.fromJson({getter}, (ok) => {}, (error) => {})",
                 self.type_def_from_json_option("ok", r.ok),
@@ -648,8 +789,8 @@ impl Parsed<'_> {
             ),
             TypeDefKind::Type(ty) => self.type_from_json_inner(getter, &ty),
             TypeDefKind::Resource => format!("{}.fromJson({getter})", name.unwrap()),
-            TypeDefKind::Handle(h) => format!("{}.fromJson({getter})", self.type_def_to_name_definition(self.handle_ty(h)).unwrap()),
-            TypeDefKind::Unknown => unimplemented!("Unknown type"),
+            TypeDefKind::Handle(h) => format!("{}.fromJson({getter})", self.handle_name(h)),
+            TypeDefKind::Unknown => format!("{getter} {}", self.unsupported(ty)),
         }
     }
 
@@ -685,6 +826,15 @@ impl Parsed<'_> {
             .unwrap()
     }
 
+    /// The Dart class of a handle, borrowed handles can not be disposed.
+    fn handle_name(&self, t: &Handle) -> String {
+        let name = self.type_def_to_name_definition(self.handle_ty(t)).unwrap();
+        match t {
+            Handle::Own(_) => name,
+            Handle::Borrow(_) => format!("{name}Borrow"),
+        }
+    }
+
     fn type_def_to_name(&self, ty: &TypeDef, allow_alias: bool) -> String {
         let name = self.type_def_to_name_definition(ty);
         if allow_alias && name.is_some() {
@@ -697,7 +847,7 @@ impl Parsed<'_> {
             TypeDefKind::Flags(_flags) => name.unwrap(),
             TypeDefKind::Variant(_variant) => name.unwrap(),
             TypeDefKind::Resource => name.unwrap(),
-            TypeDefKind::Handle(t) => self.type_def_to_name_definition(self.handle_ty(t)).unwrap(),
+            TypeDefKind::Handle(t) => self.handle_name(t),
             TypeDefKind::Tuple(t) => {
                 let values = t
                     .types
@@ -711,6 +861,7 @@ impl Parsed<'_> {
                 format!("({values})")
             }
             TypeDefKind::Option(ty) => format!("Option<{}>", self.type_to_str_inner(&ty)),
+            TypeDefKind::Result(r) if is_bare_result(r) => "bool".to_string(),
             TypeDefKind::Result(r) => format!(
                 "Result<{}, {}>",
                 r.ok.map(|ty| self.type_to_str(&ty))
@@ -735,11 +886,11 @@ impl Parsed<'_> {
                     .unwrap_or("void".to_string()),
             ),
             TypeDefKind::Type(ty) => self.type_to_str_inner(&ty),
-            TypeDefKind::Unknown => unimplemented!("Unknown type"),
+            TypeDefKind::Unknown => format!("Object? {}", self.unsupported(ty)),
         }
     }
 
-    fn type_owner_name(&self, owner: TypeOwner) -> Option<String> {
+    pub fn type_owner_name(&self, owner: TypeOwner) -> Option<String> {
         match owner {
             TypeOwner::World(id) => Some(self.0.worlds.get(id).unwrap().name.clone()),
             TypeOwner::Interface(id) => self.0.interfaces.get(id).unwrap().name.clone(),
@@ -769,6 +920,29 @@ impl Parsed<'_> {
         }
     }
 
+    /// Adds a [Diagnostic] for a type that can't be generated
+    /// and returns the comment for its placeholder.
+    fn unsupported(&self, ty: &TypeDef) -> String {
+        let kind = match &ty.kind {
+            TypeDefKind::Future(_) => "future",
+            TypeDefKind::Stream(_) => "stream",
+            _ => "unknown",
+        };
+        let diagnostic = Diagnostic {
+            type_name: ty.name.clone(),
+            message: format!("Unsupported WIT type kind `{kind}`"),
+        };
+        let comment = match &diagnostic.type_name {
+            Some(name) => format!("/* {} for `{name}` */", diagnostic.message),
+            None => format!("/* {} */", diagnostic.message),
+        };
+        let mut diagnostics = self.4.borrow_mut();
+        if !diagnostics.contains(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
+        comment
+    }
+
     fn method_comment(&self, comment: MethodComment) -> &str {
         if self.2.generate_docs {
             match comment {
@@ -776,6 +950,9 @@ impl Parsed<'_> {
                 MethodComment::ToJson => TO_JSON_COMMENT,
                 MethodComment::ToWasm => TO_WASM_COMMENT,
                 MethodComment::FromJson => FROM_JSON_COMMENT,
+                MethodComment::FromTag => FROM_TAG_COMMENT,
+                MethodComment::When => WHEN_COMMENT,
+                MethodComment::Map => MAP_COMMENT,
             }
         } else {
             ""
@@ -842,6 +1019,46 @@ impl Parsed<'_> {
         }
     }
 
+    /// The `when` and `map` methods of a sealed class,
+    /// with a required callback for each case.
+    fn sealed_class_when_map(&self, cases: &[SealedCase]) -> String {
+        let mut when_params = String::new();
+        let mut when_switch = String::new();
+        let mut map_params = String::new();
+        let mut map_switch = String::new();
+        cases.iter().for_each(|c| {
+            let SealedCase {
+                param,
+                class_name,
+                payload,
+                payload_is_field,
+            } = c;
+            match payload {
+                Some(payload) => {
+                    when_params.push_str(&format!("required T Function({payload} value) {param},"));
+                    if *payload_is_field {
+                        when_switch
+                            .push_str(&format!("{class_name}(value: final v_) => {param}(v_),"));
+                    } else {
+                        when_switch.push_str(&format!("final {class_name} c_ => {param}(c_),"));
+                    }
+                }
+                None => {
+                    when_params.push_str(&format!("required T Function() {param},"));
+                    when_switch.push_str(&format!("{class_name}() => {param}(),"));
+                }
+            }
+            map_params.push_str(&format!("required T Function({class_name} value) {param},"));
+            map_switch.push_str(&format!("final {class_name} c_ => {param}(c_),"));
+        });
+        format!(
+            "{when_comment}T when<T>({{{when_params}}}) => switch (this) {{ {when_switch} }};
+            {map_comment}T map<T>({{{map_params}}}) => switch (this) {{ {map_switch} }};",
+            when_comment = self.method_comment(MethodComment::When),
+            map_comment = self.method_comment(MethodComment::Map),
+        )
+    }
+
     pub fn type_def_to_definition(&self, id_ty: &TypeId, ty: &TypeDef) -> String {
         let name = self.type_def_to_name_definition(ty);
 
@@ -869,9 +1086,21 @@ impl Parsed<'_> {
                         s.push_str(&self.type_param(&f.name, &f.ty, true));
                     });
                     s.push_str("});");
+                    if self.2.positional_record_constructor {
+                        let params = r
+                            .fields
+                            .iter()
+                            .map(|f| format!("this.{}", f.name.as_var()))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        s.push_str(&format!("const {name}.positional({params});"));
+                    }
                 }
 
                 self.add_methods_trait(&mut s, &name, r);
+                if let Some(lift_lower) = self.record_lift_lower(&name, r) {
+                    s.push_str(&lift_lower);
+                }
 
                 s.push_str(&format!(
                     "// ignore: unused_field
@@ -894,14 +1123,30 @@ impl Parsed<'_> {
                 let name = name.unwrap();
                 let implements = self.implements(&name);
                 s.push_str(&format!("enum {name} {implements}{{"));
+                // The discriminants follow the order of the cases in the WIT,
+                // independently of the Dart declaration order and [Enum.index].
                 e.cases.iter().enumerate().for_each(|(i, v)| {
                     add_docs(&mut s, &v.docs);
                     s.push_str(&format!(
-                        "{}{}",
+                        "{}({i}){}",
                         v.name.as_var(),
                         if i == e.cases.len() - 1 { ";" } else { "," }
                     ));
                 });
+                s.push_str(&format!(
+                    "/// The discriminant of the case in the canonical ABI.
+                    final int discriminant;
+                    const {name}(this.discriminant);
+                    /// The cases sorted by [discriminant].
+                    // ignore: unused_field
+                    static const _byDiscriminant = [{cases}];",
+                    cases = e
+                        .cases
+                        .iter()
+                        .map(|v| v.name.as_var())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ));
 
                 self.add_methods_trait(&mut s, &name, e);
 
@@ -995,6 +1240,24 @@ impl Parsed<'_> {
                     };
                 });
 
+                let sealed_cases = u
+                    .cases
+                    .iter()
+                    .map(|c| {
+                        let ty = self.type_to_str(&c.ty);
+                        let class_name = self.union_case_class(c, &name);
+                        let payload_is_field =
+                            !(self.2.same_class_union && self.type_class_name(&c.ty).is_some());
+                        SealedCase {
+                            param: ty.as_var(),
+                            class_name,
+                            payload: Some(ty),
+                            payload_is_field,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                s.push_str(&self.sealed_class_when_map(&sealed_cases));
+
                 s.push_str("@override Map<String, Object?> toJson();\n");
                 if self.2.same_class_union {
                     s.push_str(self.method_comment(MethodComment::ToWasm));
@@ -1031,6 +1294,7 @@ impl Parsed<'_> {
             }
             TypeDefKind::Variant(a) => {
                 let from_json_comment = self.method_comment(MethodComment::FromJson);
+                let from_tag_comment = self.method_comment(MethodComment::FromTag);
                 let name = name.unwrap();
                 let switch_value =  a.cases
                     .iter()
@@ -1046,9 +1310,25 @@ impl Parsed<'_> {
                         }
                     })
                     .collect::<String>();
+                let switch_tag = a
+                    .cases
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        let inner_name = heck::AsPascalCase(&v.name);
+                        match v.ty {
+                            Some(ty) => format!(
+                                "{i} => {name}{inner_name}({}),",
+                                self.type_from_json("payload", &ty),
+                            ),
+                            None => format!("{i} => const {name}{inner_name}(),"),
+                        }
+                    })
+                    .collect::<String>();
                 let implements = self.implements(&name);
                 s.push_str(&format!(
-                    "sealed class {name} {implements}{{ {from_json_comment}factory {name}.fromJson(Object? json_) {{
+                    "sealed class {name} {implements}{{ {from_tag_comment}factory {name}.fromTag(int tag, Object? payload) => switch (tag) {{ {switch_tag} _ => throw Exception('Invalid tag $tag for {name}'), }};
+                    {from_json_comment}factory {name}.fromJson(Object? json_) {{
                     Object? json = json_;
                     if (json is Map) {{
                         final MapEntry(:key, :value) = json.entries.firstWhere((e) => e.key != 'runtimeType');
@@ -1078,6 +1358,17 @@ impl Parsed<'_> {
                     self.add_methods_trait(&mut cases_string, &class_name,&(i, v));
                     cases_string.push_str("}");
                 });
+                let sealed_cases = a
+                    .cases
+                    .iter()
+                    .map(|v| SealedCase {
+                        param: v.name.as_var(),
+                        class_name: format!("{name}{}", heck::AsPascalCase(&v.name)),
+                        payload: v.ty.as_ref().map(|ty| self.type_to_str(ty)),
+                        payload_is_field: true,
+                    })
+                    .collect::<Vec<_>>();
+                s.push_str(&self.sealed_class_when_map(&sealed_cases));
 
                 s.push_str("@override Map<String, Object?> toJson();\n");
                 s.push_str(self.method_comment(MethodComment::ToWasm));
@@ -1140,7 +1431,11 @@ impl Parsed<'_> {
 /// Returns the bitwise XOR of the flags in this and [other].
 {name} operator ^({name} other) => {name}(flagsBits ^ other.flagsBits);
 /// Returns the flags inverted (negated).
-{name} operator ~() => {name}(~flagsBits);"
+{name} operator ~() => {name}(~flagsBits);
+/// Whether all the flags are set to `false`.
+bool get isEmpty => flagsBits.isEmpty;
+/// Whether all the flags set in [other] are also set in this.
+bool contains({name} other) => flagsBits.contains(other.flagsBits);"
                 ));
 
                 f.flags.iter().enumerate().for_each(|(i, v)| {
@@ -1161,67 +1456,96 @@ impl Parsed<'_> {
             TypeDefKind::Resource => {
                 let name = name.unwrap();
                 let implements = self.implements(&name);
-                let world_name = heck::AsPascalCase(format!(
-                    "{}World",
-                    self.0.worlds.iter().last().unwrap().1.name
-                ));
+                let world_name = heck::AsPascalCase(format!("{}World", self.world_name()));
                 let name_var = name.as_var();
 
+                // The methods receive a borrowed `self`, so they are in the
+                // borrowed handle class and an owned handle can call them.
+                let mut methods = String::new();
+                let mut statics = String::new();
+                let mut add_resource_function = |f: &Function, owner: TypeOwner| {
+                    if let Some(res) = function_resource(&f.kind) {
+                        if res == id_ty {
+                            let out = if let FunctionKind::Method(_) = f.kind {
+                                &mut methods
+                            } else {
+                                &mut statics
+                            };
+                            self.add_function(out, f, FuncKind::Resource(owner), false);
+                        }
+                    };
+                };
+                match ty.owner {
+                    TypeOwner::Interface(ii) => self.0.interfaces[ii]
+                        .functions
+                        .iter()
+                        .for_each(|(_f_name, f)| add_resource_function(f, ty.owner)),
+                    TypeOwner::World(ii) => {
+                        self.0.worlds[ii].exports.iter().for_each(|(_f_name, f)| {
+                            if let WorldItem::Function(f) = f {
+                                add_resource_function(f, ty.owner);
+                            }
+                        })
+                    }
+                    TypeOwner::None => {}
+                };
+
                 s.push_str(&format!(
-                    "class {name} {implements}{{
+                    "/// A borrowed handle to a [{name}], it is dropped by its owner
+                    /// so it can not be disposed. An owned [{name}] can be used
+                    /// wherever a borrowed handle is expected.
+                    class {name}Borrow {implements}{{
                     final int _rep;
                     final {world_name} _world;
 
-                    {name}._(this._rep, this._world) {{
-                        _world._{name_var}Finalizer.attach(this, _rep);
+                    {name}Borrow._(this._rep, this._world);
+
+                    factory {name}Borrow.fromJson(Object? json) {{
+                        return {name}Borrow._(json! as int, {world_name}.currentZoneWorld()!);
+                    }}
+
+                    @override
+                    Object? toJson() => _rep;
+
+                    int toWasm() => _rep;
+
+                    {methods}
+                    }}
+
+                    class {name} extends {name}Borrow implements WitResource {{
+                    bool _isDisposed = false;
+
+                    {name}._(int rep, {world_name} world) : super._(rep, world) {{
+                        _world._{name_var}Finalizer.attach(this, _rep, detach: this);
                     }}
 
                     factory {name}.fromJson(Object? json) {{
                         return {name}._(json! as int, {world_name}.currentZoneWorld()!);
                     }}
-                    
+
                     @override
-                    Object? toJson() => _rep;
+                    int toWasm() {{
+                        if (_isDisposed) {{
+                            throw StateError('The resource {name} was disposed.');
+                        }}
+                        return _rep;
+                    }}
 
-                    int toWasm() => _rep;"
-                ));
-                match ty.owner {
-                    TypeOwner::Interface(ii) => {
-                        self.0.interfaces[ii]
-                            .functions
-                            .iter()
-                            .for_each(|(_f_name, f)| {
-                                if let Some(res) = function_resource(&f.kind) {
-                                    if res == id_ty {
-                                        self.add_function(
-                                            &mut s,
-                                            f,
-                                            FuncKind::Resource(ty.owner),
-                                            false,
-                                        );
-                                    }
-                                };
-                            })
-                    }
-                    TypeOwner::World(ii) => {
-                        self.0.worlds[ii].exports.iter().for_each(|(_f_name, f)| {
-                            if let WorldItem::Function(f) = f {
-                                if let Some(res) = function_resource(&f.kind) {
-                                    if res == id_ty {
-                                        self.add_function(
-                                            &mut s,
-                                            f,
-                                            FuncKind::Resource(ty.owner),
-                                            false,
-                                        );
-                                    }
-                                };
-                            }
-                        })
-                    }
-                    TypeOwner::None => {}
-                };
+                    @override
+                    bool get isDisposed => _isDisposed;
+
+                    /// Drops the resource in the component, instead of waiting
+                    /// for it to be garbage collected. Calling it again does nothing.
+                    @override
+                    void dispose() {{
+                        if (_isDisposed) return;
+                        _isDisposed = true;
+                        _world._{name_var}Finalizer.detach(this);
+                        canon_resource_drop(_world.library.componentInstance, _spec, _rep);
+                    }}
 
+                    {statics}"
+                ));
                 s.push_str(&format!(
                     "static const _spec = ResourceType('{}');",
                     self.type_handle_id(ty)
@@ -1245,7 +1569,10 @@ impl Parsed<'_> {
                 }
                 s
             }
-            TypeDefKind::Unknown => todo!(),
+            TypeDefKind::Unknown => {
+                s.push_str(&self.unsupported(ty));
+                s
+            }
         }
     }
 }
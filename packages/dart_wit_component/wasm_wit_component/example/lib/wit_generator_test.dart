@@ -71,6 +71,12 @@ void witDartGeneratorTests({Future<Directory> Function()? getDirectory}) {
               typedNumberLists: true,
               asyncWorker: false,
               sameClassUnion: true,
+              fixedWidthInts: false,
+              asyncCalls: false,
+              outputMode: OutputMode.library,
+              stringEncoding: StringEncodingConfig.utf8,
+              positionalRecordConstructor: false,
+              rawResults: false,
             ),
           ),
         );
@@ -107,6 +113,12 @@ void witDartGeneratorTests({Future<Directory> Function()? getDirectory}) {
               typedNumberLists: true,
               asyncWorker: false,
               sameClassUnion: true,
+              fixedWidthInts: false,
+              asyncCalls: false,
+              outputMode: OutputMode.library,
+              stringEncoding: StringEncodingConfig.utf8,
+              positionalRecordConstructor: false,
+              rawResults: false,
             ),
           ),
         );
@@ -8,6 +8,9 @@ export 'package:wasm_wit_component/src/generator.dart';
 
 const _isWeb = identical(0, 0.0);
 
+/// The functions used by [DartWitGeneratorWorld].
+const _generatorExports = ['generate', 'generate-files', 'generate-to-file'];
+
 /// Creates a [DartWitGeneratorWorld] with the given [wasiConfig].
 /// It setsUp the dynamic library for wasm_run for native platforms and
 /// loads the dart_wit_component WASM module from the file system or
@@ -47,6 +50,15 @@ Future<DartWitGeneratorWorld> createDartWitGenerator({
     );
     module = await uris.loadModule();
   }
+  final exports = module.getExports().map((e) => e.name).toSet();
+  final missing = _generatorExports.where((e) => !exports.contains(e));
+  if (missing.isNotEmpty) {
+    throw StateError(
+      'The dart_wit_component WASM module does not export $missing,'
+      ' it is older than the Dart bindings. Rebuild it with'
+      ' `wasm_wit_component/build.sh`.',
+    );
+  }
   final builder = module.builder(
     wasiConfig: wasiConfig,
   );
@@ -74,6 +86,11 @@ WitGeneratorConfig defaultGeneratorConfig({
     typedNumberLists: true,
     asyncWorker: false,
     sameClassUnion: true,
+    fixedWidthInts: false,
+    asyncCalls: false,
+    stringEncoding: StringEncodingConfig.utf8,
+    positionalRecordConstructor: false,
+    rawResults: false,
   );
 }
 
@@ -100,7 +117,7 @@ WasiConfig wasiConfigFromPath(
     allowedPath = allowedDir.uri;
   }
   return WasiConfig(
-    inheritEnv: true,
+    process: const WasiProcessConfig(env: EnvMode.inherit()),
     preopenedDirs: [
       PreopenedDir(
         hostPath:
@@ -69,6 +69,10 @@ class CanonicalOptions {
   /// matching the callee's return type and empty results.
   final void Function(List<FlatValue> flat_results)? post_return;
 
+  /// "free" export, releases memory allocated with [realloc].
+  /// Used to free the arguments of a call when they could not be lowered.
+  final void Function(int ptr, int size, int alignment)? free;
+
   ///
   CanonicalOptions(
     this._updateMemoryView,
@@ -76,8 +80,9 @@ class CanonicalOptions {
     this.getByteData,
     this.string_encoding,
     this.realloc,
-    this.post_return,
-  );
+    this.post_return, {
+    this.free,
+  });
 }
 
 // #
@@ -295,6 +300,10 @@ class HandleTables {
 /// caller of [canon_lift] must call post_return right after lowering result.
 /// This ensures that [CanonicalOptions.post_return] can be used to perform
 /// cleanup actions after the lowering is complete.
+///
+/// If lowering the [args] throws, the guest memory already allocated for them
+/// is released with [CanonicalOptions.free]. If lifting the results throws,
+/// [CanonicalOptions.post_return] is called before rethrowing.
 (ListValue, void Function()) canon_lift(
   CanonicalOptions opts,
   ComponentInstance inst,
@@ -308,14 +317,24 @@ class HandleTables {
 
   assert(inst.may_leave);
   inst.may_leave = false;
-  final flat_args = lower_values(
-    cx,
-    MAX_FLAT_PARAMS,
-    args,
-    computedFt?.parameters ?? ft.param_types(),
-    computedTypes: computedFt?.parametersData,
-  );
-  inst.may_leave = true;
+  final List<FlatValue> flat_args;
+  try {
+    flat_args = lower_values(
+      cx,
+      MAX_FLAT_PARAMS,
+      args,
+      computedFt?.parameters ?? ft.param_types(),
+      computedTypes: computedFt?.parametersData,
+    );
+  } catch (_) {
+    // The callee never receives the pointers, release them
+    cx.free_allocations();
+    rethrow;
+  } finally {
+    inst.may_leave = true;
+  }
+  // The memory of the arguments is owned by the callee from now on
+  cx.allocations.clear();
 
   final List<FlatValue> flat_results;
   try {
@@ -325,13 +344,21 @@ class HandleTables {
   }
 
   opts._updateMemoryView();
-  final results = lift_values(
-    cx,
-    MAX_FLAT_RESULTS,
-    ValueIter(flat_results),
-    computedFt?.results ?? ft.result_types(),
-    computedTypes: computedFt?.resultsData,
-  );
+  final ListValue results;
+  try {
+    results = lift_values(
+      cx,
+      MAX_FLAT_RESULTS,
+      ValueIter(flat_results),
+      computedFt?.results ?? ft.result_types(),
+      computedTypes: computedFt?.resultsData,
+    );
+  } catch (_) {
+    // The caller will not receive the post return function,
+    // release the memory of the results
+    opts.post_return?.call(flat_results);
+    rethrow;
+  }
 
   void post_return() {
     opts.post_return?.call(flat_results);
@@ -359,14 +386,24 @@ Future<(ListValue, void Function())> canon_lift_async(
 
   assert(inst.may_leave);
   inst.may_leave = false;
-  final flat_args = lower_values(
-    cx,
-    MAX_FLAT_PARAMS,
-    args,
-    computedFt?.parameters ?? ft.param_types(),
-    computedTypes: computedFt?.parametersData,
-  );
-  inst.may_leave = true;
+  final List<FlatValue> flat_args;
+  try {
+    flat_args = lower_values(
+      cx,
+      MAX_FLAT_PARAMS,
+      args,
+      computedFt?.parameters ?? ft.param_types(),
+      computedTypes: computedFt?.parametersData,
+    );
+  } catch (_) {
+    // The callee never receives the pointers, release them
+    cx.free_allocations();
+    rethrow;
+  } finally {
+    inst.may_leave = true;
+  }
+  // The memory of the arguments is owned by the callee from now on
+  cx.allocations.clear();
 
   final completer = Completer<void>();
   inst._asyncCompleter = completer;
@@ -381,13 +418,21 @@ Future<(ListValue, void Function())> canon_lift_async(
   }
 
   opts._updateMemoryView();
-  final results = lift_values(
-    cx,
-    MAX_FLAT_RESULTS,
-    ValueIter(flat_results),
-    computedFt?.results ?? ft.result_types(),
-    computedTypes: computedFt?.resultsData,
-  );
+  final ListValue results;
+  try {
+    results = lift_values(
+      cx,
+      MAX_FLAT_RESULTS,
+      ValueIter(flat_results),
+      computedFt?.results ?? ft.result_types(),
+      computedTypes: computedFt?.resultsData,
+    );
+  } catch (_) {
+    // The caller will not receive the post return function,
+    // release the memory of the results
+    opts.post_return?.call(flat_results);
+    rethrow;
+  }
 
   void post_return() {
     opts.post_return?.call(flat_results);
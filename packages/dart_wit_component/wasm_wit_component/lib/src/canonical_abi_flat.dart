@@ -515,7 +515,7 @@ List<FlatValue> lower_values(
     final tuple_alignment = tuple_type.alignment();
     final int ptr;
     if (out_param == null) {
-      ptr = cx.opts.realloc(0, 0, tuple_alignment, tuple_size);
+      ptr = cx.realloc(0, 0, tuple_alignment, tuple_size);
     } else {
       ptr = out_param.nextInt32();
     }
@@ -521,7 +521,7 @@ PointerAndSize store_list_into_range(
 
   final byte_length = v.length * size_elem;
   trap_if(byte_length >= unpresentableU32);
-  final ptr = cx.opts.realloc(0, 0, alignment_elem, byte_length);
+  final ptr = cx.realloc(0, 0, alignment_elem, byte_length);
   trap_if(ptr != align_to(ptr, alignment_elem));
   trap_if(ptr + byte_length > cx.opts.memory.length);
   switch ((elem_type, v, Endian.host)) {
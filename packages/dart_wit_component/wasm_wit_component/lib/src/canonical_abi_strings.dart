@@ -110,7 +110,7 @@ PointerAndSize _store_string_copy(
 ) {
   final dst_byte_length = dst_code_unit_size * src_code_units;
   trap_if(dst_byte_length > MAX_STRING_BYTE_LENGTH);
-  final ptr = cx.opts.realloc(0, 0, dst_alignment, dst_byte_length);
+  final ptr = cx.realloc(0, 0, dst_alignment, dst_byte_length);
   trap_if(ptr != align_to(ptr, dst_alignment));
   trap_if(ptr + dst_byte_length > cx.opts.memory.length);
   final encoded = dst_encoding.encode(src);
@@ -139,7 +139,7 @@ PointerAndSize _store_latin1_to_utf8(
 PointerAndSize _store_string_to_utf8(
     Context cx, String src, int src_code_units, int worst_case_size) {
   assert(src_code_units <= MAX_STRING_BYTE_LENGTH);
-  int ptr = cx.opts.realloc(0, 0, 1, src_code_units);
+  int ptr = cx.realloc(0, 0, 1, src_code_units);
   trap_if(ptr + src_code_units > cx.opts.memory.length);
   final encoded = StringEncoding.utf8.encode(src);
   final lenEncoded = encoded.length;
@@ -147,7 +147,7 @@ PointerAndSize _store_string_to_utf8(
   cx.opts.memory.setRange(ptr, ptr + src_code_units, encoded);
   if (src_code_units < lenEncoded) {
     trap_if(worst_case_size > MAX_STRING_BYTE_LENGTH);
-    ptr = cx.opts.realloc(ptr, src_code_units, 1, worst_case_size);
+    ptr = cx.realloc(ptr, src_code_units, 1, worst_case_size);
     trap_if(ptr + worst_case_size > (cx.opts.memory.length));
     cx.opts.memory.setRange(
       ptr + src_code_units,
@@ -156,7 +156,7 @@ PointerAndSize _store_string_to_utf8(
       /* skipCount */ src_code_units,
     );
     if (worst_case_size > lenEncoded) {
-      ptr = cx.opts.realloc(ptr, worst_case_size, 1, lenEncoded);
+      ptr = cx.realloc(ptr, worst_case_size, 1, lenEncoded);
       trap_if(ptr + lenEncoded > cx.opts.memory.length);
     }
   }
@@ -173,14 +173,14 @@ PointerAndSize _store_utf8_to_utf16(
     Context cx, String src, int src_code_units) {
   final worst_case_size = 2 * src_code_units;
   trap_if(worst_case_size > MAX_STRING_BYTE_LENGTH);
-  int ptr = cx.opts.realloc(0, 0, 2, worst_case_size);
+  int ptr = cx.realloc(0, 0, 2, worst_case_size);
   trap_if(ptr != align_to(ptr, 2));
   trap_if(ptr + worst_case_size > cx.opts.memory.length);
   final encoded = StringEncoding.utf16.encode(src);
   final lenEncoded = encoded.length;
   cx.opts.memory.setAll(ptr, encoded);
   if (encoded.length < worst_case_size) {
-    ptr = cx.opts.realloc(ptr, worst_case_size, 2, lenEncoded);
+    ptr = cx.realloc(ptr, worst_case_size, 2, lenEncoded);
     trap_if(ptr != align_to(ptr, 2));
     trap_if(ptr + lenEncoded > cx.opts.memory.length);
   }
@@ -201,7 +201,7 @@ PointerAndSize _store_utf8_to_utf16(
 PointerAndSize _store_string_to_latin1_or_utf16(
     Context cx, String src, int src_code_units) {
   assert(src_code_units <= MAX_STRING_BYTE_LENGTH);
-  int ptr = cx.opts.realloc(0, 0, 2, src_code_units);
+  int ptr = cx.realloc(0, 0, 2, src_code_units);
   trap_if(ptr != align_to(ptr, 2));
   trap_if(ptr + src_code_units > (cx.opts.memory.length));
   int dst_byte_length = 0;
@@ -212,7 +212,7 @@ PointerAndSize _store_string_to_latin1_or_utf16(
     } else {
       final worst_case_size = 2 * src_code_units;
       trap_if(worst_case_size > MAX_STRING_BYTE_LENGTH);
-      ptr = cx.opts.realloc(ptr, src_code_units, 2, worst_case_size);
+      ptr = cx.realloc(ptr, src_code_units, 2, worst_case_size);
       trap_if(ptr != align_to(ptr, 2));
       trap_if(ptr + worst_case_size > (cx.opts.memory.length));
       for (int j = dst_byte_length - 1; j >= 0; j--) {
@@ -224,7 +224,7 @@ PointerAndSize _store_string_to_latin1_or_utf16(
       cx.opts.memory.setRange(ptr + 2 * dst_byte_length, ptr + lenEncoded,
           encoded, 2 * dst_byte_length);
       if (worst_case_size > lenEncoded) {
-        ptr = cx.opts.realloc(ptr, worst_case_size, 2, lenEncoded);
+        ptr = cx.realloc(ptr, worst_case_size, 2, lenEncoded);
         trap_if(ptr != align_to(ptr, 2));
         trap_if(ptr + lenEncoded > (cx.opts.memory.length));
       }
@@ -233,7 +233,7 @@ PointerAndSize _store_string_to_latin1_or_utf16(
     }
   }
   if (dst_byte_length < src_code_units) {
-    ptr = cx.opts.realloc(ptr, src_code_units, 2, dst_byte_length);
+    ptr = cx.realloc(ptr, src_code_units, 2, dst_byte_length);
     trap_if(ptr != align_to(ptr, 2));
     trap_if(ptr + dst_byte_length > (cx.opts.memory.length));
   }
@@ -256,7 +256,7 @@ PointerAndSize _store_probably_utf16_to_latin1_or_utf16(
     Context cx, String src, int src_code_units) {
   final src_byte_length = 2 * src_code_units;
   trap_if(src_byte_length > MAX_STRING_BYTE_LENGTH);
-  int ptr = cx.opts.realloc(0, 0, 2, src_byte_length);
+  int ptr = cx.realloc(0, 0, 2, src_byte_length);
   trap_if(ptr != align_to(ptr, 2));
   trap_if(ptr + src_byte_length > (cx.opts.memory.length));
   final encoded = StringEncoding.utf16.encode(src);
@@ -270,7 +270,7 @@ PointerAndSize _store_probably_utf16_to_latin1_or_utf16(
   for (final i in Iterable<int>.generate(latin1_size)) {
     cx.opts.memory[ptr + i] = cx.opts.memory[ptr + 2 * i];
   }
-  ptr = cx.opts.realloc(ptr, src_byte_length, 1, latin1_size);
+  ptr = cx.realloc(ptr, src_byte_length, 1, latin1_size);
   trap_if(ptr + latin1_size > (cx.opts.memory.length));
   return (ptr, latin1_size);
 }
@@ -38,8 +38,36 @@ class Context {
   final List<Handle> lenders = [];
   int borrow_count = 0;
 
+  /// The guest memory allocated with [realloc] while lowering values,
+  /// from pointer to (size, alignment).
+  final Map<int, (int, int)> allocations = {};
+
   Context(this.opts, this.inst);
 
+  /// Calls [CanonicalOptions.realloc] and records the allocation
+  /// so that it can be released with [free_allocations].
+  int realloc(int ptr, int size_initial, int alignment, int size_final) {
+    final new_ptr = opts.realloc(ptr, size_initial, alignment, size_final);
+    if (ptr != 0) allocations.remove(ptr);
+    allocations[new_ptr] = (size_final, alignment);
+    return new_ptr;
+  }
+
+  /// Releases the guest memory recorded in [allocations] with
+  /// [CanonicalOptions.free]. Used when lowering the arguments of a call fails,
+  /// since the callee never receives the pointers. Does nothing if the guest
+  /// does not export a free function.
+  void free_allocations() {
+    final free = opts.free;
+    if (free != null) {
+      for (final MapEntry(key: ptr, value: (size, alignment))
+          in allocations.entries) {
+        free(ptr, size, alignment);
+      }
+    }
+    allocations.clear();
+  }
+
   void track_owning_lend(Handle lending_handle) {
     assert(lending_handle.own);
     lending_handle.lend_count += 1;
@@ -11,6 +11,7 @@ import 'package:wasm_wit_component/src/canonical_abi_cache.dart';
 import 'package:wasm_wit_component/src/record_equality.dart' show recordToList;
 
 export 'package:wasm_wit_component/generator.dart' show Int64TypeConfig;
+export 'package:wasm_wit_component/src/fixed_width_int.dart';
 export 'package:wasm_wit_component/src/flags_bits.dart' show FlagsBits;
 export 'package:wasm_wit_component/src/models.dart';
 
@@ -190,6 +191,23 @@ WasmFunction loweredImportFunction(
   return lowered;
 }
 
+/// A handle to a resource of a component that can be dropped with [dispose]
+/// before it is garbage collected. Implemented by the generated resource
+/// classes, see the generated `withResource` method of the world to dispose
+/// a resource at the end of a scope.
+///
+/// Only owned handles implement it, the generated `<Resource>Borrow`
+/// classes of borrowed handles are dropped by their owner.
+abstract interface class WitResource {
+  /// Whether the resource was dropped with [dispose].
+  bool get isDisposed;
+
+  /// Drops the resource in the component. Calling it again does nothing.
+  /// Passing the resource to a function of the component after it is disposed
+  /// throws a [StateError].
+  void dispose();
+}
+
 List<WasmImport> resourceImports(
   WasmLibrary Function() getWasmLibrary,
   ResourceType rt,
@@ -318,10 +336,22 @@ class WasmLibrary {
       stringEncoding,
       realloc,
       postReturn,
+      free: _free,
     );
     return options;
   }
 
+  /// Calls the guest "free" export, if any, which may receive
+  /// the pointer or the pointer and the size.
+  late final void Function(int ptr, int size, int alignment)? _free = () {
+    final freeFunc = instance.getFunction('free');
+    if (freeFunc == null) return null;
+    final withSize = freeFunc.params.length == 2;
+    return (int ptr, int size, int alignment) {
+      freeFunc.call([ptr, if (withSize) size]);
+    };
+  }();
+
   void Function(List<FlatValue>)? postReturnFunction(String functionName) {
     final postFunc = instance.getFunction('cabi_post_$functionName');
     if (postFunc == null) return null;
@@ -404,4 +434,41 @@ class WasmLibrary {
       return funcLift;
     };
   }
+
+  /// Returns a Function that can be used to call the component function
+  /// with the given [name] and [ft] ([FuncType]).
+  /// The core Wasm function is executed with [WasmFunction.callAsync],
+  /// in native platforms it runs in a separate thread without blocking
+  /// the Dart isolate. Imported host functions can not be called within it.
+  Future<ListValue> Function(ListValue args)? getComponentFunctionAsync(
+    String name,
+    FuncType ft,
+  ) {
+    final func = instance.getFunction(name);
+    if (func == null) return null;
+    final computedFt = ComputedTypeData.cacheFunction(ft);
+    final flattenedFt = computedFt.liftCoreType;
+    final postFunc = postReturnFunction(name);
+    Future<List<FlatValue>> coreFunc(List<FlatValue> p) async {
+      final args = _mapValuesToFlat(componentInstance.int64Type, p);
+      final results = await func.callAsync(args);
+      if (results.isEmpty) return const [];
+      return _mapFlatToValues(results, flattenedFt.results);
+    }
+
+    final options = _functionOptions(postFunc);
+    return (ListValue args) async {
+      final (funcLift, post) = await canon_lift_async(
+        options,
+        componentInstance,
+        coreFunc,
+        ft,
+        args,
+        computedFt: computedFt,
+      );
+      post();
+
+      return funcLift;
+    };
+  }
 }
@@ -0,0 +1,73 @@
+/// An integer with a fixed bit width from a WIT type.
+///
+/// The value is validated when the instance is created, a [RangeError]
+/// is thrown if it can not be represented with the bit width.
+/// 64 bit integers are configured with the `int64-type` generator option.
+sealed class FixedWidthInt {
+  /// The integer value.
+  final int value;
+
+  const FixedWidthInt._(this.value);
+
+  static int _checked(int value, int minValue, int maxValue, String name) {
+    RangeError.checkValueInInterval(value, minValue, maxValue, name);
+    return value;
+  }
+
+  /// Returns [value] as a serializable JSON value.
+  int toJson() => value;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FixedWidthInt &&
+          other.runtimeType == runtimeType &&
+          other.value == value;
+
+  @override
+  int get hashCode => Object.hash(runtimeType, value);
+
+  @override
+  String toString() => '$runtimeType($value)';
+}
+
+/// A WIT `s8`, a signed 8 bit integer.
+class S8Int extends FixedWidthInt {
+  /// Throws a [RangeError] if [value] is not in the `s8` range.
+  S8Int(int value) : super._(FixedWidthInt._checked(value, -0x80, 0x7F, 's8'));
+}
+
+/// A WIT `s16`, a signed 16 bit integer.
+class S16Int extends FixedWidthInt {
+  /// Throws a [RangeError] if [value] is not in the `s16` range.
+  S16Int(int value)
+      : super._(FixedWidthInt._checked(value, -0x8000, 0x7FFF, 's16'));
+}
+
+/// A WIT `s32`, a signed 32 bit integer.
+class S32Int extends FixedWidthInt {
+  /// Throws a [RangeError] if [value] is not in the `s32` range.
+  S32Int(int value)
+      : super._(
+          FixedWidthInt._checked(value, -0x80000000, 0x7FFFFFFF, 's32'),
+        );
+}
+
+/// A WIT `u8`, an unsigned 8 bit integer.
+class U8Int extends FixedWidthInt {
+  /// Throws a [RangeError] if [value] is not in the `u8` range.
+  U8Int(int value) : super._(FixedWidthInt._checked(value, 0, 0xFF, 'u8'));
+}
+
+/// A WIT `u16`, an unsigned 16 bit integer.
+class U16Int extends FixedWidthInt {
+  /// Throws a [RangeError] if [value] is not in the `u16` range.
+  U16Int(int value) : super._(FixedWidthInt._checked(value, 0, 0xFFFF, 'u16'));
+}
+
+/// A WIT `u32`, an unsigned 32 bit integer.
+class U32Int extends FixedWidthInt {
+  /// Throws a [RangeError] if [value] is not in the `u32` range.
+  U32Int(int value)
+      : super._(FixedWidthInt._checked(value, 0, 0xFFFFFFFF, 'u32'));
+}
@@ -106,6 +106,13 @@ class FlagsBits implements ToJsonSerializable {
   /// Sets the flag at [i] to [enable].
   void operator []=(int i, bool enable) => setFlag(i, enable);
 
+  /// Whether all the flags are set to `false`.
+  bool get isEmpty =>
+      Iterable.generate(data.lengthInBytes ~/ 4, _index).every((v) => v == 0);
+
+  /// Whether all the flags set in [other] are also set in this.
+  bool contains(FlagsBits other) => (this & other) == other;
+
   /// Bitwise and
   FlagsBits operator &(FlagsBits other) => _merge(other, (a, b) => a & b);
 
@@ -132,6 +139,7 @@ class FlagsBits implements ToJsonSerializable {
   }
 
   void _zeroOutUnusedBits() {
+    if (numFlags % 32 == 0) return;
     final lastOffset = data.lengthInBytes - 4;
     // zero-out unused last 32 bits
     final mask = 0xFFFFFFFF >> (32 - numFlags % 32);
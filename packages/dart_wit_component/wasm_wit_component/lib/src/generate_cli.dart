@@ -123,9 +123,23 @@ class GeneratorCLIArgs {
       typedNumberLists: args.namedBool[_Arg.typedNumberLists] ?? true,
       asyncWorker: args.namedBool[_Arg.asyncWorker] ?? false,
       sameClassUnion: args.namedBool[_Arg.sameClassUnion] ?? true,
+      fixedWidthInts: args.namedBool[_Arg.fixedWidthInts] ?? false,
+      asyncCalls: args.namedBool[_Arg.asyncCalls] ?? false,
       objectComparator: args.singleArgValue(_Arg.objectComparator),
       int64Type: args.singleArgEnum(_Arg.int64Type, Int64TypeConfig.values) ??
           Int64TypeConfig.bigInt,
+      outputMode: args.singleArgEnum(_Arg.outputMode, OutputMode.values) ??
+          OutputMode.library,
+      libraryName: args.singleArgValue(_Arg.libraryName),
+      stringEncoding: args.singleArgEnum(
+            _Arg.stringEncoding,
+            StringEncodingConfig.values,
+          ) ??
+          StringEncodingConfig.utf8,
+      positionalRecordConstructor:
+          args.namedBool[_Arg.positionalRecordConstructor] ?? false,
+      rawResults: args.namedBool[_Arg.rawResults] ?? false,
+      enabledFeatures: args.namedValues[_Arg.enabledFeatures],
     );
 
     return GeneratorCLIArgs(
@@ -172,11 +186,19 @@ class _Arg {
   static const typedNumberLists = 'typed-number-lists';
   static const asyncWorker = 'async-worker';
   static const sameClassUnion = 'same-union-class';
+  static const fixedWidthInts = 'fixed-width-ints';
+  static const asyncCalls = 'async-calls';
+  static const positionalRecordConstructor = 'positional-record-constructor';
+  static const rawResults = 'raw-results';
   static const watch = 'watch';
 
   static const fileHeader = 'file-header';
   static const objectComparator = 'object-comparator';
   static const int64Type = 'int64-type';
+  static const outputMode = 'output-mode';
+  static const libraryName = 'library-name';
+  static const stringEncoding = 'string-encoding';
+  static const enabledFeatures = 'enabled-features';
   static const configFile = 'config-file';
 
   static const allBool = [
@@ -191,11 +213,19 @@ class _Arg {
     requiredOption,
     asyncWorker,
     sameClassUnion,
+    fixedWidthInts,
+    asyncCalls,
+    positionalRecordConstructor,
+    rawResults,
     watch,
   ];
 
   static const allValues = [
     int64Type,
+    outputMode,
+    libraryName,
+    stringEncoding,
+    enabledFeatures,
     fileHeader,
     objectComparator,
     configFile,
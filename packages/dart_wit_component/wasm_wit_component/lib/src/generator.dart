@@ -117,6 +117,65 @@ enum Int64TypeConfig implements ToJsonSerializable {
       ['native-object', 'big-int', 'big-int-unsigned-only', 'core-int']);
 }
 
+/// The encoding of the strings passed to and returned from the component.
+enum StringEncodingConfig implements ToJsonSerializable {
+  /// UTF-8, the default for components.
+  utf8,
+
+  /// UTF-16 little endian.
+  utf16,
+
+  /// Latin-1 when all the code points fit in a byte, UTF-16 otherwise.
+  latin1Utf16;
+
+  /// Returns a new instance from a JSON value.
+  /// May throw if the value does not have the expected structure.
+  factory StringEncodingConfig.fromJson(Object? json) {
+    return ToJsonSerializable.enumFromJson(json, values, _spec);
+  }
+  @override
+  Map<String, Object?> toJson() =>
+      {'runtimeType': 'StringEncodingConfig', _spec.labels[index]: null};
+
+  /// Returns this as a WASM canonical abi value.
+  int toWasm() => index;
+  static const _spec = EnumType(['utf8', 'utf16', 'latin1-utf16']);
+}
+
+/// How the generated code is included in the Dart library that uses it.
+enum OutputMode implements ToJsonSerializable {
+  /// A standalone library with the header and the imports it requires.
+  /// A `library` directive is emitted when [libraryName] is set,
+  /// for example, `library my_package.bindings;` for `my_package.bindings`.
+  library,
+
+  /// A part of an existing library, the library should contain the `part`
+  /// directive for the generated file and the imports it requires,
+  /// which are listed in a comment.
+  /// A [libraryName] ending with `.dart` is used as uri, for example,
+  /// `part of 'bindings.dart';` for `bindings.dart`, otherwise as library name,
+  /// for example, `part of my_package.bindings;` for `my_package.bindings`.
+  partOf,
+
+  /// Only the declarations, without the header, directives or imports,
+  /// to be pasted in an existing Dart file that imports the required libraries.
+  /// For example, the classes of the world's types and functions.
+  declarations;
+
+  /// Returns a new instance from a JSON value.
+  /// May throw if the value does not have the expected structure.
+  factory OutputMode.fromJson(Object? json) {
+    return ToJsonSerializable.enumFromJson(json, values, _spec);
+  }
+  @override
+  Map<String, Object?> toJson() =>
+      {'runtimeType': 'OutputMode', _spec.labels[index]: null};
+
+  /// Returns this as a WASM canonical abi value.
+  int toWasm() => index;
+  static const _spec = EnumType(['library', 'part-of', 'declarations']);
+}
+
 class WitFile implements ToJsonSerializable {
   /// The file path.
   /// The file name will be used as the name of the generated world.
@@ -330,6 +389,45 @@ class WitGeneratorConfig implements ToJsonSerializable {
   /// Whether to use extends for union variants instead of crating a separate
   /// wrapper class for each option.
   final bool sameClassUnion;
+
+  /// Whether to use range checked classes, such as `U8Int`, for the integer
+  /// types with a fixed width up to 32 bits instead of Dart's `int`.
+  /// Numeric lists are not affected when using [typedNumberLists].
+  final bool fixedWidthInts;
+
+  /// Whether the exported functions return a `Future` and execute the calls
+  /// in a separate thread, without blocking the Dart isolate.
+  /// Imported functions are executed in the Dart isolate, calling them
+  /// within an exported function throws. In the web, the calls are
+  /// executed in the main thread.
+  final bool asyncCalls;
+
+  /// How the generated code is included in the Dart library that uses it.
+  /// Defaults to [OutputMode.library].
+  final OutputMode? outputMode;
+
+  /// The library name or uri used by [outputMode].
+  /// Optional for `library` and required for `part-of`.
+  final String? libraryName;
+
+  /// The string encoding of the component's canonical options.
+  /// Should match the `string-encoding` used when the component was built.
+  final StringEncodingConfig stringEncoding;
+
+  /// Whether to generate a constructor with positional parameters,
+  /// `Name.positional`, for records in addition to the one with named parameters.
+  final bool positionalRecordConstructor;
+
+  /// Whether the exported functions returning a `result` return the sealed
+  /// `Result`. By default, they return the success value and throw the error,
+  /// wrapped in a generated `<ErrorType>Exception` for named error types
+  /// or in a `ResultException` for the others.
+  final bool rawResults;
+
+  /// The features of the `@unstable` feature gates to generate.
+  /// The items gated by other features are not generated and the
+  /// generated unstable items are annotated with `@Deprecated`.
+  final List<String>? enabledFeatures;
   const WitGeneratorConfig({
     required this.inputs,
     required this.jsonSerialization,
@@ -345,6 +443,14 @@ class WitGeneratorConfig implements ToJsonSerializable {
     required this.typedNumberLists,
     required this.asyncWorker,
     required this.sameClassUnion,
+    required this.fixedWidthInts,
+    required this.asyncCalls,
+    this.outputMode,
+    this.libraryName,
+    required this.stringEncoding,
+    required this.positionalRecordConstructor,
+    required this.rawResults,
+    this.enabledFeatures,
   });
 
   /// Returns a new instance from a JSON value.
@@ -368,7 +474,15 @@ class WitGeneratorConfig implements ToJsonSerializable {
         final int64Type,
         final typedNumberLists,
         final asyncWorker,
-        final sameClassUnion
+        final sameClassUnion,
+        final fixedWidthInts,
+        final asyncCalls,
+        final outputMode,
+        final libraryName,
+        final stringEncoding,
+        final positionalRecordConstructor,
+        final rawResults,
+        final enabledFeatures
       ] ||
       (
         final inputs,
@@ -384,7 +498,15 @@ class WitGeneratorConfig implements ToJsonSerializable {
         final int64Type,
         final typedNumberLists,
         final asyncWorker,
-        final sameClassUnion
+        final sameClassUnion,
+        final fixedWidthInts,
+        final asyncCalls,
+        final outputMode,
+        final libraryName,
+        final stringEncoding,
+        final positionalRecordConstructor,
+        final rawResults,
+        final enabledFeatures
       ) =>
         WitGeneratorConfig(
           inputs: WitGeneratorInput.fromJson(inputs),
@@ -407,6 +529,22 @@ class WitGeneratorConfig implements ToJsonSerializable {
           typedNumberLists: typedNumberLists! as bool,
           asyncWorker: asyncWorker! as bool,
           sameClassUnion: sameClassUnion! as bool,
+          fixedWidthInts: fixedWidthInts! as bool,
+          asyncCalls: asyncCalls! as bool,
+          outputMode: Option.fromJson(
+              outputMode, (some) => OutputMode.fromJson(some)).value,
+          libraryName: Option.fromJson(
+              libraryName,
+              (some) =>
+                  some is String ? some : (some! as ParsedString).value).value,
+          stringEncoding: StringEncodingConfig.fromJson(stringEncoding),
+          positionalRecordConstructor: positionalRecordConstructor! as bool,
+          rawResults: rawResults! as bool,
+          enabledFeatures: Option.fromJson(
+              enabledFeatures,
+              (some) => (some! as Iterable)
+                  .map((e) => e is String ? e : (e! as ParsedString).value)
+                  .toList()).value,
         ),
       _ => throw Exception('Invalid JSON $json_')
     };
@@ -432,6 +570,20 @@ class WitGeneratorConfig implements ToJsonSerializable {
         'typed-number-lists': typedNumberLists,
         'async-worker': asyncWorker,
         'same-class-union': sameClassUnion,
+        'fixed-width-ints': fixedWidthInts,
+        'async-calls': asyncCalls,
+        'output-mode': (outputMode == null
+            ? const None().toJson()
+            : Option.fromValue(outputMode).toJson((some) => some.toJson())),
+        'library-name': (libraryName == null
+            ? const None().toJson()
+            : Option.fromValue(libraryName).toJson()),
+        'string-encoding': stringEncoding.toJson(),
+        'positional-record-constructor': positionalRecordConstructor,
+        'raw-results': rawResults,
+        'enabled-features': (enabledFeatures == null
+            ? const None().toJson()
+            : Option.fromValue(enabledFeatures).toJson()),
       };
 
   /// Returns this as a WASM canonical abi value.
@@ -453,7 +605,21 @@ class WitGeneratorConfig implements ToJsonSerializable {
         int64Type.toWasm(),
         typedNumberLists,
         asyncWorker,
-        sameClassUnion
+        sameClassUnion,
+        fixedWidthInts,
+        asyncCalls,
+        (outputMode == null
+            ? const None().toWasm()
+            : Option.fromValue(outputMode).toWasm((some) => some.toWasm())),
+        (libraryName == null
+            ? const None().toWasm()
+            : Option.fromValue(libraryName).toWasm()),
+        stringEncoding.toWasm(),
+        positionalRecordConstructor,
+        rawResults,
+        (enabledFeatures == null
+            ? const None().toWasm()
+            : Option.fromValue(enabledFeatures).toWasm())
       ];
   @override
   String toString() =>
@@ -475,6 +641,14 @@ class WitGeneratorConfig implements ToJsonSerializable {
     bool? typedNumberLists,
     bool? asyncWorker,
     bool? sameClassUnion,
+    bool? fixedWidthInts,
+    bool? asyncCalls,
+    Option<OutputMode>? outputMode,
+    Option<String>? libraryName,
+    StringEncodingConfig? stringEncoding,
+    bool? positionalRecordConstructor,
+    bool? rawResults,
+    Option<List<String>>? enabledFeatures,
   }) =>
       WitGeneratorConfig(
           inputs: inputs ?? this.inputs,
@@ -492,7 +666,19 @@ class WitGeneratorConfig implements ToJsonSerializable {
           int64Type: int64Type ?? this.int64Type,
           typedNumberLists: typedNumberLists ?? this.typedNumberLists,
           asyncWorker: asyncWorker ?? this.asyncWorker,
-          sameClassUnion: sameClassUnion ?? this.sameClassUnion);
+          sameClassUnion: sameClassUnion ?? this.sameClassUnion,
+          fixedWidthInts: fixedWidthInts ?? this.fixedWidthInts,
+          asyncCalls: asyncCalls ?? this.asyncCalls,
+          outputMode: outputMode != null ? outputMode.value : this.outputMode,
+          libraryName:
+              libraryName != null ? libraryName.value : this.libraryName,
+          stringEncoding: stringEncoding ?? this.stringEncoding,
+          positionalRecordConstructor:
+              positionalRecordConstructor ?? this.positionalRecordConstructor,
+          rawResults: rawResults ?? this.rawResults,
+          enabledFeatures: enabledFeatures != null
+              ? enabledFeatures.value
+              : this.enabledFeatures);
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
@@ -516,7 +702,15 @@ class WitGeneratorConfig implements ToJsonSerializable {
         int64Type,
         typedNumberLists,
         asyncWorker,
-        sameClassUnion
+        sameClassUnion,
+        fixedWidthInts,
+        asyncCalls,
+        outputMode,
+        libraryName,
+        stringEncoding,
+        positionalRecordConstructor,
+        rawResults,
+        enabledFeatures
       ];
   static const _spec = RecordType([
     (label: 'inputs', t: WitGeneratorInput._spec),
@@ -532,7 +726,15 @@ class WitGeneratorConfig implements ToJsonSerializable {
     (label: 'int64-type', t: Int64TypeConfig._spec),
     (label: 'typed-number-lists', t: Bool()),
     (label: 'async-worker', t: Bool()),
-    (label: 'same-class-union', t: Bool())
+    (label: 'same-class-union', t: Bool()),
+    (label: 'fixed-width-ints', t: Bool()),
+    (label: 'async-calls', t: Bool()),
+    (label: 'output-mode', t: OptionType(OutputMode._spec)),
+    (label: 'library-name', t: OptionType(StringType())),
+    (label: 'string-encoding', t: StringEncodingConfig._spec),
+    (label: 'positional-record-constructor', t: Bool()),
+    (label: 'raw-results', t: Bool()),
+    (label: 'enabled-features', t: OptionType(ListType(StringType())))
   ]);
 }
 
@@ -552,6 +754,11 @@ class DartWitGeneratorWorld {
           const FuncType([('config', WitGeneratorConfig._spec)],
               [('', ResultType(WitFile._spec, StringType()))]),
         )!,
+        _generateFiles = library.getComponentFunction(
+          'generate-files',
+          const FuncType([('config', WitGeneratorConfig._spec)],
+              [('', ResultType(ListType(WitFile._spec), StringType()))]),
+        )!,
         _generateToFile = library.getComponentFunction(
           'generate-to-file',
           const FuncType([
@@ -597,6 +804,22 @@ class DartWitGeneratorWorld {
         (error) => error is String ? error : (error! as ParsedString).value));
   }
 
+  final ListValue Function(ListValue) _generateFiles;
+
+  /// Generates a Dart file for each interface and a main Dart file for the world
+  /// from the given configuration. The [path] of each file is the file name
+  /// and the interface files are `part of` the main file.
+  Result<List<WitFile>, String> generateFiles({
+    required WitGeneratorConfig config,
+  }) {
+    final results = _generateFiles([config.toWasm()]);
+    final result = results[0];
+    return withContext(() => Result.fromJson(
+        result,
+        (ok) => (ok! as Iterable).map(WitFile.fromJson).toList(),
+        (error) => error is String ? error : (error! as ParsedString).value));
+  }
+
   final ListValue Function(ListValue) _generateToFile;
 
   /// Generates a world from the given configuration to the [filePath].
@@ -0,0 +1,101 @@
+import 'dart:typed_data' show ByteData, Uint8List;
+
+import 'package:test/test.dart';
+import 'package:wasm_run/wasm_run.dart' show WasmInstance;
+import 'package:wasm_wit_component/src/canonical_abi.dart';
+import 'package:wasm_wit_component/src/generator.dart' show Int64TypeConfig;
+
+class _FakeInstance implements WasmInstance {
+  @override
+  dynamic noSuchMethod(Invocation invocation) => super.noSuchMethod(invocation);
+}
+
+void main() {
+  group('canon_lift cleanup', () {
+    final memory = Uint8List(1024);
+    final allocated = <int>{};
+    final freed = <int>{};
+    final postReturns = <List<FlatValue>>[];
+    int nextPtr = 8;
+
+    final options = CanonicalOptions(
+      () {},
+      () => memory,
+      () => ByteData.sublistView(memory),
+      StringEncoding.utf8,
+      (ptr, sizeInitial, alignment, sizeFinal) {
+        final newPtr = (nextPtr / alignment).ceil() * alignment;
+        nextPtr = newPtr + sizeFinal;
+        allocated.add(newPtr);
+        return newPtr;
+      },
+      postReturns.add,
+      free: (ptr, size, alignment) => freed.add(ptr),
+    );
+
+    setUp(() {
+      allocated.clear();
+      freed.clear();
+      postReturns.clear();
+    });
+
+    ComponentInstance instance() => ComponentInstance(
+          id: '',
+          instance: _FakeInstance(),
+          int64Type: Int64TypeConfig.bigInt,
+        );
+
+    test('frees the arguments when lowering throws', () {
+      final inst = instance();
+      bool called = false;
+      expect(
+        () => canon_lift(
+          options,
+          inst,
+          (_) {
+            called = true;
+            return const [];
+          },
+          const FuncType([('a', StringType()), ('b', U32())], []),
+          ['hello', 'not an int'],
+        ),
+        throwsA(isA<TypeError>()),
+      );
+      expect(called, false);
+      expect(allocated, isNotEmpty);
+      expect(freed, allocated);
+      expect(inst.may_leave, true);
+    });
+
+    test('does not free the arguments received by the callee', () {
+      final (results, post) = canon_lift(
+        options,
+        instance(),
+        (_) => [FlatValue(FlatType.i32, 3)],
+        const FuncType([('a', StringType())], [('', U32())]),
+        ['hello'],
+      );
+      post();
+      expect(results, [3]);
+      expect(allocated, isNotEmpty);
+      expect(freed, isEmpty);
+      expect(postReturns, hasLength(1));
+    });
+
+    test('calls post return when lifting the results throws', () {
+      expect(
+        () => canon_lift(
+          options,
+          instance(),
+          // A surrogate code point is not a valid char
+          (_) => [FlatValue(FlatType.i32, 0xD800)],
+          const FuncType([], [('', Char())]),
+          [],
+        ),
+        throwsA(isA<Trap>()),
+      );
+      expect(postReturns, hasLength(1));
+      expect(postReturns.first.first.v, 0xD800);
+    });
+  });
+}
@@ -167,6 +167,13 @@ void testAll({TestArgs? testArgs}) {
     final result = add.call([1, 4]);
     expect(result, [5]);
     print('result $result');
+
+    instance.dispose();
+    if (!isLibrary) return;
+    expect(
+      () => add.call([1, 4]),
+      throwsA(predicate((e) => e.toString().contains('is closed'))),
+    );
   });
 
   test('import function', () async {
@@ -222,6 +229,34 @@ void testAll({TestArgs? testArgs}) {
     expect(argsList, 3);
   });
 
+  test('import function exception', () async {
+    final binary = await getBinary(
+      wat: r'''
+        (module
+            (import "host" "hello" (func $host_hello (param i32)))
+            (func (export "hello")
+                (call $host_hello (i32.const 3))
+            )
+        )
+    ''',
+      base64Binary:
+          'AGFzbQEAAAABCAJgAX8AYAAAAg4BBGhvc3QFaGVsbG8AAAMCAQEHCQEFaGVsbG8AAQoIAQYAQQMQAAsAFARuYW1lAQ0BAApob3N0X2hlbGxv',
+    );
+
+    final module = compileWasmModuleSync(binary);
+    final error = StateError('host error');
+    final hostHello = WasmFunction.voidReturn(
+      (int args) => throw error,
+      params: [ValueTy.i32],
+    );
+
+    final instance =
+        (module.builder()..addImport('host', 'hello', hostHello)).buildSync();
+
+    final hello = instance.getFunction('hello')!;
+    expect(() => hello(), throwsA(same(error)));
+  });
+
   test('globals', () async {
     final binary = await getBinary(
       wat: r'''
@@ -365,6 +400,48 @@ void testAll({TestArgs? testArgs}) {
     expect(result, 'Ho');
   });
 
+  test('memory typed values', () async {
+    final binary = await getBinary(
+      wat: r'''
+(module
+  (memory (export "memory") 1)
+  (data (i32.const 16) "hello\00")
+  (func (export "store") (param i32 i32)
+    (i32.store (local.get 0) (local.get 1)))
+)
+''',
+      base64Binary:
+          'AGFzbQEAAAABBgFgAn9/AAMCAQAFAwEAAQcSAgZtZW1vcnkCAAVzdG9yZQAACgsBCQAgACABNgIACwsMAQBBEAsGaGVsbG8A',
+    );
+    final module = await compileWasmModule(binary);
+    final instance = module.builder().buildSync();
+    final memory = instance.getMemory('memory')!;
+    final store = instance.getFunction('store')!;
+
+    store([8, -42]);
+    expect(memory.readI32(8), -42);
+    expect(memory.view.sublist(8, 12), [214, 255, 255, 255]);
+    expect(memory.readCString(16), 'hello');
+
+    memory.writeI64(32, i64.fromInt(-3));
+    expect(i64.toInt(memory.readI64(32)), -3);
+    memory.writeF32(40, 1.5);
+    expect(memory.readF32(40), 1.5);
+    memory.writeF64(48, -0.25);
+    expect(memory.readF64(48), -0.25);
+    memory.writeI32(56, 0x12345678);
+    expect(memory.view.sublist(56, 60), [0x78, 0x56, 0x34, 0x12]);
+
+    expect(memory.writeCString(64, 'wörld'), 7);
+    expect(memory.readCString(64), 'wörld');
+
+    final end = memory.lengthInBytes;
+    expect(() => memory.readI32(end - 2), throwsRangeError);
+    expect(() => memory.writeCString(end - 2, 'ab'), throwsRangeError);
+    memory.view[end - 1] = 1;
+    expect(() => memory.readCString(end - 1), throwsRangeError);
+  });
+
   test('table func call', () async {
     final binary = await getBinary(
       wat: r'''
@@ -591,6 +668,7 @@ void testAll({TestArgs? testArgs}) {
           threads: v['threads']! as bool,
           tailCall: true,
           floats: true,
+          nanCanonicalization: true,
           multiMemory: true,
           exceptions: true,
           memory64: true,
@@ -598,6 +676,7 @@ void testAll({TestArgs? testArgs}) {
           componentModel: true,
           memoryControl: true,
           garbageCollection: true,
+          functionReferences: true,
           typeReflection: true,
         );
 
@@ -608,6 +687,7 @@ void testAll({TestArgs? testArgs}) {
             version: '0.2.1',
             defaultFeatures: features,
             supportedFeatures: features,
+            stability: const [],
           ),
         );
         expect(uri, v['uri']);
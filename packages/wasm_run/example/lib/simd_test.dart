@@ -74,6 +74,38 @@ void simdTests() {
       );
     });
 
+    test('lanes', () async {
+      final runtime = await wasmRuntimeFeatures();
+      if (!runtime.supportedFeatures.simd) return;
+
+      final binary = await getBinary(
+        wat: r'''
+(module
+  (func $add (export "add") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    i32x4.add
+  )
+)
+''',
+        base64Binary:
+            'AGFzbQEAAAABBwFgAnt7AXsDAgEABwcBA2FkZAAACgsBCQAgACAB/a4BCwANBG5hbWUBBgEAA2FkZA==',
+      );
+
+      final module = compileWasmModuleSync(binary);
+      final instance = module.builder().buildSync();
+      final add = instance.getFunction('add')!;
+      final a = WasmValue.i32x4([1, 2, 3, 0x7fffffff]);
+      final b = WasmValue.i32x4([10, -20, 30, 1]);
+      expect(a.asI32x4, [1, 2, 3, 0x7fffffff]);
+      expect(WasmValue.f64x2([1.5, -0.25]).asF64x2, [1.5, -0.25]);
+
+      // TODO: improve test for browser
+      if (!isLibrary) return;
+      final result = WasmValue.v128(add.inner(a.value, b.value) as U8Array16);
+      expect(result.asI32x4, [11, -18, 33, -0x80000000]);
+    });
+
     test('float', () async {
       final runtime = await wasmRuntimeFeatures();
       if (!runtime.supportedFeatures.simd) return;
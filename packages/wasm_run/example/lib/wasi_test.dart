@@ -179,10 +179,10 @@ void wasiTest({TestArgs? testArgs}) {
         captureStdout: true,
         captureStderr: true,
         inheritStdin: false,
-        inheritEnv: false,
-        inheritArgs: false,
-        args: args,
-        env: [EnvVariable(name: 'name', value: 'value')],
+        process: WasiProcessConfig(
+          args: ArgsMode.explicit(args),
+          env: EnvMode.explicit([EnvVariable(name: 'name', value: 'value')]),
+        ),
         preopenedDirs: preopenedDirs,
         webBrowserFileSystem: {
           directoryToAllow: WasiDirectory({
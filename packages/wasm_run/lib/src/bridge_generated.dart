@@ -19,6 +19,9 @@ import 'bridge_generated.io.dart'
 part 'bridge_generated.freezed.dart';
 
 abstract class WasmRunDart {
+  /// Creates a module builder for [module]. The WASI imports are linked
+  /// only if [wasi_config] is provided, so the same build can instantiate
+  /// modules without any access to the host.
   WasmRunModuleId moduleBuilder(
       {required CompiledModule module,
       int? numThreads,
@@ -31,6 +34,35 @@ abstract class WasmRunDart {
 
   FlutterRustBridgeTaskConstMeta get kParseWatFormatConstMeta;
 
+  /// Rewrites the [module_wasm] binary setting the maximum of the memories defined
+  /// by the module to [max_pages], or keeping the declared maximum if it is lower.
+  /// Returns an error if the minimum of a memory exceeds [max_pages].
+  Uint8List clampMemory(
+      {required Uint8List moduleWasm, required int maxPages, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClampMemoryConstMeta;
+
+  /// Returns `true` if the [module_wasm] binary defines a start function,
+  /// which is executed when instantiating the module.
+  bool moduleHasStart({required Uint8List moduleWasm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kModuleHasStartConstMeta;
+
+  /// Returns the SHA-256 of the [module_wasm] binary, or of its binary format
+  /// if it is in the text format, to be used as a cache or deduplication key.
+  /// The hash is stable across runs and independent of the whitespace and comments
+  /// of text format inputs.
+  Uint8List moduleContentHash({required Uint8List moduleWasm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kModuleContentHashConstMeta;
+
+  WasmEngine createEngine({required ModuleConfig config, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateEngineConstMeta;
+
+  /// Compiles the module with a new engine created from `config`.
+  /// Use [WasmEngine] to share the engine between modules.
+  /// Returns a [CompileError] if the module is invalid or can not be compiled.
   Future<CompiledModule> compileWasm(
       {required Uint8List moduleWasm,
       required ModuleConfig config,
@@ -45,6 +77,25 @@ abstract class WasmRunDart {
 
   FlutterRustBridgeTaskConstMeta get kCompileWasmSyncConstMeta;
 
+  CompileHandle createCompileHandle({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateCompileHandleConstMeta;
+
+  /// Compiles [module_wasm] like [compile_wasm], returning a [Cancelled] error
+  /// when [handle] is cancelled. The handle is checked between the phases of the
+  /// compilation, the code generation runs in a separate thread which is
+  /// detached when the handle is cancelled: the thread keeps compiling in the
+  /// background until it finishes and its module is dropped. At most 4 threads
+  /// compile at the same time, including the detached ones, the next
+  /// compilations wait for one of them to finish.
+  Future<CompiledModule> compileWasmCancelable(
+      {required Uint8List moduleWasm,
+      required ModuleConfig config,
+      required CompileHandle handle,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmCancelableConstMeta;
+
   WasmFeatures wasmFeaturesForConfig(
       {required ModuleConfig config, dynamic hint});
 
@@ -77,11 +128,81 @@ abstract class WasmRunDart {
 
   FlutterRustBridgeTaskConstMeta get kLinkImportsMethodWasmRunModuleIdConstMeta;
 
+  /// Instantiates [module] in the store of this module and defines its exports
+  /// under the [namespace] module name, so that this module can import them.
+  /// For example, to call the functions exported by a library module.
+  ///
+  /// [module] is instantiated with the imports defined so far, such as WASI
+  /// and [link_imports], and it should be compiled with the same [WasmEngine].
+  void linkModuleMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required String namespace,
+      required CompiledModule module,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLinkModuleMethodWasmRunModuleIdConstMeta;
+
   Stream<Uint8List> stdioStreamMethodWasmRunModuleId(
       {required WasmRunModuleId that, required StdIOKind kind, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kStdioStreamMethodWasmRunModuleIdConstMeta;
 
+  /// Sends the paths that the module tried to open and were denied by
+  /// the WASI sandbox, for example, paths outside of the preopened directories.
+  /// The module still receives the same WASI errno.
+  Stream<String> wasiDeniedStreamMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kWasiDeniedStreamMethodWasmRunModuleIdConstMeta;
+
+  /// Returns and removes the stdout or stderr output kept in the capture buffer,
+  /// see [WasiConfigNative::capture_capacity].
+  Uint8List readStdioMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReadStdioMethodWasmRunModuleIdConstMeta;
+
+  /// Waits up to [timeout_millis] for the next stdout or stderr output kept in
+  /// the capture buffer and removes it, see [WasiConfigNative::capture_capacity].
+  /// Returns an empty list when the timeout elapses without output and `None`
+  /// once all the output was returned after the module exited with
+  /// [WasmRunModuleId::call_wasi_start], or the module or the capture buffer was closed.
+  Future<Uint8List?> nextStdioChunkMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required StdIOKind kind,
+      required int timeoutMillis,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kNextStdioChunkMethodWasmRunModuleIdConstMeta;
+
+  /// The number of bytes of stdout or stderr dropped because
+  /// the capture buffer was full or closed.
+  int stdioDroppedBytesMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kStdioDroppedBytesMethodWasmRunModuleIdConstMeta;
+
+  /// Stops capturing stdout or stderr in the capture buffer and frees it.
+  /// The following output of the module is dropped.
+  void closeStdioMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCloseStdioMethodWasmRunModuleIdConstMeta;
+
+  /// Releases the store of the module at a well-defined point, instead of
+  /// relying on the order of the finalizers: flushes the remaining line buffered
+  /// output, closes the output streams and drops the WASI context,
+  /// which closes the preopened directories.
+  /// Further calls with the module return a [ModuleClosed] error.
+  /// Closing a closed module does nothing.
+  void closeMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCloseMethodWasmRunModuleIdConstMeta;
+
   Future<void> disposeMethodWasmRunModuleId(
       {required WasmRunModuleId that, dynamic hint});
 
@@ -96,6 +217,11 @@ abstract class WasmRunDart {
   FlutterRustBridgeTaskConstMeta
       get kCallFunctionHandleSyncMethodWasmRunModuleIdConstMeta;
 
+  /// Calls [func] in a thread outside of the Dart isolate,
+  /// so that long running calls do not block it.
+  /// Host functions are executed in the Dart isolate, calling them
+  /// within this function traps. Use [call_function_handle_sync] for
+  /// functions that call host functions.
   Future<List<WasmVal>> callFunctionHandleMethodWasmRunModuleId(
       {required WasmRunModuleId that,
       required WFunc func,
@@ -105,6 +231,106 @@ abstract class WasmRunDart {
   FlutterRustBridgeTaskConstMeta
       get kCallFunctionHandleMethodWasmRunModuleIdConstMeta;
 
+  /// Calls the function at [index] in the exports of the instance,
+  /// see [ModuleExportDesc.index], for example, when the name of the export
+  /// is not known. Returns an error if [index] is out of range or the export
+  /// is not a function. The functions of a table can be called with
+  /// [get_table] and [call_function_handle_sync].
+  List<WasmVal> callByIndexMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int index,
+      required List<WasmVal> args,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCallByIndexMethodWasmRunModuleIdConstMeta;
+
+  /// Calls the WASI `_start` [func] and returns the exit code of the execution.
+  /// The exit code is the one passed to `proc_exit`, or 0 if `_start` returns
+  /// without calling it. This does not depend on the stdout and stderr configuration.
+  /// When it returns, the captured output is finished, see [WasmRunModuleId::next_stdio_chunk].
+  int callWasiStartMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required WFunc func, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kCallWasiStartMethodWasmRunModuleIdConstMeta;
+
+  /// Initializes the instance according to its [WasiModuleKind].
+  /// Calls `_initialize` for reactors and `_start` for commands, returning
+  /// its exit code as in [call_wasi_start]. Libraries are not initialized.
+  /// Returns None for reactors and libraries.
+  int? initializeMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInitializeMethodWasmRunModuleIdConstMeta;
+
+  /// Calls the function [name] exported by a reactor or a library,
+  /// after [initialize]. Returns an error for `_initialize` and `_start`,
+  /// which are called by [initialize], and for the exports of commands.
+  List<WasmVal> callExportMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required String name,
+      required List<WasmVal> args,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCallExportMethodWasmRunModuleIdConstMeta;
+
+  /// Allocates [size] bytes in the memory of the module with its exported
+  /// allocator and returns the pointer, for example, to write a string
+  /// with [WasmRunModuleId::write_memory] before passing it to a function.
+  /// [alloc_export] is the name of the allocation function, by default `malloc`,
+  /// or `cabi_realloc` when the module does not export `malloc`.
+  /// Functions with one parameter are called with [size], like `malloc`,
+  /// and functions with four parameters are called like
+  /// `cabi_realloc(0, 0, 8, size)`, with an alignment of 8 bytes.
+  int guestAllocMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int size,
+      String? allocExport,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGuestAllocMethodWasmRunModuleIdConstMeta;
+
+  /// Frees the [size] bytes at [ptr] allocated with [WasmRunModuleId::guest_alloc].
+  /// [free_export] is the name of the function, by default `free`.
+  /// Functions with one parameter are called with [ptr], like `free`,
+  /// and functions with two parameters are called with [ptr] and [size].
+  void guestFreeMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int ptr,
+      required int size,
+      String? freeExport,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGuestFreeMethodWasmRunModuleIdConstMeta;
+
+  /// Copies the UTF-8 bytes of [value] to the memory exported by the module,
+  /// allocated with the exported `cabi_realloc` function like the canonical ABI
+  /// does to pass strings, and returns their location.
+  /// [memory_name] is required when the module exports more than one memory,
+  /// see [WasmRunModuleId::memories].
+  PointerAndLength writeGuestStringMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required String value,
+      String? memoryName,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kWriteGuestStringMethodWasmRunModuleIdConstMeta;
+
+  /// Reads the string of [len] UTF-8 bytes at [ptr] in the memory exported
+  /// by the module. Returns an error if the bytes are not valid UTF-8.
+  /// [memory_name] is required when the module exports more than one memory,
+  /// see [WasmRunModuleId::memories].
+  String readGuestStringMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int ptr,
+      required int len,
+      String? memoryName,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kReadGuestStringMethodWasmRunModuleIdConstMeta;
+
   Stream<ParallelExec> callFunctionHandleParallelMethodWasmRunModuleId(
       {required WasmRunModuleId that,
       required String funcName,
@@ -199,12 +425,17 @@ abstract class WasmRunDart {
   FlutterRustBridgeTaskConstMeta
       get kGetMemoryDataMethodWasmRunModuleIdConstMeta;
 
+  /// The address of the data of [memory]. It may change when the memory grows,
+  /// including during a call into the guest, so it should not be kept
+  /// across calls. Use [read_memory] and [write_memory] to copy the data.
   int getMemoryDataPointerMethodWasmRunModuleId(
       {required WasmRunModuleId that, required Memory memory, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta
       get kGetMemoryDataPointerMethodWasmRunModuleIdConstMeta;
 
+  /// The address and length of the data of [memory],
+  /// see [get_memory_data_pointer].
   PointerAndLength getMemoryDataPointerAndLengthMethodWasmRunModuleId(
       {required WasmRunModuleId that, required Memory memory, dynamic hint});
 
@@ -291,6 +522,28 @@ abstract class WasmRunDart {
 
   FlutterRustBridgeTaskConstMeta get kFillTableMethodWasmRunModuleIdConstMeta;
 
+  /// Limits the number of nested host function calls in the store to [max_depth],
+  /// for example, when a host function calls back into the guest which calls
+  /// it again. The call that exceeds it traps with [ReentrancyLimitExceeded].
+  /// `max_wasm_stack` only limits the Wasm frames, not the host frames between them.
+  /// Defaults to `None`, which does not limit them.
+  void setMaxHostCallDepthMethodWasmRunModuleId(
+      {required WasmRunModuleId that, int? maxDepth, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kSetMaxHostCallDepthMethodWasmRunModuleIdConstMeta;
+
+  /// Interrupts the functions of the module with an `interrupt` trap when
+  /// the epoch of its engine is incremented [ticks_beyond_current] times
+  /// from now, see [WasmEngine::increment_epoch]. `None` removes the deadline,
+  /// which is the default. Requires [ModuleConfigWasmtime::epoch_interruption].
+  /// The calls executed in the threads of `num_threads` are not interrupted.
+  void setEpochDeadlineMethodWasmRunModuleId(
+      {required WasmRunModuleId that, int? ticksBeyondCurrent, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kSetEpochDeadlineMethodWasmRunModuleIdConstMeta;
+
   void addFuelMethodWasmRunModuleId(
       {required WasmRunModuleId that, required int delta, dynamic hint});
 
@@ -307,6 +560,55 @@ abstract class WasmRunDart {
 
   FlutterRustBridgeTaskConstMeta get kConsumeFuelMethodWasmRunModuleIdConstMeta;
 
+  /// The fuel consumed by the last call to [call_function_handle],
+  /// including the fuel consumed by nested calls made from host functions.
+  /// Returns None if `consume_fuel` is not enabled or no function was called.
+  int? lastCallFuelConsumedMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kLastCallFuelConsumedMethodWasmRunModuleIdConstMeta;
+
+  /// The wall-clock duration in microseconds of the last call to [call_function_handle].
+  /// Returns None if no function was called.
+  int? lastCallDurationMicrosMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kLastCallDurationMicrosMethodWasmRunModuleIdConstMeta;
+
+  /// The memory used by the instance, the sum of the sizes of its memories
+  /// and an estimate of the overhead of its tables and globals.
+  /// The sizes are tracked by the resource limiter of the store, so they
+  /// include the memories and tables that are not exported.
+  /// The instances are allocated on demand, there is no pooling allocator
+  /// with slots to report.
+  MemoryFootprint memoryFootprintMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kMemoryFootprintMethodWasmRunModuleIdConstMeta;
+
+  /// The names of the memories exported by the module, in export order.
+  /// The functions that access the memory of the module without receiving it,
+  /// like [WasmRunModuleId::read_guest_string], require the name of the memory
+  /// when there is more than one. WASI always uses the memory exported as `memory`.
+  List<String> memoriesMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kMemoriesMethodWasmRunModuleIdConstMeta;
+
+  /// Creates a new isolated store for this module and instantiates it
+  /// with the WASI imports of [wasi_config], if any.
+  /// Use [module_builder] to define other imports before instantiating.
+  /// The returned store should be disposed with [WasmRunModuleId::dispose].
+  WasmRunModuleId instantiateMethodCompiledModule(
+      {required CompiledModule that,
+      WasiConfigNative? wasiConfig,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInstantiateMethodCompiledModuleConstMeta;
+
   WasmRunSharedMemory createSharedMemoryMethodCompiledModule(
       {required CompiledModule that,
       required MemoryTy memoryType,
@@ -321,12 +623,135 @@ abstract class WasmRunDart {
   FlutterRustBridgeTaskConstMeta
       get kGetModuleImportsMethodCompiledModuleConstMeta;
 
+  /// The [WasiModuleKind] of the module, from its exports.
+  WasiModuleKind wasiModuleKindMethodCompiledModule(
+      {required CompiledModule that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kWasiModuleKindMethodCompiledModuleConstMeta;
+
   List<ModuleExportDesc> getModuleExportsMethodCompiledModule(
       {required CompiledModule that, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta
       get kGetModuleExportsMethodCompiledModuleConstMeta;
 
+  /// Compiles [module_wasm] with the [ModuleConfig] of the engine, like [compile_wasm].
+  Future<CompiledModule> compileWasmMethodWasmEngine(
+      {required WasmEngine that, required Uint8List moduleWasm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmMethodWasmEngineConstMeta;
+
+  CompiledModule compileWasmSyncMethodWasmEngine(
+      {required WasmEngine that, required Uint8List moduleWasm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmSyncMethodWasmEngineConstMeta;
+
+  /// Increments the epoch of the engine, interrupting the functions of the
+  /// stores that reached their epoch deadline when epoch interruption is enabled.
+  /// Meant to be called from a timer thread with a custom cadence,
+  /// it is shared by all the stores of the modules compiled with this engine.
+  void incrementEpochMethodWasmEngine({required WasmEngine that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIncrementEpochMethodWasmEngineConstMeta;
+
+  /// Creates a [WasmLinker] for the modules compiled with this engine.
+  /// The WASI imports are defined once in the linker if [wasi] is `true`.
+  WasmLinker createLinkerMethodWasmEngine(
+      {required WasmEngine that, required bool wasi, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateLinkerMethodWasmEngineConstMeta;
+
+  /// Creates a [ModuleCache] for the modules compiled with this engine.
+  /// The least recently used modules are evicted when there are more than
+  /// [max_entries] modules or their binaries exceed [max_bytes] in total.
+  /// If [directory] is provided, the compiled artifacts are also stored
+  /// in it and loaded instead of compiling the module again, for example,
+  /// in the next run of the app. Loading an artifact runs its native code,
+  /// so the directory must only be writable by the app. The artifacts are
+  /// checked for corruption before they are loaded, not for tampering.
+  ModuleCache createModuleCacheMethodWasmEngine(
+      {required WasmEngine that,
+      int? maxEntries,
+      int? maxBytes,
+      String? directory,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kCreateModuleCacheMethodWasmEngineConstMeta;
+
+  /// Defines the host function [module]::[name] for all the modules
+  /// instantiated with this linker.
+  void defineHostFunctionMethodWasmLinker(
+      {required WasmLinker that,
+      required String module,
+      required String name,
+      required int functionPointer,
+      required int functionId,
+      required List<ValueTy> paramTypes,
+      required List<ValueTy> resultTypes,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kDefineHostFunctionMethodWasmLinkerConstMeta;
+
+  /// Defines the memory [module]::[name] with [memory_type] for all the modules
+  /// instantiated with this linker. A new memory is created for each module,
+  /// for example, for modules that import their memory as `env::memory`.
+  /// Shared memories should be created with [WasmRunModuleId::create_shared_memory]
+  /// and linked with [WasmRunModuleId::link_imports].
+  void defineMemoryMethodWasmLinker(
+      {required WasmLinker that,
+      required String module,
+      required String name,
+      required MemoryTy memoryType,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDefineMemoryMethodWasmLinkerConstMeta;
+
+  /// Creates a module builder for [module] using a clone of this linker.
+  /// [wasi_config] must be provided if, and only if,
+  /// the linker was created with WASI.
+  WasmRunModuleId moduleBuilderMethodWasmLinker(
+      {required WasmLinker that,
+      required CompiledModule module,
+      WasiConfigNative? wasiConfig,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kModuleBuilderMethodWasmLinkerConstMeta;
+
+  Future<CompiledModule> compileWasmMethodModuleCache(
+      {required ModuleCache that, required Uint8List moduleWasm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmMethodModuleCacheConstMeta;
+
+  CompiledModule compileWasmSyncMethodModuleCache(
+      {required ModuleCache that, required Uint8List moduleWasm, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmSyncMethodModuleCacheConstMeta;
+
+  ModuleCacheStats statsMethodModuleCache(
+      {required ModuleCache that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStatsMethodModuleCacheConstMeta;
+
+  /// Removes all the modules from memory.
+  /// The artifacts in the directory of the cache are not deleted.
+  void clearMethodModuleCache({required ModuleCache that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClearMethodModuleCacheConstMeta;
+
+  /// Cancels the compilations that use this handle, they return a [Cancelled]
+  /// error. Cancelling after the module was compiled has no effect.
+  void cancelMethodCompileHandle({required CompileHandle that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCancelMethodCompileHandleConstMeta;
+
+  bool isCancelledMethodCompileHandle(
+      {required CompileHandle that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIsCancelledMethodCompileHandleConstMeta;
+
   MemoryTy tyMethodWasmRunSharedMemory(
       {required WasmRunSharedMemory that, dynamic hint});
 
@@ -529,14 +954,26 @@ abstract class WasmRunDart {
 
   FlutterRustBridgeTaskConstMeta get kXorMethodAtomicsConstMeta;
 
+  DropFnType get dropOpaqueArcAtomicBool;
+  ShareFnType get shareOpaqueArcAtomicBool;
+  OpaqueTypeFinalizer get ArcAtomicBoolFinalizer;
+
   DropFnType get dropOpaqueArcRwLockSharedMemory;
   ShareFnType get shareOpaqueArcRwLockSharedMemory;
   OpaqueTypeFinalizer get ArcRwLockSharedMemoryFinalizer;
 
+  DropFnType get dropOpaqueArcStdSyncMutexLinkerTemplate;
+  ShareFnType get shareOpaqueArcStdSyncMutexLinkerTemplate;
+  OpaqueTypeFinalizer get ArcStdSyncMutexLinkerTemplateFinalizer;
+
   DropFnType get dropOpaqueArcStdSyncMutexModule;
   ShareFnType get shareOpaqueArcStdSyncMutexModule;
   OpaqueTypeFinalizer get ArcStdSyncMutexModuleFinalizer;
 
+  DropFnType get dropOpaqueArcStdSyncMutexModuleCacheImpl;
+  ShareFnType get shareOpaqueArcStdSyncMutexModuleCacheImpl;
+  OpaqueTypeFinalizer get ArcStdSyncMutexModuleCacheImplFinalizer;
+
   DropFnType get dropOpaqueCallStack;
   ShareFnType get shareOpaqueCallStack;
   OpaqueTypeFinalizer get CallStackFinalizer;
@@ -549,15 +986,38 @@ abstract class WasmRunDart {
   ShareFnType get shareOpaqueMemory;
   OpaqueTypeFinalizer get MemoryFinalizer;
 
+  DropFnType get dropOpaqueStdioCapture;
+  ShareFnType get shareOpaqueStdioCapture;
+  OpaqueTypeFinalizer get StdioCaptureFinalizer;
+
   DropFnType get dropOpaqueTable;
   ShareFnType get shareOpaqueTable;
   OpaqueTypeFinalizer get TableFinalizer;
 
+  DropFnType get dropOpaqueWEngine;
+  ShareFnType get shareOpaqueWEngine;
+  OpaqueTypeFinalizer get WEngineFinalizer;
+
   DropFnType get dropOpaqueWFunc;
   ShareFnType get shareOpaqueWFunc;
   OpaqueTypeFinalizer get WFuncFinalizer;
 }
 
+@sealed
+class ArcAtomicBool extends FrbOpaque {
+  final WasmRunDart bridge;
+  ArcAtomicBool.fromRaw(int ptr, int size, this.bridge)
+      : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueArcAtomicBool;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueArcAtomicBool;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.ArcAtomicBoolFinalizer;
+}
+
 @sealed
 class ArcRwLockSharedMemory extends FrbOpaque {
   final WasmRunDart bridge;
@@ -574,6 +1034,22 @@ class ArcRwLockSharedMemory extends FrbOpaque {
       bridge.ArcRwLockSharedMemoryFinalizer;
 }
 
+@sealed
+class ArcStdSyncMutexLinkerTemplate extends FrbOpaque {
+  final WasmRunDart bridge;
+  ArcStdSyncMutexLinkerTemplate.fromRaw(int ptr, int size, this.bridge)
+      : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueArcStdSyncMutexLinkerTemplate;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueArcStdSyncMutexLinkerTemplate;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer =>
+      bridge.ArcStdSyncMutexLinkerTemplateFinalizer;
+}
+
 @sealed
 class ArcStdSyncMutexModule extends FrbOpaque {
   final WasmRunDart bridge;
@@ -590,6 +1066,22 @@ class ArcStdSyncMutexModule extends FrbOpaque {
       bridge.ArcStdSyncMutexModuleFinalizer;
 }
 
+@sealed
+class ArcStdSyncMutexModuleCacheImpl extends FrbOpaque {
+  final WasmRunDart bridge;
+  ArcStdSyncMutexModuleCacheImpl.fromRaw(int ptr, int size, this.bridge)
+      : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueArcStdSyncMutexModuleCacheImpl;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueArcStdSyncMutexModuleCacheImpl;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer =>
+      bridge.ArcStdSyncMutexModuleCacheImplFinalizer;
+}
+
 @sealed
 class CallStack extends FrbOpaque {
   final WasmRunDart bridge;
@@ -632,6 +1124,21 @@ class Memory extends FrbOpaque {
   OpaqueTypeFinalizer get staticFinalizer => bridge.MemoryFinalizer;
 }
 
+@sealed
+class StdioCapture extends FrbOpaque {
+  final WasmRunDart bridge;
+  StdioCapture.fromRaw(int ptr, int size, this.bridge)
+      : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueStdioCapture;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueStdioCapture;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.StdioCaptureFinalizer;
+}
+
 @sealed
 class Table extends FrbOpaque {
   final WasmRunDart bridge;
@@ -646,6 +1153,20 @@ class Table extends FrbOpaque {
   OpaqueTypeFinalizer get staticFinalizer => bridge.TableFinalizer;
 }
 
+@sealed
+class WEngine extends FrbOpaque {
+  final WasmRunDart bridge;
+  WEngine.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueWEngine;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueWEngine;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.WEngineFinalizer;
+}
+
 @sealed
 class WFunc extends FrbOpaque {
   final WasmRunDart bridge;
@@ -826,6 +1347,20 @@ class Atomics {
       );
 }
 
+/// What happens when a module writes to a full capture buffer,
+/// see [WasiConfigNative.capture_capacity].
+enum CaptureOverflow {
+  /// The output that does not fit is dropped and counted, see
+  /// [WasmRunModuleId.stdioDroppedBytes]. The module is never blocked.
+  drop,
+
+  /// The module's `fd_write` blocks until the buffer is read, applying
+  /// backpressure. The functions of the module should be called
+  /// in a different thread than the one reading the output, for example,
+  /// with [WasmFunction.callAsync], otherwise the call never returns.
+  block,
+}
+
 class CompareExchangeResult {
   final bool success;
   final int value;
@@ -836,6 +1371,36 @@ class CompareExchangeResult {
   });
 }
 
+/// A handle to cancel a compilation started with [compile_wasm_cancelable].
+class CompileHandle {
+  final WasmRunDart bridge;
+  final ArcAtomicBool field0;
+
+  const CompileHandle({
+    required this.bridge,
+    required this.field0,
+  });
+
+  /// Cancels the compilations that use this handle, they return a [Cancelled]
+  /// error. Cancelling after the module was compiled has no effect.
+  void cancel({dynamic hint}) => bridge.cancelMethodCompileHandle(
+        that: this,
+      );
+
+  bool isCancelled({dynamic hint}) => bridge.isCancelledMethodCompileHandle(
+        that: this,
+      );
+}
+
+/// A compiled module that is shared by all its instances.
+///
+/// Compiling, with [compile_wasm] or [WasmEngine::compile_wasm], is done once.
+/// Each instantiation, with [CompiledModule::instantiate] or [module_builder],
+/// creates an isolated store with its own memories, tables, globals and WASI context,
+/// which is cheap compared to compiling. For example, to create an instance per request.
+///
+/// The compiled code is immutable and the module can be shared and instantiated
+/// from many threads concurrently. The instances are not shared between stores.
 class CompiledModule {
   final WasmRunDart bridge;
   final ArcStdSyncMutexModule field0;
@@ -845,6 +1410,16 @@ class CompiledModule {
     required this.field0,
   });
 
+  /// Creates a new isolated store for this module and instantiates it
+  /// with the WASI imports of [wasi_config], if any.
+  /// Use [module_builder] to define other imports before instantiating.
+  /// The returned store should be disposed with [WasmRunModuleId::dispose].
+  WasmRunModuleId instantiate({WasiConfigNative? wasiConfig, dynamic hint}) =>
+      bridge.instantiateMethodCompiledModule(
+        that: this,
+        wasiConfig: wasiConfig,
+      );
+
   WasmRunSharedMemory createSharedMemory(
           {required MemoryTy memoryType, dynamic hint}) =>
       bridge.createSharedMemoryMethodCompiledModule(
@@ -857,6 +1432,12 @@ class CompiledModule {
         that: this,
       );
 
+  /// The [WasiModuleKind] of the module, from its exports.
+  WasiModuleKind wasiModuleKind({dynamic hint}) =>
+      bridge.wasiModuleKindMethodCompiledModule(
+        that: this,
+      );
+
   List<ModuleExportDesc> getModuleExports({dynamic hint}) =>
       bridge.getModuleExportsMethodCompiledModule(
         that: this,
@@ -918,6 +1499,36 @@ class ExternalValue with _$ExternalValue {
   ) = ExternalValue_SharedMemory;
 }
 
+/// The stability of a Wasm proposal in a runtime.
+enum FeatureStability {
+  /// The proposal is enabled by default.
+  Stable,
+
+  /// The proposal is supported, but it must be enabled manually
+  /// with [ModuleConfig] and its implementation may change.
+  Experimental,
+
+  /// The proposal is not supported by the runtime.
+  Unsupported,
+}
+
+/// The fuel consumption mode of the wasmi engine.
+///
+/// The modes only differ for instructions with a variable cost, such as
+/// `memory.grow`, `memory.copy` or `table.fill`, which fail with an
+/// out of fuel trap if there is not enough fuel to execute them.
+enum FuelConsumptionMode {
+  /// Fuel is consumed only for the work actually performed by an
+  /// instruction, after checking it can be performed. Faster, but the
+  /// fuel may be checked later than the start of the instruction.
+  lazy,
+
+  /// The maximum fuel an instruction may consume is consumed before
+  /// executing it, even if it performs less work. More precise at the
+  /// trap boundary, but slower and it may trap sooner.
+  eager,
+}
+
 class FuncTy {
   /// The number of function parameters.
   final List<ValueTy> parameters;
@@ -960,6 +1571,48 @@ class GlobalTy {
   });
 }
 
+/// The memory used by the instance of a module, for capacity planning.
+/// The sizes of the memories and tables are tracked by the store as they
+/// are created and grown, they include the ones that are not exported
+/// and the ones created by the host. Only the exported ones are counted.
+class MemoryFootprint {
+  /// The number of exported linear memories, including shared memories.
+  final int memories;
+
+  /// The sum of the current pages of the memories, of 64 KiB each.
+  final int memoryPages;
+
+  /// The sum of the current sizes in bytes of the memories.
+  final int memoryBytes;
+
+  /// The number of exported tables.
+  final int tables;
+
+  /// The sum of the current number of elements of the tables.
+  final int tableElements;
+
+  /// The number of exported globals.
+  final int globals;
+
+  /// An estimate of the host memory used by the tables and globals,
+  /// a pointer for each table element and 16 bytes for each global.
+  final int overheadBytes;
+
+  /// The sum of [memory_bytes] and [overhead_bytes].
+  final int totalBytes;
+
+  const MemoryFootprint({
+    required this.memories,
+    required this.memoryPages,
+    required this.memoryBytes,
+    required this.tables,
+    required this.tableElements,
+    required this.globals,
+    required this.overheadBytes,
+    required this.totalBytes,
+  });
+}
+
 class MemoryTy {
   /// Whether or not this memory could be shared between multiple processes.
   final bool shared;
@@ -977,6 +1630,71 @@ class MemoryTy {
   });
 }
 
+/// Compiled modules of a [WasmEngine] keyed by the content hash of their binary,
+/// as returned by [module_content_hash]. Compiling the same bytes again
+/// returns the cached module instead of recompiling it.
+/// Created with [WasmEngine::create_module_cache].
+class ModuleCache {
+  final WasmRunDart bridge;
+  final ArcStdSyncMutexModuleCacheImpl field0;
+
+  const ModuleCache({
+    required this.bridge,
+    required this.field0,
+  });
+
+  Future<CompiledModule> compileWasm(
+          {required Uint8List moduleWasm, dynamic hint}) =>
+      bridge.compileWasmMethodModuleCache(
+        that: this,
+        moduleWasm: moduleWasm,
+      );
+
+  CompiledModule compileWasmSync(
+          {required Uint8List moduleWasm, dynamic hint}) =>
+      bridge.compileWasmSyncMethodModuleCache(
+        that: this,
+        moduleWasm: moduleWasm,
+      );
+
+  ModuleCacheStats stats({dynamic hint}) => bridge.statsMethodModuleCache(
+        that: this,
+      );
+
+  /// Removes all the modules from memory.
+  /// The artifacts in the directory of the cache are not deleted.
+  void clear({dynamic hint}) => bridge.clearMethodModuleCache(
+        that: this,
+      );
+}
+
+/// The statistics of a [ModuleCache](crate::api::ModuleCache).
+class ModuleCacheStats {
+  /// The number of modules in the cache.
+  final int entries;
+
+  /// The sum of the sizes of the binaries of the modules in the cache.
+  final int sizeBytes;
+
+  /// The number of modules returned from the cache without compiling them.
+  final int hits;
+
+  /// The number of modules compiled, or loaded from disk,
+  /// because they were not in the cache.
+  final int misses;
+
+  /// The number of modules removed to respect the limits of the cache.
+  final int evictions;
+
+  const ModuleCacheStats({
+    required this.entries,
+    required this.sizeBytes,
+    required this.hits,
+    required this.misses,
+    required this.evictions,
+  });
+}
+
 class ModuleConfig {
   /// Is `true` if the [`multi-value`] Wasm proposal is enabled.
   final bool? multiValue;
@@ -996,6 +1714,50 @@ class ModuleConfig {
   /// Configuration specific to the wasmtime runtime
   final ModuleConfigWasmtime? wasmtime;
 
+  /// The maximum size in bytes of the module binary.
+  /// Larger modules are rejected with [ModuleTooLarge] before compilation.
+  final int? maxModuleBytes;
+
+  /// The maximum number of pages for the minimum size of the memories
+  /// defined or imported by the module. Larger memories are rejected with
+  /// [MemoryTooLarge] before compilation, since they would be allocated
+  /// when instantiating the module.
+  final int? maxMemoryPages;
+
+  /// The maximum number of memories defined or imported by the module.
+  /// Modules declaring more are rejected with [DeclarationLimitExceeded]
+  /// before compilation. Mostly relevant with the multi-memory proposal.
+  final int? maxMemories;
+
+  /// The maximum number of tables defined or imported by the module.
+  /// Modules declaring more are rejected with [DeclarationLimitExceeded].
+  final int? maxTables;
+
+  /// The maximum number of globals defined or imported by the module.
+  /// Modules declaring more are rejected with [DeclarationLimitExceeded].
+  final int? maxGlobals;
+
+  /// The maximum number of functions defined or imported by the module.
+  /// Modules declaring more are rejected with [DeclarationLimitExceeded].
+  final int? maxFunctions;
+
+  /// Is `true` if a signed overflow in `i32.add` shall trap instead of wrapping.
+  /// Defaults to `false`, the wrapping semantics of the Wasm specification.
+  /// Neither wasmi nor wasmtime support this natively, the module binary
+  /// is instrumented with overflow checks before compilation, which
+  /// increases the code size and the execution time.
+  final bool? trapOnIntegerOverflow;
+
+  /// Is `true` if the module shall produce the same results on every host.
+  /// Enables [ModuleConfigWasmtime.cranelift_nan_canonicalization] and
+  /// [ModuleConfigWasmtime.relaxed_simd_deterministic] and disables
+  /// [ModuleConfigWasmtime.wasm_threads]. Compiling fails if one of them
+  /// is explicitly configured with the opposite value.
+  /// Use [WasiConfigNative.deterministic] for the WASI clocks and random values.
+  /// Only supported by wasmtime, wasmi does not implement threads
+  /// or relaxed SIMD and ignores it.
+  final bool? deterministic;
+
   const ModuleConfig({
     this.multiValue,
     this.bulkMemory,
@@ -1003,6 +1765,14 @@ class ModuleConfig {
     this.consumeFuel,
     this.wasmi,
     this.wasmtime,
+    this.maxModuleBytes,
+    this.maxMemoryPages,
+    this.maxMemories,
+    this.maxTables,
+    this.maxGlobals,
+    this.maxFunctions,
+    this.trapOnIntegerOverflow,
+    this.deterministic,
   });
 }
 
@@ -1031,6 +1801,10 @@ class ModuleConfigWasmi {
   /// Is `true` if Wasm instructions on `f32` and `f64` types are allowed.
   final bool? floats;
 
+  /// How fuel is consumed when [ModuleConfig.consume_fuel] is enabled.
+  /// Defaults to [FuelConsumptionMode.lazy], wasmi's default.
+  final FuelConsumptionMode? fuelConsumptionMode;
+
   const ModuleConfigWasmi({
     this.stackLimits,
     this.cachedStacks,
@@ -1040,6 +1814,7 @@ class ModuleConfigWasmi {
     this.tailCall,
     this.extendedConst,
     this.floats,
+    this.fuelConsumptionMode,
   });
 }
 
@@ -1049,9 +1824,22 @@ class ModuleConfigWasmtime {
   final bool? debugInfo;
   final bool? wasmBacktrace;
   final bool? nativeUnwindInfo;
-  final int? maxWasmStack;
 
-  /// Whether or not to enable the `threads` WebAssembly feature.
+  /// Whether the functions are interrupted with a trap when the epoch of
+  /// the engine reaches the deadline of the store, see
+  /// [WasmRunModuleId::set_epoch_deadline] and [WasmEngine::increment_epoch].
+  /// Unlike [ModuleConfig::consume_fuel], it is driven by a timer
+  /// instead of the executed instructions.
+  /// This is not enabled by default.
+  final bool? epochInterruption;
+
+  /// The maximum size in bytes of the native stack used by Wasm code.
+  /// Must be greater than 0, defaults to 512 KiB.
+  /// The pooling instance allocator and async stacks are not enabled,
+  /// so there is no per-instance stack size to configure along with it.
+  final int? maxWasmStack;
+
+  /// Whether or not to enable the `threads` WebAssembly feature.
   /// This includes atomics and shared memory as well.
   /// This is not enabled by default.
   final bool? wasmThreads;
@@ -1067,6 +1855,16 @@ class ModuleConfigWasmtime {
   /// This is false by default.
   final bool? relaxedSimdDeterministic;
 
+  /// Whether all NaN values produced by floating point operations
+  /// should be canonicalized to a single bit pattern.
+  /// This is false by default.
+  ///
+  /// Enabling this makes float results reproducible across hosts
+  /// at a small runtime cost. It does not make every float operation
+  /// deterministic, for example, relaxed SIMD instructions are only
+  /// deterministic if [relaxed_simd_deterministic] is also enabled.
+  final bool? craneliftNanCanonicalization;
+
   /// Whether or not to enable the `multi-memory` WebAssembly feature.
   /// This is not enabled by default.
   final bool? wasmMultiMemory;
@@ -1074,28 +1872,93 @@ class ModuleConfigWasmtime {
   /// Whether or not to enable the `memory64` WebAssembly feature.
   /// This is not enabled by default.
   final bool? wasmMemory64;
+
+  /// Whether or not to enable the `tail-call` WebAssembly feature.
+  /// This is not enabled by default.
+  final bool? wasmTailCall;
+
+  /// Whether or not to enable the `function-references` WebAssembly feature,
+  /// typed function references such as `(ref $t)` and the `call_ref` instruction.
+  /// Depends on the `reference-types` feature. This is not enabled by default.
+  final bool? wasmFunctionReferences;
+
+  /// Whether or not to enable the `memory-control` WebAssembly feature.
+  /// This is not enabled by default.
+  /// Enabling it returns an error while the runtime does not support it,
+  /// see [WasmFeatures::supported].
+  final bool? wasmMemoryControl;
+
+  /// Whether or not to enable the `gc` WebAssembly feature.
+  /// This is not enabled by default.
+  /// Enabling it returns an error while the runtime does not support it,
+  /// see [WasmFeatures::supported].
+  final bool? wasmGc;
   final int? staticMemoryMaximumSize;
   final bool? staticMemoryForced;
   final int? staticMemoryGuardSize;
   final bool? parallelCompilation;
+
+  /// The stack size in bytes of the threads that compile the module in
+  /// [compile_wasm], including the workers of [parallel_compilation].
+  /// Increase it if the compilation of deep modules, for example,
+  /// large generated functions, overflows the stack.
+  /// Must be greater than 0. Defaults to the stack size of the
+  /// Rayon threads, 2 MiB unless `RUST_MIN_STACK` is set.
+  final int? compilationThreadStackSize;
   final bool? generateAddressMap;
 
+  /// Whether to disable the guard pages of linear memories, so that every
+  /// load and store is bounds checked by the compiled code instead of
+  /// faulting on the guard pages. This is false by default.
+  ///
+  /// When true, memories are not reserved up front, which is slower,
+  /// often by 10 to 20%, but reserves less virtual memory and out of
+  /// bounds accesses do not reach the `SIGSEGV` handler.
+  /// This does not run modules without signal-based traps: wasmtime 14.0.4
+  /// has no `signals_based_traps` setting and still relies on its signal
+  /// handlers for other traps, for example, stack overflows.
+  /// Conflicts with the `static_memory_*` fields.
+  final bool? disableGuardPages;
+
+  /// Whether traps are handled with Mach ports instead of signals on macOS.
+  /// This is true by default, disable it if the host already uses
+  /// Mach exception ports, for example, a crash reporter.
+  /// Ignored on other platforms.
+  final bool? macosUseMachPorts;
+
+  /// Proposals enabled or disabled by name, for the proposals supported by
+  /// the runtime that do not have a dedicated field.
+  /// The names recognized by wasmtime 14.0.4 are in [EXTRA_PROPOSALS],
+  /// unknown names are ignored with a warning printed to stderr.
+  /// [ModuleConfig::wasm_features] reflects the recognized toggles.
+  final List<ProposalToggle> extraProposals;
+
   const ModuleConfigWasmtime({
     this.debugInfo,
     this.wasmBacktrace,
     this.nativeUnwindInfo,
+    this.epochInterruption,
     this.maxWasmStack,
     this.wasmThreads,
     this.wasmSimd,
     this.wasmRelaxedSimd,
     this.relaxedSimdDeterministic,
+    this.craneliftNanCanonicalization,
     this.wasmMultiMemory,
     this.wasmMemory64,
+    this.wasmTailCall,
+    this.wasmFunctionReferences,
+    this.wasmMemoryControl,
+    this.wasmGc,
     this.staticMemoryMaximumSize,
     this.staticMemoryForced,
     this.staticMemoryGuardSize,
     this.parallelCompilation,
+    this.compilationThreadStackSize,
     this.generateAddressMap,
+    this.disableGuardPages,
+    this.macosUseMachPorts,
+    required this.extraProposals,
   });
 }
 
@@ -1103,9 +1966,15 @@ class ModuleExportDesc {
   final String name;
   final ExternalType ty;
 
+  /// The position of the export in the exports of the module,
+  /// which is the same in the exports of its instances.
+  /// Use it with [WasmRunModuleId::call_by_index].
+  final int index;
+
   const ModuleExportDesc({
     required this.name,
     required this.ty,
+    required this.index,
   });
 }
 
@@ -1176,9 +2045,48 @@ class PreopenedDir {
   /// and corresponds to the [wasm_guest_path]
   final String hostPath;
 
+  /// The WASI rights allowed for the directory, as a bitset of the
+  /// `rights` flags from `wasi_snapshot_preview1`. For example,
+  /// `fd_read (1 << 1) | path_open (1 << 13) | fd_readdir (1 << 14)` allows
+  /// reading files and listing directories, but not creating or removing them.
+  /// All operations are allowed when it is `null`.
+  ///
+  /// In wasmi, the rights are mapped to the capabilities of wasi-common.
+  final int? rights;
+
+  /// Whether the directory is stacked on top of the previous preopened
+  /// directories with the same [wasm_guest_path], instead of being
+  /// preopened as a separate directory.
+  ///
+  /// Reads fall through the layers: a path is resolved in the top layer
+  /// first and then in the lower ones, and the directories in several
+  /// layers are listed merged. Writes only go to the top layer, new files
+  /// and directories are created in it, and a file that only exists in a
+  /// lower layer is copied up to the top layer before it is modified.
+  /// The paths that exist in a lower layer fail with `EROFS` when
+  /// renamed or removed.
+  ///
+  /// Not supported in wasmi.
+  final bool overlay;
+
   const PreopenedDir({
     required this.wasmGuestPath,
     required this.hostPath,
+    this.rights,
+    required this.overlay,
+  });
+}
+
+/// A WebAssembly proposal enabled or disabled by name,
+/// see [ModuleConfigWasmtime::extra_proposals].
+class ProposalToggle {
+  /// The name of the proposal, for example, `component-model`.
+  final String name;
+  final bool enabled;
+
+  const ProposalToggle({
+    required this.name,
+    required this.enabled,
   });
 }
 
@@ -1279,13 +2187,24 @@ class WasiConfigNative {
   /// getter to retrieve a stream of the module's stderr.
   final bool captureStderr;
 
+  /// Whether to send the captured stdout and stderr line by line,
+  /// without the `\n` or `\r\n` terminators.
+  /// The last line is sent when the module exits or the instance is disposed.
+  /// Lines longer than 64 KiB are sent in chunks.
+  /// Not supported in the web.
+  final bool lineBufferedOutput;
+
   /// Whether to inherit stdin from the host process.
   final bool inheritStdin;
 
   /// Whether to inherit environment variables from the host process.
+  /// The variables in [env] replace the inherited ones with the same name.
+  /// Prefer [WasiConfigNative::set_process], which sets it with [env].
   final bool inheritEnv;
 
   /// Whether to inherit the process arguments from the host process.
+  /// The arguments in [args] are passed after the inherited ones.
+  /// Prefer [WasiConfigNative::set_process], which sets it with [args].
   final bool inheritArgs;
 
   /// Custom process arguments to pass to the WASM module
@@ -1301,9 +2220,34 @@ class WasiConfigNative {
   /// The module will be able to access and edit these directories
   final List<PreopenedDir> preopenedDirs;
 
+  /// The maximum number of bytes of captured stdout and stderr kept
+  /// in memory until they are read with [WasmRunModuleId.readStdio].
+  /// When it is set, the captured output is not sent to the
+  /// [WasmInstance.stdout] and [WasmInstance.stderr] streams and
+  /// [capture_overflow] selects what happens when the buffer is full.
+  /// When it is null, the output is sent to the streams without a bound.
+  final int? captureCapacity;
+
+  /// What happens when the module writes to a full capture buffer,
+  /// see [capture_capacity].
+  final CaptureOverflow captureOverflow;
+
+  /// Whether the WASI functions return the same values on every execution:
+  /// `random_get` returns the bytes of a random generator with a fixed seed,
+  /// the realtime clock is always the Unix epoch and the monotonic clock
+  /// does not advance. Use it with [ModuleConfig.deterministic].
+  /// Not supported by wasmi.
+  final bool deterministic;
+
+  /// The errno code of the [WasiError]s for host IO errors without
+  /// a WASI equivalent, for example, when a preopened directory
+  /// can not be opened. When it is null, they are mapped to `EIO` (29).
+  final int? unknownErrno;
+
   const WasiConfigNative({
     required this.captureStdout,
     required this.captureStderr,
+    required this.lineBufferedOutput,
     required this.inheritStdin,
     required this.inheritEnv,
     required this.inheritArgs,
@@ -1311,9 +2255,28 @@ class WasiConfigNative {
     required this.env,
     required this.preopenedFiles,
     required this.preopenedDirs,
+    this.captureCapacity,
+    required this.captureOverflow,
+    required this.deterministic,
+    this.unknownErrno,
   });
 }
 
+/// How a module is initialized with [WasmRunModuleId.initialize],
+/// from its `_initialize` and `_start` exports without parameters or results.
+enum WasiModuleKind {
+  /// Exports `_start`, which executes the program once and exits.
+  /// Its other exports should not be called after `_start`.
+  command,
+
+  /// Exports `_initialize`, which is called once before its other exports.
+  /// `_start` is never called for reactors, even if it is exported.
+  reactor,
+
+  /// Exports neither, its exports are called without an initialization.
+  library,
+}
+
 /// The configured limits of the Wasm stack.
 class WasiStackLimits {
   /// The initial value stack height that the Wasm stack prepares.
@@ -1332,6 +2295,79 @@ class WasiStackLimits {
   });
 }
 
+/// A compilation environment that can be shared across many modules.
+/// All the modules compiled with the same engine, and the stores
+/// instantiated from them, share the [ModuleConfig] used to create it.
+class WasmEngine {
+  final WasmRunDart bridge;
+  final WEngine field0;
+
+  const WasmEngine({
+    required this.bridge,
+    required this.field0,
+  });
+
+  /// Compiles [module_wasm] with the [ModuleConfig] of the engine, like [compile_wasm].
+  Future<CompiledModule> compileWasm(
+          {required Uint8List moduleWasm, dynamic hint}) =>
+      bridge.compileWasmMethodWasmEngine(
+        that: this,
+        moduleWasm: moduleWasm,
+      );
+
+  CompiledModule compileWasmSync(
+          {required Uint8List moduleWasm, dynamic hint}) =>
+      bridge.compileWasmSyncMethodWasmEngine(
+        that: this,
+        moduleWasm: moduleWasm,
+      );
+
+  /// Increments the epoch of the engine, interrupting the functions of the
+  /// stores that reached their epoch deadline when epoch interruption is enabled.
+  /// Meant to be called from a timer thread with a custom cadence,
+  /// it is shared by all the stores of the modules compiled with this engine.
+  void incrementEpoch({dynamic hint}) => bridge.incrementEpochMethodWasmEngine(
+        that: this,
+      );
+
+  /// Creates a [WasmLinker] for the modules compiled with this engine.
+  /// The WASI imports are defined once in the linker if [wasi] is `true`.
+  WasmLinker createLinker({required bool wasi, dynamic hint}) =>
+      bridge.createLinkerMethodWasmEngine(
+        that: this,
+        wasi: wasi,
+      );
+
+  /// Creates a [ModuleCache] for the modules compiled with this engine.
+  /// The least recently used modules are evicted when there are more than
+  /// [max_entries] modules or their binaries exceed [max_bytes] in total.
+  /// If [directory] is provided, the compiled artifacts are also stored
+  /// in it and loaded instead of compiling the module again, for example,
+  /// in the next run of the app. Loading an artifact runs its native code,
+  /// so the directory must only be writable by the app. The artifacts are
+  /// checked for corruption before they are loaded, not for tampering.
+  ModuleCache createModuleCache(
+          {int? maxEntries, int? maxBytes, String? directory, dynamic hint}) =>
+      bridge.createModuleCacheMethodWasmEngine(
+        that: this,
+        maxEntries: maxEntries,
+        maxBytes: maxBytes,
+        directory: directory,
+      );
+}
+
+/// The [FeatureStability] of the Wasm proposal named [feature],
+/// the name of the field in [WasmFeatures], for example, "relaxed_simd".
+class WasmFeatureStability {
+  final String feature;
+  final FeatureStability stability;
+
+  const WasmFeatureStability({
+    required this.feature,
+    required this.stability,
+  });
+}
+
 /// https://docs.wasmtime.dev/stability-wasm-proposals-support.html
 class WasmFeatures {
   /// The WebAssembly `mutable-global` proposal (enabled by default)
@@ -1377,6 +2413,11 @@ class WasmFeatures {
   /// runtimes may not desire.
   final bool floats;
 
+  /// Whether NaN values produced by floating point operations are
+  /// canonicalized, making [floats] results reproducible across hosts.
+  /// Only supported by wasmtime, see [ModuleConfigWasmtime.cranelift_nan_canonicalization].
+  final bool nanCanonicalization;
+
   /// The WebAssembly multi memory proposal
   final bool multiMemory;
 
@@ -1398,6 +2439,9 @@ class WasmFeatures {
   /// The WebAssembly garbage collection (GC) proposal
   final bool garbageCollection;
 
+  /// The WebAssembly typed function references proposal
+  final bool functionReferences;
+
   /// WebAssembly external types reflection or, for browsers,
   /// the js-types proposal (https://github.com/WebAssembly/js-types/blob/main/proposals/js-types/Overview.md)
   final bool typeReflection;
@@ -1417,6 +2461,7 @@ class WasmFeatures {
     required this.threads,
     required this.tailCall,
     required this.floats,
+    required this.nanCanonicalization,
     required this.multiMemory,
     required this.exceptions,
     required this.memory64,
@@ -1424,11 +2469,80 @@ class WasmFeatures {
     required this.componentModel,
     required this.memoryControl,
     required this.garbageCollection,
+    required this.functionReferences,
     required this.typeReflection,
     this.wasiFeatures,
   });
 }
 
+/// A linker with the WASI imports and host functions defined once
+/// and reused by all the modules instantiated with it,
+/// instead of defining them again for each module.
+/// Created with [WasmEngine::create_linker], it can only be used
+/// with the modules compiled by the same engine.
+///
+/// The host functions are shared by all the stores, so they are not
+/// bound to a module and their closures must be `Send + Sync`.
+class WasmLinker {
+  final WasmRunDart bridge;
+  final ArcStdSyncMutexLinkerTemplate field0;
+
+  const WasmLinker({
+    required this.bridge,
+    required this.field0,
+  });
+
+  /// Defines the host function [module]::[name] for all the modules
+  /// instantiated with this linker.
+  void defineHostFunction(
+          {required String module,
+          required String name,
+          required int functionPointer,
+          required int functionId,
+          required List<ValueTy> paramTypes,
+          required List<ValueTy> resultTypes,
+          dynamic hint}) =>
+      bridge.defineHostFunctionMethodWasmLinker(
+        that: this,
+        module: module,
+        name: name,
+        functionPointer: functionPointer,
+        functionId: functionId,
+        paramTypes: paramTypes,
+        resultTypes: resultTypes,
+      );
+
+  /// Defines the memory [module]::[name] with [memory_type] for all the modules
+  /// instantiated with this linker. A new memory is created for each module,
+  /// for example, for modules that import their memory as `env::memory`.
+  /// Shared memories should be created with [WasmRunModuleId::create_shared_memory]
+  /// and linked with [WasmRunModuleId::link_imports].
+  void defineMemory(
+          {required String module,
+          required String name,
+          required MemoryTy memoryType,
+          dynamic hint}) =>
+      bridge.defineMemoryMethodWasmLinker(
+        that: this,
+        module: module,
+        name: name,
+        memoryType: memoryType,
+      );
+
+  /// Creates a module builder for [module] using a clone of this linker.
+  /// [wasi_config] must be provided if, and only if,
+  /// the linker was created with WASI.
+  WasmRunModuleId moduleBuilder(
+          {required CompiledModule module,
+          WasiConfigNative? wasiConfig,
+          dynamic hint}) =>
+      bridge.moduleBuilderMethodWasmLinker(
+        that: this,
+        module: module,
+        wasiConfig: wasiConfig,
+      );
+}
+
 class WasmRunInstanceId {
   final WasmRunDart bridge;
   final int field0;
@@ -1448,11 +2562,13 @@ class WasmRunModuleId {
   final WasmRunDart bridge;
   final int field0;
   final CallStack field1;
+  final StdioCapture field2;
 
   const WasmRunModuleId({
     required this.bridge,
     required this.field0,
     required this.field1,
+    required this.field2,
   });
 
   WasmRunInstanceId instantiateSync({dynamic hint}) =>
@@ -1471,12 +2587,85 @@ class WasmRunModuleId {
         imports: imports,
       );
 
+  /// Instantiates [module] in the store of this module and defines its exports
+  /// under the [namespace] module name, so that this module can import them.
+  /// For example, to call the functions exported by a library module.
+  ///
+  /// [module] is instantiated with the imports defined so far, such as WASI
+  /// and [link_imports], and it should be compiled with the same [WasmEngine].
+  void linkModule(
+          {required String namespace,
+          required CompiledModule module,
+          dynamic hint}) =>
+      bridge.linkModuleMethodWasmRunModuleId(
+        that: this,
+        namespace: namespace,
+        module: module,
+      );
+
   Stream<Uint8List> stdioStream({required StdIOKind kind, dynamic hint}) =>
       bridge.stdioStreamMethodWasmRunModuleId(
         that: this,
         kind: kind,
       );
 
+  /// Sends the paths that the module tried to open and were denied by
+  /// the WASI sandbox, for example, paths outside of the preopened directories.
+  /// The module still receives the same WASI errno.
+  Stream<String> wasiDeniedStream({dynamic hint}) =>
+      bridge.wasiDeniedStreamMethodWasmRunModuleId(
+        that: this,
+      );
+
+  /// Returns and removes the stdout or stderr output kept in the capture buffer,
+  /// see [WasiConfigNative::capture_capacity].
+  Uint8List readStdio({required StdIOKind kind, dynamic hint}) =>
+      bridge.readStdioMethodWasmRunModuleId(
+        that: this,
+        kind: kind,
+      );
+
+  /// Waits up to [timeout_millis] for the next stdout or stderr output kept in
+  /// the capture buffer and removes it, see [WasiConfigNative::capture_capacity].
+  /// Returns an empty list when the timeout elapses without output and `None`
+  /// once all the output was returned after the module exited with
+  /// [WasmRunModuleId::call_wasi_start], or the module or the capture buffer was closed.
+  Future<Uint8List?> nextStdioChunk(
+          {required StdIOKind kind,
+          required int timeoutMillis,
+          dynamic hint}) =>
+      bridge.nextStdioChunkMethodWasmRunModuleId(
+        that: this,
+        kind: kind,
+        timeoutMillis: timeoutMillis,
+      );
+
+  /// The number of bytes of stdout or stderr dropped because
+  /// the capture buffer was full or closed.
+  int stdioDroppedBytes({required StdIOKind kind, dynamic hint}) =>
+      bridge.stdioDroppedBytesMethodWasmRunModuleId(
+        that: this,
+        kind: kind,
+      );
+
+  /// Stops capturing stdout or stderr in the capture buffer and frees it.
+  /// The following output of the module is dropped.
+  void closeStdio({required StdIOKind kind, dynamic hint}) =>
+      bridge.closeStdioMethodWasmRunModuleId(
+        that: this,
+        kind: kind,
+      );
+
+  /// Releases the store of the module at a well-defined point, instead of
+  /// relying on the order of the finalizers: flushes the remaining line buffered
+  /// output, closes the output streams and drops the WASI context,
+  /// which closes the preopened directories.
+  /// Further calls with the module return a [ModuleClosed] error.
+  /// Closing a closed module does nothing.
+  void close({dynamic hint}) => bridge.closeMethodWasmRunModuleId(
+        that: this,
+      );
+
   Future<void> dispose({dynamic hint}) => bridge.disposeMethodWasmRunModuleId(
         that: this,
       );
@@ -1489,6 +2678,11 @@ class WasmRunModuleId {
         args: args,
       );
 
+  /// Calls [func] in a thread outside of the Dart isolate,
+  /// so that long running calls do not block it.
+  /// Host functions are executed in the Dart isolate, calling them
+  /// within this function traps. Use [call_function_handle_sync] for
+  /// functions that call host functions.
   Future<List<WasmVal>> callFunctionHandle(
           {required WFunc func, required List<WasmVal> args, dynamic hint}) =>
       bridge.callFunctionHandleMethodWasmRunModuleId(
@@ -1497,6 +2691,108 @@ class WasmRunModuleId {
         args: args,
       );
 
+  /// Calls the function at [index] in the exports of the instance,
+  /// see [ModuleExportDesc.index], for example, when the name of the export
+  /// is not known. Returns an error if [index] is out of range or the export
+  /// is not a function. The functions of a table can be called with
+  /// [get_table] and [call_function_handle_sync].
+  List<WasmVal> callByIndex(
+          {required int index, required List<WasmVal> args, dynamic hint}) =>
+      bridge.callByIndexMethodWasmRunModuleId(
+        that: this,
+        index: index,
+        args: args,
+      );
+
+  /// Calls the WASI `_start` [func] and returns the exit code of the execution.
+  /// The exit code is the one passed to `proc_exit`, or 0 if `_start` returns
+  /// without calling it. This does not depend on the stdout and stderr configuration.
+  /// When it returns, the captured output is finished, see [WasmRunModuleId::next_stdio_chunk].
+  int callWasiStart({required WFunc func, dynamic hint}) =>
+      bridge.callWasiStartMethodWasmRunModuleId(
+        that: this,
+        func: func,
+      );
+
+  /// Initializes the instance according to its [WasiModuleKind].
+  /// Calls `_initialize` for reactors and `_start` for commands, returning
+  /// its exit code as in [call_wasi_start]. Libraries are not initialized.
+  /// Returns None for reactors and libraries.
+  int? initialize({dynamic hint}) => bridge.initializeMethodWasmRunModuleId(
+        that: this,
+      );
+
+  /// Calls the function [name] exported by a reactor or a library,
+  /// after [initialize]. Returns an error for `_initialize` and `_start`,
+  /// which are called by [initialize], and for the exports of commands.
+  List<WasmVal> callExport(
+          {required String name, required List<WasmVal> args, dynamic hint}) =>
+      bridge.callExportMethodWasmRunModuleId(
+        that: this,
+        name: name,
+        args: args,
+      );
+
+  /// Allocates [size] bytes in the memory of the module with its exported
+  /// allocator and returns the pointer, for example, to write a string
+  /// with [WasmRunModuleId::write_memory] before passing it to a function.
+  /// [alloc_export] is the name of the allocation function, by default `malloc`,
+  /// or `cabi_realloc` when the module does not export `malloc`.
+  /// Functions with one parameter are called with [size], like `malloc`,
+  /// and functions with four parameters are called like
+  /// `cabi_realloc(0, 0, 8, size)`, with an alignment of 8 bytes.
+  int guestAlloc({required int size, String? allocExport, dynamic hint}) =>
+      bridge.guestAllocMethodWasmRunModuleId(
+        that: this,
+        size: size,
+        allocExport: allocExport,
+      );
+
+  /// Frees the [size] bytes at [ptr] allocated with [WasmRunModuleId::guest_alloc].
+  /// [free_export] is the name of the function, by default `free`.
+  /// Functions with one parameter are called with [ptr], like `free`,
+  /// and functions with two parameters are called with [ptr] and [size].
+  void guestFree(
+          {required int ptr,
+          required int size,
+          String? freeExport,
+          dynamic hint}) =>
+      bridge.guestFreeMethodWasmRunModuleId(
+        that: this,
+        ptr: ptr,
+        size: size,
+        freeExport: freeExport,
+      );
+
+  /// Copies the UTF-8 bytes of [value] to the memory exported by the module,
+  /// allocated with the exported `cabi_realloc` function like the canonical ABI
+  /// does to pass strings, and returns their location.
+  /// [memory_name] is required when the module exports more than one memory,
+  /// see [WasmRunModuleId::memories].
+  PointerAndLength writeGuestString(
+          {required String value, String? memoryName, dynamic hint}) =>
+      bridge.writeGuestStringMethodWasmRunModuleId(
+        that: this,
+        value: value,
+        memoryName: memoryName,
+      );
+
+  /// Reads the string of [len] UTF-8 bytes at [ptr] in the memory exported
+  /// by the module. Returns an error if the bytes are not valid UTF-8.
+  /// [memory_name] is required when the module exports more than one memory,
+  /// see [WasmRunModuleId::memories].
+  String readGuestString(
+          {required int ptr,
+          required int len,
+          String? memoryName,
+          dynamic hint}) =>
+      bridge.readGuestStringMethodWasmRunModuleId(
+        that: this,
+        ptr: ptr,
+        len: len,
+        memoryName: memoryName,
+      );
+
   Stream<ParallelExec> callFunctionHandleParallel(
           {required String funcName,
           required List<WasmVal> args,
@@ -1595,12 +2891,17 @@ class WasmRunModuleId {
         memory: memory,
       );
 
+  /// The address of the data of [memory]. It may change when the memory grows,
+  /// including during a call into the guest, so it should not be kept
+  /// across calls. Use [read_memory] and [write_memory] to copy the data.
   int getMemoryDataPointer({required Memory memory, dynamic hint}) =>
       bridge.getMemoryDataPointerMethodWasmRunModuleId(
         that: this,
         memory: memory,
       );
 
+  /// The address and length of the data of [memory],
+  /// see [get_memory_data_pointer].
   PointerAndLength getMemoryDataPointerAndLength(
           {required Memory memory, dynamic hint}) =>
       bridge.getMemoryDataPointerAndLengthMethodWasmRunModuleId(
@@ -1702,6 +3003,28 @@ class WasmRunModuleId {
         len: len,
       );
 
+  /// Limits the number of nested host function calls in the store to [max_depth],
+  /// for example, when a host function calls back into the guest which calls
+  /// it again. The call that exceeds it traps with [ReentrancyLimitExceeded].
+  /// `max_wasm_stack` only limits the Wasm frames, not the host frames between them.
+  /// Defaults to `None`, which does not limit them.
+  void setMaxHostCallDepth({int? maxDepth, dynamic hint}) =>
+      bridge.setMaxHostCallDepthMethodWasmRunModuleId(
+        that: this,
+        maxDepth: maxDepth,
+      );
+
+  /// Interrupts the functions of the module with an `interrupt` trap when
+  /// the epoch of its engine is incremented [ticks_beyond_current] times
+  /// from now, see [WasmEngine::increment_epoch]. `None` removes the deadline,
+  /// which is the default. Requires [ModuleConfigWasmtime::epoch_interruption].
+  /// The calls executed in the threads of `num_threads` are not interrupted.
+  void setEpochDeadline({int? ticksBeyondCurrent, dynamic hint}) =>
+      bridge.setEpochDeadlineMethodWasmRunModuleId(
+        that: this,
+        ticksBeyondCurrent: ticksBeyondCurrent,
+      );
+
   void addFuel({required int delta, dynamic hint}) =>
       bridge.addFuelMethodWasmRunModuleId(
         that: this,
@@ -1717,6 +3040,40 @@ class WasmRunModuleId {
         that: this,
         delta: delta,
       );
+
+  /// The fuel consumed by the last call to [call_function_handle],
+  /// including the fuel consumed by nested calls made from host functions.
+  /// Returns None if `consume_fuel` is not enabled or no function was called.
+  int? lastCallFuelConsumed({dynamic hint}) =>
+      bridge.lastCallFuelConsumedMethodWasmRunModuleId(
+        that: this,
+      );
+
+  /// The wall-clock duration in microseconds of the last call to [call_function_handle].
+  /// Returns None if no function was called.
+  int? lastCallDurationMicros({dynamic hint}) =>
+      bridge.lastCallDurationMicrosMethodWasmRunModuleId(
+        that: this,
+      );
+
+  /// The memory used by the instance, the sum of the sizes of its memories
+  /// and an estimate of the overhead of its tables and globals.
+  /// The sizes are tracked by the resource limiter of the store, so they
+  /// include the memories and tables that are not exported.
+  /// The instances are allocated on demand, there is no pooling allocator
+  /// with slots to report.
+  MemoryFootprint memoryFootprint({dynamic hint}) =>
+      bridge.memoryFootprintMethodWasmRunModuleId(
+        that: this,
+      );
+
+  /// The names of the memories exported by the module, in export order.
+  /// The functions that access the memory of the module without receiving it,
+  /// like [WasmRunModuleId::read_guest_string], require the name of the memory
+  /// when there is more than one. WASI always uses the memory exported as `memory`.
+  List<String> memories({dynamic hint}) => bridge.memoriesMethodWasmRunModuleId(
+        that: this,
+      );
 }
 
 class WasmRunSharedMemory {
@@ -1844,12 +3201,16 @@ class WasmRuntimeFeatures {
   /// and it may be experimental.
   final WasmFeatures defaultFeatures;
 
+  /// The [FeatureStability] of each Wasm proposal in the runtime.
+  final List<WasmFeatureStability> stability;
+
   const WasmRuntimeFeatures({
     required this.name,
     required this.version,
     required this.isBrowser,
     required this.supportedFeatures,
     required this.defaultFeatures,
+    required this.stability,
   });
 }
 
@@ -1980,6 +3341,80 @@ class WasmRunDartImpl implements WasmRunDart {
         argNames: ["wat"],
       );
 
+  Uint8List clampMemory(
+      {required Uint8List moduleWasm, required int maxPages, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(moduleWasm);
+    var arg1 = _platform.api2wire_u64(maxPages);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_clamp_memory(arg0, arg1),
+      parseSuccessData: _wire2api_uint_8_list,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kClampMemoryConstMeta,
+      argValues: [moduleWasm, maxPages],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kClampMemoryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "clamp_memory",
+        argNames: ["moduleWasm", "maxPages"],
+      );
+
+  bool moduleHasStart({required Uint8List moduleWasm, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(moduleWasm);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_module_has_start(arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kModuleHasStartConstMeta,
+      argValues: [moduleWasm],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kModuleHasStartConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "module_has_start",
+        argNames: ["moduleWasm"],
+      );
+
+  Uint8List moduleContentHash({required Uint8List moduleWasm, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(moduleWasm);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_module_content_hash(arg0),
+      parseSuccessData: _wire2api_uint_8_list,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kModuleContentHashConstMeta,
+      argValues: [moduleWasm],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kModuleContentHashConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "module_content_hash",
+        argNames: ["moduleWasm"],
+      );
+
+  WasmEngine createEngine({required ModuleConfig config, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_module_config(config);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_create_engine(arg0),
+      parseSuccessData: _wire2api_wasm_engine,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCreateEngineConstMeta,
+      argValues: [config],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateEngineConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_engine",
+        argNames: ["config"],
+      );
+
   Future<CompiledModule> compileWasm(
       {required Uint8List moduleWasm,
       required ModuleConfig config,
@@ -2024,6 +3459,48 @@ class WasmRunDartImpl implements WasmRunDart {
         argNames: ["moduleWasm", "config"],
       );
 
+  CompileHandle createCompileHandle({dynamic hint}) {
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_create_compile_handle(),
+      parseSuccessData: _wire2api_compile_handle,
+      parseErrorData: null,
+      constMeta: kCreateCompileHandleConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateCompileHandleConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_compile_handle",
+        argNames: [],
+      );
+
+  Future<CompiledModule> compileWasmCancelable(
+      {required Uint8List moduleWasm,
+      required ModuleConfig config,
+      required CompileHandle handle,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(moduleWasm);
+    var arg1 = _platform.api2wire_box_autoadd_module_config(config);
+    var arg2 = _platform.api2wire_box_autoadd_compile_handle(handle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_compile_wasm_cancelable(port_, arg0, arg1, arg2),
+      parseSuccessData: (d) => _wire2api_compiled_module(d),
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCompileWasmCancelableConstMeta,
+      argValues: [moduleWasm, config, handle],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmCancelableConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "compile_wasm_cancelable",
+        argNames: ["moduleWasm", "config", "handle"],
+      );
+
   WasmFeatures wasmFeaturesForConfig(
       {required ModuleConfig config, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_module_config(config);
@@ -2146,16 +3623,42 @@ class WasmRunDartImpl implements WasmRunDart {
             argNames: ["that", "imports"],
           );
 
-  Stream<Uint8List> stdioStreamMethodWasmRunModuleId(
-      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint}) {
+  void linkModuleMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required String namespace,
+      required CompiledModule module,
+      dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
-    var arg1 = api2wire_std_io_kind(kind);
-    return _platform.executeStream(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_stdio_stream__method__WasmRunModuleId(port_, arg0, arg1),
-      parseSuccessData: _wire2api_uint_8_list,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kStdioStreamMethodWasmRunModuleIdConstMeta,
+    var arg1 = _platform.api2wire_String(namespace);
+    var arg2 = _platform.api2wire_box_autoadd_compiled_module(module);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_link_module__method__WasmRunModuleId(arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kLinkModuleMethodWasmRunModuleIdConstMeta,
+      argValues: [that, namespace, module],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kLinkModuleMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "link_module__method__WasmRunModuleId",
+            argNames: ["that", "namespace", "module"],
+          );
+
+  Stream<Uint8List> stdioStreamMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_std_io_kind(kind);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_stdio_stream__method__WasmRunModuleId(port_, arg0, arg1),
+      parseSuccessData: _wire2api_uint_8_list,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStdioStreamMethodWasmRunModuleIdConstMeta,
       argValues: [that, kind],
       hint: hint,
     ));
@@ -2168,6 +3671,138 @@ class WasmRunDartImpl implements WasmRunDart {
             argNames: ["that", "kind"],
           );
 
+  Stream<String> wasiDeniedStreamMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_wasi_denied_stream__method__WasmRunModuleId(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kWasiDeniedStreamMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kWasiDeniedStreamMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "wasi_denied_stream__method__WasmRunModuleId",
+            argNames: ["that"],
+          );
+
+  Uint8List readStdioMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_std_io_kind(kind);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_read_stdio__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_uint_8_list,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReadStdioMethodWasmRunModuleIdConstMeta,
+      argValues: [that, kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReadStdioMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "read_stdio__method__WasmRunModuleId",
+        argNames: ["that", "kind"],
+      );
+
+  Future<Uint8List?> nextStdioChunkMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required StdIOKind kind,
+      required int timeoutMillis,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_std_io_kind(kind);
+    var arg2 = _platform.api2wire_u64(timeoutMillis);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_next_stdio_chunk__method__WasmRunModuleId(
+              port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_opt_uint_8_list,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kNextStdioChunkMethodWasmRunModuleIdConstMeta,
+      argValues: [that, kind, timeoutMillis],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kNextStdioChunkMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "next_stdio_chunk__method__WasmRunModuleId",
+            argNames: ["that", "kind", "timeoutMillis"],
+          );
+
+  int stdioDroppedBytesMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_std_io_kind(kind);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_stdio_dropped_bytes__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_u64,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStdioDroppedBytesMethodWasmRunModuleIdConstMeta,
+      argValues: [that, kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kStdioDroppedBytesMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "stdio_dropped_bytes__method__WasmRunModuleId",
+            argNames: ["that", "kind"],
+          );
+
+  void closeStdioMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required StdIOKind kind, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_std_io_kind(kind);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_close_stdio__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCloseStdioMethodWasmRunModuleIdConstMeta,
+      argValues: [that, kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCloseStdioMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "close_stdio__method__WasmRunModuleId",
+            argNames: ["that", "kind"],
+          );
+
+  void closeMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_close__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCloseMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCloseMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "close__method__WasmRunModuleId",
+        argNames: ["that"],
+      );
+
   Future<void> disposeMethodWasmRunModuleId(
       {required WasmRunModuleId that, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
@@ -2242,6 +3877,209 @@ class WasmRunDartImpl implements WasmRunDart {
             argNames: ["that", "func", "args"],
           );
 
+  List<WasmVal> callByIndexMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int index,
+      required List<WasmVal> args,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_u32(index);
+    var arg2 = _platform.api2wire_list_wasm_val(args);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_call_by_index__method__WasmRunModuleId(arg0, arg1, arg2),
+      parseSuccessData: _wire2api_list_wasm_val,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCallByIndexMethodWasmRunModuleIdConstMeta,
+      argValues: [that, index, args],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCallByIndexMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "call_by_index__method__WasmRunModuleId",
+            argNames: ["that", "index", "args"],
+          );
+
+  int callWasiStartMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required WFunc func, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_WFunc(func);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_call_wasi_start__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_i32,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCallWasiStartMethodWasmRunModuleIdConstMeta,
+      argValues: [that, func],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCallWasiStartMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "call_wasi_start__method__WasmRunModuleId",
+            argNames: ["that", "func"],
+          );
+
+  int? initializeMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_initialize__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_i32,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kInitializeMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kInitializeMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "initialize__method__WasmRunModuleId",
+            argNames: ["that"],
+          );
+
+  List<WasmVal> callExportMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required String name,
+      required List<WasmVal> args,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_String(name);
+    var arg2 = _platform.api2wire_list_wasm_val(args);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_call_export__method__WasmRunModuleId(arg0, arg1, arg2),
+      parseSuccessData: _wire2api_list_wasm_val,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCallExportMethodWasmRunModuleIdConstMeta,
+      argValues: [that, name, args],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCallExportMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "call_export__method__WasmRunModuleId",
+            argNames: ["that", "name", "args"],
+          );
+
+  int guestAllocMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int size,
+      String? allocExport,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_u32(size);
+    var arg2 = _platform.api2wire_opt_String(allocExport);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_guest_alloc__method__WasmRunModuleId(arg0, arg1, arg2),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kGuestAllocMethodWasmRunModuleIdConstMeta,
+      argValues: [that, size, allocExport],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGuestAllocMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "guest_alloc__method__WasmRunModuleId",
+            argNames: ["that", "size", "allocExport"],
+          );
+
+  void guestFreeMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int ptr,
+      required int size,
+      String? freeExport,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_u32(ptr);
+    var arg2 = api2wire_u32(size);
+    var arg3 = _platform.api2wire_opt_String(freeExport);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_guest_free__method__WasmRunModuleId(arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kGuestFreeMethodWasmRunModuleIdConstMeta,
+      argValues: [that, ptr, size, freeExport],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGuestFreeMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "guest_free__method__WasmRunModuleId",
+        argNames: ["that", "ptr", "size", "freeExport"],
+      );
+
+  PointerAndLength writeGuestStringMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required String value,
+      String? memoryName,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_String(value);
+    var arg2 = _platform.api2wire_opt_String(memoryName);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_write_guest_string__method__WasmRunModuleId(arg0, arg1, arg2),
+      parseSuccessData: _wire2api_pointer_and_length,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kWriteGuestStringMethodWasmRunModuleIdConstMeta,
+      argValues: [that, value, memoryName],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kWriteGuestStringMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "write_guest_string__method__WasmRunModuleId",
+            argNames: ["that", "value", "memoryName"],
+          );
+
+  String readGuestStringMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required int ptr,
+      required int len,
+      String? memoryName,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = api2wire_u32(ptr);
+    var arg2 = api2wire_u32(len);
+    var arg3 = _platform.api2wire_opt_String(memoryName);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_read_guest_string__method__WasmRunModuleId(
+              arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReadGuestStringMethodWasmRunModuleIdConstMeta,
+      argValues: [that, ptr, len, memoryName],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kReadGuestStringMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "read_guest_string__method__WasmRunModuleId",
+            argNames: ["that", "ptr", "len", "memoryName"],
+          );
+
   Stream<ParallelExec> callFunctionHandleParallelMethodWasmRunModuleId(
       {required WasmRunModuleId that,
       required String funcName,
@@ -2305,7 +4143,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_function_type__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_func_ty,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetFunctionTypeMethodWasmRunModuleIdConstMeta,
       argValues: [that, func],
       hint: hint,
@@ -2440,7 +4278,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_global_type__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_global_ty,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetGlobalTypeMethodWasmRunModuleIdConstMeta,
       argValues: [that, global],
       hint: hint,
@@ -2462,7 +4300,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_global_value__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_wasm_val,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetGlobalValueMethodWasmRunModuleIdConstMeta,
       argValues: [that, global],
       hint: hint,
@@ -2510,7 +4348,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_memory_type__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_memory_ty,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetMemoryTypeMethodWasmRunModuleIdConstMeta,
       argValues: [that, memory],
       hint: hint,
@@ -2532,7 +4370,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_memory_data__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_uint_8_list,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetMemoryDataMethodWasmRunModuleIdConstMeta,
       argValues: [that, memory],
       hint: hint,
@@ -2554,7 +4392,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_memory_data_pointer__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_usize,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetMemoryDataPointerMethodWasmRunModuleIdConstMeta,
       argValues: [that, memory],
       hint: hint,
@@ -2577,7 +4415,7 @@ class WasmRunDartImpl implements WasmRunDart {
           .wire_get_memory_data_pointer_and_length__method__WasmRunModuleId(
               arg0, arg1),
       parseSuccessData: _wire2api_pointer_and_length,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetMemoryDataPointerAndLengthMethodWasmRunModuleIdConstMeta,
       argValues: [that, memory],
       hint: hint,
@@ -2628,7 +4466,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_memory_pages__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_u32,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetMemoryPagesMethodWasmRunModuleIdConstMeta,
       argValues: [that, memory],
       hint: hint,
@@ -2704,7 +4542,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_table_size__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_u32,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetTableSizeMethodWasmRunModuleIdConstMeta,
       argValues: [that, table],
       hint: hint,
@@ -2726,7 +4564,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_table_type__method__WasmRunModuleId(arg0, arg1),
       parseSuccessData: _wire2api_table_ty,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetTableTypeMethodWasmRunModuleIdConstMeta,
       argValues: [that, table],
       hint: hint,
@@ -2779,7 +4617,7 @@ class WasmRunDartImpl implements WasmRunDart {
       callFfi: () => _platform.inner
           .wire_get_table__method__WasmRunModuleId(arg0, arg1, arg2),
       parseSuccessData: _wire2api_opt_box_autoadd_wasm_val,
-      parseErrorData: null,
+      parseErrorData: _wire2api_FrbAnyhowException,
       constMeta: kGetTableMethodWasmRunModuleIdConstMeta,
       argValues: [that, table, index],
       hint: hint,
@@ -2792,191 +4630,702 @@ class WasmRunDartImpl implements WasmRunDart {
         argNames: ["that", "table", "index"],
       );
 
-  void setTableMethodWasmRunModuleId(
-      {required WasmRunModuleId that,
-      required Table table,
-      required int index,
-      required WasmVal value,
+  void setTableMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required Table table,
+      required int index,
+      required WasmVal value,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_Table(table);
+    var arg2 = api2wire_u32(index);
+    var arg3 = _platform.api2wire_box_autoadd_wasm_val(value);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_set_table__method__WasmRunModuleId(arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetTableMethodWasmRunModuleIdConstMeta,
+      argValues: [that, table, index, value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetTableMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_table__method__WasmRunModuleId",
+        argNames: ["that", "table", "index", "value"],
+      );
+
+  void fillTableMethodWasmRunModuleId(
+      {required WasmRunModuleId that,
+      required Table table,
+      required int index,
+      required WasmVal value,
+      required int len,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_Table(table);
+    var arg2 = api2wire_u32(index);
+    var arg3 = _platform.api2wire_box_autoadd_wasm_val(value);
+    var arg4 = api2wire_u32(len);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_fill_table__method__WasmRunModuleId(
+          arg0, arg1, arg2, arg3, arg4),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kFillTableMethodWasmRunModuleIdConstMeta,
+      argValues: [that, table, index, value, len],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFillTableMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "fill_table__method__WasmRunModuleId",
+        argNames: ["that", "table", "index", "value", "len"],
+      );
+
+  void setMaxHostCallDepthMethodWasmRunModuleId(
+      {required WasmRunModuleId that, int? maxDepth, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_opt_box_autoadd_u32(maxDepth);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_set_max_host_call_depth__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetMaxHostCallDepthMethodWasmRunModuleIdConstMeta,
+      argValues: [that, maxDepth],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kSetMaxHostCallDepthMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "set_max_host_call_depth__method__WasmRunModuleId",
+            argNames: ["that", "maxDepth"],
+          );
+
+  void setEpochDeadlineMethodWasmRunModuleId(
+      {required WasmRunModuleId that, int? ticksBeyondCurrent, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_opt_box_autoadd_u64(ticksBeyondCurrent);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_set_epoch_deadline__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetEpochDeadlineMethodWasmRunModuleIdConstMeta,
+      argValues: [that, ticksBeyondCurrent],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kSetEpochDeadlineMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "set_epoch_deadline__method__WasmRunModuleId",
+            argNames: ["that", "ticksBeyondCurrent"],
+          );
+
+  void addFuelMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required int delta, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_u64(delta);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_add_fuel__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kAddFuelMethodWasmRunModuleIdConstMeta,
+      argValues: [that, delta],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAddFuelMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "add_fuel__method__WasmRunModuleId",
+        argNames: ["that", "delta"],
+      );
+
+  int? fuelConsumedMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_fuel_consumed__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_u64,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kFuelConsumedMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kFuelConsumedMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "fuel_consumed__method__WasmRunModuleId",
+            argNames: ["that"],
+          );
+
+  int consumeFuelMethodWasmRunModuleId(
+      {required WasmRunModuleId that, required int delta, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    var arg1 = _platform.api2wire_u64(delta);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_consume_fuel__method__WasmRunModuleId(arg0, arg1),
+      parseSuccessData: _wire2api_u64,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kConsumeFuelMethodWasmRunModuleIdConstMeta,
+      argValues: [that, delta],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kConsumeFuelMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "consume_fuel__method__WasmRunModuleId",
+            argNames: ["that", "delta"],
+          );
+
+  int? lastCallFuelConsumedMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_last_call_fuel_consumed__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_u64,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kLastCallFuelConsumedMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kLastCallFuelConsumedMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "last_call_fuel_consumed__method__WasmRunModuleId",
+            argNames: ["that"],
+          );
+
+  int? lastCallDurationMicrosMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_last_call_duration_micros__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_u64,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kLastCallDurationMicrosMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kLastCallDurationMicrosMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "last_call_duration_micros__method__WasmRunModuleId",
+            argNames: ["that"],
+          );
+
+  MemoryFootprint memoryFootprintMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_memory_footprint__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_memory_footprint,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kMemoryFootprintMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kMemoryFootprintMethodWasmRunModuleIdConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "memory_footprint__method__WasmRunModuleId",
+            argNames: ["that"],
+          );
+
+  List<String> memoriesMethodWasmRunModuleId(
+      {required WasmRunModuleId that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_memories__method__WasmRunModuleId(arg0),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kMemoriesMethodWasmRunModuleIdConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kMemoriesMethodWasmRunModuleIdConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "memories__method__WasmRunModuleId",
+        argNames: ["that"],
+      );
+
+  WasmRunModuleId instantiateMethodCompiledModule(
+      {required CompiledModule that,
+      WasiConfigNative? wasiConfig,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+    var arg1 =
+        _platform.api2wire_opt_box_autoadd_wasi_config_native(wasiConfig);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_instantiate__method__CompiledModule(arg0, arg1),
+      parseSuccessData: _wire2api_wasm_run_module_id,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kInstantiateMethodCompiledModuleConstMeta,
+      argValues: [that, wasiConfig],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kInstantiateMethodCompiledModuleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "instantiate__method__CompiledModule",
+            argNames: ["that", "wasiConfig"],
+          );
+
+  WasmRunSharedMemory createSharedMemoryMethodCompiledModule(
+      {required CompiledModule that,
+      required MemoryTy memoryType,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+    var arg1 = _platform.api2wire_box_autoadd_memory_ty(memoryType);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_create_shared_memory__method__CompiledModule(arg0, arg1),
+      parseSuccessData: _wire2api_wasm_run_shared_memory,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCreateSharedMemoryMethodCompiledModuleConstMeta,
+      argValues: [that, memoryType],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCreateSharedMemoryMethodCompiledModuleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "create_shared_memory__method__CompiledModule",
+            argNames: ["that", "memoryType"],
+          );
+
+  List<ModuleImportDesc> getModuleImportsMethodCompiledModule(
+      {required CompiledModule that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_get_module_imports__method__CompiledModule(arg0),
+      parseSuccessData: _wire2api_list_module_import_desc,
+      parseErrorData: null,
+      constMeta: kGetModuleImportsMethodCompiledModuleConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGetModuleImportsMethodCompiledModuleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "get_module_imports__method__CompiledModule",
+            argNames: ["that"],
+          );
+
+  WasiModuleKind wasiModuleKindMethodCompiledModule(
+      {required CompiledModule that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_wasi_module_kind__method__CompiledModule(arg0),
+      parseSuccessData: _wire2api_wasi_module_kind,
+      parseErrorData: null,
+      constMeta: kWasiModuleKindMethodCompiledModuleConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kWasiModuleKindMethodCompiledModuleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "wasi_module_kind__method__CompiledModule",
+            argNames: ["that"],
+          );
+
+  List<ModuleExportDesc> getModuleExportsMethodCompiledModule(
+      {required CompiledModule that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_get_module_exports__method__CompiledModule(arg0),
+      parseSuccessData: _wire2api_list_module_export_desc,
+      parseErrorData: null,
+      constMeta: kGetModuleExportsMethodCompiledModuleConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kGetModuleExportsMethodCompiledModuleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "get_module_exports__method__CompiledModule",
+            argNames: ["that"],
+          );
+
+  Future<CompiledModule> compileWasmMethodWasmEngine(
+      {required WasmEngine that, required Uint8List moduleWasm, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_engine(that);
+    var arg1 = _platform.api2wire_uint_8_list(moduleWasm);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_compile_wasm__method__WasmEngine(port_, arg0, arg1),
+      parseSuccessData: (d) => _wire2api_compiled_module(d),
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCompileWasmMethodWasmEngineConstMeta,
+      argValues: [that, moduleWasm],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCompileWasmMethodWasmEngineConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "compile_wasm__method__WasmEngine",
+        argNames: ["that", "moduleWasm"],
+      );
+
+  CompiledModule compileWasmSyncMethodWasmEngine(
+      {required WasmEngine that, required Uint8List moduleWasm, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_engine(that);
+    var arg1 = _platform.api2wire_uint_8_list(moduleWasm);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_compile_wasm_sync__method__WasmEngine(arg0, arg1),
+      parseSuccessData: _wire2api_compiled_module,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCompileWasmSyncMethodWasmEngineConstMeta,
+      argValues: [that, moduleWasm],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCompileWasmSyncMethodWasmEngineConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "compile_wasm_sync__method__WasmEngine",
+            argNames: ["that", "moduleWasm"],
+          );
+
+  void incrementEpochMethodWasmEngine(
+      {required WasmEngine that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_engine(that);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_increment_epoch__method__WasmEngine(arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kIncrementEpochMethodWasmEngineConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIncrementEpochMethodWasmEngineConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "increment_epoch__method__WasmEngine",
+        argNames: ["that"],
+      );
+
+  WasmLinker createLinkerMethodWasmEngine(
+      {required WasmEngine that, required bool wasi, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_engine(that);
+    var arg1 = wasi;
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () =>
+          _platform.inner.wire_create_linker__method__WasmEngine(arg0, arg1),
+      parseSuccessData: _wire2api_wasm_linker,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCreateLinkerMethodWasmEngineConstMeta,
+      argValues: [that, wasi],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateLinkerMethodWasmEngineConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_linker__method__WasmEngine",
+        argNames: ["that", "wasi"],
+      );
+
+  ModuleCache createModuleCacheMethodWasmEngine(
+      {required WasmEngine that,
+      int? maxEntries,
+      int? maxBytes,
+      String? directory,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_engine(that);
+    var arg1 = _platform.api2wire_opt_box_autoadd_u32(maxEntries);
+    var arg2 = _platform.api2wire_opt_box_autoadd_u64(maxBytes);
+    var arg3 = _platform.api2wire_opt_String(directory);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_create_module_cache__method__WasmEngine(arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_module_cache,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCreateModuleCacheMethodWasmEngineConstMeta,
+      argValues: [that, maxEntries, maxBytes, directory],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kCreateModuleCacheMethodWasmEngineConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "create_module_cache__method__WasmEngine",
+            argNames: ["that", "maxEntries", "maxBytes", "directory"],
+          );
+
+  void defineHostFunctionMethodWasmLinker(
+      {required WasmLinker that,
+      required String module,
+      required String name,
+      required int functionPointer,
+      required int functionId,
+      required List<ValueTy> paramTypes,
+      required List<ValueTy> resultTypes,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_wasm_linker(that);
+    var arg1 = _platform.api2wire_String(module);
+    var arg2 = _platform.api2wire_String(name);
+    var arg3 = api2wire_usize(functionPointer);
+    var arg4 = api2wire_u32(functionId);
+    var arg5 = _platform.api2wire_list_value_ty(paramTypes);
+    var arg6 = _platform.api2wire_list_value_ty(resultTypes);
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner
+          .wire_define_host_function__method__WasmLinker(
+              arg0, arg1, arg2, arg3, arg4, arg5, arg6),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDefineHostFunctionMethodWasmLinkerConstMeta,
+      argValues: [
+        that,
+        module,
+        name,
+        functionPointer,
+        functionId,
+        paramTypes,
+        resultTypes
+      ],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kDefineHostFunctionMethodWasmLinkerConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "define_host_function__method__WasmLinker",
+            argNames: [
+              "that",
+              "module",
+              "name",
+              "functionPointer",
+              "functionId",
+              "paramTypes",
+              "resultTypes"
+            ],
+          );
+
+  void defineMemoryMethodWasmLinker(
+      {required WasmLinker that,
+      required String module,
+      required String name,
+      required MemoryTy memoryType,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
-    var arg1 = _platform.api2wire_Table(table);
-    var arg2 = api2wire_u32(index);
-    var arg3 = _platform.api2wire_box_autoadd_wasm_val(value);
+    var arg0 = _platform.api2wire_box_autoadd_wasm_linker(that);
+    var arg1 = _platform.api2wire_String(module);
+    var arg2 = _platform.api2wire_String(name);
+    var arg3 = _platform.api2wire_box_autoadd_memory_ty(memoryType);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
       callFfi: () => _platform.inner
-          .wire_set_table__method__WasmRunModuleId(arg0, arg1, arg2, arg3),
+          .wire_define_memory__method__WasmLinker(arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_unit,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kSetTableMethodWasmRunModuleIdConstMeta,
-      argValues: [that, table, index, value],
+      constMeta: kDefineMemoryMethodWasmLinkerConstMeta,
+      argValues: [that, module, name, memoryType],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kSetTableMethodWasmRunModuleIdConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kDefineMemoryMethodWasmLinkerConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "set_table__method__WasmRunModuleId",
-        argNames: ["that", "table", "index", "value"],
+        debugName: "define_memory__method__WasmLinker",
+        argNames: ["that", "module", "name", "memoryType"],
       );
 
-  void fillTableMethodWasmRunModuleId(
-      {required WasmRunModuleId that,
-      required Table table,
-      required int index,
-      required WasmVal value,
-      required int len,
+  WasmRunModuleId moduleBuilderMethodWasmLinker(
+      {required WasmLinker that,
+      required CompiledModule module,
+      WasiConfigNative? wasiConfig,
       dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
-    var arg1 = _platform.api2wire_Table(table);
-    var arg2 = api2wire_u32(index);
-    var arg3 = _platform.api2wire_box_autoadd_wasm_val(value);
-    var arg4 = api2wire_u32(len);
+    var arg0 = _platform.api2wire_box_autoadd_wasm_linker(that);
+    var arg1 = _platform.api2wire_box_autoadd_compiled_module(module);
+    var arg2 =
+        _platform.api2wire_opt_box_autoadd_wasi_config_native(wasiConfig);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
-      callFfi: () => _platform.inner.wire_fill_table__method__WasmRunModuleId(
-          arg0, arg1, arg2, arg3, arg4),
-      parseSuccessData: _wire2api_unit,
+      callFfi: () => _platform.inner
+          .wire_module_builder__method__WasmLinker(arg0, arg1, arg2),
+      parseSuccessData: _wire2api_wasm_run_module_id,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kFillTableMethodWasmRunModuleIdConstMeta,
-      argValues: [that, table, index, value, len],
+      constMeta: kModuleBuilderMethodWasmLinkerConstMeta,
+      argValues: [that, module, wasiConfig],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kFillTableMethodWasmRunModuleIdConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kModuleBuilderMethodWasmLinkerConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "fill_table__method__WasmRunModuleId",
-        argNames: ["that", "table", "index", "value", "len"],
+        debugName: "module_builder__method__WasmLinker",
+        argNames: ["that", "module", "wasiConfig"],
       );
 
-  void addFuelMethodWasmRunModuleId(
-      {required WasmRunModuleId that, required int delta, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
-    var arg1 = _platform.api2wire_u64(delta);
-    return _platform.executeSync(FlutterRustBridgeSyncTask(
-      callFfi: () =>
-          _platform.inner.wire_add_fuel__method__WasmRunModuleId(arg0, arg1),
-      parseSuccessData: _wire2api_unit,
+  Future<CompiledModule> compileWasmMethodModuleCache(
+      {required ModuleCache that,
+      required Uint8List moduleWasm,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_module_cache(that);
+    var arg1 = _platform.api2wire_uint_8_list(moduleWasm);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_compile_wasm__method__ModuleCache(port_, arg0, arg1),
+      parseSuccessData: (d) => _wire2api_compiled_module(d),
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kAddFuelMethodWasmRunModuleIdConstMeta,
-      argValues: [that, delta],
+      constMeta: kCompileWasmMethodModuleCacheConstMeta,
+      argValues: [that, moduleWasm],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAddFuelMethodWasmRunModuleIdConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kCompileWasmMethodModuleCacheConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "add_fuel__method__WasmRunModuleId",
-        argNames: ["that", "delta"],
+        debugName: "compile_wasm__method__ModuleCache",
+        argNames: ["that", "moduleWasm"],
       );
 
-  int? fuelConsumedMethodWasmRunModuleId(
-      {required WasmRunModuleId that, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
+  CompiledModule compileWasmSyncMethodModuleCache(
+      {required ModuleCache that,
+      required Uint8List moduleWasm,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_module_cache(that);
+    var arg1 = _platform.api2wire_uint_8_list(moduleWasm);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
-      callFfi: () =>
-          _platform.inner.wire_fuel_consumed__method__WasmRunModuleId(arg0),
-      parseSuccessData: _wire2api_opt_box_autoadd_u64,
-      parseErrorData: null,
-      constMeta: kFuelConsumedMethodWasmRunModuleIdConstMeta,
-      argValues: [that],
+      callFfi: () => _platform.inner
+          .wire_compile_wasm_sync__method__ModuleCache(arg0, arg1),
+      parseSuccessData: _wire2api_compiled_module,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCompileWasmSyncMethodModuleCacheConstMeta,
+      argValues: [that, moduleWasm],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kFuelConsumedMethodWasmRunModuleIdConstMeta =>
+      get kCompileWasmSyncMethodModuleCacheConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "fuel_consumed__method__WasmRunModuleId",
-            argNames: ["that"],
+            debugName: "compile_wasm_sync__method__ModuleCache",
+            argNames: ["that", "moduleWasm"],
           );
 
-  int consumeFuelMethodWasmRunModuleId(
-      {required WasmRunModuleId that, required int delta, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_wasm_run_module_id(that);
-    var arg1 = _platform.api2wire_u64(delta);
+  ModuleCacheStats statsMethodModuleCache(
+      {required ModuleCache that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_module_cache(that);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
-      callFfi: () => _platform.inner
-          .wire_consume_fuel__method__WasmRunModuleId(arg0, arg1),
-      parseSuccessData: _wire2api_u64,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kConsumeFuelMethodWasmRunModuleIdConstMeta,
-      argValues: [that, delta],
+      callFfi: () => _platform.inner.wire_stats__method__ModuleCache(arg0),
+      parseSuccessData: _wire2api_module_cache_stats,
+      parseErrorData: null,
+      constMeta: kStatsMethodModuleCacheConstMeta,
+      argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kConsumeFuelMethodWasmRunModuleIdConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "consume_fuel__method__WasmRunModuleId",
-            argNames: ["that", "delta"],
-          );
+  FlutterRustBridgeTaskConstMeta get kStatsMethodModuleCacheConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "stats__method__ModuleCache",
+        argNames: ["that"],
+      );
 
-  WasmRunSharedMemory createSharedMemoryMethodCompiledModule(
-      {required CompiledModule that,
-      required MemoryTy memoryType,
-      dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
-    var arg1 = _platform.api2wire_box_autoadd_memory_ty(memoryType);
+  void clearMethodModuleCache({required ModuleCache that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_module_cache(that);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
-      callFfi: () => _platform.inner
-          .wire_create_shared_memory__method__CompiledModule(arg0, arg1),
-      parseSuccessData: _wire2api_wasm_run_shared_memory,
-      parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kCreateSharedMemoryMethodCompiledModuleConstMeta,
-      argValues: [that, memoryType],
+      callFfi: () => _platform.inner.wire_clear__method__ModuleCache(arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: null,
+      constMeta: kClearMethodModuleCacheConstMeta,
+      argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kCreateSharedMemoryMethodCompiledModuleConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "create_shared_memory__method__CompiledModule",
-            argNames: ["that", "memoryType"],
-          );
+  FlutterRustBridgeTaskConstMeta get kClearMethodModuleCacheConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "clear__method__ModuleCache",
+        argNames: ["that"],
+      );
 
-  List<ModuleImportDesc> getModuleImportsMethodCompiledModule(
-      {required CompiledModule that, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+  void cancelMethodCompileHandle({required CompileHandle that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compile_handle(that);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
-      callFfi: () =>
-          _platform.inner.wire_get_module_imports__method__CompiledModule(arg0),
-      parseSuccessData: _wire2api_list_module_import_desc,
+      callFfi: () => _platform.inner.wire_cancel__method__CompileHandle(arg0),
+      parseSuccessData: _wire2api_unit,
       parseErrorData: null,
-      constMeta: kGetModuleImportsMethodCompiledModuleConstMeta,
+      constMeta: kCancelMethodCompileHandleConstMeta,
       argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kGetModuleImportsMethodCompiledModuleConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "get_module_imports__method__CompiledModule",
-            argNames: ["that"],
-          );
+  FlutterRustBridgeTaskConstMeta get kCancelMethodCompileHandleConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "cancel__method__CompileHandle",
+        argNames: ["that"],
+      );
 
-  List<ModuleExportDesc> getModuleExportsMethodCompiledModule(
-      {required CompiledModule that, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_compiled_module(that);
+  bool isCancelledMethodCompileHandle(
+      {required CompileHandle that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_compile_handle(that);
     return _platform.executeSync(FlutterRustBridgeSyncTask(
       callFfi: () =>
-          _platform.inner.wire_get_module_exports__method__CompiledModule(arg0),
-      parseSuccessData: _wire2api_list_module_export_desc,
+          _platform.inner.wire_is_cancelled__method__CompileHandle(arg0),
+      parseSuccessData: _wire2api_bool,
       parseErrorData: null,
-      constMeta: kGetModuleExportsMethodCompiledModuleConstMeta,
+      constMeta: kIsCancelledMethodCompileHandleConstMeta,
       argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta
-      get kGetModuleExportsMethodCompiledModuleConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "get_module_exports__method__CompiledModule",
-            argNames: ["that"],
-          );
+  FlutterRustBridgeTaskConstMeta get kIsCancelledMethodCompileHandleConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "is_cancelled__method__CompileHandle",
+        argNames: ["that"],
+      );
 
   MemoryTy tyMethodWasmRunSharedMemory(
       {required WasmRunSharedMemory that, dynamic hint}) {
@@ -3451,6 +5800,13 @@ class WasmRunDartImpl implements WasmRunDart {
         argNames: ["that", "offset", "kind", "val", "order"],
       );
 
+  DropFnType get dropOpaqueArcAtomicBool =>
+      _platform.inner.drop_opaque_ArcAtomicBool;
+  ShareFnType get shareOpaqueArcAtomicBool =>
+      _platform.inner.share_opaque_ArcAtomicBool;
+  OpaqueTypeFinalizer get ArcAtomicBoolFinalizer =>
+      _platform.ArcAtomicBoolFinalizer;
+
   DropFnType get dropOpaqueArcRwLockSharedMemory =>
       _platform.inner.drop_opaque_ArcRwLockSharedMemory;
   ShareFnType get shareOpaqueArcRwLockSharedMemory =>
@@ -3458,6 +5814,13 @@ class WasmRunDartImpl implements WasmRunDart {
   OpaqueTypeFinalizer get ArcRwLockSharedMemoryFinalizer =>
       _platform.ArcRwLockSharedMemoryFinalizer;
 
+  DropFnType get dropOpaqueArcStdSyncMutexLinkerTemplate =>
+      _platform.inner.drop_opaque_ArcStdSyncMutexLinkerTemplate;
+  ShareFnType get shareOpaqueArcStdSyncMutexLinkerTemplate =>
+      _platform.inner.share_opaque_ArcStdSyncMutexLinkerTemplate;
+  OpaqueTypeFinalizer get ArcStdSyncMutexLinkerTemplateFinalizer =>
+      _platform.ArcStdSyncMutexLinkerTemplateFinalizer;
+
   DropFnType get dropOpaqueArcStdSyncMutexModule =>
       _platform.inner.drop_opaque_ArcStdSyncMutexModule;
   ShareFnType get shareOpaqueArcStdSyncMutexModule =>
@@ -3465,6 +5828,13 @@ class WasmRunDartImpl implements WasmRunDart {
   OpaqueTypeFinalizer get ArcStdSyncMutexModuleFinalizer =>
       _platform.ArcStdSyncMutexModuleFinalizer;
 
+  DropFnType get dropOpaqueArcStdSyncMutexModuleCacheImpl =>
+      _platform.inner.drop_opaque_ArcStdSyncMutexModuleCacheImpl;
+  ShareFnType get shareOpaqueArcStdSyncMutexModuleCacheImpl =>
+      _platform.inner.share_opaque_ArcStdSyncMutexModuleCacheImpl;
+  OpaqueTypeFinalizer get ArcStdSyncMutexModuleCacheImplFinalizer =>
+      _platform.ArcStdSyncMutexModuleCacheImplFinalizer;
+
   DropFnType get dropOpaqueCallStack => _platform.inner.drop_opaque_CallStack;
   ShareFnType get shareOpaqueCallStack =>
       _platform.inner.share_opaque_CallStack;
@@ -3478,10 +5848,21 @@ class WasmRunDartImpl implements WasmRunDart {
   ShareFnType get shareOpaqueMemory => _platform.inner.share_opaque_Memory;
   OpaqueTypeFinalizer get MemoryFinalizer => _platform.MemoryFinalizer;
 
+  DropFnType get dropOpaqueStdioCapture =>
+      _platform.inner.drop_opaque_StdioCapture;
+  ShareFnType get shareOpaqueStdioCapture =>
+      _platform.inner.share_opaque_StdioCapture;
+  OpaqueTypeFinalizer get StdioCaptureFinalizer =>
+      _platform.StdioCaptureFinalizer;
+
   DropFnType get dropOpaqueTable => _platform.inner.drop_opaque_Table;
   ShareFnType get shareOpaqueTable => _platform.inner.share_opaque_Table;
   OpaqueTypeFinalizer get TableFinalizer => _platform.TableFinalizer;
 
+  DropFnType get dropOpaqueWEngine => _platform.inner.drop_opaque_WEngine;
+  ShareFnType get shareOpaqueWEngine => _platform.inner.share_opaque_WEngine;
+  OpaqueTypeFinalizer get WEngineFinalizer => _platform.WEngineFinalizer;
+
   DropFnType get dropOpaqueWFunc => _platform.inner.drop_opaque_WFunc;
   ShareFnType get shareOpaqueWFunc => _platform.inner.share_opaque_WFunc;
   OpaqueTypeFinalizer get WFuncFinalizer => _platform.WFuncFinalizer;
@@ -3491,14 +5872,28 @@ class WasmRunDartImpl implements WasmRunDart {
   }
 // Section: wire2api
 
+  ArcAtomicBool _wire2api_ArcAtomicBool(dynamic raw) {
+    return ArcAtomicBool.fromRaw(raw[0], raw[1], this);
+  }
+
   ArcRwLockSharedMemory _wire2api_ArcRwLockSharedMemory(dynamic raw) {
     return ArcRwLockSharedMemory.fromRaw(raw[0], raw[1], this);
   }
 
+  ArcStdSyncMutexLinkerTemplate _wire2api_ArcStdSyncMutexLinkerTemplate(
+      dynamic raw) {
+    return ArcStdSyncMutexLinkerTemplate.fromRaw(raw[0], raw[1], this);
+  }
+
   ArcStdSyncMutexModule _wire2api_ArcStdSyncMutexModule(dynamic raw) {
     return ArcStdSyncMutexModule.fromRaw(raw[0], raw[1], this);
   }
 
+  ArcStdSyncMutexModuleCacheImpl _wire2api_ArcStdSyncMutexModuleCacheImpl(
+      dynamic raw) {
+    return ArcStdSyncMutexModuleCacheImpl.fromRaw(raw[0], raw[1], this);
+  }
+
   CallStack _wire2api_CallStack(dynamic raw) {
     return CallStack.fromRaw(raw[0], raw[1], this);
   }
@@ -3515,14 +5910,26 @@ class WasmRunDartImpl implements WasmRunDart {
     return Memory.fromRaw(raw[0], raw[1], this);
   }
 
+  StdioCapture _wire2api_StdioCapture(dynamic raw) {
+    return StdioCapture.fromRaw(raw[0], raw[1], this);
+  }
+
   String _wire2api_String(dynamic raw) {
     return raw as String;
   }
 
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
+
   Table _wire2api_Table(dynamic raw) {
     return Table.fromRaw(raw[0], raw[1], this);
   }
 
+  WEngine _wire2api_WEngine(dynamic raw) {
+    return WEngine.fromRaw(raw[0], raw[1], this);
+  }
+
   WFunc _wire2api_WFunc(dynamic raw) {
     return WFunc.fromRaw(raw[0], raw[1], this);
   }
@@ -3557,6 +5964,10 @@ class WasmRunDartImpl implements WasmRunDart {
     return _wire2api_global_ty(raw);
   }
 
+  int _wire2api_box_autoadd_i32(dynamic raw) {
+    return raw as int;
+  }
+
   MemoryTy _wire2api_box_autoadd_memory_ty(dynamic raw) {
     return _wire2api_memory_ty(raw);
   }
@@ -3596,6 +6007,16 @@ class WasmRunDartImpl implements WasmRunDart {
     );
   }
 
+  CompileHandle _wire2api_compile_handle(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return CompileHandle(
+      bridge: this,
+      field0: _wire2api_ArcAtomicBool(arr[0]),
+    );
+  }
+
   CompiledModule _wire2api_compiled_module(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1)
@@ -3664,6 +6085,10 @@ class WasmRunDartImpl implements WasmRunDart {
     return raw as double;
   }
 
+  FeatureStability _wire2api_feature_stability(dynamic raw) {
+    return FeatureStability.values[raw as int];
+  }
+
   FuncTy _wire2api_func_ty(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2)
@@ -3721,10 +6146,33 @@ class WasmRunDartImpl implements WasmRunDart {
     return (raw as List<dynamic>).map(_wire2api_value_ty).toList();
   }
 
+  List<WasmFeatureStability> _wire2api_list_wasm_feature_stability(
+      dynamic raw) {
+    return (raw as List<dynamic>)
+        .map(_wire2api_wasm_feature_stability)
+        .toList();
+  }
+
   List<WasmVal> _wire2api_list_wasm_val(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_wasm_val).toList();
   }
 
+  MemoryFootprint _wire2api_memory_footprint(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return MemoryFootprint(
+      memories: _wire2api_u32(arr[0]),
+      memoryPages: _wire2api_u64(arr[1]),
+      memoryBytes: _wire2api_u64(arr[2]),
+      tables: _wire2api_u32(arr[3]),
+      tableElements: _wire2api_u64(arr[4]),
+      globals: _wire2api_u32(arr[5]),
+      overheadBytes: _wire2api_u64(arr[6]),
+      totalBytes: _wire2api_u64(arr[7]),
+    );
+  }
+
   MemoryTy _wire2api_memory_ty(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 3)
@@ -3736,13 +6184,37 @@ class WasmRunDartImpl implements WasmRunDart {
     );
   }
 
+  ModuleCache _wire2api_module_cache(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ModuleCache(
+      bridge: this,
+      field0: _wire2api_ArcStdSyncMutexModuleCacheImpl(arr[0]),
+    );
+  }
+
+  ModuleCacheStats _wire2api_module_cache_stats(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ModuleCacheStats(
+      entries: _wire2api_u32(arr[0]),
+      sizeBytes: _wire2api_u64(arr[1]),
+      hits: _wire2api_u64(arr[2]),
+      misses: _wire2api_u64(arr[3]),
+      evictions: _wire2api_u64(arr[4]),
+    );
+  }
+
   ModuleExportDesc _wire2api_module_export_desc(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return ModuleExportDesc(
       name: _wire2api_String(arr[0]),
       ty: _wire2api_external_type(arr[1]),
+      index: _wire2api_u32(arr[2]),
     );
   }
 
@@ -3771,6 +6243,10 @@ class WasmRunDartImpl implements WasmRunDart {
     return raw == null ? null : _wire2api_box_autoadd_WFunc(raw);
   }
 
+  int? _wire2api_opt_box_autoadd_i32(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_i32(raw);
+  }
+
   int? _wire2api_opt_box_autoadd_u32(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_u32(raw);
   }
@@ -3787,6 +6263,10 @@ class WasmRunDartImpl implements WasmRunDart {
     return raw == null ? null : _wire2api_box_autoadd_wasm_wasi_features(raw);
   }
 
+  Uint8List? _wire2api_opt_uint_8_list(dynamic raw) {
+    return raw == null ? null : _wire2api_uint_8_list(raw);
+  }
+
   ParallelExec _wire2api_parallel_exec(dynamic raw) {
     switch (raw[0]) {
       case 0:
@@ -3863,10 +6343,34 @@ class WasmRunDartImpl implements WasmRunDart {
     return ValueTy.values[raw as int];
   }
 
+  WasiModuleKind _wire2api_wasi_module_kind(dynamic raw) {
+    return WasiModuleKind.values[raw as int];
+  }
+
+  WasmEngine _wire2api_wasm_engine(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WasmEngine(
+      bridge: this,
+      field0: _wire2api_WEngine(arr[0]),
+    );
+  }
+
+  WasmFeatureStability _wire2api_wasm_feature_stability(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WasmFeatureStability(
+      feature: _wire2api_String(arr[0]),
+      stability: _wire2api_feature_stability(arr[1]),
+    );
+  }
+
   WasmFeatures _wire2api_wasm_features(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 20)
-      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    if (arr.length != 22)
+      throw Exception('unexpected arr length: expect 22 but see ${arr.length}');
     return WasmFeatures(
       mutableGlobal: _wire2api_bool(arr[0]),
       saturatingFloatToInt: _wire2api_bool(arr[1]),
@@ -3879,15 +6383,27 @@ class WasmRunDartImpl implements WasmRunDart {
       threads: _wire2api_bool(arr[8]),
       tailCall: _wire2api_bool(arr[9]),
       floats: _wire2api_bool(arr[10]),
-      multiMemory: _wire2api_bool(arr[11]),
-      exceptions: _wire2api_bool(arr[12]),
-      memory64: _wire2api_bool(arr[13]),
-      extendedConst: _wire2api_bool(arr[14]),
-      componentModel: _wire2api_bool(arr[15]),
-      memoryControl: _wire2api_bool(arr[16]),
-      garbageCollection: _wire2api_bool(arr[17]),
-      typeReflection: _wire2api_bool(arr[18]),
-      wasiFeatures: _wire2api_opt_box_autoadd_wasm_wasi_features(arr[19]),
+      nanCanonicalization: _wire2api_bool(arr[11]),
+      multiMemory: _wire2api_bool(arr[12]),
+      exceptions: _wire2api_bool(arr[13]),
+      memory64: _wire2api_bool(arr[14]),
+      extendedConst: _wire2api_bool(arr[15]),
+      componentModel: _wire2api_bool(arr[16]),
+      memoryControl: _wire2api_bool(arr[17]),
+      garbageCollection: _wire2api_bool(arr[18]),
+      functionReferences: _wire2api_bool(arr[19]),
+      typeReflection: _wire2api_bool(arr[20]),
+      wasiFeatures: _wire2api_opt_box_autoadd_wasm_wasi_features(arr[21]),
+    );
+  }
+
+  WasmLinker _wire2api_wasm_linker(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return WasmLinker(
+      bridge: this,
+      field0: _wire2api_ArcStdSyncMutexLinkerTemplate(arr[0]),
     );
   }
 
@@ -3903,12 +6419,13 @@ class WasmRunDartImpl implements WasmRunDart {
 
   WasmRunModuleId _wire2api_wasm_run_module_id(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return WasmRunModuleId(
       bridge: this,
       field0: _wire2api_u32(arr[0]),
       field1: _wire2api_CallStack(arr[1]),
+      field2: _wire2api_StdioCapture(arr[2]),
     );
   }
 
@@ -3924,14 +6441,15 @@ class WasmRunDartImpl implements WasmRunDart {
 
   WasmRuntimeFeatures _wire2api_wasm_runtime_features(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return WasmRuntimeFeatures(
       name: _wire2api_String(arr[0]),
       version: _wire2api_String(arr[1]),
       isBrowser: _wire2api_bool(arr[2]),
       supportedFeatures: _wire2api_wasm_features(arr[3]),
       defaultFeatures: _wire2api_wasm_features(arr[4]),
+      stability: _wire2api_list_wasm_feature_stability(arr[5]),
     );
   }
 
@@ -4004,6 +6522,11 @@ bool api2wire_bool(bool raw) {
   return raw;
 }
 
+@protected
+int api2wire_capture_overflow(CaptureOverflow raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 double api2wire_f32(double raw) {
   return raw;
@@ -4014,6 +6537,11 @@ double api2wire_f64(double raw) {
   return raw;
 }
 
+@protected
+int api2wire_fuel_consumption_mode(FuelConsumptionMode raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_i32(int raw) {
   return raw;
@@ -4024,6 +6552,11 @@ int api2wire_std_io_kind(StdIOKind raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_u16(int raw) {
+  return raw;
+}
+
 @protected
 int api2wire_u32(int raw) {
   return raw;
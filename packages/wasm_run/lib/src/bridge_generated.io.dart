@@ -15,6 +15,13 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
 
 // Section: api2wire
 
+  @protected
+  wire_ArcAtomicBool api2wire_ArcAtomicBool(ArcAtomicBool raw) {
+    final ptr = inner.new_ArcAtomicBool();
+    _api_fill_to_wire_ArcAtomicBool(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_ArcRwLockSharedMemory api2wire_ArcRwLockSharedMemory(
       ArcRwLockSharedMemory raw) {
@@ -23,6 +30,14 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  wire_ArcStdSyncMutexLinkerTemplate api2wire_ArcStdSyncMutexLinkerTemplate(
+      ArcStdSyncMutexLinkerTemplate raw) {
+    final ptr = inner.new_ArcStdSyncMutexLinkerTemplate();
+    _api_fill_to_wire_ArcStdSyncMutexLinkerTemplate(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_ArcStdSyncMutexModule api2wire_ArcStdSyncMutexModule(
       ArcStdSyncMutexModule raw) {
@@ -31,6 +46,14 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  wire_ArcStdSyncMutexModuleCacheImpl api2wire_ArcStdSyncMutexModuleCacheImpl(
+      ArcStdSyncMutexModuleCacheImpl raw) {
+    final ptr = inner.new_ArcStdSyncMutexModuleCacheImpl();
+    _api_fill_to_wire_ArcStdSyncMutexModuleCacheImpl(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_CallStack api2wire_CallStack(CallStack raw) {
     final ptr = inner.new_CallStack();
@@ -52,6 +75,13 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  wire_StdioCapture api2wire_StdioCapture(StdioCapture raw) {
+    final ptr = inner.new_StdioCapture();
+    _api_fill_to_wire_StdioCapture(raw, ptr);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
     return api2wire_uint_8_list(utf8.encoder.convert(raw));
@@ -73,6 +103,13 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  wire_WEngine api2wire_WEngine(WEngine raw) {
+    final ptr = inner.new_WEngine();
+    _api_fill_to_wire_WEngine(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_WFunc api2wire_WFunc(WFunc raw) {
     final ptr = inner.new_WFunc();
@@ -99,6 +136,14 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return inner.new_box_autoadd_bool_0(api2wire_bool(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_CompileHandle> api2wire_box_autoadd_compile_handle(
+      CompileHandle raw) {
+    final ptr = inner.new_box_autoadd_compile_handle_0();
+    _api_fill_to_wire_compile_handle(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_CompiledModule> api2wire_box_autoadd_compiled_module(
       CompiledModule raw) {
@@ -107,6 +152,13 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_fuel_consumption_mode(
+      FuelConsumptionMode raw) {
+    return inner.new_box_autoadd_fuel_consumption_mode_0(
+        api2wire_fuel_consumption_mode(raw));
+  }
+
   @protected
   ffi.Pointer<wire_MemoryTy> api2wire_box_autoadd_memory_ty(MemoryTy raw) {
     final ptr = inner.new_box_autoadd_memory_ty_0();
@@ -114,6 +166,14 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ModuleCache> api2wire_box_autoadd_module_cache(
+      ModuleCache raw) {
+    final ptr = inner.new_box_autoadd_module_cache_0();
+    _api_fill_to_wire_module_cache(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ModuleConfig> api2wire_box_autoadd_module_config(
       ModuleConfig raw) {
@@ -145,6 +205,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Uint16> api2wire_box_autoadd_u16(int raw) {
+    return inner.new_box_autoadd_u16_0(api2wire_u16(raw));
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
     return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
@@ -176,6 +241,22 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_WasmEngine> api2wire_box_autoadd_wasm_engine(
+      WasmEngine raw) {
+    final ptr = inner.new_box_autoadd_wasm_engine_0();
+    _api_fill_to_wire_wasm_engine(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_WasmLinker> api2wire_box_autoadd_wasm_linker(
+      WasmLinker raw) {
+    final ptr = inner.new_box_autoadd_wasm_linker_0();
+    _api_fill_to_wire_wasm_linker(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_WasmRunInstanceId> api2wire_box_autoadd_wasm_run_instance_id(
       WasmRunInstanceId raw) {
@@ -242,6 +323,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_list_proposal_toggle> api2wire_list_proposal_toggle(
+      List<ProposalToggle> raw) {
+    final ans = inner.new_list_proposal_toggle_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_proposal_toggle(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_list_value_ty> api2wire_list_value_ty(List<ValueTy> raw) {
     final ans = inner.new_list_value_ty_0(raw.length);
@@ -260,6 +351,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_opt_String(String? raw) {
+    return raw == null ? ffi.nullptr : api2wire_String(raw);
+  }
+
   @protected
   ffi.Pointer<wire_WFunc> api2wire_opt_box_autoadd_WFunc(WFunc? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_WFunc(raw);
@@ -270,6 +366,14 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_bool(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_fuel_consumption_mode(
+      FuelConsumptionMode? raw) {
+    return raw == null
+        ? ffi.nullptr
+        : api2wire_box_autoadd_fuel_consumption_mode(raw);
+  }
+
   @protected
   ffi.Pointer<wire_ModuleConfigWasmi>
       api2wire_opt_box_autoadd_module_config_wasmi(ModuleConfigWasmi? raw) {
@@ -287,6 +391,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
         : api2wire_box_autoadd_module_config_wasmtime(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Uint16> api2wire_opt_box_autoadd_u16(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u16(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
@@ -339,14 +448,25 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
 
 // Section: finalizer
 
+  late final OpaqueTypeFinalizer _ArcAtomicBoolFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_ArcAtomicBoolPtr);
+  OpaqueTypeFinalizer get ArcAtomicBoolFinalizer => _ArcAtomicBoolFinalizer;
   late final OpaqueTypeFinalizer _ArcRwLockSharedMemoryFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_ArcRwLockSharedMemoryPtr);
   OpaqueTypeFinalizer get ArcRwLockSharedMemoryFinalizer =>
       _ArcRwLockSharedMemoryFinalizer;
+  late final OpaqueTypeFinalizer _ArcStdSyncMutexLinkerTemplateFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_ArcStdSyncMutexLinkerTemplatePtr);
+  OpaqueTypeFinalizer get ArcStdSyncMutexLinkerTemplateFinalizer =>
+      _ArcStdSyncMutexLinkerTemplateFinalizer;
   late final OpaqueTypeFinalizer _ArcStdSyncMutexModuleFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_ArcStdSyncMutexModulePtr);
   OpaqueTypeFinalizer get ArcStdSyncMutexModuleFinalizer =>
       _ArcStdSyncMutexModuleFinalizer;
+  late final OpaqueTypeFinalizer _ArcStdSyncMutexModuleCacheImplFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_ArcStdSyncMutexModuleCacheImplPtr);
+  OpaqueTypeFinalizer get ArcStdSyncMutexModuleCacheImplFinalizer =>
+      _ArcStdSyncMutexModuleCacheImplFinalizer;
   late final OpaqueTypeFinalizer _CallStackFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_CallStackPtr);
   OpaqueTypeFinalizer get CallStackFinalizer => _CallStackFinalizer;
@@ -356,24 +476,47 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
   late final OpaqueTypeFinalizer _MemoryFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_MemoryPtr);
   OpaqueTypeFinalizer get MemoryFinalizer => _MemoryFinalizer;
+  late final OpaqueTypeFinalizer _StdioCaptureFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_StdioCapturePtr);
+  OpaqueTypeFinalizer get StdioCaptureFinalizer => _StdioCaptureFinalizer;
   late final OpaqueTypeFinalizer _TableFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_TablePtr);
   OpaqueTypeFinalizer get TableFinalizer => _TableFinalizer;
+  late final OpaqueTypeFinalizer _WEngineFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_WEnginePtr);
+  OpaqueTypeFinalizer get WEngineFinalizer => _WEngineFinalizer;
   late final OpaqueTypeFinalizer _WFuncFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_WFuncPtr);
   OpaqueTypeFinalizer get WFuncFinalizer => _WFuncFinalizer;
 // Section: api_fill_to_wire
 
+  void _api_fill_to_wire_ArcAtomicBool(
+      ArcAtomicBool apiObj, wire_ArcAtomicBool wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_ArcRwLockSharedMemory(
       ArcRwLockSharedMemory apiObj, wire_ArcRwLockSharedMemory wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_ArcStdSyncMutexLinkerTemplate(
+      ArcStdSyncMutexLinkerTemplate apiObj,
+      wire_ArcStdSyncMutexLinkerTemplate wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_ArcStdSyncMutexModule(
       ArcStdSyncMutexModule apiObj, wire_ArcStdSyncMutexModule wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_ArcStdSyncMutexModuleCacheImpl(
+      ArcStdSyncMutexModuleCacheImpl apiObj,
+      wire_ArcStdSyncMutexModuleCacheImpl wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_CallStack(CallStack apiObj, wire_CallStack wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
@@ -386,10 +529,19 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_StdioCapture(
+      StdioCapture apiObj, wire_StdioCapture wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_Table(Table apiObj, wire_Table wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_WEngine(WEngine apiObj, wire_WEngine wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_WFunc(WFunc apiObj, wire_WFunc wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
@@ -408,6 +560,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     _api_fill_to_wire_atomics(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_compile_handle(
+      CompileHandle apiObj, ffi.Pointer<wire_CompileHandle> wireObj) {
+    _api_fill_to_wire_compile_handle(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_compiled_module(
       CompiledModule apiObj, ffi.Pointer<wire_CompiledModule> wireObj) {
     _api_fill_to_wire_compiled_module(apiObj, wireObj.ref);
@@ -418,6 +575,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     _api_fill_to_wire_memory_ty(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_module_cache(
+      ModuleCache apiObj, ffi.Pointer<wire_ModuleCache> wireObj) {
+    _api_fill_to_wire_module_cache(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_module_config(
       ModuleConfig apiObj, ffi.Pointer<wire_ModuleConfig> wireObj) {
     _api_fill_to_wire_module_config(apiObj, wireObj.ref);
@@ -449,6 +611,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     _api_fill_to_wire_wasi_stack_limits(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_wasm_engine(
+      WasmEngine apiObj, ffi.Pointer<wire_WasmEngine> wireObj) {
+    _api_fill_to_wire_wasm_engine(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_wasm_linker(
+      WasmLinker apiObj, ffi.Pointer<wire_WasmLinker> wireObj) {
+    _api_fill_to_wire_wasm_linker(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_wasm_run_instance_id(
       WasmRunInstanceId apiObj, ffi.Pointer<wire_WasmRunInstanceId> wireObj) {
     _api_fill_to_wire_wasm_run_instance_id(apiObj, wireObj.ref);
@@ -470,6 +642,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     _api_fill_to_wire_wasm_val(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_compile_handle(
+      CompileHandle apiObj, wire_CompileHandle wireObj) {
+    wireObj.field0 = api2wire_ArcAtomicBool(apiObj.field0);
+  }
+
   void _api_fill_to_wire_compiled_module(
       CompiledModule apiObj, wire_CompiledModule wireObj) {
     wireObj.field0 = api2wire_ArcStdSyncMutexModule(apiObj.field0);
@@ -527,6 +704,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     wireObj.maximum = api2wire_opt_box_autoadd_u32(apiObj.maximum);
   }
 
+  void _api_fill_to_wire_module_cache(
+      ModuleCache apiObj, wire_ModuleCache wireObj) {
+    wireObj.field0 = api2wire_ArcStdSyncMutexModuleCacheImpl(apiObj.field0);
+  }
+
   void _api_fill_to_wire_module_config(
       ModuleConfig apiObj, wire_ModuleConfig wireObj) {
     wireObj.multi_value = api2wire_opt_box_autoadd_bool(apiObj.multiValue);
@@ -537,6 +719,17 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     wireObj.wasmi = api2wire_opt_box_autoadd_module_config_wasmi(apiObj.wasmi);
     wireObj.wasmtime =
         api2wire_opt_box_autoadd_module_config_wasmtime(apiObj.wasmtime);
+    wireObj.max_module_bytes =
+        api2wire_opt_box_autoadd_usize(apiObj.maxModuleBytes);
+    wireObj.max_memory_pages =
+        api2wire_opt_box_autoadd_u64(apiObj.maxMemoryPages);
+    wireObj.max_memories = api2wire_opt_box_autoadd_u32(apiObj.maxMemories);
+    wireObj.max_tables = api2wire_opt_box_autoadd_u32(apiObj.maxTables);
+    wireObj.max_globals = api2wire_opt_box_autoadd_u32(apiObj.maxGlobals);
+    wireObj.max_functions = api2wire_opt_box_autoadd_u32(apiObj.maxFunctions);
+    wireObj.trap_on_integer_overflow =
+        api2wire_opt_box_autoadd_bool(apiObj.trapOnIntegerOverflow);
+    wireObj.deterministic = api2wire_opt_box_autoadd_bool(apiObj.deterministic);
   }
 
   void _api_fill_to_wire_module_config_wasmi(
@@ -554,6 +747,9 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     wireObj.extended_const =
         api2wire_opt_box_autoadd_bool(apiObj.extendedConst);
     wireObj.floats = api2wire_opt_box_autoadd_bool(apiObj.floats);
+    wireObj.fuel_consumption_mode =
+        api2wire_opt_box_autoadd_fuel_consumption_mode(
+            apiObj.fuelConsumptionMode);
   }
 
   void _api_fill_to_wire_module_config_wasmtime(
@@ -563,6 +759,8 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
         api2wire_opt_box_autoadd_bool(apiObj.wasmBacktrace);
     wireObj.native_unwind_info =
         api2wire_opt_box_autoadd_bool(apiObj.nativeUnwindInfo);
+    wireObj.epoch_interruption =
+        api2wire_opt_box_autoadd_bool(apiObj.epochInterruption);
     wireObj.max_wasm_stack =
         api2wire_opt_box_autoadd_usize(apiObj.maxWasmStack);
     wireObj.wasm_threads = api2wire_opt_box_autoadd_bool(apiObj.wasmThreads);
@@ -571,9 +769,17 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
         api2wire_opt_box_autoadd_bool(apiObj.wasmRelaxedSimd);
     wireObj.relaxed_simd_deterministic =
         api2wire_opt_box_autoadd_bool(apiObj.relaxedSimdDeterministic);
+    wireObj.cranelift_nan_canonicalization =
+        api2wire_opt_box_autoadd_bool(apiObj.craneliftNanCanonicalization);
     wireObj.wasm_multi_memory =
         api2wire_opt_box_autoadd_bool(apiObj.wasmMultiMemory);
     wireObj.wasm_memory64 = api2wire_opt_box_autoadd_bool(apiObj.wasmMemory64);
+    wireObj.wasm_tail_call = api2wire_opt_box_autoadd_bool(apiObj.wasmTailCall);
+    wireObj.wasm_function_references =
+        api2wire_opt_box_autoadd_bool(apiObj.wasmFunctionReferences);
+    wireObj.wasm_memory_control =
+        api2wire_opt_box_autoadd_bool(apiObj.wasmMemoryControl);
+    wireObj.wasm_gc = api2wire_opt_box_autoadd_bool(apiObj.wasmGc);
     wireObj.static_memory_maximum_size =
         api2wire_opt_box_autoadd_u64(apiObj.staticMemoryMaximumSize);
     wireObj.static_memory_forced =
@@ -582,8 +788,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
         api2wire_opt_box_autoadd_u64(apiObj.staticMemoryGuardSize);
     wireObj.parallel_compilation =
         api2wire_opt_box_autoadd_bool(apiObj.parallelCompilation);
+    wireObj.compilation_thread_stack_size =
+        api2wire_opt_box_autoadd_usize(apiObj.compilationThreadStackSize);
     wireObj.generate_address_map =
         api2wire_opt_box_autoadd_bool(apiObj.generateAddressMap);
+    wireObj.disable_guard_pages =
+        api2wire_opt_box_autoadd_bool(apiObj.disableGuardPages);
+    wireObj.macos_use_mach_ports =
+        api2wire_opt_box_autoadd_bool(apiObj.macosUseMachPorts);
+    wireObj.extra_proposals =
+        api2wire_list_proposal_toggle(apiObj.extraProposals);
   }
 
   void _api_fill_to_wire_module_import(
@@ -597,6 +811,14 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
       PreopenedDir apiObj, wire_PreopenedDir wireObj) {
     wireObj.wasm_guest_path = api2wire_String(apiObj.wasmGuestPath);
     wireObj.host_path = api2wire_String(apiObj.hostPath);
+    wireObj.rights = api2wire_opt_box_autoadd_u64(apiObj.rights);
+    wireObj.overlay = api2wire_bool(apiObj.overlay);
+  }
+
+  void _api_fill_to_wire_proposal_toggle(
+      ProposalToggle apiObj, wire_ProposalToggle wireObj) {
+    wireObj.name = api2wire_String(apiObj.name);
+    wireObj.enabled = api2wire_bool(apiObj.enabled);
   }
 
   void _api_fill_to_wire_table_args(TableArgs apiObj, wire_TableArgs wireObj) {
@@ -608,6 +830,7 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
       WasiConfigNative apiObj, wire_WasiConfigNative wireObj) {
     wireObj.capture_stdout = api2wire_bool(apiObj.captureStdout);
     wireObj.capture_stderr = api2wire_bool(apiObj.captureStderr);
+    wireObj.line_buffered_output = api2wire_bool(apiObj.lineBufferedOutput);
     wireObj.inherit_stdin = api2wire_bool(apiObj.inheritStdin);
     wireObj.inherit_env = api2wire_bool(apiObj.inheritEnv);
     wireObj.inherit_args = api2wire_bool(apiObj.inheritArgs);
@@ -615,6 +838,12 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
     wireObj.env = api2wire_list_env_variable(apiObj.env);
     wireObj.preopened_files = api2wire_StringList(apiObj.preopenedFiles);
     wireObj.preopened_dirs = api2wire_list_preopened_dir(apiObj.preopenedDirs);
+    wireObj.capture_capacity =
+        api2wire_opt_box_autoadd_usize(apiObj.captureCapacity);
+    wireObj.capture_overflow =
+        api2wire_capture_overflow(apiObj.captureOverflow);
+    wireObj.deterministic = api2wire_bool(apiObj.deterministic);
+    wireObj.unknown_errno = api2wire_opt_box_autoadd_u16(apiObj.unknownErrno);
   }
 
   void _api_fill_to_wire_wasi_stack_limits(
@@ -627,6 +856,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
         api2wire_usize(apiObj.maximumRecursionDepth);
   }
 
+  void _api_fill_to_wire_wasm_engine(
+      WasmEngine apiObj, wire_WasmEngine wireObj) {
+    wireObj.field0 = api2wire_WEngine(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_wasm_linker(
+      WasmLinker apiObj, wire_WasmLinker wireObj) {
+    wireObj.field0 = api2wire_ArcStdSyncMutexLinkerTemplate(apiObj.field0);
+  }
+
   void _api_fill_to_wire_wasm_run_instance_id(
       WasmRunInstanceId apiObj, wire_WasmRunInstanceId wireObj) {
     wireObj.field0 = api2wire_u32(apiObj.field0);
@@ -636,6 +875,7 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire> {
       WasmRunModuleId apiObj, wire_WasmRunModuleId wireObj) {
     wireObj.field0 = api2wire_u32(apiObj.field0);
     wireObj.field1 = api2wire_CallStack(apiObj.field1);
+    wireObj.field2 = api2wire_StdioCapture(apiObj.field2);
   }
 
   void _api_fill_to_wire_wasm_run_shared_memory(
@@ -831,6 +1071,68 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _wire_parse_wat_format = _wire_parse_wat_formatPtr
       .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  WireSyncReturn wire_clamp_memory(
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+    int max_pages,
+  ) {
+    return _wire_clamp_memory(
+      module_wasm,
+      max_pages,
+    );
+  }
+
+  late final _wire_clamp_memoryPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_uint_8_list>, ffi.Uint64)>>('wire_clamp_memory');
+  late final _wire_clamp_memory = _wire_clamp_memoryPtr.asFunction<
+      WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>, int)>();
+
+  WireSyncReturn wire_module_has_start(
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+  ) {
+    return _wire_module_has_start(
+      module_wasm,
+    );
+  }
+
+  late final _wire_module_has_startPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_uint_8_list>)>>('wire_module_has_start');
+  late final _wire_module_has_start = _wire_module_has_startPtr
+      .asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_module_content_hash(
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+  ) {
+    return _wire_module_content_hash(
+      module_wasm,
+    );
+  }
+
+  late final _wire_module_content_hashPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_uint_8_list>)>>('wire_module_content_hash');
+  late final _wire_module_content_hash = _wire_module_content_hashPtr
+      .asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_create_engine(
+    ffi.Pointer<wire_ModuleConfig> config,
+  ) {
+    return _wire_create_engine(
+      config,
+    );
+  }
+
+  late final _wire_create_enginePtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_ModuleConfig>)>>('wire_create_engine');
+  late final _wire_create_engine = _wire_create_enginePtr
+      .asFunction<WireSyncReturn Function(ffi.Pointer<wire_ModuleConfig>)>();
+
   void wire_compile_wasm(
     int port_,
     ffi.Pointer<wire_uint_8_list> module_wasm,
@@ -869,6 +1171,46 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       WireSyncReturn Function(
           ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_ModuleConfig>)>();
 
+  WireSyncReturn wire_create_compile_handle() {
+    return _wire_create_compile_handle();
+  }
+
+  late final _wire_create_compile_handlePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>(
+          'wire_create_compile_handle');
+  late final _wire_create_compile_handle =
+      _wire_create_compile_handlePtr.asFunction<WireSyncReturn Function()>();
+
+  void wire_compile_wasm_cancelable(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+    ffi.Pointer<wire_ModuleConfig> config,
+    ffi.Pointer<wire_CompileHandle> handle,
+  ) {
+    return _wire_compile_wasm_cancelable(
+      port_,
+      module_wasm,
+      config,
+      handle,
+    );
+  }
+
+  late final _wire_compile_wasm_cancelablePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_ModuleConfig>,
+                  ffi.Pointer<wire_CompileHandle>)>>(
+      'wire_compile_wasm_cancelable');
+  late final _wire_compile_wasm_cancelable =
+      _wire_compile_wasm_cancelablePtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_ModuleConfig>,
+              ffi.Pointer<wire_CompileHandle>)>();
+
   WireSyncReturn wire_wasm_features_for_config(
     ffi.Pointer<wire_ModuleConfig> config,
   ) {
@@ -964,6 +1306,32 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
           WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
               ffi.Pointer<wire_list_module_import>)>();
 
+  WireSyncReturn wire_link_module__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    ffi.Pointer<wire_uint_8_list> namespace_,
+    ffi.Pointer<wire_CompiledModule> module,
+  ) {
+    return _wire_link_module__method__WasmRunModuleId(
+      that,
+      namespace_,
+      module,
+    );
+  }
+
+  late final _wire_link_module__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_CompiledModule>)>>(
+      'wire_link_module__method__WasmRunModuleId');
+  late final _wire_link_module__method__WasmRunModuleId =
+      _wire_link_module__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_CompiledModule>)>();
+
   void wire_stdio_stream__method__WasmRunModuleId(
     int port_,
     ffi.Pointer<wire_WasmRunModuleId> that,
@@ -984,6 +1352,119 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _wire_stdio_stream__method__WasmRunModuleIdPtr.asFunction<
           void Function(int, ffi.Pointer<wire_WasmRunModuleId>, int)>();
 
+  void wire_wasi_denied_stream__method__WasmRunModuleId(
+    int port_,
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_wasi_denied_stream__method__WasmRunModuleId(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire_wasi_denied_stream__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_WasmRunModuleId>)>>(
+      'wire_wasi_denied_stream__method__WasmRunModuleId');
+  late final _wire_wasi_denied_stream__method__WasmRunModuleId =
+      _wire_wasi_denied_stream__method__WasmRunModuleIdPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_WasmRunModuleId>)>();
+
+  WireSyncReturn wire_read_stdio__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int kind,
+  ) {
+    return _wire_read_stdio__method__WasmRunModuleId(
+      that,
+      kind,
+    );
+  }
+
+  late final _wire_read_stdio__method__WasmRunModuleIdPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Int32)>>('wire_read_stdio__method__WasmRunModuleId');
+  late final _wire_read_stdio__method__WasmRunModuleId =
+      _wire_read_stdio__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int)>();
+
+  void wire_next_stdio_chunk__method__WasmRunModuleId(
+    int port_,
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int kind,
+    int timeout_millis,
+  ) {
+    return _wire_next_stdio_chunk__method__WasmRunModuleId(
+      port_,
+      that,
+      kind,
+      timeout_millis,
+    );
+  }
+
+  late final _wire_next_stdio_chunk__method__WasmRunModuleIdPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Int32,
+              ffi.Uint64)>>('wire_next_stdio_chunk__method__WasmRunModuleId');
+  late final _wire_next_stdio_chunk__method__WasmRunModuleId =
+      _wire_next_stdio_chunk__method__WasmRunModuleIdPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_WasmRunModuleId>, int, int)>();
+
+  WireSyncReturn wire_stdio_dropped_bytes__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int kind,
+  ) {
+    return _wire_stdio_dropped_bytes__method__WasmRunModuleId(
+      that,
+      kind,
+    );
+  }
+
+  late final _wire_stdio_dropped_bytes__method__WasmRunModuleIdPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Int32)>>('wire_stdio_dropped_bytes__method__WasmRunModuleId');
+  late final _wire_stdio_dropped_bytes__method__WasmRunModuleId =
+      _wire_stdio_dropped_bytes__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int)>();
+
+  WireSyncReturn wire_close_stdio__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int kind,
+  ) {
+    return _wire_close_stdio__method__WasmRunModuleId(
+      that,
+      kind,
+    );
+  }
+
+  late final _wire_close_stdio__method__WasmRunModuleIdPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Int32)>>('wire_close_stdio__method__WasmRunModuleId');
+  late final _wire_close_stdio__method__WasmRunModuleId =
+      _wire_close_stdio__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int)>();
+
+  WireSyncReturn wire_close__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_close__method__WasmRunModuleId(
+      that,
+    );
+  }
+
+  late final _wire_close__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>>(
+      'wire_close__method__WasmRunModuleId');
+  late final _wire_close__method__WasmRunModuleId =
+      _wire_close__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>();
+
   void wire_dispose__method__WasmRunModuleId(
     int port_,
     ffi.Pointer<wire_WasmRunModuleId> that,
@@ -1049,28 +1530,205 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
           void Function(int, ffi.Pointer<wire_WasmRunModuleId>, wire_WFunc,
               ffi.Pointer<wire_list_wasm_val>)>();
 
-  void wire_call_function_handle_parallel__method__WasmRunModuleId(
-    int port_,
+  WireSyncReturn wire_call_by_index__method__WasmRunModuleId(
     ffi.Pointer<wire_WasmRunModuleId> that,
-    ffi.Pointer<wire_uint_8_list> func_name,
+    int index,
     ffi.Pointer<wire_list_wasm_val> args,
-    int num_tasks,
   ) {
-    return _wire_call_function_handle_parallel__method__WasmRunModuleId(
-      port_,
+    return _wire_call_by_index__method__WasmRunModuleId(
       that,
-      func_name,
+      index,
       args,
-      num_tasks,
     );
   }
 
-  late final _wire_call_function_handle_parallel__method__WasmRunModuleIdPtr =
-      _lookup<
-              ffi.NativeFunction<
-                  ffi.Void Function(
-                      ffi.Int64,
-                      ffi.Pointer<wire_WasmRunModuleId>,
+  late final _wire_call_by_index__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Uint32, ffi.Pointer<wire_list_wasm_val>)>>(
+      'wire_call_by_index__method__WasmRunModuleId');
+  late final _wire_call_by_index__method__WasmRunModuleId =
+      _wire_call_by_index__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int,
+              ffi.Pointer<wire_list_wasm_val>)>();
+
+  WireSyncReturn wire_call_wasi_start__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    wire_WFunc func,
+  ) {
+    return _wire_call_wasi_start__method__WasmRunModuleId(
+      that,
+      func,
+    );
+  }
+
+  late final _wire_call_wasi_start__method__WasmRunModuleIdPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+              wire_WFunc)>>('wire_call_wasi_start__method__WasmRunModuleId');
+  late final _wire_call_wasi_start__method__WasmRunModuleId =
+      _wire_call_wasi_start__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmRunModuleId>, wire_WFunc)>();
+
+  WireSyncReturn wire_initialize__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_initialize__method__WasmRunModuleId(
+      that,
+    );
+  }
+
+  late final _wire_initialize__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>>(
+      'wire_initialize__method__WasmRunModuleId');
+  late final _wire_initialize__method__WasmRunModuleId =
+      _wire_initialize__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>();
+
+  WireSyncReturn wire_call_export__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    ffi.Pointer<wire_uint_8_list> name,
+    ffi.Pointer<wire_list_wasm_val> args,
+  ) {
+    return _wire_call_export__method__WasmRunModuleId(
+      that,
+      name,
+      args,
+    );
+  }
+
+  late final _wire_call_export__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_list_wasm_val>)>>(
+      'wire_call_export__method__WasmRunModuleId');
+  late final _wire_call_export__method__WasmRunModuleId =
+      _wire_call_export__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_list_wasm_val>)>();
+
+  WireSyncReturn wire_guest_alloc__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int size,
+    ffi.Pointer<wire_uint_8_list> alloc_export,
+  ) {
+    return _wire_guest_alloc__method__WasmRunModuleId(
+      that,
+      size,
+      alloc_export,
+    );
+  }
+
+  late final _wire_guest_alloc__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_guest_alloc__method__WasmRunModuleId');
+  late final _wire_guest_alloc__method__WasmRunModuleId =
+      _wire_guest_alloc__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_guest_free__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int ptr,
+    int size,
+    ffi.Pointer<wire_uint_8_list> free_export,
+  ) {
+    return _wire_guest_free__method__WasmRunModuleId(
+      that,
+      ptr,
+      size,
+      free_export,
+    );
+  }
+
+  late final _wire_guest_free__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Uint32, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_guest_free__method__WasmRunModuleId');
+  late final _wire_guest_free__method__WasmRunModuleId =
+      _wire_guest_free__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int, int,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_write_guest_string__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    ffi.Pointer<wire_uint_8_list> value,
+    ffi.Pointer<wire_uint_8_list> memory_name,
+  ) {
+    return _wire_write_guest_string__method__WasmRunModuleId(
+      that,
+      value,
+      memory_name,
+    );
+  }
+
+  late final _wire_write_guest_string__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_write_guest_string__method__WasmRunModuleId');
+  late final _wire_write_guest_string__method__WasmRunModuleId =
+      _wire_write_guest_string__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_read_guest_string__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    int ptr,
+    int len,
+    ffi.Pointer<wire_uint_8_list> memory_name,
+  ) {
+    return _wire_read_guest_string__method__WasmRunModuleId(
+      that,
+      ptr,
+      len,
+      memory_name,
+    );
+  }
+
+  late final _wire_read_guest_string__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+                  ffi.Uint32, ffi.Uint32, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_read_guest_string__method__WasmRunModuleId');
+  late final _wire_read_guest_string__method__WasmRunModuleId =
+      _wire_read_guest_string__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int, int,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_call_function_handle_parallel__method__WasmRunModuleId(
+    int port_,
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    ffi.Pointer<wire_uint_8_list> func_name,
+    ffi.Pointer<wire_list_wasm_val> args,
+    int num_tasks,
+  ) {
+    return _wire_call_function_handle_parallel__method__WasmRunModuleId(
+      port_,
+      that,
+      func_name,
+      args,
+      num_tasks,
+    );
+  }
+
+  late final _wire_call_function_handle_parallel__method__WasmRunModuleIdPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64,
+                      ffi.Pointer<wire_WasmRunModuleId>,
                       ffi.Pointer<wire_uint_8_list>,
                       ffi.Pointer<wire_list_wasm_val>,
                       ffi.UintPtr)>>(
@@ -1595,6 +2253,47 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
           WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, wire_Table,
               int, ffi.Pointer<wire_WasmVal>, int)>();
 
+  WireSyncReturn wire_set_max_host_call_depth__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    ffi.Pointer<ffi.Uint32> max_depth,
+  ) {
+    return _wire_set_max_host_call_depth__method__WasmRunModuleId(
+      that,
+      max_depth,
+    );
+  }
+
+  late final _wire_set_max_host_call_depth__method__WasmRunModuleIdPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+                      ffi.Pointer<ffi.Uint32>)>>(
+          'wire_set_max_host_call_depth__method__WasmRunModuleId');
+  late final _wire_set_max_host_call_depth__method__WasmRunModuleId =
+      _wire_set_max_host_call_depth__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmRunModuleId>, ffi.Pointer<ffi.Uint32>)>();
+
+  WireSyncReturn wire_set_epoch_deadline__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+    ffi.Pointer<ffi.Uint64> ticks_beyond_current,
+  ) {
+    return _wire_set_epoch_deadline__method__WasmRunModuleId(
+      that,
+      ticks_beyond_current,
+    );
+  }
+
+  late final _wire_set_epoch_deadline__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmRunModuleId>, ffi.Pointer<ffi.Uint64>)>>(
+      'wire_set_epoch_deadline__method__WasmRunModuleId');
+  late final _wire_set_epoch_deadline__method__WasmRunModuleId =
+      _wire_set_epoch_deadline__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmRunModuleId>, ffi.Pointer<ffi.Uint64>)>();
+
   WireSyncReturn wire_add_fuel__method__WasmRunModuleId(
     ffi.Pointer<wire_WasmRunModuleId> that,
     int delta,
@@ -1633,71 +2332,483 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
     ffi.Pointer<wire_WasmRunModuleId> that,
     int delta,
   ) {
-    return _wire_consume_fuel__method__WasmRunModuleId(
+    return _wire_consume_fuel__method__WasmRunModuleId(
+      that,
+      delta,
+    );
+  }
+
+  late final _wire_consume_fuel__method__WasmRunModuleIdPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
+              ffi.Uint64)>>('wire_consume_fuel__method__WasmRunModuleId');
+  late final _wire_consume_fuel__method__WasmRunModuleId =
+      _wire_consume_fuel__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int)>();
+
+  WireSyncReturn wire_last_call_fuel_consumed__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_last_call_fuel_consumed__method__WasmRunModuleId(
+      that,
+    );
+  }
+
+  late final _wire_last_call_fuel_consumed__method__WasmRunModuleIdPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>>(
+          'wire_last_call_fuel_consumed__method__WasmRunModuleId');
+  late final _wire_last_call_fuel_consumed__method__WasmRunModuleId =
+      _wire_last_call_fuel_consumed__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>();
+
+  WireSyncReturn wire_last_call_duration_micros__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_last_call_duration_micros__method__WasmRunModuleId(
+      that,
+    );
+  }
+
+  late final _wire_last_call_duration_micros__method__WasmRunModuleIdPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>>(
+          'wire_last_call_duration_micros__method__WasmRunModuleId');
+  late final _wire_last_call_duration_micros__method__WasmRunModuleId =
+      _wire_last_call_duration_micros__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>();
+
+  WireSyncReturn wire_memory_footprint__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_memory_footprint__method__WasmRunModuleId(
+      that,
+    );
+  }
+
+  late final _wire_memory_footprint__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>>(
+      'wire_memory_footprint__method__WasmRunModuleId');
+  late final _wire_memory_footprint__method__WasmRunModuleId =
+      _wire_memory_footprint__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>();
+
+  WireSyncReturn wire_memories__method__WasmRunModuleId(
+    ffi.Pointer<wire_WasmRunModuleId> that,
+  ) {
+    return _wire_memories__method__WasmRunModuleId(
+      that,
+    );
+  }
+
+  late final _wire_memories__method__WasmRunModuleIdPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>>(
+      'wire_memories__method__WasmRunModuleId');
+  late final _wire_memories__method__WasmRunModuleId =
+      _wire_memories__method__WasmRunModuleIdPtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>)>();
+
+  WireSyncReturn wire_instantiate__method__CompiledModule(
+    ffi.Pointer<wire_CompiledModule> that,
+    ffi.Pointer<wire_WasiConfigNative> wasi_config,
+  ) {
+    return _wire_instantiate__method__CompiledModule(
+      that,
+      wasi_config,
+    );
+  }
+
+  late final _wire_instantiate__method__CompiledModulePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>,
+                  ffi.Pointer<wire_WasiConfigNative>)>>(
+      'wire_instantiate__method__CompiledModule');
+  late final _wire_instantiate__method__CompiledModule =
+      _wire_instantiate__method__CompiledModulePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>,
+              ffi.Pointer<wire_WasiConfigNative>)>();
+
+  WireSyncReturn wire_create_shared_memory__method__CompiledModule(
+    ffi.Pointer<wire_CompiledModule> that,
+    ffi.Pointer<wire_MemoryTy> memory_type,
+  ) {
+    return _wire_create_shared_memory__method__CompiledModule(
+      that,
+      memory_type,
+    );
+  }
+
+  late final _wire_create_shared_memory__method__CompiledModulePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>,
+                  ffi.Pointer<wire_MemoryTy>)>>(
+      'wire_create_shared_memory__method__CompiledModule');
+  late final _wire_create_shared_memory__method__CompiledModule =
+      _wire_create_shared_memory__method__CompiledModulePtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_CompiledModule>, ffi.Pointer<wire_MemoryTy>)>();
+
+  WireSyncReturn wire_get_module_imports__method__CompiledModule(
+    ffi.Pointer<wire_CompiledModule> that,
+  ) {
+    return _wire_get_module_imports__method__CompiledModule(
+      that,
+    );
+  }
+
+  late final _wire_get_module_imports__method__CompiledModulePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>>(
+      'wire_get_module_imports__method__CompiledModule');
+  late final _wire_get_module_imports__method__CompiledModule =
+      _wire_get_module_imports__method__CompiledModulePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>();
+
+  WireSyncReturn wire_wasi_module_kind__method__CompiledModule(
+    ffi.Pointer<wire_CompiledModule> that,
+  ) {
+    return _wire_wasi_module_kind__method__CompiledModule(
+      that,
+    );
+  }
+
+  late final _wire_wasi_module_kind__method__CompiledModulePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>>(
+      'wire_wasi_module_kind__method__CompiledModule');
+  late final _wire_wasi_module_kind__method__CompiledModule =
+      _wire_wasi_module_kind__method__CompiledModulePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>();
+
+  WireSyncReturn wire_get_module_exports__method__CompiledModule(
+    ffi.Pointer<wire_CompiledModule> that,
+  ) {
+    return _wire_get_module_exports__method__CompiledModule(
+      that,
+    );
+  }
+
+  late final _wire_get_module_exports__method__CompiledModulePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>>(
+      'wire_get_module_exports__method__CompiledModule');
+  late final _wire_get_module_exports__method__CompiledModule =
+      _wire_get_module_exports__method__CompiledModulePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>();
+
+  void wire_compile_wasm__method__WasmEngine(
+    int port_,
+    ffi.Pointer<wire_WasmEngine> that,
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+  ) {
+    return _wire_compile_wasm__method__WasmEngine(
+      port_,
+      that,
+      module_wasm,
+    );
+  }
+
+  late final _wire_compile_wasm__method__WasmEnginePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_WasmEngine>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_compile_wasm__method__WasmEngine');
+  late final _wire_compile_wasm__method__WasmEngine =
+      _wire_compile_wasm__method__WasmEnginePtr.asFunction<
+          void Function(int, ffi.Pointer<wire_WasmEngine>,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_compile_wasm_sync__method__WasmEngine(
+    ffi.Pointer<wire_WasmEngine> that,
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+  ) {
+    return _wire_compile_wasm_sync__method__WasmEngine(
+      that,
+      module_wasm,
+    );
+  }
+
+  late final _wire_compile_wasm_sync__method__WasmEnginePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmEngine>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_compile_wasm_sync__method__WasmEngine');
+  late final _wire_compile_wasm_sync__method__WasmEngine =
+      _wire_compile_wasm_sync__method__WasmEnginePtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmEngine>, ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_increment_epoch__method__WasmEngine(
+    ffi.Pointer<wire_WasmEngine> that,
+  ) {
+    return _wire_increment_epoch__method__WasmEngine(
+      that,
+    );
+  }
+
+  late final _wire_increment_epoch__method__WasmEnginePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_WasmEngine>)>>(
+      'wire_increment_epoch__method__WasmEngine');
+  late final _wire_increment_epoch__method__WasmEngine =
+      _wire_increment_epoch__method__WasmEnginePtr
+          .asFunction<WireSyncReturn Function(ffi.Pointer<wire_WasmEngine>)>();
+
+  WireSyncReturn wire_create_linker__method__WasmEngine(
+    ffi.Pointer<wire_WasmEngine> that,
+    bool wasi,
+  ) {
+    return _wire_create_linker__method__WasmEngine(
+      that,
+      wasi,
+    );
+  }
+
+  late final _wire_create_linker__method__WasmEnginePtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmEngine>,
+              ffi.Bool)>>('wire_create_linker__method__WasmEngine');
+  late final _wire_create_linker__method__WasmEngine =
+      _wire_create_linker__method__WasmEnginePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_WasmEngine>, bool)>();
+
+  WireSyncReturn wire_create_module_cache__method__WasmEngine(
+    ffi.Pointer<wire_WasmEngine> that,
+    ffi.Pointer<ffi.Uint32> max_entries,
+    ffi.Pointer<ffi.Uint64> max_bytes,
+    ffi.Pointer<wire_uint_8_list> directory,
+  ) {
+    return _wire_create_module_cache__method__WasmEngine(
+      that,
+      max_entries,
+      max_bytes,
+      directory,
+    );
+  }
+
+  late final _wire_create_module_cache__method__WasmEnginePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmEngine>,
+                  ffi.Pointer<ffi.Uint32>,
+                  ffi.Pointer<ffi.Uint64>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_create_module_cache__method__WasmEngine');
+  late final _wire_create_module_cache__method__WasmEngine =
+      _wire_create_module_cache__method__WasmEnginePtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmEngine>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<ffi.Uint64>,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_define_host_function__method__WasmLinker(
+    ffi.Pointer<wire_WasmLinker> that,
+    ffi.Pointer<wire_uint_8_list> module,
+    ffi.Pointer<wire_uint_8_list> name,
+    int function_pointer,
+    int function_id,
+    ffi.Pointer<wire_list_value_ty> param_types,
+    ffi.Pointer<wire_list_value_ty> result_types,
+  ) {
+    return _wire_define_host_function__method__WasmLinker(
+      that,
+      module,
+      name,
+      function_pointer,
+      function_id,
+      param_types,
+      result_types,
+    );
+  }
+
+  late final _wire_define_host_function__method__WasmLinkerPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmLinker>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.UintPtr,
+                  ffi.Uint32,
+                  ffi.Pointer<wire_list_value_ty>,
+                  ffi.Pointer<wire_list_value_ty>)>>(
+      'wire_define_host_function__method__WasmLinker');
+  late final _wire_define_host_function__method__WasmLinker =
+      _wire_define_host_function__method__WasmLinkerPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmLinker>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              int,
+              int,
+              ffi.Pointer<wire_list_value_ty>,
+              ffi.Pointer<wire_list_value_ty>)>();
+
+  WireSyncReturn wire_define_memory__method__WasmLinker(
+    ffi.Pointer<wire_WasmLinker> that,
+    ffi.Pointer<wire_uint_8_list> module,
+    ffi.Pointer<wire_uint_8_list> name,
+    ffi.Pointer<wire_MemoryTy> memory_type,
+  ) {
+    return _wire_define_memory__method__WasmLinker(
+      that,
+      module,
+      name,
+      memory_type,
+    );
+  }
+
+  late final _wire_define_memory__method__WasmLinkerPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmLinker>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_MemoryTy>)>>(
+      'wire_define_memory__method__WasmLinker');
+  late final _wire_define_memory__method__WasmLinker =
+      _wire_define_memory__method__WasmLinkerPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmLinker>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_MemoryTy>)>();
+
+  WireSyncReturn wire_module_builder__method__WasmLinker(
+    ffi.Pointer<wire_WasmLinker> that,
+    ffi.Pointer<wire_CompiledModule> module,
+    ffi.Pointer<wire_WasiConfigNative> wasi_config,
+  ) {
+    return _wire_module_builder__method__WasmLinker(
+      that,
+      module,
+      wasi_config,
+    );
+  }
+
+  late final _wire_module_builder__method__WasmLinkerPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(
+                  ffi.Pointer<wire_WasmLinker>,
+                  ffi.Pointer<wire_CompiledModule>,
+                  ffi.Pointer<wire_WasiConfigNative>)>>(
+      'wire_module_builder__method__WasmLinker');
+  late final _wire_module_builder__method__WasmLinker =
+      _wire_module_builder__method__WasmLinkerPtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_WasmLinker>,
+              ffi.Pointer<wire_CompiledModule>,
+              ffi.Pointer<wire_WasiConfigNative>)>();
+
+  void wire_compile_wasm__method__ModuleCache(
+    int port_,
+    ffi.Pointer<wire_ModuleCache> that,
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+  ) {
+    return _wire_compile_wasm__method__ModuleCache(
+      port_,
+      that,
+      module_wasm,
+    );
+  }
+
+  late final _wire_compile_wasm__method__ModuleCachePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ModuleCache>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_compile_wasm__method__ModuleCache');
+  late final _wire_compile_wasm__method__ModuleCache =
+      _wire_compile_wasm__method__ModuleCachePtr.asFunction<
+          void Function(int, ffi.Pointer<wire_ModuleCache>,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_compile_wasm_sync__method__ModuleCache(
+    ffi.Pointer<wire_ModuleCache> that,
+    ffi.Pointer<wire_uint_8_list> module_wasm,
+  ) {
+    return _wire_compile_wasm_sync__method__ModuleCache(
       that,
-      delta,
+      module_wasm,
     );
   }
 
-  late final _wire_consume_fuel__method__WasmRunModuleIdPtr = _lookup<
-      ffi.NativeFunction<
-          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>,
-              ffi.Uint64)>>('wire_consume_fuel__method__WasmRunModuleId');
-  late final _wire_consume_fuel__method__WasmRunModuleId =
-      _wire_consume_fuel__method__WasmRunModuleIdPtr.asFunction<
-          WireSyncReturn Function(ffi.Pointer<wire_WasmRunModuleId>, int)>();
+  late final _wire_compile_wasm_sync__method__ModuleCachePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_ModuleCache>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_compile_wasm_sync__method__ModuleCache');
+  late final _wire_compile_wasm_sync__method__ModuleCache =
+      _wire_compile_wasm_sync__method__ModuleCachePtr.asFunction<
+          WireSyncReturn Function(
+              ffi.Pointer<wire_ModuleCache>, ffi.Pointer<wire_uint_8_list>)>();
 
-  WireSyncReturn wire_create_shared_memory__method__CompiledModule(
-    ffi.Pointer<wire_CompiledModule> that,
-    ffi.Pointer<wire_MemoryTy> memory_type,
+  WireSyncReturn wire_stats__method__ModuleCache(
+    ffi.Pointer<wire_ModuleCache> that,
   ) {
-    return _wire_create_shared_memory__method__CompiledModule(
+    return _wire_stats__method__ModuleCache(
       that,
-      memory_type,
     );
   }
 
-  late final _wire_create_shared_memory__method__CompiledModulePtr = _lookup<
+  late final _wire_stats__method__ModuleCachePtr = _lookup<
           ffi.NativeFunction<
-              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>,
-                  ffi.Pointer<wire_MemoryTy>)>>(
-      'wire_create_shared_memory__method__CompiledModule');
-  late final _wire_create_shared_memory__method__CompiledModule =
-      _wire_create_shared_memory__method__CompiledModulePtr.asFunction<
-          WireSyncReturn Function(
-              ffi.Pointer<wire_CompiledModule>, ffi.Pointer<wire_MemoryTy>)>();
-
-  WireSyncReturn wire_get_module_imports__method__CompiledModule(
-    ffi.Pointer<wire_CompiledModule> that,
+              WireSyncReturn Function(ffi.Pointer<wire_ModuleCache>)>>(
+      'wire_stats__method__ModuleCache');
+  late final _wire_stats__method__ModuleCache =
+      _wire_stats__method__ModuleCachePtr
+          .asFunction<WireSyncReturn Function(ffi.Pointer<wire_ModuleCache>)>();
+
+  WireSyncReturn wire_clear__method__ModuleCache(
+    ffi.Pointer<wire_ModuleCache> that,
   ) {
-    return _wire_get_module_imports__method__CompiledModule(
+    return _wire_clear__method__ModuleCache(
       that,
     );
   }
 
-  late final _wire_get_module_imports__method__CompiledModulePtr = _lookup<
+  late final _wire_clear__method__ModuleCachePtr = _lookup<
           ffi.NativeFunction<
-              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>>(
-      'wire_get_module_imports__method__CompiledModule');
-  late final _wire_get_module_imports__method__CompiledModule =
-      _wire_get_module_imports__method__CompiledModulePtr.asFunction<
-          WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>();
+              WireSyncReturn Function(ffi.Pointer<wire_ModuleCache>)>>(
+      'wire_clear__method__ModuleCache');
+  late final _wire_clear__method__ModuleCache =
+      _wire_clear__method__ModuleCachePtr
+          .asFunction<WireSyncReturn Function(ffi.Pointer<wire_ModuleCache>)>();
+
+  WireSyncReturn wire_cancel__method__CompileHandle(
+    ffi.Pointer<wire_CompileHandle> that,
+  ) {
+    return _wire_cancel__method__CompileHandle(
+      that,
+    );
+  }
 
-  WireSyncReturn wire_get_module_exports__method__CompiledModule(
-    ffi.Pointer<wire_CompiledModule> that,
+  late final _wire_cancel__method__CompileHandlePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncReturn Function(ffi.Pointer<wire_CompileHandle>)>>(
+      'wire_cancel__method__CompileHandle');
+  late final _wire_cancel__method__CompileHandle =
+      _wire_cancel__method__CompileHandlePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_CompileHandle>)>();
+
+  WireSyncReturn wire_is_cancelled__method__CompileHandle(
+    ffi.Pointer<wire_CompileHandle> that,
   ) {
-    return _wire_get_module_exports__method__CompiledModule(
+    return _wire_is_cancelled__method__CompileHandle(
       that,
     );
   }
 
-  late final _wire_get_module_exports__method__CompiledModulePtr = _lookup<
+  late final _wire_is_cancelled__method__CompileHandlePtr = _lookup<
           ffi.NativeFunction<
-              WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>>(
-      'wire_get_module_exports__method__CompiledModule');
-  late final _wire_get_module_exports__method__CompiledModule =
-      _wire_get_module_exports__method__CompiledModulePtr.asFunction<
-          WireSyncReturn Function(ffi.Pointer<wire_CompiledModule>)>();
+              WireSyncReturn Function(ffi.Pointer<wire_CompileHandle>)>>(
+      'wire_is_cancelled__method__CompileHandle');
+  late final _wire_is_cancelled__method__CompileHandle =
+      _wire_is_cancelled__method__CompileHandlePtr.asFunction<
+          WireSyncReturn Function(ffi.Pointer<wire_CompileHandle>)>();
 
   WireSyncReturn wire_ty__method__WasmRunSharedMemory(
     ffi.Pointer<wire_WasmRunSharedMemory> that,
@@ -2114,6 +3225,16 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _wire_xor__method__AtomicsPtr.asFunction<
           void Function(int, ffi.Pointer<wire_Atomics>, int, int, int, int)>();
 
+  wire_ArcAtomicBool new_ArcAtomicBool() {
+    return _new_ArcAtomicBool();
+  }
+
+  late final _new_ArcAtomicBoolPtr =
+      _lookup<ffi.NativeFunction<wire_ArcAtomicBool Function()>>(
+          'new_ArcAtomicBool');
+  late final _new_ArcAtomicBool =
+      _new_ArcAtomicBoolPtr.asFunction<wire_ArcAtomicBool Function()>();
+
   wire_ArcRwLockSharedMemory new_ArcRwLockSharedMemory() {
     return _new_ArcRwLockSharedMemory();
   }
@@ -2124,6 +3245,17 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_ArcRwLockSharedMemory = _new_ArcRwLockSharedMemoryPtr
       .asFunction<wire_ArcRwLockSharedMemory Function()>();
 
+  wire_ArcStdSyncMutexLinkerTemplate new_ArcStdSyncMutexLinkerTemplate() {
+    return _new_ArcStdSyncMutexLinkerTemplate();
+  }
+
+  late final _new_ArcStdSyncMutexLinkerTemplatePtr = _lookup<
+          ffi.NativeFunction<wire_ArcStdSyncMutexLinkerTemplate Function()>>(
+      'new_ArcStdSyncMutexLinkerTemplate');
+  late final _new_ArcStdSyncMutexLinkerTemplate =
+      _new_ArcStdSyncMutexLinkerTemplatePtr
+          .asFunction<wire_ArcStdSyncMutexLinkerTemplate Function()>();
+
   wire_ArcStdSyncMutexModule new_ArcStdSyncMutexModule() {
     return _new_ArcStdSyncMutexModule();
   }
@@ -2134,6 +3266,17 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_ArcStdSyncMutexModule = _new_ArcStdSyncMutexModulePtr
       .asFunction<wire_ArcStdSyncMutexModule Function()>();
 
+  wire_ArcStdSyncMutexModuleCacheImpl new_ArcStdSyncMutexModuleCacheImpl() {
+    return _new_ArcStdSyncMutexModuleCacheImpl();
+  }
+
+  late final _new_ArcStdSyncMutexModuleCacheImplPtr = _lookup<
+          ffi.NativeFunction<wire_ArcStdSyncMutexModuleCacheImpl Function()>>(
+      'new_ArcStdSyncMutexModuleCacheImpl');
+  late final _new_ArcStdSyncMutexModuleCacheImpl =
+      _new_ArcStdSyncMutexModuleCacheImplPtr
+          .asFunction<wire_ArcStdSyncMutexModuleCacheImpl Function()>();
+
   wire_CallStack new_CallStack() {
     return _new_CallStack();
   }
@@ -2159,6 +3302,16 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<wire_Memory Function()>>('new_Memory');
   late final _new_Memory = _new_MemoryPtr.asFunction<wire_Memory Function()>();
 
+  wire_StdioCapture new_StdioCapture() {
+    return _new_StdioCapture();
+  }
+
+  late final _new_StdioCapturePtr =
+      _lookup<ffi.NativeFunction<wire_StdioCapture Function()>>(
+          'new_StdioCapture');
+  late final _new_StdioCapture =
+      _new_StdioCapturePtr.asFunction<wire_StdioCapture Function()>();
+
   ffi.Pointer<wire_StringList> new_StringList_0(
     int len,
   ) {
@@ -2181,6 +3334,15 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<wire_Table Function()>>('new_Table');
   late final _new_Table = _new_TablePtr.asFunction<wire_Table Function()>();
 
+  wire_WEngine new_WEngine() {
+    return _new_WEngine();
+  }
+
+  late final _new_WEnginePtr =
+      _lookup<ffi.NativeFunction<wire_WEngine Function()>>('new_WEngine');
+  late final _new_WEngine =
+      _new_WEnginePtr.asFunction<wire_WEngine Function()>();
+
   wire_WFunc new_WFunc() {
     return _new_WFunc();
   }
@@ -2223,6 +3385,17 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_bool_0 = _new_box_autoadd_bool_0Ptr
       .asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
 
+  ffi.Pointer<wire_CompileHandle> new_box_autoadd_compile_handle_0() {
+    return _new_box_autoadd_compile_handle_0();
+  }
+
+  late final _new_box_autoadd_compile_handle_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_CompileHandle> Function()>>(
+          'new_box_autoadd_compile_handle_0');
+  late final _new_box_autoadd_compile_handle_0 =
+      _new_box_autoadd_compile_handle_0Ptr
+          .asFunction<ffi.Pointer<wire_CompileHandle> Function()>();
+
   ffi.Pointer<wire_CompiledModule> new_box_autoadd_compiled_module_0() {
     return _new_box_autoadd_compiled_module_0();
   }
@@ -2234,6 +3407,21 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _new_box_autoadd_compiled_module_0Ptr
           .asFunction<ffi.Pointer<wire_CompiledModule> Function()>();
 
+  ffi.Pointer<ffi.Int32> new_box_autoadd_fuel_consumption_mode_0(
+    int value,
+  ) {
+    return _new_box_autoadd_fuel_consumption_mode_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_fuel_consumption_mode_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'new_box_autoadd_fuel_consumption_mode_0');
+  late final _new_box_autoadd_fuel_consumption_mode_0 =
+      _new_box_autoadd_fuel_consumption_mode_0Ptr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
   ffi.Pointer<wire_MemoryTy> new_box_autoadd_memory_ty_0() {
     return _new_box_autoadd_memory_ty_0();
   }
@@ -2244,6 +3432,17 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_memory_ty_0 = _new_box_autoadd_memory_ty_0Ptr
       .asFunction<ffi.Pointer<wire_MemoryTy> Function()>();
 
+  ffi.Pointer<wire_ModuleCache> new_box_autoadd_module_cache_0() {
+    return _new_box_autoadd_module_cache_0();
+  }
+
+  late final _new_box_autoadd_module_cache_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ModuleCache> Function()>>(
+          'new_box_autoadd_module_cache_0');
+  late final _new_box_autoadd_module_cache_0 =
+      _new_box_autoadd_module_cache_0Ptr
+          .asFunction<ffi.Pointer<wire_ModuleCache> Function()>();
+
   ffi.Pointer<wire_ModuleConfig> new_box_autoadd_module_config_0() {
     return _new_box_autoadd_module_config_0();
   }
@@ -2289,6 +3488,20 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_table_args_0 = _new_box_autoadd_table_args_0Ptr
       .asFunction<ffi.Pointer<wire_TableArgs> Function()>();
 
+  ffi.Pointer<ffi.Uint16> new_box_autoadd_u16_0(
+    int value,
+  ) {
+    return _new_box_autoadd_u16_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_u16_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint16> Function(ffi.Uint16)>>(
+          'new_box_autoadd_u16_0');
+  late final _new_box_autoadd_u16_0 = _new_box_autoadd_u16_0Ptr
+      .asFunction<ffi.Pointer<ffi.Uint16> Function(int)>();
+
   ffi.Pointer<ffi.Uint32> new_box_autoadd_u32_0(
     int value,
   ) {
@@ -2353,6 +3566,26 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _new_box_autoadd_wasi_stack_limits_0Ptr
           .asFunction<ffi.Pointer<wire_WasiStackLimits> Function()>();
 
+  ffi.Pointer<wire_WasmEngine> new_box_autoadd_wasm_engine_0() {
+    return _new_box_autoadd_wasm_engine_0();
+  }
+
+  late final _new_box_autoadd_wasm_engine_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_WasmEngine> Function()>>(
+          'new_box_autoadd_wasm_engine_0');
+  late final _new_box_autoadd_wasm_engine_0 = _new_box_autoadd_wasm_engine_0Ptr
+      .asFunction<ffi.Pointer<wire_WasmEngine> Function()>();
+
+  ffi.Pointer<wire_WasmLinker> new_box_autoadd_wasm_linker_0() {
+    return _new_box_autoadd_wasm_linker_0();
+  }
+
+  late final _new_box_autoadd_wasm_linker_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_WasmLinker> Function()>>(
+          'new_box_autoadd_wasm_linker_0');
+  late final _new_box_autoadd_wasm_linker_0 = _new_box_autoadd_wasm_linker_0Ptr
+      .asFunction<ffi.Pointer<wire_WasmLinker> Function()>();
+
   ffi.Pointer<wire_WasmRunInstanceId> new_box_autoadd_wasm_run_instance_id_0() {
     return _new_box_autoadd_wasm_run_instance_id_0();
   }
@@ -2442,6 +3675,21 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_list_preopened_dir_0 = _new_list_preopened_dir_0Ptr
       .asFunction<ffi.Pointer<wire_list_preopened_dir> Function(int)>();
 
+  ffi.Pointer<wire_list_proposal_toggle> new_list_proposal_toggle_0(
+    int len,
+  ) {
+    return _new_list_proposal_toggle_0(
+      len,
+    );
+  }
+
+  late final _new_list_proposal_toggle_0Ptr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<wire_list_proposal_toggle> Function(
+              ffi.Int32)>>('new_list_proposal_toggle_0');
+  late final _new_list_proposal_toggle_0 = _new_list_proposal_toggle_0Ptr
+      .asFunction<ffi.Pointer<wire_list_proposal_toggle> Function(int)>();
+
   ffi.Pointer<wire_list_value_ty> new_list_value_ty_0(
     int len,
   ) {
@@ -2487,6 +3735,35 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _new_uint_8_list_0 = _new_uint_8_list_0Ptr
       .asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
+  void drop_opaque_ArcAtomicBool(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_ArcAtomicBool(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_ArcAtomicBoolPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_ArcAtomicBool');
+  late final _drop_opaque_ArcAtomicBool = _drop_opaque_ArcAtomicBoolPtr
+      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_ArcAtomicBool(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_ArcAtomicBool(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_ArcAtomicBoolPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<ffi.Void> Function(
+              ffi.Pointer<ffi.Void>)>>('share_opaque_ArcAtomicBool');
+  late final _share_opaque_ArcAtomicBool = _share_opaque_ArcAtomicBoolPtr
+      .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_ArcRwLockSharedMemory(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -2518,6 +3795,37 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _share_opaque_ArcRwLockSharedMemoryPtr
           .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_ArcStdSyncMutexLinkerTemplate(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_ArcStdSyncMutexLinkerTemplate(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_ArcStdSyncMutexLinkerTemplatePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_ArcStdSyncMutexLinkerTemplate');
+  late final _drop_opaque_ArcStdSyncMutexLinkerTemplate =
+      _drop_opaque_ArcStdSyncMutexLinkerTemplatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_ArcStdSyncMutexLinkerTemplate(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_ArcStdSyncMutexLinkerTemplate(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_ArcStdSyncMutexLinkerTemplatePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+      'share_opaque_ArcStdSyncMutexLinkerTemplate');
+  late final _share_opaque_ArcStdSyncMutexLinkerTemplate =
+      _share_opaque_ArcStdSyncMutexLinkerTemplatePtr
+          .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_ArcStdSyncMutexModule(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -2549,6 +3857,37 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
       _share_opaque_ArcStdSyncMutexModulePtr
           .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_ArcStdSyncMutexModuleCacheImpl(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_ArcStdSyncMutexModuleCacheImpl(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_ArcStdSyncMutexModuleCacheImplPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_ArcStdSyncMutexModuleCacheImpl');
+  late final _drop_opaque_ArcStdSyncMutexModuleCacheImpl =
+      _drop_opaque_ArcStdSyncMutexModuleCacheImplPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_ArcStdSyncMutexModuleCacheImpl(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_ArcStdSyncMutexModuleCacheImpl(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_ArcStdSyncMutexModuleCacheImplPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+      'share_opaque_ArcStdSyncMutexModuleCacheImpl');
+  late final _share_opaque_ArcStdSyncMutexModuleCacheImpl =
+      _share_opaque_ArcStdSyncMutexModuleCacheImplPtr
+          .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_CallStack(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -2636,6 +3975,35 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _share_opaque_Memory = _share_opaque_MemoryPtr
       .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_StdioCapture(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_StdioCapture(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_StdioCapturePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_StdioCapture');
+  late final _drop_opaque_StdioCapture = _drop_opaque_StdioCapturePtr
+      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_StdioCapture(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_StdioCapture(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_StdioCapturePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<ffi.Void> Function(
+              ffi.Pointer<ffi.Void>)>>('share_opaque_StdioCapture');
+  late final _share_opaque_StdioCapture = _share_opaque_StdioCapturePtr
+      .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_Table(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -2665,6 +4033,35 @@ class WasmRunDartWire implements FlutterRustBridgeWireBase {
   late final _share_opaque_Table = _share_opaque_TablePtr
       .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_WEngine(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_WEngine(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_WEnginePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_WEngine');
+  late final _drop_opaque_WEngine = _drop_opaque_WEnginePtr
+      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_WEngine(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_WEngine(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_WEnginePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<ffi.Void> Function(
+              ffi.Pointer<ffi.Void>)>>('share_opaque_WEngine');
+  late final _share_opaque_WEngine = _share_opaque_WEnginePtr
+      .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_WFunc(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -2871,6 +4268,11 @@ final class wire_PreopenedDir extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> wasm_guest_path;
 
   external ffi.Pointer<wire_uint_8_list> host_path;
+
+  external ffi.Pointer<ffi.Uint64> rights;
+
+  @ffi.Bool()
+  external bool overlay;
 }
 
 final class wire_list_preopened_dir extends ffi.Struct {
@@ -2887,6 +4289,9 @@ final class wire_WasiConfigNative extends ffi.Struct {
   @ffi.Bool()
   external bool capture_stderr;
 
+  @ffi.Bool()
+  external bool line_buffered_output;
+
   @ffi.Bool()
   external bool inherit_stdin;
 
@@ -2903,6 +4308,16 @@ final class wire_WasiConfigNative extends ffi.Struct {
   external ffi.Pointer<wire_StringList> preopened_files;
 
   external ffi.Pointer<wire_list_preopened_dir> preopened_dirs;
+
+  external ffi.Pointer<ffi.UintPtr> capture_capacity;
+
+  @ffi.Int32()
+  external int capture_overflow;
+
+  @ffi.Bool()
+  external bool deterministic;
+
+  external ffi.Pointer<ffi.Uint16> unknown_errno;
 }
 
 final class wire_WasiStackLimits extends ffi.Struct {
@@ -2932,6 +4347,22 @@ final class wire_ModuleConfigWasmi extends ffi.Struct {
   external ffi.Pointer<ffi.Bool> extended_const;
 
   external ffi.Pointer<ffi.Bool> floats;
+
+  external ffi.Pointer<ffi.Int32> fuel_consumption_mode;
+}
+
+final class wire_ProposalToggle extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> name;
+
+  @ffi.Bool()
+  external bool enabled;
+}
+
+final class wire_list_proposal_toggle extends ffi.Struct {
+  external ffi.Pointer<wire_ProposalToggle> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_ModuleConfigWasmtime extends ffi.Struct {
@@ -2941,6 +4372,8 @@ final class wire_ModuleConfigWasmtime extends ffi.Struct {
 
   external ffi.Pointer<ffi.Bool> native_unwind_info;
 
+  external ffi.Pointer<ffi.Bool> epoch_interruption;
+
   external ffi.Pointer<ffi.UintPtr> max_wasm_stack;
 
   external ffi.Pointer<ffi.Bool> wasm_threads;
@@ -2951,10 +4384,20 @@ final class wire_ModuleConfigWasmtime extends ffi.Struct {
 
   external ffi.Pointer<ffi.Bool> relaxed_simd_deterministic;
 
+  external ffi.Pointer<ffi.Bool> cranelift_nan_canonicalization;
+
   external ffi.Pointer<ffi.Bool> wasm_multi_memory;
 
   external ffi.Pointer<ffi.Bool> wasm_memory64;
 
+  external ffi.Pointer<ffi.Bool> wasm_tail_call;
+
+  external ffi.Pointer<ffi.Bool> wasm_function_references;
+
+  external ffi.Pointer<ffi.Bool> wasm_memory_control;
+
+  external ffi.Pointer<ffi.Bool> wasm_gc;
+
   external ffi.Pointer<ffi.Uint64> static_memory_maximum_size;
 
   external ffi.Pointer<ffi.Bool> static_memory_forced;
@@ -2963,7 +4406,15 @@ final class wire_ModuleConfigWasmtime extends ffi.Struct {
 
   external ffi.Pointer<ffi.Bool> parallel_compilation;
 
+  external ffi.Pointer<ffi.UintPtr> compilation_thread_stack_size;
+
   external ffi.Pointer<ffi.Bool> generate_address_map;
+
+  external ffi.Pointer<ffi.Bool> disable_guard_pages;
+
+  external ffi.Pointer<ffi.Bool> macos_use_mach_ports;
+
+  external ffi.Pointer<wire_list_proposal_toggle> extra_proposals;
 }
 
 final class wire_ModuleConfig extends ffi.Struct {
@@ -2978,6 +4429,30 @@ final class wire_ModuleConfig extends ffi.Struct {
   external ffi.Pointer<wire_ModuleConfigWasmi> wasmi;
 
   external ffi.Pointer<wire_ModuleConfigWasmtime> wasmtime;
+
+  external ffi.Pointer<ffi.UintPtr> max_module_bytes;
+
+  external ffi.Pointer<ffi.Uint64> max_memory_pages;
+
+  external ffi.Pointer<ffi.Uint32> max_memories;
+
+  external ffi.Pointer<ffi.Uint32> max_tables;
+
+  external ffi.Pointer<ffi.Uint32> max_globals;
+
+  external ffi.Pointer<ffi.Uint32> max_functions;
+
+  external ffi.Pointer<ffi.Bool> trap_on_integer_overflow;
+
+  external ffi.Pointer<ffi.Bool> deterministic;
+}
+
+final class wire_ArcAtomicBool extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_CompileHandle extends ffi.Struct {
+  external wire_ArcAtomicBool field0;
 }
 
 final class wire_WasmRunInstanceId extends ffi.Struct {
@@ -2989,11 +4464,17 @@ final class wire_CallStack extends ffi.Struct {
   external ffi.Pointer<ffi.Void> ptr;
 }
 
+final class wire_StdioCapture extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
 final class wire_WasmRunModuleId extends ffi.Struct {
   @ffi.Uint32()
   external int field0;
 
   external wire_CallStack field1;
+
+  external wire_StdioCapture field2;
 }
 
 final class wire_WFunc extends ffi.Struct {
@@ -3160,6 +4641,30 @@ final class wire_TableArgs extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> maximum;
 }
 
+final class wire_WEngine extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_WasmEngine extends ffi.Struct {
+  external wire_WEngine field0;
+}
+
+final class wire_ArcStdSyncMutexLinkerTemplate extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_WasmLinker extends ffi.Struct {
+  external wire_ArcStdSyncMutexLinkerTemplate field0;
+}
+
+final class wire_ArcStdSyncMutexModuleCacheImpl extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_ModuleCache extends ffi.Struct {
+  external wire_ArcStdSyncMutexModuleCacheImpl field0;
+}
+
 final class wire_Atomics extends ffi.Struct {
   @ffi.UintPtr()
   external int field0;
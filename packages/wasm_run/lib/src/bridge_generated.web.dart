@@ -19,16 +19,33 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
 
 // Section: api2wire
 
+  @protected
+  Object api2wire_ArcAtomicBool(ArcAtomicBool raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_ArcRwLockSharedMemory(ArcRwLockSharedMemory raw) {
     return raw.shareOrMove();
   }
 
+  @protected
+  Object api2wire_ArcStdSyncMutexLinkerTemplate(
+      ArcStdSyncMutexLinkerTemplate raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_ArcStdSyncMutexModule(ArcStdSyncMutexModule raw) {
     return raw.shareOrMove();
   }
 
+  @protected
+  Object api2wire_ArcStdSyncMutexModuleCacheImpl(
+      ArcStdSyncMutexModuleCacheImpl raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_CallStack(CallStack raw) {
     return raw.shareOrMove();
@@ -44,6 +61,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return raw.shareOrMove();
   }
 
+  @protected
+  Object api2wire_StdioCapture(StdioCapture raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   String api2wire_String(String raw) {
     return raw;
@@ -59,6 +81,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return raw.shareOrMove();
   }
 
+  @protected
+  Object api2wire_WEngine(WEngine raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_WFunc(WFunc raw) {
     return raw.shareOrMove();
@@ -84,16 +111,31 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return api2wire_bool(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_compile_handle(CompileHandle raw) {
+    return api2wire_compile_handle(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_compiled_module(CompiledModule raw) {
     return api2wire_compiled_module(raw);
   }
 
+  @protected
+  int api2wire_box_autoadd_fuel_consumption_mode(FuelConsumptionMode raw) {
+    return api2wire_fuel_consumption_mode(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_memory_ty(MemoryTy raw) {
     return api2wire_memory_ty(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_module_cache(ModuleCache raw) {
+    return api2wire_module_cache(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_module_config(ModuleConfig raw) {
     return api2wire_module_config(raw);
@@ -116,6 +158,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return api2wire_table_args(raw);
   }
 
+  @protected
+  int api2wire_box_autoadd_u16(int raw) {
+    return api2wire_u16(raw);
+  }
+
   @protected
   int api2wire_box_autoadd_u32(int raw) {
     return api2wire_u32(raw);
@@ -141,6 +188,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return api2wire_wasi_stack_limits(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_wasm_engine(WasmEngine raw) {
+    return api2wire_wasm_engine(raw);
+  }
+
+  @protected
+  List<dynamic> api2wire_box_autoadd_wasm_linker(WasmLinker raw) {
+    return api2wire_wasm_linker(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_wasm_run_instance_id(
       WasmRunInstanceId raw) {
@@ -163,6 +220,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return api2wire_wasm_val(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_compile_handle(CompileHandle raw) {
+    return [api2wire_ArcAtomicBool(raw.field0)];
+  }
+
   @protected
   List<dynamic> api2wire_compiled_module(CompiledModule raw) {
     return [api2wire_ArcStdSyncMutexModule(raw.field0)];
@@ -214,6 +276,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return raw.map(api2wire_preopened_dir).toList();
   }
 
+  @protected
+  List<dynamic> api2wire_list_proposal_toggle(List<ProposalToggle> raw) {
+    return raw.map(api2wire_proposal_toggle).toList();
+  }
+
   @protected
   List<dynamic> api2wire_list_value_ty(List<ValueTy> raw) {
     return raw.map(api2wire_value_ty).toList();
@@ -233,6 +300,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     ];
   }
 
+  @protected
+  List<dynamic> api2wire_module_cache(ModuleCache raw) {
+    return [api2wire_ArcStdSyncMutexModuleCacheImpl(raw.field0)];
+  }
+
   @protected
   List<dynamic> api2wire_module_config(ModuleConfig raw) {
     return [
@@ -241,7 +313,15 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
       api2wire_opt_box_autoadd_bool(raw.referenceTypes),
       api2wire_opt_box_autoadd_bool(raw.consumeFuel),
       api2wire_opt_box_autoadd_module_config_wasmi(raw.wasmi),
-      api2wire_opt_box_autoadd_module_config_wasmtime(raw.wasmtime)
+      api2wire_opt_box_autoadd_module_config_wasmtime(raw.wasmtime),
+      api2wire_opt_box_autoadd_usize(raw.maxModuleBytes),
+      api2wire_opt_box_autoadd_u64(raw.maxMemoryPages),
+      api2wire_opt_box_autoadd_u32(raw.maxMemories),
+      api2wire_opt_box_autoadd_u32(raw.maxTables),
+      api2wire_opt_box_autoadd_u32(raw.maxGlobals),
+      api2wire_opt_box_autoadd_u32(raw.maxFunctions),
+      api2wire_opt_box_autoadd_bool(raw.trapOnIntegerOverflow),
+      api2wire_opt_box_autoadd_bool(raw.deterministic)
     ];
   }
 
@@ -255,7 +335,8 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
       api2wire_opt_box_autoadd_bool(raw.saturatingFloatToInt),
       api2wire_opt_box_autoadd_bool(raw.tailCall),
       api2wire_opt_box_autoadd_bool(raw.extendedConst),
-      api2wire_opt_box_autoadd_bool(raw.floats)
+      api2wire_opt_box_autoadd_bool(raw.floats),
+      api2wire_opt_box_autoadd_fuel_consumption_mode(raw.fuelConsumptionMode)
     ];
   }
 
@@ -265,18 +346,28 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
       api2wire_opt_box_autoadd_bool(raw.debugInfo),
       api2wire_opt_box_autoadd_bool(raw.wasmBacktrace),
       api2wire_opt_box_autoadd_bool(raw.nativeUnwindInfo),
+      api2wire_opt_box_autoadd_bool(raw.epochInterruption),
       api2wire_opt_box_autoadd_usize(raw.maxWasmStack),
       api2wire_opt_box_autoadd_bool(raw.wasmThreads),
       api2wire_opt_box_autoadd_bool(raw.wasmSimd),
       api2wire_opt_box_autoadd_bool(raw.wasmRelaxedSimd),
       api2wire_opt_box_autoadd_bool(raw.relaxedSimdDeterministic),
+      api2wire_opt_box_autoadd_bool(raw.craneliftNanCanonicalization),
       api2wire_opt_box_autoadd_bool(raw.wasmMultiMemory),
       api2wire_opt_box_autoadd_bool(raw.wasmMemory64),
+      api2wire_opt_box_autoadd_bool(raw.wasmTailCall),
+      api2wire_opt_box_autoadd_bool(raw.wasmFunctionReferences),
+      api2wire_opt_box_autoadd_bool(raw.wasmMemoryControl),
+      api2wire_opt_box_autoadd_bool(raw.wasmGc),
       api2wire_opt_box_autoadd_u64(raw.staticMemoryMaximumSize),
       api2wire_opt_box_autoadd_bool(raw.staticMemoryForced),
       api2wire_opt_box_autoadd_u64(raw.staticMemoryGuardSize),
       api2wire_opt_box_autoadd_bool(raw.parallelCompilation),
-      api2wire_opt_box_autoadd_bool(raw.generateAddressMap)
+      api2wire_opt_box_autoadd_usize(raw.compilationThreadStackSize),
+      api2wire_opt_box_autoadd_bool(raw.generateAddressMap),
+      api2wire_opt_box_autoadd_bool(raw.disableGuardPages),
+      api2wire_opt_box_autoadd_bool(raw.macosUseMachPorts),
+      api2wire_list_proposal_toggle(raw.extraProposals)
     ];
   }
 
@@ -289,6 +380,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     ];
   }
 
+  @protected
+  String? api2wire_opt_String(String? raw) {
+    return raw == null ? null : api2wire_String(raw);
+  }
+
   @protected
   Object? api2wire_opt_box_autoadd_WFunc(WFunc? raw) {
     return raw == null ? null : api2wire_box_autoadd_WFunc(raw);
@@ -299,6 +395,12 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return raw == null ? null : api2wire_box_autoadd_bool(raw);
   }
 
+  @protected
+  int? api2wire_opt_box_autoadd_fuel_consumption_mode(
+      FuelConsumptionMode? raw) {
+    return raw == null ? null : api2wire_box_autoadd_fuel_consumption_mode(raw);
+  }
+
   @protected
   List<dynamic>? api2wire_opt_box_autoadd_module_config_wasmi(
       ModuleConfigWasmi? raw) {
@@ -313,6 +415,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
         : api2wire_box_autoadd_module_config_wasmtime(raw);
   }
 
+  @protected
+  int? api2wire_opt_box_autoadd_u16(int? raw) {
+    return raw == null ? null : api2wire_box_autoadd_u16(raw);
+  }
+
   @protected
   int? api2wire_opt_box_autoadd_u32(int? raw) {
     return raw == null ? null : api2wire_box_autoadd_u32(raw);
@@ -342,7 +449,17 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
 
   @protected
   List<dynamic> api2wire_preopened_dir(PreopenedDir raw) {
-    return [api2wire_String(raw.wasmGuestPath), api2wire_String(raw.hostPath)];
+    return [
+      api2wire_String(raw.wasmGuestPath),
+      api2wire_String(raw.hostPath),
+      api2wire_opt_box_autoadd_u64(raw.rights),
+      api2wire_bool(raw.overlay)
+    ];
+  }
+
+  @protected
+  List<dynamic> api2wire_proposal_toggle(ProposalToggle raw) {
+    return [api2wire_String(raw.name), api2wire_bool(raw.enabled)];
   }
 
   @protected
@@ -373,13 +490,18 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     return [
       api2wire_bool(raw.captureStdout),
       api2wire_bool(raw.captureStderr),
+      api2wire_bool(raw.lineBufferedOutput),
       api2wire_bool(raw.inheritStdin),
       api2wire_bool(raw.inheritEnv),
       api2wire_bool(raw.inheritArgs),
       api2wire_StringList(raw.args),
       api2wire_list_env_variable(raw.env),
       api2wire_StringList(raw.preopenedFiles),
-      api2wire_list_preopened_dir(raw.preopenedDirs)
+      api2wire_list_preopened_dir(raw.preopenedDirs),
+      api2wire_opt_box_autoadd_usize(raw.captureCapacity),
+      api2wire_capture_overflow(raw.captureOverflow),
+      api2wire_bool(raw.deterministic),
+      api2wire_opt_box_autoadd_u16(raw.unknownErrno)
     ];
   }
 
@@ -392,6 +514,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
     ];
   }
 
+  @protected
+  List<dynamic> api2wire_wasm_engine(WasmEngine raw) {
+    return [api2wire_WEngine(raw.field0)];
+  }
+
+  @protected
+  List<dynamic> api2wire_wasm_linker(WasmLinker raw) {
+    return [api2wire_ArcStdSyncMutexLinkerTemplate(raw.field0)];
+  }
+
   @protected
   List<dynamic> api2wire_wasm_run_instance_id(WasmRunInstanceId raw) {
     return [api2wire_u32(raw.field0)];
@@ -399,7 +531,11 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
 
   @protected
   List<dynamic> api2wire_wasm_run_module_id(WasmRunModuleId raw) {
-    return [api2wire_u32(raw.field0), api2wire_CallStack(raw.field1)];
+    return [
+      api2wire_u32(raw.field0),
+      api2wire_CallStack(raw.field1),
+      api2wire_StdioCapture(raw.field2)
+    ];
   }
 
   @protected
@@ -435,14 +571,28 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
   }
 // Section: finalizer
 
+  late final Finalizer<PlatformPointer> _ArcAtomicBoolFinalizer =
+      Finalizer<PlatformPointer>(inner.drop_opaque_ArcAtomicBool);
+  Finalizer<PlatformPointer> get ArcAtomicBoolFinalizer =>
+      _ArcAtomicBoolFinalizer;
   late final Finalizer<PlatformPointer> _ArcRwLockSharedMemoryFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_ArcRwLockSharedMemory);
   Finalizer<PlatformPointer> get ArcRwLockSharedMemoryFinalizer =>
       _ArcRwLockSharedMemoryFinalizer;
+  late final Finalizer<PlatformPointer>
+      _ArcStdSyncMutexLinkerTemplateFinalizer = Finalizer<PlatformPointer>(
+      inner.drop_opaque_ArcStdSyncMutexLinkerTemplate);
+  Finalizer<PlatformPointer> get ArcStdSyncMutexLinkerTemplateFinalizer =>
+      _ArcStdSyncMutexLinkerTemplateFinalizer;
   late final Finalizer<PlatformPointer> _ArcStdSyncMutexModuleFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_ArcStdSyncMutexModule);
   Finalizer<PlatformPointer> get ArcStdSyncMutexModuleFinalizer =>
       _ArcStdSyncMutexModuleFinalizer;
+  late final Finalizer<PlatformPointer>
+      _ArcStdSyncMutexModuleCacheImplFinalizer = Finalizer<PlatformPointer>(
+      inner.drop_opaque_ArcStdSyncMutexModuleCacheImpl);
+  Finalizer<PlatformPointer> get ArcStdSyncMutexModuleCacheImplFinalizer =>
+      _ArcStdSyncMutexModuleCacheImplFinalizer;
   late final Finalizer<PlatformPointer> _CallStackFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_CallStack);
   Finalizer<PlatformPointer> get CallStackFinalizer => _CallStackFinalizer;
@@ -452,9 +602,16 @@ class WasmRunDartPlatform extends FlutterRustBridgeBase<WasmRunDartWire>
   late final Finalizer<PlatformPointer> _MemoryFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_Memory);
   Finalizer<PlatformPointer> get MemoryFinalizer => _MemoryFinalizer;
+  late final Finalizer<PlatformPointer> _StdioCaptureFinalizer =
+      Finalizer<PlatformPointer>(inner.drop_opaque_StdioCapture);
+  Finalizer<PlatformPointer> get StdioCaptureFinalizer =>
+      _StdioCaptureFinalizer;
   late final Finalizer<PlatformPointer> _TableFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_Table);
   Finalizer<PlatformPointer> get TableFinalizer => _TableFinalizer;
+  late final Finalizer<PlatformPointer> _WEngineFinalizer =
+      Finalizer<PlatformPointer>(inner.drop_opaque_WEngine);
+  Finalizer<PlatformPointer> get WEngineFinalizer => _WEngineFinalizer;
   late final Finalizer<PlatformPointer> _WFuncFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_WFunc);
   Finalizer<PlatformPointer> get WFuncFinalizer => _WFuncFinalizer;
@@ -476,12 +633,27 @@ class WasmRunDartWasmModule implements WasmModule {
   external dynamic /* void */ wire_parse_wat_format(
       NativePortType port_, String wat);
 
+  external dynamic /* Uint8List */ wire_clamp_memory(
+      Uint8List module_wasm, Object max_pages);
+
+  external dynamic /* bool */ wire_module_has_start(Uint8List module_wasm);
+
+  external dynamic /* Uint8List */ wire_module_content_hash(
+      Uint8List module_wasm);
+
+  external dynamic /* List<dynamic> */ wire_create_engine(List<dynamic> config);
+
   external dynamic /* void */ wire_compile_wasm(
       NativePortType port_, Uint8List module_wasm, List<dynamic> config);
 
   external dynamic /* List<dynamic> */ wire_compile_wasm_sync(
       Uint8List module_wasm, List<dynamic> config);
 
+  external dynamic /* List<dynamic> */ wire_create_compile_handle();
+
+  external dynamic /* void */ wire_compile_wasm_cancelable(NativePortType port_,
+      Uint8List module_wasm, List<dynamic> config, List<dynamic> handle);
+
   external dynamic /* List<dynamic> */ wire_wasm_features_for_config(
       List<dynamic> config);
 
@@ -499,9 +671,34 @@ class WasmRunDartWasmModule implements WasmModule {
   external dynamic /* void */ wire_link_imports__method__WasmRunModuleId(
       List<dynamic> that, List<dynamic> imports);
 
+  external dynamic /* void */ wire_link_module__method__WasmRunModuleId(
+      List<dynamic> that, String namespace, List<dynamic> module);
+
   external dynamic /* void */ wire_stdio_stream__method__WasmRunModuleId(
       NativePortType port_, List<dynamic> that, int kind);
 
+  external dynamic /* void */ wire_wasi_denied_stream__method__WasmRunModuleId(
+      NativePortType port_, List<dynamic> that);
+
+  external dynamic /* Uint8List */ wire_read_stdio__method__WasmRunModuleId(
+      List<dynamic> that, int kind);
+
+  external dynamic /* void */ wire_next_stdio_chunk__method__WasmRunModuleId(
+      NativePortType port_,
+      List<dynamic> that,
+      int kind,
+      Object timeout_millis);
+
+  external dynamic /* Object */
+      wire_stdio_dropped_bytes__method__WasmRunModuleId(
+          List<dynamic> that, int kind);
+
+  external dynamic /* void */ wire_close_stdio__method__WasmRunModuleId(
+      List<dynamic> that, int kind);
+
+  external dynamic /* void */ wire_close__method__WasmRunModuleId(
+      List<dynamic> that);
+
   external dynamic /* void */ wire_dispose__method__WasmRunModuleId(
       NativePortType port_, List<dynamic> that);
 
@@ -513,6 +710,33 @@ class WasmRunDartWasmModule implements WasmModule {
       wire_call_function_handle__method__WasmRunModuleId(NativePortType port_,
           List<dynamic> that, Object func, List<dynamic> args);
 
+  external dynamic /* List<dynamic> */
+      wire_call_by_index__method__WasmRunModuleId(
+          List<dynamic> that, int index, List<dynamic> args);
+
+  external dynamic /* int */ wire_call_wasi_start__method__WasmRunModuleId(
+      List<dynamic> that, Object func);
+
+  external dynamic /* int? */ wire_initialize__method__WasmRunModuleId(
+      List<dynamic> that);
+
+  external dynamic /* List<dynamic> */
+      wire_call_export__method__WasmRunModuleId(
+          List<dynamic> that, String name, List<dynamic> args);
+
+  external dynamic /* int */ wire_guest_alloc__method__WasmRunModuleId(
+      List<dynamic> that, int size, String? alloc_export);
+
+  external dynamic /* void */ wire_guest_free__method__WasmRunModuleId(
+      List<dynamic> that, int ptr, int size, String? free_export);
+
+  external dynamic /* List<dynamic> */
+      wire_write_guest_string__method__WasmRunModuleId(
+          List<dynamic> that, String value, String? memory_name);
+
+  external dynamic /* String */ wire_read_guest_string__method__WasmRunModuleId(
+      List<dynamic> that, int ptr, int len, String? memory_name);
+
   external dynamic /* void */
       wire_call_function_handle_parallel__method__WasmRunModuleId(
           NativePortType port_,
@@ -606,6 +830,13 @@ class WasmRunDartWasmModule implements WasmModule {
       List<dynamic> value,
       int len);
 
+  external dynamic /* void */
+      wire_set_max_host_call_depth__method__WasmRunModuleId(
+          List<dynamic> that, int? max_depth);
+
+  external dynamic /* void */ wire_set_epoch_deadline__method__WasmRunModuleId(
+      List<dynamic> that, Object? ticks_beyond_current);
+
   external dynamic /* void */ wire_add_fuel__method__WasmRunModuleId(
       List<dynamic> that, Object delta);
 
@@ -615,6 +846,22 @@ class WasmRunDartWasmModule implements WasmModule {
   external dynamic /* Object */ wire_consume_fuel__method__WasmRunModuleId(
       List<dynamic> that, Object delta);
 
+  external dynamic /* Object? */
+      wire_last_call_fuel_consumed__method__WasmRunModuleId(List<dynamic> that);
+
+  external dynamic /* Object? */
+      wire_last_call_duration_micros__method__WasmRunModuleId(
+          List<dynamic> that);
+
+  external dynamic /* List<dynamic> */
+      wire_memory_footprint__method__WasmRunModuleId(List<dynamic> that);
+
+  external dynamic /* List<String> */ wire_memories__method__WasmRunModuleId(
+      List<dynamic> that);
+
+  external dynamic /* List<dynamic> */ wire_instantiate__method__CompiledModule(
+      List<dynamic> that, List<dynamic>? wasi_config);
+
   external dynamic /* List<dynamic> */
       wire_create_shared_memory__method__CompiledModule(
           List<dynamic> that, List<dynamic> memory_type);
@@ -622,9 +869,66 @@ class WasmRunDartWasmModule implements WasmModule {
   external dynamic /* List<dynamic> */
       wire_get_module_imports__method__CompiledModule(List<dynamic> that);
 
+  external dynamic /* int */ wire_wasi_module_kind__method__CompiledModule(
+      List<dynamic> that);
+
   external dynamic /* List<dynamic> */
       wire_get_module_exports__method__CompiledModule(List<dynamic> that);
 
+  external dynamic /* void */ wire_compile_wasm__method__WasmEngine(
+      NativePortType port_, List<dynamic> that, Uint8List module_wasm);
+
+  external dynamic /* List<dynamic> */
+      wire_compile_wasm_sync__method__WasmEngine(
+          List<dynamic> that, Uint8List module_wasm);
+
+  external dynamic /* void */ wire_increment_epoch__method__WasmEngine(
+      List<dynamic> that);
+
+  external dynamic /* List<dynamic> */ wire_create_linker__method__WasmEngine(
+      List<dynamic> that, bool wasi);
+
+  external dynamic /* List<dynamic> */
+      wire_create_module_cache__method__WasmEngine(List<dynamic> that,
+          int? max_entries, Object? max_bytes, String? directory);
+
+  external dynamic /* void */ wire_define_host_function__method__WasmLinker(
+      List<dynamic> that,
+      String module,
+      String name,
+      int function_pointer,
+      int function_id,
+      List<dynamic> param_types,
+      List<dynamic> result_types);
+
+  external dynamic /* void */ wire_define_memory__method__WasmLinker(
+      List<dynamic> that,
+      String module,
+      String name,
+      List<dynamic> memory_type);
+
+  external dynamic /* List<dynamic> */ wire_module_builder__method__WasmLinker(
+      List<dynamic> that, List<dynamic> module, List<dynamic>? wasi_config);
+
+  external dynamic /* void */ wire_compile_wasm__method__ModuleCache(
+      NativePortType port_, List<dynamic> that, Uint8List module_wasm);
+
+  external dynamic /* List<dynamic> */
+      wire_compile_wasm_sync__method__ModuleCache(
+          List<dynamic> that, Uint8List module_wasm);
+
+  external dynamic /* List<dynamic> */ wire_stats__method__ModuleCache(
+      List<dynamic> that);
+
+  external dynamic /* void */ wire_clear__method__ModuleCache(
+      List<dynamic> that);
+
+  external dynamic /* void */ wire_cancel__method__CompileHandle(
+      List<dynamic> that);
+
+  external dynamic /* bool */ wire_is_cancelled__method__CompileHandle(
+      List<dynamic> that);
+
   external dynamic /* List<dynamic> */ wire_ty__method__WasmRunSharedMemory(
       List<dynamic> that);
 
@@ -686,14 +990,28 @@ class WasmRunDartWasmModule implements WasmModule {
   external dynamic /* void */ wire_xor__method__Atomics(NativePortType port_,
       List<dynamic> that, int offset, int kind, Object val, int order);
 
+  external dynamic /*  */ drop_opaque_ArcAtomicBool(ptr);
+
+  external int /* *const c_void */ share_opaque_ArcAtomicBool(ptr);
+
   external dynamic /*  */ drop_opaque_ArcRwLockSharedMemory(ptr);
 
   external int /* *const c_void */ share_opaque_ArcRwLockSharedMemory(ptr);
 
+  external dynamic /*  */ drop_opaque_ArcStdSyncMutexLinkerTemplate(ptr);
+
+  external int /* *const c_void */ share_opaque_ArcStdSyncMutexLinkerTemplate(
+      ptr);
+
   external dynamic /*  */ drop_opaque_ArcStdSyncMutexModule(ptr);
 
   external int /* *const c_void */ share_opaque_ArcStdSyncMutexModule(ptr);
 
+  external dynamic /*  */ drop_opaque_ArcStdSyncMutexModuleCacheImpl(ptr);
+
+  external int /* *const c_void */ share_opaque_ArcStdSyncMutexModuleCacheImpl(
+      ptr);
+
   external dynamic /*  */ drop_opaque_CallStack(ptr);
 
   external int /* *const c_void */ share_opaque_CallStack(ptr);
@@ -706,10 +1024,18 @@ class WasmRunDartWasmModule implements WasmModule {
 
   external int /* *const c_void */ share_opaque_Memory(ptr);
 
+  external dynamic /*  */ drop_opaque_StdioCapture(ptr);
+
+  external int /* *const c_void */ share_opaque_StdioCapture(ptr);
+
   external dynamic /*  */ drop_opaque_Table(ptr);
 
   external int /* *const c_void */ share_opaque_Table(ptr);
 
+  external dynamic /*  */ drop_opaque_WEngine(ptr);
+
+  external int /* *const c_void */ share_opaque_WEngine(ptr);
+
   external dynamic /*  */ drop_opaque_WFunc(ptr);
 
   external int /* *const c_void */ share_opaque_WFunc(ptr);
@@ -729,6 +1055,19 @@ class WasmRunDartWire
   void wire_parse_wat_format(NativePortType port_, String wat) =>
       wasmModule.wire_parse_wat_format(port_, wat);
 
+  dynamic /* Uint8List */ wire_clamp_memory(
+          Uint8List module_wasm, Object max_pages) =>
+      wasmModule.wire_clamp_memory(module_wasm, max_pages);
+
+  dynamic /* bool */ wire_module_has_start(Uint8List module_wasm) =>
+      wasmModule.wire_module_has_start(module_wasm);
+
+  dynamic /* Uint8List */ wire_module_content_hash(Uint8List module_wasm) =>
+      wasmModule.wire_module_content_hash(module_wasm);
+
+  dynamic /* List<dynamic> */ wire_create_engine(List<dynamic> config) =>
+      wasmModule.wire_create_engine(config);
+
   void wire_compile_wasm(
           NativePortType port_, Uint8List module_wasm, List<dynamic> config) =>
       wasmModule.wire_compile_wasm(port_, module_wasm, config);
@@ -737,6 +1076,14 @@ class WasmRunDartWire
           Uint8List module_wasm, List<dynamic> config) =>
       wasmModule.wire_compile_wasm_sync(module_wasm, config);
 
+  dynamic /* List<dynamic> */ wire_create_compile_handle() =>
+      wasmModule.wire_create_compile_handle();
+
+  void wire_compile_wasm_cancelable(NativePortType port_, Uint8List module_wasm,
+          List<dynamic> config, List<dynamic> handle) =>
+      wasmModule.wire_compile_wasm_cancelable(
+          port_, module_wasm, config, handle);
+
   dynamic /* List<dynamic> */ wire_wasm_features_for_config(
           List<dynamic> config) =>
       wasmModule.wire_wasm_features_for_config(config);
@@ -760,10 +1107,39 @@ class WasmRunDartWire
           List<dynamic> that, List<dynamic> imports) =>
       wasmModule.wire_link_imports__method__WasmRunModuleId(that, imports);
 
+  dynamic /* void */ wire_link_module__method__WasmRunModuleId(
+          List<dynamic> that, String namespace, List<dynamic> module) =>
+      wasmModule.wire_link_module__method__WasmRunModuleId(
+          that, namespace, module);
+
   void wire_stdio_stream__method__WasmRunModuleId(
           NativePortType port_, List<dynamic> that, int kind) =>
       wasmModule.wire_stdio_stream__method__WasmRunModuleId(port_, that, kind);
 
+  void wire_wasi_denied_stream__method__WasmRunModuleId(
+          NativePortType port_, List<dynamic> that) =>
+      wasmModule.wire_wasi_denied_stream__method__WasmRunModuleId(port_, that);
+
+  dynamic /* Uint8List */ wire_read_stdio__method__WasmRunModuleId(
+          List<dynamic> that, int kind) =>
+      wasmModule.wire_read_stdio__method__WasmRunModuleId(that, kind);
+
+  void wire_next_stdio_chunk__method__WasmRunModuleId(NativePortType port_,
+          List<dynamic> that, int kind, Object timeout_millis) =>
+      wasmModule.wire_next_stdio_chunk__method__WasmRunModuleId(
+          port_, that, kind, timeout_millis);
+
+  dynamic /* Object */ wire_stdio_dropped_bytes__method__WasmRunModuleId(
+          List<dynamic> that, int kind) =>
+      wasmModule.wire_stdio_dropped_bytes__method__WasmRunModuleId(that, kind);
+
+  dynamic /* void */ wire_close_stdio__method__WasmRunModuleId(
+          List<dynamic> that, int kind) =>
+      wasmModule.wire_close_stdio__method__WasmRunModuleId(that, kind);
+
+  dynamic /* void */ wire_close__method__WasmRunModuleId(List<dynamic> that) =>
+      wasmModule.wire_close__method__WasmRunModuleId(that);
+
   void wire_dispose__method__WasmRunModuleId(
           NativePortType port_, List<dynamic> that) =>
       wasmModule.wire_dispose__method__WasmRunModuleId(port_, that);
@@ -779,6 +1155,42 @@ class WasmRunDartWire
       wasmModule.wire_call_function_handle__method__WasmRunModuleId(
           port_, that, func, args);
 
+  dynamic /* List<dynamic> */ wire_call_by_index__method__WasmRunModuleId(
+          List<dynamic> that, int index, List<dynamic> args) =>
+      wasmModule.wire_call_by_index__method__WasmRunModuleId(that, index, args);
+
+  dynamic /* int */ wire_call_wasi_start__method__WasmRunModuleId(
+          List<dynamic> that, Object func) =>
+      wasmModule.wire_call_wasi_start__method__WasmRunModuleId(that, func);
+
+  dynamic /* int? */ wire_initialize__method__WasmRunModuleId(
+          List<dynamic> that) =>
+      wasmModule.wire_initialize__method__WasmRunModuleId(that);
+
+  dynamic /* List<dynamic> */ wire_call_export__method__WasmRunModuleId(
+          List<dynamic> that, String name, List<dynamic> args) =>
+      wasmModule.wire_call_export__method__WasmRunModuleId(that, name, args);
+
+  dynamic /* int */ wire_guest_alloc__method__WasmRunModuleId(
+          List<dynamic> that, int size, String? alloc_export) =>
+      wasmModule.wire_guest_alloc__method__WasmRunModuleId(
+          that, size, alloc_export);
+
+  dynamic /* void */ wire_guest_free__method__WasmRunModuleId(
+          List<dynamic> that, int ptr, int size, String? free_export) =>
+      wasmModule.wire_guest_free__method__WasmRunModuleId(
+          that, ptr, size, free_export);
+
+  dynamic /* List<dynamic> */ wire_write_guest_string__method__WasmRunModuleId(
+          List<dynamic> that, String value, String? memory_name) =>
+      wasmModule.wire_write_guest_string__method__WasmRunModuleId(
+          that, value, memory_name);
+
+  dynamic /* String */ wire_read_guest_string__method__WasmRunModuleId(
+          List<dynamic> that, int ptr, int len, String? memory_name) =>
+      wasmModule.wire_read_guest_string__method__WasmRunModuleId(
+          that, ptr, len, memory_name);
+
   void wire_call_function_handle_parallel__method__WasmRunModuleId(
           NativePortType port_,
           List<dynamic> that,
@@ -902,6 +1314,16 @@ class WasmRunDartWire
       wasmModule.wire_fill_table__method__WasmRunModuleId(
           that, table, index, value, len);
 
+  dynamic /* void */ wire_set_max_host_call_depth__method__WasmRunModuleId(
+          List<dynamic> that, int? max_depth) =>
+      wasmModule.wire_set_max_host_call_depth__method__WasmRunModuleId(
+          that, max_depth);
+
+  dynamic /* void */ wire_set_epoch_deadline__method__WasmRunModuleId(
+          List<dynamic> that, Object? ticks_beyond_current) =>
+      wasmModule.wire_set_epoch_deadline__method__WasmRunModuleId(
+          that, ticks_beyond_current);
+
   dynamic /* void */ wire_add_fuel__method__WasmRunModuleId(
           List<dynamic> that, Object delta) =>
       wasmModule.wire_add_fuel__method__WasmRunModuleId(that, delta);
@@ -914,6 +1336,26 @@ class WasmRunDartWire
           List<dynamic> that, Object delta) =>
       wasmModule.wire_consume_fuel__method__WasmRunModuleId(that, delta);
 
+  dynamic /* Object? */ wire_last_call_fuel_consumed__method__WasmRunModuleId(
+          List<dynamic> that) =>
+      wasmModule.wire_last_call_fuel_consumed__method__WasmRunModuleId(that);
+
+  dynamic /* Object? */ wire_last_call_duration_micros__method__WasmRunModuleId(
+          List<dynamic> that) =>
+      wasmModule.wire_last_call_duration_micros__method__WasmRunModuleId(that);
+
+  dynamic /* List<dynamic> */ wire_memory_footprint__method__WasmRunModuleId(
+          List<dynamic> that) =>
+      wasmModule.wire_memory_footprint__method__WasmRunModuleId(that);
+
+  dynamic /* List<String> */ wire_memories__method__WasmRunModuleId(
+          List<dynamic> that) =>
+      wasmModule.wire_memories__method__WasmRunModuleId(that);
+
+  dynamic /* List<dynamic> */ wire_instantiate__method__CompiledModule(
+          List<dynamic> that, List<dynamic>? wasi_config) =>
+      wasmModule.wire_instantiate__method__CompiledModule(that, wasi_config);
+
   dynamic /* List<dynamic> */ wire_create_shared_memory__method__CompiledModule(
           List<dynamic> that, List<dynamic> memory_type) =>
       wasmModule.wire_create_shared_memory__method__CompiledModule(
@@ -923,10 +1365,85 @@ class WasmRunDartWire
           List<dynamic> that) =>
       wasmModule.wire_get_module_imports__method__CompiledModule(that);
 
+  dynamic /* int */ wire_wasi_module_kind__method__CompiledModule(
+          List<dynamic> that) =>
+      wasmModule.wire_wasi_module_kind__method__CompiledModule(that);
+
   dynamic /* List<dynamic> */ wire_get_module_exports__method__CompiledModule(
           List<dynamic> that) =>
       wasmModule.wire_get_module_exports__method__CompiledModule(that);
 
+  void wire_compile_wasm__method__WasmEngine(
+          NativePortType port_, List<dynamic> that, Uint8List module_wasm) =>
+      wasmModule.wire_compile_wasm__method__WasmEngine(
+          port_, that, module_wasm);
+
+  dynamic /* List<dynamic> */ wire_compile_wasm_sync__method__WasmEngine(
+          List<dynamic> that, Uint8List module_wasm) =>
+      wasmModule.wire_compile_wasm_sync__method__WasmEngine(that, module_wasm);
+
+  dynamic /* void */ wire_increment_epoch__method__WasmEngine(
+          List<dynamic> that) =>
+      wasmModule.wire_increment_epoch__method__WasmEngine(that);
+
+  dynamic /* List<dynamic> */ wire_create_linker__method__WasmEngine(
+          List<dynamic> that, bool wasi) =>
+      wasmModule.wire_create_linker__method__WasmEngine(that, wasi);
+
+  dynamic /* List<dynamic> */ wire_create_module_cache__method__WasmEngine(
+          List<dynamic> that,
+          int? max_entries,
+          Object? max_bytes,
+          String? directory) =>
+      wasmModule.wire_create_module_cache__method__WasmEngine(
+          that, max_entries, max_bytes, directory);
+
+  dynamic /* void */ wire_define_host_function__method__WasmLinker(
+          List<dynamic> that,
+          String module,
+          String name,
+          int function_pointer,
+          int function_id,
+          List<dynamic> param_types,
+          List<dynamic> result_types) =>
+      wasmModule.wire_define_host_function__method__WasmLinker(that, module,
+          name, function_pointer, function_id, param_types, result_types);
+
+  dynamic /* void */ wire_define_memory__method__WasmLinker(List<dynamic> that,
+          String module, String name, List<dynamic> memory_type) =>
+      wasmModule.wire_define_memory__method__WasmLinker(
+          that, module, name, memory_type);
+
+  dynamic /* List<dynamic> */ wire_module_builder__method__WasmLinker(
+          List<dynamic> that,
+          List<dynamic> module,
+          List<dynamic>? wasi_config) =>
+      wasmModule.wire_module_builder__method__WasmLinker(
+          that, module, wasi_config);
+
+  void wire_compile_wasm__method__ModuleCache(
+          NativePortType port_, List<dynamic> that, Uint8List module_wasm) =>
+      wasmModule.wire_compile_wasm__method__ModuleCache(
+          port_, that, module_wasm);
+
+  dynamic /* List<dynamic> */ wire_compile_wasm_sync__method__ModuleCache(
+          List<dynamic> that, Uint8List module_wasm) =>
+      wasmModule.wire_compile_wasm_sync__method__ModuleCache(that, module_wasm);
+
+  dynamic /* List<dynamic> */ wire_stats__method__ModuleCache(
+          List<dynamic> that) =>
+      wasmModule.wire_stats__method__ModuleCache(that);
+
+  dynamic /* void */ wire_clear__method__ModuleCache(List<dynamic> that) =>
+      wasmModule.wire_clear__method__ModuleCache(that);
+
+  dynamic /* void */ wire_cancel__method__CompileHandle(List<dynamic> that) =>
+      wasmModule.wire_cancel__method__CompileHandle(that);
+
+  dynamic /* bool */ wire_is_cancelled__method__CompileHandle(
+          List<dynamic> that) =>
+      wasmModule.wire_is_cancelled__method__CompileHandle(that);
+
   dynamic /* List<dynamic> */ wire_ty__method__WasmRunSharedMemory(
           List<dynamic> that) =>
       wasmModule.wire_ty__method__WasmRunSharedMemory(that);
@@ -1017,18 +1534,36 @@ class WasmRunDartWire
       wasmModule.wire_xor__method__Atomics(
           port_, that, offset, kind, val, order);
 
+  dynamic /*  */ drop_opaque_ArcAtomicBool(ptr) =>
+      wasmModule.drop_opaque_ArcAtomicBool(ptr);
+
+  int /* *const c_void */ share_opaque_ArcAtomicBool(ptr) =>
+      wasmModule.share_opaque_ArcAtomicBool(ptr);
+
   dynamic /*  */ drop_opaque_ArcRwLockSharedMemory(ptr) =>
       wasmModule.drop_opaque_ArcRwLockSharedMemory(ptr);
 
   int /* *const c_void */ share_opaque_ArcRwLockSharedMemory(ptr) =>
       wasmModule.share_opaque_ArcRwLockSharedMemory(ptr);
 
+  dynamic /*  */ drop_opaque_ArcStdSyncMutexLinkerTemplate(ptr) =>
+      wasmModule.drop_opaque_ArcStdSyncMutexLinkerTemplate(ptr);
+
+  int /* *const c_void */ share_opaque_ArcStdSyncMutexLinkerTemplate(ptr) =>
+      wasmModule.share_opaque_ArcStdSyncMutexLinkerTemplate(ptr);
+
   dynamic /*  */ drop_opaque_ArcStdSyncMutexModule(ptr) =>
       wasmModule.drop_opaque_ArcStdSyncMutexModule(ptr);
 
   int /* *const c_void */ share_opaque_ArcStdSyncMutexModule(ptr) =>
       wasmModule.share_opaque_ArcStdSyncMutexModule(ptr);
 
+  dynamic /*  */ drop_opaque_ArcStdSyncMutexModuleCacheImpl(ptr) =>
+      wasmModule.drop_opaque_ArcStdSyncMutexModuleCacheImpl(ptr);
+
+  int /* *const c_void */ share_opaque_ArcStdSyncMutexModuleCacheImpl(ptr) =>
+      wasmModule.share_opaque_ArcStdSyncMutexModuleCacheImpl(ptr);
+
   dynamic /*  */ drop_opaque_CallStack(ptr) =>
       wasmModule.drop_opaque_CallStack(ptr);
 
@@ -1045,11 +1580,23 @@ class WasmRunDartWire
   int /* *const c_void */ share_opaque_Memory(ptr) =>
       wasmModule.share_opaque_Memory(ptr);
 
+  dynamic /*  */ drop_opaque_StdioCapture(ptr) =>
+      wasmModule.drop_opaque_StdioCapture(ptr);
+
+  int /* *const c_void */ share_opaque_StdioCapture(ptr) =>
+      wasmModule.share_opaque_StdioCapture(ptr);
+
   dynamic /*  */ drop_opaque_Table(ptr) => wasmModule.drop_opaque_Table(ptr);
 
   int /* *const c_void */ share_opaque_Table(ptr) =>
       wasmModule.share_opaque_Table(ptr);
 
+  dynamic /*  */ drop_opaque_WEngine(ptr) =>
+      wasmModule.drop_opaque_WEngine(ptr);
+
+  int /* *const c_void */ share_opaque_WEngine(ptr) =>
+      wasmModule.share_opaque_WEngine(ptr);
+
   dynamic /*  */ drop_opaque_WFunc(ptr) => wasmModule.drop_opaque_WFunc(ptr);
 
   int /* *const c_void */ share_opaque_WFunc(ptr) =>
@@ -76,7 +76,8 @@ abstract class WASI {
 
   external Map<String, Function> get wasiImport;
 
-  external void start(Object /*Instance*/ instance);
+  /// Executes the `_start` function and returns the exit code.
+  external int start(Object /*Instance*/ instance);
   external void initialize(Object /*Instance*/ instance);
 }
 
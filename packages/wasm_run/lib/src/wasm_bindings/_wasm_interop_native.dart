@@ -6,7 +6,10 @@ import 'dart:typed_data' show Uint8List;
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart'
     show WireSyncReturn, wireSyncReturnIntoDart;
 import 'package:meta/meta.dart';
-import 'package:wasm_run/src/bridge_generated.io.dart';
+import 'package:wasm_run/src/bridge_generated.io.dart'
+    hide WasmEngine, WasmLinker;
+import 'package:wasm_run/src/bridge_generated.io.dart' as bridge
+    show WasmEngine, WasmLinker;
 import 'package:wasm_run/src/ffi.dart' show defaultInstance;
 import 'package:wasm_run/src/logger.dart';
 import 'package:wasm_run/src/wasm_bindings/make_function_num_args.dart';
@@ -22,12 +25,19 @@ Future<WasmRuntimeFeatures> wasmRuntimeFeatures() async =>
 Future<WasmModule> compileWasmModule(
   Uint8List bytes, {
   ModuleConfig? config,
+  CompileHandle? compileHandle,
 }) async {
   final config_ = config ?? const ModuleConfig();
-  final module = await defaultInstance().compileWasm(
-    moduleWasm: bytes,
-    config: config_,
-  );
+  final module = compileHandle == null
+      ? await defaultInstance().compileWasm(
+          moduleWasm: bytes,
+          config: config_,
+        )
+      : await defaultInstance().compileWasmCancelable(
+          moduleWasm: bytes,
+          config: config_,
+          handle: compileHandle,
+        );
   return _WasmModule._(module, config_);
 }
 
@@ -43,6 +53,145 @@ WasmModule compileWasmModuleSync(
   return _WasmModule._(module, config_);
 }
 
+CompileHandle createCompileHandle() => defaultInstance().createCompileHandle();
+
+WasmEngine createWasmEngine({ModuleConfig? config}) {
+  final config_ = config ?? const ModuleConfig();
+  final engine = defaultInstance().createEngine(config: config_);
+  return _Engine(engine, config_);
+}
+
+class _Engine extends WasmEngine {
+  final bridge.WasmEngine engine;
+  @override
+  final ModuleConfig config;
+
+  _Engine(this.engine, this.config);
+
+  @override
+  Future<WasmModule> compileWasmModule(Uint8List bytes) async {
+    final module = await engine.compileWasm(moduleWasm: bytes);
+    return _WasmModule._(module, config);
+  }
+
+  @override
+  WasmModule compileWasmModuleSync(Uint8List bytes) {
+    final module = engine.compileWasmSync(moduleWasm: bytes);
+    return _WasmModule._(module, config);
+  }
+
+  @override
+  void incrementEpoch() => engine.incrementEpoch();
+
+  @override
+  WasmLinker createLinker({bool wasi = false}) =>
+      _Linker(engine.createLinker(wasi: wasi));
+
+  @override
+  WasmModuleCache createModuleCache({
+    int? maxEntries,
+    int? maxBytes,
+    String? directory,
+  }) {
+    final cache = engine.createModuleCache(
+      maxEntries: maxEntries,
+      maxBytes: maxBytes,
+      directory: directory,
+    );
+    return _ModuleCache(cache, config);
+  }
+}
+
+class _Linker extends WasmLinker {
+  final bridge.WasmLinker linker;
+
+  _Linker(this.linker);
+
+  @override
+  void defineFunction(String moduleName, String name, WasmFunction function) {
+    final results = function.results;
+    if (results == null) {
+      throw ArgumentError.value(
+        function,
+        'function',
+        'The results of a linker function are required',
+      );
+    }
+    final params = function.params.cast<ValueTy>();
+    if (params.any(_isReference) || results.any(_isReference)) {
+      throw ArgumentError.value(
+        function,
+        'function',
+        'A linker function can not receive or return references',
+      );
+    }
+    linker.defineHostFunction(
+      module: moduleName,
+      name: name,
+      functionPointer: _References.globalWasmFunctionPointer,
+      functionId: _References.getOrCreateId(function, null),
+      paramTypes: params,
+      resultTypes: results,
+    );
+  }
+
+  static bool _isReference(ValueTy ty) =>
+      ty == ValueTy.externRef || ty == ValueTy.funcRef;
+
+  @override
+  void defineMemory(
+    String moduleName,
+    String name, {
+    required int minPages,
+    int? maxPages,
+  }) {
+    linker.defineMemory(
+      module: moduleName,
+      name: name,
+      memoryType: MemoryTy(
+        shared: false,
+        minimum: minPages,
+        maximum: maxPages,
+      ),
+    );
+  }
+
+  @override
+  WasmInstanceBuilder builder(WasmModule module, {WasiConfig? wasiConfig}) {
+    final module_ = module as _WasmModule;
+    final builder = linker.moduleBuilder(
+      module: module_.module,
+      wasiConfig: wasiConfig,
+    );
+    return _Builder(module_, builder, wasiConfig);
+  }
+}
+
+class _ModuleCache extends WasmModuleCache {
+  final ModuleCache cache;
+  final ModuleConfig config;
+
+  _ModuleCache(this.cache, this.config);
+
+  @override
+  Future<WasmModule> compileWasmModule(Uint8List bytes) async {
+    final module = await cache.compileWasm(moduleWasm: bytes);
+    return _WasmModule._(module, config);
+  }
+
+  @override
+  WasmModule compileWasmModuleSync(Uint8List bytes) {
+    final module = cache.compileWasmSync(moduleWasm: bytes);
+    return _WasmModule._(module, config);
+  }
+
+  @override
+  ModuleCacheStats stats() => cache.stats();
+
+  @override
+  void clear() => cache.clear();
+}
+
 class _WasmModule extends WasmModule {
   final CompiledModule module;
   final ModuleConfig config;
@@ -96,16 +245,30 @@ class _WasmModule extends WasmModule {
   List<WasmModuleExport> getExports() {
     final exports = module.getModuleExports();
     return exports
-        .map((e) => WasmModuleExport(e.name, _toImpExpKind(e.ty), type: e.ty))
+        .map(
+          (e) => WasmModuleExport(
+            e.name,
+            _toImpExpKind(e.ty),
+            type: e.ty,
+            index: e.index,
+          ),
+        )
         .toList(growable: false);
   }
+
+  @override
+  WasiModuleKind wasiModuleKind() => module.wasiModuleKind();
 }
 
 WasmVal _fromWasmValue(WasmValue value, WasmRunModuleId module) {
   return _fromWasmValueRaw(value.type, value.value, module);
 }
 
-WasmVal _fromWasmValueRaw(ValueTy ty, Object? value, WasmRunModuleId module) {
+WasmVal _fromWasmValueRaw(
+  ValueTy ty,
+  Object? value,
+  WasmRunModuleId? module,
+) {
   switch (ty) {
     case ValueTy.i32:
       return WasmVal.i32(value! as int);
@@ -121,13 +284,13 @@ WasmVal _fromWasmValueRaw(ValueTy ty, Object? value, WasmRunModuleId module) {
       return WasmVal.v128(value! as U8Array16);
     case ValueTy.externRef:
       return WasmVal.externRef(
-        value == null ? null : _References.getOrCreateId(value, module),
+        value == null ? null : _References.getOrCreateId(value, module!),
       );
     case ValueTy.funcRef:
       if (value == null) {
         return WasmVal.funcRef();
       }
-      return _makeFunction(value as WasmFunction, module);
+      return _makeFunction(value as WasmFunction, module!);
   }
 }
 
@@ -165,7 +328,17 @@ WasmFunction _toWasmFunction(WFunc func, WasmRunModuleId module, String? name) {
   }
 
   List<Object?> call([List<Object?>? args]) {
-    final result = module.callFunctionHandleSync(
+    final result = _References.guardHostErrors(
+      () => module.callFunctionHandleSync(func: func, args: mapArgs(args)),
+    );
+    if (result.isEmpty) return const [];
+    return result
+        .map((r) => _References.dartValueFromWasm(r, module))
+        .toList(growable: false);
+  }
+
+  Future<List<Object?>> callAsync([List<Object?>? args]) async {
+    final result = await module.callFunctionHandle(
       func: func,
       args: mapArgs(args),
     );
@@ -179,6 +352,7 @@ WasmFunction _toWasmFunction(WFunc func, WasmRunModuleId module, String? name) {
     params: params,
     results: type.results,
     call: call,
+    callAsync: callAsync,
     name: name,
     makeFunctionNumArgs(
       params.length,
@@ -215,9 +389,12 @@ WasmExternal _toWasmExternal(ModuleExportValue value, _Instance instance) {
   // }
 }
 
+/// A Dart object referenced by the Wasm module [module].
+/// [module] is null for the functions defined in a [WasmLinker],
+/// which are shared by all the modules instantiated with it.
 @immutable
 class _ModuleObjectReference {
-  final WasmRunModuleId module;
+  final WasmRunModuleId? module;
   final Object value;
 
   const _ModuleObjectReference(this.module, this.value);
@@ -225,15 +402,15 @@ class _ModuleObjectReference {
   @override
   bool operator ==(Object other) =>
       other is _ModuleObjectReference &&
-      other.module.field0 == module.field0 &&
+      other.module?.field0 == module?.field0 &&
       other.value == value;
 
   @override
-  int get hashCode => module.field0.hashCode ^ value.hashCode;
+  int get hashCode => Object.hash(module?.field0, value);
 
   @override
   String toString() {
-    return '_ModuleObjectReference(${module.field0}, $value)';
+    return '_ModuleObjectReference(${module?.field0}, $value)';
   }
 }
 
@@ -250,7 +427,7 @@ class _References {
   static final Map<int, _ModuleObjectReference> _idToReference = {};
   static final Map<_ModuleObjectReference, int> _referenceToId = {};
 
-  static int getOrCreateId(Object reference, WasmRunModuleId module) {
+  static int getOrCreateId(Object reference, WasmRunModuleId? module) {
     final ref = _ModuleObjectReference(module, reference);
     final id = _referenceToId.putIfAbsent(ref, () {
       final id = _lastId++;
@@ -265,7 +442,7 @@ class _References {
     final ref = _idToReference[id];
     assert(ref != null, 'Invalid reference: id $id module ${module.field0}');
     assert(
-      module.field0 == ref?.module.field0,
+      module.field0 == ref?.module?.field0,
       'Invalid module: reference id $id module ${module.field0}',
     );
     return ref?.value;
@@ -302,6 +479,28 @@ class _References {
     return mapped;
   }
 
+  /// The exceptions thrown by the host functions within the synchronous
+  /// calls in progress, one for each call, the innermost call is the last.
+  static final List<(Object, StackTrace)?> _hostErrors = [];
+
+  /// Executes the synchronous [call] to the module and rethrows the exception
+  /// thrown by a host function within it, if the call failed because of it.
+  /// The exceptions of host functions executed outside of these calls,
+  /// for example, in asynchronous or parallel calls, are not kept.
+  static T guardHostErrors<T>(T Function() call) {
+    _hostErrors.add(null);
+    final index = _hostErrors.length - 1;
+    try {
+      return call();
+    } catch (_) {
+      final error = _hostErrors[index];
+      if (error != null) Error.throwWithStackTrace(error.$1, error.$2);
+      rethrow;
+    } finally {
+      _hostErrors.removeLast();
+    }
+  }
+
   static int get globalWasmFunctionPointer =>
       ffi.Pointer.fromFunction<GlobalWasmFunction>(_globalWasmFunction).address;
   static ffi.Pointer<wire_list_wasm_val> _globalWasmFunction(
@@ -318,8 +517,10 @@ class _References {
       // ignore: invalid_use_of_protected_member
       pointer = platform.api2wire_list_wasm_val(mapped);
     } catch (e, s) {
-      print('_globalWasmFunction error: $e $s');
-      rethrow;
+      // The null pointer traps the execution and the exception
+      // is rethrown when the call to the wasm function returns.
+      if (_hostErrors.isNotEmpty) _hostErrors.last = (e, s);
+      return ffi.nullptr;
     }
     return pointer;
   }
@@ -360,7 +561,7 @@ class _References {
 
   static T _self<T>(T value) => value;
 
-  static Object? dartValueFromWasm(WasmVal raw, WasmRunModuleId module) {
+  static Object? dartValueFromWasm(WasmVal raw, WasmRunModuleId? module) {
     return raw.when(
       i32: _self,
       i64: _self,
@@ -369,9 +570,9 @@ class _References {
       v128: _self,
       funcRef: (func) {
         if (func == null) return null;
-        return _toWasmFunction(func, module, null);
+        return _toWasmFunction(func, module!, null);
       },
-      externRef: (id) => getReference(id, module),
+      externRef: (id) => getReference(id, module!),
     );
   }
 }
@@ -503,6 +704,15 @@ class _Builder extends WasmInstanceBuilder {
     );
   }
 
+  @override
+  WasmInstanceBuilder linkModule(String namespace, WasmModule module) {
+    mod.linkModule(
+      namespace: namespace,
+      module: (module as _WasmModule).module,
+    );
+    return this;
+  }
+
   @override
   WasmInstanceFuel? fuel() => _fuel;
 
@@ -543,6 +753,9 @@ class _WasmInstanceFuel extends WasmInstanceFuel {
 
   @override
   int fuelAdded() => _fuelAdded;
+
+  @override
+  int? lastCallFuelConsumed() => module.lastCallFuelConsumed();
 }
 
 class _Instance extends WasmInstance {
@@ -556,6 +769,7 @@ class _Instance extends WasmInstance {
 
   Stream<Uint8List>? _stderr;
   Stream<Uint8List>? _stdout;
+  int? _wasiExitCode;
 
   _Instance(this.instance, this.builder) {
     final d = instance.exports();
@@ -565,22 +779,23 @@ class _Instance extends WasmInstance {
     );
     final wasiConfig = builder.wasiConfig;
     if (wasiConfig != null) {
-      if (wasiConfig.captureStderr) {
+      final captureStreams = wasiConfig.captureCapacity == null;
+      if (wasiConfig.captureStderr && captureStreams) {
         _stderr ??=
             builder.mod.stdioStream(kind: StdIOKind.stderr).asBroadcastStream();
         _stderr!.first;
       }
-      if (wasiConfig.captureStdout) {
+      if (wasiConfig.captureStdout && captureStreams) {
         _stdout ??=
             builder.mod.stdioStream(kind: StdIOKind.stdout).asBroadcastStream();
         _stdout!.first;
       }
 
-      // TODO: extract into separate function
-      if (getFunction('_start')?.params.isEmpty ?? false) {
-        getFunction('_start')!();
-      } else if (getFunction('_initialize')?.params.isEmpty ?? false) {
-        getFunction('_initialize')!();
+      // Calls `_initialize` for reactors, never `_start`,
+      // and `_start` for commands. Libraries are not initialized.
+      if ((getFunction('_initialize')?.params.isEmpty ?? false) ||
+          (getFunction('_start')?.params.isEmpty ?? false)) {
+        _wasiExitCode = _References.guardHostErrors(builder.mod.initialize);
       } else {
         logWasiNoStartOrInitialize();
       }
@@ -696,6 +911,11 @@ class _Instance extends WasmInstance {
       throw Exception('Wasi is not enabled');
     } else if (builder.wasiConfig!.captureStderr == false) {
       throw Exception('Wasi is not capturing stderr');
+    } else if (_stderr == null) {
+      throw Exception(
+        'Wasi stderr is captured with `WasiConfig.captureCapacity`,'
+        ' use `readCapturedOutput`',
+      );
     }
     return _stderr!;
   }
@@ -706,14 +926,134 @@ class _Instance extends WasmInstance {
       throw Exception('Wasi is not enabled');
     } else if (builder.wasiConfig!.captureStdout == false) {
       throw Exception('Wasi is not capturing stdout');
+    } else if (_stdout == null) {
+      throw Exception(
+        'Wasi stdout is captured with `WasiConfig.captureCapacity`,'
+        ' use `readCapturedOutput`',
+      );
     }
     return _stdout!;
   }
 
+  StdIOKind _stdioKind(bool stderr) =>
+      stderr ? StdIOKind.stderr : StdIOKind.stdout;
+
+  @override
+  Uint8List readCapturedOutput({bool stderr = false}) =>
+      builder.mod.readStdio(kind: _stdioKind(stderr));
+
+  @override
+  Future<Uint8List?> nextCapturedOutput({
+    required Duration timeout,
+    bool stderr = false,
+  }) =>
+      builder.mod.nextStdioChunk(
+        kind: _stdioKind(stderr),
+        timeoutMillis: timeout.inMilliseconds,
+      );
+
+  @override
+  int capturedOutputDroppedBytes({bool stderr = false}) =>
+      builder.mod.stdioDroppedBytes(kind: _stdioKind(stderr));
+
+  @override
+  void closeCapturedOutput({bool stderr = false}) =>
+      builder.mod.closeStdio(kind: _stdioKind(stderr));
+
+  @override
+  int? get wasiExitCode => _wasiExitCode;
+
+  @override
+  late final Stream<String> wasiDeniedPaths =
+      builder.mod.wasiDeniedStream().asBroadcastStream();
+
+  /// Maps the [args] with the parameters of [function]. The arguments are
+  /// not mapped if [function] is not a function, the call reports the error.
+  List<WasmVal> _mapArgs(WasmExternal? function, List<Object?> args) {
+    if (function is! WasmFunction || args.isEmpty) return const [];
+    if (args.length != function.params.length) {
+      throw ArgumentError.value(
+        args,
+        'args',
+        'Expected ${function.params.length} arguments',
+      );
+    }
+    int i = 0;
+    return args
+        .map((v) => _fromWasmValueRaw(function.params[i++]!, v, builder.mod))
+        .toList(growable: false);
+  }
+
+  List<Object?> _callSync(List<WasmVal> Function() call) {
+    final result = _References.guardHostErrors(call);
+    if (result.isEmpty) return const [];
+    return result
+        .map((r) => _References.dartValueFromWasm(r, builder.mod))
+        .toList(growable: false);
+  }
+
+  @override
+  List<Object?> callByIndex(int index, [List<Object?> args = const []]) {
+    final function = index >= 0 && index < exports.length
+        ? exports.values.elementAt(index)
+        : null;
+    final mapped = _mapArgs(function, args);
+    return _callSync(
+      () => builder.mod.callByIndex(index: index, args: mapped),
+    );
+  }
+
   @override
-  void dispose() {
-    // TODO: dispose
+  List<Object?> callExport(String name, [List<Object?> args = const []]) {
+    final mapped = _mapArgs(exports[name], args);
+    return _callSync(
+      () => builder.mod.callExport(name: name, args: mapped),
+    );
   }
+
+  @override
+  int guestAlloc(int size, {String? allocExport}) =>
+      builder.mod.guestAlloc(size: size, allocExport: allocExport);
+
+  @override
+  void guestFree(int ptr, int size, {String? freeExport}) =>
+      builder.mod.guestFree(ptr: ptr, size: size, freeExport: freeExport);
+
+  @override
+  ({int ptr, int len}) writeGuestString(String value, {String? memoryName}) {
+    final location = builder.mod.writeGuestString(
+      value: value,
+      memoryName: memoryName,
+    );
+    return (ptr: location.pointer, len: location.length);
+  }
+
+  @override
+  String readGuestString(int ptr, int len, {String? memoryName}) =>
+      builder.mod.readGuestString(ptr: ptr, len: len, memoryName: memoryName);
+
+  @override
+  List<String> memories() => builder.mod.memories();
+
+  @override
+  MemoryFootprint memoryFootprint() => builder.mod.memoryFootprint();
+
+  @override
+  void setMaxHostCallDepth(int? maxDepth) =>
+      builder.mod.setMaxHostCallDepth(maxDepth: maxDepth);
+
+  @override
+  void setEpochDeadline(int? ticks) =>
+      builder.mod.setEpochDeadline(ticksBeyondCurrent: ticks);
+
+  @override
+  Duration? get lastCallDuration {
+    final micros = builder.mod.lastCallDurationMicros();
+    return micros == null ? null : Duration(microseconds: micros);
+  }
+
+  @override
+  void dispose() => builder.mod.close();
 }
 
 class _Memory extends WasmMemory {
@@ -801,6 +1141,7 @@ class _WasmFunction extends WasmFunction {
     required super.results,
     super.name,
     super.call,
+    super.callAsync,
   });
 
   final WFunc func;
@@ -1,6 +1,7 @@
 import 'dart:typed_data';
 
-import 'package:wasm_run/src/bridge_generated.dart';
+import 'package:wasm_run/src/bridge_generated.dart'
+    hide WasmEngine, WasmLinker;
 import 'package:wasm_run/src/wasm_bindings/wasm_interface.dart';
 
 bool isVoidReturn(dynamic value) => throw UnimplementedError();
@@ -8,6 +9,7 @@ bool isVoidReturn(dynamic value) => throw UnimplementedError();
 Future<WasmModule> compileWasmModule(
   Uint8List bytes, {
   ModuleConfig? config,
+  CompileHandle? compileHandle,
 }) async {
   throw UnimplementedError();
 }
@@ -21,3 +23,8 @@ WasmModule compileWasmModuleSync(
 
 Future<WasmRuntimeFeatures> wasmRuntimeFeatures() async =>
     throw UnimplementedError();
+
+CompileHandle createCompileHandle() => throw UnimplementedError();
+
+WasmEngine createWasmEngine({ModuleConfig? config}) =>
+    throw UnimplementedError();
@@ -67,6 +67,7 @@ Future<WasmRuntimeFeatures> _calculateFeatures() async {
     multiValue: features[7],
     bulkMemory: features[1],
     floats: true,
+    nanCanonicalization: false,
     threads: features[16],
     exceptions: features[2], // not in firefox
     simd: features[13], //  not in safari
@@ -79,6 +80,7 @@ Future<WasmRuntimeFeatures> _calculateFeatures() async {
     componentModel: false, // TODO(web-compat): check
     memoryControl: false, // TODO(web-compat): check
     garbageCollection: features[4],
+    functionReferences: false, // TODO(web-compat): check
     typeReflection: typeReflection,
     wasiFeatures: const WasmWasiFeatures(
       io: true,
@@ -99,6 +101,35 @@ Future<WasmRuntimeFeatures> _calculateFeatures() async {
     isBrowser: true,
     supportedFeatures: wasmFeatures,
     defaultFeatures: wasmFeatures,
+    // The browser only exposes the proposals it enables by default
+    stability: [
+      for (final (feature, enabled) in [
+        ('multi_value', wasmFeatures.multiValue),
+        ('bulk_memory', wasmFeatures.bulkMemory),
+        ('reference_types', wasmFeatures.referenceTypes),
+        ('mutable_global', wasmFeatures.mutableGlobal),
+        ('saturating_float_to_int', wasmFeatures.saturatingFloatToInt),
+        ('sign_extension', wasmFeatures.signExtension),
+        ('floats', wasmFeatures.floats),
+        ('simd', wasmFeatures.simd),
+        ('relaxed_simd', wasmFeatures.relaxedSimd),
+        ('threads', wasmFeatures.threads),
+        ('multi_memory', wasmFeatures.multiMemory),
+        ('memory64', wasmFeatures.memory64),
+        ('tail_call', wasmFeatures.tailCall),
+        ('extended_const', wasmFeatures.extendedConst),
+        ('memory_control', wasmFeatures.memoryControl),
+        ('garbage_collection', wasmFeatures.garbageCollection),
+        ('function_references', wasmFeatures.functionReferences),
+        ('exceptions', wasmFeatures.exceptions),
+        ('component_model', wasmFeatures.componentModel),
+      ])
+        WasmFeatureStability(
+          feature: feature,
+          stability:
+              enabled ? FeatureStability.Stable : FeatureStability.Unsupported,
+        ),
+    ],
   );
 }
 
@@ -110,6 +141,7 @@ Future<WasmRuntimeFeatures> wasmRuntimeFeatures() {
 Future<WasmModule> compileWasmModule(
   Uint8List bytes, {
   ModuleConfig? config,
+  CompileHandle? compileHandle,
 }) async {
   return _WasmModule.compileAsync(bytes);
 }
@@ -121,6 +153,12 @@ WasmModule compileWasmModuleSync(
   return _WasmModule(bytes);
 }
 
+CompileHandle createCompileHandle() =>
+    throw UnsupportedError('Compile handles are not supported on web');
+
+WasmEngine createWasmEngine({ModuleConfig? config}) =>
+    throw UnsupportedError('Wasm engines are not supported on web');
+
 Map<String, Object> _mapWasiFiles(Map<String, WasiFd> items) {
   return items.map(
     (key, value) {
@@ -212,16 +250,31 @@ class _WasmModule extends WasmModule {
 
   @override
   List<WasmModuleExport> getExports() {
-    return module.exports
+    return module.exports.indexed
         .map(
           (e) => WasmModuleExport(
-            e.name,
-            WasmExternalKind.values.byName(e.kind.name),
-            type: _getExternalType(e),
+            e.$2.name,
+            WasmExternalKind.values.byName(e.$2.kind.name),
+            type: _getExternalType(e.$2),
+            index: e.$1,
           ),
         )
         .toList(growable: false);
   }
+
+  @override
+  WasiModuleKind wasiModuleKind() {
+    final functions = module.exports
+        .where((e) => e.kind.name == WasmExternalKind.function.name)
+        .map((e) => e.name)
+        .toSet();
+    if (functions.contains('_initialize')) {
+      return WasiModuleKind.reactor;
+    } else if (functions.contains('_start')) {
+      return WasiModuleKind.command;
+    }
+    return WasiModuleKind.library;
+  }
 }
 
 class _Builder extends WasmInstanceBuilder {
@@ -301,6 +354,10 @@ class _Builder extends WasmInstanceBuilder {
     return _Global(inner, type, value);
   }
 
+  @override
+  WasmInstanceBuilder linkModule(String namespace, WasmModule module) =>
+      throw UnsupportedError('Linking modules is not supported on web');
+
   @override
   WasmInstanceBuilder addImport(
     String moduleName,
@@ -452,6 +509,7 @@ class _Instance extends WasmInstance {
   final List<WasmWorker>? workers;
   final List<WasmWorker> availableWorkers;
   final Queue<WorkerTask> tasks = Queue();
+  int? _wasiExitCode;
 
   _Instance(this.builder, this.instance, this.workers)
       : module = _WasmModule._(instance.module),
@@ -483,10 +541,11 @@ class _Instance extends WasmInstance {
     );
     final wasi = builder.wasi?.inner;
     if (wasi != null) {
-      if (getFunction('_start')?.params.isEmpty ?? false) {
-        wasi.start(instance.jsObject);
-      } else if (getFunction('_initialize')?.params.isEmpty ?? false) {
+      // `_start` is never called for reactors
+      if (getFunction('_initialize')?.params.isEmpty ?? false) {
         wasi.initialize(instance.jsObject);
+      } else if (getFunction('_start')?.params.isEmpty ?? false) {
+        _wasiExitCode = wasi.start(instance.jsObject);
       } else {
         js_util.setProperty(wasi, 'inst', instance.jsObject);
         logWasiNoStartOrInitialize();
@@ -603,6 +662,211 @@ class _Instance extends WasmInstance {
     return stream;
   }
 
+  @override
+  Uint8List readCapturedOutput({bool stderr = false}) =>
+      throw UnsupportedError('Capture buffers are not supported on web');
+
+  @override
+  Future<Uint8List?> nextCapturedOutput({
+    required Duration timeout,
+    bool stderr = false,
+  }) =>
+      throw UnsupportedError('Capture buffers are not supported on web');
+
+  @override
+  int capturedOutputDroppedBytes({bool stderr = false}) =>
+      throw UnsupportedError('Capture buffers are not supported on web');
+
+  @override
+  void closeCapturedOutput({bool stderr = false}) =>
+      throw UnsupportedError('Capture buffers are not supported on web');
+
+  @override
+  int? get wasiExitCode => _wasiExitCode;
+
+  @override
+  Duration? get lastCallDuration => null;
+
+  @override
+  Stream<String> get wasiDeniedPaths =>
+      throw UnsupportedError('WASI denied paths are not supported on web');
+
+  @override
+  List<Object?> callByIndex(int index, [List<Object?> args = const []]) {
+    // The [exports] map is grouped by kind, not in export order
+    final moduleExports = module.getExports();
+    RangeError.checkValidIndex(index, moduleExports, 'index');
+    final name = moduleExports[index].name;
+    final function = getFunction(name);
+    if (function == null) {
+      throw ArgumentError.value(
+        index,
+        'index',
+        'The export `$name` is not a function',
+      );
+    }
+    return function.call(args);
+  }
+
+  @override
+  List<Object?> callExport(String name, [List<Object?> args = const []]) {
+    if (name == '_initialize' || name == '_start') {
+      throw ArgumentError.value(
+        name,
+        'name',
+        'It is called when the instance is built',
+      );
+    } else if (wasiModuleKind == WasiModuleKind.command) {
+      throw StateError('The exports of a WASI command can not be called');
+    }
+    final function = getFunction(name);
+    if (function == null) {
+      throw ArgumentError.value(name, 'name', 'The function is not exported');
+    }
+    return function.call(args);
+  }
+
+  /// Calls the first function in [names] exported by the instance with the
+  /// arguments returned by [args] for its number of parameters.
+  List<Object?> _callGuestExport(
+    List<String> names,
+    List<Object?>? Function(int numParams) args,
+  ) {
+    for (final name in names) {
+      final function = getFunction(name);
+      if (function == null) continue;
+      final numParams = function.params.length;
+      final arguments = args(numParams);
+      if (arguments == null) {
+        throw StateError(
+          'The `$name` function can not have $numParams parameters',
+        );
+      }
+      return function.call(arguments);
+    }
+    throw StateError(
+      'The module does not export a `${names.join('` or `')}` function',
+    );
+  }
+
+  /// Allocates [size] bytes with the first allocation function in [names].
+  /// [align] is the alignment passed to `cabi_realloc` like functions.
+  int _allocGuest(List<String> names, int size, int align) {
+    final output = _callGuestExport(
+      names,
+      (numParams) => switch (numParams) {
+        1 => [size],
+        4 => [0, 0, align, size],
+        _ => null,
+      },
+    );
+    final ptr = output.length == 1 ? output.first : null;
+    if (ptr is! int) {
+      throw StateError('The allocation function should return an i32 pointer');
+    } else if (ptr == 0 && size != 0) {
+      throw StateError('The module could not allocate $size bytes');
+    }
+    return ptr;
+  }
+
+  @override
+  int guestAlloc(int size, {String? allocExport}) => _allocGuest(
+        allocExport == null ? const ['malloc', 'cabi_realloc'] : [allocExport],
+        size,
+        8,
+      );
+
+  @override
+  void guestFree(int ptr, int size, {String? freeExport}) {
+    _callGuestExport(
+      [freeExport ?? 'free'],
+      (numParams) => switch (numParams) {
+        1 => [ptr],
+        2 => [ptr, size],
+        _ => null,
+      },
+    );
+  }
+
+  /// The memory exported as [name]. Without a [name], the only memory
+  /// exported by the instance.
+  WasmMemory _exportedMemory(String? name) {
+    final names = memories();
+    final memoryName = name ??
+        switch (names) {
+          [final only] => only,
+          [] => throw StateError('The module does not export a `memory`'),
+          _ => throw ArgumentError.value(
+              name,
+              'memoryName',
+              'The module exports more than one memory $names',
+            ),
+        };
+    final memory = getMemory(memoryName);
+    if (memory == null) {
+      throw ArgumentError.value(
+        name,
+        'memoryName',
+        'The memory is not exported',
+      );
+    }
+    return memory;
+  }
+
+  @override
+  ({int ptr, int len}) writeGuestString(String value, {String? memoryName}) {
+    final bytes = utf8.encode(value);
+    final ptr = _allocGuest(const ['cabi_realloc'], bytes.length, 1);
+    _exportedMemory(memoryName).view.setAll(ptr, bytes);
+    return (ptr: ptr, len: bytes.length);
+  }
+
+  @override
+  String readGuestString(int ptr, int len, {String? memoryName}) {
+    final view = _exportedMemory(memoryName).view;
+    return utf8.decode(Uint8List.sublistView(view, ptr, ptr + len));
+  }
+
+  @override
+  List<String> memories() => module
+      .getExports()
+      .where((e) => e.kind == WasmExternalKind.memory)
+      .map((e) => e.name)
+      .toList(growable: false);
+
+  @override
+  MemoryFootprint memoryFootprint() {
+    // The same estimates of the native runtime in 64-bit hosts
+    const tableElementBytes = 8;
+    const globalBytes = 16;
+    final memories = exports.values.whereType<WasmMemory>();
+    final tables = exports.values.whereType<WasmTable>();
+    final globals = exports.values.whereType<WasmGlobal>().length;
+
+    final memoryBytes = memories.fold(0, (sum, m) => sum + m.lengthInBytes);
+    final tableElements = tables.fold(0, (sum, t) => sum + t.length);
+    final overheadBytes =
+        tableElements * tableElementBytes + globals * globalBytes;
+    return MemoryFootprint(
+      memories: memories.length,
+      memoryPages: memories.fold(0, (sum, m) => sum + m.lengthInPages),
+      memoryBytes: memoryBytes,
+      tables: tables.length,
+      tableElements: tableElements,
+      globals: globals,
+      overheadBytes: overheadBytes,
+      totalBytes: memoryBytes + overheadBytes,
+    );
+  }
+
+  @override
+  void setMaxHostCallDepth(int? maxDepth) =>
+      throw UnsupportedError('Host call depth limits are not supported on web');
+
+  @override
+  void setEpochDeadline(int? ticks) =>
+      throw UnsupportedError('Epoch interruption is not supported on web');
+
   @override
   void dispose() {
     builder.wasi?.stderr?.streamController.close();
@@ -1,6 +1,7 @@
 import 'dart:typed_data';
 
-import 'package:wasm_run/src/bridge_generated.dart';
+import 'package:wasm_run/src/bridge_generated.dart'
+    hide WasmEngine, WasmLinker;
 import 'package:wasm_run/src/wasm_bindings/_wasm_interop_stub.dart'
     if (dart.library.io) '_wasm_interop_native.dart'
     if (dart.library.html) '_wasm_interop_web.dart' as platform_impl;
@@ -8,13 +9,18 @@ import 'package:wasm_run/src/wasm_bindings/wasm_interface.dart';
 
 export 'package:wasm_run/src/bridge_generated.dart'
     show
+        CompileHandle,
         EnvVariable,
         ExternalType,
+        FeatureStability,
+        FuelConsumptionMode,
+        ModuleCacheStats,
         ModuleConfig,
         ModuleConfigWasmi,
         ModuleConfigWasmtime,
         PreopenedDir,
         WasiStackLimits,
+        WasmFeatureStability,
         WasmFeatures,
         WasmRuntimeFeatures,
         WasmWasiFeatures;
@@ -30,11 +36,18 @@ Future<WasmRuntimeFeatures> wasmRuntimeFeatures() async =>
     platform_impl.wasmRuntimeFeatures();
 
 /// Compiles a Wasm module asynchronously.
+/// The compilation fails when [compileHandle] is cancelled,
+/// see [createCompileHandle].
 Future<WasmModule> compileWasmModule(
   Uint8List bytes, {
   ModuleConfig? config,
+  CompileHandle? compileHandle,
 }) async {
-  return platform_impl.compileWasmModule(bytes, config: config);
+  return platform_impl.compileWasmModule(
+    bytes,
+    config: config,
+    compileHandle: compileHandle,
+  );
 }
 
 /// Compiles a Wasm module synchronously.
@@ -45,3 +58,12 @@ WasmModule compileWasmModuleSync(
 }) {
   return platform_impl.compileWasmModuleSync(bytes, config: config);
 }
+
+/// Creates a handle to cancel the compilation of [compileWasmModule].
+/// Not supported in the web.
+CompileHandle createCompileHandle() => platform_impl.createCompileHandle();
+
+/// Creates a [WasmEngine] that compiles the modules with [config].
+/// Not supported in the web.
+WasmEngine createWasmEngine({ModuleConfig? config}) =>
+    platform_impl.createWasmEngine(config: config);
@@ -1,18 +1,26 @@
+import 'dart:convert' show utf8;
 import 'dart:typed_data';
 
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart'
+    show FfiException;
 import 'package:meta/meta.dart';
 import 'package:wasm_run/src/bridge_generated.dart'
     show
+        CaptureOverflow,
         EnvVariable,
         ExternalType,
         GlobalTy,
+        MemoryFootprint,
         MemoryTy,
+        ModuleCacheStats,
+        ModuleConfig,
         PreopenedDir,
         SharedMemoryWaitResult,
         TableTy,
         U8Array16,
         ValueTy,
-        WasiConfigNative;
+        WasiConfigNative,
+        WasiModuleKind;
 import 'package:wasm_run/src/int64_bigint/int64_bigint.dart';
 import 'package:wasm_run/src/wasm_bindings/_wasm_interop_stub.dart'
     if (dart.library.io) '_wasm_interop_native.dart'
@@ -20,16 +28,19 @@ import 'package:wasm_run/src/wasm_bindings/_wasm_interop_stub.dart'
 
 export 'package:wasm_run/src/bridge_generated.dart'
     show
+        CaptureOverflow,
         EnvVariable,
         ExternalType,
         FuncTy,
         GlobalTy,
+        MemoryFootprint,
         MemoryTy,
         PreopenedDir,
         SharedMemoryWaitResult,
         TableTy,
         U8Array16,
         ValueTy,
+        WasiModuleKind,
         WasmFeatures;
 export 'package:wasm_run/src/int64_bigint/int64_bigint.dart';
 
@@ -37,6 +48,10 @@ export 'package:wasm_run/src/int64_bigint/int64_bigint.dart';
 /// You may introspect it by using [getImports] and [getExports].
 /// You may use [builder] to create a [WasmInstance] from it to execute it
 /// or use its exports.
+///
+/// A module is compiled once and may be instantiated many times, for example,
+/// an instance per request. Each [WasmInstance] has its own isolated memories,
+/// tables, globals and WASI context, and creating it is cheap compared to compiling.
 abstract class WasmModule {
   /// A builder that creates a new [WasmInstance] from this module.
   /// It configures the imports and definitions of the instance.
@@ -63,10 +78,95 @@ abstract class WasmModule {
   /// Returns a list of exports provided by this module.
   List<WasmModuleExport> getExports();
 
+  /// The kind of WASI module, from its `_initialize` and `_start` exports.
+  /// Reactors are initialized with `_initialize` and commands with `_start`
+  /// when they are instantiated with a [WasiConfig].
+  WasiModuleKind wasiModuleKind();
+
   @override
   String toString() => 'WasmModule(${getImports()}, ${getExports()})';
 }
 
+/// A compilation environment shared by the modules compiled with it.
+/// The modules compiled with the same engine share its [config] and
+/// may be instantiated with the same [WasmLinker].
+/// Created with [createWasmEngine]. Not supported in the web.
+abstract class WasmEngine {
+  /// The configuration of the modules compiled with this engine.
+  ModuleConfig get config;
+
+  /// Compiles a Wasm module asynchronously with this engine.
+  Future<WasmModule> compileWasmModule(Uint8List bytes);
+
+  /// Compiles a Wasm module synchronously with this engine.
+  /// You should use [compileWasmModule], unless the module is small.
+  WasmModule compileWasmModuleSync(Uint8List bytes);
+
+  /// Increments the epoch of the engine, interrupting the functions of the
+  /// instances that reached their epoch deadline when epoch interruption
+  /// is enabled. Not supported in the wasmi executor.
+  void incrementEpoch();
+
+  /// Creates a [WasmLinker] for the modules compiled with this engine.
+  /// The WASI imports are defined once in the linker if [wasi] is true.
+  WasmLinker createLinker({bool wasi = false});
+
+  /// Creates a [WasmModuleCache] for the modules compiled with this engine.
+  /// The least recently used modules are evicted when there are more than
+  /// [maxEntries] modules or their binaries exceed [maxBytes] in total.
+  /// If [directory] is provided, the compiled artifacts are also stored
+  /// in it and loaded instead of compiling the module again. Loading an
+  /// artifact runs its native code, so the directory must only be writable
+  /// by the app. The artifacts are checked for corruption, not for tampering.
+  WasmModuleCache createModuleCache({
+    int? maxEntries,
+    int? maxBytes,
+    String? directory,
+  });
+}
+
+/// The imports defined once and shared by all the modules instantiated
+/// with it, created with [WasmEngine.createLinker].
+abstract class WasmLinker {
+  /// Defines the host [function] `moduleName::name` for all the modules
+  /// instantiated with this linker. The function is shared by the instances,
+  /// so its [WasmFunction.results] are required and its parameters and
+  /// results can not be references.
+  void defineFunction(String moduleName, String name, WasmFunction function);
+
+  /// Defines the memory `moduleName::name` for all the modules instantiated
+  /// with this linker. A new memory is created for each instance.
+  void defineMemory(
+    String moduleName,
+    String name, {
+    required int minPages,
+    int? maxPages,
+  });
+
+  /// A builder that creates a new [WasmInstance] from [module] with the
+  /// imports of this linker. [module] must be compiled by the engine of the
+  /// linker and [wasiConfig] must be provided if, and only if,
+  /// the linker was created with WASI.
+  WasmInstanceBuilder builder(WasmModule module, {WasiConfig? wasiConfig});
+}
+
+/// Keeps the modules compiled with a [WasmEngine] by the hash of their
+/// bytes, created with [WasmEngine.createModuleCache].
+abstract class WasmModuleCache {
+  /// Returns the cached module for [bytes] or compiles it asynchronously.
+  Future<WasmModule> compileWasmModule(Uint8List bytes);
+
+  /// Returns the cached module for [bytes] or compiles it synchronously.
+  WasmModule compileWasmModuleSync(Uint8List bytes);
+
+  /// The number of modules, hits and evictions of this cache.
+  ModuleCacheStats stats();
+
+  /// Removes all the modules from memory.
+  /// The artifacts in the directory of the cache are not deleted.
+  void clear();
+}
+
 /// Configuration for spawning workers.
 class WorkersConfig {
   /// The number of threads or workers to spawn
@@ -138,6 +238,65 @@ class WasiDirectory implements WasiFd {
   WasiDirectory(this.items);
 }
 
+/// The environment variables of the WASI module, see [WasiProcessConfig].
+class EnvMode {
+  /// Whether the variables of the host process are inherited.
+  final bool inherit;
+
+  /// The variables passed to the module, they replace
+  /// the inherited ones with the same name.
+  final List<EnvVariable> values;
+
+  const EnvMode._(this.inherit, this.values);
+
+  /// The variables of the host process.
+  const EnvMode.inherit() : this._(true, const []);
+
+  /// Only the given variables.
+  const EnvMode.explicit(List<EnvVariable> values) : this._(false, values);
+
+  /// The variables of the host process, where [values]
+  /// replace the ones with the same name.
+  const EnvMode.inheritThenOverride(List<EnvVariable> values)
+      : this._(true, values);
+}
+
+/// The process arguments of the WASI module, see [WasiProcessConfig].
+class ArgsMode {
+  /// Whether the arguments of the host process are inherited.
+  final bool inherit;
+
+  /// The arguments passed to the module after the inherited ones.
+  final List<String> values;
+
+  const ArgsMode._(this.inherit, this.values);
+
+  /// The arguments of the host process.
+  const ArgsMode.inherit() : this._(true, const []);
+
+  /// Only the given arguments.
+  const ArgsMode.explicit(List<String> values) : this._(false, values);
+
+  /// The arguments of the host process followed by [values].
+  const ArgsMode.inheritThenOverride(List<String> values)
+      : this._(true, values);
+}
+
+/// The environment variables and arguments of the WASI module.
+class WasiProcessConfig {
+  /// The environment variables of the module.
+  final EnvMode env;
+
+  /// The process arguments of the module.
+  final ArgsMode args;
+
+  /// The environment variables and arguments of the WASI module.
+  const WasiProcessConfig({
+    this.env = const EnvMode.explicit([]),
+    this.args = const ArgsMode.explicit([]),
+  });
+}
+
 /// The configuration and arguments for the WASI module.
 class WasiConfig implements WasiConfigNative {
   @override
@@ -145,19 +304,38 @@ class WasiConfig implements WasiConfigNative {
   @override
   final bool captureStderr;
   @override
+  final bool lineBufferedOutput;
+  @override
   final bool inheritStdin;
   @override
-  final bool inheritEnv;
+  bool get inheritEnv => process?.env.inherit ?? _inheritEnv;
+  final bool _inheritEnv;
   @override
-  final bool inheritArgs;
+  bool get inheritArgs => process?.args.inherit ?? _inheritArgs;
+  final bool _inheritArgs;
   @override
-  final List<String> args;
+  List<String> get args => process?.args.values ?? _args;
+  final List<String> _args;
   @override
-  final List<EnvVariable> env;
+  List<EnvVariable> get env => process?.env.values ?? _env;
+  final List<EnvVariable> _env;
+
+  /// The environment variables and arguments of the module.
+  /// When it is set, it is used instead of [inheritEnv], [env],
+  /// [inheritArgs] and [args], which can be set independently.
+  final WasiProcessConfig? process;
   @override
   List<String> get preopenedFiles => const [];
   @override
   final List<PreopenedDir> preopenedDirs;
+  @override
+  final int? captureCapacity;
+  @override
+  final CaptureOverflow captureOverflow;
+  @override
+  final bool deterministic;
+  @override
+  final int? unknownErrno;
 
   /// Not supported outside the browser executor.
   final Map<String, WasiDirectory> webBrowserFileSystem;
@@ -168,12 +346,113 @@ class WasiConfig implements WasiConfigNative {
     required this.webBrowserFileSystem,
     this.captureStdout = false,
     this.captureStderr = false,
+    this.lineBufferedOutput = false,
     this.inheritStdin = false,
-    this.inheritEnv = false,
-    this.inheritArgs = false,
-    this.args = const [],
-    this.env = const [],
+    @Deprecated('Use process') bool inheritEnv = false,
+    @Deprecated('Use process') bool inheritArgs = false,
+    @Deprecated('Use process') List<String> args = const [],
+    @Deprecated('Use process') List<EnvVariable> env = const [],
+    this.process,
+    this.captureCapacity,
+    this.captureOverflow = CaptureOverflow.drop,
+    this.deterministic = false,
+    this.unknownErrno,
+  })  : _inheritEnv = inheritEnv,
+        _inheritArgs = inheritArgs,
+        _args = args,
+        _env = env;
+}
+
+/// A failed WASI operation with its `wasi_snapshot_preview1` errno code.
+/// For example, when a preopened directory does not exist.
+class WasiError implements Exception {
+  /// The errno code, for example, `44` for `ENOENT`.
+  final int errno;
+
+  /// The name of the errno code, for example, `ENOENT` or `EACCES`.
+  /// `EUNKNOWN` for the codes that are not mapped.
+  final String name;
+
+  /// The description of the failed operation and the errno code.
+  final String message;
+
+  /// A failed WASI operation with its `wasi_snapshot_preview1` errno code.
+  const WasiError({
+    required this.errno,
+    required this.name,
+    required this.message,
   });
+
+  static final _pattern =
+      RegExp(r'WASI error (E[A-Z0-9]+) \((\d+)\): ([^\n]*)');
+
+  /// Returns the [WasiError] in the message of an [error] thrown
+  /// by the native executor or null if it is not a WASI error.
+  static WasiError? fromException(Object error) {
+    final message = error is FfiException ? error.message : error.toString();
+    final match = _pattern.firstMatch(message);
+    if (match == null) return null;
+    return WasiError(
+      errno: int.parse(match.group(2)!),
+      name: match.group(1)!,
+      message: match.group(3)!,
+    );
+  }
+
+  @override
+  String toString() => 'WASI error $name ($errno): $message';
+}
+
+/// The reason a module could not be compiled.
+enum CompileErrorKind {
+  /// The module binary is malformed or does not validate
+  /// with the enabled Wasm features.
+  invalid,
+
+  /// The module is valid, but the runtime could not compile it.
+  compilation,
+}
+
+/// A module that failed to compile, with the position of the problem.
+class CompileError implements Exception {
+  /// The byte offset in the module binary where the error was found,
+  /// if it is known.
+  final int? offset;
+
+  /// The reason the module could not be compiled.
+  final CompileErrorKind kind;
+
+  /// The description of the error.
+  final String message;
+
+  /// A module that failed to compile, with the position of the problem.
+  const CompileError({
+    required this.offset,
+    required this.kind,
+    required this.message,
+  });
+
+  static final _pattern = RegExp(
+    r'Compile error \((invalid|compilation)\)(?: at offset (\d+))?: ([^\n]*)',
+  );
+
+  /// Returns the [CompileError] in the message of an [error] thrown
+  /// by the native executor or null if it is not a compile error.
+  static CompileError? fromException(Object error) {
+    final message = error is FfiException ? error.message : error.toString();
+    final match = _pattern.firstMatch(message);
+    if (match == null) return null;
+    final offset = match.group(2);
+    return CompileError(
+      offset: offset == null ? null : int.parse(offset),
+      kind: CompileErrorKind.values.byName(match.group(1)!),
+      message: match.group(3)!,
+    );
+  }
+
+  @override
+  String toString() => 'Compile error (${kind.name})'
+      '${offset == null ? '' : ' at offset $offset'}: $message';
 }
 
 /// Constructs a new [WasmInstance] from a [WasmModule]
@@ -218,6 +497,12 @@ abstract class WasmInstanceBuilder {
     return this;
   }
 
+  /// Instantiates [module] with the imports added so far and adds its exports
+  /// as imports under the [namespace] module name. For example, to call the
+  /// functions exported by a library module. [module] should be compiled
+  /// with the same [WasmEngine]. Not supported in the web.
+  WasmInstanceBuilder linkModule(String namespace, WasmModule module);
+
   /// Returns the fuel that can be used to limit
   /// the amount of computations performed by the instance.
   WasmInstanceFuel? fuel();
@@ -304,7 +589,107 @@ abstract class WasmInstance {
   /// this is the stdout stream.
   Stream<Uint8List> get stdout;
 
+  /// When [WasiConfig.captureCapacity] is set, returns and removes the
+  /// stdout, or the stderr if [stderr] is true, kept in memory.
+  /// Not available in the web.
+  Uint8List readCapturedOutput({bool stderr = false});
+
+  /// When [WasiConfig.captureCapacity] is set, waits up to [timeout] for the
+  /// next stdout, or stderr if [stderr] is true, and removes it.
+  /// Completes with an empty list when the [timeout] elapses without output
+  /// and with `null` once all the output was returned after the module exited
+  /// or the capture buffer was closed.
+  /// Not available in the web.
+  Future<Uint8List?> nextCapturedOutput({
+    required Duration timeout,
+    bool stderr = false,
+  });
+
+  /// The number of bytes of stdout, or stderr if [stderr] is true,
+  /// dropped because the capture buffer was full or closed.
+  /// Not available in the web.
+  int capturedOutputDroppedBytes({bool stderr = false});
+
+  /// Stops capturing stdout, or stderr if [stderr] is true, and frees the
+  /// capture buffer. The following output of the module is dropped.
+  /// Not available in the web.
+  void closeCapturedOutput({bool stderr = false});
+
+  /// When using WASI with [WasiConfig] in [WasmModule.builder] and the module
+  /// exports a `_start` function, this is the exit code of its execution.
+  /// The exit code passed to `proc_exit` or 0 if `_start` returned normally.
+  /// It is available independently of [WasiConfig.captureStdout]
+  /// and [WasiConfig.captureStderr].
+  int? get wasiExitCode;
+
+  /// The wall-clock duration of the last function call.
+  /// Null if no function was called. Not available in the web.
+  Duration? get lastCallDuration;
+
+  /// The guest paths denied by the WASI sandbox of this instance.
+  /// Not available in the web.
+  Stream<String> get wasiDeniedPaths;
+
+  /// The kind of WASI module of this instance, see [WasmModule.wasiModuleKind].
+  WasiModuleKind get wasiModuleKind => module.wasiModuleKind();
+
+  /// Calls the function at [index] in the exports of the module,
+  /// see [WasmModuleExport.index]. Throws if [index] is out of range
+  /// or the export is not a function.
+  List<Object?> callByIndex(int index, [List<Object?> args = const []]);
+
+  /// Calls the function [name] exported by a WASI reactor or library,
+  /// which were initialized when the instance was built.
+  /// Throws for `_initialize`, `_start` and the exports of commands.
+  List<Object?> callExport(String name, [List<Object?> args = const []]);
+
+  /// Allocates [size] bytes in the memory of the instance with its exported
+  /// allocator and returns the pointer. [allocExport] is the name of the
+  /// allocation function, by default `malloc`, or `cabi_realloc` when the
+  /// module does not export `malloc`.
+  int guestAlloc(int size, {String? allocExport});
+
+  /// Frees the [size] bytes at [ptr] allocated with [guestAlloc].
+  /// [freeExport] is the name of the function, by default `free`.
+  void guestFree(int ptr, int size, {String? freeExport});
+
+  /// Copies the UTF-8 bytes of [value] to the memory of the instance,
+  /// allocated with the exported `cabi_realloc` function, and returns
+  /// their location. [memoryName] is required when the instance exports
+  /// more than one memory, see [memories].
+  ({int ptr, int len}) writeGuestString(String value, {String? memoryName});
+
+  /// Reads the string of [len] UTF-8 bytes at [ptr] in the memory of the
+  /// instance. [memoryName] is required when the instance exports more than
+  /// one memory, see [memories].
+  String readGuestString(int ptr, int len, {String? memoryName});
+
+  /// The names of the memories exported by the instance, in export order.
+  List<String> memories();
+
+  /// The memory used by the instance, the sum of the sizes of its memories
+  /// and an estimate of the overhead of its tables and globals.
+  /// The native runtime tracks the sizes of all the memories and tables of
+  /// the instance, including the ones that are not exported. On web, only
+  /// the exported ones are known.
+  MemoryFootprint memoryFootprint();
+
+  /// Limits the number of nested host function calls to [maxDepth],
+  /// for example, when a host function calls back into the instance which
+  /// calls it again. Null does not limit them. Not supported in the web.
+  void setMaxHostCallDepth(int? maxDepth);
+
+  /// Interrupts the functions of the instance when the epoch of its engine
+  /// is incremented [ticks] times from now, see [WasmEngine.incrementEpoch].
+  /// Null removes the deadline, which is the default. Requires
+  /// `epoch_interruption` in the wasmtime config. Not supported in the web
+  /// and in the wasmi executor.
+  void setEpochDeadline(int? ticks);
+
   /// Frees up resources used by this instance.
+  /// The remaining output is flushed to [stdout] and [stderr], which are
+  /// closed, and the WASI resources, such as the preopened directories, are
+  /// released. Calling the functions of the instance afterwards throws.
   void dispose();
 
   @override
@@ -325,8 +710,82 @@ abstract class WasmMemory extends WasmExternal {
   int get lengthInPages;
 
   /// A view of the memory as a [Uint8List].
+  ///
+  /// The view is invalidated when the memory grows, which may happen
+  /// in any call into the guest. Read [view] again after those calls
+  /// instead of keeping the previous one.
   Uint8List get view;
 
+  /// A view of the memory for reading and writing typed values.
+  ByteData get _bytes => ByteData.sublistView(view);
+
+  /// Reads the little-endian i32 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  int readI32(int offset) => _bytes.getInt32(offset, Endian.little);
+
+  /// Reads the little-endian i64 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  I64 readI64(int offset) => i64.getInt64(_bytes, offset, Endian.little);
+
+  /// Reads the little-endian f32 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  double readF32(int offset) => _bytes.getFloat32(offset, Endian.little);
+
+  /// Reads the little-endian f64 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  double readF64(int offset) => _bytes.getFloat64(offset, Endian.little);
+
+  /// Reads the UTF-8 string starting at [offset] and ending
+  /// before the first NUL byte.
+  /// Throws a [RangeError] if there is no NUL byte after [offset].
+  String readCString(int offset) {
+    final bytes = view;
+    RangeError.checkValueInInterval(offset, 0, bytes.length, 'offset');
+    final end = bytes.indexOf(0, offset);
+    if (end == -1) {
+      throw RangeError('No NUL terminator found after offset $offset.');
+    }
+    return utf8.decode(Uint8List.sublistView(bytes, offset, end));
+  }
+
+  /// Writes [value] as a little-endian i32 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  void writeI32(int offset, int value) =>
+      _bytes.setInt32(offset, value, Endian.little);
+
+  /// Writes [value] as a little-endian i64 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  void writeI64(int offset, I64 value) =>
+      i64.setInt64(_bytes, offset, value, Endian.little);
+
+  /// Writes [value] as a little-endian f32 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  void writeF32(int offset, double value) =>
+      _bytes.setFloat32(offset, value, Endian.little);
+
+  /// Writes [value] as a little-endian f64 at [offset].
+  /// Throws a [RangeError] if the value is out of the memory bounds.
+  void writeF64(int offset, double value) =>
+      _bytes.setFloat64(offset, value, Endian.little);
+
+  /// Writes [value] UTF-8 encoded at [offset] followed by a NUL byte.
+  /// Returns the number of bytes written, including the NUL byte.
+  /// Throws a [RangeError] if the string is out of the memory bounds,
+  /// in which case nothing is written.
+  int writeCString(int offset, String value) {
+    final encoded = utf8.encode(value);
+    final bytes = view;
+    RangeError.checkValidRange(
+      offset,
+      offset + encoded.length + 1,
+      bytes.length,
+      'offset',
+    );
+    bytes.setAll(offset, encoded);
+    bytes[offset + encoded.length] = 0;
+    return encoded.length + 1;
+  }
+
   /// The number of bytes per page in a wasm memory.
   /// The maximum size of the memory in pages.
   /// [WasmMemory.bytesPerPage] = 65536 = 2^16 = 64KiB
@@ -452,7 +911,9 @@ class WasmFunction extends WasmExternal {
     required this.results,
     this.name,
     List<Object?> Function([List<Object?>? args])? call,
-  }) : _call = call;
+    Future<List<Object?>> Function([List<Object?>? args])? callAsync,
+  })  : _call = call,
+        _callAsync = callAsync;
 
   /// Constructs a Wasm function with no results.
   const WasmFunction.voidReturn(
@@ -460,8 +921,10 @@ class WasmFunction extends WasmExternal {
     required this.params,
     this.name,
     List<Object?> Function([List<Object?>? args])? call,
+    Future<List<Object?>> Function([List<Object?>? args])? callAsync,
   })  : results = const [],
-        _call = call;
+        _call = call,
+        _callAsync = callAsync;
 
   /// Optional name for debugging purposes.
   final String? name;
@@ -489,6 +952,7 @@ class WasmFunction extends WasmExternal {
   final Function inner;
 
   final List<Object?> Function([List<Object?>? args])? _call;
+  final Future<List<Object?>> Function([List<Object?>? args])? _callAsync;
 
   /// Invokes [inner] with the given [args]
   /// and casts the result to a [List] of Dart values.
@@ -506,6 +970,18 @@ class WasmFunction extends WasmExternal {
     return [values];
   }
 
+  /// Invokes the function with the given [args] without blocking
+  /// the Dart isolate. In native platforms, the call is executed in a separate
+  /// thread. Host functions can not be called within it, since they
+  /// are executed in the Dart isolate, the call will throw if the
+  /// function calls an imported host function.
+  /// In the web, and for functions that are not exported by a module,
+  /// this is the same as [call].
+  Future<List<Object?>> callAsync([List<Object?>? args]) async {
+    if (_callAsync != null) return _callAsync!(args);
+    return call(args);
+  }
+
   @override
   bool operator ==(Object other) {
     return other is WasmFunction && inner == other.inner;
@@ -638,6 +1114,40 @@ class WasmValue {
     U8Array16 this.value,
   ) : type = ValueTy.v128;
 
+  /// A 128 bit vector with sixteen 8-bit integer lanes.
+  /// The first lane is stored in the lowest bytes.
+  factory WasmValue.i8x16(List<int> lanes) =>
+      WasmValue._lanes(Int8List.fromList(lanes));
+
+  /// A 128 bit vector with eight 16-bit integer lanes.
+  /// The first lane is stored in the lowest bytes.
+  factory WasmValue.i16x8(List<int> lanes) =>
+      WasmValue._lanes(Int16List.fromList(lanes));
+
+  /// A 128 bit vector with four 32-bit integer lanes.
+  /// The first lane is stored in the lowest bytes.
+  factory WasmValue.i32x4(List<int> lanes) =>
+      WasmValue._lanes(Int32List.fromList(lanes));
+
+  /// A 128 bit vector with two 64-bit integer lanes.
+  /// The first lane is stored in the lowest bytes.
+  /// Not supported in the web, where 64-bit integer lists are not available.
+  factory WasmValue.i64x2(List<int> lanes) =>
+      WasmValue._lanes(Int64List.fromList(lanes));
+
+  /// A 128 bit vector with four 32-bit floating point lanes.
+  /// The first lane is stored in the lowest bytes.
+  factory WasmValue.f32x4(List<double> lanes) =>
+      WasmValue._lanes(Float32List.fromList(lanes));
+
+  /// A 128 bit vector with two 64-bit floating point lanes.
+  /// The first lane is stored in the lowest bytes.
+  factory WasmValue.f64x2(List<double> lanes) =>
+      WasmValue._lanes(Float64List.fromList(lanes));
+
+  factory WasmValue._lanes(TypedData lanes) =>
+      WasmValue.v128(U8Array16(lanes.buffer.asUint8List()));
+
   /// A nullable function reference.
   const factory WasmValue.funcRef(
     WasmFunction? value,
@@ -663,6 +1173,36 @@ class WasmValue {
   int get hashCode => Object.hash(runtimeType, value, type);
 }
 
+/// The lanes of a [ValueTy.v128] [WasmValue].
+extension WasmValueV128 on WasmValue {
+  /// The bytes of a [ValueTy.v128] value. Throws for other types.
+  Uint8List get _v128Bytes => Uint8List.fromList(value! as U8Array16);
+
+  /// The sixteen 8-bit integer lanes of a [ValueTy.v128] value.
+  /// Throws for other types.
+  Int8List get asI8x16 => _v128Bytes.buffer.asInt8List();
+
+  /// The eight 16-bit integer lanes of a [ValueTy.v128] value.
+  /// Throws for other types.
+  Int16List get asI16x8 => _v128Bytes.buffer.asInt16List();
+
+  /// The four 32-bit integer lanes of a [ValueTy.v128] value.
+  /// Throws for other types.
+  Int32List get asI32x4 => _v128Bytes.buffer.asInt32List();
+
+  /// The two 64-bit integer lanes of a [ValueTy.v128] value.
+  /// Throws for other types. Not supported in the web.
+  Int64List get asI64x2 => _v128Bytes.buffer.asInt64List();
+
+  /// The four 32-bit floating point lanes of a [ValueTy.v128] value.
+  /// Throws for other types.
+  Float32List get asF32x4 => _v128Bytes.buffer.asFloat32List();
+
+  /// The two 64-bit floating point lanes of a [ValueTy.v128] value.
+  /// Throws for other types.
+  Float64List get asF64x2 => _v128Bytes.buffer.asFloat64List();
+}
+
 /// A WASM reference value.
 class WasmValueRef implements WasmValue {
   /// The Dart value.
@@ -702,6 +1242,11 @@ abstract class WasmInstanceFuel {
   /// Returns the fuel added so far.
   int fuelAdded();
 
+  /// Returns the fuel consumed by the last function call,
+  /// including the calls made from imported host functions.
+  /// Null if no function was called.
+  int? lastCallFuelConsumed();
+
   /// Consumes [delta] quantity of fuel from the remaining fuel.
   /// Returns the remaining fuel after the operation.
   int consumeFuel(int delta);
@@ -750,11 +1295,16 @@ class WasmModuleExport {
   /// Type of the exported entry.
   final ExternalType? type;
 
+  /// Position of the entry in the exports of the module,
+  /// which is the same in the exports of its instances.
+  final int? index;
+
   /// [WasmModule] exports entry.
   const WasmModuleExport(
     this.name,
     this.kind, {
     this.type,
+    this.index,
   });
 
   @override
@@ -1,35 +1,58 @@
 pub use crate::atomics::*;
 use crate::bridge_generated::{wire_list_wasm_val, Wire2Api};
+use crate::capture::CaptureBuffer;
+use crate::compile_error::CompileError;
+use crate::compile_thread::CompilationSlot;
 use crate::config::*;
 pub use crate::external::*;
+use crate::line_buffer::LineBuffer;
+use crate::metrics::{CallMetrics, StoreCounters};
+use crate::module_cache::*;
+use crate::observer;
 use crate::types::*;
+use crate::wasi_error::WasiError;
 use anyhow::{Ok, Result};
 use flutter_rust_bridge::{
     support::new_leak_box_ptr, DartAbi, IntoDart, RustOpaque, StreamSink, SyncReturn,
 };
 use once_cell::sync::Lazy;
 use std::io::Write;
+pub use std::sync::atomic::AtomicBool;
 use std::sync::mpsc::{self, Receiver, Sender};
 pub use std::sync::{Mutex, RwLock};
 use std::{cell::RefCell, collections::HashMap, fs, sync::Arc};
 use wasi_common::pipe::WritePipe;
 use wasmtime::*;
-pub use wasmtime::{Func, Global, GlobalType, Memory, Module, SharedMemory, Table};
+pub use wasmtime::{Engine, Func, Global, GlobalType, Memory, Module, SharedMemory, Table};
 
 type Value = wasmtime::Val;
 type ValueType = wasmtime::ValType;
 
 static ARRAY: Lazy<RwLock<GlobalState>> = Lazy::new(|| RwLock::new(Default::default()));
 
-thread_local!(static STORE: RefCell<Option<WasmiModuleImpl>> = RefCell::new(None));
+/// The epoch deadline of the stores without a deadline,
+/// it is never reached by [Engine::increment_epoch].
+const NO_EPOCH_DEADLINE: u64 = u64::MAX / 2;
+
+thread_local!(static STORE: RefCell<Option<WasmiModuleImpl>> = const { RefCell::new(None) });
+thread_local!(static IN_ASYNC_CALL: std::cell::Cell<bool> = const { std::cell::Cell::new(false) });
 
 #[derive(Default)]
 struct GlobalState {
-    map: HashMap<u32, WasmiModuleImpl>,
+    /// Each module is locked separately, so that a long call to a module
+    /// does not block the others.
+    map: HashMap<u32, Arc<Mutex<WasmiModuleImpl>>>,
     last_id: u32,
 }
 
-fn default_val(ty: &ValueType) -> Value {
+/// The module with [module_id], the global state is only locked to get it.
+/// Returns a [ModuleClosed] error if the module was closed or disposed.
+fn get_module(module_id: u32) -> Result<Arc<Mutex<WasmiModuleImpl>>> {
+    let module = ARRAY.read().unwrap().map.get(&module_id).cloned();
+    Ok(module.ok_or(ModuleClosed { module_id })?)
+}
+
+pub(crate) fn default_val(ty: &ValueType) -> Value {
     match ty {
         ValueType::I32 => Value::I32(0),
         ValueType::I64 => Value::I64(0),
@@ -51,15 +74,173 @@ struct WasmiModuleImpl {
     channels: Option<Arc<Mutex<FunctionChannels>>>,
 }
 
-struct StoreState {
+pub(crate) struct StoreState {
     wasi_ctx: Option<wasi_common::WasiCtx>,
     stdout: Option<StreamSink<Vec<u8>>>,
     stderr: Option<StreamSink<Vec<u8>>>,
+    wasi_denied: Option<StreamSink<String>>,
     functions: HashMap<usize, HostFunction>,
     stack: CallStack,
+    pub(crate) last_call: Option<CallMetrics>,
+    /// The counters of the [CallMetrics], and the resource limiter of the store.
+    pub(crate) counters: StoreCounters,
+    /// The number of host functions executing in the store.
+    host_call_depth: u32,
+    max_host_call_depth: Option<u32>,
+    stdout_lines: Option<std::sync::Mutex<LineBuffer>>,
+    stderr_lines: Option<std::sync::Mutex<LineBuffer>>,
+    /// Shared with the [WasmRunModuleId] of the module.
+    capture: Arc<StdioCapture>,
     // TODO: add to stdin?
 }
 
+impl StoreState {
+    /// Sends the last lines of the line buffered stdout and stderr
+    /// when they were not terminated with a new line.
+    fn flush_output_lines(&self) {
+        for (sink, lines) in [
+            (&self.stdout, &self.stdout_lines),
+            (&self.stderr, &self.stderr_lines),
+        ] {
+            if let (Some(sink), Some(lines)) = (sink, lines) {
+                if let Some(line) = lines.lock().unwrap().finish() {
+                    sink.add(line);
+                }
+            }
+        }
+    }
+
+    /// Flushes the line buffered output and finishes the capture buffers,
+    /// the remaining captured output can still be read.
+    fn finish_output(&self) {
+        self.flush_output_lines();
+        for capture in [&self.capture.stdout, &self.capture.stderr]
+            .into_iter()
+            .flatten()
+        {
+            capture.finish();
+        }
+    }
+
+    /// Finishes the output and closes the output streams.
+    fn close_output(&self) {
+        self.finish_output();
+        for sink in [&self.stdout, &self.stderr].into_iter().flatten() {
+            sink.close();
+        }
+        if let Some(sink) = &self.wasi_denied {
+            sink.close();
+        }
+    }
+}
+
+/// The error returned when using a module after [WasmRunModuleId::close]
+/// or [WasmRunModuleId::dispose].
+#[derive(Debug)]
+pub struct ModuleClosed {
+    pub module_id: u32,
+}
+
+impl std::fmt::Display for ModuleClosed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Module {} is closed.", self.module_id)
+    }
+}
+
+impl std::error::Error for ModuleClosed {}
+
+/// The error returned when using a module while a call to it, for example,
+/// an asynchronous call, is executed by another thread.
+#[derive(Debug)]
+pub struct ModuleBusy {
+    pub module_id: u32,
+}
+
+impl std::fmt::Display for ModuleBusy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Module {} is busy in an asynchronous call on another thread.",
+            self.module_id
+        )
+    }
+}
+
+impl std::error::Error for ModuleBusy {}
+
+/// The error returned by [compile_wasm_cancelable] when its
+/// [CompileHandle] is cancelled before the module is compiled.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cancelled: the compilation was cancelled.")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// The error returned when a host function panics.
+///
+/// The panic is caught before it unwinds into the WASM runtime
+/// and the function call fails with a trap.
+#[derive(Debug)]
+pub struct HostPanic {
+    pub function_id: u32,
+    pub message: String,
+}
+
+impl HostPanic {
+    fn new(function_id: u32, payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else {
+            "unknown panic payload".to_string()
+        };
+        HostPanic {
+            function_id,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for HostPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HostPanic: host function with id {} panicked: {}",
+            self.function_id, self.message
+        )
+    }
+}
+
+impl std::error::Error for HostPanic {}
+
+/// The error of a host function call made while [limit] host functions
+/// are already executing in the store, for example, a host function
+/// that calls back into the guest which calls it again.
+/// Configured with [WasmRunModuleId::set_max_host_call_depth].
+#[derive(Debug)]
+pub struct ReentrancyLimitExceeded {
+    pub function_id: u32,
+    pub limit: u32,
+}
+
+impl std::fmt::Display for ReentrancyLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ReentrancyLimitExceeded: host function with id {} exceeded the maximum host call depth of {}",
+            self.function_id, self.limit
+        )
+    }
+}
+
+impl std::error::Error for ReentrancyLimitExceeded {}
+
 #[derive(Clone)]
 struct HostFunction {
     function_pointer: usize,
@@ -69,10 +250,75 @@ struct HostFunction {
 }
 
 #[derive(Clone)]
-pub struct WasmRunModuleId(pub u32, pub RustOpaque<CallStack>);
+pub struct WasmRunModuleId(
+    pub u32,
+    pub RustOpaque<CallStack>,
+    pub RustOpaque<StdioCapture>,
+);
+
+/// The bounded buffers of the captured stdout and stderr of a module,
+/// when [WasiConfigNative::capture_capacity] is set. They are owned by the
+/// [WasmRunModuleId], outside of the store, so that they are read without
+/// accessing the store while the module is writing to them.
+#[derive(Default)]
+pub struct StdioCapture {
+    stdout: Option<Arc<CaptureBuffer>>,
+    stderr: Option<Arc<CaptureBuffer>>,
+}
+
+impl StdioCapture {
+    /// The buffer of the captured output of [kind].
+    fn get(&self, kind: StdIOKind) -> Result<&Arc<CaptureBuffer>> {
+        match kind {
+            StdIOKind::stdout => self.stdout.as_ref(),
+            StdIOKind::stderr => self.stderr.as_ref(),
+        }
+        .ok_or_else(|| anyhow::anyhow!("The {kind:?} output is not captured with a capacity"))
+    }
+}
 
 #[derive(Clone, Default)]
-pub struct CallStack(Arc<RwLock<Vec<RwLock<StoreContextMut<'static, StoreState>>>>>);
+/// The store contexts of the calls in progress, from the outermost one.
+/// Each one is locked while it is used, the stack itself is only locked
+/// to push, pop or get one, so that host functions can call the module again.
+pub struct CallStack(Arc<RwLock<Vec<CallerStore>>>);
+
+/// The store context of a call in [CallStack] and the thread executing it,
+/// which is the only one that can use it.
+#[derive(Clone)]
+struct CallerStore {
+    thread: std::thread::ThreadId,
+    context: CallerContext,
+}
+
+type CallerContext = Arc<RwLock<StoreContextMut<'static, StoreState>>>;
+
+impl CallStack {
+    fn push(&self, context: StoreContextMut<'static, StoreState>) {
+        self.0.write().unwrap().push(CallerStore {
+            thread: std::thread::current().id(),
+            context: Arc::new(RwLock::new(context)),
+        });
+    }
+
+    fn pop(&self) -> Option<CallerContext> {
+        self.0.write().unwrap().pop().map(|caller| caller.context)
+    }
+
+    /// The store context of the innermost call, when it is executed by the
+    /// current thread, for example, within a host function.
+    /// Returns a [ModuleBusy] error if it is executed by another thread,
+    /// for example, in an asynchronous call.
+    fn current(&self, module_id: u32) -> Result<Option<CallerContext>> {
+        match self.0.read().unwrap().last() {
+            None => Ok(None),
+            Some(caller) if caller.thread == std::thread::current().id() => {
+                Ok(Some(caller.context.clone()))
+            }
+            Some(_) => Err(ModuleBusy { module_id }.into()),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct WasmRunInstanceId(pub u32);
@@ -80,14 +326,21 @@ pub struct WasmRunInstanceId(pub u32);
 fn make_wasi_ctx(
     id: &WasmRunModuleId,
     wasi_config: &Option<WasiConfigNative>,
+    configure_wasi: Option<WasiCtxConfigure>,
 ) -> Result<Option<wasi_common::WasiCtx>> {
     let mut wasi_ctx = None;
     if let Some(wasi_config) = wasi_config {
-        let wasi = wasi_config.to_wasi_ctx()?;
+        let mut wasi = wasi_config.to_wasi_ctx()?;
 
         if !wasi_config.preopened_files.is_empty() {
             for value in &wasi_config.preopened_files {
-                let file = fs::File::open(value)?;
+                let file = fs::File::open(value).map_err(|err| {
+                    WasiError::from_io(
+                        err,
+                        wasi_config.unknown_errno,
+                        format!("Could not open the preopened file {value:?}"),
+                    )
+                })?;
                 let wasm_file =
                     wasmtime_wasi::file::File::from_cap_std(cap_std::fs::File::from_std(file));
                 wasi.push_file(
@@ -111,16 +364,112 @@ fn make_wasi_ctx(
             };
             wasi.set_stderr(Box::new(WritePipe::new(stderr_handler)));
         }
+        if let Some(configure_wasi) = configure_wasi {
+            configure_wasi(&mut wasi)?;
+        }
         wasi_ctx = Some(wasi);
     }
 
     Ok(wasi_ctx)
 }
 
+/// Overrides the WASI `path_open` import to send the paths denied by the sandbox
+/// to the [WasmRunModuleId::wasi_denied_stream] sink.
+/// The errno returned to the module is not modified.
+fn add_wasi_denied_hook(linker: &mut Linker<StoreState>) -> Result<()> {
+    use wasi_common::snapshots::preview_1::{types::Errno, wasi_snapshot_preview1};
+    use wiggle::wasmtime::WasmtimeGuestMemory;
+
+    linker.allow_shadowing(true);
+    linker.func_wrap(
+        "wasi_snapshot_preview1",
+        "path_open",
+        |mut caller: Caller<'_, StoreState>,
+         fd: i32,
+         dirflags: i32,
+         path: i32,
+         path_len: i32,
+         oflags: i32,
+         fs_rights_base: i64,
+         fs_rights_inheriting: i64,
+         fdflags: i32,
+         opened_fd: i32|
+         -> Result<i32> {
+            let path_open = |ctx: &mut StoreState, mem: &WasmtimeGuestMemory<'_>| {
+                wiggle::run_in_dummy_executor(wasi_snapshot_preview1::path_open(
+                    ctx.wasi_ctx.as_mut().unwrap(),
+                    mem,
+                    fd,
+                    dirflags,
+                    path,
+                    path_len,
+                    oflags,
+                    fs_rights_base,
+                    fs_rights_inheriting,
+                    fdflags,
+                    opened_fd,
+                ))?
+            };
+            let start = path as u32 as usize;
+            let end = start.saturating_add(path_len as u32 as usize);
+            let (errno, path) = match caller.get_export("memory") {
+                Some(Extern::Memory(m)) => {
+                    let (mem, ctx) = m.data_and_store_mut(&mut caller);
+                    let errno = path_open(ctx, &WasmtimeGuestMemory::new(mem))?;
+                    (errno, m.data(&caller).get(start..end).map(<[u8]>::to_vec))
+                }
+                Some(Extern::SharedMemory(m)) => {
+                    let errno =
+                        path_open(caller.data_mut(), &WasmtimeGuestMemory::shared(m.data()))?;
+                    let path = m.data().get(start..end).map(|cells| {
+                        cells
+                            .iter()
+                            .map(|c| unsafe { *c.get() })
+                            .collect::<Vec<u8>>()
+                    });
+                    (errno, path)
+                }
+                _ => return Err(anyhow::anyhow!("missing required memory export")),
+            };
+            let denied = [Errno::Notcapable, Errno::Perm, Errno::Acces]
+                .into_iter()
+                .any(|e| e as i32 == errno);
+            if let (true, Some(sink), Some(path)) = (denied, &caller.data().wasi_denied, path) {
+                sink.add(String::from_utf8_lossy(&path).into_owned());
+            }
+            Ok(errno)
+        },
+    )?;
+    linker.allow_shadowing(false);
+    Ok(())
+}
+
+/// Defines the WASI imports in [linker], they use the WASI context of the store.
+fn add_wasi_to_linker(linker: &mut Linker<StoreState>) -> Result<()> {
+    wasmtime_wasi::add_to_linker(linker, |ctx| ctx.wasi_ctx.as_mut().unwrap())?;
+    add_wasi_denied_hook(linker)
+}
+
+/// Creates a module builder for [module]. The WASI imports are linked
+/// only if [wasi_config] is provided, so the same build can instantiate
+/// modules without any access to the host.
 pub fn module_builder(
     module: CompiledModule,
     num_threads: Option<usize>,
     wasi_config: Option<WasiConfigNative>,
+) -> Result<SyncReturn<WasmRunModuleId>> {
+    module_builder_with_linker(module, num_threads, wasi_config, None, None)
+}
+
+/// Creates a module builder that instantiates [module] with a clone of
+/// [template], if provided, instead of a new linker.
+/// [configure_wasi] is applied to the WASI context created from [wasi_config].
+pub(crate) fn module_builder_with_linker(
+    module: CompiledModule,
+    num_threads: Option<usize>,
+    wasi_config: Option<WasiConfigNative>,
+    template: Option<Linker<StoreState>>,
+    configure_wasi: Option<WasiCtxConfigure>,
 ) -> Result<SyncReturn<WasmRunModuleId>> {
     let guard = module.0.lock().unwrap();
     let engine = guard.engine();
@@ -130,25 +479,62 @@ pub fn module_builder(
 
     let id = arr.last_id;
 
+    let capture = |captured: fn(&WasiConfigNative) -> bool| {
+        wasi_config
+            .as_ref()
+            .filter(|c| captured(c))
+            .and_then(|c| Some((c.capture_capacity?, c.capture_overflow)))
+            .map(|(capacity, overflow)| Arc::new(CaptureBuffer::new(capacity, overflow)))
+    };
+    let capture = Arc::new(StdioCapture {
+        stdout: capture(|c| c.capture_stdout),
+        stderr: capture(|c| c.capture_stderr),
+    });
     let stack: CallStack = Default::default();
-    let module_id = WasmRunModuleId(id, RustOpaque::new(stack.clone()));
+    let module_id = WasmRunModuleId(
+        id,
+        RustOpaque::new(stack.clone()),
+        Arc::clone(&capture).into(),
+    );
 
-    let mut linker = <Linker<StoreState>>::new(engine);
-    let wasi_ctx = make_wasi_ctx(&module_id, &wasi_config)?;
-    if wasi_ctx.is_some() {
-        wasmtime_wasi::add_to_linker(&mut linker, |ctx| ctx.wasi_ctx.as_mut().unwrap())?;
-    }
+    let wasi_ctx = make_wasi_ctx(&module_id, &wasi_config, configure_wasi)?;
+    let linker = match template {
+        Some(linker) => linker,
+        None => {
+            let mut linker = <Linker<StoreState>>::new(engine);
+            if wasi_ctx.is_some() {
+                add_wasi_to_linker(&mut linker)?;
+            }
+            linker
+        }
+    };
 
-    let store = Store::new(
+    let line_buffer = || {
+        wasi_config
+            .as_ref()
+            .filter(|c| c.line_buffered_output)
+            .map(|_| Mutex::new(LineBuffer::default()))
+    };
+    let mut store = Store::new(
         engine,
         StoreState {
             wasi_ctx: wasi_ctx.clone(),
             stdout: None,
             stderr: None,
+            wasi_denied: None,
             functions: Default::default(),
             stack,
+            last_call: None,
+            counters: Default::default(),
+            host_call_depth: 0,
+            max_host_call_depth: None,
+            stdout_lines: line_buffer(),
+            stderr_lines: line_buffer(),
+            capture: Arc::clone(&capture),
         },
     );
+    store.limiter(|state| &mut state.counters);
+    store.set_epoch_deadline(NO_EPOCH_DEADLINE);
     let wasm_module = Arc::clone(&module.0);
     let threads = if let Some(num_threads) = num_threads {
         if num_threads <= 1 {
@@ -164,19 +550,29 @@ pub fn module_builder(
                         ctx.wasi_ctx.as_mut().unwrap()
                     })?;
                 }
+                let mut store = Store::new(
+                    engine,
+                    StoreState {
+                        wasi_ctx: wasi_ctx.clone(),
+                        stdout: None,
+                        stderr: None,
+                        wasi_denied: None,
+                        functions: Default::default(),
+                        stack: Default::default(),
+                        last_call: None,
+                        counters: Default::default(),
+                        host_call_depth: 0,
+                        max_host_call_depth: None,
+                        stdout_lines: None,
+                        stderr_lines: None,
+                        capture: Arc::clone(&capture),
+                    },
+                );
+                store.set_epoch_deadline(NO_EPOCH_DEADLINE);
                 Ok(Some(WasmiModuleImpl {
                     module: wasm_module.clone(),
                     linker,
-                    store: Store::new(
-                        engine,
-                        StoreState {
-                            wasi_ctx: wasi_ctx.clone(),
-                            stdout: None,
-                            stderr: None,
-                            functions: Default::default(),
-                            stack: Default::default(),
-                        },
-                    ),
+                    store,
                     instance: None,
                     threads: None,
                     pool: None,
@@ -200,7 +596,7 @@ pub fn module_builder(
         channels: num_threads
             .map(|num_threads| Arc::new(Mutex::new(FunctionChannels::new(num_threads)))),
     };
-    arr.map.insert(id, module_builder);
+    arr.map.insert(id, Arc::new(Mutex::new(module_builder)));
 
     Ok(SyncReturn(module_id))
 }
@@ -212,22 +608,41 @@ struct ModuleIOWriter {
 
 impl Write for ModuleIOWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.id.with_module(|store| {
-            let data = store.data();
+        let kind = if self.is_stdout {
+            StdIOKind::stdout
+        } else {
+            StdIOKind::stderr
+        };
+        // The module may block on a full buffer, it is written
+        // outside of the module lock so that it can be read.
+        if let std::result::Result::Ok(capture) = self.id.2.get(kind) {
+            return std::io::Result::Ok(capture.write(buf));
+        }
+        self.id
+            .with_module(|store| {
+                let data = store.data();
 
-            let sink = if self.is_stdout {
-                data.stdout.as_ref()
-            } else {
-                data.stderr.as_ref()
-            };
-            let mut bytes_written = buf.len();
-            if let Some(stream) = sink {
-                if !stream.add(buf.to_owned()) {
-                    bytes_written = 0;
+                let (sink, lines) = if self.is_stdout {
+                    (data.stdout.as_ref(), data.stdout_lines.as_ref())
+                } else {
+                    (data.stderr.as_ref(), data.stderr_lines.as_ref())
+                };
+                let mut bytes_written = buf.len();
+                if let Some(stream) = sink {
+                    let sent = match lines {
+                        Some(lines) => {
+                            let lines = lines.lock().unwrap().push(buf);
+                            lines.into_iter().all(|line| stream.add(line))
+                        }
+                        None => stream.add(buf.to_owned()),
+                    };
+                    if !sent {
+                        bytes_written = 0;
+                    }
                 }
-            }
-            std::io::Result::Ok(bytes_written)
-        })
+                bytes_written
+            })
+            .map_err(std::io::Error::other)
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
@@ -266,8 +681,9 @@ impl FunctionChannels {
 
 impl WasmRunInstanceId {
     pub fn exports(&self) -> SyncReturn<Vec<ModuleExportValue>> {
-        let mut v = ARRAY.write().unwrap();
-        let value = v.map.get_mut(&self.0).unwrap();
+        let module = get_module(self.0).unwrap();
+        let mut module = module.lock().unwrap();
+        let value = &mut *module;
         let instance = value.instance.unwrap();
         let l = instance
             .exports(&mut value.store)
@@ -275,25 +691,119 @@ impl WasmRunInstanceId {
             .collect::<Vec<(String, wasmtime::Extern)>>();
         SyncReturn(
             l.into_iter()
-                .map(|e| ModuleExportValue::from_export(e, &value.store))
+                .enumerate()
+                .map(|(index, e)| ModuleExportValue::from_export(index, e, &value.store))
                 .collect(),
         )
     }
 }
 
+/// The `_initialize` or `_start` function [name] exported by [instance],
+/// which should not have parameters or results.
+fn init_export(instance: Instance, store: &mut Store<StoreState>, name: &str) -> Option<Func> {
+    instance.get_func(&mut *store, name).filter(|func| {
+        let ty = func.ty(&*store);
+        ty.params().len() == 0 && ty.results().len() == 0
+    })
+}
+
+/// Instantiates [module] in [store], wrapping the error in a [StartTrap] if
+/// the start function of the module trapped.
+fn instantiate_in_store(
+    linker: &Linker<StoreState>,
+    store: &mut Store<StoreState>,
+    module: &Module,
+) -> Result<Instance> {
+    linker.instantiate(&mut *store, module).map_err(|err| {
+        if is_start_trap(&err) {
+            StartTrap { trap: err }.into()
+        } else {
+            err
+        }
+    })
+}
+
+/// Whether the instantiation error [err] is a trap of the start function.
+/// The segments are initialized before the start function, their traps are
+/// out of bounds accesses without Wasm frames. The frames are not recorded
+/// when the Wasm backtraces are disabled in the config, in that case the out
+/// of bounds traps are attributed to the segments.
+/// The errors of the host functions called by the start function are
+/// not traps, they are returned as they are.
+fn is_start_trap(err: &anyhow::Error) -> bool {
+    let Some(trap) = err.downcast_ref::<Trap>() else {
+        return false;
+    };
+    let has_wasm_frames = err
+        .downcast_ref::<WasmBacktrace>()
+        .is_some_and(|backtrace| !backtrace.frames().is_empty());
+    has_wasm_frames || !matches!(trap, Trap::MemoryOutOfBounds | Trap::TableOutOfBounds)
+}
+
+/// Returns an error if the module imports a WASI function that is not
+/// defined in the linker because the instance was built without WASI.
+fn check_wasi_disabled(module: &mut WasmiModuleImpl) -> Result<()> {
+    if module.store.data().wasi_ctx.is_some() {
+        return Ok(());
+    }
+    let wasm_module = module.module.lock().unwrap();
+    for import in wasm_module.imports() {
+        if import.module().starts_with("wasi_")
+            && module
+                .linker
+                .get_by_import(&mut module.store, &import)
+                .is_none()
+        {
+            return Err(anyhow::anyhow!(
+                "The module imports `{}::{}`, but WASI is disabled for this instance. Provide a `wasi_config` to link the WASI imports.",
+                import.module(),
+                import.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if the module imports a memory that is not defined
+/// in the linker, which should be created by the host before instantiating.
+fn check_memory_imports(module: &mut WasmiModuleImpl) -> Result<()> {
+    let wasm_module = module.module.lock().unwrap();
+    for import in wasm_module.imports() {
+        if matches!(import.ty(), ExternType::Memory(_))
+            && module
+                .linker
+                .get_by_import(&mut module.store, &import)
+                .is_none()
+        {
+            return Err(anyhow::anyhow!(
+                "The module imports the memory `{}::{}`, but none is provided. Create it with `create_memory` and link it with `link_imports`, or define it in the linker with `define_memory`.",
+                import.module(),
+                import.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
 impl WasmRunModuleId {
     pub fn instantiate_sync(&self) -> Result<SyncReturn<WasmRunInstanceId>> {
         Ok(SyncReturn(self.instantiate()?))
     }
     pub fn instantiate(&self) -> Result<WasmRunInstanceId> {
-        let mut state = ARRAY.write().unwrap();
-        let module = state.map.get_mut(&self.0).unwrap();
+        let _span = observer::instantiate_span(self.0);
+        let module = get_module(self.0)?;
+        let mut module = module.lock().unwrap();
+        let module = &mut *module;
         if module.instance.is_some() {
             return Err(anyhow::anyhow!("Instance already exists"));
         }
-        let instance = module
-            .linker
-            .instantiate(&mut module.store, &module.module.lock().unwrap())?;
+        check_wasi_disabled(module)?;
+        check_memory_imports(module)?;
+        let instance = instantiate_in_store(
+            &module.linker,
+            &mut module.store,
+            &module.module.lock().unwrap(),
+        )?;
 
         module.instance = Some(instance);
         let threads = module.threads.take();
@@ -302,9 +812,11 @@ impl WasmRunModuleId {
                 let mut threads_i = threads.lock().unwrap();
                 for thread in threads_i.iter_mut() {
                     let thread = thread.as_mut().unwrap();
-                    let thread_instance = thread
-                        .linker
-                        .instantiate(&mut thread.store, &thread.module.lock().unwrap())?;
+                    let thread_instance = instantiate_in_store(
+                        &thread.linker,
+                        &mut thread.store,
+                        &thread.module.lock().unwrap(),
+                    )?;
                     thread.instance = Some(thread_instance);
                 }
                 threads_i.len()
@@ -381,8 +893,9 @@ impl WasmRunModuleId {
     }
 
     pub fn link_imports(&self, imports: Vec<ModuleImport>) -> Result<SyncReturn<()>> {
-        let mut arr = ARRAY.write().unwrap();
-        let m = arr.map.get_mut(&self.0).unwrap();
+        let module = get_module(self.0)?;
+        let mut module = module.lock().unwrap();
+        let m = &mut *module;
         if m.instance.is_some() {
             return Err(anyhow::anyhow!("Instance already exists"));
         }
@@ -464,6 +977,34 @@ impl WasmRunModuleId {
         Ok(SyncReturn(()))
     }
 
+    /// Instantiates [module] in the store of this module and defines its exports
+    /// under the [namespace] module name, so that this module can import them.
+    /// For example, to call the functions exported by a library module.
+    ///
+    /// [module] is instantiated with the imports defined so far, such as WASI
+    /// and [link_imports], and it should be compiled with the same [WasmEngine].
+    pub fn link_module(&self, namespace: String, module: CompiledModule) -> Result<SyncReturn<()>> {
+        let this = get_module(self.0)?;
+        let mut this = this.lock().unwrap();
+        let m = &mut *this;
+        if m.instance.is_some() {
+            return Err(anyhow::anyhow!("Instance already exists"));
+        }
+        let module = module.0.lock().unwrap();
+        let instance = instantiate_in_store(&m.linker, &mut m.store, &module)?;
+        m.linker.instance(&mut m.store, &namespace, instance)?;
+        if let Some(threads) = m.threads.as_ref() {
+            for thread in threads.lock().unwrap().iter_mut() {
+                let thread = thread.as_mut().unwrap();
+                let instance = instantiate_in_store(&thread.linker, &mut thread.store, &module)?;
+                thread
+                    .linker
+                    .instance(&mut thread.store, &namespace, instance)?;
+            }
+        }
+        Ok(SyncReturn(()))
+    }
+
     pub fn stdio_stream(&self, sink: StreamSink<Vec<u8>>, kind: StdIOKind) -> Result<()> {
         self.with_module_mut(|mut store| {
             let store_state = store.data_mut();
@@ -481,12 +1022,84 @@ impl WasmRunModuleId {
                 StdIOKind::stderr => store_state.stderr = Some(sink),
             };
             Ok(())
-        })
+        })?
+    }
+
+    /// Sends the paths that the module tried to open and were denied by
+    /// the WASI sandbox, for example, paths outside of the preopened directories.
+    /// The module still receives the same WASI errno.
+    pub fn wasi_denied_stream(&self, sink: StreamSink<String>) -> Result<()> {
+        self.with_module_mut(|mut store| {
+            let store_state = store.data_mut();
+            if store_state.wasi_ctx.is_none() {
+                return Err(anyhow::anyhow!("WASI is not enabled for this module"));
+            }
+            if store_state.wasi_denied.is_some() {
+                return Err(anyhow::anyhow!("Stream sink already set"));
+            }
+            store_state.wasi_denied = Some(sink);
+            Ok(())
+        })?
+    }
+
+    /// Returns and removes the stdout or stderr output kept in the capture buffer,
+    /// see [WasiConfigNative::capture_capacity].
+    pub fn read_stdio(&self, kind: StdIOKind) -> Result<SyncReturn<Vec<u8>>> {
+        Ok(SyncReturn(self.2.get(kind)?.read()))
+    }
+
+    /// Waits up to [timeout_millis] for the next stdout or stderr output kept in
+    /// the capture buffer and removes it, see [WasiConfigNative::capture_capacity].
+    /// Returns an empty list when the timeout elapses without output and `None`
+    /// once all the output was returned after the module exited with
+    /// [WasmRunModuleId::call_wasi_start], or the module or the capture buffer was closed.
+    pub fn next_stdio_chunk(
+        &self,
+        kind: StdIOKind,
+        timeout_millis: u64,
+    ) -> Result<Option<Vec<u8>>> {
+        let capture = self.2.get(kind)?;
+        Ok(capture.next_chunk(std::time::Duration::from_millis(timeout_millis)))
+    }
+
+    /// The number of bytes of stdout or stderr dropped because
+    /// the capture buffer was full or closed.
+    pub fn stdio_dropped_bytes(&self, kind: StdIOKind) -> Result<SyncReturn<u64>> {
+        Ok(SyncReturn(self.2.get(kind)?.dropped_bytes()))
+    }
+
+    /// Stops capturing stdout or stderr in the capture buffer and frees it.
+    /// The following output of the module is dropped.
+    pub fn close_stdio(&self, kind: StdIOKind) -> Result<SyncReturn<()>> {
+        self.2.get(kind)?.close();
+        Ok(SyncReturn(()))
+    }
+
+    /// Releases the store of the module at a well-defined point, instead of
+    /// relying on the order of the finalizers: flushes the remaining line buffered
+    /// output, closes the output streams and drops the WASI context,
+    /// which closes the preopened directories.
+    /// Further calls with the module return a [ModuleClosed] error.
+    /// Closing a closed module does nothing.
+    pub fn close(&self) -> Result<SyncReturn<()>> {
+        if self.1.current(self.0)?.is_some() {
+            return Err(anyhow::anyhow!(
+                "Module {} can not be closed within a call to it.",
+                self.0
+            ));
+        }
+        let module = ARRAY.write().unwrap().map.remove(&self.0);
+        if let Some(module) = module {
+            module.lock().unwrap().store.data().close_output();
+        }
+        Ok(SyncReturn(()))
     }
 
     pub fn dispose(&self) -> Result<()> {
-        let mut arr = ARRAY.write().unwrap();
-        arr.map.remove(&self.0);
+        let module = ARRAY.write().unwrap().map.remove(&self.0);
+        if let Some(module) = module {
+            module.lock().unwrap().store.data().flush_output_lines();
+        }
         Ok(())
     }
 
@@ -495,23 +1108,350 @@ impl WasmRunModuleId {
         func: RustOpaque<WFunc>,
         args: Vec<WasmVal>,
     ) -> Result<SyncReturn<Vec<WasmVal>>> {
-        self.call_function_handle(func, args).map(SyncReturn)
+        self.call_function(func, args, None).map(SyncReturn)
     }
+
+    /// Calls [func] in a thread outside of the Dart isolate,
+    /// so that long running calls do not block it.
+    /// Host functions are executed in the Dart isolate, calling them
+    /// within this function traps. Use [call_function_handle_sync] for
+    /// functions that call host functions.
     pub fn call_function_handle(
         &self,
         func: RustOpaque<WFunc>,
         args: Vec<WasmVal>,
+    ) -> Result<Vec<WasmVal>> {
+        let in_async_call = IN_ASYNC_CALL.with(|c| c.replace(true));
+        let result = self.call_function(func, args, None);
+        IN_ASYNC_CALL.with(|c| c.set(in_async_call));
+        result
+    }
+
+    /// Calls the function at [index] in the exports of the instance,
+    /// see [ModuleExportDesc.index], for example, when the name of the export
+    /// is not known. Returns an error if [index] is out of range or the export
+    /// is not a function. The functions of a table can be called with
+    /// [get_table] and [call_function_handle_sync].
+    pub fn call_by_index(
+        &self,
+        index: u32,
+        args: Vec<WasmVal>,
+    ) -> Result<SyncReturn<Vec<WasmVal>>> {
+        let (name, func) = self.with_instance(|instance, store| {
+            let count = instance.exports(&mut *store).count();
+            let mut exports = instance
+                .exports(&mut *store)
+                .map(|e| (e.name().to_string(), e.into_func()));
+            let (name, value) = exports.nth(index as usize).ok_or_else(|| {
+                anyhow::anyhow!("The export index {index} is out of range for {count} exports.")
+            })?;
+            let func = value.ok_or_else(|| {
+                anyhow::anyhow!("The export `{name}` at index {index} is not a function.")
+            })?;
+            Ok((name, func))
+        })?;
+        self.call_function(RustOpaque::new(func.into()), args, Some(&name))
+            .map(SyncReturn)
+    }
+
+    fn call_function(
+        &self,
+        func: RustOpaque<WFunc>,
+        args: Vec<WasmVal>,
+        name: Option<&str>,
     ) -> Result<Vec<WasmVal>> {
         let func: Func = func.func_wasmtime;
-        self.with_module_mut(|mut store| {
-            let mut outputs: Vec<Value> =
-                func.ty(&store).results().map(|t| default_val(&t)).collect();
+        self.call_measured(name, |store| {
+            let mut outputs: Vec<Value> = func
+                .ty(&*store)
+                .results()
+                .map(|t| default_val(&t))
+                .collect();
             let inputs: Vec<Value> = args.into_iter().map(|v| v.to_val()).collect();
-            func.call(&mut store, inputs.as_slice(), &mut outputs)?;
+            func.call(store, inputs.as_slice(), &mut outputs)?;
             Ok(outputs.into_iter().map(WasmVal::from_val).collect())
         })
     }
 
+    /// Executes the call [f] within the store of the module and records
+    /// its [last_call_fuel_consumed] and [last_call_duration_micros].
+    pub(crate) fn call_measured<T>(
+        &self,
+        name: Option<&str>,
+        f: impl FnOnce(&mut StoreContextMut<'_, StoreState>) -> Result<T>,
+    ) -> Result<T> {
+        let _span = observer::call_span(self.0, name);
+        self.with_module_mut(|mut store| {
+            let fuel_before = store.fuel_consumed();
+            let counters_before = store.data().counters;
+            let start = std::time::Instant::now();
+            let result = f(&mut store);
+            let wall_time = start.elapsed();
+            let fuel_consumed = fuel_before
+                .zip(store.fuel_consumed())
+                .map(|(before, after)| after - before);
+            let state = store.data_mut();
+            let metrics = state
+                .counters
+                .call_metrics(counters_before, wall_time, fuel_consumed);
+            state.last_call = Some(metrics);
+            result
+        })?
+    }
+
+    /// Calls the WASI `_start` [func] and returns the exit code of the execution.
+    /// The exit code is the one passed to `proc_exit`, or 0 if `_start` returns
+    /// without calling it. This does not depend on the stdout and stderr configuration.
+    /// When it returns, the captured output is finished, see [WasmRunModuleId::next_stdio_chunk].
+    pub fn call_wasi_start(&self, func: RustOpaque<WFunc>) -> Result<SyncReturn<i32>> {
+        let result = self.call_function(func, vec![], Some("_start"));
+        self.with_module(|store| store.data().finish_output())?;
+        let err = match result {
+            Err(err) => err,
+            _ => return Ok(SyncReturn(0)),
+        };
+        let exit_code = err
+            .downcast_ref::<wasi_common::I32Exit>()
+            .map(|exit| exit.0);
+        match exit_code {
+            Some(code) => Ok(SyncReturn(code)),
+            None => Err(err),
+        }
+    }
+
+    /// Initializes the instance according to its [WasiModuleKind].
+    /// Calls `_initialize` for reactors and `_start` for commands, returning
+    /// its exit code as in [call_wasi_start]. Libraries are not initialized.
+    /// Returns None for reactors and libraries.
+    pub fn initialize(&self) -> Result<SyncReturn<Option<i32>>> {
+        let (initialize, start) = self.with_instance(|instance, store| {
+            Ok((
+                init_export(instance, store, "_initialize"),
+                init_export(instance, store, "_start"),
+            ))
+        })?;
+        match (initialize, start) {
+            (Some(func), _) => {
+                let func = RustOpaque::new(func.into());
+                self.call_function(func, vec![], Some("_initialize"))?;
+                Ok(SyncReturn(None))
+            }
+            (None, Some(func)) => {
+                let exit_code = self.call_wasi_start(RustOpaque::new(func.into()))?.0;
+                Ok(SyncReturn(Some(exit_code)))
+            }
+            (None, None) => Ok(SyncReturn(None)),
+        }
+    }
+
+    /// Calls the function [name] exported by a reactor or a library,
+    /// after [initialize]. Returns an error for `_initialize` and `_start`,
+    /// which are called by [initialize], and for the exports of commands.
+    pub fn call_export(
+        &self,
+        name: String,
+        args: Vec<WasmVal>,
+    ) -> Result<SyncReturn<Vec<WasmVal>>> {
+        if name == "_initialize" || name == "_start" {
+            return Err(anyhow::anyhow!(
+                "The `{name}` export should be called with `initialize`."
+            ));
+        }
+        let func = self.with_instance(|instance, store| {
+            let kind = WasiModuleKind::from_exports(|n| init_export(instance, store, n).is_some());
+            if kind == WasiModuleKind::command {
+                return Err(anyhow::anyhow!(
+                    "The exports of module {} can not be called, it is a WASI command.",
+                    self.0
+                ));
+            }
+            instance
+                .get_func(&mut *store, &name)
+                .ok_or_else(|| anyhow::anyhow!("The module does not export a `{name}` function."))
+        })?;
+        self.call_function(RustOpaque::new(func.into()), args, Some(&name))
+            .map(SyncReturn)
+    }
+
+    /// Allocates [size] bytes in the memory of the module with its exported
+    /// allocator and returns the pointer, for example, to write a string
+    /// with [WasmRunModuleId::write_memory] before passing it to a function.
+    /// [alloc_export] is the name of the allocation function, by default `malloc`,
+    /// or `cabi_realloc` when the module does not export `malloc`.
+    /// Functions with one parameter are called with [size], like `malloc`,
+    /// and functions with four parameters are called like
+    /// `cabi_realloc(0, 0, 8, size)`, with an alignment of 8 bytes.
+    pub fn guest_alloc(&self, size: u32, alloc_export: Option<String>) -> Result<SyncReturn<u32>> {
+        let names = match &alloc_export {
+            Some(name) => vec![name.as_str()],
+            None => vec!["malloc", "cabi_realloc"],
+        };
+        self.alloc_guest(&names, size, 8).map(SyncReturn)
+    }
+
+    /// Allocates [size] bytes with the first allocation function in [names].
+    /// [align] is the alignment passed to `cabi_realloc` like functions.
+    fn alloc_guest(&self, names: &[&str], size: u32, align: u32) -> Result<u32> {
+        let output = self.call_guest_export(names, |num_params| match num_params {
+            1 => Some(vec![WasmVal::i32(size as i32)]),
+            4 => Some(vec![
+                WasmVal::i32(0),
+                WasmVal::i32(0),
+                WasmVal::i32(align as i32),
+                WasmVal::i32(size as i32),
+            ]),
+            _ => None,
+        })?;
+        match output.as_slice() {
+            [WasmVal::i32(0)] if size != 0 => Err(anyhow::anyhow!(
+                "The module could not allocate {size} bytes."
+            )),
+            [WasmVal::i32(ptr)] => Ok(*ptr as u32),
+            _ => Err(anyhow::anyhow!(
+                "The allocation function should return an i32 pointer."
+            )),
+        }
+    }
+
+    /// Frees the [size] bytes at [ptr] allocated with [WasmRunModuleId::guest_alloc].
+    /// [free_export] is the name of the function, by default `free`.
+    /// Functions with one parameter are called with [ptr], like `free`,
+    /// and functions with two parameters are called with [ptr] and [size].
+    pub fn guest_free(
+        &self,
+        ptr: u32,
+        size: u32,
+        free_export: Option<String>,
+    ) -> Result<SyncReturn<()>> {
+        let name = free_export.as_deref().unwrap_or("free");
+        self.call_guest_export(&[name], |num_params| match num_params {
+            1 => Some(vec![WasmVal::i32(ptr as i32)]),
+            2 => Some(vec![WasmVal::i32(ptr as i32), WasmVal::i32(size as i32)]),
+            _ => None,
+        })?;
+        Ok(SyncReturn(()))
+    }
+
+    /// Copies the UTF-8 bytes of [value] to the memory exported by the module,
+    /// allocated with the exported `cabi_realloc` function like the canonical ABI
+    /// does to pass strings, and returns their location.
+    /// [memory_name] is required when the module exports more than one memory,
+    /// see [WasmRunModuleId::memories].
+    pub fn write_guest_string(
+        &self,
+        value: String,
+        memory_name: Option<String>,
+    ) -> Result<SyncReturn<PointerAndLength>> {
+        let len = u32::try_from(value.len())?;
+        let ptr = self.alloc_guest(&["cabi_realloc"], len, 1)?;
+        let memory = self.exported_memory(memory_name.as_deref())?;
+        self.write_memory(memory, ptr as usize, value.into_bytes())?;
+        Ok(SyncReturn(PointerAndLength {
+            pointer: ptr as usize,
+            length: len as usize,
+        }))
+    }
+
+    /// Reads the string of [len] UTF-8 bytes at [ptr] in the memory exported
+    /// by the module. Returns an error if the bytes are not valid UTF-8.
+    /// [memory_name] is required when the module exports more than one memory,
+    /// see [WasmRunModuleId::memories].
+    pub fn read_guest_string(
+        &self,
+        ptr: u32,
+        len: u32,
+        memory_name: Option<String>,
+    ) -> Result<SyncReturn<String>> {
+        let memory = self.exported_memory(memory_name.as_deref())?;
+        let bytes = self.read_memory(memory, ptr as usize, len as usize)?.0;
+        let value = String::from_utf8(bytes).map_err(|e| {
+            anyhow::anyhow!(
+                "The guest string at {ptr} is not valid UTF-8: {}",
+                e.utf8_error()
+            )
+        })?;
+        Ok(SyncReturn(value))
+    }
+
+    /// Calls the first function in [names] exported by the module with the
+    /// arguments returned by [args] for its number of parameters.
+    fn call_guest_export(
+        &self,
+        names: &[&str],
+        args: impl FnOnce(usize) -> Option<Vec<WasmVal>>,
+    ) -> Result<Vec<WasmVal>> {
+        let (name, func) = self.with_instance(|instance, store| {
+            names
+                .iter()
+                .find_map(|name| {
+                    instance
+                        .get_func(&mut *store, name)
+                        .map(|func| (*name, func))
+                })
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The module does not export a `{}` function.",
+                        names.join("` or `")
+                    )
+                })
+        })?;
+        let num_params = self.with_module(|store| func.ty(store).params().len())?;
+        let args = args(num_params).ok_or_else(|| {
+            anyhow::anyhow!("The `{name}` function can not have {num_params} parameters.")
+        })?;
+        self.call_function(RustOpaque::new(func.into()), args, Some(name))
+    }
+
+    /// The memory exported as [name] by the module. Without a [name],
+    /// the only memory exported by the module, the memories are ambiguous
+    /// when it exports more than one.
+    fn exported_memory(&self, name: Option<&str>) -> Result<RustOpaque<Memory>> {
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => {
+                let names = self.memories()?.0;
+                match names.as_slice() {
+                    [name] => name.clone(),
+                    [] => return Err(anyhow::anyhow!("The module does not export a `memory`.")),
+                    _ => {
+                        return Err(anyhow::anyhow!(
+                            "The module exports {} memories (`{}`), \
+                            the name of the memory to use is required.",
+                            names.len(),
+                            names.join("`, `")
+                        ))
+                    }
+                }
+            }
+        };
+        self.with_instance(|instance, store| {
+            instance
+                .get_memory(&mut *store, &name)
+                .map(RustOpaque::new)
+                .ok_or_else(|| anyhow::anyhow!("The module does not export a `{name}` memory."))
+        })
+    }
+
+    /// Executes [f] with the instance of the module, outside of a call to it.
+    pub(crate) fn with_instance<T>(
+        &self,
+        f: impl FnOnce(Instance, &mut Store<StoreState>) -> Result<T>,
+    ) -> Result<T> {
+        if self.1.current(self.0)?.is_some() {
+            return Err(anyhow::anyhow!(
+                "The exports of module {} can not be used within a call to it.",
+                self.0
+            ));
+        }
+        let module = get_module(self.0)?;
+        let mut module = module.lock().unwrap();
+        let value = &mut *module;
+        let instance = value
+            .instance
+            .ok_or_else(|| anyhow::anyhow!("Module {} is not instantiated.", self.0))?;
+        f(instance, &mut value.store)
+    }
+
     pub fn call_function_handle_parallel(
         &self,
         func_name: String,
@@ -522,8 +1462,9 @@ impl WasmRunModuleId {
         use rayon::prelude::*;
 
         let (num_params, result_types, pool, channels) = {
-            let mut m = ARRAY.write().unwrap();
-            let module = m.map.get_mut(&self.0).unwrap();
+            let module = get_module(self.0).unwrap();
+            let mut module = module.lock().unwrap();
+            let module = &mut *module;
 
             let func: Func = module
                 .instance
@@ -532,7 +1473,7 @@ impl WasmRunModuleId {
                 .unwrap();
             let num_params = func.ty(&module.store).params().count();
             if (num_params == 0 && !args.is_empty())
-                || (num_params != 0 && args.len() % num_params != 0)
+                || (num_params != 0 && !args.len().is_multiple_of(num_params))
                 || num_params * num_tasks != args.len()
             {
                 function_stream.add(ParallelExec::Err(format!(
@@ -638,8 +1579,8 @@ impl WasmRunModuleId {
         worker_index: usize,
         results: Vec<WasmVal>,
     ) -> Result<SyncReturn<()>> {
-        let m = ARRAY.read().unwrap();
-        let module = m.map.get(&self.0).unwrap();
+        let module = get_module(self.0)?;
+        let module = module.lock().unwrap();
         let worker = &module
             .channels
             .as_ref()
@@ -652,40 +1593,48 @@ impl WasmRunModuleId {
         Ok(SyncReturn(()))
     }
 
-    fn with_module_mut<T>(&self, f: impl FnOnce(StoreContextMut<'_, StoreState>) -> T) -> T {
-        {
-            let stack = self.1 .0.read().unwrap();
-            if let Some(caller) = stack.last() {
-                return f(caller.write().unwrap().as_context_mut());
-            }
+    /// Runs [f] with the store of the module, the one of the innermost call
+    /// when it is called from a host function.
+    /// Returns a [ModuleClosed] error if the module was closed or disposed.
+    pub(crate) fn with_module_mut<T>(
+        &self,
+        f: impl FnOnce(StoreContextMut<'_, StoreState>) -> T,
+    ) -> Result<T> {
+        if let Some(caller) = self.1.current(self.0)? {
+            return Ok(f(caller.write().unwrap().as_context_mut()));
         }
-        let mut arr = ARRAY.write().unwrap();
-        let value = arr.map.get_mut(&self.0).unwrap();
+        let module = get_module(self.0)?;
+        let mut value = module.lock().unwrap();
 
         let mut ctx = value.store.as_context_mut();
-        {
-            let v = RwLock::new(unsafe { std::mem::transmute(ctx.as_context_mut()) });
-            self.1 .0.write().unwrap().push(v);
-        }
+        self.1.push(unsafe {
+            std::mem::transmute::<
+                StoreContextMut<'_, StoreState>,
+                StoreContextMut<'static, StoreState>,
+            >(ctx.as_context_mut())
+        });
         let result = f(ctx);
-        self.1 .0.write().unwrap().pop();
-        result
+        self.1.pop();
+        Ok(result)
     }
 
-    fn with_module<T>(&self, f: impl FnOnce(&StoreContext<'_, StoreState>) -> T) -> T {
-        {
-            let stack = self.1 .0.read().unwrap();
-            if let Some(caller) = stack.last() {
-                return f(&caller.read().unwrap().as_context());
-            }
+    /// Runs [f] with the store of the module, see [Self::with_module_mut].
+    pub(crate) fn with_module<T>(
+        &self,
+        f: impl FnOnce(&StoreContext<'_, StoreState>) -> T,
+    ) -> Result<T> {
+        if let Some(caller) = self.1.current(self.0)? {
+            return Ok(f(&caller.read().unwrap().as_context()));
         }
-        let arr = ARRAY.read().unwrap();
-        let value = arr.map.get(&self.0).unwrap();
-        f(&value.store.as_context())
+        let module = get_module(self.0)?;
+        let value = module.lock().unwrap();
+        Ok(f(&value.store.as_context()))
     }
 
-    pub fn get_function_type(&self, func: RustOpaque<WFunc>) -> SyncReturn<FuncTy> {
-        SyncReturn(self.with_module(|store| (&func.func_wasmtime.ty(store)).into()))
+    pub fn get_function_type(&self, func: RustOpaque<WFunc>) -> Result<SyncReturn<FuncTy>> {
+        Ok(SyncReturn(self.with_module(|store| {
+            (&func.func_wasmtime.ty(store)).into()
+        })?))
     }
 
     pub fn create_function(
@@ -706,7 +1655,7 @@ impl WasmRunModuleId {
                 },
                 None,
             )
-        })
+        })?
     }
 
     fn _create_function(
@@ -760,29 +1709,55 @@ impl WasmRunModuleId {
     }
 
     fn execute_function(
-        caller: StoreContextMut<'_, StoreState>,
+        mut caller: StoreContextMut<'_, StoreState>,
         mapped: Vec<WasmVal>,
         f: WasmFunction,
         function_id: u32,
         results: &mut [Val],
     ) -> Result<()> {
+        if IN_ASYNC_CALL.with(|c| c.get()) {
+            return Err(anyhow::anyhow!(
+                "Host function with id {function_id} can not be called within an asynchronous call"
+            ));
+        }
+        let state = caller.data_mut();
+        if let Some(limit) = state.max_host_call_depth {
+            if state.host_call_depth >= limit {
+                return Err(ReentrancyLimitExceeded { function_id, limit }.into());
+            }
+        }
+        state.counters.count_host_call();
+        state.host_call_depth += 1;
         let inputs = vec![mapped].into_dart();
-        let stack = {
-            let stack = caller.data().stack.clone();
-            let v = RwLock::new(unsafe { std::mem::transmute(caller) });
-            stack.0.write().unwrap().push(v);
-            stack
-        };
+        let stack = caller.data().stack.clone();
+        stack.push(unsafe {
+            std::mem::transmute::<
+                StoreContextMut<'_, StoreState>,
+                StoreContextMut<'static, StoreState>,
+            >(caller)
+        });
 
-        let output: Vec<WasmVal> = unsafe {
+        let result = unsafe {
             let pointer = new_leak_box_ptr(inputs);
-            let result = f(function_id, pointer);
+            let result =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(function_id, pointer)));
             pointer.drop_in_place();
-            result.wire2api()
+            result
         };
         // TODO: use Drop for this
-        let last_caller = stack.0.write().unwrap().pop();
+        let last_caller = stack.pop();
+        if let Some(caller) = &last_caller {
+            caller.write().unwrap().data_mut().host_call_depth -= 1;
+        }
+        let result = result.map_err(|payload| HostPanic::new(function_id, payload))?;
 
+        // The host function returns a null pointer when it throws.
+        if result.is_null() {
+            return Err(anyhow::anyhow!(
+                "Host function with id {function_id} threw an exception"
+            ));
+        }
+        let output: Vec<WasmVal> = result.wire2api();
         if output.len() != results.len() {
             return Err(anyhow::anyhow!("Invalid output length"));
         } else if last_caller.is_none() {
@@ -804,7 +1779,7 @@ impl WasmRunModuleId {
             let mem_type = memory_type.to_memory_type()?;
             let memory = Memory::new(store, mem_type).map_err(to_anyhow)?;
             Ok(SyncReturn(RustOpaque::new(memory)))
-        })
+        })?
     }
 
     pub fn create_global(
@@ -827,7 +1802,7 @@ impl WasmRunModuleId {
                 mapped,
             )?;
             Ok(SyncReturn(RustOpaque::new(global)))
-        })
+        })?
     }
 
     pub fn create_table(
@@ -844,17 +1819,21 @@ impl WasmRunModuleId {
             )
             .map_err(to_anyhow)?;
             Ok(SyncReturn(RustOpaque::new(table)))
-        })
+        })?
     }
 
     // GLOBAL
 
-    pub fn get_global_type(&self, global: RustOpaque<Global>) -> SyncReturn<GlobalTy> {
-        SyncReturn(self.with_module(|store| (&global.ty(store)).into()))
+    pub fn get_global_type(&self, global: RustOpaque<Global>) -> Result<SyncReturn<GlobalTy>> {
+        Ok(SyncReturn(
+            self.with_module(|store| (&global.ty(store)).into())?,
+        ))
     }
 
-    pub fn get_global_value(&self, global: RustOpaque<Global>) -> SyncReturn<WasmVal> {
-        SyncReturn(self.with_module_mut(|store| WasmVal::from_val(global.get(store))))
+    pub fn get_global_value(&self, global: RustOpaque<Global>) -> Result<SyncReturn<WasmVal>> {
+        Ok(SyncReturn(self.with_module_mut(|store| {
+            WasmVal::from_val(global.get(store))
+        })?))
     }
 
     pub fn set_global_value(
@@ -868,28 +1847,39 @@ impl WasmRunModuleId {
                 .set(&mut store, mapped)
                 .map(|_| SyncReturn(()))
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
     // MEMORY
 
-    pub fn get_memory_type(&self, memory: RustOpaque<Memory>) -> SyncReturn<MemoryTy> {
-        SyncReturn(self.with_module(|store| (&memory.ty(store)).into()))
+    pub fn get_memory_type(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<MemoryTy>> {
+        Ok(SyncReturn(
+            self.with_module(|store| (&memory.ty(store)).into())?,
+        ))
     }
-    pub fn get_memory_data(&self, memory: RustOpaque<Memory>) -> SyncReturn<Vec<u8>> {
-        SyncReturn(self.with_module(|store| memory.data(store).to_owned()))
+    pub fn get_memory_data(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<Vec<u8>>> {
+        Ok(SyncReturn(
+            self.with_module(|store| memory.data(store).to_owned())?,
+        ))
     }
-    pub fn get_memory_data_pointer(&self, memory: RustOpaque<Memory>) -> SyncReturn<usize> {
-        SyncReturn(self.with_module(|store| memory.data_ptr(store) as usize))
+    /// The address of the data of [memory]. It may change when the memory grows,
+    /// including during a call into the guest, so it should not be kept
+    /// across calls. Use [read_memory] and [write_memory] to copy the data.
+    pub fn get_memory_data_pointer(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<usize>> {
+        Ok(SyncReturn(
+            self.with_module(|store| memory.data_ptr(store) as usize)?,
+        ))
     }
+    /// The address and length of the data of [memory],
+    /// see [get_memory_data_pointer].
     pub fn get_memory_data_pointer_and_length(
         &self,
         memory: RustOpaque<Memory>,
-    ) -> SyncReturn<PointerAndLength> {
-        SyncReturn(self.with_module(|store| PointerAndLength {
+    ) -> Result<SyncReturn<PointerAndLength>> {
+        Ok(SyncReturn(self.with_module(|store| PointerAndLength {
             pointer: memory.data_ptr(store) as usize,
             length: memory.data_size(store),
-        }))
+        })?))
     }
     pub fn read_memory(
         &self,
@@ -897,20 +1887,15 @@ impl WasmRunModuleId {
         offset: usize,
         bytes: usize,
     ) -> Result<SyncReturn<Vec<u8>>> {
-        self.with_module(|store| {
-            let mut buffer = Vec::with_capacity(bytes);
-            #[allow(clippy::uninit_vec)]
-            unsafe {
-                buffer.set_len(bytes)
-            };
-            memory
-                .read(store, offset, &mut buffer)
-                .map(|_| SyncReturn(buffer))
-                .map_err(to_anyhow)
-        })
+        let mut buffer = Vec::new();
+        self.read_memory_into_vec(&memory, offset, bytes, &mut buffer)?;
+        Ok(SyncReturn(buffer))
     }
-    pub fn get_memory_pages(&self, memory: RustOpaque<Memory>) -> SyncReturn<u32> {
-        SyncReturn(self.with_module(|store| memory.size(store).try_into().unwrap()))
+
+    pub fn get_memory_pages(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<u32>> {
+        Ok(SyncReturn(self.with_module(|store| {
+            memory.size(store).try_into().unwrap()
+        })?))
     }
 
     pub fn write_memory(
@@ -924,7 +1909,7 @@ impl WasmRunModuleId {
                 .write(store, offset, &buffer)
                 .map(SyncReturn)
                 .map_err(to_anyhow)
-        })
+        })?
     }
     pub fn grow_memory(&self, memory: RustOpaque<Memory>, pages: u32) -> Result<SyncReturn<u32>> {
         self.with_module_mut(|store| {
@@ -932,16 +1917,18 @@ impl WasmRunModuleId {
                 .grow(store, pages.into())
                 .map(|p| SyncReturn(p.try_into().unwrap()))
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
     // TABLE
 
-    pub fn get_table_size(&self, table: RustOpaque<Table>) -> SyncReturn<u32> {
-        SyncReturn(self.with_module(|store| table.size(store)))
+    pub fn get_table_size(&self, table: RustOpaque<Table>) -> Result<SyncReturn<u32>> {
+        Ok(SyncReturn(self.with_module(|store| table.size(store))?))
     }
-    pub fn get_table_type(&self, table: RustOpaque<Table>) -> SyncReturn<TableTy> {
-        SyncReturn(self.with_module(|store| (&table.ty(store)).into()))
+    pub fn get_table_type(&self, table: RustOpaque<Table>) -> Result<SyncReturn<TableTy>> {
+        Ok(SyncReturn(
+            self.with_module(|store| (&table.ty(store)).into())?,
+        ))
     }
 
     pub fn grow_table(
@@ -956,11 +1943,17 @@ impl WasmRunModuleId {
                 .grow(&mut store, delta, mapped)
                 .map(SyncReturn)
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
-    pub fn get_table(&self, table: RustOpaque<Table>, index: u32) -> SyncReturn<Option<WasmVal>> {
-        SyncReturn(self.with_module_mut(|store| table.get(store, index).map(WasmVal::from_val)))
+    pub fn get_table(
+        &self,
+        table: RustOpaque<Table>,
+        index: u32,
+    ) -> Result<SyncReturn<Option<WasmVal>>> {
+        Ok(SyncReturn(self.with_module_mut(|store| {
+            table.get(store, index).map(WasmVal::from_val)
+        })?))
     }
 
     pub fn set_table(
@@ -975,7 +1968,7 @@ impl WasmRunModuleId {
                 .set(&mut store, index, mapped)
                 .map(SyncReturn)
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
     pub fn fill_table(
@@ -991,20 +1984,108 @@ impl WasmRunModuleId {
                 .fill(&mut store, index, mapped, len)
                 .map(|_| SyncReturn(()))
                 .map_err(to_anyhow)
-        })
+        })?
+    }
+
+    // HOST CALLS
+
+    /// Limits the number of nested host function calls in the store to [max_depth],
+    /// for example, when a host function calls back into the guest which calls
+    /// it again. The call that exceeds it traps with [ReentrancyLimitExceeded].
+    /// `max_wasm_stack` only limits the Wasm frames, not the host frames between them.
+    /// Defaults to `None`, which does not limit them.
+    pub fn set_max_host_call_depth(&self, max_depth: Option<u32>) -> Result<SyncReturn<()>> {
+        self.with_module_mut(|mut store| store.data_mut().max_host_call_depth = max_depth)?;
+        Ok(SyncReturn(()))
+    }
+
+    // EPOCH
+    //
+
+    /// Interrupts the functions of the module with an `interrupt` trap when
+    /// the epoch of its engine is incremented [ticks_beyond_current] times
+    /// from now, see [WasmEngine::increment_epoch]. `None` removes the deadline,
+    /// which is the default. Requires [ModuleConfigWasmtime::epoch_interruption].
+    /// The calls executed in the threads of `num_threads` are not interrupted.
+    pub fn set_epoch_deadline(&self, ticks_beyond_current: Option<u64>) -> Result<SyncReturn<()>> {
+        self.with_module_mut(|mut store| {
+            store.set_epoch_deadline(ticks_beyond_current.unwrap_or(NO_EPOCH_DEADLINE))
+        })?;
+        Ok(SyncReturn(()))
     }
 
     // FUEL
     //
 
     pub fn add_fuel(&self, delta: u64) -> Result<SyncReturn<()>> {
-        self.with_module_mut(|mut store| store.add_fuel(delta).map(SyncReturn))
+        self.with_module_mut(|mut store| store.add_fuel(delta).map(SyncReturn))?
     }
-    pub fn fuel_consumed(&self) -> SyncReturn<Option<u64>> {
+    pub fn fuel_consumed(&self) -> Result<SyncReturn<Option<u64>>> {
         self.with_module_mut(|store| SyncReturn(store.fuel_consumed()))
     }
     pub fn consume_fuel(&self, delta: u64) -> Result<SyncReturn<u64>> {
-        self.with_module_mut(|mut store| store.consume_fuel(delta).map(SyncReturn))
+        self.with_module_mut(|mut store| store.consume_fuel(delta).map(SyncReturn))?
+    }
+    /// The fuel consumed by the last call to [call_function_handle],
+    /// including the fuel consumed by nested calls made from host functions.
+    /// Returns None if `consume_fuel` is not enabled or no function was called.
+    pub fn last_call_fuel_consumed(&self) -> Result<SyncReturn<Option<u64>>> {
+        self.with_module(|store| SyncReturn(store.data().last_call.and_then(|c| c.fuel_consumed)))
+    }
+    /// The wall-clock duration in microseconds of the last call to [call_function_handle].
+    /// Returns None if no function was called.
+    pub fn last_call_duration_micros(&self) -> Result<SyncReturn<Option<u64>>> {
+        self.with_module(|store| {
+            SyncReturn(
+                store
+                    .data()
+                    .last_call
+                    .map(|c| c.wall_time.as_micros() as u64),
+            )
+        })
+    }
+
+    /// The memory used by the instance, the sum of the sizes of its memories
+    /// and an estimate of the overhead of its tables and globals.
+    /// The sizes are tracked by the resource limiter of the store, so they
+    /// include the memories and tables that are not exported.
+    /// The instances are allocated on demand, there is no pooling allocator
+    /// with slots to report.
+    pub fn memory_footprint(&self) -> Result<SyncReturn<MemoryFootprint>> {
+        self.with_instance(|instance, store| {
+            let exports = instance
+                .exports(&mut *store)
+                .map(Export::into_extern)
+                .collect::<Vec<_>>();
+            let mut footprint = store.data().counters.memory_footprint();
+            for export in exports {
+                match export {
+                    Extern::Memory(_) | Extern::SharedMemory(_) => footprint.add_memory(),
+                    Extern::Table(_) => footprint.add_table(),
+                    Extern::Global(_) => footprint.add_global(),
+                    Extern::Func(_) => {}
+                }
+            }
+            Ok(SyncReturn(footprint))
+        })
+    }
+
+    /// The names of the memories exported by the module, in export order.
+    /// The functions that access the memory of the module without receiving it,
+    /// like [WasmRunModuleId::read_guest_string], require the name of the memory
+    /// when there is more than one. WASI always uses the memory exported as `memory`.
+    pub fn memories(&self) -> Result<SyncReturn<Vec<String>>> {
+        self.with_instance(|instance, store| {
+            let names = instance
+                .exports(&mut *store)
+                .filter_map(|e| {
+                    let name = e.name().to_string();
+                    matches!(e.into_extern(), Extern::Memory(_) | Extern::SharedMemory(_))
+                        .then_some(name)
+                })
+                .collect();
+            Ok(SyncReturn(names))
+        })
     }
 }
 
@@ -1012,12 +2093,64 @@ pub fn parse_wat_format(wat: String) -> Result<Vec<u8>> {
     Ok(wat::parse_str(wat)?)
 }
 
+/// Rewrites the [module_wasm] binary setting the maximum of the memories defined
+/// by the module to [max_pages], or keeping the declared maximum if it is lower.
+/// Returns an error if the minimum of a memory exceeds [max_pages].
+pub fn clamp_memory(module_wasm: Vec<u8>, max_pages: u64) -> Result<SyncReturn<Vec<u8>>> {
+    crate::transform::clamp_memory(&module_wasm, max_pages).map(SyncReturn)
+}
+
+/// Returns `true` if the [module_wasm] binary defines a start function,
+/// which is executed when instantiating the module.
+pub fn module_has_start(module_wasm: Vec<u8>) -> Result<SyncReturn<bool>> {
+    for payload in wasmparser::Parser::new(0).parse_all(&module_wasm) {
+        if let wasmparser::Payload::StartSection { .. } = payload? {
+            return Ok(SyncReturn(true));
+        }
+    }
+    Ok(SyncReturn(false))
+}
+
+/// Returns the SHA-256 of the [module_wasm] binary, or of its binary format
+/// if it is in the text format, to be used as a cache or deduplication key.
+/// The hash is stable across runs and independent of the whitespace and comments
+/// of text format inputs.
+pub fn module_content_hash(module_wasm: Vec<u8>) -> Result<SyncReturn<Vec<u8>>> {
+    let hash = crate::hash::content_hash(&module_wasm)?;
+    Ok(SyncReturn(hash.to_vec()))
+}
+
 type WasmFunction =
-    unsafe extern "C" fn(function_id: u32, args: *mut DartAbi) -> *mut wire_list_wasm_val;
+    unsafe extern "C-unwind" fn(function_id: u32, args: *mut DartAbi) -> *mut wire_list_wasm_val;
 
+/// A compiled module that is shared by all its instances.
+///
+/// Compiling, with [compile_wasm] or [WasmEngine::compile_wasm], is done once.
+/// Each instantiation, with [CompiledModule::instantiate] or [module_builder],
+/// creates an isolated store with its own memories, tables, globals and WASI context,
+/// which is cheap compared to compiling. For example, to create an instance per request.
+///
+/// The compiled code is immutable and the module can be shared and instantiated
+/// from many threads concurrently. The instances are not shared between stores.
 pub struct CompiledModule(pub RustOpaque<Arc<std::sync::Mutex<Module>>>);
 
 impl CompiledModule {
+    /// Creates a new isolated store for this module and instantiates it
+    /// with the WASI imports of [wasi_config], if any.
+    /// Use [module_builder] to define other imports before instantiating.
+    /// The returned store should be disposed with [WasmRunModuleId::dispose].
+    pub fn instantiate(
+        &self,
+        wasi_config: Option<WasiConfigNative>,
+    ) -> Result<SyncReturn<WasmRunModuleId>> {
+        let module_id = module_builder(CompiledModule(self.0.clone()), None, wasi_config)?.0;
+        if let Err(err) = module_id.instantiate() {
+            module_id.dispose()?;
+            return Err(err);
+        }
+        Ok(SyncReturn(module_id))
+    }
+
     pub fn create_shared_memory(
         &self,
         memory_type: MemoryTy,
@@ -1038,13 +2171,25 @@ impl CompiledModule {
         )
     }
 
+    /// The [WasiModuleKind] of the module, from its exports.
+    pub fn wasi_module_kind(&self) -> SyncReturn<WasiModuleKind> {
+        let module = self.0.lock().unwrap();
+        SyncReturn(WasiModuleKind::from_exports(|name| {
+            matches!(
+                module.get_export(name),
+                Some(ExternType::Func(ty)) if ty.params().len() == 0 && ty.results().len() == 0
+            )
+        }))
+    }
+
     pub fn get_module_exports(&self) -> SyncReturn<Vec<ModuleExportDesc>> {
         SyncReturn(
             self.0
                 .lock()
                 .unwrap()
                 .exports()
-                .map(|i| (&i).into())
+                .enumerate()
+                .map(|(index, e)| ModuleExportDesc::new(index, &e))
                 .collect(),
         )
     }
@@ -1056,13 +2201,356 @@ impl From<Module> for CompiledModule {
     }
 }
 
+/// A compilation environment that can be shared across many modules.
+/// All the modules compiled with the same engine, and the stores
+/// instantiated from them, share the [ModuleConfig] used to create it.
+#[derive(Clone)]
+pub struct WasmEngine(pub RustOpaque<WEngine>);
+
+impl WasmEngine {
+    /// Compiles [module_wasm] with the [ModuleConfig] of the engine, like [compile_wasm].
+    pub fn compile_wasm(&self, module_wasm: Vec<u8>) -> Result<CompiledModule> {
+        Ok(compile_with_engine(&self.0, module_wasm)?.into())
+    }
+
+    pub fn compile_wasm_sync(&self, module_wasm: Vec<u8>) -> Result<SyncReturn<CompiledModule>> {
+        self.compile_wasm(module_wasm).map(SyncReturn)
+    }
+
+    /// Increments the epoch of the engine, interrupting the functions of the
+    /// stores that reached their epoch deadline when epoch interruption is enabled.
+    /// Meant to be called from a timer thread with a custom cadence,
+    /// it is shared by all the stores of the modules compiled with this engine.
+    pub fn increment_epoch(&self) -> Result<SyncReturn<()>> {
+        self.0.engine_wasmtime.increment_epoch();
+        Ok(SyncReturn(()))
+    }
+
+    /// Creates a [WasmLinker] for the modules compiled with this engine.
+    /// The WASI imports are defined once in the linker if [wasi] is `true`.
+    pub fn create_linker(&self, wasi: bool) -> Result<SyncReturn<WasmLinker>> {
+        let engine = self.0.engine_wasmtime.clone();
+        let mut linker = <Linker<StoreState>>::new(&engine);
+        if wasi {
+            add_wasi_to_linker(&mut linker)?;
+        }
+        Ok(SyncReturn(WasmLinker(RustOpaque::new(Arc::new(
+            std::sync::Mutex::new(LinkerTemplate {
+                engine,
+                linker,
+                wasi,
+                memories: vec![],
+            }),
+        )))))
+    }
+
+    /// Creates a [ModuleCache] for the modules compiled with this engine.
+    /// The least recently used modules are evicted when there are more than
+    /// [max_entries] modules or their binaries exceed [max_bytes] in total.
+    /// If [directory] is provided, the compiled artifacts are also stored
+    /// in it and loaded instead of compiling the module again, for example,
+    /// in the next run of the app. Loading an artifact runs its native code,
+    /// so the directory must only be writable by the app. The artifacts are
+    /// checked for corruption before they are loaded, not for tampering.
+    pub fn create_module_cache(
+        &self,
+        max_entries: Option<u32>,
+        max_bytes: Option<u64>,
+        directory: Option<String>,
+    ) -> Result<SyncReturn<ModuleCache>> {
+        if let Some(directory) = &directory {
+            fs::create_dir_all(directory)?;
+        }
+        let engine = (*self.0).clone();
+        let store = ModuleStore::new(ModuleCacheLimits {
+            max_entries,
+            max_bytes,
+        });
+        Ok(SyncReturn(ModuleCache(RustOpaque::new(Arc::new(
+            std::sync::Mutex::new(ModuleCacheImpl {
+                engine,
+                store,
+                directory,
+            }),
+        )))))
+    }
+}
+
+/// The definitions of a [WasmLinker].
+pub struct LinkerTemplate {
+    engine: Engine,
+    linker: Linker<StoreState>,
+    wasi: bool,
+    /// The memories created for each module, see [WasmLinker::define_memory].
+    memories: Vec<(String, String, MemoryTy)>,
+}
+
+/// A linker with the WASI imports and host functions defined once
+/// and reused by all the modules instantiated with it,
+/// instead of defining them again for each module.
+/// Created with [WasmEngine::create_linker], it can only be used
+/// with the modules compiled by the same engine.
+///
+/// The host functions are shared by all the stores, so they are not
+/// bound to a module and their closures must be `Send + Sync`.
+#[derive(Clone)]
+pub struct WasmLinker(pub RustOpaque<Arc<std::sync::Mutex<LinkerTemplate>>>);
+
+impl WasmLinker {
+    /// Defines the host function [module]::[name] for all the modules
+    /// instantiated with this linker.
+    pub fn define_host_function(
+        &self,
+        module: String,
+        name: String,
+        function_pointer: usize,
+        function_id: u32,
+        param_types: Vec<ValueTy>,
+        result_types: Vec<ValueTy>,
+    ) -> Result<SyncReturn<()>> {
+        let f: WasmFunction = unsafe { std::mem::transmute(function_pointer) };
+        let ty = FuncType::new(
+            param_types.into_iter().map(ValueType::from),
+            result_types.into_iter().map(ValueType::from),
+        );
+        self.0.lock().unwrap().linker.func_new(
+            &module,
+            &name,
+            ty,
+            move |mut caller, params, results| {
+                let mapped: Vec<WasmVal> = params
+                    .iter()
+                    .map(|a| WasmVal::from_val(a.clone()))
+                    .collect();
+                WasmRunModuleId::execute_function(
+                    caller.as_context_mut(),
+                    mapped,
+                    f,
+                    function_id,
+                    results,
+                )
+            },
+        )?;
+        Ok(SyncReturn(()))
+    }
+
+    /// Defines the memory [module]::[name] with [memory_type] for all the modules
+    /// instantiated with this linker. A new memory is created for each module,
+    /// for example, for modules that import their memory as `env::memory`.
+    /// Shared memories should be created with [WasmRunModuleId::create_shared_memory]
+    /// and linked with [WasmRunModuleId::link_imports].
+    pub fn define_memory(
+        &self,
+        module: String,
+        name: String,
+        memory_type: MemoryTy,
+    ) -> Result<SyncReturn<()>> {
+        if memory_type.shared {
+            return Err(anyhow::anyhow!(
+                "The shared memory `{module}::{name}` can not be defined in the linker, link it with `link_imports`."
+            ));
+        }
+        memory_type.to_memory_type()?;
+        self.0
+            .lock()
+            .unwrap()
+            .memories
+            .push((module, name, memory_type));
+        Ok(SyncReturn(()))
+    }
+
+    /// Creates a module builder for [module] using a clone of this linker.
+    /// [wasi_config] must be provided if, and only if,
+    /// the linker was created with WASI.
+    pub fn module_builder(
+        &self,
+        module: CompiledModule,
+        wasi_config: Option<WasiConfigNative>,
+    ) -> Result<SyncReturn<WasmRunModuleId>> {
+        let (linker, memories) = {
+            let template = self.0.lock().unwrap();
+            if !Engine::same(&template.engine, module.0.lock().unwrap().engine()) {
+                return Err(anyhow::anyhow!(
+                    "The module was not compiled with the engine of the linker."
+                ));
+            }
+            if template.wasi != wasi_config.is_some() {
+                return Err(anyhow::anyhow!(
+                    "The linker was created with `wasi: {}`, a `wasi_config` must be provided if, and only if, WASI is enabled.",
+                    template.wasi
+                ));
+            }
+            (template.linker.clone(), template.memories.clone())
+        };
+        let module_id =
+            module_builder_with_linker(module, None, wasi_config, Some(linker), None)?.0;
+        for (namespace, name, memory_type) in memories {
+            let memory = module_id.create_memory(memory_type)?.0;
+            module_id.link_imports(vec![ModuleImport {
+                module: namespace,
+                name,
+                value: ExternalValue::Memory(memory),
+            }])?;
+        }
+        Ok(SyncReturn(module_id))
+    }
+}
+
+/// The state of a [ModuleCache].
+pub struct ModuleCacheImpl {
+    engine: WEngine,
+    store: ModuleStore<RustOpaque<Arc<std::sync::Mutex<Module>>>>,
+    directory: Option<String>,
+}
+
+/// Compiled modules of a [WasmEngine] keyed by the content hash of their binary,
+/// as returned by [module_content_hash]. Compiling the same bytes again
+/// returns the cached module instead of recompiling it.
+/// Created with [WasmEngine::create_module_cache].
+#[derive(Clone)]
+pub struct ModuleCache(pub RustOpaque<Arc<std::sync::Mutex<ModuleCacheImpl>>>);
+
+impl ModuleCache {
+    pub fn compile_wasm(&self, module_wasm: Vec<u8>) -> Result<CompiledModule> {
+        let hash = crate::hash::content_hash(&module_wasm)?;
+        let (engine, artifact) = {
+            let mut cache = self.0.lock().unwrap();
+            if let Some(module) = cache.store.get(&hash) {
+                return Ok(CompiledModule(module));
+            }
+            let artifact = cache
+                .directory
+                .as_ref()
+                .map(|d| std::path::Path::new(d).join(artifact_file_name(&hash)));
+            (cache.engine.clone(), artifact)
+        };
+
+        // The artifacts are only written by this cache, a corrupted, stale or
+        // incompatible artifact is replaced by compiling the module.
+        let sealed = artifact.as_ref().and_then(|path| fs::read(path).ok());
+        let deserialized = sealed
+            .as_deref()
+            .and_then(open_artifact)
+            .and_then(|bytes| unsafe { Module::deserialize(&engine.engine_wasmtime, bytes) }.ok());
+        let size_bytes = module_wasm.len() as u64;
+        let module = match deserialized {
+            Some(module) => module,
+            None => {
+                let module = compile_with_engine(&engine, module_wasm)?;
+                if let Some(path) = &artifact {
+                    fs::write(path, seal_artifact(&module.serialize()?))?;
+                }
+                module
+            }
+        };
+        let CompiledModule(module) = module.into();
+        self.0
+            .lock()
+            .unwrap()
+            .store
+            .insert(hash, module.clone(), size_bytes);
+        Ok(CompiledModule(module))
+    }
+
+    pub fn compile_wasm_sync(&self, module_wasm: Vec<u8>) -> Result<SyncReturn<CompiledModule>> {
+        self.compile_wasm(module_wasm).map(SyncReturn)
+    }
+
+    pub fn stats(&self) -> SyncReturn<ModuleCacheStats> {
+        SyncReturn(self.0.lock().unwrap().store.stats())
+    }
+
+    /// Removes all the modules from memory.
+    /// The artifacts in the directory of the cache are not deleted.
+    pub fn clear(&self) -> SyncReturn<()> {
+        self.0.lock().unwrap().store.clear();
+        SyncReturn(())
+    }
+}
+
+pub fn create_engine(config: ModuleConfig) -> Result<SyncReturn<WasmEngine>> {
+    let engine = Engine::new(&Config::try_from(config.clone())?)?;
+    Ok(SyncReturn(WasmEngine(RustOpaque::new(WEngine::new(
+        engine, config,
+    )))))
+}
+
+/// Compiles [module_wasm] with [engine] after checking the limits and applying
+/// the transformations of its [ModuleConfig], like [compile_wasm].
+fn compile_with_engine(engine: &WEngine, module_wasm: Vec<u8>) -> Result<Module> {
+    let _span = observer::compile_span(module_wasm.len());
+    let config = &engine.config;
+    config.check_module_limits(&module_wasm)?;
+    let module_wasm = config.transform_module(module_wasm)?;
+    let features = crate::validate::parser_features(config);
+    let stack_size = config
+        .wasmtime
+        .as_ref()
+        .and_then(|c| c.compilation_thread_stack_size);
+    let module = with_compilation_stack(stack_size, || {
+        Module::new(&engine.engine_wasmtime, &module_wasm[..])
+    })?
+    .map_err(|err| CompileError::new(&module_wasm, features, err))?;
+    Ok(module)
+}
+
+/// Compiles the module with a new engine created from `config`.
+/// Use [WasmEngine] to share the engine between modules.
+/// Returns a [CompileError] if the module is invalid or can not be compiled.
 pub fn compile_wasm(module_wasm: Vec<u8>, config: ModuleConfig) -> Result<CompiledModule> {
-    let config: Config = config.into();
+    let _span = observer::compile_span(module_wasm.len());
+    config.check_module_limits(&module_wasm)?;
+    let module_wasm = config.transform_module(module_wasm)?;
+    let features = crate::validate::parser_features(&config);
+    let stack_size = config
+        .wasmtime
+        .as_ref()
+        .and_then(|c| c.compilation_thread_stack_size);
+    let config = Config::try_from(config)?;
     let engine = Engine::new(&config)?;
-    let module = Module::new(&engine, &module_wasm[..])?;
+    let module = with_compilation_stack(stack_size, || Module::new(&engine, &module_wasm[..]))?
+        .map_err(|err| CompileError::new(&module_wasm, features, err))?;
     Ok(module.into())
 }
 
+/// Runs [compile] in a thread pool whose threads have [stack_size] bytes
+/// of stack. Wasmtime's parallel compilation uses the pool's threads
+/// since it is the current Rayon pool while [compile] runs.
+/// Runs [compile] in the current thread if [stack_size] is `None`.
+/// The pools are kept for the next compilations with the same stack size.
+fn with_compilation_stack<T: Send>(
+    stack_size: Option<usize>,
+    compile: impl FnOnce() -> T + Send,
+) -> Result<T> {
+    static POOLS: Lazy<Mutex<HashMap<usize, Arc<rayon::ThreadPool>>>> =
+        Lazy::new(|| Mutex::new(HashMap::new()));
+
+    let Some(stack_size) = stack_size else {
+        return Ok(compile());
+    };
+    if stack_size == 0 {
+        return Err(anyhow::anyhow!(
+            "`compilation_thread_stack_size` must be greater than 0 bytes."
+        ));
+    }
+    let pool = {
+        let mut pools = POOLS.lock().unwrap();
+        match pools.get(&stack_size) {
+            Some(pool) => pool.clone(),
+            None => {
+                let pool = Arc::new(
+                    rayon::ThreadPoolBuilder::new()
+                        .stack_size(stack_size)
+                        .build()?,
+                );
+                pools.insert(stack_size, pool.clone());
+                pool
+            }
+        }
+    };
+    Ok(pool.install(compile))
+}
+
 pub fn compile_wasm_sync(
     module_wasm: Vec<u8>,
     config: ModuleConfig,
@@ -1070,6 +2558,81 @@ pub fn compile_wasm_sync(
     compile_wasm(module_wasm, config).map(SyncReturn)
 }
 
+/// A handle to cancel a compilation started with [compile_wasm_cancelable].
+#[derive(Debug, Clone)]
+pub struct CompileHandle(pub RustOpaque<Arc<AtomicBool>>);
+
+impl CompileHandle {
+    /// Cancels the compilations that use this handle, they return a [Cancelled]
+    /// error. Cancelling after the module was compiled has no effect.
+    pub fn cancel(&self) -> SyncReturn<()> {
+        self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        SyncReturn(())
+    }
+
+    pub fn is_cancelled(&self) -> SyncReturn<bool> {
+        SyncReturn(self.0.load(std::sync::atomic::Ordering::SeqCst))
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.is_cancelled().0 {
+            return Err(Cancelled.into());
+        }
+        Ok(())
+    }
+}
+
+pub fn create_compile_handle() -> SyncReturn<CompileHandle> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    SyncReturn(CompileHandle(RustOpaque::new(cancelled)))
+}
+
+/// Compiles [module_wasm] like [compile_wasm], returning a [Cancelled] error
+/// when [handle] is cancelled. The handle is checked between the phases of the
+/// compilation, the code generation runs in a separate thread which is
+/// detached when the handle is cancelled: the thread keeps compiling in the
+/// background until it finishes and its module is dropped. At most 4 threads
+/// compile at the same time, including the detached ones, the next
+/// compilations wait for one of them to finish.
+pub fn compile_wasm_cancelable(
+    module_wasm: Vec<u8>,
+    config: ModuleConfig,
+    handle: CompileHandle,
+) -> Result<CompiledModule> {
+    handle.check()?;
+    let _span = observer::compile_span(module_wasm.len());
+    config.check_module_limits(&module_wasm)?;
+    let module_wasm = config.transform_module(module_wasm)?;
+    let features = crate::validate::parser_features(&config);
+    let stack_size = config
+        .wasmtime
+        .as_ref()
+        .and_then(|c| c.compilation_thread_stack_size);
+    let config = Config::try_from(config)?;
+    let engine = Engine::new(&config)?;
+    handle.check()?;
+
+    let slot = CompilationSlot::acquire(|| handle.check())?;
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _slot = slot;
+        let module = with_compilation_stack(stack_size, || Module::new(&engine, &module_wasm[..]))
+            .and_then(|module| {
+                module.map_err(|err| CompileError::new(&module_wasm, features, err).into())
+            });
+        _ = sender.send(module);
+    });
+    loop {
+        match receiver.recv_timeout(std::time::Duration::from_millis(10)) {
+            std::result::Result::Ok(module) => return Ok(module?.into()),
+            Err(mpsc::RecvTimeoutError::Timeout) => handle.check()?,
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(anyhow::anyhow!("The compilation thread panicked."))
+            }
+        }
+    }
+}
+
 pub fn wasm_features_for_config(config: ModuleConfig) -> SyncReturn<WasmFeatures> {
     SyncReturn(config.wasm_features())
 }
@@ -1556,3 +3119,1607 @@ impl Num for u64 {
         self as i64
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(deprecated)]
+    fn run_wasi_start(wat: &str, capture_output: bool) -> Result<i32> {
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let wasi_config = WasiConfigNative {
+            capture_stdout: capture_output,
+            capture_stderr: capture_output,
+            line_buffered_output: false,
+            inherit_stdin: false,
+            inherit_env: false,
+            inherit_args: false,
+            args: vec![],
+            env: vec![],
+            preopened_files: vec![],
+            preopened_dirs: vec![],
+            capture_capacity: None,
+            capture_overflow: CaptureOverflow::drop,
+            deterministic: false,
+            unknown_errno: None,
+        };
+        let module_id = module_builder(module, None, Some(wasi_config))?.0;
+        let instance = module_id.instantiate()?;
+        let start = instance
+            .exports()
+            .0
+            .into_iter()
+            .find_map(|e| match e.value {
+                ExternalValue::Func(func) if e.desc.name == "_start" => Some(func),
+                _ => None,
+            })
+            .unwrap();
+        let exit_code = module_id.call_wasi_start(start).map(|v| v.0);
+        module_id.dispose()?;
+        exit_code
+    }
+
+    thread_local!(static REENTRANT: RefCell<Option<(WasmRunModuleId, RustOpaque<WFunc>)>> = const { RefCell::new(None) });
+
+    /// A host function that calls the exported `inner` function when
+    /// [function_id] is 1, panics when it is 3 and throws otherwise.
+    unsafe extern "C-unwind" fn host_function(
+        function_id: u32,
+        _args: *mut DartAbi,
+    ) -> *mut wire_list_wasm_val {
+        if function_id == 3 {
+            panic!("deliberate host panic");
+        }
+        if function_id != 1 {
+            return std::ptr::null_mut();
+        }
+        let (module_id, inner) = REENTRANT.with(|r| r.borrow().clone()).unwrap();
+        let output = module_id.call_function_handle(inner, vec![]).unwrap();
+        assert!(matches!(output.as_slice(), [WasmVal::i32(42)]));
+        crate::bridge_generated::new_list_wasm_val_0(0)
+    }
+
+    fn call_host_function(function_id: u32) -> Result<Vec<WasmVal>> {
+        let wat = r#"(module
+            (import "host" "f" (func $f))
+            (func (export "inner") (result i32) i32.const 42)
+            (func (export "run") (result i32) call $f i32.const 1)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let f = module_id
+            .create_function(
+                host_function as WasmFunction as usize,
+                function_id,
+                vec![],
+                vec![],
+            )?
+            .0;
+        module_id.link_imports(vec![ModuleImport {
+            module: "host".to_string(),
+            name: "f".to_string(),
+            value: ExternalValue::Func(f),
+        }])?;
+        let instance = module_id.instantiate()?;
+        let export = |name: &str| {
+            instance
+                .exports()
+                .0
+                .into_iter()
+                .find_map(|e| match e.value {
+                    ExternalValue::Func(func) if e.desc.name == name => Some(func),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        REENTRANT.with(|r| *r.borrow_mut() = Some((module_id.clone(), export("inner"))));
+        let output = module_id
+            .call_function_handle_sync(export("run"), vec![])
+            .map(|v| v.0);
+        REENTRANT.with(|r| *r.borrow_mut() = None);
+        module_id.dispose()?;
+        output
+    }
+
+    #[test]
+    fn host_functions_are_reentrant() {
+        let output = call_host_function(1).unwrap();
+        assert!(matches!(output.as_slice(), [WasmVal::i32(1)]));
+    }
+
+    #[test]
+    fn host_function_exceptions_trap() {
+        let err = call_host_function(2).unwrap_err();
+        assert!(format!("{err:?}").contains("Host function with id 2 threw an exception"));
+    }
+
+    #[test]
+    fn host_function_panics_trap() {
+        let err = call_host_function(3).unwrap_err();
+        let panic = err.downcast_ref::<HostPanic>().unwrap();
+        assert_eq!(panic.function_id, 3);
+        assert_eq!(panic.message, "deliberate host panic");
+        assert!(format!("{err:?}").contains("HostPanic: host function with id 3 panicked"));
+    }
+
+    #[test]
+    fn compile_wasm_cancelable_returns_cancelled() -> Result<()> {
+        let handle = create_compile_handle().0;
+        handle.cancel();
+        let err = compile_wasm_cancelable(vec![], Default::default(), handle)
+            .err()
+            .unwrap();
+        assert!(err.downcast_ref::<Cancelled>().is_some());
+
+        // Many functions with large bodies take seconds to compile.
+        let body = "(drop (i32.add (local.get 0) (i32.const 1)))".repeat(200);
+        let funcs = format!("(func (param i32) {body})").repeat(2000);
+        let wasm = parse_wat_format(format!("(module {funcs})"))?;
+        let handle = create_compile_handle().0;
+        let compilation = {
+            let handle = handle.clone();
+            std::thread::spawn(move || compile_wasm_cancelable(wasm, Default::default(), handle))
+        };
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        handle.cancel();
+        let cancelled_at = std::time::Instant::now();
+        let err = compilation.join().unwrap().err().unwrap();
+        assert!(cancelled_at.elapsed() < std::time::Duration::from_millis(500));
+        assert!(err.downcast_ref::<Cancelled>().is_some(), "{err}");
+        assert!(handle.is_cancelled().0);
+
+        let handle = create_compile_handle().0;
+        let module = compile_wasm_cancelable(
+            parse_wat_format("(module)".to_string())?,
+            Default::default(),
+            handle.clone(),
+        )?;
+        assert!(module.get_module_exports().0.is_empty());
+        handle.cancel();
+        Ok(())
+    }
+
+    /// The module, its `run` export, the number of calls
+    /// and the first error of [recursive_host_function].
+    type Recursion = (
+        WasmRunModuleId,
+        RustOpaque<WFunc>,
+        u32,
+        Option<anyhow::Error>,
+    );
+
+    thread_local!(static RECURSION: RefCell<Option<Recursion>> = const { RefCell::new(None) });
+
+    /// A host function that calls the exported `run` function,
+    /// which calls it again, until it was called 20 times.
+    unsafe extern "C-unwind" fn recursive_host_function(
+        _function_id: u32,
+        _args: *mut DartAbi,
+    ) -> *mut wire_list_wasm_val {
+        let (module_id, run, calls) = RECURSION.with(|r| {
+            let mut r = r.borrow_mut();
+            let r = r.as_mut().unwrap();
+            r.2 += 1;
+            (r.0.clone(), r.1.clone(), r.2)
+        });
+        if calls < 20 {
+            if let Err(err) = module_id.call_function_handle_sync(run, vec![]) {
+                RECURSION.with(|r| {
+                    r.borrow_mut().as_mut().unwrap().3.get_or_insert(err);
+                });
+                return std::ptr::null_mut();
+            }
+        }
+        crate::bridge_generated::new_list_wasm_val_0(0)
+    }
+
+    #[test]
+    fn host_call_depth_limit() -> Result<()> {
+        let wat = r#"(module
+            (import "host" "f" (func $f))
+            (func (export "run") call $f)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let f = module_id
+            .create_function(
+                recursive_host_function as WasmFunction as usize,
+                7,
+                vec![],
+                vec![],
+            )?
+            .0;
+        module_id.link_imports(vec![ModuleImport {
+            module: "host".to_string(),
+            name: "f".to_string(),
+            value: ExternalValue::Func(f),
+        }])?;
+        let instance = module_id.instantiate()?;
+        let run = instance
+            .exports()
+            .0
+            .into_iter()
+            .find_map(|e| match e.value {
+                ExternalValue::Func(func) if e.desc.name == "run" => Some(func),
+                _ => None,
+            })
+            .unwrap();
+        let call = |max_depth: Option<u32>| {
+            module_id.set_max_host_call_depth(max_depth).unwrap();
+            RECURSION.with(|r| *r.borrow_mut() = Some((module_id.clone(), run.clone(), 0, None)));
+            let output = module_id.call_function_handle_sync(run.clone(), vec![]);
+            let (_, _, calls, err) = RECURSION.with(|r| r.borrow_mut().take()).unwrap();
+            (output.map(|_| ()), calls, err)
+        };
+
+        let (output, calls, err) = call(Some(8));
+        assert!(output.is_err());
+        assert_eq!(calls, 8);
+        let err = err.unwrap();
+        let exceeded = err.downcast_ref::<ReentrancyLimitExceeded>().unwrap();
+        assert_eq!((exceeded.function_id, exceeded.limit), (7, 8));
+        assert!(format!("{err:?}").contains("ReentrancyLimitExceeded"));
+
+        // The depth is restored after the trap, all 20 calls fit in the limit.
+        let (output, calls, err) = call(Some(20));
+        assert!(output.is_ok() && err.is_none());
+        assert_eq!(calls, 20);
+
+        let (output, calls, _) = call(None);
+        assert!(output.is_ok());
+        assert_eq!(calls, 20);
+        module_id.dispose()
+    }
+
+    /// The module, its memory, its `grow` export and the bytes
+    /// read by [grow_host_function].
+    type Grow = (
+        WasmRunModuleId,
+        RustOpaque<Memory>,
+        RustOpaque<WFunc>,
+        Vec<u8>,
+    );
+
+    thread_local!(static GROW: RefCell<Option<Grow>> = const { RefCell::new(None) });
+
+    /// A host function that reads the memory in two parts,
+    /// calling the exported `grow` function in between.
+    unsafe extern "C-unwind" fn grow_host_function(
+        _function_id: u32,
+        _args: *mut DartAbi,
+    ) -> *mut wire_list_wasm_val {
+        let (module_id, memory, grow, _) = GROW.with(|g| g.borrow().clone()).unwrap();
+        let mut read = module_id.read_memory(memory.clone(), 65530, 3).unwrap().0;
+        let output = module_id.call_function_handle(grow, vec![]).unwrap();
+        assert!(matches!(output.as_slice(), [WasmVal::i32(1)]));
+        module_id
+            .write_memory(memory.clone(), 65536 * 16, b"grown".to_vec())
+            .unwrap();
+        read.extend(module_id.read_memory(memory.clone(), 65533, 3).unwrap().0);
+        read.extend(module_id.read_memory(memory, 65536 * 16, 5).unwrap().0);
+        GROW.with(|g| g.borrow_mut().as_mut().unwrap().3 = read);
+        crate::bridge_generated::new_list_wasm_val_0(0)
+    }
+
+    #[test]
+    fn memory_reads_across_grow_in_host_function() -> Result<()> {
+        let wat = r#"(module
+            (import "host" "f" (func $f))
+            (memory (export "memory") 1)
+            (data (i32.const 65530) "hello!")
+            (func (export "grow") (result i32) (memory.grow (i32.const 16)))
+            (func (export "run") call $f)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let f = module_id
+            .create_function(
+                grow_host_function as WasmFunction as usize,
+                0,
+                vec![],
+                vec![],
+            )?
+            .0;
+        module_id.link_imports(vec![ModuleImport {
+            module: "host".to_string(),
+            name: "f".to_string(),
+            value: ExternalValue::Func(f),
+        }])?;
+        let instance = module_id.instantiate()?;
+        let exports = instance.exports().0;
+        let memory = exports
+            .iter()
+            .find_map(|e| match &e.value {
+                ExternalValue::Memory(memory) => Some(memory.clone()),
+                _ => None,
+            })
+            .unwrap();
+        let export = |name: &str| {
+            exports
+                .iter()
+                .find_map(|e| match &e.value {
+                    ExternalValue::Func(func) if e.desc.name == name => Some(func.clone()),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        GROW.with(|g| {
+            *g.borrow_mut() = Some((module_id.clone(), memory.clone(), export("grow"), vec![]))
+        });
+        module_id.call_function_handle_sync(export("run"), vec![])?;
+        let read = GROW.with(|g| g.borrow_mut().take()).unwrap().3;
+
+        assert_eq!(read, b"hello!grown");
+        assert_eq!(module_id.get_memory_pages(memory.clone())?.0, 17);
+        assert_eq!(module_id.read_memory(memory, 65530, 6)?.0, b"hello!");
+        module_id.dispose()
+    }
+
+    #[test]
+    fn call_wasi_start_returns_exit_code() {
+        let proc_exit = r#"(module
+            (import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+            (memory (export "memory") 1)
+            (func (export "_start") (call $proc_exit (i32.const 3)))
+        )"#;
+        let returns = r#"(module
+            (memory (export "memory") 1)
+            (func (export "_start"))
+        )"#;
+        let traps = r#"(module
+            (memory (export "memory") 1)
+            (func (export "_start") unreachable)
+        )"#;
+
+        for capture_output in [false, true] {
+            assert_eq!(run_wasi_start(proc_exit, capture_output).unwrap(), 3);
+            assert_eq!(run_wasi_start(returns, capture_output).unwrap(), 0);
+            assert!(run_wasi_start(traps, capture_output).is_err());
+        }
+    }
+
+    #[test]
+    fn wasi_sched_yield_returns_to_the_module() {
+        // Exits with the errno of the last `sched_yield` after yielding 1000 times.
+        let sched_yield = r#"(module
+            (import "wasi_snapshot_preview1" "sched_yield" (func $yield (result i32)))
+            (import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+            (memory (export "memory") 1)
+            (func (export "_start") (local $i i32) (local $errno i32)
+                (loop $l
+                    (local.set $errno (call $yield))
+                    (local.tee $i (i32.add (local.get $i) (i32.const 1)))
+                    (br_if $l (i32.lt_u (i32.const 1000)))
+                )
+                (call $proc_exit (local.get $errno))
+            )
+        )"#;
+        assert_eq!(run_wasi_start(sched_yield, false).unwrap(), 0);
+    }
+
+    #[test]
+    fn wasi_preopen_temp() -> Result<()> {
+        // Returns the first byte of "data.txt" in the preopened directory,
+        // or the negated errno if it can not be read.
+        let wat = r#"(module
+            (import "wasi_snapshot_preview1" "path_open"
+                (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "fd_read"
+                (func $fd_read (param i32 i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "data.txt")
+            (func (export "read") (result i32) (local $errno i32)
+                (local.set $errno (call $path_open (i32.const 3) (i32.const 0)
+                    (i32.const 0) (i32.const 8) (i32.const 0)
+                    (i64.const 2) (i64.const 0) (i32.const 0) (i32.const 16)))
+                (if (local.get $errno)
+                    (then (return (i32.sub (i32.const 0) (local.get $errno)))))
+                (i32.store (i32.const 32) (i32.const 64))
+                (i32.store (i32.const 36) (i32.const 1))
+                (local.set $errno (call $fd_read (i32.load (i32.const 16))
+                    (i32.const 32) (i32.const 1) (i32.const 40)))
+                (if (local.get $errno)
+                    (then (return (i32.sub (i32.const 0) (local.get $errno)))))
+                (i32.load8_u (i32.const 64))
+            )
+        )"#;
+        let mut wasi_config = WasiConfigNative::default();
+        let temp = wasi_config.preopen_temp("/".to_string())?;
+        std::fs::write(temp.path().join("data.txt"), "wasm")?;
+        wasi_config.preopen_cwd("/cwd".to_string())?;
+        assert_eq!(
+            wasi_config.preopened_dirs[1].host_path,
+            std::env::current_dir()?.to_str().unwrap()
+        );
+
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, Some(wasi_config))?.0;
+        let instance = module_id.instantiate()?;
+        let read = instance
+            .exports()
+            .0
+            .into_iter()
+            .find_map(|e| match e.value {
+                ExternalValue::Func(func) if e.desc.name == "read" => Some(func),
+                _ => None,
+            })
+            .unwrap();
+        let result = module_id.call_function_handle(read, vec![])?;
+        assert!(matches!(result[..], [WasmVal::i32(x)] if x == b'w' as i32));
+        module_id.dispose()?;
+
+        let path = temp.path().to_path_buf();
+        drop(temp);
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn call_after_close() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (func (export "run") (result i32) (i32.const 1))
+        )"#;
+        let mut wasi_config = WasiConfigNative::default();
+        let _temp = wasi_config.preopen_temp("/".to_string())?;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, Some(wasi_config))?.0;
+        let instance = module_id.instantiate()?;
+        let mut exports = instance.exports().0.into_iter();
+        let ExternalValue::Memory(memory) = exports.next().unwrap().value else {
+            panic!("memory is not a memory");
+        };
+        let ExternalValue::Func(run) = exports.next().unwrap().value else {
+            panic!("run is not a function");
+        };
+        let result = module_id.call_function_handle(run.clone(), vec![])?;
+        assert!(matches!(result[..], [WasmVal::i32(1)]));
+
+        module_id.close()?;
+        let err = module_id.call_function_handle(run, vec![]).err().unwrap();
+        let err = err.downcast_ref::<ModuleClosed>().unwrap();
+        assert_eq!(err.module_id, module_id.0);
+        assert!(module_id.read_memory(memory.clone(), 0, 4).is_err());
+        let err = module_id.get_memory_pages(memory).err().unwrap();
+        assert!(err.downcast_ref::<ModuleClosed>().is_some());
+        assert!(module_id.last_call_metrics().is_err());
+        assert!(module_id.instantiate().is_err());
+        // Closing twice does nothing
+        module_id.close()?;
+        module_id.dispose()
+    }
+
+    #[test]
+    fn guest_allocation() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (global $next (mut i32) (i32.const 16))
+            (global $freed (export "freed") (mut i32) (i32.const 0))
+            (func (export "malloc") (param $size i32) (result i32)
+                (global.get $next)
+                (global.set $next (i32.add (global.get $next) (local.get $size)))
+            )
+            (func (export "dealloc") (param $ptr i32) (param $size i32)
+                (global.set $freed (i32.add (local.get $ptr) (local.get $size)))
+            )
+            (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+                (i32.const 1024)
+            )
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        assert!(module_id.guest_alloc(4, None).is_err());
+        let instance = module_id.instantiate()?;
+        let mut exports = instance.exports().0.into_iter();
+        let ExternalValue::Memory(memory) = exports.next().unwrap().value else {
+            panic!("memory is not a memory");
+        };
+        let ExternalValue::Global(freed) = exports.next().unwrap().value else {
+            panic!("freed is not a global");
+        };
+
+        let ptr = module_id.guest_alloc(5, None)?.0;
+        assert_eq!(ptr, 16);
+        module_id.write_memory(memory.clone(), ptr as usize, b"hello".to_vec())?;
+        assert_eq!(module_id.read_memory(memory, ptr as usize, 5)?.0, b"hello");
+        assert_eq!(module_id.guest_alloc(3, None)?.0, 21);
+        let realloc = Some("cabi_realloc".to_string());
+        assert_eq!(module_id.guest_alloc(3, realloc)?.0, 1024);
+
+        module_id.guest_free(ptr, 5, Some("dealloc".to_string()))?;
+        assert!(matches!(
+            module_id.get_global_value(freed)?.0,
+            WasmVal::i32(21)
+        ));
+        let err = module_id.guest_free(ptr, 5, None).err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("does not export a `free` function"));
+        module_id.dispose()
+    }
+
+    #[test]
+    fn guest_strings() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (global $next (mut i32) (i32.const 8))
+            (func (export "cabi_realloc") (param i32 i32 i32) (param $size i32) (result i32)
+                (global.get $next)
+                (global.set $next (i32.add (global.get $next) (local.get $size)))
+            )
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let instance = module_id.instantiate()?;
+        let ExternalValue::Memory(memory) = instance.exports().0.remove(0).value else {
+            panic!("memory is not a memory");
+        };
+
+        let value = "héllo, 世界 🌍";
+        let PointerAndLength { pointer, length } =
+            module_id.write_guest_string(value.to_string(), None)?.0;
+        assert_eq!((pointer, length), (8, value.len()));
+        let ptr = pointer as u32;
+        assert_eq!(
+            module_id.read_guest_string(ptr, length as u32, None)?.0,
+            value
+        );
+        assert_eq!(
+            module_id.write_guest_string("".to_string(), None)?.0.length,
+            0
+        );
+
+        // A multibyte character split in half
+        let err = module_id.read_guest_string(ptr, 2, None).err().unwrap();
+        assert!(err.to_string().contains("not valid UTF-8"), "{err}");
+        module_id.write_memory(memory, 0, vec![0xff])?;
+        assert!(module_id.read_guest_string(0, 1, None).is_err());
+        module_id.dispose()
+    }
+
+    #[test]
+    fn multiple_memories() -> Result<()> {
+        let wat = r#"(module
+            (memory $heap (export "heap") 1)
+            (memory (export "memory") 1)
+            (data (memory $heap) (i32.const 0) "heap")
+            (data (memory 1) (i32.const 0) "main")
+        )"#;
+        let config = ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_multi_memory: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, config)?;
+        let module_id = module_builder(module, None, None)?.0;
+        module_id.instantiate()?;
+        assert_eq!(module_id.memories()?.0, ["heap", "memory"]);
+
+        // The `memory` export is not picked when there is more than one
+        let err = module_id.read_guest_string(0, 4, None).err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("exports 2 memories (`heap`, `memory`)"),
+            "{err}"
+        );
+        let heap = Some("heap".to_string());
+        assert_eq!(module_id.read_guest_string(0, 4, heap)?.0, "heap");
+        let memory = Some("memory".to_string());
+        assert_eq!(module_id.read_guest_string(0, 4, memory)?.0, "main");
+        let err = module_id
+            .read_guest_string(0, 4, Some("stack".to_string()))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("does not export a `stack` memory"));
+        module_id.dispose()
+    }
+
+    #[test]
+    fn call_export_by_index() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+            )
+            (func (export "two") (result i32) (i32.const 2))
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let desc = module.get_module_exports().0;
+        assert_eq!(desc[1].name, "add");
+        assert_eq!(desc[1].index, 1);
+        let module_id = module_builder(module, None, None)?.0;
+        let instance = module_id.instantiate()?;
+        assert!(instance
+            .exports()
+            .0
+            .iter()
+            .enumerate()
+            .all(|(index, e)| e.desc.index as usize == index));
+
+        let args = vec![WasmVal::i32(3), WasmVal::i32(4)];
+        let output = module_id.call_by_index(1, args)?.0;
+        assert!(matches!(output.as_slice(), [WasmVal::i32(7)]));
+        let output = module_id.call_by_index(2, vec![])?.0;
+        assert!(matches!(output.as_slice(), [WasmVal::i32(2)]));
+
+        let err = module_id.call_by_index(0, vec![]).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "The export `memory` at index 0 is not a function."
+        );
+        let err = module_id.call_by_index(3, vec![]).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "The export index 3 is out of range for 3 exports."
+        );
+        module_id.dispose()
+    }
+
+    #[test]
+    fn wasi_missing_preopened_dir() -> Result<()> {
+        let mut wasi_config = WasiConfigNative::default();
+        let temp = wasi_config.preopen_temp("/".to_string())?;
+        let missing = temp.path().join("missing");
+        wasi_config.preopened_dirs[0].host_path = missing.to_str().unwrap().to_string();
+
+        let module = compile_wasm(
+            parse_wat_format("(module)".to_string())?,
+            Default::default(),
+        )?;
+        let err = module_builder(module, None, Some(wasi_config))
+            .err()
+            .unwrap();
+        let err = err.downcast_ref::<WasiError>().unwrap();
+        assert_eq!((err.errno, err.name), (44, "ENOENT"));
+        assert!(err.message.contains("missing"));
+        Ok(())
+    }
+
+    #[test]
+    fn wasi_disabled_at_runtime() -> Result<()> {
+        let wat = r#"(module
+            (import "wasi_snapshot_preview1" "sched_yield" (func $yield (result i32)))
+            (memory (export "memory") 1)
+            (func (export "yield") (result i32) (call $yield))
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+
+        let with_wasi = module.instantiate(Some(WasiConfigNative::default()))?.0;
+        let output = with_wasi.call_by_index(1, vec![])?.0;
+        assert!(matches!(output.as_slice(), [WasmVal::i32(0)]));
+        with_wasi.dispose()?;
+
+        let err = module.instantiate(None).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "The module imports `wasi_snapshot_preview1::sched_yield`, but WASI is disabled for this instance. Provide a `wasi_config` to link the WASI imports."
+        );
+        Ok(())
+    }
+
+    /// Writes "hello world" to stdout with [writes] calls to `fd_write`.
+    fn hello_world_wat(writes: usize) -> String {
+        let write =
+            "(drop (call $fd_write (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 8)))";
+        format!(
+            r#"(module
+                (import "wasi_snapshot_preview1" "fd_write"
+                    (func $fd_write (param i32 i32 i32 i32) (result i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 16) "hello world")
+                (func (export "_start")
+                    (i32.store (i32.const 0) (i32.const 16))
+                    (i32.store (i32.const 4) (i32.const 11))
+                    {}
+                )
+            )"#,
+            write.repeat(writes)
+        )
+    }
+
+    /// Instantiates [hello_world_wat] capturing its stdout with [capacity]
+    /// and [overflow], and returns the module and its `_start` function.
+    fn hello_world_capture(
+        writes: usize,
+        capacity: usize,
+        overflow: CaptureOverflow,
+    ) -> Result<(WasmRunModuleId, RustOpaque<WFunc>)> {
+        let module = compile_wasm(
+            parse_wat_format(hello_world_wat(writes))?,
+            Default::default(),
+        )?;
+        let wasi_config = WasiConfigNative {
+            capture_stdout: true,
+            capture_capacity: Some(capacity),
+            capture_overflow: overflow,
+            ..Default::default()
+        };
+        let module_id = module_builder(module, None, Some(wasi_config))?.0;
+        let instance = module_id.instantiate()?;
+        let start = instance
+            .exports()
+            .0
+            .into_iter()
+            .find_map(|e| match e.value {
+                ExternalValue::Func(func) if e.desc.name == "_start" => Some(func),
+                _ => None,
+            })
+            .unwrap();
+        Ok((module_id, start))
+    }
+
+    #[test]
+    fn wasi_capture_capacity() -> Result<()> {
+        let (module_id, start) = hello_world_capture(1, 5, CaptureOverflow::drop)?;
+        module_id.call_wasi_start(start)?;
+
+        assert_eq!(module_id.read_stdio(StdIOKind::stdout)?.0, b"hello");
+        assert_eq!(module_id.stdio_dropped_bytes(StdIOKind::stdout)?.0, 6);
+        assert!(module_id.read_stdio(StdIOKind::stderr).is_err());
+        // The module exited and all the captured output was read.
+        assert_eq!(module_id.next_stdio_chunk(StdIOKind::stdout, 0)?, None);
+        module_id.close_stdio(StdIOKind::stdout)?;
+        assert!(module_id.read_stdio(StdIOKind::stdout)?.0.is_empty());
+        module_id.dispose()
+    }
+
+    #[test]
+    fn wasi_capture_reads_while_blocked() -> Result<()> {
+        let (module_id, start) = hello_world_capture(2, 3, CaptureOverflow::block)?;
+        let running = module_id.clone();
+        let thread = std::thread::spawn(move || running.call_wasi_start(start).map(|c| c.0));
+
+        // The second write blocks on the full buffer until the first one is read,
+        // each one appends the 3 bytes that fit.
+        let mut output = vec![];
+        while let Some(chunk) = module_id.next_stdio_chunk(StdIOKind::stdout, 1000)? {
+            output.extend(chunk);
+        }
+        assert_eq!(thread.join().unwrap()?, 0);
+        assert_eq!(output, b"helhel");
+        assert_eq!(module_id.stdio_dropped_bytes(StdIOKind::stdout)?.0, 0);
+        module_id.dispose()
+    }
+
+    #[test]
+    fn wasi_capture_zero_capacity() -> Result<()> {
+        let (module_id, start) = hello_world_capture(1, 0, CaptureOverflow::drop)?;
+        module_id.call_wasi_start(start)?;
+        assert!(module_id.read_stdio(StdIOKind::stdout)?.0.is_empty());
+        assert_eq!(module_id.stdio_dropped_bytes(StdIOKind::stdout)?.0, 11);
+        module_id.dispose()?;
+
+        let err = hello_world_capture(1, 0, CaptureOverflow::block)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("CaptureOverflow::block"));
+        Ok(())
+    }
+
+    #[test]
+    fn wasi_deterministic() -> Result<()> {
+        // Writes 8 random bytes followed by the realtime clock to stdout.
+        let wat = r#"(module
+            (import "wasi_snapshot_preview1" "random_get"
+                (func $random_get (param i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "clock_time_get"
+                (func $clock_time_get (param i32 i64 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "fd_write"
+                (func $fd_write (param i32 i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "_start")
+                (drop (call $random_get (i32.const 16) (i32.const 8)))
+                (drop (call $clock_time_get (i32.const 0) (i64.const 1) (i32.const 24)))
+                (i32.store (i32.const 0) (i32.const 16))
+                (i32.store (i32.const 4) (i32.const 16))
+                (drop (call $fd_write (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 8)))
+            )
+        )"#;
+        let run = || -> Result<Vec<u8>> {
+            let module = compile_wasm(
+                parse_wat_format(wat.to_string())?,
+                ModuleConfig {
+                    deterministic: Some(true),
+                    ..Default::default()
+                },
+            )?;
+            let wasi_config = WasiConfigNative {
+                capture_stdout: true,
+                capture_capacity: Some(64),
+                deterministic: true,
+                ..Default::default()
+            };
+            let module_id = module_builder(module, None, Some(wasi_config))?.0;
+            let instance = module_id.instantiate()?;
+            let start = instance
+                .exports()
+                .0
+                .into_iter()
+                .find_map(|e| match e.value {
+                    ExternalValue::Func(func) if e.desc.name == "_start" => Some(func),
+                    _ => None,
+                })
+                .unwrap();
+            module_id.call_wasi_start(start)?;
+            let output = module_id.read_stdio(StdIOKind::stdout)?.0;
+            module_id.dispose()?;
+            Ok(output)
+        };
+
+        let output = run()?;
+        assert_eq!(output.len(), 16);
+        assert_eq!(output, run()?);
+        // The realtime clock is the Unix epoch.
+        assert_eq!(output[8..], [0; 8]);
+        Ok(())
+    }
+
+    #[test]
+    fn async_calls_trap_on_host_functions() -> Result<()> {
+        let wat = r#"(module
+            (import "host" "f" (func $f))
+            (func (export "pure") (result i32) i32.const 1)
+            (func (export "run") call $f)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let f = module_id
+            .create_function(host_function as WasmFunction as usize, 1, vec![], vec![])?
+            .0;
+        module_id.link_imports(vec![ModuleImport {
+            module: "host".to_string(),
+            name: "f".to_string(),
+            value: ExternalValue::Func(f),
+        }])?;
+        let instance = module_id.instantiate()?;
+        let export = |name: &str| {
+            instance
+                .exports()
+                .0
+                .into_iter()
+                .find_map(|e| match e.value {
+                    ExternalValue::Func(func) if e.desc.name == name => Some(func),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let output = module_id.call_function_handle(export("pure"), vec![])?;
+        assert!(matches!(output.as_slice(), [WasmVal::i32(1)]));
+
+        let err = match module_id.call_function_handle(export("run"), vec![]) {
+            Err(err) => err,
+            _ => panic!("host function called within an asynchronous call"),
+        };
+        assert!(
+            format!("{err:?}").contains("can not be called within an asynchronous call"),
+            "{err:?}"
+        );
+        assert!(!IN_ASYNC_CALL.with(|c| c.get()));
+        module_id.dispose()
+    }
+
+    #[test]
+    fn last_call_metrics() -> Result<()> {
+        let wat = r#"(module
+            (func (export "count") (param $n i32)
+                (loop $l
+                    (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $l)
+                )
+            )
+        )"#;
+        let config = ModuleConfig {
+            consume_fuel: Some(true),
+            ..Default::default()
+        };
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, config)?;
+        let module_id = module_builder(module, None, None)?.0;
+        module_id.add_fuel(1_000_000)?;
+        let instance = module_id.instantiate()?;
+        let count = instance.exports().0.into_iter().next().unwrap();
+        let ExternalValue::Func(count) = count.value else {
+            panic!("count is not a function");
+        };
+        assert_eq!(module_id.last_call_fuel_consumed()?.0, None);
+        assert_eq!(module_id.last_call_duration_micros()?.0, None);
+
+        for iterations in [1000, 10000] {
+            module_id.call_function_handle(count.clone(), vec![WasmVal::i32(iterations)])?;
+            let fuel = module_id.last_call_fuel_consumed()?.0.unwrap();
+            let iterations = iterations as u64;
+            assert!(
+                (3 * iterations..=6 * iterations).contains(&fuel),
+                "{fuel} fuel for {iterations} iterations"
+            );
+            assert!(module_id.last_call_duration_micros()?.0.is_some());
+        }
+        module_id.dispose()
+    }
+
+    #[test]
+    fn call_metrics_memory_growth() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (func (export "grow") (param i32) (result i32)
+                (memory.grow (local.get 0))
+            )
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        module_id.instantiate()?;
+        module_id.call_export("grow".to_string(), vec![WasmVal::i32(1)])?;
+        assert_eq!(module_id.last_call_metrics()?, None);
+
+        module_id.set_call_metrics(true)?;
+        module_id.call_export("grow".to_string(), vec![WasmVal::i32(3)])?;
+        let metrics = module_id.last_call_metrics()?.unwrap();
+        assert_eq!(metrics.memory_growth_pages, 3);
+        assert_eq!(metrics.host_calls, 0);
+        assert_eq!(metrics.fuel_consumed, None);
+        assert!(metrics.wall_time > std::time::Duration::ZERO);
+
+        module_id.call_export("grow".to_string(), vec![WasmVal::i32(0)])?;
+        let metrics = module_id.last_call_metrics()?.unwrap();
+        assert_eq!(metrics.memory_growth_pages, 0);
+        module_id.dispose()
+    }
+
+    #[test]
+    fn trap_on_integer_overflow() -> Result<()> {
+        let wat = r#"(module
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+            )
+        )"#;
+        for trap in [false, true] {
+            let config = ModuleConfig {
+                trap_on_integer_overflow: Some(trap),
+                ..Default::default()
+            };
+            let module = compile_wasm(parse_wat_format(wat.to_string())?, config)?;
+            let module_id = module_builder(module, None, None)?.0;
+            let instance = module_id.instantiate()?;
+            let add = instance.exports().0.into_iter().next().unwrap();
+            let ExternalValue::Func(add) = add.value else {
+                panic!("add is not a function");
+            };
+            let result = module_id
+                .call_function_handle(add.clone(), vec![WasmVal::i32(2), WasmVal::i32(-3)])?;
+            assert!(matches!(result[..], [WasmVal::i32(-1)]));
+
+            let result =
+                module_id.call_function_handle(add, vec![WasmVal::i32(i32::MAX), WasmVal::i32(1)]);
+            if trap {
+                assert!(result.is_err());
+            } else {
+                assert!(matches!(result?[..], [WasmVal::i32(i32::MIN)]));
+            }
+            module_id.dispose()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn instantiate_isolated_stores() -> Result<()> {
+        let wat = r#"(module
+            (global $count (mut i32) (i32.const 0))
+            (func (export "increment") (result i32)
+                (global.set $count (i32.add (global.get $count) (i32.const 1)))
+                (global.get $count)
+            )
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module = Arc::new(module);
+        let handles = (0..4)
+            .map(|_| {
+                let module = Arc::clone(&module);
+                std::thread::spawn(move || -> Result<Vec<WasmVal>> {
+                    let module_id = module.instantiate(None)?.0;
+                    let increment = WasmRunInstanceId(module_id.0).exports().0.remove(0);
+                    let ExternalValue::Func(increment) = increment.value else {
+                        panic!("increment is not a function");
+                    };
+                    module_id.call_function_handle(increment.clone(), vec![])?;
+                    let result = module_id.call_function_handle(increment, vec![])?;
+                    module_id.dispose()?;
+                    Ok(result)
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            // Each store has its own global, incremented twice.
+            let result = handle.join().unwrap()?;
+            assert!(matches!(result[..], [WasmVal::i32(2)]));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn link_module_exports() -> Result<()> {
+        let library = r#"(module
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+            )
+        )"#;
+        let main = r#"(module
+            (import "lib" "add" (func $add (param i32 i32) (result i32)))
+            (func (export "run") (result i32)
+                (call $add (i32.const 2) (i32.const 3))
+            )
+        )"#;
+        let engine = create_engine(Default::default())?.0;
+        let library = engine.compile_wasm(parse_wat_format(library.to_string())?)?;
+        let main = engine.compile_wasm(parse_wat_format(main.to_string())?)?;
+
+        let module_id = module_builder(main, None, None)?.0;
+        module_id.link_module("lib".to_string(), library)?;
+        let instance = module_id.instantiate()?;
+        let run = instance.exports().0.into_iter().next().unwrap();
+        let ExternalValue::Func(run) = run.value else {
+            panic!("run is not a function");
+        };
+        let result = module_id.call_function_handle(run, vec![])?;
+        assert!(matches!(result[..], [WasmVal::i32(5)]));
+        module_id.dispose()
+    }
+
+    #[test]
+    fn instantiate_with_shared_linker() -> Result<()> {
+        let yields = r#"(module
+            (import "wasi_snapshot_preview1" "sched_yield" (func $yield (result i32)))
+            (memory (export "memory") 1)
+            (func (export "run") (result i32) (call $yield))
+        )"#;
+        let constant = r#"(module
+            (import "wasi_snapshot_preview1" "sched_yield" (func $yield (result i32)))
+            (memory (export "memory") 1)
+            (func (export "run") (result i32) (i32.const 7))
+        )"#;
+        let engine = create_engine(Default::default())?.0;
+        let linker = engine.create_linker(true)?.0;
+
+        for (wat, expected) in [(yields, 0), (constant, 7)] {
+            let module = engine.compile_wasm(parse_wat_format(wat.to_string())?)?;
+            let same = CompiledModule(module.0.clone());
+            assert!(linker.module_builder(same, None).is_err());
+            let module_id = linker
+                .module_builder(module, Some(WasiConfigNative::default()))?
+                .0;
+            module_id.instantiate()?;
+            let output = module_id.call_by_index(1, vec![])?.0;
+            assert!(matches!(output.as_slice(), [WasmVal::i32(v)] if *v == expected));
+            module_id.dispose()?;
+        }
+
+        let other = compile_wasm(parse_wat_format(yields.to_string())?, Default::default())?;
+        let err = linker
+            .module_builder(other, Some(WasiConfigNative::default()))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "The module was not compiled with the engine of the linker."
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn instantiate_with_imported_memory() -> Result<()> {
+        let wat = r#"(module
+            (import "env" "memory" (memory 1 2))
+            (func (export "load") (result i32) (i32.load (i32.const 0)))
+            (func (export "pages") (result i32) (memory.size))
+        )"#;
+        let memory_type = MemoryTy {
+            shared: false,
+            minimum: 1,
+            maximum: Some(2),
+        };
+        let call = |module_id: &WasmRunModuleId, name: &str| -> Result<i32> {
+            let output = module_id.call_export(name.to_string(), vec![])?.0;
+            match output.as_slice() {
+                [WasmVal::i32(v)] => Ok(*v),
+                _ => unreachable!(),
+            }
+        };
+
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(CompiledModule(module.0.clone()), None, None)?.0;
+        let err = module_id.instantiate().err().unwrap();
+        assert!(
+            err.to_string()
+                .starts_with("The module imports the memory `env::memory`, but none is provided."),
+            "{err}"
+        );
+        let memory = module_id.create_memory(memory_type.clone())?.0;
+        module_id.write_memory(memory.clone(), 0, 42i32.to_le_bytes().to_vec())?;
+        module_id.link_imports(vec![ModuleImport {
+            module: "env".to_string(),
+            name: "memory".to_string(),
+            value: ExternalValue::Memory(memory),
+        }])?;
+        module_id.instantiate()?;
+        assert_eq!(call(&module_id, "load")?, 42);
+        module_id.dispose()?;
+
+        let engine = create_engine(Default::default())?.0;
+        let linker = engine.create_linker(false)?.0;
+        let shared = MemoryTy {
+            shared: true,
+            ..memory_type.clone()
+        };
+        assert!(linker
+            .define_memory("env".to_string(), "memory".to_string(), shared)
+            .is_err());
+        linker.define_memory("env".to_string(), "memory".to_string(), memory_type)?;
+        for _ in 0..2 {
+            let module = engine.compile_wasm(parse_wat_format(wat.to_string())?)?;
+            let module_id = linker.module_builder(module, None)?.0;
+            module_id.instantiate()?;
+            // Each module has its own memory
+            assert_eq!(call(&module_id, "load")?, 0);
+            assert_eq!(call(&module_id, "pages")?, 1);
+            module_id.dispose()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn module_cache_compiles_once() -> Result<()> {
+        let wat = r#"(module (func (export "f") (result i32) i32.const 1))"#;
+        let engine = create_engine(Default::default())?.0;
+        let cache = engine.create_module_cache(Some(1), None, None)?.0;
+
+        let first = cache.compile_wasm(parse_wat_format(wat.to_string())?)?;
+        // The text format has the same content hash as its binary
+        let second = cache.compile_wasm(wat.as_bytes().to_vec())?;
+        assert!(Arc::ptr_eq(&*first.0, &*second.0));
+        let stats = cache.stats().0;
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+
+        let other = r#"(module (func (export "f") (result i32) i32.const 2))"#;
+        let third = cache.compile_wasm(other.as_bytes().to_vec())?;
+        assert!(!Arc::ptr_eq(&*first.0, &*third.0));
+        let stats = cache.stats().0;
+        assert_eq!((stats.misses, stats.entries, stats.evictions), (2, 1, 1));
+
+        let dir = crate::config::TempDir::new()?;
+        let directory = Some(dir.path().to_string_lossy().to_string());
+        let cache = engine.create_module_cache(None, None, directory.clone())?.0;
+        cache.compile_wasm(wat.as_bytes().to_vec())?;
+        assert_eq!(fs::read_dir(dir.path())?.count(), 1);
+        // A new cache loads the artifact stored by the previous one
+        let cache = engine.create_module_cache(None, None, directory.clone())?.0;
+        let module = cache.compile_wasm(wat.as_bytes().to_vec())?;
+        assert_eq!(module.get_module_exports().0.len(), 1);
+
+        // A corrupted artifact is not loaded, the module is compiled again
+        let artifact = fs::read_dir(dir.path())?.next().unwrap()?.path();
+        let mut bytes = fs::read(&artifact)?;
+        *bytes.last_mut().unwrap() ^= 1;
+        fs::write(&artifact, &bytes)?;
+        let cache = engine.create_module_cache(None, None, directory)?.0;
+        let module = cache.compile_wasm(wat.as_bytes().to_vec())?;
+        assert_eq!(module.get_module_exports().0.len(), 1);
+        assert!(open_artifact(&fs::read(&artifact)?).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn epoch_deadline_interrupts_loop() -> Result<()> {
+        let wat = r#"(module
+            (func (export "spin") (loop br 0))
+            (func (export "one") (result i32) i32.const 1)
+        )"#;
+        let engine = create_engine(ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                epoch_interruption: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })?
+        .0;
+        let module = engine.compile_wasm(parse_wat_format(wat.to_string())?)?;
+        let module_id = module_builder(module, None, None)?.0;
+        module_id.instantiate()?;
+        // Without a deadline, the functions are not interrupted
+        engine.increment_epoch()?;
+        let output = module_id.call_export("one".to_string(), vec![])?.0;
+        assert!(matches!(output.as_slice(), [WasmVal::i32(1)]));
+
+        module_id.set_epoch_deadline(Some(1))?;
+        let ticker = {
+            let engine = engine.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                engine.increment_epoch().unwrap();
+            })
+        };
+        let err = module_id
+            .call_export("spin".to_string(), vec![])
+            .err()
+            .unwrap();
+        ticker.join().unwrap();
+        assert_eq!(err.downcast_ref::<Trap>(), Some(&Trap::Interrupt), "{err}");
+
+        module_id.set_epoch_deadline(None)?;
+        let output = module_id.call_export("one".to_string(), vec![])?.0;
+        assert!(matches!(output.as_slice(), [WasmVal::i32(1)]));
+        module_id.dispose()
+    }
+
+    #[test]
+    fn read_memory_during_async_call() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (data (i32.const 0) "busy")
+            (func (export "spin") (loop br 0))
+        )"#;
+        let engine = create_engine(ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                epoch_interruption: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })?
+        .0;
+        let module = engine.compile_wasm(parse_wat_format(wat.to_string())?)?;
+        let instantiate = || -> Result<(WasmRunModuleId, RustOpaque<Memory>, RustOpaque<WFunc>)> {
+            let module = CompiledModule(module.0.clone());
+            let module_id = module_builder(module, None, None)?.0;
+            let (mut memory, mut spin) = (None, None);
+            for export in module_id.instantiate()?.exports().0 {
+                match export.value {
+                    ExternalValue::Memory(m) => memory = Some(m),
+                    ExternalValue::Func(f) => spin = Some(f),
+                    _ => {}
+                }
+            }
+            Ok((module_id, memory.unwrap(), spin.unwrap()))
+        };
+        let (module_id, memory, spin) = instantiate()?;
+        let (other_id, other_memory, _) = instantiate()?;
+
+        module_id.set_epoch_deadline(Some(1))?;
+        let call = {
+            let module_id = module_id.clone();
+            std::thread::spawn(move || module_id.call_function_handle(spin, vec![]))
+        };
+        while module_id.1 .0.read().unwrap().is_empty() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        // The store of the call is not shared with other threads
+        let err = module_id.read_memory(memory.clone(), 0, 4).err().unwrap();
+        assert!(err.downcast_ref::<ModuleBusy>().is_some(), "{err}");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Module {} is busy in an asynchronous call on another thread.",
+                module_id.0
+            )
+        );
+        // Other modules are not blocked by the call
+        assert_eq!(other_id.read_memory(other_memory, 0, 4)?.0, b"busy");
+
+        engine.increment_epoch()?;
+        let err = call.join().unwrap().err().unwrap();
+        assert_eq!(err.downcast_ref::<Trap>(), Some(&Trap::Interrupt), "{err}");
+        assert_eq!(module_id.read_memory(memory, 0, 4)?.0, b"busy");
+        other_id.dispose()?;
+        module_id.dispose()
+    }
+
+    #[test]
+    fn engine_compiles_with_its_config() -> Result<()> {
+        let wat = r#"(module (func (export "f") (result i32 i32) i32.const 1 i32.const 2))"#;
+        let module_wasm = parse_wat_format(wat.to_string())?;
+        let engine = create_engine(ModuleConfig {
+            max_module_bytes: Some(10),
+            ..Default::default()
+        })?
+        .0;
+        let err = engine.compile_wasm(module_wasm.clone()).err().unwrap();
+        assert!(err.downcast_ref::<ModuleTooLarge>().is_some());
+        let cache = engine.create_module_cache(None, None, None)?.0;
+        let err = cache.compile_wasm(module_wasm.clone()).err().unwrap();
+        assert!(err.downcast_ref::<ModuleTooLarge>().is_some());
+
+        // The module is validated with the features of the engine
+        let engine = create_engine(ModuleConfig {
+            multi_value: Some(false),
+            ..Default::default()
+        })?
+        .0;
+        let err = engine.compile_wasm(module_wasm).err().unwrap();
+        let err = err.downcast_ref::<CompileError>().unwrap();
+        assert_eq!(err.kind, crate::compile_error::CompileErrorKind::Invalid);
+        assert!(err.offset.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn call_v128_function() -> Result<()> {
+        let wat = r#"(module
+            (func (export "add") (param v128 v128) (result v128)
+                (i32x4.add (local.get 0) (local.get 1))
+            )
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let instance = module_id.instantiate()?;
+        let add = instance.exports().0.into_iter().next().unwrap();
+        let ExternalValue::Func(add) = add.value else {
+            panic!("add is not a function");
+        };
+        let result = module_id.call_function_handle(
+            add,
+            vec![
+                WasmVal::v128_i32x4([1, 2, 3, i32::MAX]),
+                WasmVal::v128_i32x4([10, -20, 30, 1]),
+            ],
+        )?;
+        assert_eq!(result[0].as_i32x4()?, [11, -18, 33, i32::MIN]);
+        module_id.dispose()
+    }
+
+    #[test]
+    fn read_memory_into_reuses_buffer() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (data (i32.const 8) "hello world")
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let instance = module_id.instantiate()?;
+        let memory = instance.exports().0.into_iter().next().unwrap();
+        let ExternalValue::Memory(memory) = memory.value else {
+            panic!("memory is not a memory");
+        };
+
+        let mut buffer = Vec::with_capacity(16);
+        let pointer = buffer.as_ptr();
+        module_id.read_memory_into_vec(&memory, 8, 11, &mut buffer)?;
+        assert_eq!(buffer, b"hello world");
+        module_id.read_memory_into_vec(&memory, 14, 5, &mut buffer)?;
+        assert_eq!(buffer, b"world");
+        assert_eq!(buffer.as_ptr(), pointer);
+
+        let len =
+            module_id.with_memory(&memory, 8, 11, |data| data.iter().position(|b| *b == b' '))?;
+        assert_eq!(len, Some(5));
+        assert!(module_id
+            .read_memory_into_vec(&memory, 65530, 16, &mut buffer)
+            .is_err());
+
+        module_id.dispose()
+    }
+
+    #[test]
+    fn clamp_memory_caps_grow() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (func (export "grow") (param i32) (result i32)
+                (memory.grow (local.get 0))
+            )
+        )"#;
+        let wasm = parse_wat_format(wat.to_string())?;
+        let wasm = clamp_memory(wasm, 2)?.0;
+        let module = compile_wasm(wasm.clone(), Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let instance = module_id.instantiate()?;
+        let grow = instance
+            .exports()
+            .0
+            .into_iter()
+            .find(|e| e.desc.name == "grow")
+            .unwrap();
+        let ExternalValue::Func(grow) = grow.value else {
+            panic!("grow is not a function");
+        };
+        let result = module_id.call_function_handle(grow.clone(), vec![WasmVal::i32(1)])?;
+        assert!(matches!(result[..], [WasmVal::i32(1)]));
+        let result = module_id.call_function_handle(grow, vec![WasmVal::i32(1)])?;
+        assert!(matches!(result[..], [WasmVal::i32(-1)]));
+        module_id.dispose()?;
+
+        // The declared maximum is kept if it is lower.
+        let wasm = parse_wat_format("(module (memory 1 3))".to_string())?;
+        assert_eq!(clamp_memory(wasm.clone(), 5)?.0, clamp_memory(wasm, 3)?.0);
+
+        let wasm = parse_wat_format("(module (memory 3))".to_string())?;
+        assert!(clamp_memory(wasm, 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn start_function_trap() -> Result<()> {
+        let wat = r#"(module
+            (func $start unreachable)
+            (start $start)
+        )"#;
+        let wasm = parse_wat_format(wat.to_string())?;
+        assert!(module_has_start(wasm.clone())?.0);
+        let module = compile_wasm(wasm, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let err = module_id.instantiate().err().unwrap();
+        let err = err.downcast_ref::<StartTrap>().unwrap();
+        assert!(err.to_string().contains("unreachable"), "{err}");
+        module_id.dispose()?;
+
+        // Detected without the Wasm backtraces
+        let wasm = parse_wat_format(wat.to_string())?;
+        let config = ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_backtrace: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let module = compile_wasm(wasm, config)?;
+        let module_id = module_builder(module, None, None)?.0;
+        let err = module_id.instantiate().err().unwrap();
+        assert!(err.downcast_ref::<StartTrap>().is_some(), "{err}");
+        module_id.dispose()?;
+
+        // The segments are initialized before the start function
+        let wat = r#"(module
+            (memory 1)
+            (data (i32.const 65535) "ab")
+            (func $start unreachable)
+            (start $start)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let err = module_id.instantiate().err().unwrap();
+        assert!(err.downcast_ref::<StartTrap>().is_none(), "{err}");
+        assert_eq!(err.downcast_ref::<Trap>(), Some(&Trap::MemoryOutOfBounds));
+        module_id.dispose()?;
+
+        // An out of bounds access in the start function has Wasm frames
+        let wat = r#"(module
+            (memory 1)
+            (func $start (drop (i32.load (i32.const 65535))))
+            (start $start)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let err = module_id.instantiate().err().unwrap();
+        assert!(err.downcast_ref::<StartTrap>().is_some(), "{err}");
+        module_id.dispose()?;
+
+        // The errors of the host functions are not traps
+        let wat = r#"(module
+            (import "env" "fail" (func $fail))
+            (start $fail)
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let mut linker = Linker::new(module.0.lock().unwrap().engine());
+        linker.func_wrap("env", "fail", || -> Result<()> {
+            Err(anyhow::anyhow!("host error"))
+        })?;
+        let module_id = module_builder_with_linker(module, None, None, Some(linker), None)?.0;
+        let err = module_id.instantiate().err().unwrap();
+        assert!(err.downcast_ref::<StartTrap>().is_none(), "{err}");
+        assert_eq!(err.root_cause().to_string(), "host error");
+        module_id.dispose()?;
+
+        let wasm = parse_wat_format("(module (func (export \"init\")))".to_string())?;
+        assert!(!module_has_start(wasm.clone())?.0);
+        let module = compile_wasm(wasm, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        module_id.instantiate()?;
+        module_id.dispose()
+    }
+
+    #[test]
+    fn wasi_module_kinds() -> Result<()> {
+        let module = |exports: &str| {
+            format!(
+                r#"(module
+                    (global $g (mut i32) (i32.const 0))
+                    (func (export "get") (result i32) (global.get $g))
+                    {exports}
+                )"#
+            )
+        };
+        let command = module(r#"(func (export "_start") (global.set $g (i32.const 1)))"#);
+        let reactor = module(
+            r#"(func (export "_initialize") (global.set $g (i32.const 2)))
+            (func (export "_start") unreachable)"#,
+        );
+        let library = module("");
+
+        let instantiate = |wat: String, kind: WasiModuleKind| -> Result<WasmRunModuleId> {
+            let module = compile_wasm(parse_wat_format(wat)?, Default::default())?;
+            assert_eq!(module.wasi_module_kind().0, kind);
+            let module_id = module_builder(module, None, None)?.0;
+            module_id.instantiate()?;
+            Ok(module_id)
+        };
+        let get = |module_id: &WasmRunModuleId| -> Result<i32> {
+            match module_id
+                .call_export("get".to_string(), vec![])?
+                .0
+                .as_slice()
+            {
+                [WasmVal::i32(value)] => Ok(*value),
+                values => Err(anyhow::anyhow!("Unexpected outputs {values:?}")),
+            }
+        };
+
+        let module_id = instantiate(command, WasiModuleKind::command)?;
+        assert_eq!(module_id.initialize()?.0, Some(0));
+        assert!(get(&module_id).is_err());
+        module_id.dispose()?;
+
+        let module_id = instantiate(reactor, WasiModuleKind::reactor)?;
+        assert_eq!(module_id.initialize()?.0, None);
+        assert_eq!(get(&module_id)?, 2);
+        assert!(module_id.call_export("_start".to_string(), vec![]).is_err());
+        module_id.dispose()?;
+
+        let module_id = instantiate(library, WasiModuleKind::library)?;
+        assert_eq!(module_id.initialize()?.0, None);
+        assert_eq!(get(&module_id)?, 0);
+        assert!(module_id
+            .call_export("missing".to_string(), vec![])
+            .is_err());
+        module_id.dispose()
+    }
+
+    #[test]
+    fn memory_footprint_grows() -> Result<()> {
+        let wat = r#"(module
+            (memory (export "memory") 1)
+            (table (export "table") 2 funcref)
+            (global (export "g") (mut i32) (i32.const 0))
+            (func (export "grow") (param i32) (result i32)
+                (memory.grow (local.get 0))
+            )
+        )"#;
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        assert!(module_id.memory_footprint().is_err());
+        module_id.instantiate()?;
+
+        let before = module_id.memory_footprint()?.0;
+        assert_eq!(before.memories, 1);
+        assert_eq!(before.memory_pages, 1);
+        assert_eq!(before.memory_bytes, 65536);
+        assert_eq!((before.tables, before.table_elements), (1, 2));
+        assert_eq!(before.globals, 1);
+        assert_eq!(
+            before.total_bytes,
+            before.memory_bytes + before.overhead_bytes
+        );
+
+        module_id.call_export("grow".to_string(), vec![WasmVal::i32(2)])?;
+        let after = module_id.memory_footprint()?.0;
+        assert_eq!(after.memory_pages, 3);
+        assert_eq!(after.total_bytes - before.total_bytes, 2 * 65536);
+        // A memory growth that exceeds the maximum is not counted
+        assert!(matches!(
+            module_id
+                .call_export("grow".to_string(), vec![WasmVal::i32(70000)])?
+                .0[..],
+            [WasmVal::i32(-1)]
+        ));
+        assert_eq!(module_id.memory_footprint()?.0, after);
+
+        // The memories created by the host are not exported, but they are counted
+        let memory = module_id
+            .create_memory(MemoryTy {
+                minimum: 2,
+                maximum: None,
+                shared: false,
+            })?
+            .0;
+        module_id.grow_memory(memory, 1)?;
+        let host = module_id.memory_footprint()?.0;
+        assert_eq!(host.memories, 1);
+        assert_eq!(host.memory_pages, 6);
+        module_id.dispose()
+    }
+}
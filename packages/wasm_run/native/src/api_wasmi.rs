@@ -1,7 +1,14 @@
 pub use crate::atomics::*;
 use crate::bridge_generated::{wire_list_wasm_val, Wire2Api};
+use crate::capture::CaptureBuffer;
+use crate::compile_error::CompileError;
+use crate::compile_thread::CompilationSlot;
 use crate::config::*;
-pub use crate::external::WFunc;
+pub use crate::external::{WEngine, WFunc};
+use crate::line_buffer::LineBuffer;
+use crate::metrics::{CallMetrics, StoreCounters};
+use crate::module_cache::*;
+use crate::observer;
 use crate::types::*;
 use anyhow::{Ok, Result};
 use flutter_rust_bridge::{
@@ -9,12 +16,13 @@ use flutter_rust_bridge::{
 };
 use once_cell::sync::Lazy;
 use std::io::Write;
-pub use std::sync::RwLock;
+pub use std::sync::atomic::AtomicBool;
+pub use std::sync::{Mutex, RwLock};
 use std::{collections::HashMap, sync::Arc};
 #[cfg(feature = "wasi")]
 use wasi_common::pipe::WritePipe;
 use wasmi::core::Trap;
-pub use wasmi::{core::Pages, Func, Global, Memory, Module, Table};
+pub use wasmi::{core::Pages, Engine, Func, Global, Memory, Module, Table};
 use wasmi::{core::ValueType, *};
 
 static ARRAY: Lazy<RwLock<GlobalState>> = Lazy::new(|| RwLock::new(Default::default()));
@@ -22,12 +30,23 @@ static ARRAY: Lazy<RwLock<GlobalState>> = Lazy::new(|| RwLock::new(Default::defa
 static CALLER_STACK2: Lazy<RwLock<Vec<RwLock<&mut Store<StoreState>>>>> =
     Lazy::new(|| RwLock::new(Default::default()));
 
+thread_local!(static IN_ASYNC_CALL: std::cell::Cell<bool> = const { std::cell::Cell::new(false) });
+
 #[derive(Default)]
 struct GlobalState {
-    map: HashMap<u32, WasmiModuleImpl>,
+    /// Each module is locked separately, so that a long call to a module
+    /// does not block the others.
+    map: HashMap<u32, Arc<Mutex<WasmiModuleImpl>>>,
     last_id: u32,
 }
 
+/// The module with [module_id], the global state is only locked to get it.
+/// Returns a [ModuleClosed] error if the module was closed or disposed.
+fn get_module(module_id: u32) -> Result<Arc<Mutex<WasmiModuleImpl>>> {
+    let module = ARRAY.read().unwrap().map.get(&module_id).cloned();
+    Ok(module.ok_or(ModuleClosed { module_id })?)
+}
+
 struct WasmiModuleImpl {
     module: Arc<std::sync::Mutex<Module>>,
     linker: Linker<StoreState>,
@@ -35,15 +54,169 @@ struct WasmiModuleImpl {
     instance: Option<Instance>,
 }
 
-struct StoreState {
+pub(crate) struct StoreState {
     #[cfg(feature = "wasi")]
     wasi_ctx: Option<wasi_common::WasiCtx>,
     stdout: Option<StreamSink<Vec<u8>>>,
     stderr: Option<StreamSink<Vec<u8>>>,
     stack: CallStack,
+    pub(crate) last_call: Option<CallMetrics>,
+    /// The counters of the [CallMetrics], and the resource limiter of the store.
+    pub(crate) counters: StoreCounters,
+    /// The number of host functions executing in the store.
+    host_call_depth: u32,
+    max_host_call_depth: Option<u32>,
+    stdout_lines: Option<std::sync::Mutex<LineBuffer>>,
+    stderr_lines: Option<std::sync::Mutex<LineBuffer>>,
+    /// Shared with the [WasmRunModuleId] of the module.
+    capture: Arc<StdioCapture>,
     // TODO: add to stdin?
 }
 
+impl StoreState {
+    /// Sends the last lines of the line buffered stdout and stderr
+    /// when they were not terminated with a new line.
+    fn flush_output_lines(&self) {
+        for (sink, lines) in [
+            (&self.stdout, &self.stdout_lines),
+            (&self.stderr, &self.stderr_lines),
+        ] {
+            if let (Some(sink), Some(lines)) = (sink, lines) {
+                if let Some(line) = lines.lock().unwrap().finish() {
+                    sink.add(line);
+                }
+            }
+        }
+    }
+
+    /// Flushes the line buffered output and finishes the capture buffers,
+    /// the remaining captured output can still be read.
+    fn finish_output(&self) {
+        self.flush_output_lines();
+        for capture in [&self.capture.stdout, &self.capture.stderr]
+            .into_iter()
+            .flatten()
+        {
+            capture.finish();
+        }
+    }
+
+    /// Finishes the output and closes the output streams.
+    fn close_output(&self) {
+        self.finish_output();
+        for sink in [&self.stdout, &self.stderr].into_iter().flatten() {
+            sink.close();
+        }
+    }
+}
+
+/// The error returned when using a module after [WasmRunModuleId::close]
+/// or [WasmRunModuleId::dispose].
+#[derive(Debug)]
+pub struct ModuleClosed {
+    pub module_id: u32,
+}
+
+impl std::fmt::Display for ModuleClosed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Module {} is closed.", self.module_id)
+    }
+}
+
+impl std::error::Error for ModuleClosed {}
+
+/// The error returned when using a module while a call to it, for example,
+/// an asynchronous call, is executed by another thread.
+#[derive(Debug)]
+pub struct ModuleBusy {
+    pub module_id: u32,
+}
+
+impl std::fmt::Display for ModuleBusy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Module {} is busy in an asynchronous call on another thread.",
+            self.module_id
+        )
+    }
+}
+
+impl std::error::Error for ModuleBusy {}
+
+/// The error returned by [compile_wasm_cancelable] when its
+/// [CompileHandle] is cancelled before the module is compiled.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cancelled: the compilation was cancelled.")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// The error returned when a host function panics.
+///
+/// The panic is caught before it unwinds into the WASM runtime
+/// and the function call fails with a trap.
+#[derive(Debug)]
+pub struct HostPanic {
+    pub function_id: u32,
+    pub message: String,
+}
+
+impl HostPanic {
+    fn new(function_id: u32, payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else {
+            "unknown panic payload".to_string()
+        };
+        HostPanic {
+            function_id,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for HostPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HostPanic: host function with id {} panicked: {}",
+            self.function_id, self.message
+        )
+    }
+}
+
+impl std::error::Error for HostPanic {}
+
+/// The error of a host function call made while [limit] host functions
+/// are already executing in the store, for example, a host function
+/// that calls back into the guest which calls it again.
+/// Configured with [WasmRunModuleId::set_max_host_call_depth].
+#[derive(Debug)]
+pub struct ReentrancyLimitExceeded {
+    pub function_id: u32,
+    pub limit: u32,
+}
+
+impl std::fmt::Display for ReentrancyLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ReentrancyLimitExceeded: host function with id {} exceeded the maximum host call depth of {}",
+            self.function_id, self.limit
+        )
+    }
+}
+
+impl std::error::Error for ReentrancyLimitExceeded {}
+
 #[derive(Debug)]
 pub struct WasmRunSharedMemory(pub RustOpaque<Arc<RwLock<SharedMemory>>>);
 
@@ -51,10 +224,75 @@ pub struct WasmRunSharedMemory(pub RustOpaque<Arc<RwLock<SharedMemory>>>);
 pub struct SharedMemory;
 
 #[derive(Clone)]
-pub struct WasmRunModuleId(pub u32, pub RustOpaque<CallStack>);
+pub struct WasmRunModuleId(
+    pub u32,
+    pub RustOpaque<CallStack>,
+    pub RustOpaque<StdioCapture>,
+);
+
+/// The bounded buffers of the captured stdout and stderr of a module,
+/// when [WasiConfigNative::capture_capacity] is set. They are owned by the
+/// [WasmRunModuleId], outside of the store, so that they are read without
+/// accessing the store while the module is writing to them.
+#[derive(Default)]
+pub struct StdioCapture {
+    stdout: Option<Arc<CaptureBuffer>>,
+    stderr: Option<Arc<CaptureBuffer>>,
+}
+
+impl StdioCapture {
+    /// The buffer of the captured output of [kind].
+    fn get(&self, kind: StdIOKind) -> Result<&Arc<CaptureBuffer>> {
+        match kind {
+            StdIOKind::stdout => self.stdout.as_ref(),
+            StdIOKind::stderr => self.stderr.as_ref(),
+        }
+        .ok_or_else(|| anyhow::anyhow!("The {kind:?} output is not captured with a capacity"))
+    }
+}
 
 #[derive(Clone, Default)]
-pub struct CallStack(Arc<RwLock<Vec<RwLock<StoreContextMut<'static, StoreState>>>>>);
+/// The store contexts of the calls in progress, from the outermost one.
+/// Each one is locked while it is used, the stack itself is only locked
+/// to push, pop or get one, so that host functions can call the module again.
+pub struct CallStack(Arc<RwLock<Vec<CallerStore>>>);
+
+/// The store context of a call in [CallStack] and the thread executing it,
+/// which is the only one that can use it.
+#[derive(Clone)]
+struct CallerStore {
+    thread: std::thread::ThreadId,
+    context: CallerContext,
+}
+
+type CallerContext = Arc<RwLock<StoreContextMut<'static, StoreState>>>;
+
+impl CallStack {
+    fn push(&self, context: StoreContextMut<'static, StoreState>) {
+        self.0.write().unwrap().push(CallerStore {
+            thread: std::thread::current().id(),
+            context: Arc::new(RwLock::new(context)),
+        });
+    }
+
+    fn pop(&self) -> Option<CallerContext> {
+        self.0.write().unwrap().pop().map(|caller| caller.context)
+    }
+
+    /// The store context of the innermost call, when it is executed by the
+    /// current thread, for example, within a host function.
+    /// Returns a [ModuleBusy] error if it is executed by another thread,
+    /// for example, in an asynchronous call.
+    fn current(&self, module_id: u32) -> Result<Option<CallerContext>> {
+        match self.0.read().unwrap().last() {
+            None => Ok(None),
+            Some(caller) if caller.thread == std::thread::current().id() => {
+                Ok(Some(caller.context.clone()))
+            }
+            Some(_) => Err(ModuleBusy { module_id }.into()),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct WasmRunInstanceId(pub u32);
@@ -62,6 +300,7 @@ pub struct WasmRunInstanceId(pub u32);
 fn make_wasi_ctx(
     id: &WasmRunModuleId,
     wasi_config: &Option<WasiConfigNative>,
+    configure_wasi: Option<WasiCtxConfigure>,
 ) -> Result<Option<wasi_common::WasiCtx>> {
     let mut wasi_ctx = None;
     if let Some(wasi_config) = wasi_config {
@@ -81,12 +320,25 @@ fn make_wasi_ctx(
             };
             wasi.set_stderr(Box::new(WritePipe::new(stderr_handler)));
         }
+        if let Some(configure_wasi) = configure_wasi {
+            configure_wasi(&mut wasi)?;
+        }
         wasi_ctx = Some(wasi);
     }
 
     Ok(wasi_ctx)
 }
 
+/// Defines the WASI imports in [linker], they use the WASI context of the store.
+#[cfg(feature = "wasi")]
+fn add_wasi_to_linker(linker: &mut Linker<StoreState>) -> Result<()> {
+    wasmi_wasi::add_to_linker(linker, |ctx| ctx.wasi_ctx.as_mut().unwrap())?;
+    Ok(())
+}
+
+/// Creates a module builder for [module]. The WASI imports are linked
+/// only if [wasi_config] is provided, so the same build can instantiate
+/// modules without any access to the host.
 pub fn module_builder(
     module: CompiledModule,
     num_threads: Option<usize>,
@@ -102,27 +354,61 @@ pub fn module_builder(
             "Multi-threading is not supported for the wasmi runtime.",
         ));
     }
+    module_builder_with_linker(module, wasi_config, None, None)
+}
 
+/// Creates a module builder that instantiates [module] with a clone of
+/// [template], if provided, instead of a new linker.
+/// [configure_wasi] is applied to the WASI context created from [wasi_config].
+pub(crate) fn module_builder_with_linker(
+    module: CompiledModule,
+    wasi_config: Option<WasiConfigNative>,
+    template: Option<Linker<StoreState>>,
+    configure_wasi: Option<WasiCtxConfigure>,
+) -> Result<SyncReturn<WasmRunModuleId>> {
     let guard = module.0.lock().unwrap();
     let engine = guard.engine();
-    let mut linker = <Linker<StoreState>>::new(engine);
+    #[cfg(feature = "wasi")]
+    let has_template = template.is_some();
+    let mut linker = template.unwrap_or_else(|| <Linker<StoreState>>::new(engine));
 
     let mut arr = ARRAY.write().unwrap();
     arr.last_id += 1;
 
     let id = arr.last_id;
 
+    let capture = |captured: fn(&WasiConfigNative) -> bool| {
+        wasi_config
+            .as_ref()
+            .filter(|c| captured(c))
+            .and_then(|c| Some((c.capture_capacity?, c.capture_overflow)))
+            .map(|(capacity, overflow)| Arc::new(CaptureBuffer::new(capacity, overflow)))
+    };
+    let capture = Arc::new(StdioCapture {
+        stdout: capture(|c| c.capture_stdout),
+        stderr: capture(|c| c.capture_stderr),
+    });
     let stack: CallStack = Default::default();
-    let module_id = WasmRunModuleId(id, RustOpaque::new(stack.clone()));
+    let module_id = WasmRunModuleId(
+        id,
+        RustOpaque::new(stack.clone()),
+        Arc::clone(&capture).into(),
+    );
 
     #[cfg(feature = "wasi")]
-    let wasi_ctx = make_wasi_ctx(&module_id, &wasi_config)?;
+    let wasi_ctx = make_wasi_ctx(&module_id, &wasi_config, configure_wasi)?;
     #[cfg(feature = "wasi")]
-    if wasi_ctx.is_some() {
-        wasmi_wasi::add_to_linker(&mut linker, |ctx| ctx.wasi_ctx.as_mut().unwrap())?;
+    if wasi_ctx.is_some() && !has_template {
+        add_wasi_to_linker(&mut linker)?;
     }
 
-    let store = Store::new(
+    let line_buffer = || {
+        wasi_config
+            .as_ref()
+            .filter(|c| c.line_buffered_output)
+            .map(|_| std::sync::Mutex::new(LineBuffer::default()))
+    };
+    let mut store = Store::new(
         engine,
         StoreState {
             #[cfg(feature = "wasi")]
@@ -130,15 +416,23 @@ pub fn module_builder(
             stdout: None,
             stderr: None,
             stack,
+            last_call: None,
+            counters: Default::default(),
+            host_call_depth: 0,
+            max_host_call_depth: None,
+            stdout_lines: line_buffer(),
+            stderr_lines: line_buffer(),
+            capture,
         },
     );
+    store.limiter(|state| &mut state.counters);
     let module_builder = WasmiModuleImpl {
         module: Arc::clone(&module.0),
         linker,
         store,
         instance: None,
     };
-    arr.map.insert(id, module_builder);
+    arr.map.insert(id, Arc::new(Mutex::new(module_builder)));
 
     Ok(SyncReturn(module_id))
 }
@@ -150,22 +444,41 @@ struct ModuleIOWriter {
 
 impl Write for ModuleIOWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.id.with_module(|store| {
-            let data = store.data();
+        let kind = if self.is_stdout {
+            StdIOKind::stdout
+        } else {
+            StdIOKind::stderr
+        };
+        // The module may block on a full buffer, it is written
+        // outside of the module lock so that it can be read.
+        if let std::result::Result::Ok(capture) = self.id.2.get(kind) {
+            return std::io::Result::Ok(capture.write(buf));
+        }
+        self.id
+            .with_module(|store| {
+                let data = store.data();
 
-            let sink = if self.is_stdout {
-                data.stdout.as_ref()
-            } else {
-                data.stderr.as_ref()
-            };
-            let mut bytes_written = buf.len();
-            if let Some(stream) = sink {
-                if !stream.add(buf.to_owned()) {
-                    bytes_written = 0;
+                let (sink, lines) = if self.is_stdout {
+                    (data.stdout.as_ref(), data.stdout_lines.as_ref())
+                } else {
+                    (data.stderr.as_ref(), data.stderr_lines.as_ref())
+                };
+                let mut bytes_written = buf.len();
+                if let Some(stream) = sink {
+                    let sent = match lines {
+                        Some(lines) => {
+                            let lines = lines.lock().unwrap().push(buf);
+                            lines.into_iter().all(|line| stream.add(line))
+                        }
+                        None => stream.add(buf.to_owned()),
+                    };
+                    if !sent {
+                        bytes_written = 0;
+                    }
                 }
-            }
-            std::io::Result::Ok(bytes_written)
-        })
+                bytes_written
+            })
+            .map_err(std::io::Error::other)
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
@@ -175,39 +488,181 @@ impl Write for ModuleIOWriter {
 
 impl WasmRunInstanceId {
     pub fn exports(&self) -> SyncReturn<Vec<ModuleExportValue>> {
-        let value = &ARRAY.read().unwrap().map[&self.0];
+        let module = get_module(self.0).unwrap();
+        let value = module.lock().unwrap();
         SyncReturn(
             value
                 .instance
                 .unwrap()
                 .exports(&value.store)
-                .map(|e| ModuleExportValue::from_export(e, &value.store))
+                .enumerate()
+                .map(|(index, e)| ModuleExportValue::from_export(index, e, &value.store))
                 .collect(),
         )
     }
 }
 
+/// The `_initialize` or `_start` function [name] exported by [instance],
+/// which should not have parameters or results.
+fn init_export(instance: Instance, store: &Store<StoreState>, name: &str) -> Option<Func> {
+    instance.get_func(store, name).filter(|func| {
+        let ty = func.ty(store);
+        ty.params().is_empty() && ty.results().is_empty()
+    })
+}
+
+/// Returns an error if the module imports a WASI function that is not
+/// defined in the linker because the instance was built without WASI.
+fn check_wasi_disabled(module: &WasmiModuleImpl) -> Result<()> {
+    if module.store.data().wasi_ctx.is_some() {
+        return Ok(());
+    }
+    let wasm_module = module.module.lock().unwrap();
+    for import in wasm_module.imports() {
+        if import.module().starts_with("wasi_")
+            && module
+                .linker
+                .get(&module.store, import.module(), import.name())
+                .is_none()
+        {
+            return Err(anyhow::anyhow!(
+                "The module imports `{}::{}`, but WASI is disabled for this instance. Provide a `wasi_config` to link the WASI imports.",
+                import.module(),
+                import.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if the module imports a memory that is not defined
+/// in the linker, which should be created by the host before instantiating.
+fn check_memory_imports(module: &WasmiModuleImpl) -> Result<()> {
+    let wasm_module = module.module.lock().unwrap();
+    for import in wasm_module.imports() {
+        if matches!(import.ty(), ExternType::Memory(_))
+            && module
+                .linker
+                .get(&module.store, import.module(), import.name())
+                .is_none()
+        {
+            return Err(anyhow::anyhow!(
+                "The module imports the memory `{}::{}`, but none is provided. Create it with `create_memory` and link it with `link_imports`, or define it in the linker with `define_memory`.",
+                import.module(),
+                import.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The closure of a host function that calls the Dart function
+/// at [function_pointer] with [function_id].
+fn host_function(
+    function_pointer: usize,
+    function_id: u32,
+) -> impl Fn(Caller<'_, StoreState>, &[Value], &mut [Value]) -> std::result::Result<(), Trap>
+       + Send
+       + Sync
+       + 'static {
+    let f: WasmFunction = unsafe { std::mem::transmute(function_pointer) };
+    move |mut caller, params, results| {
+        if IN_ASYNC_CALL.with(|c| c.get()) {
+            return std::result::Result::Err(Trap::new(format!(
+                "Host function with id {function_id} can not be called within an asynchronous call"
+            )));
+        }
+        let mapped: Vec<WasmVal> = params
+            .iter()
+            .map(|a| WasmVal::from_value(a, &caller))
+            .collect();
+        let state = caller.data_mut();
+        if let Some(limit) = state.max_host_call_depth {
+            if state.host_call_depth >= limit {
+                return std::result::Result::Err(Trap::new(
+                    ReentrancyLimitExceeded { function_id, limit }.to_string(),
+                ));
+            }
+        }
+        state.counters.count_host_call();
+        state.host_call_depth += 1;
+        let inputs = vec![mapped].into_dart();
+        let stack = caller.data().stack.clone();
+        stack.push(unsafe {
+            std::mem::transmute::<
+                StoreContextMut<'_, StoreState>,
+                StoreContextMut<'static, StoreState>,
+            >(caller.as_context_mut())
+        });
+        let result = unsafe {
+            let pointer = new_leak_box_ptr(inputs);
+            let result =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(function_id, pointer)));
+            pointer.drop_in_place();
+            result
+        };
+        let last_caller = stack.pop();
+        if let Some(caller) = &last_caller {
+            caller.write().unwrap().data_mut().host_call_depth -= 1;
+        }
+        let result = result
+            .map_err(|payload| Trap::new(HostPanic::new(function_id, payload).to_string()))?;
+
+        // The host function returns a null pointer when it throws.
+        if result.is_null() {
+            return std::result::Result::Err(Trap::new(format!(
+                "Host function with id {function_id} threw an exception"
+            )));
+        }
+        let output: Vec<WasmVal> = result.wire2api();
+        if output.len() != results.len() {
+            return std::result::Result::Err(Trap::new("Invalid output length"));
+        } else if last_caller.is_none() {
+            return std::result::Result::Err(Trap::new("CALLER_STACK is empty"));
+        } else if output.is_empty() {
+            return std::result::Result::Ok(());
+        }
+        let last_caller = last_caller.unwrap();
+        let mut caller = last_caller.write().unwrap();
+        let mut outputs = output.into_iter();
+        for value in results {
+            *value = outputs
+                .next()
+                .unwrap()
+                .to_value(caller.as_context_mut())
+                .map_err(|e| Trap::new(e.to_string()))?;
+        }
+        std::result::Result::Ok(())
+    }
+}
+
 impl WasmRunModuleId {
     pub fn instantiate_sync(&self) -> Result<SyncReturn<WasmRunInstanceId>> {
         Ok(SyncReturn(self.instantiate()?))
     }
     pub fn instantiate(&self) -> Result<WasmRunInstanceId> {
-        let mut state = ARRAY.write().unwrap();
-        let module = state.map.get_mut(&self.0).unwrap();
+        let _span = observer::instantiate_span(self.0);
+        let module = get_module(self.0)?;
+        let mut module = module.lock().unwrap();
+        let module = &mut *module;
         if module.instance.is_some() {
             return Err(anyhow::anyhow!("Instance already exists"));
         }
+        check_wasi_disabled(module)?;
+        check_memory_imports(module)?;
         let instance = module
             .linker
             .instantiate(&mut module.store, &module.module.lock().unwrap())?
-            .start(&mut module.store)?;
+            .start(&mut module.store)
+            .map_err(|err| StartTrap { trap: err.into() })?;
 
         module.instance = Some(instance);
         Ok(WasmRunInstanceId(self.0))
     }
     pub fn link_imports(&self, imports: Vec<ModuleImport>) -> Result<SyncReturn<()>> {
-        let mut arr = ARRAY.write().unwrap();
-        let m = arr.map.get_mut(&self.0).unwrap();
+        let module = get_module(self.0)?;
+        let mut module = module.lock().unwrap();
+        let m = &mut *module;
         for import in imports {
             m.linker
                 .define(&import.module, &import.name, &import.value)?;
@@ -215,6 +670,34 @@ impl WasmRunModuleId {
         Ok(SyncReturn(()))
     }
 
+    /// Instantiates [module] in the store of this module and defines its exports
+    /// under the [namespace] module name, so that this module can import them.
+    /// For example, to call the functions exported by a library module.
+    ///
+    /// [module] is instantiated with the imports defined so far, such as WASI
+    /// and [link_imports], and it should be compiled with the same [WasmEngine].
+    pub fn link_module(&self, namespace: String, module: CompiledModule) -> Result<SyncReturn<()>> {
+        let this = get_module(self.0)?;
+        let mut this = this.lock().unwrap();
+        let m = &mut *this;
+        if m.instance.is_some() {
+            return Err(anyhow::anyhow!("Instance already exists"));
+        }
+        let instance = m
+            .linker
+            .instantiate(&mut m.store, &module.0.lock().unwrap())?
+            .start(&mut m.store)
+            .map_err(|err| StartTrap { trap: err.into() })?;
+        let exports = instance
+            .exports(&m.store)
+            .map(|e| (e.name().to_owned(), e.into_extern()))
+            .collect::<Vec<_>>();
+        for (name, value) in exports {
+            m.linker.define(&namespace, &name, value)?;
+        }
+        Ok(SyncReturn(()))
+    }
+
     pub fn stdio_stream(&self, sink: StreamSink<Vec<u8>>, kind: StdIOKind) -> Result<()> {
         if !cfg!(feature = "wasi") {
             return Err(anyhow::anyhow!(
@@ -237,12 +720,73 @@ impl WasmRunModuleId {
                 StdIOKind::stderr => store_state.stderr = Some(sink),
             };
             Ok(())
-        })
+        })?
+    }
+
+    pub fn wasi_denied_stream(&self, _sink: StreamSink<String>) -> Result<()> {
+        Err(anyhow::anyhow!(
+            "Reporting WASI denied paths is not supported for the wasmi runtime."
+        ))
+    }
+
+    /// Returns and removes the stdout or stderr output kept in the capture buffer,
+    /// see [WasiConfigNative::capture_capacity].
+    pub fn read_stdio(&self, kind: StdIOKind) -> Result<SyncReturn<Vec<u8>>> {
+        Ok(SyncReturn(self.2.get(kind)?.read()))
+    }
+
+    /// Waits up to [timeout_millis] for the next stdout or stderr output kept in
+    /// the capture buffer and removes it, see [WasiConfigNative::capture_capacity].
+    /// Returns an empty list when the timeout elapses without output and `None`
+    /// once all the output was returned after the module exited with
+    /// [WasmRunModuleId::call_wasi_start], or the module or the capture buffer was closed.
+    pub fn next_stdio_chunk(
+        &self,
+        kind: StdIOKind,
+        timeout_millis: u64,
+    ) -> Result<Option<Vec<u8>>> {
+        let capture = self.2.get(kind)?;
+        Ok(capture.next_chunk(std::time::Duration::from_millis(timeout_millis)))
+    }
+
+    /// The number of bytes of stdout or stderr dropped because
+    /// the capture buffer was full or closed.
+    pub fn stdio_dropped_bytes(&self, kind: StdIOKind) -> Result<SyncReturn<u64>> {
+        Ok(SyncReturn(self.2.get(kind)?.dropped_bytes()))
+    }
+
+    /// Stops capturing stdout or stderr in the capture buffer and frees it.
+    /// The following output of the module is dropped.
+    pub fn close_stdio(&self, kind: StdIOKind) -> Result<SyncReturn<()>> {
+        self.2.get(kind)?.close();
+        Ok(SyncReturn(()))
+    }
+
+    /// Releases the store of the module at a well-defined point, instead of
+    /// relying on the order of the finalizers: flushes the remaining line buffered
+    /// output, closes the output streams and drops the WASI context,
+    /// which closes the preopened directories.
+    /// Further calls with the module return a [ModuleClosed] error.
+    /// Closing a closed module does nothing.
+    pub fn close(&self) -> Result<SyncReturn<()>> {
+        if self.1.current(self.0)?.is_some() {
+            return Err(anyhow::anyhow!(
+                "Module {} can not be closed within a call to it.",
+                self.0
+            ));
+        }
+        let module = ARRAY.write().unwrap().map.remove(&self.0);
+        if let Some(module) = module {
+            module.lock().unwrap().store.data().close_output();
+        }
+        Ok(SyncReturn(()))
     }
 
     pub fn dispose(&self) -> Result<()> {
-        let mut arr = ARRAY.write().unwrap();
-        arr.map.remove(&self.0);
+        let module = ARRAY.write().unwrap().map.remove(&self.0);
+        if let Some(module) = module {
+            module.lock().unwrap().store.data().flush_output_lines();
+        }
         Ok(())
     }
 
@@ -251,30 +795,361 @@ impl WasmRunModuleId {
         func: RustOpaque<WFunc>,
         args: Vec<WasmVal>,
     ) -> Result<SyncReturn<Vec<WasmVal>>> {
-        self.call_function_handle(func, args).map(SyncReturn)
+        self.call_function(func, args, None).map(SyncReturn)
     }
+
+    /// Calls [func] in a thread outside of the Dart isolate,
+    /// so that long running calls do not block it.
+    /// Host functions are executed in the Dart isolate, calling them
+    /// within this function traps. Use [call_function_handle_sync] for
+    /// functions that call host functions.
     pub fn call_function_handle(
         &self,
         func: RustOpaque<WFunc>,
         args: Vec<WasmVal>,
     ) -> Result<Vec<WasmVal>> {
+        let in_async_call = IN_ASYNC_CALL.with(|c| c.replace(true));
+        let result = self.call_function(func, args, None);
+        IN_ASYNC_CALL.with(|c| c.set(in_async_call));
+        result
+    }
+
+    /// Calls the function at [index] in the exports of the instance,
+    /// see [ModuleExportDesc.index], for example, when the name of the export
+    /// is not known. Returns an error if [index] is out of range or the export
+    /// is not a function. The functions of a table can be called with
+    /// [get_table] and [call_function_handle_sync].
+    pub fn call_by_index(
+        &self,
+        index: u32,
+        args: Vec<WasmVal>,
+    ) -> Result<SyncReturn<Vec<WasmVal>>> {
+        let (name, func) = self.with_instance(|instance, store| {
+            let count = instance.exports(&*store).count();
+            let mut exports = instance
+                .exports(&*store)
+                .map(|e| (e.name().to_string(), e.into_func()));
+            let (name, value) = exports.nth(index as usize).ok_or_else(|| {
+                anyhow::anyhow!("The export index {index} is out of range for {count} exports.")
+            })?;
+            let func = value.ok_or_else(|| {
+                anyhow::anyhow!("The export `{name}` at index {index} is not a function.")
+            })?;
+            Ok((name, func))
+        })?;
+        self.call_function(RustOpaque::new(func.into()), args, Some(&name))
+            .map(SyncReturn)
+    }
+
+    fn call_function(
+        &self,
+        func: RustOpaque<WFunc>,
+        args: Vec<WasmVal>,
+        name: Option<&str>,
+    ) -> Result<Vec<WasmVal>> {
+        let _span = observer::call_span(self.0, name);
         let func = func.func_wasmi;
-        self.with_module_mut(|mut store| {
+        let counters_before = self.with_module(|store| store.data().counters)?;
+        // The fuel is only accessible from the [Store], which is not available
+        // in calls nested within host functions.
+        let is_nested = self.1.current(self.0)?.is_some();
+        let fuel_consumed = || {
+            if is_nested {
+                Ok(None)
+            } else {
+                self.with_module_mut2(|store| store.fuel_consumed())
+            }
+        };
+        let fuel_before = fuel_consumed()?;
+        let start = std::time::Instant::now();
+        let result = self.with_module_mut(|mut store| {
             let mut outputs: Vec<Value> = func
                 .ty(&store)
                 .results()
                 .iter()
                 .map(|t| Value::default(*t))
                 .collect();
-            let inputs: Vec<Value> = args.into_iter().map(|v| v.to_value(&mut store)).collect();
+            let inputs = args
+                .into_iter()
+                .map(|v| v.to_value(&mut store))
+                .collect::<Result<Vec<Value>>>()?;
             func.call(&mut store, inputs.as_slice(), &mut outputs)?;
             Ok(outputs
                 .into_iter()
                 .map(|a| WasmVal::from_value(&a, &store))
                 .collect())
+        })?;
+        let wall_time = start.elapsed();
+        let fuel_consumed = fuel_before
+            .zip(fuel_consumed()?)
+            .map(|(before, after)| after - before);
+        self.with_module_mut(|mut store| {
+            let state = store.data_mut();
+            let metrics = state
+                .counters
+                .call_metrics(counters_before, wall_time, fuel_consumed);
+            state.last_call = Some(metrics);
+        })?;
+        result
+    }
+
+    #[allow(unused_variables)]
+    /// Calls the WASI `_start` [func] and returns the exit code of the execution.
+    /// The exit code is the one passed to `proc_exit`, or 0 if `_start` returns
+    /// without calling it. This does not depend on the stdout and stderr configuration.
+    /// When it returns, the captured output is finished, see [WasmRunModuleId::next_stdio_chunk].
+    pub fn call_wasi_start(&self, func: RustOpaque<WFunc>) -> Result<SyncReturn<i32>> {
+        let result = self.call_function(func, vec![], Some("_start"));
+        self.with_module(|store| store.data().finish_output())?;
+        let err = match result {
+            Err(err) => err,
+            _ => return Ok(SyncReturn(0)),
+        };
+        let exit_code = match err.downcast_ref::<wasmi::Error>() {
+            Some(wasmi::Error::Trap(trap)) => trap.i32_exit_status(),
+            _ => None,
+        };
+        match exit_code {
+            Some(code) => Ok(SyncReturn(code)),
+            None => Err(err),
+        }
+    }
+
+    /// Initializes the instance according to its [WasiModuleKind].
+    /// Calls `_initialize` for reactors and `_start` for commands, returning
+    /// its exit code as in [call_wasi_start]. Libraries are not initialized.
+    /// Returns None for reactors and libraries.
+    pub fn initialize(&self) -> Result<SyncReturn<Option<i32>>> {
+        let (initialize, start) = self.with_instance(|instance, store| {
+            Ok((
+                init_export(instance, store, "_initialize"),
+                init_export(instance, store, "_start"),
+            ))
+        })?;
+        match (initialize, start) {
+            (Some(func), _) => {
+                let func = RustOpaque::new(func.into());
+                self.call_function(func, vec![], Some("_initialize"))?;
+                Ok(SyncReturn(None))
+            }
+            (None, Some(func)) => {
+                let exit_code = self.call_wasi_start(RustOpaque::new(func.into()))?.0;
+                Ok(SyncReturn(Some(exit_code)))
+            }
+            (None, None) => Ok(SyncReturn(None)),
+        }
+    }
+
+    /// Calls the function [name] exported by a reactor or a library,
+    /// after [initialize]. Returns an error for `_initialize` and `_start`,
+    /// which are called by [initialize], and for the exports of commands.
+    pub fn call_export(
+        &self,
+        name: String,
+        args: Vec<WasmVal>,
+    ) -> Result<SyncReturn<Vec<WasmVal>>> {
+        if name == "_initialize" || name == "_start" {
+            return Err(anyhow::anyhow!(
+                "The `{name}` export should be called with `initialize`."
+            ));
+        }
+        let func = self.with_instance(|instance, store| {
+            let kind = WasiModuleKind::from_exports(|n| init_export(instance, store, n).is_some());
+            if kind == WasiModuleKind::command {
+                return Err(anyhow::anyhow!(
+                    "The exports of module {} can not be called, it is a WASI command.",
+                    self.0
+                ));
+            }
+            instance
+                .get_func(&*store, &name)
+                .ok_or_else(|| anyhow::anyhow!("The module does not export a `{name}` function."))
+        })?;
+        self.call_function(RustOpaque::new(func.into()), args, Some(&name))
+            .map(SyncReturn)
+    }
+
+    /// Allocates [size] bytes in the memory of the module with its exported
+    /// allocator and returns the pointer, for example, to write a string
+    /// with [WasmRunModuleId::write_memory] before passing it to a function.
+    /// [alloc_export] is the name of the allocation function, by default `malloc`,
+    /// or `cabi_realloc` when the module does not export `malloc`.
+    /// Functions with one parameter are called with [size], like `malloc`,
+    /// and functions with four parameters are called like
+    /// `cabi_realloc(0, 0, 8, size)`, with an alignment of 8 bytes.
+    pub fn guest_alloc(&self, size: u32, alloc_export: Option<String>) -> Result<SyncReturn<u32>> {
+        let names = match &alloc_export {
+            Some(name) => vec![name.as_str()],
+            None => vec!["malloc", "cabi_realloc"],
+        };
+        self.alloc_guest(&names, size, 8).map(SyncReturn)
+    }
+
+    /// Allocates [size] bytes with the first allocation function in [names].
+    /// [align] is the alignment passed to `cabi_realloc` like functions.
+    fn alloc_guest(&self, names: &[&str], size: u32, align: u32) -> Result<u32> {
+        let output = self.call_guest_export(names, |num_params| match num_params {
+            1 => Some(vec![WasmVal::i32(size as i32)]),
+            4 => Some(vec![
+                WasmVal::i32(0),
+                WasmVal::i32(0),
+                WasmVal::i32(align as i32),
+                WasmVal::i32(size as i32),
+            ]),
+            _ => None,
+        })?;
+        match output.as_slice() {
+            [WasmVal::i32(0)] if size != 0 => Err(anyhow::anyhow!(
+                "The module could not allocate {size} bytes."
+            )),
+            [WasmVal::i32(ptr)] => Ok(*ptr as u32),
+            _ => Err(anyhow::anyhow!(
+                "The allocation function should return an i32 pointer."
+            )),
+        }
+    }
+
+    /// Frees the [size] bytes at [ptr] allocated with [WasmRunModuleId::guest_alloc].
+    /// [free_export] is the name of the function, by default `free`.
+    /// Functions with one parameter are called with [ptr], like `free`,
+    /// and functions with two parameters are called with [ptr] and [size].
+    pub fn guest_free(
+        &self,
+        ptr: u32,
+        size: u32,
+        free_export: Option<String>,
+    ) -> Result<SyncReturn<()>> {
+        let name = free_export.as_deref().unwrap_or("free");
+        self.call_guest_export(&[name], |num_params| match num_params {
+            1 => Some(vec![WasmVal::i32(ptr as i32)]),
+            2 => Some(vec![WasmVal::i32(ptr as i32), WasmVal::i32(size as i32)]),
+            _ => None,
+        })?;
+        Ok(SyncReturn(()))
+    }
+
+    /// Copies the UTF-8 bytes of [value] to the memory exported by the module,
+    /// allocated with the exported `cabi_realloc` function like the canonical ABI
+    /// does to pass strings, and returns their location.
+    /// [memory_name] is required when the module exports more than one memory,
+    /// see [WasmRunModuleId::memories].
+    pub fn write_guest_string(
+        &self,
+        value: String,
+        memory_name: Option<String>,
+    ) -> Result<SyncReturn<PointerAndLength>> {
+        let len = u32::try_from(value.len())?;
+        let ptr = self.alloc_guest(&["cabi_realloc"], len, 1)?;
+        let memory = self.exported_memory(memory_name.as_deref())?;
+        self.write_memory(memory, ptr as usize, value.into_bytes())?;
+        Ok(SyncReturn(PointerAndLength {
+            pointer: ptr as usize,
+            length: len as usize,
+        }))
+    }
+
+    /// Reads the string of [len] UTF-8 bytes at [ptr] in the memory exported
+    /// by the module. Returns an error if the bytes are not valid UTF-8.
+    /// [memory_name] is required when the module exports more than one memory,
+    /// see [WasmRunModuleId::memories].
+    pub fn read_guest_string(
+        &self,
+        ptr: u32,
+        len: u32,
+        memory_name: Option<String>,
+    ) -> Result<SyncReturn<String>> {
+        let memory = self.exported_memory(memory_name.as_deref())?;
+        let bytes = self.read_memory(memory, ptr as usize, len as usize)?.0;
+        let value = String::from_utf8(bytes).map_err(|e| {
+            anyhow::anyhow!(
+                "The guest string at {ptr} is not valid UTF-8: {}",
+                e.utf8_error()
+            )
+        })?;
+        Ok(SyncReturn(value))
+    }
+
+    /// Calls the first function in [names] exported by the module with the
+    /// arguments returned by [args] for its number of parameters.
+    fn call_guest_export(
+        &self,
+        names: &[&str],
+        args: impl FnOnce(usize) -> Option<Vec<WasmVal>>,
+    ) -> Result<Vec<WasmVal>> {
+        let (name, func) = self.with_instance(|instance, store| {
+            names
+                .iter()
+                .find_map(|name| {
+                    instance
+                        .get_export(&*store, name)
+                        .and_then(|e| e.into_func())
+                        .map(|func| (*name, func))
+                })
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The module does not export a `{}` function.",
+                        names.join("` or `")
+                    )
+                })
+        })?;
+        let num_params = self.with_module(|store| func.ty(store).params().len())?;
+        let args = args(num_params).ok_or_else(|| {
+            anyhow::anyhow!("The `{name}` function can not have {num_params} parameters.")
+        })?;
+        self.call_function(RustOpaque::new(func.into()), args, Some(name))
+    }
+
+    /// The memory exported as [name] by the module. Without a [name],
+    /// the only memory exported by the module, the memories are ambiguous
+    /// when it exports more than one.
+    fn exported_memory(&self, name: Option<&str>) -> Result<RustOpaque<Memory>> {
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => {
+                let names = self.memories()?.0;
+                match names.as_slice() {
+                    [name] => name.clone(),
+                    [] => return Err(anyhow::anyhow!("The module does not export a `memory`.")),
+                    _ => {
+                        return Err(anyhow::anyhow!(
+                            "The module exports {} memories (`{}`), \
+                            the name of the memory to use is required.",
+                            names.len(),
+                            names.join("`, `")
+                        ))
+                    }
+                }
+            }
+        };
+        self.with_instance(|instance, store| {
+            instance
+                .get_export(&*store, &name)
+                .and_then(|e| e.into_memory())
+                .map(RustOpaque::new)
+                .ok_or_else(|| anyhow::anyhow!("The module does not export a `{name}` memory."))
         })
     }
 
+    /// Executes [f] with the instance of the module, outside of a call to it.
+    fn with_instance<T>(
+        &self,
+        f: impl FnOnce(Instance, &mut Store<StoreState>) -> Result<T>,
+    ) -> Result<T> {
+        if self.1.current(self.0)?.is_some() {
+            return Err(anyhow::anyhow!(
+                "The exports of module {} can not be used within a call to it.",
+                self.0
+            ));
+        }
+        let module = get_module(self.0)?;
+        let mut module = module.lock().unwrap();
+        let value = &mut *module;
+        let instance = value
+            .instance
+            .ok_or_else(|| anyhow::anyhow!("Module {} is not instantiated.", self.0))?;
+        f(instance, &mut value.store)
+    }
+
     #[allow(unused_variables)]
     pub fn call_function_handle_parallel(
         &self,
@@ -299,60 +1174,72 @@ impl WasmRunModuleId {
         ))
     }
 
-    fn with_module_mut<T>(&self, f: impl FnOnce(StoreContextMut<'_, StoreState>) -> T) -> T {
-        {
-            let stack = self.1 .0.read().unwrap();
-            if let Some(caller) = stack.last() {
-                return f(caller.write().unwrap().as_context_mut());
-            }
+    /// Runs [f] with the store of the module, the one of the innermost call
+    /// when it is called from a host function.
+    /// Returns a [ModuleClosed] error if the module was closed or disposed.
+    pub(crate) fn with_module_mut<T>(
+        &self,
+        f: impl FnOnce(StoreContextMut<'_, StoreState>) -> T,
+    ) -> Result<T> {
+        if let Some(caller) = self.1.current(self.0)? {
+            return Ok(f(caller.write().unwrap().as_context_mut()));
         }
-        let mut arr = ARRAY.write().unwrap();
-        let value = arr.map.get_mut(&self.0).unwrap();
+        let module = get_module(self.0)?;
+        let mut value = module.lock().unwrap();
 
         let mut ctx = value.store.as_context_mut();
-        {
-            let v = RwLock::new(unsafe { std::mem::transmute(ctx.as_context_mut()) });
-            self.1 .0.write().unwrap().push(v);
-        }
+        self.1.push(unsafe {
+            std::mem::transmute::<
+                StoreContextMut<'_, StoreState>,
+                StoreContextMut<'static, StoreState>,
+            >(ctx.as_context_mut())
+        });
         let result = f(ctx);
-        self.1 .0.write().unwrap().pop();
-        result
+        self.1.pop();
+        Ok(result)
     }
 
-    fn with_module_mut2<T>(&self, f: impl FnOnce(&mut Store<StoreState>) -> T) -> T {
+    fn with_module_mut2<T>(&self, f: impl FnOnce(&mut Store<StoreState>) -> T) -> Result<T> {
         {
             let stack = CALLER_STACK2.read().unwrap();
             if let Some(caller) = stack.last() {
-                return f(&mut caller.write().unwrap());
+                return Ok(f(&mut caller.write().unwrap()));
             }
         }
-        let mut arr = ARRAY.write().unwrap();
-        let value = arr.map.get_mut(&self.0).unwrap();
+        let module = get_module(self.0)?;
+        let mut value = module.lock().unwrap();
 
         let ctx = &mut value.store;
         {
-            let v = RwLock::new(unsafe { std::mem::transmute(&mut *ctx) });
+            let v = RwLock::new(unsafe {
+                std::mem::transmute::<&mut Store<StoreState>, &'static mut Store<StoreState>>(
+                    &mut *ctx,
+                )
+            });
             CALLER_STACK2.write().unwrap().push(v);
         }
         let result = f(ctx);
         CALLER_STACK2.write().unwrap().pop();
-        result
+        Ok(result)
     }
 
-    fn with_module<T>(&self, f: impl FnOnce(&StoreContext<'_, StoreState>) -> T) -> T {
-        {
-            let stack = self.1 .0.read().unwrap();
-            if let Some(caller) = stack.last() {
-                return f(&caller.read().unwrap().as_context());
-            }
+    /// Runs [f] with the store of the module, see [Self::with_module_mut].
+    pub(crate) fn with_module<T>(
+        &self,
+        f: impl FnOnce(&StoreContext<'_, StoreState>) -> T,
+    ) -> Result<T> {
+        if let Some(caller) = self.1.current(self.0)? {
+            return Ok(f(&caller.read().unwrap().as_context()));
         }
-        let arr = ARRAY.read().unwrap();
-        let value = arr.map.get(&self.0).unwrap();
-        f(&value.store.as_context())
+        let module = get_module(self.0)?;
+        let value = module.lock().unwrap();
+        Ok(f(&value.store.as_context()))
     }
 
-    pub fn get_function_type(&self, func: RustOpaque<WFunc>) -> SyncReturn<FuncTy> {
-        SyncReturn(self.with_module(|store| (&func.func_wasmi.ty(store)).into()))
+    pub fn get_function_type(&self, func: RustOpaque<WFunc>) -> Result<SyncReturn<FuncTy>> {
+        Ok(SyncReturn(self.with_module(|store| {
+            (&func.func_wasmi.ty(store)).into()
+        })?))
     }
 
     pub fn create_function(
@@ -363,52 +1250,16 @@ impl WasmRunModuleId {
         result_types: Vec<ValueTy>,
     ) -> Result<SyncReturn<RustOpaque<WFunc>>> {
         self.with_module_mut(|store| {
-            let f: WasmFunction = unsafe { std::mem::transmute(function_pointer) };
             let func = Func::new(
                 store,
                 FuncType::new(
                     param_types.into_iter().map(ValueType::from),
                     result_types.into_iter().map(ValueType::from),
                 ),
-                move |mut caller, params, results| {
-                    let mapped: Vec<WasmVal> = params
-                        .iter()
-                        .map(|a| WasmVal::from_value(a, &caller))
-                        .collect();
-                    let inputs = vec![mapped].into_dart();
-                    let stack = {
-                        let stack = caller.data().stack.clone();
-                        let v =
-                            RwLock::new(unsafe { std::mem::transmute(caller.as_context_mut()) });
-                        stack.0.write().unwrap().push(v);
-                        stack
-                    };
-                    let output: Vec<WasmVal> = unsafe {
-                        let pointer = new_leak_box_ptr(inputs);
-                        let result = f(function_id, pointer);
-                        pointer.drop_in_place();
-                        result.wire2api()
-                    };
-                    let last_caller = stack.0.write().unwrap().pop();
-
-                    if output.len() != results.len() {
-                        return std::result::Result::Err(Trap::new("Invalid output length"));
-                    } else if last_caller.is_none() {
-                        return std::result::Result::Err(Trap::new("CALLER_STACK is empty"));
-                    } else if output.is_empty() {
-                        return std::result::Result::Ok(());
-                    }
-                    let last_caller = last_caller.unwrap();
-                    let mut caller = last_caller.write().unwrap();
-                    let mut outputs = output.into_iter();
-                    for value in results {
-                        *value = outputs.next().unwrap().to_value(caller.as_context_mut());
-                    }
-                    std::result::Result::Ok(())
-                },
+                host_function(function_pointer, function_id),
             );
             Ok(SyncReturn(RustOpaque::new(func.into())))
-        })
+        })?
     }
 
     pub fn create_memory(&self, memory_type: MemoryTy) -> Result<SyncReturn<RustOpaque<Memory>>> {
@@ -416,7 +1267,7 @@ impl WasmRunModuleId {
             let mem_type = memory_type.to_memory_type()?;
             let memory = Memory::new(store, mem_type).map_err(to_anyhow)?;
             Ok(SyncReturn(RustOpaque::new(memory)))
-        })
+        })?
     }
 
     pub fn create_global(
@@ -425,7 +1276,7 @@ impl WasmRunModuleId {
         mutable: bool,
     ) -> Result<SyncReturn<RustOpaque<Global>>> {
         self.with_module_mut(|mut store| {
-            let mapped = value.to_value(&mut store);
+            let mapped = value.to_value(&mut store)?;
             let global = Global::new(
                 &mut store,
                 mapped,
@@ -436,7 +1287,7 @@ impl WasmRunModuleId {
                 },
             );
             Ok(SyncReturn(RustOpaque::new(global)))
-        })
+        })?
     }
 
     pub fn create_table(
@@ -445,7 +1296,7 @@ impl WasmRunModuleId {
         table_type: TableArgs,
     ) -> Result<SyncReturn<RustOpaque<Table>>> {
         self.with_module_mut(|mut store| {
-            let mapped_value = value.to_value(&mut store);
+            let mapped_value = value.to_value(&mut store)?;
             let table = Table::new(
                 &mut store,
                 TableType::new(mapped_value.ty(), table_type.minimum, table_type.maximum),
@@ -453,17 +1304,21 @@ impl WasmRunModuleId {
             )
             .map_err(to_anyhow)?;
             Ok(SyncReturn(RustOpaque::new(table)))
-        })
+        })?
     }
 
     // GLOBAL
 
-    pub fn get_global_type(&self, global: RustOpaque<Global>) -> SyncReturn<GlobalTy> {
-        SyncReturn(self.with_module(|store| (&global.ty(store)).into()))
+    pub fn get_global_type(&self, global: RustOpaque<Global>) -> Result<SyncReturn<GlobalTy>> {
+        Ok(SyncReturn(
+            self.with_module(|store| (&global.ty(store)).into())?,
+        ))
     }
 
-    pub fn get_global_value(&self, global: RustOpaque<Global>) -> SyncReturn<WasmVal> {
-        SyncReturn(self.with_module(|store| WasmVal::from_value(&global.get(store), store)))
+    pub fn get_global_value(&self, global: RustOpaque<Global>) -> Result<SyncReturn<WasmVal>> {
+        Ok(SyncReturn(self.with_module(|store| {
+            WasmVal::from_value(&global.get(store), store)
+        })?))
     }
 
     pub fn set_global_value(
@@ -472,36 +1327,47 @@ impl WasmRunModuleId {
         value: WasmVal,
     ) -> Result<SyncReturn<()>> {
         self.with_module_mut(|mut store| {
-            let mapped = value.to_value(&mut store);
+            let mapped = value.to_value(&mut store)?;
             global
                 .set(&mut store, mapped)
                 .map(|_| SyncReturn(()))
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
     // MEMORY
 
-    pub fn get_memory_type(&self, memory: RustOpaque<Memory>) -> SyncReturn<MemoryTy> {
-        SyncReturn(self.with_module(|store| (&memory.ty(store)).into()))
+    pub fn get_memory_type(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<MemoryTy>> {
+        Ok(SyncReturn(
+            self.with_module(|store| (&memory.ty(store)).into())?,
+        ))
     }
-    pub fn get_memory_data(&self, memory: RustOpaque<Memory>) -> SyncReturn<Vec<u8>> {
-        SyncReturn(self.with_module(|store| memory.data(store).to_owned()))
+    pub fn get_memory_data(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<Vec<u8>>> {
+        Ok(SyncReturn(
+            self.with_module(|store| memory.data(store).to_owned())?,
+        ))
     }
-    pub fn get_memory_data_pointer(&self, memory: RustOpaque<Memory>) -> SyncReturn<usize> {
-        SyncReturn(self.with_module_mut(|store| memory.data_mut(store).as_mut_ptr() as usize))
+    /// The address of the data of [memory]. It may change when the memory grows,
+    /// including during a call into the guest, so it should not be kept
+    /// across calls. Use [read_memory] and [write_memory] to copy the data.
+    pub fn get_memory_data_pointer(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<usize>> {
+        Ok(SyncReturn(self.with_module_mut(|store| {
+            memory.data_mut(store).as_mut_ptr() as usize
+        })?))
     }
+    /// The address and length of the data of [memory],
+    /// see [get_memory_data_pointer].
     pub fn get_memory_data_pointer_and_length(
         &self,
         memory: RustOpaque<Memory>,
-    ) -> SyncReturn<PointerAndLength> {
-        SyncReturn(self.with_module(|store| {
+    ) -> Result<SyncReturn<PointerAndLength>> {
+        Ok(SyncReturn(self.with_module(|store| {
             let data = memory.data(store);
             PointerAndLength {
                 pointer: data.as_ptr() as usize,
                 length: data.len(),
             }
-        }))
+        })?))
     }
     pub fn read_memory(
         &self,
@@ -509,20 +1375,15 @@ impl WasmRunModuleId {
         offset: usize,
         bytes: usize,
     ) -> Result<SyncReturn<Vec<u8>>> {
-        self.with_module(|store| {
-            let mut buffer = Vec::with_capacity(bytes);
-            #[allow(clippy::uninit_vec)]
-            unsafe {
-                buffer.set_len(bytes)
-            };
-            memory
-                .read(store, offset, &mut buffer)
-                .map(|_| SyncReturn(buffer))
-                .map_err(to_anyhow)
-        })
+        let mut buffer = Vec::new();
+        self.read_memory_into_vec(&memory, offset, bytes, &mut buffer)?;
+        Ok(SyncReturn(buffer))
     }
-    pub fn get_memory_pages(&self, memory: RustOpaque<Memory>) -> SyncReturn<u32> {
-        SyncReturn(self.with_module(|store| memory.current_pages(store).into()))
+
+    pub fn get_memory_pages(&self, memory: RustOpaque<Memory>) -> Result<SyncReturn<u32>> {
+        Ok(SyncReturn(
+            self.with_module(|store| memory.current_pages(store).into())?,
+        ))
     }
 
     pub fn write_memory(
@@ -536,7 +1397,7 @@ impl WasmRunModuleId {
                 .write(store, offset, &buffer)
                 .map(SyncReturn)
                 .map_err(to_anyhow)
-        })
+        })?
     }
     pub fn grow_memory(&self, memory: RustOpaque<Memory>, pages: u32) -> Result<SyncReturn<u32>> {
         self.with_module_mut(|store| {
@@ -547,16 +1408,18 @@ impl WasmRunModuleId {
                 )
                 .map(|p| SyncReturn(p.into()))
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
     // TABLE
 
-    pub fn get_table_size(&self, table: RustOpaque<Table>) -> SyncReturn<u32> {
-        SyncReturn(self.with_module(|store| table.size(store)))
+    pub fn get_table_size(&self, table: RustOpaque<Table>) -> Result<SyncReturn<u32>> {
+        Ok(SyncReturn(self.with_module(|store| table.size(store))?))
     }
-    pub fn get_table_type(&self, table: RustOpaque<Table>) -> SyncReturn<TableTy> {
-        SyncReturn(self.with_module(|store| (&table.ty(store)).into()))
+    pub fn get_table_type(&self, table: RustOpaque<Table>) -> Result<SyncReturn<TableTy>> {
+        Ok(SyncReturn(
+            self.with_module(|store| (&table.ty(store)).into())?,
+        ))
     }
 
     pub fn grow_table(
@@ -566,20 +1429,24 @@ impl WasmRunModuleId {
         value: WasmVal,
     ) -> Result<SyncReturn<u32>> {
         self.with_module_mut(|mut store| {
-            let mapped = value.to_value(&mut store);
+            let mapped = value.to_value(&mut store)?;
             table
                 .grow(&mut store, delta, mapped)
                 .map(SyncReturn)
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
-    pub fn get_table(&self, table: RustOpaque<Table>, index: u32) -> SyncReturn<Option<WasmVal>> {
-        SyncReturn(self.with_module(|store| {
+    pub fn get_table(
+        &self,
+        table: RustOpaque<Table>,
+        index: u32,
+    ) -> Result<SyncReturn<Option<WasmVal>>> {
+        Ok(SyncReturn(self.with_module(|store| {
             table
                 .get(store, index)
                 .map(|v| WasmVal::from_value(&v, store))
-        }))
+        })?))
     }
 
     pub fn set_table(
@@ -589,12 +1456,12 @@ impl WasmRunModuleId {
         value: WasmVal,
     ) -> Result<SyncReturn<()>> {
         self.with_module_mut(|mut store| {
-            let mapped = value.to_value(&mut store);
+            let mapped = value.to_value(&mut store)?;
             table
                 .set(&mut store, index, mapped)
                 .map(SyncReturn)
                 .map_err(to_anyhow)
-        })
+        })?
     }
 
     pub fn fill_table(
@@ -605,25 +1472,110 @@ impl WasmRunModuleId {
         len: u32,
     ) -> Result<SyncReturn<()>> {
         self.with_module_mut(|mut store| {
-            let mapped = value.to_value(&mut store);
+            let mapped = value.to_value(&mut store)?;
             table
                 .fill(&mut store, index, mapped, len)
                 .map(|_| SyncReturn(()))
                 .map_err(to_anyhow)
-        })
+        })?
+    }
+
+    // HOST CALLS
+
+    /// Limits the number of nested host function calls in the store to [max_depth],
+    /// for example, when a host function calls back into the guest which calls
+    /// it again. The call that exceeds it traps with [ReentrancyLimitExceeded].
+    /// `max_wasm_stack` only limits the Wasm frames, not the host frames between them.
+    /// Defaults to `None`, which does not limit them.
+    pub fn set_max_host_call_depth(&self, max_depth: Option<u32>) -> Result<SyncReturn<()>> {
+        self.with_module_mut(|mut store| store.data_mut().max_host_call_depth = max_depth)?;
+        Ok(SyncReturn(()))
+    }
+
+    // EPOCH
+    //
+
+    /// Interrupts the functions of the module when the epoch of its engine
+    /// is incremented [ticks_beyond_current] times from now.
+    /// Returns an error since epoch interruption is not supported by wasmi,
+    /// use [ModuleConfig::consume_fuel] instead.
+    pub fn set_epoch_deadline(&self, _ticks_beyond_current: Option<u64>) -> Result<SyncReturn<()>> {
+        Err(anyhow::anyhow!(
+            "Epoch interruption is not supported by wasmi, use fuel consumption instead."
+        ))
     }
 
     // FUEL
     //
 
     pub fn add_fuel(&self, delta: u64) -> Result<SyncReturn<()>> {
-        self.with_module_mut2(|store| store.add_fuel(delta).map(SyncReturn).map_err(to_anyhow))
+        self.with_module_mut2(|store| store.add_fuel(delta).map(SyncReturn).map_err(to_anyhow))?
     }
-    pub fn fuel_consumed(&self) -> SyncReturn<Option<u64>> {
+    pub fn fuel_consumed(&self) -> Result<SyncReturn<Option<u64>>> {
         self.with_module_mut2(|store| SyncReturn(store.fuel_consumed()))
     }
     pub fn consume_fuel(&self, delta: u64) -> Result<SyncReturn<u64>> {
-        self.with_module_mut2(|store| store.consume_fuel(delta).map(SyncReturn).map_err(to_anyhow))
+        self.with_module_mut2(|store| store.consume_fuel(delta).map(SyncReturn).map_err(to_anyhow))?
+    }
+    /// The fuel consumed by the last call to [call_function_handle],
+    /// including the fuel consumed by nested calls made from host functions.
+    /// Returns None if `consume_fuel` is not enabled or no function was called.
+    pub fn last_call_fuel_consumed(&self) -> Result<SyncReturn<Option<u64>>> {
+        self.with_module(|store| SyncReturn(store.data().last_call.and_then(|c| c.fuel_consumed)))
+    }
+    /// The wall-clock duration in microseconds of the last call to [call_function_handle].
+    /// Returns None if no function was called.
+    pub fn last_call_duration_micros(&self) -> Result<SyncReturn<Option<u64>>> {
+        self.with_module(|store| {
+            SyncReturn(
+                store
+                    .data()
+                    .last_call
+                    .map(|c| c.wall_time.as_micros() as u64),
+            )
+        })
+    }
+
+    /// The memory used by the instance, the sum of the sizes of its memories
+    /// and an estimate of the overhead of its tables and globals.
+    /// The sizes are tracked by the resource limiter of the store, so they
+    /// include the memories and tables that are not exported.
+    /// The instances are allocated on demand, there is no pooling allocator
+    /// with slots to report.
+    pub fn memory_footprint(&self) -> Result<SyncReturn<MemoryFootprint>> {
+        self.with_instance(|instance, store| {
+            let exports = instance
+                .exports(&*store)
+                .map(Export::into_extern)
+                .collect::<Vec<_>>();
+            let mut footprint = store.data().counters.memory_footprint();
+            for export in exports {
+                match export {
+                    Extern::Memory(_) => footprint.add_memory(),
+                    Extern::Table(_) => footprint.add_table(),
+                    Extern::Global(_) => footprint.add_global(),
+                    Extern::Func(_) => {}
+                }
+            }
+            Ok(SyncReturn(footprint))
+        })
+    }
+
+    /// The names of the memories exported by the module, in export order.
+    /// The functions that access the memory of the module without receiving it,
+    /// like [WasmRunModuleId::read_guest_string], require the name of the memory
+    /// when there is more than one. WASI always uses the memory exported as `memory`.
+    pub fn memories(&self) -> Result<SyncReturn<Vec<String>>> {
+        self.with_instance(|instance, store| {
+            let names = instance
+                .exports(&*store)
+                .filter_map(|e| {
+                    let name = e.name().to_string();
+                    matches!(e.into_extern(), Extern::Memory(_)).then_some(name)
+                })
+                .collect();
+            Ok(SyncReturn(names))
+        })
     }
 }
 
@@ -631,12 +1583,64 @@ pub fn parse_wat_format(wat: String) -> Result<Vec<u8>> {
     Ok(wat::parse_str(wat)?)
 }
 
-type WasmFunction =
-    unsafe extern "C" fn(function_id: u32, args: *mut DartAbi) -> *mut wire_list_wasm_val;
+/// Rewrites the [module_wasm] binary setting the maximum of the memories defined
+/// by the module to [max_pages], or keeping the declared maximum if it is lower.
+/// Returns an error if the minimum of a memory exceeds [max_pages].
+pub fn clamp_memory(module_wasm: Vec<u8>, max_pages: u64) -> Result<SyncReturn<Vec<u8>>> {
+    crate::transform::clamp_memory(&module_wasm, max_pages).map(SyncReturn)
+}
+
+/// Returns `true` if the [module_wasm] binary defines a start function,
+/// which is executed when instantiating the module.
+pub fn module_has_start(module_wasm: Vec<u8>) -> Result<SyncReturn<bool>> {
+    for payload in wasmparser::Parser::new(0).parse_all(&module_wasm) {
+        if let wasmparser::Payload::StartSection { .. } = payload? {
+            return Ok(SyncReturn(true));
+        }
+    }
+    Ok(SyncReturn(false))
+}
+
+/// Returns the SHA-256 of the [module_wasm] binary, or of its binary format
+/// if it is in the text format, to be used as a cache or deduplication key.
+/// The hash is stable across runs and independent of the whitespace and comments
+/// of text format inputs.
+pub fn module_content_hash(module_wasm: Vec<u8>) -> Result<SyncReturn<Vec<u8>>> {
+    let hash = crate::hash::content_hash(&module_wasm)?;
+    Ok(SyncReturn(hash.to_vec()))
+}
 
+type WasmFunction =
+    unsafe extern "C-unwind" fn(function_id: u32, args: *mut DartAbi) -> *mut wire_list_wasm_val;
+
+/// A compiled module that is shared by all its instances.
+///
+/// Compiling, with [compile_wasm] or [WasmEngine::compile_wasm], is done once.
+/// Each instantiation, with [CompiledModule::instantiate] or [module_builder],
+/// creates an isolated store with its own memories, tables, globals and WASI context,
+/// which is cheap compared to compiling. For example, to create an instance per request.
+///
+/// The compiled code is immutable and the module can be shared and instantiated
+/// from many threads concurrently. The instances are not shared between stores.
 pub struct CompiledModule(pub RustOpaque<Arc<std::sync::Mutex<Module>>>);
 
 impl CompiledModule {
+    /// Creates a new isolated store for this module and instantiates it
+    /// with the WASI imports of [wasi_config], if any.
+    /// Use [module_builder] to define other imports before instantiating.
+    /// The returned store should be disposed with [WasmRunModuleId::dispose].
+    pub fn instantiate(
+        &self,
+        wasi_config: Option<WasiConfigNative>,
+    ) -> Result<SyncReturn<WasmRunModuleId>> {
+        let module_id = module_builder(CompiledModule(self.0.clone()), None, wasi_config)?.0;
+        if let Err(err) = module_id.instantiate() {
+            module_id.dispose()?;
+            return Err(err);
+        }
+        Ok(SyncReturn(module_id))
+    }
+
     #[allow(unused)]
     pub fn create_shared_memory(
         &self,
@@ -658,13 +1662,25 @@ impl CompiledModule {
         )
     }
 
+    /// The [WasiModuleKind] of the module, from its exports.
+    pub fn wasi_module_kind(&self) -> SyncReturn<WasiModuleKind> {
+        let module = self.0.lock().unwrap();
+        SyncReturn(WasiModuleKind::from_exports(|name| {
+            matches!(
+                module.get_export(name),
+                Some(ExternType::Func(ty)) if ty.params().is_empty() && ty.results().is_empty()
+            )
+        }))
+    }
+
     pub fn get_module_exports(&self) -> SyncReturn<Vec<ModuleExportDesc>> {
         SyncReturn(
             self.0
                 .lock()
                 .unwrap()
                 .exports()
-                .map(|i| (&i).into())
+                .enumerate()
+                .map(|(index, e)| ModuleExportDesc::new(index, &e))
                 .collect(),
         )
     }
@@ -676,10 +1692,274 @@ impl From<Module> for CompiledModule {
     }
 }
 
+/// A compilation environment that can be shared across many modules.
+/// All the modules compiled with the same engine, and the stores
+/// instantiated from them, share the [ModuleConfig] used to create it.
+#[derive(Clone)]
+pub struct WasmEngine(pub RustOpaque<WEngine>);
+
+impl WasmEngine {
+    /// Compiles [module_wasm] with the [ModuleConfig] of the engine, like [compile_wasm].
+    pub fn compile_wasm(&self, module_wasm: Vec<u8>) -> Result<CompiledModule> {
+        Ok(compile_with_engine(&self.0, module_wasm)?.into())
+    }
+
+    pub fn compile_wasm_sync(&self, module_wasm: Vec<u8>) -> Result<SyncReturn<CompiledModule>> {
+        self.compile_wasm(module_wasm).map(SyncReturn)
+    }
+
+    /// Increments the epoch of the engine.
+    /// Returns an error since epoch interruption is not supported by wasmi,
+    /// use [ModuleConfig::consume_fuel] instead.
+    pub fn increment_epoch(&self) -> Result<SyncReturn<()>> {
+        Err(anyhow::anyhow!(
+            "Epoch interruption is not supported by wasmi, use fuel consumption instead."
+        ))
+    }
+
+    /// Creates a [WasmLinker] for the modules compiled with this engine.
+    /// The WASI imports are defined once in the linker if [wasi] is `true`.
+    pub fn create_linker(&self, wasi: bool) -> Result<SyncReturn<WasmLinker>> {
+        if wasi && !cfg!(feature = "wasi") {
+            return Err(anyhow::Error::msg(
+                "WASI feature is not enabled. Please enable it by adding `--features wasi` when building.",
+            ));
+        }
+        let engine = self.0.engine_wasmi.clone();
+        let mut linker = <Linker<StoreState>>::new(&engine);
+        #[cfg(feature = "wasi")]
+        if wasi {
+            add_wasi_to_linker(&mut linker)?;
+        }
+        Ok(SyncReturn(WasmLinker(RustOpaque::new(Arc::new(
+            std::sync::Mutex::new(LinkerTemplate {
+                engine,
+                linker,
+                wasi,
+                memories: vec![],
+            }),
+        )))))
+    }
+
+    /// Creates a [ModuleCache] for the modules compiled with this engine.
+    /// The least recently used modules are evicted when there are more than
+    /// [max_entries] modules or their binaries exceed [max_bytes] in total.
+    /// Returns an error if [directory] is provided, since wasmi
+    /// can not serialize compiled modules.
+    pub fn create_module_cache(
+        &self,
+        max_entries: Option<u32>,
+        max_bytes: Option<u64>,
+        directory: Option<String>,
+    ) -> Result<SyncReturn<ModuleCache>> {
+        if directory.is_some() {
+            return Err(anyhow::anyhow!(
+                "Storing compiled modules in a directory is not supported by wasmi."
+            ));
+        }
+        let engine = (*self.0).clone();
+        let store = ModuleStore::new(ModuleCacheLimits {
+            max_entries,
+            max_bytes,
+        });
+        Ok(SyncReturn(ModuleCache(RustOpaque::new(Arc::new(
+            std::sync::Mutex::new(ModuleCacheImpl { engine, store }),
+        )))))
+    }
+}
+
+/// The definitions of a [WasmLinker].
+pub struct LinkerTemplate {
+    engine: Engine,
+    linker: Linker<StoreState>,
+    wasi: bool,
+    /// The memories created for each module, see [WasmLinker::define_memory].
+    memories: Vec<(String, String, MemoryTy)>,
+}
+
+/// A linker with the WASI imports and host functions defined once
+/// and reused by all the modules instantiated with it,
+/// instead of defining them again for each module.
+/// Created with [WasmEngine::create_linker], it can only be used
+/// with the modules compiled by the same engine.
+///
+/// The host functions are shared by all the stores, so they are not
+/// bound to a module and their closures must be `Send + Sync`.
+#[derive(Clone)]
+pub struct WasmLinker(pub RustOpaque<Arc<std::sync::Mutex<LinkerTemplate>>>);
+
+impl WasmLinker {
+    /// Defines the host function [module]::[name] for all the modules
+    /// instantiated with this linker.
+    pub fn define_host_function(
+        &self,
+        module: String,
+        name: String,
+        function_pointer: usize,
+        function_id: u32,
+        param_types: Vec<ValueTy>,
+        result_types: Vec<ValueTy>,
+    ) -> Result<SyncReturn<()>> {
+        let ty = FuncType::new(
+            param_types.into_iter().map(ValueType::from),
+            result_types.into_iter().map(ValueType::from),
+        );
+        self.0
+            .lock()
+            .unwrap()
+            .linker
+            .func_new(
+                &module,
+                &name,
+                ty,
+                host_function(function_pointer, function_id),
+            )
+            .map_err(to_anyhow)?;
+        Ok(SyncReturn(()))
+    }
+
+    /// Defines the memory [module]::[name] with [memory_type] for all the modules
+    /// instantiated with this linker. A new memory is created for each module,
+    /// for example, for modules that import their memory as `env::memory`.
+    /// Shared memories should be created with [WasmRunModuleId::create_shared_memory]
+    /// and linked with [WasmRunModuleId::link_imports].
+    pub fn define_memory(
+        &self,
+        module: String,
+        name: String,
+        memory_type: MemoryTy,
+    ) -> Result<SyncReturn<()>> {
+        if memory_type.shared {
+            return Err(anyhow::anyhow!(
+                "The shared memory `{module}::{name}` can not be defined in the linker, link it with `link_imports`."
+            ));
+        }
+        memory_type.to_memory_type()?;
+        self.0
+            .lock()
+            .unwrap()
+            .memories
+            .push((module, name, memory_type));
+        Ok(SyncReturn(()))
+    }
+
+    /// Creates a module builder for [module] using a clone of this linker.
+    /// [wasi_config] must be provided if, and only if,
+    /// the linker was created with WASI.
+    pub fn module_builder(
+        &self,
+        module: CompiledModule,
+        wasi_config: Option<WasiConfigNative>,
+    ) -> Result<SyncReturn<WasmRunModuleId>> {
+        let (linker, memories) = {
+            let template = self.0.lock().unwrap();
+            if !Engine::same(&template.engine, module.0.lock().unwrap().engine()) {
+                return Err(anyhow::anyhow!(
+                    "The module was not compiled with the engine of the linker."
+                ));
+            }
+            if template.wasi != wasi_config.is_some() {
+                return Err(anyhow::anyhow!(
+                    "The linker was created with `wasi: {}`, a `wasi_config` must be provided if, and only if, WASI is enabled.",
+                    template.wasi
+                ));
+            }
+            (template.linker.clone(), template.memories.clone())
+        };
+        let module_id = module_builder_with_linker(module, wasi_config, Some(linker), None)?.0;
+        for (namespace, name, memory_type) in memories {
+            let memory = module_id.create_memory(memory_type)?.0;
+            module_id.link_imports(vec![ModuleImport {
+                module: namespace,
+                name,
+                value: ExternalValue::Memory(memory),
+            }])?;
+        }
+        Ok(SyncReturn(module_id))
+    }
+}
+
+/// The state of a [ModuleCache].
+pub struct ModuleCacheImpl {
+    engine: WEngine,
+    store: ModuleStore<RustOpaque<Arc<std::sync::Mutex<Module>>>>,
+}
+
+/// Compiled modules of a [WasmEngine] keyed by the content hash of their binary,
+/// as returned by [module_content_hash]. Compiling the same bytes again
+/// returns the cached module instead of recompiling it.
+/// Created with [WasmEngine::create_module_cache].
+#[derive(Clone)]
+pub struct ModuleCache(pub RustOpaque<Arc<std::sync::Mutex<ModuleCacheImpl>>>);
+
+impl ModuleCache {
+    pub fn compile_wasm(&self, module_wasm: Vec<u8>) -> Result<CompiledModule> {
+        let hash = crate::hash::content_hash(&module_wasm)?;
+        let engine = {
+            let mut cache = self.0.lock().unwrap();
+            if let Some(module) = cache.store.get(&hash) {
+                return Ok(CompiledModule(module));
+            }
+            cache.engine.clone()
+        };
+
+        let size_bytes = module_wasm.len() as u64;
+        let CompiledModule(module) = compile_with_engine(&engine, module_wasm)?.into();
+        self.0
+            .lock()
+            .unwrap()
+            .store
+            .insert(hash, module.clone(), size_bytes);
+        Ok(CompiledModule(module))
+    }
+
+    pub fn compile_wasm_sync(&self, module_wasm: Vec<u8>) -> Result<SyncReturn<CompiledModule>> {
+        self.compile_wasm(module_wasm).map(SyncReturn)
+    }
+
+    pub fn stats(&self) -> SyncReturn<ModuleCacheStats> {
+        SyncReturn(self.0.lock().unwrap().store.stats())
+    }
+
+    /// Removes all the modules from the cache.
+    pub fn clear(&self) -> SyncReturn<()> {
+        self.0.lock().unwrap().store.clear();
+        SyncReturn(())
+    }
+}
+
+pub fn create_engine(config: ModuleConfig) -> Result<SyncReturn<WasmEngine>> {
+    let engine = Engine::new(&Config::from(config.clone()));
+    Ok(SyncReturn(WasmEngine(RustOpaque::new(WEngine::new(
+        engine, config,
+    )))))
+}
+
+/// Compiles [module_wasm] with [engine] after checking the limits and applying
+/// the transformations of its [ModuleConfig], like [compile_wasm].
+fn compile_with_engine(engine: &WEngine, module_wasm: Vec<u8>) -> Result<Module> {
+    let _span = observer::compile_span(module_wasm.len());
+    let config = &engine.config;
+    config.check_module_limits(&module_wasm)?;
+    let module_wasm = config.transform_module(module_wasm)?;
+    let features = crate::validate::parser_features(config);
+    let module = Module::new(&engine.engine_wasmi, &mut &module_wasm[..])
+        .map_err(|err| CompileError::new(&module_wasm, features, err.into()))?;
+    Ok(module)
+}
+
+/// Compiles the module with a new engine created from `config`.
+/// Use [WasmEngine] to share the engine between modules.
+/// Returns a [CompileError] if the module is invalid or can not be compiled.
 pub fn compile_wasm(module_wasm: Vec<u8>, config: ModuleConfig) -> Result<CompiledModule> {
+    let _span = observer::compile_span(module_wasm.len());
+    config.check_module_limits(&module_wasm)?;
+    let module_wasm = config.transform_module(module_wasm)?;
+    let features = crate::validate::parser_features(&config);
     let config: Config = config.into();
     let engine = Engine::new(&config);
-    let module = Module::new(&engine, &mut &module_wasm[..])?;
+    let module = Module::new(&engine, &mut &module_wasm[..])
+        .map_err(|err| CompileError::new(&module_wasm, features, err.into()))?;
     Ok(module.into())
 }
 
@@ -690,6 +1970,75 @@ pub fn compile_wasm_sync(
     compile_wasm(module_wasm, config).map(SyncReturn)
 }
 
+/// A handle to cancel a compilation started with [compile_wasm_cancelable].
+#[derive(Debug, Clone)]
+pub struct CompileHandle(pub RustOpaque<Arc<AtomicBool>>);
+
+impl CompileHandle {
+    /// Cancels the compilations that use this handle, they return a [Cancelled]
+    /// error. Cancelling after the module was compiled has no effect.
+    pub fn cancel(&self) -> SyncReturn<()> {
+        self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        SyncReturn(())
+    }
+
+    pub fn is_cancelled(&self) -> SyncReturn<bool> {
+        SyncReturn(self.0.load(std::sync::atomic::Ordering::SeqCst))
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.is_cancelled().0 {
+            return Err(Cancelled.into());
+        }
+        Ok(())
+    }
+}
+
+pub fn create_compile_handle() -> SyncReturn<CompileHandle> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    SyncReturn(CompileHandle(RustOpaque::new(cancelled)))
+}
+
+/// Compiles [module_wasm] like [compile_wasm], returning a [Cancelled] error
+/// when [handle] is cancelled. The handle is checked between the phases of the
+/// compilation, the module is compiled in a separate thread which is
+/// detached when the handle is cancelled: the thread keeps compiling in the
+/// background until it finishes and its module is dropped. At most 4 threads
+/// compile at the same time, including the detached ones, the next
+/// compilations wait for one of them to finish.
+pub fn compile_wasm_cancelable(
+    module_wasm: Vec<u8>,
+    config: ModuleConfig,
+    handle: CompileHandle,
+) -> Result<CompiledModule> {
+    handle.check()?;
+    let _span = observer::compile_span(module_wasm.len());
+    config.check_module_limits(&module_wasm)?;
+    let module_wasm = config.transform_module(module_wasm)?;
+    let features = crate::validate::parser_features(&config);
+    let config: Config = config.into();
+    let engine = Engine::new(&config);
+    handle.check()?;
+
+    let slot = CompilationSlot::acquire(|| handle.check())?;
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _slot = slot;
+        let module = Module::new(&engine, &mut &module_wasm[..])
+            .map_err(|err| CompileError::new(&module_wasm, features, err.into()));
+        _ = sender.send(module);
+    });
+    loop {
+        match receiver.recv_timeout(std::time::Duration::from_millis(10)) {
+            std::result::Result::Ok(module) => return Ok(module?.into()),
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => handle.check()?,
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(anyhow::anyhow!("The compilation thread panicked."))
+            }
+        }
+    }
+}
+
 pub fn wasm_features_for_config(config: ModuleConfig) -> SyncReturn<WasmFeatures> {
     SyncReturn(config.wasm_features())
 }
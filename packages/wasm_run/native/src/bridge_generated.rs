@@ -9,7 +9,7 @@
     clippy::too_many_arguments
 )]
 // AUTO GENERATED FILE, DO NOT EDIT.
-// Generated by `flutter_rust_bridge`@ 1.82.4.
+// Generated by `flutter_rust_bridge`@ 1.82.6.
 
 use crate::api::*;
 use core::panic::UnwindSafe;
@@ -25,22 +25,30 @@ use crate::atomics::AtomicOrdering;
 use crate::atomics::Atomics;
 use crate::atomics::CompareExchangeResult;
 use crate::atomics::SharedMemoryWaitResult;
+use crate::config::CaptureOverflow;
 use crate::config::EnvVariable;
+use crate::config::FeatureStability;
+use crate::config::FuelConsumptionMode;
 use crate::config::ModuleConfig;
 use crate::config::ModuleConfigWasmi;
 use crate::config::ModuleConfigWasmtime;
 use crate::config::PreopenedDir;
+use crate::config::ProposalToggle;
 use crate::config::StdIOKind;
 use crate::config::WasiConfigNative;
+use crate::config::WasiModuleKind;
 use crate::config::WasiStackLimits;
+use crate::config::WasmFeatureStability;
 use crate::config::WasmFeatures;
 use crate::config::WasmRuntimeFeatures;
 use crate::config::WasmWasiFeatures;
+use crate::module_cache::ModuleCacheStats;
 use crate::types::ExternalType;
 use crate::types::ExternalValue;
 use crate::types::FuncTy;
 use crate::types::FunctionCall;
 use crate::types::GlobalTy;
+use crate::types::MemoryFootprint;
 use crate::types::MemoryTy;
 use crate::types::ModuleExportDesc;
 use crate::types::ModuleExportValue;
@@ -87,6 +95,68 @@ fn wire_parse_wat_format_impl(port_: MessagePort, wat: impl Wire2Api<String> + U
         },
     )
 }
+fn wire_clamp_memory_impl(
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    max_pages: impl Wire2Api<u64> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "clamp_memory",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_module_wasm = module_wasm.wire2api();
+            let api_max_pages = max_pages.wire2api();
+            clamp_memory(api_module_wasm, api_max_pages)
+        },
+    )
+}
+fn wire_module_has_start_impl(
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "module_has_start",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_module_wasm = module_wasm.wire2api();
+            module_has_start(api_module_wasm)
+        },
+    )
+}
+fn wire_module_content_hash_impl(
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "module_content_hash",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_module_wasm = module_wasm.wire2api();
+            module_content_hash(api_module_wasm)
+        },
+    )
+}
+fn wire_create_engine_impl(
+    config: impl Wire2Api<ModuleConfig> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_engine",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_config = config.wire2api();
+            create_engine(api_config)
+        },
+    )
+}
 fn wire_compile_wasm_impl(
     port_: MessagePort,
     module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
@@ -122,6 +192,36 @@ fn wire_compile_wasm_sync_impl(
         },
     )
 }
+fn wire_create_compile_handle_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_compile_handle",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || Result::<_, ()>::Ok(create_compile_handle()),
+    )
+}
+fn wire_compile_wasm_cancelable_impl(
+    port_: MessagePort,
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    config: impl Wire2Api<ModuleConfig> + UnwindSafe,
+    handle: impl Wire2Api<CompileHandle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CompiledModule, _>(
+        WrapInfo {
+            debug_name: "compile_wasm_cancelable",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_module_wasm = module_wasm.wire2api();
+            let api_config = config.wire2api();
+            let api_handle = handle.wire2api();
+            move |task_callback| compile_wasm_cancelable(api_module_wasm, api_config, api_handle)
+        },
+    )
+}
 fn wire_wasm_features_for_config_impl(
     config: impl Wire2Api<ModuleConfig> + UnwindSafe,
 ) -> support::WireSyncReturn {
@@ -210,6 +310,25 @@ fn wire_link_imports__method__WasmRunModuleId_impl(
         },
     )
 }
+fn wire_link_module__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    namespace: impl Wire2Api<String> + UnwindSafe,
+    module: impl Wire2Api<CompiledModule> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "link_module__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_namespace = namespace.wire2api();
+            let api_module = module.wire2api();
+            WasmRunModuleId::link_module(&api_that, api_namespace, api_module)
+        },
+    )
+}
 fn wire_stdio_stream__method__WasmRunModuleId_impl(
     port_: MessagePort,
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
@@ -234,627 +353,1271 @@ fn wire_stdio_stream__method__WasmRunModuleId_impl(
         },
     )
 }
-fn wire_dispose__method__WasmRunModuleId_impl(
+fn wire_wasi_denied_stream__method__WasmRunModuleId_impl(
     port_: MessagePort,
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "dispose__method__WasmRunModuleId",
+            debug_name: "wasi_denied_stream__method__WasmRunModuleId",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| WasmRunModuleId::dispose(&api_that)
+            move |task_callback| {
+                WasmRunModuleId::wasi_denied_stream(
+                    &api_that,
+                    task_callback.stream_sink::<_, String>(),
+                )
+            }
         },
     )
 }
-fn wire_call_function_handle_sync__method__WasmRunModuleId_impl(
+fn wire_read_stdio__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
-    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
+    kind: impl Wire2Api<StdIOKind> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "call_function_handle_sync__method__WasmRunModuleId",
+            debug_name: "read_stdio__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_func = func.wire2api();
-            let api_args = args.wire2api();
-            WasmRunModuleId::call_function_handle_sync(&api_that, api_func, api_args)
+            let api_kind = kind.wire2api();
+            WasmRunModuleId::read_stdio(&api_that, api_kind)
         },
     )
 }
-fn wire_call_function_handle__method__WasmRunModuleId_impl(
+fn wire_next_stdio_chunk__method__WasmRunModuleId_impl(
     port_: MessagePort,
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
-    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
+    kind: impl Wire2Api<StdIOKind> + UnwindSafe,
+    timeout_millis: impl Wire2Api<u64> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<WasmVal>, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<Vec<u8>>, _>(
         WrapInfo {
-            debug_name: "call_function_handle__method__WasmRunModuleId",
+            debug_name: "next_stdio_chunk__method__WasmRunModuleId",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_func = func.wire2api();
-            let api_args = args.wire2api();
+            let api_kind = kind.wire2api();
+            let api_timeout_millis = timeout_millis.wire2api();
             move |task_callback| {
-                WasmRunModuleId::call_function_handle(&api_that, api_func, api_args)
+                WasmRunModuleId::next_stdio_chunk(&api_that, api_kind, api_timeout_millis)
             }
         },
     )
 }
-fn wire_call_function_handle_parallel__method__WasmRunModuleId_impl(
-    port_: MessagePort,
+fn wire_stdio_dropped_bytes__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    func_name: impl Wire2Api<String> + UnwindSafe,
-    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
-    num_tasks: impl Wire2Api<usize> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    kind: impl Wire2Api<StdIOKind> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "call_function_handle_parallel__method__WasmRunModuleId",
-            port: Some(port_),
-            mode: FfiCallMode::Stream,
+            debug_name: "stdio_dropped_bytes__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_func_name = func_name.wire2api();
-            let api_args = args.wire2api();
-            let api_num_tasks = num_tasks.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(WasmRunModuleId::call_function_handle_parallel(
-                    &api_that,
-                    api_func_name,
-                    api_args,
-                    api_num_tasks,
-                    task_callback.stream_sink::<_, ParallelExec>(),
-                ))
-            }
+            let api_kind = kind.wire2api();
+            WasmRunModuleId::stdio_dropped_bytes(&api_that, api_kind)
         },
     )
 }
-fn wire_worker_execution__method__WasmRunModuleId_impl(
+fn wire_close_stdio__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    worker_index: impl Wire2Api<usize> + UnwindSafe,
-    results: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
+    kind: impl Wire2Api<StdIOKind> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "worker_execution__method__WasmRunModuleId",
+            debug_name: "close_stdio__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_worker_index = worker_index.wire2api();
-            let api_results = results.wire2api();
-            WasmRunModuleId::worker_execution(&api_that, api_worker_index, api_results)
+            let api_kind = kind.wire2api();
+            WasmRunModuleId::close_stdio(&api_that, api_kind)
         },
     )
 }
-fn wire_get_function_type__method__WasmRunModuleId_impl(
+fn wire_close__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_function_type__method__WasmRunModuleId",
+            debug_name: "close__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_func = func.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_function_type(&api_that, api_func))
+            WasmRunModuleId::close(&api_that)
         },
     )
 }
-fn wire_create_function__method__WasmRunModuleId_impl(
+fn wire_dispose__method__WasmRunModuleId_impl(
+    port_: MessagePort,
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    function_pointer: impl Wire2Api<usize> + UnwindSafe,
-    function_id: impl Wire2Api<u32> + UnwindSafe,
-    param_types: impl Wire2Api<Vec<ValueTy>> + UnwindSafe,
-    result_types: impl Wire2Api<Vec<ValueTy>> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "create_function__method__WasmRunModuleId",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "dispose__method__WasmRunModuleId",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_function_pointer = function_pointer.wire2api();
-            let api_function_id = function_id.wire2api();
-            let api_param_types = param_types.wire2api();
-            let api_result_types = result_types.wire2api();
-            WasmRunModuleId::create_function(
-                &api_that,
-                api_function_pointer,
-                api_function_id,
-                api_param_types,
-                api_result_types,
-            )
+            move |task_callback| WasmRunModuleId::dispose(&api_that)
         },
     )
 }
-fn wire_create_memory__method__WasmRunModuleId_impl(
+fn wire_call_function_handle_sync__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    memory_type: impl Wire2Api<MemoryTy> + UnwindSafe,
+    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
+    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "create_memory__method__WasmRunModuleId",
+            debug_name: "call_function_handle_sync__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory_type = memory_type.wire2api();
-            WasmRunModuleId::create_memory(&api_that, api_memory_type)
+            let api_func = func.wire2api();
+            let api_args = args.wire2api();
+            WasmRunModuleId::call_function_handle_sync(&api_that, api_func, api_args)
         },
     )
 }
-fn wire_create_global__method__WasmRunModuleId_impl(
+fn wire_call_function_handle__method__WasmRunModuleId_impl(
+    port_: MessagePort,
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    value: impl Wire2Api<WasmVal> + UnwindSafe,
-    mutable: impl Wire2Api<bool> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
+    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<WasmVal>, _>(
         WrapInfo {
-            debug_name: "create_global__method__WasmRunModuleId",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "call_function_handle__method__WasmRunModuleId",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_value = value.wire2api();
-            let api_mutable = mutable.wire2api();
-            WasmRunModuleId::create_global(&api_that, api_value, api_mutable)
+            let api_func = func.wire2api();
+            let api_args = args.wire2api();
+            move |task_callback| {
+                WasmRunModuleId::call_function_handle(&api_that, api_func, api_args)
+            }
         },
     )
 }
-fn wire_create_table__method__WasmRunModuleId_impl(
+fn wire_call_by_index__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    value: impl Wire2Api<WasmVal> + UnwindSafe,
-    table_type: impl Wire2Api<TableArgs> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
+    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "create_table__method__WasmRunModuleId",
+            debug_name: "call_by_index__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_value = value.wire2api();
-            let api_table_type = table_type.wire2api();
-            WasmRunModuleId::create_table(&api_that, api_value, api_table_type)
+            let api_index = index.wire2api();
+            let api_args = args.wire2api();
+            WasmRunModuleId::call_by_index(&api_that, api_index, api_args)
         },
     )
 }
-fn wire_get_global_type__method__WasmRunModuleId_impl(
+fn wire_call_wasi_start__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    global: impl Wire2Api<RustOpaque<Global>> + UnwindSafe,
+    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_global_type__method__WasmRunModuleId",
+            debug_name: "call_wasi_start__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_global = global.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_global_type(&api_that, api_global))
+            let api_func = func.wire2api();
+            WasmRunModuleId::call_wasi_start(&api_that, api_func)
         },
     )
 }
-fn wire_get_global_value__method__WasmRunModuleId_impl(
+fn wire_initialize__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    global: impl Wire2Api<RustOpaque<Global>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_global_value__method__WasmRunModuleId",
+            debug_name: "initialize__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_global = global.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_global_value(&api_that, api_global))
+            WasmRunModuleId::initialize(&api_that)
         },
     )
 }
-fn wire_set_global_value__method__WasmRunModuleId_impl(
+fn wire_call_export__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    global: impl Wire2Api<RustOpaque<Global>> + UnwindSafe,
-    value: impl Wire2Api<WasmVal> + UnwindSafe,
+    name: impl Wire2Api<String> + UnwindSafe,
+    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "set_global_value__method__WasmRunModuleId",
+            debug_name: "call_export__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_global = global.wire2api();
-            let api_value = value.wire2api();
-            WasmRunModuleId::set_global_value(&api_that, api_global, api_value)
+            let api_name = name.wire2api();
+            let api_args = args.wire2api();
+            WasmRunModuleId::call_export(&api_that, api_name, api_args)
         },
     )
 }
-fn wire_get_memory_type__method__WasmRunModuleId_impl(
+fn wire_guest_alloc__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+    size: impl Wire2Api<u32> + UnwindSafe,
+    alloc_export: impl Wire2Api<Option<String>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_memory_type__method__WasmRunModuleId",
+            debug_name: "guest_alloc__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory = memory.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_memory_type(&api_that, api_memory))
+            let api_size = size.wire2api();
+            let api_alloc_export = alloc_export.wire2api();
+            WasmRunModuleId::guest_alloc(&api_that, api_size, api_alloc_export)
         },
     )
 }
-fn wire_get_memory_data__method__WasmRunModuleId_impl(
+fn wire_guest_free__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+    ptr: impl Wire2Api<u32> + UnwindSafe,
+    size: impl Wire2Api<u32> + UnwindSafe,
+    free_export: impl Wire2Api<Option<String>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_memory_data__method__WasmRunModuleId",
+            debug_name: "guest_free__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_ptr = ptr.wire2api();
+            let api_size = size.wire2api();
+            let api_free_export = free_export.wire2api();
+            WasmRunModuleId::guest_free(&api_that, api_ptr, api_size, api_free_export)
+        },
+    )
+}
+fn wire_write_guest_string__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    value: impl Wire2Api<String> + UnwindSafe,
+    memory_name: impl Wire2Api<Option<String>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "write_guest_string__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_value = value.wire2api();
+            let api_memory_name = memory_name.wire2api();
+            WasmRunModuleId::write_guest_string(&api_that, api_value, api_memory_name)
+        },
+    )
+}
+fn wire_read_guest_string__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    ptr: impl Wire2Api<u32> + UnwindSafe,
+    len: impl Wire2Api<u32> + UnwindSafe,
+    memory_name: impl Wire2Api<Option<String>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "read_guest_string__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_ptr = ptr.wire2api();
+            let api_len = len.wire2api();
+            let api_memory_name = memory_name.wire2api();
+            WasmRunModuleId::read_guest_string(&api_that, api_ptr, api_len, api_memory_name)
+        },
+    )
+}
+fn wire_call_function_handle_parallel__method__WasmRunModuleId_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    func_name: impl Wire2Api<String> + UnwindSafe,
+    args: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
+    num_tasks: impl Wire2Api<usize> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "call_function_handle_parallel__method__WasmRunModuleId",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_func_name = func_name.wire2api();
+            let api_args = args.wire2api();
+            let api_num_tasks = num_tasks.wire2api();
+            move |task_callback| {
+                Result::<_, ()>::Ok(WasmRunModuleId::call_function_handle_parallel(
+                    &api_that,
+                    api_func_name,
+                    api_args,
+                    api_num_tasks,
+                    task_callback.stream_sink::<_, ParallelExec>(),
+                ))
+            }
+        },
+    )
+}
+fn wire_worker_execution__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    worker_index: impl Wire2Api<usize> + UnwindSafe,
+    results: impl Wire2Api<Vec<WasmVal>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "worker_execution__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_worker_index = worker_index.wire2api();
+            let api_results = results.wire2api();
+            WasmRunModuleId::worker_execution(&api_that, api_worker_index, api_results)
+        },
+    )
+}
+fn wire_get_function_type__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    func: impl Wire2Api<RustOpaque<WFunc>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_function_type__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_func = func.wire2api();
+            WasmRunModuleId::get_function_type(&api_that, api_func)
+        },
+    )
+}
+fn wire_create_function__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    function_pointer: impl Wire2Api<usize> + UnwindSafe,
+    function_id: impl Wire2Api<u32> + UnwindSafe,
+    param_types: impl Wire2Api<Vec<ValueTy>> + UnwindSafe,
+    result_types: impl Wire2Api<Vec<ValueTy>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_function__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_function_pointer = function_pointer.wire2api();
+            let api_function_id = function_id.wire2api();
+            let api_param_types = param_types.wire2api();
+            let api_result_types = result_types.wire2api();
+            WasmRunModuleId::create_function(
+                &api_that,
+                api_function_pointer,
+                api_function_id,
+                api_param_types,
+                api_result_types,
+            )
+        },
+    )
+}
+fn wire_create_memory__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    memory_type: impl Wire2Api<MemoryTy> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_memory__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory_type = memory_type.wire2api();
+            WasmRunModuleId::create_memory(&api_that, api_memory_type)
+        },
+    )
+}
+fn wire_create_global__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    value: impl Wire2Api<WasmVal> + UnwindSafe,
+    mutable: impl Wire2Api<bool> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_global__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_value = value.wire2api();
+            let api_mutable = mutable.wire2api();
+            WasmRunModuleId::create_global(&api_that, api_value, api_mutable)
+        },
+    )
+}
+fn wire_create_table__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    value: impl Wire2Api<WasmVal> + UnwindSafe,
+    table_type: impl Wire2Api<TableArgs> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_table__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_value = value.wire2api();
+            let api_table_type = table_type.wire2api();
+            WasmRunModuleId::create_table(&api_that, api_value, api_table_type)
+        },
+    )
+}
+fn wire_get_global_type__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    global: impl Wire2Api<RustOpaque<Global>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_global_type__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_global = global.wire2api();
+            WasmRunModuleId::get_global_type(&api_that, api_global)
+        },
+    )
+}
+fn wire_get_global_value__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    global: impl Wire2Api<RustOpaque<Global>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_global_value__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_global = global.wire2api();
+            WasmRunModuleId::get_global_value(&api_that, api_global)
+        },
+    )
+}
+fn wire_set_global_value__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    global: impl Wire2Api<RustOpaque<Global>> + UnwindSafe,
+    value: impl Wire2Api<WasmVal> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "set_global_value__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_global = global.wire2api();
+            let api_value = value.wire2api();
+            WasmRunModuleId::set_global_value(&api_that, api_global, api_value)
+        },
+    )
+}
+fn wire_get_memory_type__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_memory_type__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
             let api_memory = memory.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_memory_data(&api_that, api_memory))
+            WasmRunModuleId::get_memory_type(&api_that, api_memory)
         },
     )
 }
-fn wire_get_memory_data_pointer__method__WasmRunModuleId_impl(
+fn wire_get_memory_data__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
     memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_memory_data_pointer__method__WasmRunModuleId",
+            debug_name: "get_memory_data__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
             let api_memory = memory.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_memory_data_pointer(
-                &api_that, api_memory,
-            ))
+            WasmRunModuleId::get_memory_data(&api_that, api_memory)
         },
     )
 }
-fn wire_get_memory_data_pointer_and_length__method__WasmRunModuleId_impl(
+fn wire_get_memory_data_pointer__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
     memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_memory_data_pointer_and_length__method__WasmRunModuleId",
+            debug_name: "get_memory_data_pointer__method__WasmRunModuleId",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
             let api_memory = memory.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_memory_data_pointer_and_length(
-                &api_that, api_memory,
-            ))
+            WasmRunModuleId::get_memory_data_pointer(&api_that, api_memory)
         },
     )
 }
-fn wire_read_memory__method__WasmRunModuleId_impl(
+fn wire_get_memory_data_pointer_and_length__method__WasmRunModuleId_impl(
     that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
     memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
-    offset: impl Wire2Api<usize> + UnwindSafe,
-    bytes: impl Wire2Api<usize> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "read_memory__method__WasmRunModuleId",
+            debug_name: "get_memory_data_pointer_and_length__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory = memory.wire2api();
+            WasmRunModuleId::get_memory_data_pointer_and_length(&api_that, api_memory)
+        },
+    )
+}
+fn wire_read_memory__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+    offset: impl Wire2Api<usize> + UnwindSafe,
+    bytes: impl Wire2Api<usize> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "read_memory__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory = memory.wire2api();
+            let api_offset = offset.wire2api();
+            let api_bytes = bytes.wire2api();
+            WasmRunModuleId::read_memory(&api_that, api_memory, api_offset, api_bytes)
+        },
+    )
+}
+fn wire_get_memory_pages__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_memory_pages__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory = memory.wire2api();
+            WasmRunModuleId::get_memory_pages(&api_that, api_memory)
+        },
+    )
+}
+fn wire_write_memory__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+    offset: impl Wire2Api<usize> + UnwindSafe,
+    buffer: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "write_memory__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory = memory.wire2api();
+            let api_offset = offset.wire2api();
+            let api_buffer = buffer.wire2api();
+            WasmRunModuleId::write_memory(&api_that, api_memory, api_offset, api_buffer)
+        },
+    )
+}
+fn wire_grow_memory__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+    pages: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "grow_memory__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory = memory.wire2api();
+            let api_pages = pages.wire2api();
+            WasmRunModuleId::grow_memory(&api_that, api_memory, api_pages)
+        },
+    )
+}
+fn wire_get_table_size__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_table_size__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_table = table.wire2api();
+            WasmRunModuleId::get_table_size(&api_that, api_table)
+        },
+    )
+}
+fn wire_get_table_type__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_table_type__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_table = table.wire2api();
+            WasmRunModuleId::get_table_type(&api_that, api_table)
+        },
+    )
+}
+fn wire_grow_table__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+    delta: impl Wire2Api<u32> + UnwindSafe,
+    value: impl Wire2Api<WasmVal> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "grow_table__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_table = table.wire2api();
+            let api_delta = delta.wire2api();
+            let api_value = value.wire2api();
+            WasmRunModuleId::grow_table(&api_that, api_table, api_delta, api_value)
+        },
+    )
+}
+fn wire_get_table__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_table__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_table = table.wire2api();
+            let api_index = index.wire2api();
+            WasmRunModuleId::get_table(&api_that, api_table, api_index)
+        },
+    )
+}
+fn wire_set_table__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
+    value: impl Wire2Api<WasmVal> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "set_table__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_table = table.wire2api();
+            let api_index = index.wire2api();
+            let api_value = value.wire2api();
+            WasmRunModuleId::set_table(&api_that, api_table, api_index, api_value)
+        },
+    )
+}
+fn wire_fill_table__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+    index: impl Wire2Api<u32> + UnwindSafe,
+    value: impl Wire2Api<WasmVal> + UnwindSafe,
+    len: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "fill_table__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_table = table.wire2api();
+            let api_index = index.wire2api();
+            let api_value = value.wire2api();
+            let api_len = len.wire2api();
+            WasmRunModuleId::fill_table(&api_that, api_table, api_index, api_value, api_len)
+        },
+    )
+}
+fn wire_set_max_host_call_depth__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    max_depth: impl Wire2Api<Option<u32>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "set_max_host_call_depth__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_max_depth = max_depth.wire2api();
+            WasmRunModuleId::set_max_host_call_depth(&api_that, api_max_depth)
+        },
+    )
+}
+fn wire_set_epoch_deadline__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    ticks_beyond_current: impl Wire2Api<Option<u64>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "set_epoch_deadline__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_ticks_beyond_current = ticks_beyond_current.wire2api();
+            WasmRunModuleId::set_epoch_deadline(&api_that, api_ticks_beyond_current)
+        },
+    )
+}
+fn wire_add_fuel__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    delta: impl Wire2Api<u64> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "add_fuel__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_delta = delta.wire2api();
+            WasmRunModuleId::add_fuel(&api_that, api_delta)
+        },
+    )
+}
+fn wire_fuel_consumed__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "fuel_consumed__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            WasmRunModuleId::fuel_consumed(&api_that)
+        },
+    )
+}
+fn wire_consume_fuel__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+    delta: impl Wire2Api<u64> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "consume_fuel__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_delta = delta.wire2api();
+            WasmRunModuleId::consume_fuel(&api_that, api_delta)
+        },
+    )
+}
+fn wire_last_call_fuel_consumed__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "last_call_fuel_consumed__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            WasmRunModuleId::last_call_fuel_consumed(&api_that)
+        },
+    )
+}
+fn wire_last_call_duration_micros__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "last_call_duration_micros__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            WasmRunModuleId::last_call_duration_micros(&api_that)
+        },
+    )
+}
+fn wire_memory_footprint__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "memory_footprint__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            WasmRunModuleId::memory_footprint(&api_that)
+        },
+    )
+}
+fn wire_memories__method__WasmRunModuleId_impl(
+    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "memories__method__WasmRunModuleId",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            WasmRunModuleId::memories(&api_that)
+        },
+    )
+}
+fn wire_instantiate__method__CompiledModule_impl(
+    that: impl Wire2Api<CompiledModule> + UnwindSafe,
+    wasi_config: impl Wire2Api<Option<WasiConfigNative>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "instantiate__method__CompiledModule",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_wasi_config = wasi_config.wire2api();
+            CompiledModule::instantiate(&api_that, api_wasi_config)
+        },
+    )
+}
+fn wire_create_shared_memory__method__CompiledModule_impl(
+    that: impl Wire2Api<CompiledModule> + UnwindSafe,
+    memory_type: impl Wire2Api<MemoryTy> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "create_shared_memory__method__CompiledModule",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_memory_type = memory_type.wire2api();
+            CompiledModule::create_shared_memory(&api_that, api_memory_type)
+        },
+    )
+}
+fn wire_get_module_imports__method__CompiledModule_impl(
+    that: impl Wire2Api<CompiledModule> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "get_module_imports__method__CompiledModule",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory = memory.wire2api();
-            let api_offset = offset.wire2api();
-            let api_bytes = bytes.wire2api();
-            WasmRunModuleId::read_memory(&api_that, api_memory, api_offset, api_bytes)
+            Result::<_, ()>::Ok(CompiledModule::get_module_imports(&api_that))
         },
     )
 }
-fn wire_get_memory_pages__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
+fn wire_wasi_module_kind__method__CompiledModule_impl(
+    that: impl Wire2Api<CompiledModule> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_memory_pages__method__WasmRunModuleId",
+            debug_name: "wasi_module_kind__method__CompiledModule",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory = memory.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_memory_pages(&api_that, api_memory))
+            Result::<_, ()>::Ok(CompiledModule::wasi_module_kind(&api_that))
         },
     )
 }
-fn wire_write_memory__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
-    offset: impl Wire2Api<usize> + UnwindSafe,
-    buffer: impl Wire2Api<Vec<u8>> + UnwindSafe,
+fn wire_get_module_exports__method__CompiledModule_impl(
+    that: impl Wire2Api<CompiledModule> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "write_memory__method__WasmRunModuleId",
+            debug_name: "get_module_exports__method__CompiledModule",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory = memory.wire2api();
-            let api_offset = offset.wire2api();
-            let api_buffer = buffer.wire2api();
-            WasmRunModuleId::write_memory(&api_that, api_memory, api_offset, api_buffer)
+            Result::<_, ()>::Ok(CompiledModule::get_module_exports(&api_that))
         },
     )
 }
-fn wire_grow_memory__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    memory: impl Wire2Api<RustOpaque<Memory>> + UnwindSafe,
-    pages: impl Wire2Api<u32> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_compile_wasm__method__WasmEngine_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<WasmEngine> + UnwindSafe,
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CompiledModule, _>(
         WrapInfo {
-            debug_name: "grow_memory__method__WasmRunModuleId",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "compile_wasm__method__WasmEngine",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory = memory.wire2api();
-            let api_pages = pages.wire2api();
-            WasmRunModuleId::grow_memory(&api_that, api_memory, api_pages)
+            let api_module_wasm = module_wasm.wire2api();
+            move |task_callback| WasmEngine::compile_wasm(&api_that, api_module_wasm)
         },
     )
 }
-fn wire_get_table_size__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+fn wire_compile_wasm_sync__method__WasmEngine_impl(
+    that: impl Wire2Api<WasmEngine> + UnwindSafe,
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_table_size__method__WasmRunModuleId",
+            debug_name: "compile_wasm_sync__method__WasmEngine",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_table = table.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_table_size(&api_that, api_table))
+            let api_module_wasm = module_wasm.wire2api();
+            WasmEngine::compile_wasm_sync(&api_that, api_module_wasm)
         },
     )
 }
-fn wire_get_table_type__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
+fn wire_increment_epoch__method__WasmEngine_impl(
+    that: impl Wire2Api<WasmEngine> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_table_type__method__WasmRunModuleId",
+            debug_name: "increment_epoch__method__WasmEngine",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_table = table.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_table_type(&api_that, api_table))
+            WasmEngine::increment_epoch(&api_that)
         },
     )
 }
-fn wire_grow_table__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
-    delta: impl Wire2Api<u32> + UnwindSafe,
-    value: impl Wire2Api<WasmVal> + UnwindSafe,
+fn wire_create_linker__method__WasmEngine_impl(
+    that: impl Wire2Api<WasmEngine> + UnwindSafe,
+    wasi: impl Wire2Api<bool> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "grow_table__method__WasmRunModuleId",
+            debug_name: "create_linker__method__WasmEngine",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_table = table.wire2api();
-            let api_delta = delta.wire2api();
-            let api_value = value.wire2api();
-            WasmRunModuleId::grow_table(&api_that, api_table, api_delta, api_value)
+            let api_wasi = wasi.wire2api();
+            WasmEngine::create_linker(&api_that, api_wasi)
         },
     )
 }
-fn wire_get_table__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
-    index: impl Wire2Api<u32> + UnwindSafe,
+fn wire_create_module_cache__method__WasmEngine_impl(
+    that: impl Wire2Api<WasmEngine> + UnwindSafe,
+    max_entries: impl Wire2Api<Option<u32>> + UnwindSafe,
+    max_bytes: impl Wire2Api<Option<u64>> + UnwindSafe,
+    directory: impl Wire2Api<Option<String>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_table__method__WasmRunModuleId",
+            debug_name: "create_module_cache__method__WasmEngine",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_table = table.wire2api();
-            let api_index = index.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::get_table(&api_that, api_table, api_index))
+            let api_max_entries = max_entries.wire2api();
+            let api_max_bytes = max_bytes.wire2api();
+            let api_directory = directory.wire2api();
+            WasmEngine::create_module_cache(
+                &api_that,
+                api_max_entries,
+                api_max_bytes,
+                api_directory,
+            )
         },
     )
 }
-fn wire_set_table__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
-    index: impl Wire2Api<u32> + UnwindSafe,
-    value: impl Wire2Api<WasmVal> + UnwindSafe,
+fn wire_define_host_function__method__WasmLinker_impl(
+    that: impl Wire2Api<WasmLinker> + UnwindSafe,
+    module: impl Wire2Api<String> + UnwindSafe,
+    name: impl Wire2Api<String> + UnwindSafe,
+    function_pointer: impl Wire2Api<usize> + UnwindSafe,
+    function_id: impl Wire2Api<u32> + UnwindSafe,
+    param_types: impl Wire2Api<Vec<ValueTy>> + UnwindSafe,
+    result_types: impl Wire2Api<Vec<ValueTy>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "set_table__method__WasmRunModuleId",
+            debug_name: "define_host_function__method__WasmLinker",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_table = table.wire2api();
-            let api_index = index.wire2api();
-            let api_value = value.wire2api();
-            WasmRunModuleId::set_table(&api_that, api_table, api_index, api_value)
+            let api_module = module.wire2api();
+            let api_name = name.wire2api();
+            let api_function_pointer = function_pointer.wire2api();
+            let api_function_id = function_id.wire2api();
+            let api_param_types = param_types.wire2api();
+            let api_result_types = result_types.wire2api();
+            WasmLinker::define_host_function(
+                &api_that,
+                api_module,
+                api_name,
+                api_function_pointer,
+                api_function_id,
+                api_param_types,
+                api_result_types,
+            )
         },
     )
 }
-fn wire_fill_table__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    table: impl Wire2Api<RustOpaque<Table>> + UnwindSafe,
-    index: impl Wire2Api<u32> + UnwindSafe,
-    value: impl Wire2Api<WasmVal> + UnwindSafe,
-    len: impl Wire2Api<u32> + UnwindSafe,
+fn wire_define_memory__method__WasmLinker_impl(
+    that: impl Wire2Api<WasmLinker> + UnwindSafe,
+    module: impl Wire2Api<String> + UnwindSafe,
+    name: impl Wire2Api<String> + UnwindSafe,
+    memory_type: impl Wire2Api<MemoryTy> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "fill_table__method__WasmRunModuleId",
+            debug_name: "define_memory__method__WasmLinker",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_table = table.wire2api();
-            let api_index = index.wire2api();
-            let api_value = value.wire2api();
-            let api_len = len.wire2api();
-            WasmRunModuleId::fill_table(&api_that, api_table, api_index, api_value, api_len)
+            let api_module = module.wire2api();
+            let api_name = name.wire2api();
+            let api_memory_type = memory_type.wire2api();
+            WasmLinker::define_memory(&api_that, api_module, api_name, api_memory_type)
         },
     )
 }
-fn wire_add_fuel__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    delta: impl Wire2Api<u64> + UnwindSafe,
+fn wire_module_builder__method__WasmLinker_impl(
+    that: impl Wire2Api<WasmLinker> + UnwindSafe,
+    module: impl Wire2Api<CompiledModule> + UnwindSafe,
+    wasi_config: impl Wire2Api<Option<WasiConfigNative>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "add_fuel__method__WasmRunModuleId",
+            debug_name: "module_builder__method__WasmLinker",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_delta = delta.wire2api();
-            WasmRunModuleId::add_fuel(&api_that, api_delta)
+            let api_module = module.wire2api();
+            let api_wasi_config = wasi_config.wire2api();
+            WasmLinker::module_builder(&api_that, api_module, api_wasi_config)
         },
     )
 }
-fn wire_fuel_consumed__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
+fn wire_compile_wasm__method__ModuleCache_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<ModuleCache> + UnwindSafe,
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CompiledModule, _>(
+        WrapInfo {
+            debug_name: "compile_wasm__method__ModuleCache",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_module_wasm = module_wasm.wire2api();
+            move |task_callback| ModuleCache::compile_wasm(&api_that, api_module_wasm)
+        },
+    )
+}
+fn wire_compile_wasm_sync__method__ModuleCache_impl(
+    that: impl Wire2Api<ModuleCache> + UnwindSafe,
+    module_wasm: impl Wire2Api<Vec<u8>> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "fuel_consumed__method__WasmRunModuleId",
+            debug_name: "compile_wasm_sync__method__ModuleCache",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            Result::<_, ()>::Ok(WasmRunModuleId::fuel_consumed(&api_that))
+            let api_module_wasm = module_wasm.wire2api();
+            ModuleCache::compile_wasm_sync(&api_that, api_module_wasm)
         },
     )
 }
-fn wire_consume_fuel__method__WasmRunModuleId_impl(
-    that: impl Wire2Api<WasmRunModuleId> + UnwindSafe,
-    delta: impl Wire2Api<u64> + UnwindSafe,
+fn wire_stats__method__ModuleCache_impl(
+    that: impl Wire2Api<ModuleCache> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "consume_fuel__method__WasmRunModuleId",
+            debug_name: "stats__method__ModuleCache",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_delta = delta.wire2api();
-            WasmRunModuleId::consume_fuel(&api_that, api_delta)
+            Result::<_, ()>::Ok(ModuleCache::stats(&api_that))
         },
     )
 }
-fn wire_create_shared_memory__method__CompiledModule_impl(
-    that: impl Wire2Api<CompiledModule> + UnwindSafe,
-    memory_type: impl Wire2Api<MemoryTy> + UnwindSafe,
+fn wire_clear__method__ModuleCache_impl(
+    that: impl Wire2Api<ModuleCache> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "create_shared_memory__method__CompiledModule",
+            debug_name: "clear__method__ModuleCache",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            let api_memory_type = memory_type.wire2api();
-            CompiledModule::create_shared_memory(&api_that, api_memory_type)
+            Result::<_, ()>::Ok(ModuleCache::clear(&api_that))
         },
     )
 }
-fn wire_get_module_imports__method__CompiledModule_impl(
-    that: impl Wire2Api<CompiledModule> + UnwindSafe,
+fn wire_cancel__method__CompileHandle_impl(
+    that: impl Wire2Api<CompileHandle> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_module_imports__method__CompiledModule",
+            debug_name: "cancel__method__CompileHandle",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            Result::<_, ()>::Ok(CompiledModule::get_module_imports(&api_that))
+            Result::<_, ()>::Ok(CompileHandle::cancel(&api_that))
         },
     )
 }
-fn wire_get_module_exports__method__CompiledModule_impl(
-    that: impl Wire2Api<CompiledModule> + UnwindSafe,
+fn wire_is_cancelled__method__CompileHandle_impl(
+    that: impl Wire2Api<CompileHandle> + UnwindSafe,
 ) -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_module_exports__method__CompiledModule",
+            debug_name: "is_cancelled__method__CompileHandle",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
             let api_that = that.wire2api();
-            Result::<_, ()>::Ok(CompiledModule::get_module_exports(&api_that))
+            Result::<_, ()>::Ok(CompileHandle::is_cancelled(&api_that))
         },
     )
 }
@@ -1323,6 +2086,16 @@ impl Wire2Api<bool> for bool {
     }
 }
 
+impl Wire2Api<CaptureOverflow> for i32 {
+    fn wire2api(self) -> CaptureOverflow {
+        match self {
+            0 => CaptureOverflow::drop,
+            1 => CaptureOverflow::block,
+            _ => unreachable!("Invalid variant for CaptureOverflow: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<f32> for f32 {
     fn wire2api(self) -> f32 {
         self
@@ -1333,6 +2106,15 @@ impl Wire2Api<f64> for f64 {
         self
     }
 }
+impl Wire2Api<FuelConsumptionMode> for i32 {
+    fn wire2api(self) -> FuelConsumptionMode {
+        match self {
+            0 => FuelConsumptionMode::lazy,
+            1 => FuelConsumptionMode::eager,
+            _ => unreachable!("Invalid variant for FuelConsumptionMode: {}", self),
+        }
+    }
+}
 impl Wire2Api<i32> for i32 {
     fn wire2api(self) -> i32 {
         self
@@ -1354,6 +2136,11 @@ impl Wire2Api<StdIOKind> for i32 {
     }
 }
 
+impl Wire2Api<u16> for u16 {
+    fn wire2api(self) -> u16 {
+        self
+    }
+}
 impl Wire2Api<u32> for u32 {
     fn wire2api(self) -> u32 {
         self
@@ -1420,6 +2207,18 @@ impl rust2dart::IntoIntoDart<CompareExchangeResult> for CompareExchangeResult {
     }
 }
 
+impl support::IntoDart for CompileHandle {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CompileHandle {}
+impl rust2dart::IntoIntoDart<CompileHandle> for CompileHandle {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for CompiledModule {
     fn into_dart(self) -> support::DartAbi {
         vec![self.0.into_dart()].into_dart()
@@ -1469,6 +2268,23 @@ impl rust2dart::IntoIntoDart<ExternalValue> for ExternalValue {
     }
 }
 
+impl support::IntoDart for FeatureStability {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Stable => 0,
+            Self::Experimental => 1,
+            Self::Unsupported => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FeatureStability {}
+impl rust2dart::IntoIntoDart<FeatureStability> for FeatureStability {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for FuncTy {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1520,6 +2336,28 @@ impl rust2dart::IntoIntoDart<GlobalTy> for GlobalTy {
     }
 }
 
+impl support::IntoDart for MemoryFootprint {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.memories.into_into_dart().into_dart(),
+            self.memory_pages.into_into_dart().into_dart(),
+            self.memory_bytes.into_into_dart().into_dart(),
+            self.tables.into_into_dart().into_dart(),
+            self.table_elements.into_into_dart().into_dart(),
+            self.globals.into_into_dart().into_dart(),
+            self.overhead_bytes.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MemoryFootprint {}
+impl rust2dart::IntoIntoDart<MemoryFootprint> for MemoryFootprint {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for MemoryTy {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1537,11 +2375,43 @@ impl rust2dart::IntoIntoDart<MemoryTy> for MemoryTy {
     }
 }
 
+impl support::IntoDart for ModuleCache {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ModuleCache {}
+impl rust2dart::IntoIntoDart<ModuleCache> for ModuleCache {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ModuleCacheStats {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.entries.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.hits.into_into_dart().into_dart(),
+            self.misses.into_into_dart().into_dart(),
+            self.evictions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ModuleCacheStats {}
+impl rust2dart::IntoIntoDart<ModuleCacheStats> for ModuleCacheStats {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for ModuleExportDesc {
     fn into_dart(self) -> support::DartAbi {
         vec![
             self.name.into_into_dart().into_dart(),
             self.ty.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1667,8 +2537,53 @@ impl support::IntoDart for ValueTy {
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for ValueTy {}
-impl rust2dart::IntoIntoDart<ValueTy> for ValueTy {
+impl support::IntoDartExceptPrimitive for ValueTy {}
+impl rust2dart::IntoIntoDart<ValueTy> for ValueTy {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for WasiModuleKind {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::command => 0,
+            Self::reactor => 1,
+            Self::library => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WasiModuleKind {}
+impl rust2dart::IntoIntoDart<WasiModuleKind> for WasiModuleKind {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for WasmEngine {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WasmEngine {}
+impl rust2dart::IntoIntoDart<WasmEngine> for WasmEngine {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for WasmFeatureStability {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.feature.into_into_dart().into_dart(),
+            self.stability.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WasmFeatureStability {}
+impl rust2dart::IntoIntoDart<WasmFeatureStability> for WasmFeatureStability {
     fn into_into_dart(self) -> Self {
         self
     }
@@ -1688,6 +2603,7 @@ impl support::IntoDart for WasmFeatures {
             self.threads.into_into_dart().into_dart(),
             self.tail_call.into_into_dart().into_dart(),
             self.floats.into_into_dart().into_dart(),
+            self.nan_canonicalization.into_into_dart().into_dart(),
             self.multi_memory.into_into_dart().into_dart(),
             self.exceptions.into_into_dart().into_dart(),
             self.memory64.into_into_dart().into_dart(),
@@ -1695,6 +2611,7 @@ impl support::IntoDart for WasmFeatures {
             self.component_model.into_into_dart().into_dart(),
             self.memory_control.into_into_dart().into_dart(),
             self.garbage_collection.into_into_dart().into_dart(),
+            self.function_references.into_into_dart().into_dart(),
             self.type_reflection.into_into_dart().into_dart(),
             self.wasi_features.into_dart(),
         ]
@@ -1708,6 +2625,18 @@ impl rust2dart::IntoIntoDart<WasmFeatures> for WasmFeatures {
     }
 }
 
+impl support::IntoDart for WasmLinker {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for WasmLinker {}
+impl rust2dart::IntoIntoDart<WasmLinker> for WasmLinker {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for WasmRunInstanceId {
     fn into_dart(self) -> support::DartAbi {
         vec![self.0.into_into_dart().into_dart()].into_dart()
@@ -1722,7 +2651,12 @@ impl rust2dart::IntoIntoDart<WasmRunInstanceId> for WasmRunInstanceId {
 
 impl support::IntoDart for WasmRunModuleId {
     fn into_dart(self) -> support::DartAbi {
-        vec![self.0.into_into_dart().into_dart(), self.1.into_dart()].into_dart()
+        vec![
+            self.0.into_into_dart().into_dart(),
+            self.1.into_dart(),
+            self.2.into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for WasmRunModuleId {}
@@ -1752,6 +2686,7 @@ impl support::IntoDart for WasmRuntimeFeatures {
             self.is_browser.into_into_dart().into_dart(),
             self.supported_features.into_into_dart().into_dart(),
             self.default_features.into_into_dart().into_dart(),
+            self.stability.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1832,6 +2767,26 @@ mod web {
         wire_parse_wat_format_impl(port_, wat)
     }
 
+    #[wasm_bindgen]
+    pub fn wire_clamp_memory(module_wasm: Box<[u8]>, max_pages: u64) -> support::WireSyncReturn {
+        wire_clamp_memory_impl(module_wasm, max_pages)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_module_has_start(module_wasm: Box<[u8]>) -> support::WireSyncReturn {
+        wire_module_has_start_impl(module_wasm)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_module_content_hash(module_wasm: Box<[u8]>) -> support::WireSyncReturn {
+        wire_module_content_hash_impl(module_wasm)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_create_engine(config: JsValue) -> support::WireSyncReturn {
+        wire_create_engine_impl(config)
+    }
+
     #[wasm_bindgen]
     pub fn wire_compile_wasm(port_: MessagePort, module_wasm: Box<[u8]>, config: JsValue) {
         wire_compile_wasm_impl(port_, module_wasm, config)
@@ -1845,6 +2800,21 @@ mod web {
         wire_compile_wasm_sync_impl(module_wasm, config)
     }
 
+    #[wasm_bindgen]
+    pub fn wire_create_compile_handle() -> support::WireSyncReturn {
+        wire_create_compile_handle_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_compile_wasm_cancelable(
+        port_: MessagePort,
+        module_wasm: Box<[u8]>,
+        config: JsValue,
+        handle: JsValue,
+    ) {
+        wire_compile_wasm_cancelable_impl(port_, module_wasm, config, handle)
+    }
+
     #[wasm_bindgen]
     pub fn wire_wasm_features_for_config(config: JsValue) -> support::WireSyncReturn {
         wire_wasm_features_for_config_impl(config)
@@ -1880,6 +2850,15 @@ mod web {
         wire_link_imports__method__WasmRunModuleId_impl(that, imports)
     }
 
+    #[wasm_bindgen]
+    pub fn wire_link_module__method__WasmRunModuleId(
+        that: JsValue,
+        namespace: String,
+        module: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_link_module__method__WasmRunModuleId_impl(that, namespace, module)
+    }
+
     #[wasm_bindgen]
     pub fn wire_stdio_stream__method__WasmRunModuleId(
         port_: MessagePort,
@@ -1889,6 +2868,50 @@ mod web {
         wire_stdio_stream__method__WasmRunModuleId_impl(port_, that, kind)
     }
 
+    #[wasm_bindgen]
+    pub fn wire_wasi_denied_stream__method__WasmRunModuleId(port_: MessagePort, that: JsValue) {
+        wire_wasi_denied_stream__method__WasmRunModuleId_impl(port_, that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_read_stdio__method__WasmRunModuleId(
+        that: JsValue,
+        kind: i32,
+    ) -> support::WireSyncReturn {
+        wire_read_stdio__method__WasmRunModuleId_impl(that, kind)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_next_stdio_chunk__method__WasmRunModuleId(
+        port_: MessagePort,
+        that: JsValue,
+        kind: i32,
+        timeout_millis: u64,
+    ) {
+        wire_next_stdio_chunk__method__WasmRunModuleId_impl(port_, that, kind, timeout_millis)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_stdio_dropped_bytes__method__WasmRunModuleId(
+        that: JsValue,
+        kind: i32,
+    ) -> support::WireSyncReturn {
+        wire_stdio_dropped_bytes__method__WasmRunModuleId_impl(that, kind)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_close_stdio__method__WasmRunModuleId(
+        that: JsValue,
+        kind: i32,
+    ) -> support::WireSyncReturn {
+        wire_close_stdio__method__WasmRunModuleId_impl(that, kind)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_close__method__WasmRunModuleId(that: JsValue) -> support::WireSyncReturn {
+        wire_close__method__WasmRunModuleId_impl(that)
+    }
+
     #[wasm_bindgen]
     pub fn wire_dispose__method__WasmRunModuleId(port_: MessagePort, that: JsValue) {
         wire_dispose__method__WasmRunModuleId_impl(port_, that)
@@ -1913,6 +2936,75 @@ mod web {
         wire_call_function_handle__method__WasmRunModuleId_impl(port_, that, func, args)
     }
 
+    #[wasm_bindgen]
+    pub fn wire_call_by_index__method__WasmRunModuleId(
+        that: JsValue,
+        index: u32,
+        args: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_call_by_index__method__WasmRunModuleId_impl(that, index, args)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_call_wasi_start__method__WasmRunModuleId(
+        that: JsValue,
+        func: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_call_wasi_start__method__WasmRunModuleId_impl(that, func)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_initialize__method__WasmRunModuleId(that: JsValue) -> support::WireSyncReturn {
+        wire_initialize__method__WasmRunModuleId_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_call_export__method__WasmRunModuleId(
+        that: JsValue,
+        name: String,
+        args: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_call_export__method__WasmRunModuleId_impl(that, name, args)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_guest_alloc__method__WasmRunModuleId(
+        that: JsValue,
+        size: u32,
+        alloc_export: Option<String>,
+    ) -> support::WireSyncReturn {
+        wire_guest_alloc__method__WasmRunModuleId_impl(that, size, alloc_export)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_guest_free__method__WasmRunModuleId(
+        that: JsValue,
+        ptr: u32,
+        size: u32,
+        free_export: Option<String>,
+    ) -> support::WireSyncReturn {
+        wire_guest_free__method__WasmRunModuleId_impl(that, ptr, size, free_export)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_write_guest_string__method__WasmRunModuleId(
+        that: JsValue,
+        value: String,
+        memory_name: Option<String>,
+    ) -> support::WireSyncReturn {
+        wire_write_guest_string__method__WasmRunModuleId_impl(that, value, memory_name)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_read_guest_string__method__WasmRunModuleId(
+        that: JsValue,
+        ptr: u32,
+        len: u32,
+        memory_name: Option<String>,
+    ) -> support::WireSyncReturn {
+        wire_read_guest_string__method__WasmRunModuleId_impl(that, ptr, len, memory_name)
+    }
+
     #[wasm_bindgen]
     pub fn wire_call_function_handle_parallel__method__WasmRunModuleId(
         port_: MessagePort,
@@ -2097,86 +3189,258 @@ mod web {
     }
 
     #[wasm_bindgen]
-    pub fn wire_grow_table__method__WasmRunModuleId(
+    pub fn wire_grow_table__method__WasmRunModuleId(
+        that: JsValue,
+        table: JsValue,
+        delta: u32,
+        value: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_grow_table__method__WasmRunModuleId_impl(that, table, delta, value)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_get_table__method__WasmRunModuleId(
+        that: JsValue,
+        table: JsValue,
+        index: u32,
+    ) -> support::WireSyncReturn {
+        wire_get_table__method__WasmRunModuleId_impl(that, table, index)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_set_table__method__WasmRunModuleId(
+        that: JsValue,
+        table: JsValue,
+        index: u32,
+        value: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_set_table__method__WasmRunModuleId_impl(that, table, index, value)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_fill_table__method__WasmRunModuleId(
+        that: JsValue,
+        table: JsValue,
+        index: u32,
+        value: JsValue,
+        len: u32,
+    ) -> support::WireSyncReturn {
+        wire_fill_table__method__WasmRunModuleId_impl(that, table, index, value, len)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_set_max_host_call_depth__method__WasmRunModuleId(
+        that: JsValue,
+        max_depth: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_set_max_host_call_depth__method__WasmRunModuleId_impl(that, max_depth)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_set_epoch_deadline__method__WasmRunModuleId(
+        that: JsValue,
+        ticks_beyond_current: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_set_epoch_deadline__method__WasmRunModuleId_impl(that, ticks_beyond_current)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_add_fuel__method__WasmRunModuleId(
+        that: JsValue,
+        delta: u64,
+    ) -> support::WireSyncReturn {
+        wire_add_fuel__method__WasmRunModuleId_impl(that, delta)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_fuel_consumed__method__WasmRunModuleId(that: JsValue) -> support::WireSyncReturn {
+        wire_fuel_consumed__method__WasmRunModuleId_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_consume_fuel__method__WasmRunModuleId(
+        that: JsValue,
+        delta: u64,
+    ) -> support::WireSyncReturn {
+        wire_consume_fuel__method__WasmRunModuleId_impl(that, delta)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_last_call_fuel_consumed__method__WasmRunModuleId(
+        that: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_last_call_fuel_consumed__method__WasmRunModuleId_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_last_call_duration_micros__method__WasmRunModuleId(
+        that: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_last_call_duration_micros__method__WasmRunModuleId_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_memory_footprint__method__WasmRunModuleId(
+        that: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_memory_footprint__method__WasmRunModuleId_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_memories__method__WasmRunModuleId(that: JsValue) -> support::WireSyncReturn {
+        wire_memories__method__WasmRunModuleId_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_instantiate__method__CompiledModule(
+        that: JsValue,
+        wasi_config: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_instantiate__method__CompiledModule_impl(that, wasi_config)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_create_shared_memory__method__CompiledModule(
+        that: JsValue,
+        memory_type: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_create_shared_memory__method__CompiledModule_impl(that, memory_type)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_get_module_imports__method__CompiledModule(
+        that: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_get_module_imports__method__CompiledModule_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_wasi_module_kind__method__CompiledModule(that: JsValue) -> support::WireSyncReturn {
+        wire_wasi_module_kind__method__CompiledModule_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_get_module_exports__method__CompiledModule(
+        that: JsValue,
+    ) -> support::WireSyncReturn {
+        wire_get_module_exports__method__CompiledModule_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_compile_wasm__method__WasmEngine(
+        port_: MessagePort,
+        that: JsValue,
+        module_wasm: Box<[u8]>,
+    ) {
+        wire_compile_wasm__method__WasmEngine_impl(port_, that, module_wasm)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_compile_wasm_sync__method__WasmEngine(
+        that: JsValue,
+        module_wasm: Box<[u8]>,
+    ) -> support::WireSyncReturn {
+        wire_compile_wasm_sync__method__WasmEngine_impl(that, module_wasm)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_increment_epoch__method__WasmEngine(that: JsValue) -> support::WireSyncReturn {
+        wire_increment_epoch__method__WasmEngine_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_create_linker__method__WasmEngine(
         that: JsValue,
-        table: JsValue,
-        delta: u32,
-        value: JsValue,
+        wasi: bool,
     ) -> support::WireSyncReturn {
-        wire_grow_table__method__WasmRunModuleId_impl(that, table, delta, value)
+        wire_create_linker__method__WasmEngine_impl(that, wasi)
     }
 
     #[wasm_bindgen]
-    pub fn wire_get_table__method__WasmRunModuleId(
+    pub fn wire_create_module_cache__method__WasmEngine(
         that: JsValue,
-        table: JsValue,
-        index: u32,
+        max_entries: JsValue,
+        max_bytes: JsValue,
+        directory: Option<String>,
     ) -> support::WireSyncReturn {
-        wire_get_table__method__WasmRunModuleId_impl(that, table, index)
+        wire_create_module_cache__method__WasmEngine_impl(that, max_entries, max_bytes, directory)
     }
 
     #[wasm_bindgen]
-    pub fn wire_set_table__method__WasmRunModuleId(
+    pub fn wire_define_host_function__method__WasmLinker(
         that: JsValue,
-        table: JsValue,
-        index: u32,
-        value: JsValue,
+        module: String,
+        name: String,
+        function_pointer: usize,
+        function_id: u32,
+        param_types: JsValue,
+        result_types: JsValue,
     ) -> support::WireSyncReturn {
-        wire_set_table__method__WasmRunModuleId_impl(that, table, index, value)
+        wire_define_host_function__method__WasmLinker_impl(
+            that,
+            module,
+            name,
+            function_pointer,
+            function_id,
+            param_types,
+            result_types,
+        )
     }
 
     #[wasm_bindgen]
-    pub fn wire_fill_table__method__WasmRunModuleId(
+    pub fn wire_define_memory__method__WasmLinker(
         that: JsValue,
-        table: JsValue,
-        index: u32,
-        value: JsValue,
-        len: u32,
+        module: String,
+        name: String,
+        memory_type: JsValue,
     ) -> support::WireSyncReturn {
-        wire_fill_table__method__WasmRunModuleId_impl(that, table, index, value, len)
+        wire_define_memory__method__WasmLinker_impl(that, module, name, memory_type)
     }
 
     #[wasm_bindgen]
-    pub fn wire_add_fuel__method__WasmRunModuleId(
+    pub fn wire_module_builder__method__WasmLinker(
         that: JsValue,
-        delta: u64,
+        module: JsValue,
+        wasi_config: JsValue,
     ) -> support::WireSyncReturn {
-        wire_add_fuel__method__WasmRunModuleId_impl(that, delta)
+        wire_module_builder__method__WasmLinker_impl(that, module, wasi_config)
     }
 
     #[wasm_bindgen]
-    pub fn wire_fuel_consumed__method__WasmRunModuleId(that: JsValue) -> support::WireSyncReturn {
-        wire_fuel_consumed__method__WasmRunModuleId_impl(that)
+    pub fn wire_compile_wasm__method__ModuleCache(
+        port_: MessagePort,
+        that: JsValue,
+        module_wasm: Box<[u8]>,
+    ) {
+        wire_compile_wasm__method__ModuleCache_impl(port_, that, module_wasm)
     }
 
     #[wasm_bindgen]
-    pub fn wire_consume_fuel__method__WasmRunModuleId(
+    pub fn wire_compile_wasm_sync__method__ModuleCache(
         that: JsValue,
-        delta: u64,
+        module_wasm: Box<[u8]>,
     ) -> support::WireSyncReturn {
-        wire_consume_fuel__method__WasmRunModuleId_impl(that, delta)
+        wire_compile_wasm_sync__method__ModuleCache_impl(that, module_wasm)
     }
 
     #[wasm_bindgen]
-    pub fn wire_create_shared_memory__method__CompiledModule(
-        that: JsValue,
-        memory_type: JsValue,
-    ) -> support::WireSyncReturn {
-        wire_create_shared_memory__method__CompiledModule_impl(that, memory_type)
+    pub fn wire_stats__method__ModuleCache(that: JsValue) -> support::WireSyncReturn {
+        wire_stats__method__ModuleCache_impl(that)
     }
 
     #[wasm_bindgen]
-    pub fn wire_get_module_imports__method__CompiledModule(
-        that: JsValue,
-    ) -> support::WireSyncReturn {
-        wire_get_module_imports__method__CompiledModule_impl(that)
+    pub fn wire_clear__method__ModuleCache(that: JsValue) -> support::WireSyncReturn {
+        wire_clear__method__ModuleCache_impl(that)
     }
 
     #[wasm_bindgen]
-    pub fn wire_get_module_exports__method__CompiledModule(
-        that: JsValue,
-    ) -> support::WireSyncReturn {
-        wire_get_module_exports__method__CompiledModule_impl(that)
+    pub fn wire_cancel__method__CompileHandle(that: JsValue) -> support::WireSyncReturn {
+        wire_cancel__method__CompileHandle_impl(that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire_is_cancelled__method__CompileHandle(that: JsValue) -> support::WireSyncReturn {
+        wire_is_cancelled__method__CompileHandle_impl(that)
     }
 
     #[wasm_bindgen]
@@ -2356,6 +3620,21 @@ mod web {
 
     // Section: related functions
 
+    #[wasm_bindgen]
+    pub fn drop_opaque_ArcAtomicBool(ptr: *const c_void) {
+        unsafe {
+            Arc::<Arc<AtomicBool>>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn share_opaque_ArcAtomicBool(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<Arc<AtomicBool>>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[wasm_bindgen]
     pub fn drop_opaque_ArcRwLockSharedMemory(ptr: *const c_void) {
         unsafe {
@@ -2371,6 +3650,21 @@ mod web {
         }
     }
 
+    #[wasm_bindgen]
+    pub fn drop_opaque_ArcStdSyncMutexLinkerTemplate(ptr: *const c_void) {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<LinkerTemplate>>>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn share_opaque_ArcStdSyncMutexLinkerTemplate(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<LinkerTemplate>>>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[wasm_bindgen]
     pub fn drop_opaque_ArcStdSyncMutexModule(ptr: *const c_void) {
         unsafe {
@@ -2386,6 +3680,21 @@ mod web {
         }
     }
 
+    #[wasm_bindgen]
+    pub fn drop_opaque_ArcStdSyncMutexModuleCacheImpl(ptr: *const c_void) {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<ModuleCacheImpl>>>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn share_opaque_ArcStdSyncMutexModuleCacheImpl(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<ModuleCacheImpl>>>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[wasm_bindgen]
     pub fn drop_opaque_CallStack(ptr: *const c_void) {
         unsafe {
@@ -2431,6 +3740,21 @@ mod web {
         }
     }
 
+    #[wasm_bindgen]
+    pub fn drop_opaque_StdioCapture(ptr: *const c_void) {
+        unsafe {
+            Arc::<StdioCapture>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn share_opaque_StdioCapture(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<StdioCapture>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[wasm_bindgen]
     pub fn drop_opaque_Table(ptr: *const c_void) {
         unsafe {
@@ -2446,6 +3770,21 @@ mod web {
         }
     }
 
+    #[wasm_bindgen]
+    pub fn drop_opaque_WEngine(ptr: *const c_void) {
+        unsafe {
+            Arc::<WEngine>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn share_opaque_WEngine(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<WEngine>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[wasm_bindgen]
     pub fn drop_opaque_WFunc(ptr: *const c_void) {
         unsafe {
@@ -2491,6 +3830,18 @@ mod web {
         }
     }
 
+    impl Wire2Api<CompileHandle> for JsValue {
+        fn wire2api(self) -> CompileHandle {
+            let self_ = self.dyn_into::<JsArray>().unwrap();
+            assert_eq!(
+                self_.length(),
+                1,
+                "Expected 1 elements, got {}",
+                self_.length()
+            );
+            CompileHandle(self_.get(0).wire2api())
+        }
+    }
     impl Wire2Api<CompiledModule> for JsValue {
         fn wire2api(self) -> CompiledModule {
             let self_ = self.dyn_into::<JsArray>().unwrap();
@@ -2559,6 +3910,15 @@ mod web {
                 .collect()
         }
     }
+    impl Wire2Api<Vec<ProposalToggle>> for JsValue {
+        fn wire2api(self) -> Vec<ProposalToggle> {
+            self.dyn_into::<JsArray>()
+                .unwrap()
+                .iter()
+                .map(Wire2Api::wire2api)
+                .collect()
+        }
+    }
     impl Wire2Api<Vec<ValueTy>> for JsValue {
         fn wire2api(self) -> Vec<ValueTy> {
             self.dyn_into::<JsArray>()
@@ -2593,13 +3953,25 @@ mod web {
             }
         }
     }
+    impl Wire2Api<ModuleCache> for JsValue {
+        fn wire2api(self) -> ModuleCache {
+            let self_ = self.dyn_into::<JsArray>().unwrap();
+            assert_eq!(
+                self_.length(),
+                1,
+                "Expected 1 elements, got {}",
+                self_.length()
+            );
+            ModuleCache(self_.get(0).wire2api())
+        }
+    }
     impl Wire2Api<ModuleConfig> for JsValue {
         fn wire2api(self) -> ModuleConfig {
             let self_ = self.dyn_into::<JsArray>().unwrap();
             assert_eq!(
                 self_.length(),
-                6,
-                "Expected 6 elements, got {}",
+                14,
+                "Expected 14 elements, got {}",
                 self_.length()
             );
             ModuleConfig {
@@ -2609,6 +3981,14 @@ mod web {
                 consume_fuel: self_.get(3).wire2api(),
                 wasmi: self_.get(4).wire2api(),
                 wasmtime: self_.get(5).wire2api(),
+                max_module_bytes: self_.get(6).wire2api(),
+                max_memory_pages: self_.get(7).wire2api(),
+                max_memories: self_.get(8).wire2api(),
+                max_tables: self_.get(9).wire2api(),
+                max_globals: self_.get(10).wire2api(),
+                max_functions: self_.get(11).wire2api(),
+                trap_on_integer_overflow: self_.get(12).wire2api(),
+                deterministic: self_.get(13).wire2api(),
             }
         }
     }
@@ -2617,8 +3997,8 @@ mod web {
             let self_ = self.dyn_into::<JsArray>().unwrap();
             assert_eq!(
                 self_.length(),
-                8,
-                "Expected 8 elements, got {}",
+                9,
+                "Expected 9 elements, got {}",
                 self_.length()
             );
             ModuleConfigWasmi {
@@ -2630,6 +4010,7 @@ mod web {
                 tail_call: self_.get(5).wire2api(),
                 extended_const: self_.get(6).wire2api(),
                 floats: self_.get(7).wire2api(),
+                fuel_consumption_mode: self_.get(8).wire2api(),
             }
         }
     }
@@ -2638,26 +4019,36 @@ mod web {
             let self_ = self.dyn_into::<JsArray>().unwrap();
             assert_eq!(
                 self_.length(),
-                15,
-                "Expected 15 elements, got {}",
+                25,
+                "Expected 25 elements, got {}",
                 self_.length()
             );
             ModuleConfigWasmtime {
                 debug_info: self_.get(0).wire2api(),
                 wasm_backtrace: self_.get(1).wire2api(),
                 native_unwind_info: self_.get(2).wire2api(),
-                max_wasm_stack: self_.get(3).wire2api(),
-                wasm_threads: self_.get(4).wire2api(),
-                wasm_simd: self_.get(5).wire2api(),
-                wasm_relaxed_simd: self_.get(6).wire2api(),
-                relaxed_simd_deterministic: self_.get(7).wire2api(),
-                wasm_multi_memory: self_.get(8).wire2api(),
-                wasm_memory64: self_.get(9).wire2api(),
-                static_memory_maximum_size: self_.get(10).wire2api(),
-                static_memory_forced: self_.get(11).wire2api(),
-                static_memory_guard_size: self_.get(12).wire2api(),
-                parallel_compilation: self_.get(13).wire2api(),
-                generate_address_map: self_.get(14).wire2api(),
+                epoch_interruption: self_.get(3).wire2api(),
+                max_wasm_stack: self_.get(4).wire2api(),
+                wasm_threads: self_.get(5).wire2api(),
+                wasm_simd: self_.get(6).wire2api(),
+                wasm_relaxed_simd: self_.get(7).wire2api(),
+                relaxed_simd_deterministic: self_.get(8).wire2api(),
+                cranelift_nan_canonicalization: self_.get(9).wire2api(),
+                wasm_multi_memory: self_.get(10).wire2api(),
+                wasm_memory64: self_.get(11).wire2api(),
+                wasm_tail_call: self_.get(12).wire2api(),
+                wasm_function_references: self_.get(13).wire2api(),
+                wasm_memory_control: self_.get(14).wire2api(),
+                wasm_gc: self_.get(15).wire2api(),
+                static_memory_maximum_size: self_.get(16).wire2api(),
+                static_memory_forced: self_.get(17).wire2api(),
+                static_memory_guard_size: self_.get(18).wire2api(),
+                parallel_compilation: self_.get(19).wire2api(),
+                compilation_thread_stack_size: self_.get(20).wire2api(),
+                generate_address_map: self_.get(21).wire2api(),
+                disable_guard_pages: self_.get(22).wire2api(),
+                macos_use_mach_ports: self_.get(23).wire2api(),
+                extra_proposals: self_.get(24).wire2api(),
             }
         }
     }
@@ -2677,19 +4068,41 @@ mod web {
             }
         }
     }
+    impl Wire2Api<Option<String>> for Option<String> {
+        fn wire2api(self) -> Option<String> {
+            self.map(Wire2Api::wire2api)
+        }
+    }
 
     impl Wire2Api<PreopenedDir> for JsValue {
         fn wire2api(self) -> PreopenedDir {
             let self_ = self.dyn_into::<JsArray>().unwrap();
             assert_eq!(
                 self_.length(),
-                2,
-                "Expected 2 elements, got {}",
+                4,
+                "Expected 4 elements, got {}",
                 self_.length()
             );
             PreopenedDir {
                 wasm_guest_path: self_.get(0).wire2api(),
                 host_path: self_.get(1).wire2api(),
+                rights: self_.get(2).wire2api(),
+                overlay: self_.get(3).wire2api(),
+            }
+        }
+    }
+    impl Wire2Api<ProposalToggle> for JsValue {
+        fn wire2api(self) -> ProposalToggle {
+            let self_ = self.dyn_into::<JsArray>().unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            ProposalToggle {
+                name: self_.get(0).wire2api(),
+                enabled: self_.get(1).wire2api(),
             }
         }
     }
@@ -2727,20 +4140,25 @@ mod web {
             let self_ = self.dyn_into::<JsArray>().unwrap();
             assert_eq!(
                 self_.length(),
-                9,
-                "Expected 9 elements, got {}",
+                14,
+                "Expected 14 elements, got {}",
                 self_.length()
             );
             WasiConfigNative {
                 capture_stdout: self_.get(0).wire2api(),
                 capture_stderr: self_.get(1).wire2api(),
-                inherit_stdin: self_.get(2).wire2api(),
-                inherit_env: self_.get(3).wire2api(),
-                inherit_args: self_.get(4).wire2api(),
-                args: self_.get(5).wire2api(),
-                env: self_.get(6).wire2api(),
-                preopened_files: self_.get(7).wire2api(),
-                preopened_dirs: self_.get(8).wire2api(),
+                line_buffered_output: self_.get(2).wire2api(),
+                inherit_stdin: self_.get(3).wire2api(),
+                inherit_env: self_.get(4).wire2api(),
+                inherit_args: self_.get(5).wire2api(),
+                args: self_.get(6).wire2api(),
+                env: self_.get(7).wire2api(),
+                preopened_files: self_.get(8).wire2api(),
+                preopened_dirs: self_.get(9).wire2api(),
+                capture_capacity: self_.get(10).wire2api(),
+                capture_overflow: self_.get(11).wire2api(),
+                deterministic: self_.get(12).wire2api(),
+                unknown_errno: self_.get(13).wire2api(),
             }
         }
     }
@@ -2760,6 +4178,30 @@ mod web {
             }
         }
     }
+    impl Wire2Api<WasmEngine> for JsValue {
+        fn wire2api(self) -> WasmEngine {
+            let self_ = self.dyn_into::<JsArray>().unwrap();
+            assert_eq!(
+                self_.length(),
+                1,
+                "Expected 1 elements, got {}",
+                self_.length()
+            );
+            WasmEngine(self_.get(0).wire2api())
+        }
+    }
+    impl Wire2Api<WasmLinker> for JsValue {
+        fn wire2api(self) -> WasmLinker {
+            let self_ = self.dyn_into::<JsArray>().unwrap();
+            assert_eq!(
+                self_.length(),
+                1,
+                "Expected 1 elements, got {}",
+                self_.length()
+            );
+            WasmLinker(self_.get(0).wire2api())
+        }
+    }
     impl Wire2Api<WasmRunInstanceId> for JsValue {
         fn wire2api(self) -> WasmRunInstanceId {
             let self_ = self.dyn_into::<JsArray>().unwrap();
@@ -2777,11 +4219,15 @@ mod web {
             let self_ = self.dyn_into::<JsArray>().unwrap();
             assert_eq!(
                 self_.length(),
-                2,
-                "Expected 2 elements, got {}",
+                3,
+                "Expected 3 elements, got {}",
                 self_.length()
             );
-            WasmRunModuleId(self_.get(0).wire2api(), self_.get(1).wire2api())
+            WasmRunModuleId(
+                self_.get(0).wire2api(),
+                self_.get(1).wire2api(),
+                self_.get(2).wire2api(),
+            )
         }
     }
     impl Wire2Api<WasmRunSharedMemory> for JsValue {
@@ -2821,6 +4267,16 @@ mod web {
             (!self.is_null() && !self.is_undefined()).then(|| self.wire2api())
         }
     }
+    impl Wire2Api<RustOpaque<Arc<AtomicBool>>> for JsValue {
+        fn wire2api(self) -> RustOpaque<Arc<AtomicBool>> {
+            #[cfg(target_pointer_width = "64")]
+            {
+                compile_error!("64-bit pointers are not supported.");
+            }
+
+            unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<Arc<RwLock<SharedMemory>>>> for JsValue {
         fn wire2api(self) -> RustOpaque<Arc<RwLock<SharedMemory>>> {
             #[cfg(target_pointer_width = "64")]
@@ -2831,6 +4287,16 @@ mod web {
             unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
         }
     }
+    impl Wire2Api<RustOpaque<Arc<std::sync::Mutex<LinkerTemplate>>>> for JsValue {
+        fn wire2api(self) -> RustOpaque<Arc<std::sync::Mutex<LinkerTemplate>>> {
+            #[cfg(target_pointer_width = "64")]
+            {
+                compile_error!("64-bit pointers are not supported.");
+            }
+
+            unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<Arc<std::sync::Mutex<Module>>>> for JsValue {
         fn wire2api(self) -> RustOpaque<Arc<std::sync::Mutex<Module>>> {
             #[cfg(target_pointer_width = "64")]
@@ -2841,6 +4307,16 @@ mod web {
             unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
         }
     }
+    impl Wire2Api<RustOpaque<Arc<std::sync::Mutex<ModuleCacheImpl>>>> for JsValue {
+        fn wire2api(self) -> RustOpaque<Arc<std::sync::Mutex<ModuleCacheImpl>>> {
+            #[cfg(target_pointer_width = "64")]
+            {
+                compile_error!("64-bit pointers are not supported.");
+            }
+
+            unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<CallStack>> for JsValue {
         fn wire2api(self) -> RustOpaque<CallStack> {
             #[cfg(target_pointer_width = "64")]
@@ -2871,6 +4347,16 @@ mod web {
             unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
         }
     }
+    impl Wire2Api<RustOpaque<StdioCapture>> for JsValue {
+        fn wire2api(self) -> RustOpaque<StdioCapture> {
+            #[cfg(target_pointer_width = "64")]
+            {
+                compile_error!("64-bit pointers are not supported.");
+            }
+
+            unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+        }
+    }
     impl Wire2Api<String> for JsValue {
         fn wire2api(self) -> String {
             self.as_string().expect("non-UTF-8 string, or not a string")
@@ -2886,6 +4372,16 @@ mod web {
             unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
         }
     }
+    impl Wire2Api<RustOpaque<WEngine>> for JsValue {
+        fn wire2api(self) -> RustOpaque<WEngine> {
+            #[cfg(target_pointer_width = "64")]
+            {
+                compile_error!("64-bit pointers are not supported.");
+            }
+
+            unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<WFunc>> for JsValue {
         fn wire2api(self) -> RustOpaque<WFunc> {
             #[cfg(target_pointer_width = "64")]
@@ -2911,6 +4407,11 @@ mod web {
             self.is_truthy()
         }
     }
+    impl Wire2Api<CaptureOverflow> for JsValue {
+        fn wire2api(self) -> CaptureOverflow {
+            (self.unchecked_into_f64() as i32).wire2api()
+        }
+    }
     impl Wire2Api<f32> for JsValue {
         fn wire2api(self) -> f32 {
             self.unchecked_into_f64() as _
@@ -2921,6 +4422,11 @@ mod web {
             self.unchecked_into_f64() as _
         }
     }
+    impl Wire2Api<FuelConsumptionMode> for JsValue {
+        fn wire2api(self) -> FuelConsumptionMode {
+            (self.unchecked_into_f64() as i32).wire2api()
+        }
+    }
     impl Wire2Api<i32> for JsValue {
         fn wire2api(self) -> i32 {
             self.unchecked_into_f64() as _
@@ -2936,6 +4442,11 @@ mod web {
             (self.unchecked_into_f64() as i32).wire2api()
         }
     }
+    impl Wire2Api<u16> for JsValue {
+        fn wire2api(self) -> u16 {
+            self.unchecked_into_f64() as _
+        }
+    }
     impl Wire2Api<u32> for JsValue {
         fn wire2api(self) -> u32 {
             self.unchecked_into_f64() as _
@@ -2974,7 +4485,7 @@ mod web {
     }
 }
 #[cfg(target_family = "wasm")]
-pub use web::*;
+pub use self::web::*;
 
 #[cfg(not(target_family = "wasm"))]
 mod io {
@@ -2995,6 +4506,35 @@ mod io {
         wire_parse_wat_format_impl(port_, wat)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_clamp_memory(
+        module_wasm: *mut wire_uint_8_list,
+        max_pages: u64,
+    ) -> support::WireSyncReturn {
+        wire_clamp_memory_impl(module_wasm, max_pages)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_module_has_start(
+        module_wasm: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_module_has_start_impl(module_wasm)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_module_content_hash(
+        module_wasm: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_module_content_hash_impl(module_wasm)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_create_engine(
+        config: *mut wire_ModuleConfig,
+    ) -> support::WireSyncReturn {
+        wire_create_engine_impl(config)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_compile_wasm(
         port_: i64,
@@ -3012,6 +4552,21 @@ mod io {
         wire_compile_wasm_sync_impl(module_wasm, config)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_create_compile_handle() -> support::WireSyncReturn {
+        wire_create_compile_handle_impl()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_compile_wasm_cancelable(
+        port_: i64,
+        module_wasm: *mut wire_uint_8_list,
+        config: *mut wire_ModuleConfig,
+        handle: *mut wire_CompileHandle,
+    ) {
+        wire_compile_wasm_cancelable_impl(port_, module_wasm, config, handle)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_wasm_features_for_config(
         config: *mut wire_ModuleConfig,
@@ -3054,6 +4609,15 @@ mod io {
         wire_link_imports__method__WasmRunModuleId_impl(that, imports)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_link_module__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        namespace: *mut wire_uint_8_list,
+        module: *mut wire_CompiledModule,
+    ) -> support::WireSyncReturn {
+        wire_link_module__method__WasmRunModuleId_impl(that, namespace, module)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_stdio_stream__method__WasmRunModuleId(
         port_: i64,
@@ -3063,6 +4627,55 @@ mod io {
         wire_stdio_stream__method__WasmRunModuleId_impl(port_, that, kind)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_wasi_denied_stream__method__WasmRunModuleId(
+        port_: i64,
+        that: *mut wire_WasmRunModuleId,
+    ) {
+        wire_wasi_denied_stream__method__WasmRunModuleId_impl(port_, that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_read_stdio__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        kind: i32,
+    ) -> support::WireSyncReturn {
+        wire_read_stdio__method__WasmRunModuleId_impl(that, kind)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_next_stdio_chunk__method__WasmRunModuleId(
+        port_: i64,
+        that: *mut wire_WasmRunModuleId,
+        kind: i32,
+        timeout_millis: u64,
+    ) {
+        wire_next_stdio_chunk__method__WasmRunModuleId_impl(port_, that, kind, timeout_millis)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_stdio_dropped_bytes__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        kind: i32,
+    ) -> support::WireSyncReturn {
+        wire_stdio_dropped_bytes__method__WasmRunModuleId_impl(that, kind)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_close_stdio__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        kind: i32,
+    ) -> support::WireSyncReturn {
+        wire_close_stdio__method__WasmRunModuleId_impl(that, kind)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_close__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+    ) -> support::WireSyncReturn {
+        wire_close__method__WasmRunModuleId_impl(that)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_dispose__method__WasmRunModuleId(
         port_: i64,
@@ -3090,6 +4703,77 @@ mod io {
         wire_call_function_handle__method__WasmRunModuleId_impl(port_, that, func, args)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_call_by_index__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        index: u32,
+        args: *mut wire_list_wasm_val,
+    ) -> support::WireSyncReturn {
+        wire_call_by_index__method__WasmRunModuleId_impl(that, index, args)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_call_wasi_start__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        func: wire_WFunc,
+    ) -> support::WireSyncReturn {
+        wire_call_wasi_start__method__WasmRunModuleId_impl(that, func)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_initialize__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+    ) -> support::WireSyncReturn {
+        wire_initialize__method__WasmRunModuleId_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_call_export__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        name: *mut wire_uint_8_list,
+        args: *mut wire_list_wasm_val,
+    ) -> support::WireSyncReturn {
+        wire_call_export__method__WasmRunModuleId_impl(that, name, args)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_guest_alloc__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        size: u32,
+        alloc_export: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_guest_alloc__method__WasmRunModuleId_impl(that, size, alloc_export)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_guest_free__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        ptr: u32,
+        size: u32,
+        free_export: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_guest_free__method__WasmRunModuleId_impl(that, ptr, size, free_export)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_write_guest_string__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        value: *mut wire_uint_8_list,
+        memory_name: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_write_guest_string__method__WasmRunModuleId_impl(that, value, memory_name)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_read_guest_string__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        ptr: u32,
+        len: u32,
+        memory_name: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_read_guest_string__method__WasmRunModuleId_impl(that, ptr, len, memory_name)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_call_function_handle_parallel__method__WasmRunModuleId(
         port_: i64,
@@ -3313,6 +4997,22 @@ mod io {
         wire_fill_table__method__WasmRunModuleId_impl(that, table, index, value, len)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_set_max_host_call_depth__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        max_depth: *mut u32,
+    ) -> support::WireSyncReturn {
+        wire_set_max_host_call_depth__method__WasmRunModuleId_impl(that, max_depth)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_set_epoch_deadline__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+        ticks_beyond_current: *mut u64,
+    ) -> support::WireSyncReturn {
+        wire_set_epoch_deadline__method__WasmRunModuleId_impl(that, ticks_beyond_current)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_add_fuel__method__WasmRunModuleId(
         that: *mut wire_WasmRunModuleId,
@@ -3336,6 +5036,42 @@ mod io {
         wire_consume_fuel__method__WasmRunModuleId_impl(that, delta)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_last_call_fuel_consumed__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+    ) -> support::WireSyncReturn {
+        wire_last_call_fuel_consumed__method__WasmRunModuleId_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_last_call_duration_micros__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+    ) -> support::WireSyncReturn {
+        wire_last_call_duration_micros__method__WasmRunModuleId_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_memory_footprint__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+    ) -> support::WireSyncReturn {
+        wire_memory_footprint__method__WasmRunModuleId_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_memories__method__WasmRunModuleId(
+        that: *mut wire_WasmRunModuleId,
+    ) -> support::WireSyncReturn {
+        wire_memories__method__WasmRunModuleId_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_instantiate__method__CompiledModule(
+        that: *mut wire_CompiledModule,
+        wasi_config: *mut wire_WasiConfigNative,
+    ) -> support::WireSyncReturn {
+        wire_instantiate__method__CompiledModule_impl(that, wasi_config)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_create_shared_memory__method__CompiledModule(
         that: *mut wire_CompiledModule,
@@ -3351,6 +5087,13 @@ mod io {
         wire_get_module_imports__method__CompiledModule_impl(that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_wasi_module_kind__method__CompiledModule(
+        that: *mut wire_CompiledModule,
+    ) -> support::WireSyncReturn {
+        wire_wasi_module_kind__method__CompiledModule_impl(that)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_get_module_exports__method__CompiledModule(
         that: *mut wire_CompiledModule,
@@ -3358,6 +5101,133 @@ mod io {
         wire_get_module_exports__method__CompiledModule_impl(that)
     }
 
+    #[no_mangle]
+    pub extern "C" fn wire_compile_wasm__method__WasmEngine(
+        port_: i64,
+        that: *mut wire_WasmEngine,
+        module_wasm: *mut wire_uint_8_list,
+    ) {
+        wire_compile_wasm__method__WasmEngine_impl(port_, that, module_wasm)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_compile_wasm_sync__method__WasmEngine(
+        that: *mut wire_WasmEngine,
+        module_wasm: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_compile_wasm_sync__method__WasmEngine_impl(that, module_wasm)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_increment_epoch__method__WasmEngine(
+        that: *mut wire_WasmEngine,
+    ) -> support::WireSyncReturn {
+        wire_increment_epoch__method__WasmEngine_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_create_linker__method__WasmEngine(
+        that: *mut wire_WasmEngine,
+        wasi: bool,
+    ) -> support::WireSyncReturn {
+        wire_create_linker__method__WasmEngine_impl(that, wasi)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_create_module_cache__method__WasmEngine(
+        that: *mut wire_WasmEngine,
+        max_entries: *mut u32,
+        max_bytes: *mut u64,
+        directory: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_create_module_cache__method__WasmEngine_impl(that, max_entries, max_bytes, directory)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_define_host_function__method__WasmLinker(
+        that: *mut wire_WasmLinker,
+        module: *mut wire_uint_8_list,
+        name: *mut wire_uint_8_list,
+        function_pointer: usize,
+        function_id: u32,
+        param_types: *mut wire_list_value_ty,
+        result_types: *mut wire_list_value_ty,
+    ) -> support::WireSyncReturn {
+        wire_define_host_function__method__WasmLinker_impl(
+            that,
+            module,
+            name,
+            function_pointer,
+            function_id,
+            param_types,
+            result_types,
+        )
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_define_memory__method__WasmLinker(
+        that: *mut wire_WasmLinker,
+        module: *mut wire_uint_8_list,
+        name: *mut wire_uint_8_list,
+        memory_type: *mut wire_MemoryTy,
+    ) -> support::WireSyncReturn {
+        wire_define_memory__method__WasmLinker_impl(that, module, name, memory_type)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_module_builder__method__WasmLinker(
+        that: *mut wire_WasmLinker,
+        module: *mut wire_CompiledModule,
+        wasi_config: *mut wire_WasiConfigNative,
+    ) -> support::WireSyncReturn {
+        wire_module_builder__method__WasmLinker_impl(that, module, wasi_config)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_compile_wasm__method__ModuleCache(
+        port_: i64,
+        that: *mut wire_ModuleCache,
+        module_wasm: *mut wire_uint_8_list,
+    ) {
+        wire_compile_wasm__method__ModuleCache_impl(port_, that, module_wasm)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_compile_wasm_sync__method__ModuleCache(
+        that: *mut wire_ModuleCache,
+        module_wasm: *mut wire_uint_8_list,
+    ) -> support::WireSyncReturn {
+        wire_compile_wasm_sync__method__ModuleCache_impl(that, module_wasm)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_stats__method__ModuleCache(
+        that: *mut wire_ModuleCache,
+    ) -> support::WireSyncReturn {
+        wire_stats__method__ModuleCache_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_clear__method__ModuleCache(
+        that: *mut wire_ModuleCache,
+    ) -> support::WireSyncReturn {
+        wire_clear__method__ModuleCache_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_cancel__method__CompileHandle(
+        that: *mut wire_CompileHandle,
+    ) -> support::WireSyncReturn {
+        wire_cancel__method__CompileHandle_impl(that)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wire_is_cancelled__method__CompileHandle(
+        that: *mut wire_CompileHandle,
+    ) -> support::WireSyncReturn {
+        wire_is_cancelled__method__CompileHandle_impl(that)
+    }
+
     #[no_mangle]
     pub extern "C" fn wire_ty__method__WasmRunSharedMemory(
         that: *mut wire_WasmRunSharedMemory,
@@ -3542,16 +5412,31 @@ mod io {
 
     // Section: allocate functions
 
+    #[no_mangle]
+    pub extern "C" fn new_ArcAtomicBool() -> wire_ArcAtomicBool {
+        wire_ArcAtomicBool::new_with_null_ptr()
+    }
+
     #[no_mangle]
     pub extern "C" fn new_ArcRwLockSharedMemory() -> wire_ArcRwLockSharedMemory {
         wire_ArcRwLockSharedMemory::new_with_null_ptr()
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_ArcStdSyncMutexLinkerTemplate() -> wire_ArcStdSyncMutexLinkerTemplate {
+        wire_ArcStdSyncMutexLinkerTemplate::new_with_null_ptr()
+    }
+
     #[no_mangle]
     pub extern "C" fn new_ArcStdSyncMutexModule() -> wire_ArcStdSyncMutexModule {
         wire_ArcStdSyncMutexModule::new_with_null_ptr()
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_ArcStdSyncMutexModuleCacheImpl() -> wire_ArcStdSyncMutexModuleCacheImpl {
+        wire_ArcStdSyncMutexModuleCacheImpl::new_with_null_ptr()
+    }
+
     #[no_mangle]
     pub extern "C" fn new_CallStack() -> wire_CallStack {
         wire_CallStack::new_with_null_ptr()
@@ -3567,6 +5452,11 @@ mod io {
         wire_Memory::new_with_null_ptr()
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_StdioCapture() -> wire_StdioCapture {
+        wire_StdioCapture::new_with_null_ptr()
+    }
+
     #[no_mangle]
     pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
         let wrap = wire_StringList {
@@ -3581,6 +5471,11 @@ mod io {
         wire_Table::new_with_null_ptr()
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_WEngine() -> wire_WEngine {
+        wire_WEngine::new_with_null_ptr()
+    }
+
     #[no_mangle]
     pub extern "C" fn new_WFunc() -> wire_WFunc {
         wire_WFunc::new_with_null_ptr()
@@ -3601,16 +5496,31 @@ mod io {
         support::new_leak_box_ptr(value)
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_compile_handle_0() -> *mut wire_CompileHandle {
+        support::new_leak_box_ptr(wire_CompileHandle::new_with_null_ptr())
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd_compiled_module_0() -> *mut wire_CompiledModule {
         support::new_leak_box_ptr(wire_CompiledModule::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_fuel_consumption_mode_0(value: i32) -> *mut i32 {
+        support::new_leak_box_ptr(value)
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd_memory_ty_0() -> *mut wire_MemoryTy {
         support::new_leak_box_ptr(wire_MemoryTy::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_module_cache_0() -> *mut wire_ModuleCache {
+        support::new_leak_box_ptr(wire_ModuleCache::new_with_null_ptr())
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd_module_config_0() -> *mut wire_ModuleConfig {
         support::new_leak_box_ptr(wire_ModuleConfig::new_with_null_ptr())
@@ -3631,6 +5541,11 @@ mod io {
         support::new_leak_box_ptr(wire_TableArgs::new_with_null_ptr())
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_u16_0(value: u16) -> *mut u16 {
+        support::new_leak_box_ptr(value)
+    }
+
     #[no_mangle]
     pub extern "C" fn new_box_autoadd_u32_0(value: u32) -> *mut u32 {
         support::new_leak_box_ptr(value)
@@ -3652,8 +5567,18 @@ mod io {
     }
 
     #[no_mangle]
-    pub extern "C" fn new_box_autoadd_wasi_stack_limits_0() -> *mut wire_WasiStackLimits {
-        support::new_leak_box_ptr(wire_WasiStackLimits::new_with_null_ptr())
+    pub extern "C" fn new_box_autoadd_wasi_stack_limits_0() -> *mut wire_WasiStackLimits {
+        support::new_leak_box_ptr(wire_WasiStackLimits::new_with_null_ptr())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_wasm_engine_0() -> *mut wire_WasmEngine {
+        support::new_leak_box_ptr(wire_WasmEngine::new_with_null_ptr())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn new_box_autoadd_wasm_linker_0() -> *mut wire_WasmLinker {
+        support::new_leak_box_ptr(wire_WasmLinker::new_with_null_ptr())
     }
 
     #[no_mangle]
@@ -3703,6 +5628,15 @@ mod io {
         support::new_leak_box_ptr(wrap)
     }
 
+    #[no_mangle]
+    pub extern "C" fn new_list_proposal_toggle_0(len: i32) -> *mut wire_list_proposal_toggle {
+        let wrap = wire_list_proposal_toggle {
+            ptr: support::new_leak_vec_ptr(<wire_ProposalToggle>::new_with_null_ptr(), len),
+            len,
+        };
+        support::new_leak_box_ptr(wrap)
+    }
+
     #[no_mangle]
     pub extern "C" fn new_list_value_ty_0(len: i32) -> *mut wire_list_value_ty {
         let wrap = wire_list_value_ty {
@@ -3732,6 +5666,21 @@ mod io {
 
     // Section: related functions
 
+    #[no_mangle]
+    pub extern "C" fn drop_opaque_ArcAtomicBool(ptr: *const c_void) {
+        unsafe {
+            Arc::<Arc<AtomicBool>>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn share_opaque_ArcAtomicBool(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<Arc<AtomicBool>>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn drop_opaque_ArcRwLockSharedMemory(ptr: *const c_void) {
         unsafe {
@@ -3747,6 +5696,23 @@ mod io {
         }
     }
 
+    #[no_mangle]
+    pub extern "C" fn drop_opaque_ArcStdSyncMutexLinkerTemplate(ptr: *const c_void) {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<LinkerTemplate>>>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn share_opaque_ArcStdSyncMutexLinkerTemplate(
+        ptr: *const c_void,
+    ) -> *const c_void {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<LinkerTemplate>>>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn drop_opaque_ArcStdSyncMutexModule(ptr: *const c_void) {
         unsafe {
@@ -3762,6 +5728,23 @@ mod io {
         }
     }
 
+    #[no_mangle]
+    pub extern "C" fn drop_opaque_ArcStdSyncMutexModuleCacheImpl(ptr: *const c_void) {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<ModuleCacheImpl>>>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn share_opaque_ArcStdSyncMutexModuleCacheImpl(
+        ptr: *const c_void,
+    ) -> *const c_void {
+        unsafe {
+            Arc::<Arc<std::sync::Mutex<ModuleCacheImpl>>>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn drop_opaque_CallStack(ptr: *const c_void) {
         unsafe {
@@ -3807,6 +5790,21 @@ mod io {
         }
     }
 
+    #[no_mangle]
+    pub extern "C" fn drop_opaque_StdioCapture(ptr: *const c_void) {
+        unsafe {
+            Arc::<StdioCapture>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn share_opaque_StdioCapture(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<StdioCapture>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn drop_opaque_Table(ptr: *const c_void) {
         unsafe {
@@ -3822,6 +5820,21 @@ mod io {
         }
     }
 
+    #[no_mangle]
+    pub extern "C" fn drop_opaque_WEngine(ptr: *const c_void) {
+        unsafe {
+            Arc::<WEngine>::decrement_strong_count(ptr as _);
+        }
+    }
+
+    #[no_mangle]
+    pub extern "C" fn share_opaque_WEngine(ptr: *const c_void) -> *const c_void {
+        unsafe {
+            Arc::<WEngine>::increment_strong_count(ptr as _);
+            ptr
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn drop_opaque_WFunc(ptr: *const c_void) {
         unsafe {
@@ -3839,16 +5852,35 @@ mod io {
 
     // Section: impl Wire2Api
 
+    impl Wire2Api<RustOpaque<Arc<AtomicBool>>> for wire_ArcAtomicBool {
+        fn wire2api(self) -> RustOpaque<Arc<AtomicBool>> {
+            unsafe { support::opaque_from_dart(self.ptr as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<Arc<RwLock<SharedMemory>>>> for wire_ArcRwLockSharedMemory {
         fn wire2api(self) -> RustOpaque<Arc<RwLock<SharedMemory>>> {
             unsafe { support::opaque_from_dart(self.ptr as _) }
         }
     }
+    impl Wire2Api<RustOpaque<Arc<std::sync::Mutex<LinkerTemplate>>>>
+        for wire_ArcStdSyncMutexLinkerTemplate
+    {
+        fn wire2api(self) -> RustOpaque<Arc<std::sync::Mutex<LinkerTemplate>>> {
+            unsafe { support::opaque_from_dart(self.ptr as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<Arc<std::sync::Mutex<Module>>>> for wire_ArcStdSyncMutexModule {
         fn wire2api(self) -> RustOpaque<Arc<std::sync::Mutex<Module>>> {
             unsafe { support::opaque_from_dart(self.ptr as _) }
         }
     }
+    impl Wire2Api<RustOpaque<Arc<std::sync::Mutex<ModuleCacheImpl>>>>
+        for wire_ArcStdSyncMutexModuleCacheImpl
+    {
+        fn wire2api(self) -> RustOpaque<Arc<std::sync::Mutex<ModuleCacheImpl>>> {
+            unsafe { support::opaque_from_dart(self.ptr as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<CallStack>> for wire_CallStack {
         fn wire2api(self) -> RustOpaque<CallStack> {
             unsafe { support::opaque_from_dart(self.ptr as _) }
@@ -3864,6 +5896,11 @@ mod io {
             unsafe { support::opaque_from_dart(self.ptr as _) }
         }
     }
+    impl Wire2Api<RustOpaque<StdioCapture>> for wire_StdioCapture {
+        fn wire2api(self) -> RustOpaque<StdioCapture> {
+            unsafe { support::opaque_from_dart(self.ptr as _) }
+        }
+    }
     impl Wire2Api<String> for *mut wire_uint_8_list {
         fn wire2api(self) -> String {
             let vec: Vec<u8> = self.wire2api();
@@ -3884,6 +5921,11 @@ mod io {
             unsafe { support::opaque_from_dart(self.ptr as _) }
         }
     }
+    impl Wire2Api<RustOpaque<WEngine>> for wire_WEngine {
+        fn wire2api(self) -> RustOpaque<WEngine> {
+            unsafe { support::opaque_from_dart(self.ptr as _) }
+        }
+    }
     impl Wire2Api<RustOpaque<WFunc>> for wire_WFunc {
         fn wire2api(self) -> RustOpaque<WFunc> {
             unsafe { support::opaque_from_dart(self.ptr as _) }
@@ -3913,18 +5955,36 @@ mod io {
             unsafe { *support::box_from_leak_ptr(self) }
         }
     }
+    impl Wire2Api<CompileHandle> for *mut wire_CompileHandle {
+        fn wire2api(self) -> CompileHandle {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<CompileHandle>::wire2api(*wrap).into()
+        }
+    }
     impl Wire2Api<CompiledModule> for *mut wire_CompiledModule {
         fn wire2api(self) -> CompiledModule {
             let wrap = unsafe { support::box_from_leak_ptr(self) };
             Wire2Api::<CompiledModule>::wire2api(*wrap).into()
         }
     }
+    impl Wire2Api<FuelConsumptionMode> for *mut i32 {
+        fn wire2api(self) -> FuelConsumptionMode {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<FuelConsumptionMode>::wire2api(*wrap).into()
+        }
+    }
     impl Wire2Api<MemoryTy> for *mut wire_MemoryTy {
         fn wire2api(self) -> MemoryTy {
             let wrap = unsafe { support::box_from_leak_ptr(self) };
             Wire2Api::<MemoryTy>::wire2api(*wrap).into()
         }
     }
+    impl Wire2Api<ModuleCache> for *mut wire_ModuleCache {
+        fn wire2api(self) -> ModuleCache {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<ModuleCache>::wire2api(*wrap).into()
+        }
+    }
     impl Wire2Api<ModuleConfig> for *mut wire_ModuleConfig {
         fn wire2api(self) -> ModuleConfig {
             let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -3949,6 +6009,11 @@ mod io {
             Wire2Api::<TableArgs>::wire2api(*wrap).into()
         }
     }
+    impl Wire2Api<u16> for *mut u16 {
+        fn wire2api(self) -> u16 {
+            unsafe { *support::box_from_leak_ptr(self) }
+        }
+    }
     impl Wire2Api<u32> for *mut u32 {
         fn wire2api(self) -> u32 {
             unsafe { *support::box_from_leak_ptr(self) }
@@ -3976,6 +6041,18 @@ mod io {
             Wire2Api::<WasiStackLimits>::wire2api(*wrap).into()
         }
     }
+    impl Wire2Api<WasmEngine> for *mut wire_WasmEngine {
+        fn wire2api(self) -> WasmEngine {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<WasmEngine>::wire2api(*wrap).into()
+        }
+    }
+    impl Wire2Api<WasmLinker> for *mut wire_WasmLinker {
+        fn wire2api(self) -> WasmLinker {
+            let wrap = unsafe { support::box_from_leak_ptr(self) };
+            Wire2Api::<WasmLinker>::wire2api(*wrap).into()
+        }
+    }
     impl Wire2Api<WasmRunInstanceId> for *mut wire_WasmRunInstanceId {
         fn wire2api(self) -> WasmRunInstanceId {
             let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -4000,6 +6077,12 @@ mod io {
             Wire2Api::<WasmVal>::wire2api(*wrap).into()
         }
     }
+
+    impl Wire2Api<CompileHandle> for wire_CompileHandle {
+        fn wire2api(self) -> CompileHandle {
+            CompileHandle(self.field0.wire2api())
+        }
+    }
     impl Wire2Api<CompiledModule> for wire_CompiledModule {
         fn wire2api(self) -> CompiledModule {
             CompiledModule(self.field0.wire2api())
@@ -4073,6 +6156,15 @@ mod io {
             vec.into_iter().map(Wire2Api::wire2api).collect()
         }
     }
+    impl Wire2Api<Vec<ProposalToggle>> for *mut wire_list_proposal_toggle {
+        fn wire2api(self) -> Vec<ProposalToggle> {
+            let vec = unsafe {
+                let wrap = support::box_from_leak_ptr(self);
+                support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(Wire2Api::wire2api).collect()
+        }
+    }
     impl Wire2Api<Vec<ValueTy>> for *mut wire_list_value_ty {
         fn wire2api(self) -> Vec<ValueTy> {
             let vec = unsafe {
@@ -4100,6 +6192,11 @@ mod io {
             }
         }
     }
+    impl Wire2Api<ModuleCache> for wire_ModuleCache {
+        fn wire2api(self) -> ModuleCache {
+            ModuleCache(self.field0.wire2api())
+        }
+    }
     impl Wire2Api<ModuleConfig> for wire_ModuleConfig {
         fn wire2api(self) -> ModuleConfig {
             ModuleConfig {
@@ -4109,6 +6206,14 @@ mod io {
                 consume_fuel: self.consume_fuel.wire2api(),
                 wasmi: self.wasmi.wire2api(),
                 wasmtime: self.wasmtime.wire2api(),
+                max_module_bytes: self.max_module_bytes.wire2api(),
+                max_memory_pages: self.max_memory_pages.wire2api(),
+                max_memories: self.max_memories.wire2api(),
+                max_tables: self.max_tables.wire2api(),
+                max_globals: self.max_globals.wire2api(),
+                max_functions: self.max_functions.wire2api(),
+                trap_on_integer_overflow: self.trap_on_integer_overflow.wire2api(),
+                deterministic: self.deterministic.wire2api(),
             }
         }
     }
@@ -4123,6 +6228,7 @@ mod io {
                 tail_call: self.tail_call.wire2api(),
                 extended_const: self.extended_const.wire2api(),
                 floats: self.floats.wire2api(),
+                fuel_consumption_mode: self.fuel_consumption_mode.wire2api(),
             }
         }
     }
@@ -4132,18 +6238,28 @@ mod io {
                 debug_info: self.debug_info.wire2api(),
                 wasm_backtrace: self.wasm_backtrace.wire2api(),
                 native_unwind_info: self.native_unwind_info.wire2api(),
+                epoch_interruption: self.epoch_interruption.wire2api(),
                 max_wasm_stack: self.max_wasm_stack.wire2api(),
                 wasm_threads: self.wasm_threads.wire2api(),
                 wasm_simd: self.wasm_simd.wire2api(),
                 wasm_relaxed_simd: self.wasm_relaxed_simd.wire2api(),
                 relaxed_simd_deterministic: self.relaxed_simd_deterministic.wire2api(),
+                cranelift_nan_canonicalization: self.cranelift_nan_canonicalization.wire2api(),
                 wasm_multi_memory: self.wasm_multi_memory.wire2api(),
                 wasm_memory64: self.wasm_memory64.wire2api(),
+                wasm_tail_call: self.wasm_tail_call.wire2api(),
+                wasm_function_references: self.wasm_function_references.wire2api(),
+                wasm_memory_control: self.wasm_memory_control.wire2api(),
+                wasm_gc: self.wasm_gc.wire2api(),
                 static_memory_maximum_size: self.static_memory_maximum_size.wire2api(),
                 static_memory_forced: self.static_memory_forced.wire2api(),
                 static_memory_guard_size: self.static_memory_guard_size.wire2api(),
                 parallel_compilation: self.parallel_compilation.wire2api(),
+                compilation_thread_stack_size: self.compilation_thread_stack_size.wire2api(),
                 generate_address_map: self.generate_address_map.wire2api(),
+                disable_guard_pages: self.disable_guard_pages.wire2api(),
+                macos_use_mach_ports: self.macos_use_mach_ports.wire2api(),
+                extra_proposals: self.extra_proposals.wire2api(),
             }
         }
     }
@@ -4162,6 +6278,16 @@ mod io {
             PreopenedDir {
                 wasm_guest_path: self.wasm_guest_path.wire2api(),
                 host_path: self.host_path.wire2api(),
+                rights: self.rights.wire2api(),
+                overlay: self.overlay.wire2api(),
+            }
+        }
+    }
+    impl Wire2Api<ProposalToggle> for wire_ProposalToggle {
+        fn wire2api(self) -> ProposalToggle {
+            ProposalToggle {
+                name: self.name.wire2api(),
+                enabled: self.enabled.wire2api(),
             }
         }
     }
@@ -4195,6 +6321,7 @@ mod io {
             WasiConfigNative {
                 capture_stdout: self.capture_stdout.wire2api(),
                 capture_stderr: self.capture_stderr.wire2api(),
+                line_buffered_output: self.line_buffered_output.wire2api(),
                 inherit_stdin: self.inherit_stdin.wire2api(),
                 inherit_env: self.inherit_env.wire2api(),
                 inherit_args: self.inherit_args.wire2api(),
@@ -4202,6 +6329,10 @@ mod io {
                 env: self.env.wire2api(),
                 preopened_files: self.preopened_files.wire2api(),
                 preopened_dirs: self.preopened_dirs.wire2api(),
+                capture_capacity: self.capture_capacity.wire2api(),
+                capture_overflow: self.capture_overflow.wire2api(),
+                deterministic: self.deterministic.wire2api(),
+                unknown_errno: self.unknown_errno.wire2api(),
             }
         }
     }
@@ -4214,6 +6345,16 @@ mod io {
             }
         }
     }
+    impl Wire2Api<WasmEngine> for wire_WasmEngine {
+        fn wire2api(self) -> WasmEngine {
+            WasmEngine(self.field0.wire2api())
+        }
+    }
+    impl Wire2Api<WasmLinker> for wire_WasmLinker {
+        fn wire2api(self) -> WasmLinker {
+            WasmLinker(self.field0.wire2api())
+        }
+    }
     impl Wire2Api<WasmRunInstanceId> for wire_WasmRunInstanceId {
         fn wire2api(self) -> WasmRunInstanceId {
             WasmRunInstanceId(self.field0.wire2api())
@@ -4221,7 +6362,11 @@ mod io {
     }
     impl Wire2Api<WasmRunModuleId> for wire_WasmRunModuleId {
         fn wire2api(self) -> WasmRunModuleId {
-            WasmRunModuleId(self.field0.wire2api(), self.field1.wire2api())
+            WasmRunModuleId(
+                self.field0.wire2api(),
+                self.field1.wire2api(),
+                self.field2.wire2api(),
+            )
         }
     }
     impl Wire2Api<WasmRunSharedMemory> for wire_WasmRunSharedMemory {
@@ -4273,18 +6418,36 @@ mod io {
     }
     // Section: wire structs
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ArcAtomicBool {
+        ptr: *const core::ffi::c_void,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_ArcRwLockSharedMemory {
         ptr: *const core::ffi::c_void,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ArcStdSyncMutexLinkerTemplate {
+        ptr: *const core::ffi::c_void,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_ArcStdSyncMutexModule {
         ptr: *const core::ffi::c_void,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ArcStdSyncMutexModuleCacheImpl {
+        ptr: *const core::ffi::c_void,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_CallStack {
@@ -4303,6 +6466,12 @@ mod io {
         ptr: *const core::ffi::c_void,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_StdioCapture {
+        ptr: *const core::ffi::c_void,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_StringList {
@@ -4316,6 +6485,12 @@ mod io {
         ptr: *const core::ffi::c_void,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_WEngine {
+        ptr: *const core::ffi::c_void,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_WFunc {
@@ -4328,6 +6503,12 @@ mod io {
         field0: usize,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_CompileHandle {
+        field0: wire_ArcAtomicBool,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_CompiledModule {
@@ -4362,6 +6543,13 @@ mod io {
         len: i32,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_list_proposal_toggle {
+        ptr: *mut wire_ProposalToggle,
+        len: i32,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_list_value_ty {
@@ -4384,6 +6572,12 @@ mod io {
         maximum: *mut u32,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ModuleCache {
+        field0: wire_ArcStdSyncMutexModuleCacheImpl,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_ModuleConfig {
@@ -4393,6 +6587,14 @@ mod io {
         consume_fuel: *mut bool,
         wasmi: *mut wire_ModuleConfigWasmi,
         wasmtime: *mut wire_ModuleConfigWasmtime,
+        max_module_bytes: *mut usize,
+        max_memory_pages: *mut u64,
+        max_memories: *mut u32,
+        max_tables: *mut u32,
+        max_globals: *mut u32,
+        max_functions: *mut u32,
+        trap_on_integer_overflow: *mut bool,
+        deterministic: *mut bool,
     }
 
     #[repr(C)]
@@ -4406,6 +6608,7 @@ mod io {
         tail_call: *mut bool,
         extended_const: *mut bool,
         floats: *mut bool,
+        fuel_consumption_mode: *mut i32,
     }
 
     #[repr(C)]
@@ -4414,18 +6617,28 @@ mod io {
         debug_info: *mut bool,
         wasm_backtrace: *mut bool,
         native_unwind_info: *mut bool,
+        epoch_interruption: *mut bool,
         max_wasm_stack: *mut usize,
         wasm_threads: *mut bool,
         wasm_simd: *mut bool,
         wasm_relaxed_simd: *mut bool,
         relaxed_simd_deterministic: *mut bool,
+        cranelift_nan_canonicalization: *mut bool,
         wasm_multi_memory: *mut bool,
         wasm_memory64: *mut bool,
+        wasm_tail_call: *mut bool,
+        wasm_function_references: *mut bool,
+        wasm_memory_control: *mut bool,
+        wasm_gc: *mut bool,
         static_memory_maximum_size: *mut u64,
         static_memory_forced: *mut bool,
         static_memory_guard_size: *mut u64,
         parallel_compilation: *mut bool,
+        compilation_thread_stack_size: *mut usize,
         generate_address_map: *mut bool,
+        disable_guard_pages: *mut bool,
+        macos_use_mach_ports: *mut bool,
+        extra_proposals: *mut wire_list_proposal_toggle,
     }
 
     #[repr(C)]
@@ -4441,6 +6654,15 @@ mod io {
     pub struct wire_PreopenedDir {
         wasm_guest_path: *mut wire_uint_8_list,
         host_path: *mut wire_uint_8_list,
+        rights: *mut u64,
+        overlay: bool,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_ProposalToggle {
+        name: *mut wire_uint_8_list,
+        enabled: bool,
     }
 
     #[repr(C)]
@@ -4462,6 +6684,7 @@ mod io {
     pub struct wire_WasiConfigNative {
         capture_stdout: bool,
         capture_stderr: bool,
+        line_buffered_output: bool,
         inherit_stdin: bool,
         inherit_env: bool,
         inherit_args: bool,
@@ -4469,6 +6692,10 @@ mod io {
         env: *mut wire_list_env_variable,
         preopened_files: *mut wire_StringList,
         preopened_dirs: *mut wire_list_preopened_dir,
+        capture_capacity: *mut usize,
+        capture_overflow: i32,
+        deterministic: bool,
+        unknown_errno: *mut u16,
     }
 
     #[repr(C)]
@@ -4479,6 +6706,18 @@ mod io {
         maximum_recursion_depth: usize,
     }
 
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_WasmEngine {
+        field0: wire_WEngine,
+    }
+
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct wire_WasmLinker {
+        field0: wire_ArcStdSyncMutexLinkerTemplate,
+    }
+
     #[repr(C)]
     #[derive(Clone)]
     pub struct wire_WasmRunInstanceId {
@@ -4490,6 +6729,7 @@ mod io {
     pub struct wire_WasmRunModuleId {
         field0: u32,
         field1: wire_CallStack,
+        field2: wire_StdioCapture,
     }
 
     #[repr(C)]
@@ -4615,6 +6855,13 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_ArcAtomicBool {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ptr: core::ptr::null(),
+            }
+        }
+    }
     impl NewWithNullPtr for wire_ArcRwLockSharedMemory {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4622,6 +6869,13 @@ mod io {
             }
         }
     }
+    impl NewWithNullPtr for wire_ArcStdSyncMutexLinkerTemplate {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ptr: core::ptr::null(),
+            }
+        }
+    }
     impl NewWithNullPtr for wire_ArcStdSyncMutexModule {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4629,6 +6883,13 @@ mod io {
             }
         }
     }
+    impl NewWithNullPtr for wire_ArcStdSyncMutexModuleCacheImpl {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ptr: core::ptr::null(),
+            }
+        }
+    }
     impl NewWithNullPtr for wire_CallStack {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4650,6 +6911,13 @@ mod io {
             }
         }
     }
+    impl NewWithNullPtr for wire_StdioCapture {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ptr: core::ptr::null(),
+            }
+        }
+    }
 
     impl NewWithNullPtr for wire_Table {
         fn new_with_null_ptr() -> Self {
@@ -4658,6 +6926,13 @@ mod io {
             }
         }
     }
+    impl NewWithNullPtr for wire_WEngine {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ptr: core::ptr::null(),
+            }
+        }
+    }
     impl NewWithNullPtr for wire_WFunc {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4680,6 +6955,20 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_CompileHandle {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                field0: wire_ArcAtomicBool::new_with_null_ptr(),
+            }
+        }
+    }
+
+    impl Default for wire_CompileHandle {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
     impl NewWithNullPtr for wire_CompiledModule {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4785,6 +7074,20 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_ModuleCache {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                field0: wire_ArcStdSyncMutexModuleCacheImpl::new_with_null_ptr(),
+            }
+        }
+    }
+
+    impl Default for wire_ModuleCache {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
     impl NewWithNullPtr for wire_ModuleConfig {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4794,6 +7097,14 @@ mod io {
                 consume_fuel: core::ptr::null_mut(),
                 wasmi: core::ptr::null_mut(),
                 wasmtime: core::ptr::null_mut(),
+                max_module_bytes: core::ptr::null_mut(),
+                max_memory_pages: core::ptr::null_mut(),
+                max_memories: core::ptr::null_mut(),
+                max_tables: core::ptr::null_mut(),
+                max_globals: core::ptr::null_mut(),
+                max_functions: core::ptr::null_mut(),
+                trap_on_integer_overflow: core::ptr::null_mut(),
+                deterministic: core::ptr::null_mut(),
             }
         }
     }
@@ -4815,6 +7126,7 @@ mod io {
                 tail_call: core::ptr::null_mut(),
                 extended_const: core::ptr::null_mut(),
                 floats: core::ptr::null_mut(),
+                fuel_consumption_mode: core::ptr::null_mut(),
             }
         }
     }
@@ -4831,18 +7143,28 @@ mod io {
                 debug_info: core::ptr::null_mut(),
                 wasm_backtrace: core::ptr::null_mut(),
                 native_unwind_info: core::ptr::null_mut(),
+                epoch_interruption: core::ptr::null_mut(),
                 max_wasm_stack: core::ptr::null_mut(),
                 wasm_threads: core::ptr::null_mut(),
                 wasm_simd: core::ptr::null_mut(),
                 wasm_relaxed_simd: core::ptr::null_mut(),
                 relaxed_simd_deterministic: core::ptr::null_mut(),
+                cranelift_nan_canonicalization: core::ptr::null_mut(),
                 wasm_multi_memory: core::ptr::null_mut(),
                 wasm_memory64: core::ptr::null_mut(),
+                wasm_tail_call: core::ptr::null_mut(),
+                wasm_function_references: core::ptr::null_mut(),
+                wasm_memory_control: core::ptr::null_mut(),
+                wasm_gc: core::ptr::null_mut(),
                 static_memory_maximum_size: core::ptr::null_mut(),
                 static_memory_forced: core::ptr::null_mut(),
                 static_memory_guard_size: core::ptr::null_mut(),
                 parallel_compilation: core::ptr::null_mut(),
+                compilation_thread_stack_size: core::ptr::null_mut(),
                 generate_address_map: core::ptr::null_mut(),
+                disable_guard_pages: core::ptr::null_mut(),
+                macos_use_mach_ports: core::ptr::null_mut(),
+                extra_proposals: core::ptr::null_mut(),
             }
         }
     }
@@ -4874,6 +7196,8 @@ mod io {
             Self {
                 wasm_guest_path: core::ptr::null_mut(),
                 host_path: core::ptr::null_mut(),
+                rights: core::ptr::null_mut(),
+                overlay: Default::default(),
             }
         }
     }
@@ -4884,6 +7208,21 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_ProposalToggle {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                name: core::ptr::null_mut(),
+                enabled: Default::default(),
+            }
+        }
+    }
+
+    impl Default for wire_ProposalToggle {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
     impl NewWithNullPtr for wire_TableArgs {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4904,6 +7243,7 @@ mod io {
             Self {
                 capture_stdout: Default::default(),
                 capture_stderr: Default::default(),
+                line_buffered_output: Default::default(),
                 inherit_stdin: Default::default(),
                 inherit_env: Default::default(),
                 inherit_args: Default::default(),
@@ -4911,6 +7251,10 @@ mod io {
                 env: core::ptr::null_mut(),
                 preopened_files: core::ptr::null_mut(),
                 preopened_dirs: core::ptr::null_mut(),
+                capture_capacity: core::ptr::null_mut(),
+                capture_overflow: Default::default(),
+                deterministic: Default::default(),
+                unknown_errno: core::ptr::null_mut(),
             }
         }
     }
@@ -4937,6 +7281,34 @@ mod io {
         }
     }
 
+    impl NewWithNullPtr for wire_WasmEngine {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                field0: wire_WEngine::new_with_null_ptr(),
+            }
+        }
+    }
+
+    impl Default for wire_WasmEngine {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    impl NewWithNullPtr for wire_WasmLinker {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                field0: wire_ArcStdSyncMutexLinkerTemplate::new_with_null_ptr(),
+            }
+        }
+    }
+
+    impl Default for wire_WasmLinker {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
     impl NewWithNullPtr for wire_WasmRunInstanceId {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -4956,6 +7328,7 @@ mod io {
             Self {
                 field0: Default::default(),
                 field1: wire_CallStack::new_with_null_ptr(),
+                field2: wire_StdioCapture::new_with_null_ptr(),
             }
         }
     }
@@ -5068,4 +7441,4 @@ mod io {
     }
 }
 #[cfg(not(target_family = "wasm"))]
-pub use io::*;
+pub use self::io::*;
@@ -0,0 +1,198 @@
+use crate::config::CaptureOverflow;
+use std::{
+    sync::{Condvar, Mutex},
+    time::Duration,
+};
+
+/// A bounded buffer for the stdout or stderr captured from a module,
+/// see [crate::config::WasiConfigNative::capture_capacity].
+#[derive(Debug)]
+pub struct CaptureBuffer {
+    capacity: usize,
+    overflow: CaptureOverflow,
+    state: Mutex<CaptureState>,
+    /// Notified when bytes are read or the buffer is closed.
+    space: Condvar,
+    /// Notified when bytes are written or the buffer is finished or closed.
+    ready: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct CaptureState {
+    data: Vec<u8>,
+    dropped_bytes: u64,
+    closed: bool,
+    /// The module exited, the following writes are dropped.
+    finished: bool,
+}
+
+impl CaptureBuffer {
+    pub fn new(capacity: usize, overflow: CaptureOverflow) -> Self {
+        CaptureBuffer {
+            capacity,
+            overflow,
+            state: Default::default(),
+            space: Condvar::new(),
+            ready: Condvar::new(),
+        }
+    }
+
+    /// Appends the bytes of [buf] that fit in the buffer and returns
+    /// the number of bytes written for the module's `fd_write`.
+    ///
+    /// With [CaptureOverflow::block], waits until there is space for at
+    /// least one byte and returns the number of bytes appended.
+    /// Otherwise, the bytes that do not fit are dropped and all of [buf] is reported
+    /// as written, as well as when the buffer is closed.
+    pub fn write(&self, buf: &[u8]) -> usize {
+        let mut state = self.state.lock().unwrap();
+        if self.overflow == CaptureOverflow::block {
+            while !state.closed && state.data.len() >= self.capacity {
+                state = self.space.wait(state).unwrap();
+            }
+        }
+        if state.closed || state.finished {
+            state.dropped_bytes += buf.len() as u64;
+            return buf.len();
+        }
+        let written = buf.len().min(self.capacity - state.data.len());
+        state.data.extend_from_slice(&buf[..written]);
+        if written > 0 {
+            self.ready.notify_all();
+        }
+        match self.overflow {
+            CaptureOverflow::block => written,
+            _ => {
+                state.dropped_bytes += (buf.len() - written) as u64;
+                buf.len()
+            }
+        }
+    }
+
+    /// Removes and returns the buffered bytes, unblocking the module's writes.
+    pub fn read(&self) -> Vec<u8> {
+        let data = std::mem::take(&mut self.state.lock().unwrap().data);
+        self.space.notify_all();
+        data
+    }
+
+    /// Waits up to [timeout] for buffered bytes and removes them.
+    ///
+    /// Returns an empty list when the timeout elapses without bytes
+    /// and `None` when the buffer was finished or closed and all
+    /// the remaining bytes were returned.
+    pub fn next_chunk(&self, timeout: Duration) -> Option<Vec<u8>> {
+        let state = self.state.lock().unwrap();
+        let (mut state, _) = self
+            .ready
+            .wait_timeout_while(state, timeout, |s| {
+                s.data.is_empty() && !s.finished && !s.closed
+            })
+            .unwrap();
+        if !state.data.is_empty() {
+            let data = std::mem::take(&mut state.data);
+            self.space.notify_all();
+            Some(data)
+        } else if state.finished || state.closed {
+            None
+        } else {
+            Some(vec![])
+        }
+    }
+
+    /// Marks the end of the module's output. The buffered bytes
+    /// can still be read and the following writes are dropped.
+    pub fn finish(&self) {
+        self.state.lock().unwrap().finished = true;
+        self.ready.notify_all();
+        self.space.notify_all();
+    }
+
+    /// The number of bytes dropped because the buffer was full or closed.
+    pub fn dropped_bytes(&self) -> u64 {
+        self.state.lock().unwrap().dropped_bytes
+    }
+
+    /// Drops the buffered bytes, freeing them, and the following writes,
+    /// unblocking the module if it is waiting for space.
+    pub fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        state.dropped_bytes += state.data.len() as u64;
+        state.data = vec![];
+        self.space.notify_all();
+        self.ready.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn drops_and_counts_overflow() {
+        let buffer = CaptureBuffer::new(4, CaptureOverflow::drop);
+        assert_eq!(buffer.write(b"abc"), 3);
+        assert_eq!(buffer.write(b"def"), 3);
+        assert_eq!(buffer.read(), b"abcd");
+        assert_eq!(buffer.dropped_bytes(), 2);
+
+        assert_eq!(buffer.write(b"gh"), 2);
+        buffer.close();
+        assert_eq!(buffer.write(b"ij"), 2);
+        assert_eq!(buffer.read(), b"");
+        assert_eq!(buffer.dropped_bytes(), 6);
+    }
+
+    #[test]
+    fn blocks_until_read() {
+        let buffer = Arc::new(CaptureBuffer::new(4, CaptureOverflow::block));
+        assert_eq!(buffer.write(b"abcdef"), 4);
+
+        let writer = {
+            let buffer = Arc::clone(&buffer);
+            std::thread::spawn(move || buffer.write(b"ef"))
+        };
+        let mut output = vec![];
+        while output.len() < 6 {
+            output.extend(buffer.read());
+            std::thread::yield_now();
+        }
+        assert_eq!(writer.join().unwrap(), 2);
+        assert_eq!(output, b"abcdef");
+        assert_eq!(buffer.dropped_bytes(), 0);
+
+        assert_eq!(buffer.write(b"ghij"), 4);
+        let writer = {
+            let buffer = Arc::clone(&buffer);
+            std::thread::spawn(move || buffer.write(b"kl"))
+        };
+        buffer.close();
+        assert_eq!(writer.join().unwrap(), 2);
+        // The unread bytes and the blocked write are dropped.
+        assert_eq!(buffer.dropped_bytes(), 6);
+    }
+
+    #[test]
+    fn next_chunk_until_finished() {
+        let buffer = Arc::new(CaptureBuffer::new(8, CaptureOverflow::block));
+        let timeout = Duration::from_millis(10);
+        assert_eq!(buffer.next_chunk(timeout), Some(vec![]));
+
+        let reader = {
+            let buffer = Arc::clone(&buffer);
+            std::thread::spawn(move || buffer.next_chunk(Duration::from_secs(10)))
+        };
+        assert_eq!(buffer.write(b"ab"), 2);
+        assert_eq!(reader.join().unwrap(), Some(b"ab".to_vec()));
+
+        // The partial output is returned before the end of the stream.
+        assert_eq!(buffer.write(b"cd"), 2);
+        buffer.finish();
+        assert_eq!(buffer.write(b"ef"), 2);
+        assert_eq!(buffer.next_chunk(timeout), Some(b"cd".to_vec()));
+        assert_eq!(buffer.next_chunk(timeout), None);
+        assert_eq!(buffer.dropped_bytes(), 2);
+    }
+}
@@ -0,0 +1,90 @@
+//! Structured errors for the Wasm modules that fail to compile.
+
+use std::fmt::Display;
+use wasmparser::{Validator, WasmFeatures};
+
+/// The reason a module could not be compiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompileErrorKind {
+    /// The module binary is malformed or does not validate
+    /// with the enabled Wasm features.
+    Invalid,
+    /// The module is valid, but the runtime could not compile it.
+    Compilation,
+}
+
+impl CompileErrorKind {
+    fn name(self) -> &'static str {
+        match self {
+            CompileErrorKind::Invalid => "invalid",
+            CompileErrorKind::Compilation => "compilation",
+        }
+    }
+}
+
+/// A module that failed to compile, with the position of the problem.
+///
+/// Formatted as `Compile error (invalid) at offset 10: <message>`, so that
+/// Dart code can parse it from the error message with `CompileError.fromException`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileError {
+    /// The byte offset in the module binary where the error was found,
+    /// if it is known.
+    pub offset: Option<usize>,
+    pub kind: CompileErrorKind,
+    pub message: String,
+}
+
+impl CompileError {
+    /// Maps the [error] returned by the runtime when compiling [module_wasm].
+    /// The module is validated with [features] to find the offset of the error,
+    /// if the module is valid, the error is a [CompileErrorKind::Compilation].
+    pub fn new(module_wasm: &[u8], features: WasmFeatures, error: anyhow::Error) -> Self {
+        match Validator::new_with_features(features).validate_all(module_wasm) {
+            Err(invalid) => CompileError {
+                offset: Some(invalid.offset()),
+                kind: CompileErrorKind::Invalid,
+                message: invalid.message().to_string(),
+            },
+            Ok(_) => CompileError {
+                offset: None,
+                kind: CompileErrorKind::Compilation,
+                message: format!("{error:#}"),
+            },
+        }
+    }
+}
+
+impl Display for CompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Compile error ({})", self.kind.name())?;
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {offset}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::compile_wasm;
+
+    #[test]
+    fn invalid_section_offset() {
+        let module_wasm = vec![
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, // type section with one type
+            0x61, 0x00, 0x00, // invalid type form at offset 11
+        ];
+        let err = compile_wasm(module_wasm, Default::default()).err().unwrap();
+        let err = err.downcast_ref::<CompileError>().unwrap();
+        assert_eq!(err.offset, Some(11));
+        assert_eq!(err.kind, CompileErrorKind::Invalid);
+        assert!(err
+            .to_string()
+            .starts_with("Compile error (invalid) at offset 11: "));
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+/// The maximum number of threads compiling modules for
+/// `compile_wasm_cancelable`, including the threads detached by cancelled
+/// compilations that are still running.
+pub const MAX_COMPILATION_THREADS: usize = 4;
+
+static RUNNING: Mutex<usize> = Mutex::new(0);
+static RELEASED: Condvar = Condvar::new();
+
+/// One of the [MAX_COMPILATION_THREADS] compilation threads,
+/// released when it is dropped at the end of the thread.
+pub struct CompilationSlot(());
+
+impl CompilationSlot {
+    /// Waits until less than [MAX_COMPILATION_THREADS] threads are compiling.
+    /// [check] is called while waiting, its error is returned.
+    pub fn acquire(check: impl Fn() -> anyhow::Result<()>) -> anyhow::Result<Self> {
+        let mut running = RUNNING.lock().unwrap();
+        while *running >= MAX_COMPILATION_THREADS {
+            check()?;
+            running = RELEASED
+                .wait_timeout(running, Duration::from_millis(10))
+                .unwrap()
+                .0;
+        }
+        *running += 1;
+        Ok(CompilationSlot(()))
+    }
+}
+
+impl Drop for CompilationSlot {
+    fn drop(&mut self) {
+        *RUNNING.lock().unwrap() -= 1;
+        RELEASED.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compilation_slots_are_bounded() {
+        let slots = (0..MAX_COMPILATION_THREADS)
+            .map(|_| CompilationSlot::acquire(|| Ok(())).unwrap())
+            .collect::<Vec<_>>();
+        let err = CompilationSlot::acquire(|| Err(anyhow::anyhow!("cancelled")));
+        assert_eq!(err.err().unwrap().to_string(), "cancelled");
+
+        drop(slots);
+        assert!(CompilationSlot::acquire(|| Ok(())).is_ok());
+    }
+}
@@ -1,4 +1,16 @@
-#[derive(Debug)]
+//! Configuration for WASI, modules and the runtime features.
+//!
+//! [StdIOKind], [CaptureOverflow], [WasiConfigNative], [EnvVariable], [PreopenedDir],
+//! [ModuleConfig], [ModuleConfigWasmi], [ModuleConfigWasmtime] and [WasiStackLimits] are
+//! `#[non_exhaustive]` since new variants and fields will be added for new proposals and
+//! runtime options. Use a wildcard arm when matching the enums and create the structs with their
+//! constructors or [Default], instead of struct expressions.
+
+#[cfg(feature = "wasi")]
+use crate::wasi_error::WasiError;
+
+#[derive(Debug, Default)]
+#[non_exhaustive]
 pub struct WasiConfigNative {
     /// Whether to capture stdout.
     /// If this is true, you can use the [WasmInstance.stdout]
@@ -8,85 +20,336 @@ pub struct WasiConfigNative {
     /// If this is true, you can use the [WasmInstance.stderr]
     /// getter to retrieve a stream of the module's stderr.
     pub capture_stderr: bool,
+    /// Whether to send the captured stdout and stderr line by line,
+    /// without the `\n` or `\r\n` terminators.
+    /// The last line is sent when the module exits or the instance is disposed.
+    /// Lines longer than 64 KiB are sent in chunks.
+    /// Not supported in the web.
+    pub line_buffered_output: bool,
     // TODO: custom stdin
     /// Whether to inherit stdin from the host process.
     pub inherit_stdin: bool,
     /// Whether to inherit environment variables from the host process.
+    /// The variables in [env] replace the inherited ones with the same name.
+    /// Prefer [WasiConfigNative::set_process], which sets it with [env].
+    #[deprecated(note = "use `WasiConfigNative::set_process` with an `EnvMode`")]
     pub inherit_env: bool,
     /// Whether to inherit the process arguments from the host process.
+    /// The arguments in [args] are passed after the inherited ones.
+    /// Prefer [WasiConfigNative::set_process], which sets it with [args].
+    #[deprecated(note = "use `WasiConfigNative::set_process` with an `ArgsMode`")]
     pub inherit_args: bool,
     /// Custom process arguments to pass to the WASM module
+    #[deprecated(note = "use `WasiConfigNative::set_process` with an `ArgsMode`")]
     pub args: Vec<String>,
     /// Custom Environment variables to pass to the WASM module
+    #[deprecated(note = "use `WasiConfigNative::set_process` with an `EnvMode`")]
     pub env: Vec<EnvVariable>,
     /// Custom preopened files to pass to the WASM module
     pub preopened_files: Vec<String>,
     /// Custom preopened directories to pass to the WASM module
     /// The module will be able to access and edit these directories
     pub preopened_dirs: Vec<PreopenedDir>,
+    /// The maximum number of bytes of captured stdout and stderr kept
+    /// in memory until they are read with [WasmRunModuleId.readStdio].
+    /// When it is set, the captured output is not sent to the
+    /// [WasmInstance.stdout] and [WasmInstance.stderr] streams and
+    /// [capture_overflow] selects what happens when the buffer is full.
+    /// When it is null, the output is sent to the streams without a bound.
+    pub capture_capacity: Option<usize>,
+    /// What happens when the module writes to a full capture buffer,
+    /// see [capture_capacity].
+    pub capture_overflow: CaptureOverflow,
+    /// Whether the WASI functions return the same values on every execution:
+    /// `random_get` returns the bytes of a random generator with a fixed seed,
+    /// the realtime clock is always the Unix epoch and the monotonic clock
+    /// does not advance. Use it with [ModuleConfig.deterministic].
+    /// Not supported by wasmi.
+    pub deterministic: bool,
+    /// The errno code of the [WasiError]s for host IO errors without
+    /// a WASI equivalent, for example, when a preopened directory
+    /// can not be opened. When it is null, they are mapped to `EIO` (29).
+    pub unknown_errno: Option<u16>,
 }
 
+/// Configures the WASI context of a module after the settings of its
+/// [WasiConfigNative] are applied, for the options of the runtime
+/// that are not exposed in [WasiConfigNative].
+/// Used with [module_builder_with_wasi](crate::wasi_configure::module_builder_with_wasi).
+///
+/// The context is the `WasiCtx` of the `wasi-common` version used by
+/// the runtime, which is different for wasmi and wasmtime,
+/// so the hooks are runtime specific and may not be portable between them.
+pub type WasiCtxConfigure = Box<dyn FnOnce(&mut wasi_common::WasiCtx) -> anyhow::Result<()> + Send>;
+
 #[derive(Debug)]
 #[allow(non_camel_case_types)]
+#[non_exhaustive]
 pub enum StdIOKind {
     stdout,
     stderr,
 }
 
+/// How a module is initialized with [WasmRunModuleId.initialize],
+/// from its `_initialize` and `_start` exports without parameters or results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum WasiModuleKind {
+    /// Exports `_start`, which executes the program once and exits.
+    /// Its other exports should not be called after `_start`.
+    command,
+    /// Exports `_initialize`, which is called once before its other exports.
+    /// `_start` is never called for reactors, even if it is exported.
+    reactor,
+    /// Exports neither, its exports are called without an initialization.
+    library,
+}
+
+impl WasiModuleKind {
+    /// The kind of a module with the exports that satisfy [is_init_export].
+    pub fn from_exports(mut is_init_export: impl FnMut(&str) -> bool) -> Self {
+        if is_init_export("_initialize") {
+            WasiModuleKind::reactor
+        } else if is_init_export("_start") {
+            WasiModuleKind::command
+        } else {
+            WasiModuleKind::library
+        }
+    }
+}
+
+/// What happens when a module writes to a full capture buffer,
+/// see [WasiConfigNative.capture_capacity].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum CaptureOverflow {
+    /// The output that does not fit is dropped and counted, see
+    /// [WasmRunModuleId.stdioDroppedBytes]. The module is never blocked.
+    #[default]
+    drop,
+    /// The module's `fd_write` blocks until the buffer is read, applying
+    /// backpressure. The functions of the module should be called
+    /// in a different thread than the one reading the output, for example,
+    /// with [WasmFunction.callAsync], otherwise the call never returns.
+    block,
+}
+
 #[cfg(feature = "wasi")]
 impl WasiConfigNative {
     pub fn to_wasi_ctx(&self) -> anyhow::Result<wasi_common::WasiCtx> {
         #[cfg(not(feature = "wasmtime"))]
-        use wasmi_wasi::{ambient_authority, WasiCtxBuilder};
-        #[cfg(feature = "wasmtime")]
-        use wasmtime_wasi::{ambient_authority, WasiCtxBuilder};
+        use wasmi_wasi::WasiCtxBuilder;
 
-        // add wasi to linker
         #[cfg(not(feature = "wasmtime"))]
-        let mut wasi_builder = WasiCtxBuilder::new();
-        #[cfg(feature = "wasmtime")]
-        let mut wasi_builder = &mut WasiCtxBuilder::new();
-        if self.inherit_args {
-            wasi_builder = wasi_builder.inherit_args()?;
+        if self.deterministic {
+            return Err(anyhow::anyhow!(
+                "Deterministic WASI clocks and random values are not supported by wasmi."
+            ));
         }
-        if self.inherit_env {
-            wasi_builder = wasi_builder.inherit_env()?;
-        }
-        if self.inherit_stdin {
-            wasi_builder = wasi_builder.inherit_stdin();
-        }
-        if !self.capture_stdout {
-            wasi_builder = wasi_builder.inherit_stdout();
+        #[cfg(not(feature = "wasmtime"))]
+        if self.preopened_dirs.iter().any(|dir| dir.overlay) {
+            return Err(anyhow::anyhow!(
+                "Overlay preopened directories are not supported by wasmi."
+            ));
         }
-        if !self.capture_stderr {
-            wasi_builder = wasi_builder.inherit_stderr();
+        if self.capture_capacity == Some(0) && self.capture_overflow == CaptureOverflow::block {
+            return Err(anyhow::anyhow!(
+                "The capture capacity must be greater than 0 with `CaptureOverflow::block`, \
+                the module would block on its first write."
+            ));
         }
-        if !self.args.is_empty() {
-            for value in &self.args {
-                wasi_builder = wasi_builder.arg(value)?;
+
+        // add wasi to linker
+        #[cfg(not(feature = "wasmtime"))]
+        let mut wasi = {
+            let mut wasi_builder = WasiCtxBuilder::new();
+            if self.inherit_stdin {
+                wasi_builder = wasi_builder.inherit_stdin();
             }
-        }
-        if !self.env.is_empty() {
-            for EnvVariable { name, value } in &self.env {
-                wasi_builder = wasi_builder.env(name, value)?;
+            if !self.capture_stdout {
+                wasi_builder = wasi_builder.inherit_stdout();
             }
-        }
-        if !self.preopened_dirs.is_empty() {
-            for PreopenedDir {
+            if !self.capture_stderr {
+                wasi_builder = wasi_builder.inherit_stderr();
+            }
+            for value in self.process_args() {
+                wasi_builder = wasi_builder.arg(&value)?;
+            }
+            for (name, value) in self.process_env() {
+                wasi_builder = wasi_builder.env(&name, &value)?;
+            }
+            wasi_builder.build()
+        };
+        // The context is created without `WasiCtxBuilder`,
+        // which does not allow replacing the clocks and random generator.
+        #[cfg(feature = "wasmtime")]
+        let wasi = {
+            use wasmtime_wasi::sync::{clocks_ctx, random_ctx, sched_ctx, stdio};
+
+            let (random, clocks) = if self.deterministic {
+                deterministic_random_and_clocks()
+            } else {
+                (random_ctx(), clocks_ctx())
+            };
+            let mut wasi =
+                wasi_common::WasiCtx::new(random, clocks, sched_ctx(), wasi_common::Table::new());
+            if self.inherit_stdin {
+                wasi.set_stdin(Box::new(stdio::stdin()));
+            }
+            if !self.capture_stdout {
+                wasi.set_stdout(Box::new(stdio::stdout()));
+            }
+            if !self.capture_stderr {
+                wasi.set_stderr(Box::new(stdio::stderr()));
+            }
+            for value in self.process_args() {
+                wasi.push_arg(&value)?;
+            }
+            for (name, value) in self.process_env() {
+                wasi.push_env(&name, &value)?;
+            }
+            wasi
+        };
+
+        // The layers of each guest path, from the bottom to the top one.
+        #[cfg(feature = "wasmtime")]
+        let mut layers: Vec<(&str, Vec<Box<dyn wasi_common::WasiDir>>)> = vec![];
+        for preopened in &self.preopened_dirs {
+            let PreopenedDir {
                 wasm_guest_path,
                 host_path,
-            } in &self.preopened_dirs
+                rights,
+                ..
+            } = preopened;
+            // All the directories are pushed in order, so that their file
+            // descriptors match the ones in wasmtime.
+            #[cfg(not(feature = "wasmtime"))]
             {
-                let dir = cap_std::fs::Dir::open_ambient_dir(host_path, ambient_authority())?;
-                wasi_builder = wasi_builder.preopened_dir(dir, wasm_guest_path)?;
+                // wasi-common 2.0 has native capabilities for directories
+                use wasi_common::dir::DirCaps;
+                use wasi_common::file::FileCaps;
+                use wasi_common::snapshots::preview_1::types::Rights;
+
+                let dir = open_preopened_dir(host_path, self.unknown_errno)?;
+                let (dir_caps, file_caps) = match rights {
+                    Some(rights) => {
+                        let rights = Rights::from_bits_truncate(*rights);
+                        (DirCaps::from(&rights), FileCaps::from(&rights))
+                    }
+                    // The capabilities of `WasiCtx::push_preopened_dir`
+                    None => (DirCaps::all(), FileCaps::all()),
+                };
+                // `push_preopened_dir` does not take capabilities, the
+                // directory is inserted at the next free file descriptor.
+                let fd = (3..)
+                    .find(|fd| !wasi.table().contains_key(*fd))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("The WASI table has no free file descriptors.")
+                    })?;
+                wasi.insert_dir(
+                    fd,
+                    Box::new(wasmi_wasi::dir::Dir::from_cap_std(dir)),
+                    dir_caps,
+                    file_caps,
+                    wasm_guest_path.into(),
+                );
             }
+            #[cfg(feature = "wasmtime")]
+            {
+                let dir = open_preopened_dir(host_path, self.unknown_errno)?;
+                let dir: Box<dyn wasi_common::WasiDir> =
+                    Box::new(wasmtime_wasi::dir::Dir::from_cap_std(dir));
+                let dir: Box<dyn wasi_common::WasiDir> = match rights {
+                    Some(rights) => Box::new(crate::wasi_dir::RightsDir::new(dir, *rights)),
+                    None => dir,
+                };
+                let lower = layers
+                    .iter_mut()
+                    .rev()
+                    .find(|(path, _)| *path == wasm_guest_path.as_str())
+                    .filter(|_| preopened.overlay);
+                match lower {
+                    Some((_, dirs)) => dirs.push(dir),
+                    None => layers.push((wasm_guest_path, vec![dir])),
+                }
+            }
+        }
+        #[cfg(feature = "wasmtime")]
+        for (wasm_guest_path, mut dirs) in layers {
+            let dir = if dirs.len() == 1 {
+                dirs.pop().unwrap()
+            } else {
+                Box::new(crate::wasi_dir::OverlayDir::new(dirs))
+            };
+            wasi.push_preopened_dir(dir, wasm_guest_path)?;
         }
 
-        Ok(wasi_builder.build())
+        Ok(wasi)
     }
 }
 
+/// The random generator with a fixed seed and the frozen clocks
+/// used by [WasiConfigNative::deterministic].
+#[cfg(all(feature = "wasi", feature = "wasmtime"))]
+fn deterministic_random_and_clocks() -> (
+    Box<dyn wasi_common::RngCore + Send + Sync>,
+    wasi_common::WasiClocks,
+) {
+    use cap_rand::{rngs::StdRng, SeedableRng};
+    use cap_std::time::{Duration, Instant, SystemTime};
+
+    struct EpochClock;
+    impl wasi_common::WasiSystemClock for EpochClock {
+        fn resolution(&self) -> Duration {
+            Duration::from_nanos(1)
+        }
+        fn now(&self, _precision: Duration) -> SystemTime {
+            SystemTime::from_std(std::time::UNIX_EPOCH)
+        }
+    }
+
+    struct FrozenClock(Instant);
+    impl wasi_common::WasiMonotonicClock for FrozenClock {
+        fn resolution(&self) -> Duration {
+            Duration::from_nanos(1)
+        }
+        fn now(&self, _precision: Duration) -> Instant {
+            self.0
+        }
+    }
+
+    let start = Instant::from_std(std::time::Instant::now());
+    let clocks = wasi_common::WasiClocks::new()
+        .with_system(EpochClock)
+        .with_monotonic(FrozenClock(start));
+    (Box::new(StdRng::seed_from_u64(0)), clocks)
+}
+
+/// Opens the host directory of a [PreopenedDir], failing with a [WasiError]
+/// with the errno code of the IO error, for example, `ENOENT` if it does not exist.
+#[cfg(feature = "wasi")]
+fn open_preopened_dir(
+    host_path: &str,
+    unknown_errno: Option<u16>,
+) -> anyhow::Result<cap_std::fs::Dir> {
+    #[cfg(not(feature = "wasmtime"))]
+    use wasmi_wasi::ambient_authority;
+    #[cfg(feature = "wasmtime")]
+    use wasmtime_wasi::ambient_authority;
+
+    cap_std::fs::Dir::open_ambient_dir(host_path, ambient_authority()).map_err(|err| {
+        WasiError::from_io(
+            err,
+            unknown_errno,
+            format!("Could not open the preopened directory {host_path:?}"),
+        )
+        .into()
+    })
+}
+
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct EnvVariable {
     /// The name of the environment variable
     pub name: String,
@@ -94,8 +357,46 @@ pub struct EnvVariable {
     pub value: String,
 }
 
+impl EnvVariable {
+    pub fn new(name: String, value: String) -> Self {
+        EnvVariable { name, value }
+    }
+}
+
+/// The environment variables and arguments of the WASM module,
+/// see [WasiConfigNative::set_process].
+#[derive(Debug)]
+pub struct WasiProcessConfig {
+    pub env: EnvMode,
+    pub args: ArgsMode,
+}
+
+/// The environment variables of the WASM module.
+#[derive(Debug)]
+pub enum EnvMode {
+    /// The variables of the host process.
+    Inherit,
+    /// Only the given variables.
+    Explicit(Vec<EnvVariable>),
+    /// The variables of the host process, where the given variables
+    /// replace the ones with the same name.
+    InheritThenOverride(Vec<EnvVariable>),
+}
+
+/// The process arguments of the WASM module.
+#[derive(Debug)]
+pub enum ArgsMode {
+    /// The arguments of the host process.
+    Inherit,
+    /// Only the given arguments.
+    Explicit(Vec<String>),
+    /// The arguments of the host process followed by the given arguments.
+    InheritThenOverride(Vec<String>),
+}
+
 /// A preopened directory that the WASM module will be able to access
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct PreopenedDir {
     /// The path inside the WASM module.
     /// Should be "/" separated, if you are on windows, you will need to convert the path
@@ -103,6 +404,189 @@ pub struct PreopenedDir {
     /// The path on the host that the WASM module will be able to access
     /// and corresponds to the [wasm_guest_path]
     pub host_path: String,
+    /// The WASI rights allowed for the directory, as a bitset of the
+    /// `rights` flags from `wasi_snapshot_preview1`. For example,
+    /// `fd_read (1 << 1) | path_open (1 << 13) | fd_readdir (1 << 14)` allows
+    /// reading files and listing directories, but not creating or removing them.
+    /// All operations are allowed when it is `null`.
+    ///
+    /// In wasmi, the rights are mapped to the capabilities of wasi-common.
+    pub rights: Option<u64>,
+    /// Whether the directory is stacked on top of the previous preopened
+    /// directories with the same [wasm_guest_path], instead of being
+    /// preopened as a separate directory.
+    ///
+    /// Reads fall through the layers: a path is resolved in the top layer
+    /// first and then in the lower ones, and the directories in several
+    /// layers are listed merged. Writes only go to the top layer, new files
+    /// and directories are created in it, and a file that only exists in a
+    /// lower layer is copied up to the top layer before it is modified.
+    /// The paths that exist in a lower layer fail with `EROFS` when
+    /// renamed or removed.
+    ///
+    /// Not supported in wasmi.
+    pub overlay: bool,
+}
+
+impl PreopenedDir {
+    /// A preopened directory with all the WASI rights.
+    pub fn new(wasm_guest_path: String, host_path: String) -> Self {
+        PreopenedDir {
+            wasm_guest_path,
+            host_path,
+            rights: None,
+            overlay: false,
+        }
+    }
+}
+
+impl WasiConfigNative {
+    /// Sets [inherit_env], [env], [inherit_args] and [args] from [process],
+    /// replacing their previous values.
+    #[allow(deprecated)]
+    pub fn set_process(&mut self, process: WasiProcessConfig) -> &mut Self {
+        (self.inherit_env, self.env) = match process.env {
+            EnvMode::Inherit => (true, vec![]),
+            EnvMode::Explicit(env) => (false, env),
+            EnvMode::InheritThenOverride(env) => (true, env),
+        };
+        (self.inherit_args, self.args) = match process.args {
+            ArgsMode::Inherit => (true, vec![]),
+            ArgsMode::Explicit(args) => (false, args),
+            ArgsMode::InheritThenOverride(args) => (true, args),
+        };
+        self
+    }
+
+    /// The environment variables passed to the module: the inherited ones,
+    /// if [inherit_env] is true, replaced by the ones in [env] with the same name.
+    #[cfg(feature = "wasi")]
+    #[allow(deprecated)]
+    fn process_env(&self) -> Vec<(String, String)> {
+        let mut vars = if self.inherit_env {
+            std::env::vars()
+                .filter(|(name, _)| !self.env.iter().any(|v| &v.name == name))
+                .collect()
+        } else {
+            vec![]
+        };
+        vars.extend(
+            self.env
+                .iter()
+                .map(|EnvVariable { name, value }| (name.clone(), value.clone())),
+        );
+        vars
+    }
+
+    /// The process arguments passed to the module: the inherited ones,
+    /// if [inherit_args] is true, followed by the ones in [args].
+    #[cfg(feature = "wasi")]
+    #[allow(deprecated)]
+    fn process_args(&self) -> Vec<String> {
+        let mut args = if self.inherit_args {
+            std::env::args().collect()
+        } else {
+            vec![]
+        };
+        args.extend(self.args.iter().cloned());
+        args
+    }
+
+    /// Preopens the current working directory of the process at [wasm_guest_path],
+    /// for example, "/" to give the module access to ".".
+    pub fn preopen_cwd(&mut self, wasm_guest_path: String) -> anyhow::Result<&mut Self> {
+        let cwd = std::env::current_dir()?;
+        self.preopened_dirs
+            .push(PreopenedDir::new(wasm_guest_path, path_to_string(&cwd)?));
+        Ok(self)
+    }
+
+    /// Creates a new empty temporary directory and preopens it at [wasm_guest_path].
+    /// The directory is removed with its contents when the returned [TempDir]
+    /// is dropped, it should be kept alive while the module uses it.
+    pub fn preopen_temp(&mut self, wasm_guest_path: String) -> anyhow::Result<TempDir> {
+        let dir = TempDir::new()?;
+        self.preopened_dirs.push(PreopenedDir::new(
+            wasm_guest_path,
+            path_to_string(&dir.path)?,
+        ));
+        Ok(dir)
+    }
+
+    /// Writes [contents] to a new temporary file and passes its path in the
+    /// module as the last argument in [args], for inputs that are too large
+    /// to be passed as process arguments. The directory of [wasm_guest_path]
+    /// is preopened to a new temporary directory with the file, for example,
+    /// "/input/data.bin" preopens "/input" with the file "data.bin".
+    ///
+    /// The file exists until the returned [TempDir] is dropped, which removes it.
+    /// It should be kept alive for the run of the module.
+    #[allow(deprecated)]
+    pub fn arg_file(
+        &mut self,
+        wasm_guest_path: String,
+        contents: &[u8],
+    ) -> anyhow::Result<TempDir> {
+        let (guest_dir, file_name) = match wasm_guest_path.rsplit_once('/') {
+            Some((dir, name)) if !matches!(name, "" | "." | "..") => {
+                (if dir.is_empty() { "/" } else { dir }, name)
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "The guest path {wasm_guest_path:?} must be a file in a directory, \
+                    for example, \"/input/data.bin\"."
+                ))
+            }
+        };
+        let dir = TempDir::new()?;
+        std::fs::write(dir.path.join(file_name), contents)?;
+        self.preopened_dirs.push(PreopenedDir::new(
+            guest_dir.to_string(),
+            path_to_string(&dir.path)?,
+        ));
+        self.args.push(wasm_guest_path);
+        Ok(dir)
+    }
+}
+
+fn path_to_string(path: &std::path::Path) -> anyhow::Result<String> {
+    path.to_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("The path {path:?} is not valid UTF-8."))
+}
+
+/// A temporary directory created by [WasiConfigNative::preopen_temp].
+/// The directory is removed with its contents when dropped.
+#[derive(Debug)]
+pub struct TempDir {
+    path: std::path::PathBuf,
+}
+
+impl TempDir {
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        loop {
+            let count = COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let path =
+                std::env::temp_dir().join(format!("wasm_run_{}_{count}", std::process::id()));
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(TempDir { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// The path of the directory in the host.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
 }
 
 pub struct WasmRuntimeFeatures {
@@ -121,6 +605,71 @@ pub struct WasmRuntimeFeatures {
     /// then it must be enabled manually, perhaps with [ModuleConfig],
     /// and it may be experimental.
     pub default_features: WasmFeatures,
+    /// The [FeatureStability] of each Wasm proposal in the runtime.
+    pub stability: Vec<WasmFeatureStability>,
+}
+
+/// The stability of a Wasm proposal in a runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureStability {
+    /// The proposal is enabled by default.
+    Stable,
+    /// The proposal is supported, but it must be enabled manually
+    /// with [ModuleConfig] and its implementation may change.
+    Experimental,
+    /// The proposal is not supported by the runtime.
+    Unsupported,
+}
+
+/// The [FeatureStability] of the Wasm proposal named [feature],
+/// the name of the field in [WasmFeatures], for example, "relaxed_simd".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmFeatureStability {
+    pub feature: String,
+    pub stability: FeatureStability,
+}
+
+/// The Wasm proposals in [WasmFeatures].
+const PROPOSALS: [(&str, FeatureGetter); 19] = [
+    ("multi_value", |f| f.multi_value),
+    ("bulk_memory", |f| f.bulk_memory),
+    ("reference_types", |f| f.reference_types),
+    ("mutable_global", |f| f.mutable_global),
+    ("saturating_float_to_int", |f| f.saturating_float_to_int),
+    ("sign_extension", |f| f.sign_extension),
+    ("floats", |f| f.floats),
+    ("simd", |f| f.simd),
+    ("relaxed_simd", |f| f.relaxed_simd),
+    ("threads", |f| f.threads),
+    ("multi_memory", |f| f.multi_memory),
+    ("memory64", |f| f.memory64),
+    ("tail_call", |f| f.tail_call),
+    ("extended_const", |f| f.extended_const),
+    ("memory_control", |f| f.memory_control),
+    ("garbage_collection", |f| f.garbage_collection),
+    ("function_references", |f| f.function_references),
+    ("exceptions", |f| f.exceptions),
+    ("component_model", |f| f.component_model),
+];
+
+impl WasmRuntimeFeatures {
+    /// Returns the [FeatureStability] of each proposal. A supported proposal
+    /// is stable if it is enabled by default and experimental otherwise.
+    fn stability(supported: &WasmFeatures, default: &WasmFeatures) -> Vec<WasmFeatureStability> {
+        PROPOSALS
+            .iter()
+            .map(|(feature, get)| WasmFeatureStability {
+                feature: feature.to_string(),
+                stability: if get(default) {
+                    FeatureStability::Stable
+                } else if get(supported) {
+                    FeatureStability::Experimental
+                } else {
+                    FeatureStability::Unsupported
+                },
+            })
+            .collect()
+    }
 }
 
 impl Default for WasmRuntimeFeatures {
@@ -132,6 +681,10 @@ impl Default for WasmRuntimeFeatures {
             is_browser: false,
             supported_features: WasmFeatures::supported(),
             default_features: WasmFeatures::default(),
+            stability: WasmRuntimeFeatures::stability(
+                &WasmFeatures::supported(),
+                &WasmFeatures::default(),
+            ),
         }
     }
 
@@ -143,11 +696,16 @@ impl Default for WasmRuntimeFeatures {
             is_browser: false,
             supported_features: WasmFeatures::supported(),
             default_features: WasmFeatures::default(),
+            stability: WasmRuntimeFeatures::stability(
+                &WasmFeatures::supported(),
+                &WasmFeatures::default(),
+            ),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
 pub struct ModuleConfig {
     /// Is `true` if the [`multi-value`] Wasm proposal is enabled.
     pub multi_value: Option<bool>,
@@ -161,11 +719,128 @@ pub struct ModuleConfig {
     pub wasmi: Option<ModuleConfigWasmi>,
     /// Configuration specific to the wasmtime runtime
     pub wasmtime: Option<ModuleConfigWasmtime>,
+    /// The maximum size in bytes of the module binary.
+    /// Larger modules are rejected with [ModuleTooLarge] before compilation.
+    pub max_module_bytes: Option<usize>,
+    /// The maximum number of pages for the minimum size of the memories
+    /// defined or imported by the module. Larger memories are rejected with
+    /// [MemoryTooLarge] before compilation, since they would be allocated
+    /// when instantiating the module.
+    pub max_memory_pages: Option<u64>,
+    /// The maximum number of memories defined or imported by the module.
+    /// Modules declaring more are rejected with [DeclarationLimitExceeded]
+    /// before compilation. Mostly relevant with the multi-memory proposal.
+    pub max_memories: Option<u32>,
+    /// The maximum number of tables defined or imported by the module.
+    /// Modules declaring more are rejected with [DeclarationLimitExceeded].
+    pub max_tables: Option<u32>,
+    /// The maximum number of globals defined or imported by the module.
+    /// Modules declaring more are rejected with [DeclarationLimitExceeded].
+    pub max_globals: Option<u32>,
+    /// The maximum number of functions defined or imported by the module.
+    /// Modules declaring more are rejected with [DeclarationLimitExceeded].
+    pub max_functions: Option<u32>,
+    /// Is `true` if a signed overflow in `i32.add` shall trap instead of wrapping.
+    /// Defaults to `false`, the wrapping semantics of the Wasm specification.
+    /// Neither wasmi nor wasmtime support this natively, the module binary
+    /// is instrumented with overflow checks before compilation, which
+    /// increases the code size and the execution time.
+    pub trap_on_integer_overflow: Option<bool>,
+    /// Is `true` if the module shall produce the same results on every host.
+    /// Enables [ModuleConfigWasmtime.cranelift_nan_canonicalization] and
+    /// [ModuleConfigWasmtime.relaxed_simd_deterministic] and disables
+    /// [ModuleConfigWasmtime.wasm_threads]. Compiling fails if one of them
+    /// is explicitly configured with the opposite value.
+    /// Use [WasiConfigNative.deterministic] for the WASI clocks and random values.
+    /// Only supported by wasmtime, wasmi does not implement threads
+    /// or relaxed SIMD and ignores it.
+    pub deterministic: Option<bool>,
+}
+
+/// The error returned when a module binary exceeds [ModuleConfig.max_module_bytes].
+#[derive(Debug)]
+pub struct ModuleTooLarge {
+    pub size: usize,
+    pub limit: usize,
+}
+
+impl std::fmt::Display for ModuleTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Module size of {} bytes exceeds the limit of {} bytes.",
+            self.size, self.limit
+        )
+    }
+}
+
+impl std::error::Error for ModuleTooLarge {}
+
+/// The error returned when the minimum size of a memory in a module
+/// exceeds [ModuleConfig.max_memory_pages].
+#[derive(Debug)]
+pub struct MemoryTooLarge {
+    pub minimum_pages: u64,
+    pub limit: u64,
+}
+
+impl std::fmt::Display for MemoryTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Memory minimum of {} pages exceeds the limit of {} pages.",
+            self.minimum_pages, self.limit
+        )
+    }
+}
+
+impl std::error::Error for MemoryTooLarge {}
+
+/// The error returned when the number of memories, tables, globals or functions
+/// of a module exceeds [ModuleConfig.max_memories], [ModuleConfig.max_tables],
+/// [ModuleConfig.max_globals] or [ModuleConfig.max_functions].
+/// The imported items are counted along with the ones defined in the module.
+#[derive(Debug)]
+pub struct DeclarationLimitExceeded {
+    /// The kind of the declarations, one of "memories", "tables", "globals" or "functions".
+    pub declaration: &'static str,
+    pub count: u32,
+    pub limit: u32,
+}
+
+impl std::fmt::Display for DeclarationLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Module declares {} {} exceeding the limit of {}.",
+            self.count, self.declaration, self.limit
+        )
+    }
+}
+
+impl std::error::Error for DeclarationLimitExceeded {}
+
+/// Where the value of a setting listed by [ModuleConfig::explain] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The setting was configured in the [ModuleConfig].
+    User,
+    /// The setting was not configured, the engine default is used.
+    Default,
+    /// The setting is not supported by the current runtime.
+    Unsupported,
 }
 
 #[cfg(feature = "wasmtime")]
-impl From<ModuleConfig> for wasmtime::Config {
-    fn from(c: ModuleConfig) -> Self {
+impl TryFrom<ModuleConfig> for wasmtime::Config {
+    type Error = anyhow::Error;
+
+    fn try_from(mut c: ModuleConfig) -> Result<Self, Self::Error> {
+        if c.deterministic == Some(true) {
+            c.wasmtime
+                .get_or_insert_with(Default::default)
+                .set_deterministic()?;
+        }
         let mut config = Self::new();
         c.multi_value.map(|v| config.wasm_multi_value(v));
         c.bulk_memory.map(|v| config.wasm_bulk_memory(v));
@@ -178,16 +853,36 @@ impl From<ModuleConfig> for wasmtime::Config {
             wtc.debug_info.map(|v| config.debug_info(v));
             wtc.wasm_backtrace.map(|v| config.wasm_backtrace(v));
             wtc.native_unwind_info.map(|v| config.native_unwind_info(v));
-            // wtc.epoch_interruption.map(|v| config.epoch_interruption(v));
+            wtc.epoch_interruption.map(|v| config.epoch_interruption(v));
+            if wtc.max_wasm_stack == Some(0) {
+                return Err(anyhow::anyhow!(
+                    "`max_wasm_stack` must be greater than 0 bytes."
+                ));
+            }
             wtc.max_wasm_stack.map(|v| config.max_wasm_stack(v));
             wtc.wasm_simd.map(|v| config.wasm_simd(v));
             wtc.wasm_relaxed_simd.map(|v| config.wasm_relaxed_simd(v));
             wtc.relaxed_simd_deterministic
                 .map(|v| config.relaxed_simd_deterministic(v));
+            wtc.cranelift_nan_canonicalization
+                .map(|v| config.cranelift_nan_canonicalization(v));
             wtc.wasm_threads.map(|v| config.wasm_threads(v));
             wtc.wasm_multi_memory.map(|v| config.wasm_multi_memory(v));
-            // TODO: wtc.tail_call.map(|v| config.wasm_tail_call(v));
+            wtc.wasm_tail_call.map(|v| config.wasm_tail_call(v));
+            wtc.wasm_function_references
+                .map(|v| config.wasm_function_references(v));
             wtc.wasm_memory64.map(|v| config.wasm_memory64(v));
+            if wtc.wasm_memory_control == Some(true) && !WasmFeatures::supported().memory_control {
+                return Err(anyhow::anyhow!(
+                    "The `memory_control` proposal is not supported by this version of wasmtime."
+                ));
+            }
+            if wtc.wasm_gc == Some(true) && !WasmFeatures::supported().garbage_collection {
+                return Err(anyhow::anyhow!(
+                    "The `gc` proposal is not supported by wasmtime {}.",
+                    WasmRuntimeFeatures::default().version
+                ));
+            }
             // TODO: feature component-model
             // wtc.wasm_component_model.map(|v| config.wasm_component_model(v));
             wtc.static_memory_maximum_size
@@ -200,8 +895,78 @@ impl From<ModuleConfig> for wasmtime::Config {
                 .map(|v| config.parallel_compilation(v));
             wtc.generate_address_map
                 .map(|v| config.generate_address_map(v));
+            if wtc.disable_guard_pages == Some(true) {
+                let static_settings = [
+                    (
+                        "static_memory_forced",
+                        wtc.static_memory_forced == Some(true),
+                    ),
+                    (
+                        "static_memory_maximum_size",
+                        wtc.static_memory_maximum_size.unwrap_or(0) > 0,
+                    ),
+                    (
+                        "static_memory_guard_size",
+                        wtc.static_memory_guard_size.unwrap_or(0) > 0,
+                    ),
+                ];
+                if let Some((name, _)) = static_settings.iter().find(|(_, set)| *set) {
+                    return Err(anyhow::anyhow!(
+                        "`disable_guard_pages: true` conflicts with `{name}`."
+                    ));
+                }
+                // Every memory is dynamic without guard pages, so each access
+                // is bounds checked instead of faulting.
+                config.static_memory_maximum_size(0);
+                config.static_memory_guard_size(0);
+                config.dynamic_memory_guard_size(0);
+                config.guard_before_linear_memory(false);
+            }
+            wtc.macos_use_mach_ports
+                .map(|v| config.macos_use_mach_ports(v));
+            for ProposalToggle { name, enabled } in &wtc.extra_proposals {
+                match EXTRA_PROPOSALS.iter().find(|(known, _)| known == name) {
+                    Some((_, set)) => set(&mut config, *enabled),
+                    None => eprintln!(
+                        "wasm_run: ignoring the proposal `{name}` in `extra_proposals`, \
+                        it is not recognized by wasmtime {}.",
+                        WasmRuntimeFeatures::default().version
+                    ),
+                }
+            }
         }
-        config
+        Ok(config)
+    }
+}
+
+#[cfg(feature = "wasmtime")]
+impl ModuleConfigWasmtime {
+    /// Applies the settings of [ModuleConfig.deterministic],
+    /// failing if one of them was configured with the opposite value.
+    fn set_deterministic(&mut self) -> anyhow::Result<()> {
+        let settings = [
+            (
+                "cranelift_nan_canonicalization",
+                &mut self.cranelift_nan_canonicalization,
+                true,
+            ),
+            (
+                "relaxed_simd_deterministic",
+                &mut self.relaxed_simd_deterministic,
+                true,
+            ),
+            ("wasm_threads", &mut self.wasm_threads, false),
+        ];
+        for (name, setting, value) in settings {
+            if *setting == Some(!value) {
+                return Err(anyhow::anyhow!(
+                    "`deterministic` conflicts with `{name}: {}`.",
+                    !value
+                ));
+            }
+            *setting = Some(value);
+        }
+        Ok(())
     }
 }
 
@@ -224,13 +989,16 @@ impl From<ModuleConfig> for wasmi::Config {
             wic.tail_call.map(|v| config.wasm_tail_call(v));
             wic.extended_const.map(|v| config.wasm_extended_const(v));
             wic.floats.map(|v| config.floats(v));
+            wic.fuel_consumption_mode
+                .map(|v| config.fuel_consumption_mode(v.into()));
             // config.set_fuel_costs(wic.flue_costs);
         }
         config
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
 pub struct ModuleConfigWasmi {
     /// The limits set on the value stack and call stack.
     pub stack_limits: Option<WasiStackLimits>,
@@ -243,19 +1011,51 @@ pub struct ModuleConfigWasmi {
     /// Is `true` if the `saturating-float-to-int` Wasm proposal is enabled.
     pub saturating_float_to_int: Option<bool>,
     /// Is `true` if the [`tail-call`] Wasm proposal is enabled.
-    pub tail_call: Option<bool>, // wasmtime disabled
+    pub tail_call: Option<bool>,
     /// Is `true` if the [`extended-const`] Wasm proposal is enabled.
     pub extended_const: Option<bool>,
     /// Is `true` if Wasm instructions on `f32` and `f64` types are allowed.
     pub floats: Option<bool>,
-    // /// The fuel consumption mode of the `wasmi` [`Engine`](crate::Engine).
-    // // TODO: pub fuel_consumption_mode: FuelConsumptionMode,
+    /// How fuel is consumed when [ModuleConfig.consume_fuel] is enabled.
+    /// Defaults to [FuelConsumptionMode.lazy], wasmi's default.
+    pub fuel_consumption_mode: Option<FuelConsumptionMode>,
     // /// The configured fuel costs of all `wasmi` bytecode instructions.
     // // pub fuel_costs: FuelCosts,
 }
 
+/// The fuel consumption mode of the wasmi engine.
+///
+/// The modes only differ for instructions with a variable cost, such as
+/// `memory.grow`, `memory.copy` or `table.fill`, which fail with an
+/// out of fuel trap if there is not enough fuel to execute them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum FuelConsumptionMode {
+    /// Fuel is consumed only for the work actually performed by an
+    /// instruction, after checking it can be performed. Faster, but the
+    /// fuel may be checked later than the start of the instruction.
+    #[default]
+    lazy,
+    /// The maximum fuel an instruction may consume is consumed before
+    /// executing it, even if it performs less work. More precise at the
+    /// trap boundary, but slower and it may trap sooner.
+    eager,
+}
+
+#[cfg(not(feature = "wasmtime"))]
+impl From<FuelConsumptionMode> for wasmi::FuelConsumptionMode {
+    fn from(mode: FuelConsumptionMode) -> Self {
+        match mode {
+            FuelConsumptionMode::lazy => wasmi::FuelConsumptionMode::Lazy,
+            FuelConsumptionMode::eager => wasmi::FuelConsumptionMode::Eager,
+        }
+    }
+}
+
 /// The configured limits of the Wasm stack.
 #[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
 pub struct WasiStackLimits {
     /// The initial value stack height that the Wasm stack prepares.
     pub initial_value_stack_height: usize,
@@ -265,6 +1065,20 @@ pub struct WasiStackLimits {
     pub maximum_recursion_depth: usize,
 }
 
+impl WasiStackLimits {
+    pub fn new(
+        initial_value_stack_height: usize,
+        maximum_value_stack_height: usize,
+        maximum_recursion_depth: usize,
+    ) -> Self {
+        WasiStackLimits {
+            initial_value_stack_height,
+            maximum_value_stack_height,
+            maximum_recursion_depth,
+        }
+    }
+}
+
 #[cfg(not(feature = "wasmtime"))]
 impl TryFrom<WasiStackLimits> for wasmi::StackLimits {
     type Error = anyhow::Error;
@@ -281,7 +1095,8 @@ impl TryFrom<WasiStackLimits> for wasmi::StackLimits {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
 pub struct ModuleConfigWasmtime {
     // TODO: pub enable_incremental_compilation: Option<bool>, incremental-cache feature
     // TODO: pub async_support: Option<bool>,                  async feature
@@ -291,8 +1106,17 @@ pub struct ModuleConfigWasmtime {
     pub wasm_backtrace: Option<bool>,
     pub native_unwind_info: Option<bool>,
     // TODO: pub wasm_backtrace_details: WasmBacktraceDetails, // Or WASMTIME_BACKTRACE_DETAILS env var
-    //
-    // TODO: pub epoch_interruption: Option<bool>, // vs consume_fuel
+    /// Whether the functions are interrupted with a trap when the epoch of
+    /// the engine reaches the deadline of the store, see
+    /// [WasmRunModuleId::set_epoch_deadline] and [WasmEngine::increment_epoch].
+    /// Unlike [ModuleConfig::consume_fuel], it is driven by a timer
+    /// instead of the executed instructions.
+    /// This is not enabled by default.
+    pub epoch_interruption: Option<bool>,
+    /// The maximum size in bytes of the native stack used by Wasm code.
+    /// Must be greater than 0, defaults to 512 KiB.
+    /// The pooling instance allocator and async stacks are not enabled,
+    /// so there is no per-instance stack size to configure along with it.
     pub max_wasm_stack: Option<usize>,
     /// Whether or not to enable the `threads` WebAssembly feature.
     /// This includes atomics and shared memory as well.
@@ -306,12 +1130,38 @@ pub struct ModuleConfigWasmtime {
     /// Whether [wasm_relaxed_simd] should be deterministic.
     /// This is false by default.
     pub relaxed_simd_deterministic: Option<bool>,
+    /// Whether all NaN values produced by floating point operations
+    /// should be canonicalized to a single bit pattern.
+    /// This is false by default.
+    ///
+    /// Enabling this makes float results reproducible across hosts
+    /// at a small runtime cost. It does not make every float operation
+    /// deterministic, for example, relaxed SIMD instructions are only
+    /// deterministic if [relaxed_simd_deterministic] is also enabled.
+    pub cranelift_nan_canonicalization: Option<bool>,
     /// Whether or not to enable the `multi-memory` WebAssembly feature.
     /// This is not enabled by default.
     pub wasm_multi_memory: Option<bool>,
     /// Whether or not to enable the `memory64` WebAssembly feature.
     /// This is not enabled by default.
     pub wasm_memory64: Option<bool>,
+    /// Whether or not to enable the `tail-call` WebAssembly feature.
+    /// This is not enabled by default.
+    pub wasm_tail_call: Option<bool>,
+    /// Whether or not to enable the `function-references` WebAssembly feature,
+    /// typed function references such as `(ref $t)` and the `call_ref` instruction.
+    /// Depends on the `reference-types` feature. This is not enabled by default.
+    pub wasm_function_references: Option<bool>,
+    /// Whether or not to enable the `memory-control` WebAssembly feature.
+    /// This is not enabled by default.
+    /// Enabling it returns an error while the runtime does not support it,
+    /// see [WasmFeatures::supported].
+    pub wasm_memory_control: Option<bool>,
+    /// Whether or not to enable the `gc` WebAssembly feature.
+    /// This is not enabled by default.
+    /// Enabling it returns an error while the runtime does not support it,
+    /// see [WasmFeatures::supported].
+    pub wasm_gc: Option<bool>,
     // TODO: pub wasm_component_model: Option<bool>, // false component-model feature
     //
     // pub strategy: Strategy,
@@ -321,9 +1171,77 @@ pub struct ModuleConfigWasmtime {
     pub static_memory_forced: Option<bool>,
     pub static_memory_guard_size: Option<u64>,
     pub parallel_compilation: Option<bool>,
+    /// The stack size in bytes of the threads that compile the module in
+    /// [compile_wasm], including the workers of [parallel_compilation].
+    /// Increase it if the compilation of deep modules, for example,
+    /// large generated functions, overflows the stack.
+    /// Must be greater than 0. Defaults to the stack size of the
+    /// Rayon threads, 2 MiB unless `RUST_MIN_STACK` is set.
+    pub compilation_thread_stack_size: Option<usize>,
     pub generate_address_map: Option<bool>,
+    /// Whether to disable the guard pages of linear memories, so that every
+    /// load and store is bounds checked by the compiled code instead of
+    /// faulting on the guard pages. This is false by default.
+    ///
+    /// When true, memories are not reserved up front, which is slower,
+    /// often by 10 to 20%, but reserves less virtual memory and out of
+    /// bounds accesses do not reach the `SIGSEGV` handler.
+    /// This does not run modules without signal-based traps: wasmtime 14.0.4
+    /// has no `signals_based_traps` setting and still relies on its signal
+    /// handlers for other traps, for example, stack overflows.
+    /// Conflicts with the `static_memory_*` fields.
+    pub disable_guard_pages: Option<bool>,
+    /// Whether traps are handled with Mach ports instead of signals on macOS.
+    /// This is true by default, disable it if the host already uses
+    /// Mach exception ports, for example, a crash reporter.
+    /// Ignored on other platforms.
+    pub macos_use_mach_ports: Option<bool>,
+    /// Proposals enabled or disabled by name, for the proposals supported by
+    /// the runtime that do not have a dedicated field.
+    /// The names recognized by wasmtime 14.0.4 are in [EXTRA_PROPOSALS],
+    /// unknown names are ignored with a warning printed to stderr.
+    /// [ModuleConfig::wasm_features] reflects the recognized toggles.
+    pub extra_proposals: Vec<ProposalToggle>,
+}
+
+/// A WebAssembly proposal enabled or disabled by name,
+/// see [ModuleConfigWasmtime::extra_proposals].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposalToggle {
+    /// The name of the proposal, for example, `component-model`.
+    pub name: String,
+    pub enabled: bool,
+}
+
+impl ProposalToggle {
+    pub fn new(name: String, enabled: bool) -> Self {
+        ProposalToggle { name, enabled }
+    }
+}
+
+#[cfg(feature = "wasmtime")]
+impl ModuleConfigWasmtime {
+    /// The value of the last toggle of the proposal [name]
+    /// in [ModuleConfigWasmtime::extra_proposals], the one that is applied.
+    fn extra_proposal(&self, name: &str) -> Option<bool> {
+        self.extra_proposals
+            .iter()
+            .rev()
+            .find(|toggle| toggle.name == name)
+            .map(|toggle| toggle.enabled)
+    }
 }
 
+/// Enables or disables a proposal in a `wasmtime::Config`.
+#[cfg(feature = "wasmtime")]
+pub type ProposalSetter = fn(&mut wasmtime::Config, bool);
+
+/// The proposals that can be configured with [ModuleConfigWasmtime::extra_proposals]
+/// and their `wasmtime::Config` setters, the ones without a dedicated field.
+#[cfg(feature = "wasmtime")]
+pub const EXTRA_PROPOSALS: [(&str, ProposalSetter); 1] =
+    [("component-model", |c, v| _ = c.wasm_component_model(v))];
+
 /// https://docs.wasmtime.dev/stability-wasm-proposals-support.html
 pub struct WasmFeatures {
     /// The WebAssembly `mutable-global` proposal (enabled by default)
@@ -358,6 +1276,10 @@ pub struct WasmFeatures {
     /// across hosts which can lead to host-dependent execution which some
     /// runtimes may not desire.
     pub floats: bool,
+    /// Whether NaN values produced by floating point operations are
+    /// canonicalized, making [floats] results reproducible across hosts.
+    /// Only supported by wasmtime, see [ModuleConfigWasmtime.cranelift_nan_canonicalization].
+    pub nan_canonicalization: bool,
     /// The WebAssembly multi memory proposal
     pub multi_memory: bool,
     /// The WebAssembly exception handling proposal
@@ -372,6 +1294,8 @@ pub struct WasmFeatures {
     pub memory_control: bool,
     /// The WebAssembly garbage collection (GC) proposal
     pub garbage_collection: bool,
+    /// The WebAssembly typed function references proposal
+    pub function_references: bool,
     /// WebAssembly external types reflection or, for browsers,
     /// the js-types proposal (https://github.com/WebAssembly/js-types/blob/main/proposals/js-types/Overview.md)
     pub type_reflection: bool,
@@ -402,9 +1326,9 @@ pub struct WasmWasiFeatures {
     pub threads: bool,
 }
 
-impl WasmWasiFeatures {
+impl Default for WasmWasiFeatures {
     /// Returns the default set of Wasi features.
-    pub fn default() -> WasmWasiFeatures {
+    fn default() -> WasmWasiFeatures {
         WasmWasiFeatures {
             io: true,
             filesystem: true,
@@ -418,15 +1342,17 @@ impl WasmWasiFeatures {
             threads: false,
         }
     }
+}
 
+impl WasmWasiFeatures {
     pub fn supported() -> WasmWasiFeatures {
         WasmWasiFeatures::default()
     }
 }
 
-impl WasmFeatures {
+impl Default for WasmFeatures {
     /// Returns the default set of Wasm features.
-    pub fn default() -> WasmFeatures {
+    fn default() -> WasmFeatures {
         #[cfg(feature = "wasmtime")]
         {
             return WasmFeatures {
@@ -436,17 +1362,19 @@ impl WasmFeatures {
                 mutable_global: true,
                 saturating_float_to_int: true,
                 sign_extension: true,
-                extended_const: true,
                 floats: true,
+                nan_canonicalization: false,
                 simd: true,
                 relaxed_simd: false,
-                threads: false,      // Default false
-                multi_memory: false, // Default false
-                memory64: false,     // Default false
+                threads: false,             // Default false
+                multi_memory: false,        // Default false
+                memory64: false,            // Default false
+                tail_call: false,           // Default false
+                function_references: false, // Default false
                 // Unsupported
+                extended_const: false,
                 component_model: false, // Feature
                 garbage_collection: false,
-                tail_call: false,
                 exceptions: false,
                 memory_control: false,
                 type_reflection: true,
@@ -470,8 +1398,10 @@ impl WasmFeatures {
             extended_const: false, // Default false
             floats: true,
             // Unsupported
+            nan_canonicalization: false,
             component_model: false,
             garbage_collection: false,
+            function_references: false,
             simd: false,
             relaxed_simd: false,
             threads: false,
@@ -487,7 +1417,12 @@ impl WasmFeatures {
             },
         }
     }
+}
+
+/// Returns the value of a feature in [WasmFeatures].
+type FeatureGetter = fn(&WasmFeatures) -> bool;
 
+impl WasmFeatures {
     pub fn supported() -> WasmFeatures {
         #[cfg(feature = "wasmtime")]
         {
@@ -498,18 +1433,20 @@ impl WasmFeatures {
                 mutable_global: true,
                 saturating_float_to_int: true,
                 sign_extension: true,
-                extended_const: true,
                 floats: true,
+                nan_canonicalization: true,
                 simd: true,
                 relaxed_simd: true,
                 threads: true,
                 multi_memory: true,
                 memory64: true,
+                tail_call: true,
+                function_references: true,
                 // Unsupported
+                extended_const: false,
                 component_model: false, // Feature
                 garbage_collection: false,
                 exceptions: false,
-                tail_call: false,
                 memory_control: false,
                 type_reflection: true,
                 wasi_features: if cfg!(feature = "wasi") {
@@ -532,8 +1469,10 @@ impl WasmFeatures {
             extended_const: true,
             floats: true,
             // Unsupported
+            nan_canonicalization: false,
             component_model: false,
             garbage_collection: false,
+            function_references: false,
             simd: false,
             relaxed_simd: false,
             threads: false,
@@ -551,7 +1490,387 @@ impl WasmFeatures {
     }
 }
 
+impl WasmFeatures {
+    /// Only the proposals of the WebAssembly 1.0 (MVP) specification,
+    /// `mutable_global` and `floats`. All the other proposals are disabled.
+    pub fn minimal() -> WasmFeatures {
+        WasmFeatures {
+            mutable_global: true,
+            floats: true,
+            saturating_float_to_int: false,
+            sign_extension: false,
+            reference_types: false,
+            multi_value: false,
+            bulk_memory: false,
+            simd: false,
+            relaxed_simd: false,
+            threads: false,
+            tail_call: false,
+            nan_canonicalization: false,
+            multi_memory: false,
+            exceptions: false,
+            memory64: false,
+            extended_const: false,
+            component_model: false,
+            memory_control: false,
+            garbage_collection: false,
+            function_references: false,
+            type_reflection: true,
+            wasi_features: if cfg!(feature = "wasi") {
+                Some(WasmWasiFeatures::default())
+            } else {
+                None
+            },
+        }
+    }
+
+    /// The [minimal] features with the `simd`, `bulk_memory`, `reference_types`
+    /// and `multi_value` proposals, the ones commonly emitted by compilers.
+    /// A proposal that is not [supported] by the runtime is disabled,
+    /// for example, `simd` in wasmi.
+    pub fn modern() -> WasmFeatures {
+        let supported = WasmFeatures::supported();
+        WasmFeatures {
+            simd: supported.simd,
+            bulk_memory: supported.bulk_memory,
+            reference_types: supported.reference_types,
+            multi_value: supported.multi_value,
+            ..WasmFeatures::minimal()
+        }
+    }
+
+    /// Every proposal [supported] by the runtime, including the experimental
+    /// ones such as `threads` or `memory64` in wasmtime.
+    /// Unlike [supported], `nan_canonicalization` is disabled,
+    /// since it is not a proposal.
+    pub fn all_supported() -> WasmFeatures {
+        WasmFeatures {
+            nan_canonicalization: false,
+            ..WasmFeatures::supported()
+        }
+    }
+
+    /// The names of the proposals enabled in [required] that are disabled
+    /// in these features, for example, the proposals a module requires
+    /// that are not available in the runtime.
+    pub fn missing_from(&self, required: &WasmFeatures) -> Vec<&'static str> {
+        PROPOSALS
+            .iter()
+            .filter(|(_, get)| get(required) && !get(self))
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// Describes the proposals of [missing_from] in one line each,
+    /// for example, `+simd (required, not available)`.
+    /// Empty if every required proposal is available.
+    pub fn describe_diff(&self, required: &WasmFeatures) -> String {
+        self.missing_from(required)
+            .iter()
+            .map(|name| format!("+{name} (required, not available)"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A named set of Wasm proposals, used to create a [ModuleConfig]
+/// with [ModuleConfig::from_preset].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// [WasmFeatures::minimal]
+    Minimal,
+    /// [WasmFeatures::modern]
+    Modern,
+    /// [WasmFeatures::all_supported]
+    AllSupported,
+}
+
+impl Preset {
+    /// The [WasmFeatures] enabled by the preset.
+    pub fn features(self) -> WasmFeatures {
+        match self {
+            Preset::Minimal => WasmFeatures::minimal(),
+            Preset::Modern => WasmFeatures::modern(),
+            Preset::AllSupported => WasmFeatures::all_supported(),
+        }
+    }
+}
+
 impl ModuleConfig {
+    /// Checks [max_module_bytes], [max_memory_pages] and the declaration limits
+    /// such as [max_memories] for the [module_wasm] binary.
+    /// Invalid binaries are not rejected, they will fail when compiling.
+    pub fn check_module_limits(&self, module_wasm: &[u8]) -> anyhow::Result<()> {
+        if let Some(limit) = self.max_module_bytes {
+            if module_wasm.len() > limit {
+                return Err(ModuleTooLarge {
+                    size: module_wasm.len(),
+                    limit,
+                }
+                .into());
+            }
+        }
+        let declaration_limits = [
+            ("memories", self.max_memories),
+            ("tables", self.max_tables),
+            ("globals", self.max_globals),
+            ("functions", self.max_functions),
+        ];
+        if self.max_memory_pages.is_none()
+            && declaration_limits.iter().all(|(_, limit)| limit.is_none())
+        {
+            return Ok(());
+        }
+        let check = |ty: wasmparser::MemoryType| match self.max_memory_pages {
+            Some(limit) if ty.initial > limit => Err(MemoryTooLarge {
+                minimum_pages: ty.initial,
+                limit,
+            }),
+            _ => Ok(()),
+        };
+        // The number of memories, tables, globals and functions, in the order of [declaration_limits].
+        let mut counts = [0u32; 4];
+        for payload in wasmparser::Parser::new(0).parse_all(module_wasm) {
+            match payload {
+                Ok(wasmparser::Payload::ImportSection(reader)) => {
+                    for import in reader.into_iter().flatten() {
+                        match import.ty {
+                            wasmparser::TypeRef::Memory(ty) => {
+                                check(ty)?;
+                                counts[0] += 1;
+                            }
+                            wasmparser::TypeRef::Table(_) => counts[1] += 1,
+                            wasmparser::TypeRef::Global(_) => counts[2] += 1,
+                            wasmparser::TypeRef::Func(_) => counts[3] += 1,
+                            wasmparser::TypeRef::Tag(_) => {}
+                        }
+                    }
+                }
+                Ok(wasmparser::Payload::MemorySection(reader)) => {
+                    counts[0] += reader.count();
+                    for ty in reader.into_iter().flatten() {
+                        check(ty)?;
+                    }
+                }
+                Ok(wasmparser::Payload::TableSection(reader)) => counts[1] += reader.count(),
+                Ok(wasmparser::Payload::GlobalSection(reader)) => counts[2] += reader.count(),
+                Ok(wasmparser::Payload::FunctionSection(reader)) => counts[3] += reader.count(),
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+        for ((declaration, limit), count) in declaration_limits.into_iter().zip(counts) {
+            if let Some(limit) = limit.filter(|limit| count > *limit) {
+                return Err(DeclarationLimitExceeded {
+                    declaration,
+                    count,
+                    limit,
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a [ModuleConfig] that enables the proposals of the [preset]
+    /// and disables the other configurable proposals.
+    /// Proposals that can not be disabled in the runtime remain enabled,
+    /// for example, `sign_extension` in wasmtime.
+    /// Use [ModuleConfig::wasm_features] for the effective features.
+    pub fn from_preset(preset: Preset) -> ModuleConfig {
+        let f = preset.features();
+        ModuleConfig {
+            multi_value: Some(f.multi_value),
+            bulk_memory: Some(f.bulk_memory),
+            reference_types: Some(f.reference_types),
+            wasmi: Some(ModuleConfigWasmi {
+                mutable_global: Some(f.mutable_global),
+                sign_extension: Some(f.sign_extension),
+                saturating_float_to_int: Some(f.saturating_float_to_int),
+                tail_call: Some(f.tail_call),
+                extended_const: Some(f.extended_const),
+                floats: Some(f.floats),
+                ..Default::default()
+            }),
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_threads: Some(f.threads),
+                wasm_simd: Some(f.simd),
+                wasm_relaxed_simd: Some(f.relaxed_simd),
+                wasm_multi_memory: Some(f.multi_memory),
+                wasm_memory64: Some(f.memory64),
+                wasm_tail_call: Some(f.tail_call),
+                wasm_function_references: Some(f.function_references),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Applies the transformations enabled in the config to the [module_wasm] binary.
+    pub fn transform_module(&self, module_wasm: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        if self.trap_on_integer_overflow == Some(true) {
+            return crate::transform::trap_on_i32_add_overflow(&module_wasm);
+        }
+        Ok(module_wasm)
+    }
+
+    /// Returns the effective settings of the [`ModuleConfig`] for the current runtime
+    /// as `(name, value, source)`, the Wasm features from [ModuleConfig::wasm_features]
+    /// followed by the module limits and transformations.
+    /// The names and their order do not depend on the configured values.
+    pub fn explain(&self) -> Vec<(String, String, Source)> {
+        let wasmtime = |get: fn(&ModuleConfigWasmtime) -> bool| {
+            cfg!(feature = "wasmtime") && self.wasmtime.as_ref().is_some_and(get)
+        };
+        let wasmi = |get: fn(&ModuleConfigWasmi) -> bool| {
+            !cfg!(feature = "wasmtime") && self.wasmi.as_ref().is_some_and(get)
+        };
+        let features: [(&str, FeatureGetter, bool); 20] = [
+            ("multi_value", |f| f.multi_value, self.multi_value.is_some()),
+            ("bulk_memory", |f| f.bulk_memory, self.bulk_memory.is_some()),
+            (
+                "reference_types",
+                |f| f.reference_types,
+                self.reference_types.is_some(),
+            ),
+            (
+                "mutable_global",
+                |f| f.mutable_global,
+                wasmi(|w| w.mutable_global.is_some()),
+            ),
+            (
+                "saturating_float_to_int",
+                |f| f.saturating_float_to_int,
+                wasmi(|w| w.saturating_float_to_int.is_some()),
+            ),
+            (
+                "sign_extension",
+                |f| f.sign_extension,
+                wasmi(|w| w.sign_extension.is_some()),
+            ),
+            ("floats", |f| f.floats, wasmi(|w| w.floats.is_some())),
+            (
+                "nan_canonicalization",
+                |f| f.nan_canonicalization,
+                wasmtime(|w| w.cranelift_nan_canonicalization.is_some()),
+            ),
+            ("simd", |f| f.simd, wasmtime(|w| w.wasm_simd.is_some())),
+            (
+                "relaxed_simd",
+                |f| f.relaxed_simd,
+                wasmtime(|w| w.wasm_relaxed_simd.is_some()),
+            ),
+            (
+                "threads",
+                |f| f.threads,
+                wasmtime(|w| w.wasm_threads.is_some()),
+            ),
+            (
+                "multi_memory",
+                |f| f.multi_memory,
+                wasmtime(|w| w.wasm_multi_memory.is_some()),
+            ),
+            (
+                "memory64",
+                |f| f.memory64,
+                wasmtime(|w| w.wasm_memory64.is_some()),
+            ),
+            (
+                "tail_call",
+                |f| f.tail_call,
+                wasmtime(|w| w.wasm_tail_call.is_some()) || wasmi(|w| w.tail_call.is_some()),
+            ),
+            (
+                "extended_const",
+                |f| f.extended_const,
+                wasmi(|w| w.extended_const.is_some()),
+            ),
+            (
+                "memory_control",
+                |f| f.memory_control,
+                wasmtime(|w| w.wasm_memory_control.is_some()),
+            ),
+            (
+                "garbage_collection",
+                |f| f.garbage_collection,
+                wasmtime(|w| w.wasm_gc.is_some()),
+            ),
+            (
+                "function_references",
+                |f| f.function_references,
+                wasmtime(|w| w.wasm_function_references.is_some()),
+            ),
+            ("exceptions", |f| f.exceptions, false),
+            ("component_model", |f| f.component_model, false),
+        ];
+
+        let effective = self.wasm_features();
+        let supported = WasmFeatures::supported();
+        let source = |configured: bool| {
+            if configured {
+                Source::User
+            } else {
+                Source::Default
+            }
+        };
+        let mut settings = features
+            .into_iter()
+            .map(|(name, get, configured)| {
+                let source = if get(&supported) {
+                    source(configured)
+                } else {
+                    Source::Unsupported
+                };
+                (name.to_string(), get(&effective).to_string(), source)
+            })
+            .collect::<Vec<_>>();
+
+        let limit = |value: Option<String>| value.unwrap_or_else(|| "unlimited".to_string());
+        settings.extend([
+            (
+                "consume_fuel".to_string(),
+                self.consume_fuel.unwrap_or(false).to_string(),
+                source(self.consume_fuel.is_some()),
+            ),
+            (
+                "max_module_bytes".to_string(),
+                limit(self.max_module_bytes.map(|v| v.to_string())),
+                source(self.max_module_bytes.is_some()),
+            ),
+            (
+                "max_memory_pages".to_string(),
+                limit(self.max_memory_pages.map(|v| v.to_string())),
+                source(self.max_memory_pages.is_some()),
+            ),
+            (
+                "max_memories".to_string(),
+                limit(self.max_memories.map(|v| v.to_string())),
+                source(self.max_memories.is_some()),
+            ),
+            (
+                "max_tables".to_string(),
+                limit(self.max_tables.map(|v| v.to_string())),
+                source(self.max_tables.is_some()),
+            ),
+            (
+                "max_globals".to_string(),
+                limit(self.max_globals.map(|v| v.to_string())),
+                source(self.max_globals.is_some()),
+            ),
+            (
+                "max_functions".to_string(),
+                limit(self.max_functions.map(|v| v.to_string())),
+                source(self.max_functions.is_some()),
+            ),
+            (
+                "trap_on_integer_overflow".to_string(),
+                self.trap_on_integer_overflow.unwrap_or(false).to_string(),
+                source(self.trap_on_integer_overflow.is_some()),
+            ),
+        ]);
+        settings
+    }
+
     /// Returns the [`WasmFeatures`] represented by the [`ModuleConfig`].
     // TODO: use features crate
     #[allow(unreachable_code)]
@@ -568,8 +1887,10 @@ impl ModuleConfig {
                 mutable_global: true,
                 saturating_float_to_int: true,
                 sign_extension: true,
-                extended_const: true,
                 floats: true,
+                nan_canonicalization: w
+                    .and_then(|w| w.cranelift_nan_canonicalization)
+                    .unwrap_or(def.nan_canonicalization),
 
                 simd: w.and_then(|w| w.wasm_simd).unwrap_or(def.simd),
                 threads: w.and_then(|w| w.wasm_threads).unwrap_or(def.threads),
@@ -580,12 +1901,18 @@ impl ModuleConfig {
                 relaxed_simd: w
                     .and_then(|w| w.wasm_relaxed_simd)
                     .unwrap_or(def.relaxed_simd),
-                // Unsupported
-                component_model: false, // Feature
+                tail_call: w.and_then(|w| w.wasm_tail_call).unwrap_or(def.tail_call),
+                function_references: w
+                    .and_then(|w| w.wasm_function_references)
+                    .unwrap_or(def.function_references),
+                component_model: w
+                    .and_then(|w| w.extra_proposal("component-model"))
+                    .unwrap_or(def.component_model),
+                // Unsupported, enabling them returns an error, see [WasmFeatures::supported]
+                memory_control: false,
                 garbage_collection: false,
-                tail_call: false,
+                extended_const: false,
                 exceptions: false,
-                memory_control: false,
                 type_reflection: true,
                 wasi_features: if cfg!(feature = "wasi") {
                     Some(WasmWasiFeatures::default())
@@ -615,7 +1942,9 @@ impl ModuleConfig {
                 .unwrap_or(def.extended_const),
             floats: w.and_then(|w| w.floats).unwrap_or(def.floats),
             // Unsupported
+            nan_canonicalization: false,
             garbage_collection: false,
+            function_references: false,
             component_model: false,
             simd: false,
             relaxed_simd: false,
@@ -633,3 +1962,868 @@ impl ModuleConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::compile_wasm;
+
+    /// A minimal module for each proposal that only validates
+    /// when the proposal is enabled in the engine.
+    const PROPOSAL_MODULES: &[(&str, FeatureGetter, &str)] = &[
+        (
+            "multi_value",
+            |f| f.multi_value,
+            "(module (func (result i32 i32) i32.const 0 i32.const 1))",
+        ),
+        (
+            "bulk_memory",
+            |f| f.bulk_memory,
+            "(module (memory 1) (func (memory.fill (i32.const 0) (i32.const 0) (i32.const 0))))",
+        ),
+        (
+            "reference_types",
+            |f| f.reference_types,
+            "(module (func (result externref) ref.null extern))",
+        ),
+        (
+            "mutable_global",
+            |f| f.mutable_global,
+            "(module (global (export \"g\") (mut i32) (i32.const 0)))",
+        ),
+        (
+            "saturating_float_to_int",
+            |f| f.saturating_float_to_int,
+            "(module (func (param f32) (result i32) local.get 0 i32.trunc_sat_f32_s))",
+        ),
+        (
+            "sign_extension",
+            |f| f.sign_extension,
+            "(module (func (param i32) (result i32) local.get 0 i32.extend8_s))",
+        ),
+        (
+            "simd",
+            |f| f.simd,
+            "(module (func (result v128) v128.const i64x2 0 0))",
+        ),
+        (
+            "relaxed_simd",
+            |f| f.relaxed_simd,
+            "(module (func (param v128 v128) (result v128) local.get 0 local.get 1 i8x16.relaxed_swizzle))",
+        ),
+        (
+            "threads",
+            |f| f.threads,
+            "(module (memory 1 1 shared))",
+        ),
+        (
+            "tail_call",
+            |f| f.tail_call,
+            "(module (func $f return_call $f))",
+        ),
+        (
+            "multi_memory",
+            |f| f.multi_memory,
+            "(module (memory 1) (memory 1))",
+        ),
+        (
+            "memory64",
+            |f| f.memory64,
+            "(module (memory i64 1))",
+        ),
+        (
+            "extended_const",
+            |f| f.extended_const,
+            "(module (global i32 (i32.add (i32.const 1) (i32.const 2))))",
+        ),
+        (
+            "function_references",
+            |f| f.function_references,
+            "(module (type $t (func)) (func (param (ref $t))))",
+        ),
+        (
+            "floats",
+            |f| f.floats,
+            "(module (func (result f32) f32.const 0))",
+        ),
+    ];
+
+    fn assert_features_parity(config: fn() -> ModuleConfig) {
+        let features = config().wasm_features();
+        for (name, enabled, wat) in PROPOSAL_MODULES {
+            let wasm = wat::parse_str(wat).unwrap();
+            let compiled = compile_wasm(wasm, config()).is_ok();
+            assert_eq!(
+                enabled(&features),
+                compiled,
+                "`{name}` reported by wasm_features does not match the engine",
+            );
+        }
+    }
+
+    #[test]
+    fn wasm_features_parity_default() {
+        assert_features_parity(ModuleConfig::default);
+    }
+
+    #[test]
+    fn wasm_features_parity_all_enabled() {
+        assert_features_parity(|| ModuleConfig {
+            multi_value: Some(true),
+            bulk_memory: Some(true),
+            reference_types: Some(true),
+            wasmi: Some(ModuleConfigWasmi {
+                tail_call: Some(true),
+                extended_const: Some(true),
+                ..Default::default()
+            }),
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_threads: Some(true),
+                wasm_simd: Some(true),
+                wasm_relaxed_simd: Some(true),
+                wasm_multi_memory: Some(true),
+                wasm_memory64: Some(true),
+                wasm_tail_call: Some(true),
+                wasm_function_references: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn wasm_features_parity_disabled() {
+        assert_features_parity(|| ModuleConfig {
+            multi_value: Some(false),
+            bulk_memory: Some(false),
+            reference_types: Some(false),
+            wasmi: Some(ModuleConfigWasmi {
+                mutable_global: Some(false),
+                sign_extension: Some(false),
+                saturating_float_to_int: Some(false),
+                floats: Some(false),
+                ..Default::default()
+            }),
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_simd: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn describe_features_diff() {
+        let available = WasmFeatures::minimal();
+        let required = WasmFeatures {
+            simd: true,
+            threads: true,
+            ..WasmFeatures::minimal()
+        };
+        assert_eq!(available.missing_from(&required), ["simd", "threads"]);
+        assert_eq!(
+            available.describe_diff(&required),
+            "+simd (required, not available)\n+threads (required, not available)"
+        );
+        assert_eq!(required.describe_diff(&available), "");
+    }
+
+    #[test]
+    fn feature_presets() {
+        let supported = WasmFeatures::supported();
+        let minimal = WasmFeatures::minimal();
+        let modern = WasmFeatures::modern();
+        let all_supported = WasmFeatures::all_supported();
+        for (name, get) in PROPOSALS {
+            assert!(!get(&minimal) || get(&modern), "`{name}` in minimal");
+            assert!(!get(&modern) || get(&supported), "`{name}` in modern");
+            assert_eq!(get(&all_supported), get(&supported), "`{name}`");
+        }
+        let enabled = PROPOSALS
+            .iter()
+            .filter(|(_, get)| get(&minimal))
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        assert_eq!(enabled, ["mutable_global", "floats"]);
+        assert_eq!(modern.simd, cfg!(feature = "wasmtime"));
+        assert!(modern.bulk_memory && modern.reference_types && modern.multi_value);
+
+        assert_features_parity(|| ModuleConfig::from_preset(Preset::Minimal));
+        assert_features_parity(|| ModuleConfig::from_preset(Preset::Modern));
+        assert_features_parity(|| ModuleConfig::from_preset(Preset::AllSupported));
+        for preset in [Preset::Minimal, Preset::Modern, Preset::AllSupported] {
+            let features = preset.features();
+            let effective = ModuleConfig::from_preset(preset).wasm_features();
+            for (name, get) in PROPOSALS {
+                assert!(!get(&features) || get(&effective), "`{name}` in {preset:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn wasm_runtime_features_stability() {
+        let features = WasmRuntimeFeatures::default();
+        assert_eq!(features.stability.len(), PROPOSALS.len());
+        for (WasmFeatureStability { feature, stability }, (name, get)) in
+            features.stability.iter().zip(PROPOSALS)
+        {
+            assert_eq!(feature, name);
+            let expected = match (
+                get(&features.supported_features),
+                get(&features.default_features),
+            ) {
+                (_, true) => FeatureStability::Stable,
+                (true, false) => FeatureStability::Experimental,
+                (false, false) => FeatureStability::Unsupported,
+            };
+            assert_eq!(*stability, expected, "`{feature}`");
+        }
+    }
+
+    #[cfg(not(feature = "wasmtime"))]
+    #[test]
+    fn fuel_consumption_modes_trap() {
+        let wat = r#"(module (func (export "run") (loop (br 0))))"#;
+        let wasm = wat::parse_str(wat).unwrap();
+        for mode in [FuelConsumptionMode::lazy, FuelConsumptionMode::eager] {
+            let config = wasmi::Config::from(ModuleConfig {
+                consume_fuel: Some(true),
+                wasmi: Some(ModuleConfigWasmi {
+                    fuel_consumption_mode: Some(mode),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+            let engine = wasmi::Engine::new(&config);
+            let module = wasmi::Module::new(&engine, &wasm[..]).unwrap();
+            let mut store = wasmi::Store::new(&engine, ());
+            store.add_fuel(10_000).unwrap();
+            let instance = wasmi::Linker::<()>::new(&engine)
+                .instantiate(&mut store, &module)
+                .unwrap()
+                .start(&mut store)
+                .unwrap();
+            let run = instance.get_typed_func::<(), ()>(&store, "run").unwrap();
+            let trap = run.call(&mut store, ()).unwrap_err();
+            assert!(
+                matches!(trap.trap_code(), Some(wasmi::core::TrapCode::OutOfFuel)),
+                "{mode:?}: {trap}"
+            );
+        }
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn max_wasm_stack_validation() {
+        let config = |max_wasm_stack| ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                max_wasm_stack: Some(max_wasm_stack),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let wasm = wat::parse_str("(module)").unwrap();
+        assert!(compile_wasm(wasm.clone(), config(256 * 1024)).is_ok());
+        let err = compile_wasm(wasm, config(0)).err().unwrap();
+        assert!(err.to_string().contains("`max_wasm_stack`"), "{err}");
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn compilation_thread_stack_size() {
+        let config = |stack_size| ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                parallel_compilation: Some(true),
+                compilation_thread_stack_size: Some(stack_size),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // Deeply nested blocks use more stack while compiling.
+        let depth = 1000;
+        let wat = format!(
+            "(module (func (export \"f\") {} {}) (func (export \"g\") (result i32) i32.const 1))",
+            "(block ".repeat(depth),
+            ")".repeat(depth),
+        );
+        let wasm = wat::parse_str(wat).unwrap();
+        assert!(compile_wasm(wasm.clone(), config(16 * 1024 * 1024)).is_ok());
+        // The pool is reused for the same stack size.
+        assert!(compile_wasm(wasm.clone(), config(16 * 1024 * 1024)).is_ok());
+        let err = compile_wasm(wasm, config(0)).err().unwrap();
+        assert!(
+            err.to_string().contains("`compilation_thread_stack_size`"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn extra_proposals() {
+        let config = |toggles: Vec<ProposalToggle>| ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                extra_proposals: toggles,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let enabled = config(vec![
+            ProposalToggle::new("component-model".to_string(), false),
+            ProposalToggle::new("component-model".to_string(), true),
+        ]);
+        assert!(enabled.wasm_features().component_model);
+        assert!(wasmtime::Config::try_from(enabled).is_ok());
+        assert!(!config(vec![]).wasm_features().component_model);
+
+        // Unknown names are ignored
+        let unknown = config(vec![ProposalToggle::new(
+            "wide-arithmetic".to_string(),
+            true,
+        )]);
+        let wasm = wat::parse_str("(module (memory 1))").unwrap();
+        assert!(compile_wasm(wasm, unknown).is_ok());
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn disable_guard_pages() {
+        let wat = r#"(module
+            (memory 1)
+            (func (export "load") (param i32) (result i32) (i32.load (local.get 0)))
+        )"#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let config = |disable_guard_pages, static_memory_forced| ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                disable_guard_pages,
+                static_memory_forced,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        for disable_guard_pages in [None, Some(false), Some(true)] {
+            let config = wasmtime::Config::try_from(config(disable_guard_pages, None)).unwrap();
+            let engine = wasmtime::Engine::new(&config).unwrap();
+            let module = wasmtime::Module::new(&engine, &wasm).unwrap();
+            let mut store = wasmtime::Store::new(&engine, ());
+            let instance = wasmtime::Instance::new(&mut store, &module, &[]).unwrap();
+            let load = instance
+                .get_typed_func::<i32, i32>(&mut store, "load")
+                .unwrap();
+            assert_eq!(load.call(&mut store, 0).unwrap(), 0);
+            let err = load.call(&mut store, 65536).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<wasmtime::Trap>(),
+                Some(&wasmtime::Trap::MemoryOutOfBounds),
+                "{disable_guard_pages:?}: {err}"
+            );
+        }
+        let err = compile_wasm(wasm, config(Some(true), Some(true)))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "`disable_guard_pages: true` conflicts with `static_memory_forced`."
+        );
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn wasm_gc_unsupported() {
+        let config = || ModuleConfig {
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_gc: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // Not reported as enabled since the module can not be compiled
+        assert!(!config().wasm_features().garbage_collection);
+        let wasm = wat::parse_str("(module)").unwrap();
+        let err = compile_wasm(wasm, config()).err().unwrap();
+        assert!(err.to_string().contains("14.0.4"), "{err}");
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn deterministic_conflicts() {
+        let config = |wasmtime| ModuleConfig {
+            deterministic: Some(true),
+            wasmtime,
+            ..Default::default()
+        };
+        let wasm = wat::parse_str("(module)").unwrap();
+        assert!(compile_wasm(wasm.clone(), config(None)).is_ok());
+        let threads = ModuleConfigWasmtime {
+            wasm_threads: Some(true),
+            ..Default::default()
+        };
+        let err = compile_wasm(wasm.clone(), config(Some(threads)))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "`deterministic` conflicts with `wasm_threads: true`."
+        );
+        let nan = ModuleConfigWasmtime {
+            cranelift_nan_canonicalization: Some(true),
+            ..Default::default()
+        };
+        assert!(compile_wasm(wasm, config(Some(nan))).is_ok());
+    }
+
+    #[cfg(feature = "wasi")]
+    #[test]
+    #[allow(deprecated)]
+    fn wasi_process_config() {
+        let (name, _) = std::env::vars().next().unwrap();
+        let mut config = WasiConfigNative::default();
+        config.set_process(WasiProcessConfig {
+            env: EnvMode::InheritThenOverride(vec![EnvVariable::new(
+                name.clone(),
+                "overridden".to_string(),
+            )]),
+            args: ArgsMode::Explicit(vec!["main.wasm".to_string()]),
+        });
+        let env = config.process_env();
+        assert_eq!(env.len(), std::env::vars().count());
+        let values = env.iter().filter(|(n, _)| *n == name).collect::<Vec<_>>();
+        assert_eq!(values, [&(name.clone(), "overridden".to_string())]);
+        assert_eq!(config.process_args(), ["main.wasm"]);
+
+        config.set_process(WasiProcessConfig {
+            env: EnvMode::Explicit(vec![]),
+            args: ArgsMode::InheritThenOverride(vec!["--verbose".to_string()]),
+        });
+        assert!(!config.inherit_env);
+        assert!(config.process_env().is_empty());
+        let args = config.process_args();
+        assert_eq!(args.len(), std::env::args().count() + 1);
+        assert_eq!(args.last().unwrap(), "--verbose");
+    }
+
+    /// Lists a preopened directory from a module and reads the metadata
+    /// of its entries, the results are the same in wasmtime and wasmi.
+    #[cfg(feature = "wasi")]
+    #[test]
+    fn wasi_preopened_dir_listing() -> anyhow::Result<()> {
+        use crate::api::{module_builder, parse_wat_format};
+        use crate::types::{ExternalValue, WasmVal};
+
+        // fd_read, path_open, fd_readdir, path_filestat_get and fd_filestat_get
+        const READ_RIGHTS: u64 = 1 << 1 | 1 << 13 | 1 << 14 | 1 << 18 | 1 << 21;
+        const LISTING_WAT: &str = r#"(module
+            (import "wasi_snapshot_preview1" "fd_readdir"
+                (func $fd_readdir (param i32 i32 i32 i64 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "path_filestat_get"
+                (func $path_filestat_get (param i32 i32 i32 i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "path_open"
+                (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "fd_filestat_get"
+                (func $fd_filestat_get (param i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "fd_close"
+                (func $fd_close (param i32) (result i32)))
+            (memory (export "memory") 1)
+            ;; The entries of fd 3 in [1024, 5120), the used length at 0
+            (func (export "list") (result i32)
+                (call $fd_readdir (i32.const 3) (i32.const 1024) (i32.const 4096)
+                    (i64.const 0) (i32.const 0))
+            )
+            ;; The filestat of the path at 256 with length $len, written at 64
+            (func (export "path_stat") (param $len i32) (result i32)
+                (call $path_filestat_get (i32.const 3) (i32.const 0) (i32.const 256)
+                    (local.get $len) (i32.const 64))
+            )
+            ;; The filestat of the opened file at 256 with length $len, written at 128
+            (func (export "fd_stat") (param $len i32) (result i32)
+                (local $errno i32)
+                (local.set $errno (call $path_open (i32.const 3) (i32.const 0) (i32.const 256)
+                    (local.get $len) (i32.const 0) (i64.const 2097154) (i64.const 0)
+                    (i32.const 0) (i32.const 16)))
+                (if (local.get $errno) (then (return (local.get $errno))))
+                (local.set $errno
+                    (call $fd_filestat_get (i32.load (i32.const 16)) (i32.const 128)))
+                (drop (call $fd_close (i32.load (i32.const 16))))
+                (local.get $errno)
+            )
+        )"#;
+        // The `filetype`s of WASI
+        const DIRECTORY: u8 = 3;
+        const REGULAR_FILE: u8 = 4;
+
+        let path = std::env::temp_dir().join("wasm_run_preopened_dir_listing");
+        let _ = std::fs::remove_dir_all(&path);
+        let (listed, empty) = (path.join("listed"), path.join("empty"));
+        std::fs::create_dir_all(listed.join("sub"))?;
+        std::fs::create_dir_all(&empty)?;
+        std::fs::write(listed.join("a.txt"), "hello")?;
+        std::fs::write(listed.join("b.bin"), [0u8; 1000])?;
+
+        // The directory with rights is preopened first, it must be fd 3.
+        let preopened = |guest: &str, host: &std::path::Path, rights| PreopenedDir {
+            rights,
+            ..PreopenedDir::new(guest.to_string(), host.to_str().unwrap().to_string())
+        };
+        let wasi = WasiConfigNative {
+            preopened_dirs: vec![
+                preopened("/listed", &listed, Some(READ_RIGHTS)),
+                preopened("/empty", &empty, None),
+            ],
+            ..Default::default()
+        };
+        let module = compile_wasm(
+            parse_wat_format(LISTING_WAT.to_string())?,
+            Default::default(),
+        )?;
+        let module_id = module_builder(module, None, Some(wasi))?.0;
+        let instance = module_id.instantiate()?;
+        let memory = instance
+            .exports()
+            .0
+            .into_iter()
+            .find_map(|e| match e.value {
+                ExternalValue::Memory(memory) => Some(memory),
+                _ => None,
+            })
+            .unwrap();
+        let read = |offset, bytes| module_id.read_memory(memory.clone(), offset, bytes);
+        let read_u64 = |bytes: &[u8], offset: usize| {
+            u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+        };
+        let call = |name: &str, args| -> anyhow::Result<i32> {
+            match module_id.call_export(name.to_string(), args)?.0.as_slice() {
+                [WasmVal::i32(errno)] => Ok(*errno),
+                _ => unreachable!(),
+            }
+        };
+
+        assert_eq!(call("list", vec![])?, 0);
+        let used = read(0, 4)?.0;
+        let used = u32::from_le_bytes(used.try_into().unwrap()) as usize;
+        let buffer = read(1024, used)?.0;
+        // `dirent`: d_next, d_ino, d_namlen at 16 and d_type at 20, followed by the name
+        let mut entries = vec![];
+        let mut offset = 0;
+        while offset + 24 <= used {
+            let name_len =
+                u32::from_le_bytes(buffer[offset + 16..offset + 20].try_into().unwrap()) as usize;
+            let name = String::from_utf8(buffer[offset + 24..offset + 24 + name_len].to_vec())?;
+            if name != "." && name != ".." {
+                entries.push((name, buffer[offset + 20]));
+            }
+            offset += 24 + name_len;
+        }
+        entries.sort();
+        assert_eq!(
+            entries,
+            [
+                ("a.txt".to_string(), REGULAR_FILE),
+                ("b.bin".to_string(), REGULAR_FILE),
+                ("sub".to_string(), DIRECTORY),
+            ]
+        );
+
+        // `filestat`: the filetype at 16 and the size at 32
+        for (name, filetype) in &entries {
+            module_id.write_memory(memory.clone(), 256, name.as_bytes().to_vec())?;
+            let len = WasmVal::i32(name.len() as i32);
+            assert_eq!(call("path_stat", vec![len.clone()])?, 0, "{name}");
+            let stat = read(64, 64)?.0;
+            assert_eq!(stat[16], *filetype, "{name}");
+            if *filetype == REGULAR_FILE {
+                let size = std::fs::metadata(listed.join(name))?.len();
+                assert_eq!(read_u64(&stat, 32), size, "{name}");
+                assert_eq!(call("fd_stat", vec![len])?, 0, "{name}");
+                let fd_stat = read(128, 64)?.0;
+                assert_eq!(fd_stat[16], *filetype, "{name}");
+                assert_eq!(read_u64(&fd_stat, 32), size, "{name}");
+            }
+        }
+
+        module_id.dispose()?;
+        std::fs::remove_dir_all(path)?;
+        Ok(())
+    }
+
+    /// Passes a 1 MiB input to a module that reads the file named
+    /// in its last argument.
+    #[cfg(feature = "wasi")]
+    #[test]
+    #[allow(deprecated)]
+    fn wasi_arg_file() -> anyhow::Result<()> {
+        use crate::api::{module_builder, parse_wat_format};
+        use crate::types::WasmVal;
+
+        const ARG_FILE_WAT: &str = r#"(module
+            (import "wasi_snapshot_preview1" "args_sizes_get"
+                (func $args_sizes_get (param i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "args_get"
+                (func $args_get (param i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "fd_prestat_get"
+                (func $fd_prestat_get (param i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "path_open"
+                (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+            (import "wasi_snapshot_preview1" "fd_read"
+                (func $fd_read (param i32 i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func $check (param $errno i32)
+                (if (local.get $errno) (then unreachable))
+            )
+            ;; Opens the last argument relative to the preopened fd 3, skipping
+            ;; its guest path, and returns the number of bytes read and their sum.
+            ;; The argument pointers are at 1024, the arguments at 2048
+            ;; and the file is read in chunks of 32 KiB at 8192.
+            (func (export "read_arg_file") (result i64 i64)
+                (local $arg i32) (local $len i32) (local $prefix i32) (local $fd i32)
+                (local $read i32) (local $i i32) (local $total i64) (local $sum i64)
+                (call $check (call $args_sizes_get (i32.const 0) (i32.const 4)))
+                (call $check (call $args_get (i32.const 1024) (i32.const 2048)))
+                (local.set $arg (i32.load (i32.add (i32.const 1020)
+                    (i32.mul (i32.load (i32.const 0)) (i32.const 4)))))
+                ;; The last argument ends at the end of the buffer, before its `\0`
+                (local.set $len (i32.sub (i32.add (i32.const 2047) (i32.load (i32.const 4)))
+                    (local.get $arg)))
+                (call $check (call $fd_prestat_get (i32.const 3) (i32.const 16)))
+                (local.set $prefix (i32.add (i32.load (i32.const 20)) (i32.const 1)))
+                (call $check (call $path_open (i32.const 3) (i32.const 0)
+                    (i32.add (local.get $arg) (local.get $prefix))
+                    (i32.sub (local.get $len) (local.get $prefix))
+                    (i32.const 0) (i64.const 2) (i64.const 0) (i32.const 0) (i32.const 32)))
+                (local.set $fd (i32.load (i32.const 32)))
+                (i32.store (i32.const 40) (i32.const 8192))
+                (i32.store (i32.const 44) (i32.const 32768))
+                (block $done
+                    (loop $chunk
+                        (call $check (call $fd_read (local.get $fd) (i32.const 40)
+                            (i32.const 1) (i32.const 36)))
+                        (local.set $read (i32.load (i32.const 36)))
+                        (br_if $done (i32.eqz (local.get $read)))
+                        (local.set $total
+                            (i64.add (local.get $total) (i64.extend_i32_u (local.get $read))))
+                        (local.set $i (i32.const 0))
+                        (loop $byte
+                            (local.set $sum (i64.add (local.get $sum) (i64.load8_u
+                                (i32.add (i32.const 8192) (local.get $i)))))
+                            (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                            (br_if $byte (i32.lt_u (local.get $i) (local.get $read)))
+                        )
+                        (br $chunk)
+                    )
+                )
+                (local.get $total)
+                (local.get $sum)
+            )
+        )"#;
+
+        let contents = (0..1 << 20).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let mut wasi = WasiConfigNative {
+            args: vec!["main.wasm".to_string()],
+            ..Default::default()
+        };
+        let dir = wasi.arg_file("/input/data.bin".to_string(), &contents)?;
+        assert_eq!(wasi.args, ["main.wasm", "/input/data.bin"]);
+        assert_eq!(wasi.preopened_dirs[0].wasm_guest_path, "/input");
+        let path = dir.path().to_path_buf();
+
+        let module = compile_wasm(
+            parse_wat_format(ARG_FILE_WAT.to_string())?,
+            Default::default(),
+        )?;
+        let module_id = module_builder(module, None, Some(wasi))?.0;
+        module_id.instantiate()?;
+        let results = module_id
+            .call_export("read_arg_file".to_string(), vec![])?
+            .0;
+        let sum = contents.iter().map(|b| *b as i64).sum::<i64>();
+        match results.as_slice() {
+            [WasmVal::i64(total), WasmVal::i64(read_sum)] => {
+                assert_eq!(*total, contents.len() as i64);
+                assert_eq!(*read_sum, sum);
+            }
+            _ => unreachable!(),
+        }
+        module_id.dispose()?;
+
+        drop(dir);
+        assert!(!path.exists());
+        let err = WasiConfigNative::default()
+            .arg_file("data.bin".to_string(), &contents)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("\"data.bin\""), "{err}");
+        Ok(())
+    }
+
+    #[cfg(feature = "wasmtime")]
+    #[test]
+    fn explain_config() {
+        let explain = |config: ModuleConfig| {
+            config
+                .explain()
+                .into_iter()
+                .map(|(name, value, source)| format!("{name}={value} ({source:?})\n"))
+                .collect::<String>()
+        };
+        assert_eq!(
+            explain(Default::default()),
+            "multi_value=true (Default)
+bulk_memory=true (Default)
+reference_types=true (Default)
+mutable_global=true (Default)
+saturating_float_to_int=true (Default)
+sign_extension=true (Default)
+floats=true (Default)
+nan_canonicalization=false (Default)
+simd=true (Default)
+relaxed_simd=false (Default)
+threads=false (Default)
+multi_memory=false (Default)
+memory64=false (Default)
+tail_call=false (Default)
+extended_const=false (Unsupported)
+memory_control=false (Unsupported)
+garbage_collection=false (Unsupported)
+function_references=false (Default)
+exceptions=false (Unsupported)
+component_model=false (Unsupported)
+consume_fuel=false (Default)
+max_module_bytes=unlimited (Default)
+max_memory_pages=unlimited (Default)
+max_memories=unlimited (Default)
+max_tables=unlimited (Default)
+max_globals=unlimited (Default)
+max_functions=unlimited (Default)
+trap_on_integer_overflow=false (Default)
+"
+        );
+
+        let config = ModuleConfig {
+            bulk_memory: Some(false),
+            max_memory_pages: Some(16),
+            wasmtime: Some(ModuleConfigWasmtime {
+                wasm_threads: Some(true),
+                ..Default::default()
+            }),
+            // Ignored by wasmtime
+            wasmi: Some(ModuleConfigWasmi {
+                floats: Some(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let explained = explain(config);
+        for line in [
+            "bulk_memory=false (User)",
+            "threads=true (User)",
+            "floats=true (Default)",
+            "max_memory_pages=16 (User)",
+        ] {
+            assert!(explained.contains(line), "{line}");
+        }
+    }
+
+    #[test]
+    fn module_size_limit() {
+        let wasm = wat::parse_str("(module (func (export \"f\")))").unwrap();
+        let config = |limit| ModuleConfig {
+            max_module_bytes: Some(limit),
+            ..Default::default()
+        };
+        assert!(compile_wasm(wasm.clone(), config(wasm.len())).is_ok());
+
+        let err = compile_wasm(wasm.clone(), config(wasm.len() - 1))
+            .err()
+            .unwrap();
+        let err = err.downcast_ref::<ModuleTooLarge>().unwrap();
+        assert_eq!(err.size, wasm.len());
+        assert_eq!(err.limit, wasm.len() - 1);
+    }
+
+    #[test]
+    fn memory_pages_limit() {
+        let config = || ModuleConfig {
+            max_memory_pages: Some(2),
+            ..Default::default()
+        };
+        for wat in [
+            "(module (memory 2))",
+            "(module (import \"env\" \"memory\" (memory 1 10)))",
+        ] {
+            let wasm = wat::parse_str(wat).unwrap();
+            assert!(compile_wasm(wasm, config()).is_ok(), "{wat}");
+        }
+        for wat in [
+            "(module (memory 3))",
+            "(module (import \"env\" \"memory\" (memory 3)))",
+        ] {
+            let wasm = wat::parse_str(wat).unwrap();
+            let err = compile_wasm(wasm, config()).err().unwrap();
+            let err = err.downcast_ref::<MemoryTooLarge>().unwrap();
+            assert_eq!((err.minimum_pages, err.limit), (3, 2), "{wat}");
+        }
+    }
+
+    #[test]
+    fn declaration_limits() {
+        let wat = r#"(module
+            (import "env" "memory" (memory 1))
+            (memory 1)
+            (memory 1)
+            (table 1 funcref)
+            (global i32 (i32.const 0))
+            (func)
+        )"#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let config = || ModuleConfig {
+            max_memories: Some(3),
+            max_tables: Some(1),
+            max_globals: Some(1),
+            max_functions: Some(1),
+            ..Default::default()
+        };
+        config().check_module_limits(&wasm).unwrap();
+
+        let err = compile_wasm(
+            wasm.clone(),
+            ModuleConfig {
+                max_memories: Some(2),
+                ..config()
+            },
+        )
+        .err()
+        .unwrap();
+        let err = err.downcast_ref::<DeclarationLimitExceeded>().unwrap();
+        assert_eq!((err.declaration, err.count, err.limit), ("memories", 3, 2));
+        assert_eq!(
+            err.to_string(),
+            "Module declares 3 memories exceeding the limit of 2."
+        );
+
+        for (config, declaration) in [
+            (
+                ModuleConfig {
+                    max_tables: Some(0),
+                    ..config()
+                },
+                "tables",
+            ),
+            (
+                ModuleConfig {
+                    max_globals: Some(0),
+                    ..config()
+                },
+                "globals",
+            ),
+            (
+                ModuleConfig {
+                    max_functions: Some(0),
+                    ..config()
+                },
+                "functions",
+            ),
+        ] {
+            let err = config.check_module_limits(&wasm).err().unwrap();
+            let err = err.downcast_ref::<DeclarationLimitExceeded>().unwrap();
+            assert_eq!((err.declaration, err.count), (declaration, 1));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::config::ModuleConfig;
 use std::{
     any::Any,
     fmt::Debug,
@@ -147,3 +148,40 @@ impl From<WTable> for wasmi::Table {
         *func.0.downcast::<wasmi::Table>().unwrap()
     }
 }
+
+/// An engine shared by the modules compiled with it. It keeps the [ModuleConfig]
+/// used to create it to check the limits and apply the transformations
+/// of the modules compiled with it.
+/// It is only mutated behind its own locks, so it can be
+/// used after a panic in a host function.
+#[derive(Clone)]
+pub struct WEngine {
+    #[cfg(not(feature = "wasmtime"))]
+    pub engine_wasmi: wasmi::Engine,
+    #[cfg(feature = "wasmtime")]
+    pub engine_wasmtime: wasmtime::Engine,
+    pub config: ModuleConfig,
+}
+
+impl UnwindSafe for WEngine {}
+impl RefUnwindSafe for WEngine {}
+
+#[cfg(feature = "wasmtime")]
+impl WEngine {
+    pub fn new(engine: wasmtime::Engine, config: ModuleConfig) -> Self {
+        Self {
+            engine_wasmtime: engine,
+            config,
+        }
+    }
+}
+
+#[cfg(not(feature = "wasmtime"))]
+impl WEngine {
+    pub fn new(engine: wasmi::Engine, config: ModuleConfig) -> Self {
+        Self {
+            engine_wasmi: engine,
+            config,
+        }
+    }
+}
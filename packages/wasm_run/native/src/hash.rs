@@ -0,0 +1,41 @@
+//! Content hashes of Wasm modules, used as cache and deduplication keys.
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+
+/// Returns the SHA-256 of the canonical binary of [module_wasm].
+///
+/// A module in the text format is hashed after being converted to the
+/// binary format, so the hash does not depend on its whitespace or comments
+/// and is the same as the one of the equivalent binary.
+pub fn content_hash(module_wasm: &[u8]) -> Result<[u8; 32]> {
+    let binary = wat::parse_bytes(module_wasm)?;
+    Ok(Sha256::digest(&binary).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_content_hash() {
+        let wat = r#"(module (func (export "f") (result i32) i32.const 1))"#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let hash = content_hash(&wasm).unwrap();
+        assert_eq!(hash, content_hash(&wasm.clone()).unwrap());
+
+        let formatted = r#"
+            ;; The same module, formatted
+            (module
+                (func (export "f") (result i32)
+                    i32.const 1
+                )
+            )"#;
+        assert_eq!(hash, content_hash(wat.as_bytes()).unwrap());
+        assert_eq!(hash, content_hash(formatted.as_bytes()).unwrap());
+
+        let other = wat::parse_str(wat.replace("i32.const 1", "i32.const 2")).unwrap();
+        assert_ne!(hash, content_hash(&other).unwrap());
+        assert!(content_hash(b"(module").is_err());
+    }
+}
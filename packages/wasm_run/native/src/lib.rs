@@ -1,12 +1,33 @@
-mod api;
+pub mod api;
 // #[cfg(feature = "wasmtime")]
 // mod api_wt;
 // #[cfg(not(feature = "wasmtime"))]
 // mod api_wasmi;
+#[allow(deprecated)]
 mod bridge_generated;
-mod config;
+mod capture;
+pub mod compile_error;
+mod compile_thread;
+pub mod config;
 mod external;
+mod hash;
+mod line_buffer;
+pub mod memory;
+pub mod metrics;
+pub mod module_cache;
+pub mod observer;
+mod transform;
 // mod interface;
 #[allow(dead_code)]
+#[allow(clippy::missing_safety_doc)]
 mod atomics;
-mod types;
+#[cfg(feature = "wasmtime")]
+pub mod typed_func;
+pub mod types;
+pub mod validate;
+pub mod wasi_check;
+#[cfg(feature = "wasi")]
+pub mod wasi_configure;
+#[cfg(all(feature = "wasi", feature = "wasmtime"))]
+mod wasi_dir;
+pub mod wasi_error;
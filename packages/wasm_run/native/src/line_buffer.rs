@@ -0,0 +1,76 @@
+/// The maximum length of a line in bytes.
+/// Longer lines are sent in chunks of this length.
+pub const MAX_LINE_BYTES: usize = 64 * 1024;
+
+/// Splits the output written by a module into lines,
+/// without the `\n` or `\r\n` terminators.
+#[derive(Debug, Default)]
+pub struct LineBuffer {
+    line: Vec<u8>,
+}
+
+impl LineBuffer {
+    /// Appends [buf] to the current line and returns the lines completed by it.
+    pub fn push(&mut self, buf: &[u8]) -> Vec<Vec<u8>> {
+        let mut lines = vec![];
+        for chunk in buf.split_inclusive(|b| *b == b'\n') {
+            let (mut content, complete) = match chunk.strip_suffix(b"\n") {
+                Some(content) => (content, true),
+                None => (chunk, false),
+            };
+            while self.line.len() + content.len() > MAX_LINE_BYTES {
+                let (head, rest) = content.split_at(MAX_LINE_BYTES - self.line.len());
+                self.line.extend_from_slice(head);
+                lines.push(std::mem::take(&mut self.line));
+                content = rest;
+            }
+            self.line.extend_from_slice(content);
+            if complete {
+                let mut line = std::mem::take(&mut self.line);
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+                lines.push(line);
+            }
+        }
+        lines
+    }
+
+    /// Returns the last line if it was not terminated.
+    pub fn finish(&mut self) -> Option<Vec<u8>> {
+        if self.line.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.line))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_multi_line_output() {
+        let mut buffer = LineBuffer::default();
+        assert_eq!(buffer.push(b"first\nsec"), vec![b"first".to_vec()]);
+        assert_eq!(buffer.push(b"ond\r"), Vec::<Vec<u8>>::new());
+        assert_eq!(
+            buffer.push(b"\n\nthird\r\nlast"),
+            vec![b"second".to_vec(), vec![], b"third".to_vec()]
+        );
+        assert_eq!(buffer.finish(), Some(b"last".to_vec()));
+        assert_eq!(buffer.finish(), None);
+    }
+
+    #[test]
+    fn splits_long_lines() {
+        let mut buffer = LineBuffer::default();
+        let long = vec![b'a'; MAX_LINE_BYTES * 2 + 10];
+        let lines = buffer.push(&long);
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| l.len() == MAX_LINE_BYTES));
+        assert_eq!(buffer.push(b"\n"), vec![vec![b'a'; 10]]);
+        assert_eq!(buffer.finish(), None);
+    }
+}
@@ -0,0 +1,52 @@
+//! Reads of the linear memory of a module for embedders that use the crate
+//! from Rust, without allocating a list per read as
+//! [read_memory](WasmRunModuleId::read_memory) does.
+
+use crate::api::{Memory, WasmRunModuleId};
+use anyhow::Result;
+
+impl WasmRunModuleId {
+    /// Runs `f` with a borrowed view of `bytes` bytes of the linear memory
+    /// starting at `offset`, without copying them.
+    /// The store is borrowed for the duration of `f`, so the memory can't be
+    /// grown while the slice is alive and the slice can't escape the closure.
+    /// `f` must not call back into the guest, for example, with
+    /// [call_function_handle](WasmRunModuleId::call_function_handle),
+    /// since the store is locked while it runs.
+    /// Each call resolves the data of the memory again, so reading it in
+    /// many calls is correct even if the guest grows it in between.
+    pub fn with_memory<T>(
+        &self,
+        memory: &Memory,
+        offset: usize,
+        bytes: usize,
+        f: impl FnOnce(&[u8]) -> T,
+    ) -> Result<T> {
+        self.with_module(|store| {
+            let data = memory.data(store);
+            offset
+                .checked_add(bytes)
+                .and_then(|end| data.get(offset..end))
+                .map(f)
+                .ok_or_else(|| anyhow::anyhow!("out of bounds memory access"))
+        })?
+    }
+
+    /// Copies `bytes` bytes of the linear memory starting at `offset` into
+    /// `buffer`, replacing its contents.
+    /// The allocation of `buffer` is reused and only grows when `bytes` is
+    /// larger than its capacity, so the same buffer can be passed on every call.
+    /// Use [Self::with_memory] when the bytes only need to be parsed.
+    pub fn read_memory_into_vec(
+        &self,
+        memory: &Memory,
+        offset: usize,
+        bytes: usize,
+        buffer: &mut Vec<u8>,
+    ) -> Result<()> {
+        self.with_memory(memory, offset, bytes, |data| {
+            buffer.clear();
+            buffer.extend_from_slice(data);
+        })
+    }
+}
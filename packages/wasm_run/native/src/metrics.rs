@@ -0,0 +1,192 @@
+//! The cost of each call to a module in one [CallMetrics], for profiling
+//! and billing. The counters are only updated after they are enabled
+//! with [WasmRunModuleId::set_call_metrics].
+
+use crate::api::WasmRunModuleId;
+use crate::types::MemoryFootprint;
+use anyhow::Result;
+use std::time::Duration;
+
+/// The size in bytes of a WebAssembly memory page.
+const PAGE_SIZE: u64 = 64 * 1024;
+
+/// The cost of a call made with
+/// [call_function_handle](WasmRunModuleId::call_function_handle),
+/// including the calls nested within host functions.
+/// Returned by [WasmRunModuleId::last_call_metrics].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallMetrics {
+    /// The elapsed real time of the call.
+    pub wall_time: Duration,
+    /// The fuel consumed by the call.
+    /// None if `consume_fuel` is not enabled.
+    pub fuel_consumed: Option<u64>,
+    /// The number of pages the memories of the store grew during the call,
+    /// including the memories that are not exported.
+    pub memory_growth_pages: u64,
+    /// The number of host functions called during the call,
+    /// including the ones called from nested calls.
+    pub host_calls: u64,
+}
+
+/// The counters of a store used by [CallMetrics], compared before and after
+/// each call. Set as the resource limiter of the store to count the growth
+/// of its memories, it never denies a growth.
+/// The sizes of the memories and tables of the store are always tracked,
+/// for its [MemoryFootprint].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct StoreCounters {
+    pub(crate) enabled: bool,
+    memory_growth_bytes: u64,
+    host_calls: u64,
+    memory_bytes: u64,
+    table_elements: u64,
+    /// The growth counted by the last call to the limiter, undone when it fails.
+    pending_memory_growth: u64,
+    pending_table_growth: u64,
+}
+
+impl StoreCounters {
+    pub(crate) fn count_host_call(&mut self) {
+        if self.enabled {
+            self.host_calls += 1;
+        }
+    }
+
+    fn count_memory_growth(&mut self, current: usize, desired: usize, maximum: Option<usize>) {
+        // The growth fails after the limiter is called when it exceeds the maximum
+        self.pending_memory_growth = if maximum.is_none_or(|max| desired <= max) {
+            desired.saturating_sub(current) as u64
+        } else {
+            0
+        };
+        self.memory_bytes += self.pending_memory_growth;
+        if self.enabled {
+            self.memory_growth_bytes += self.pending_memory_growth;
+        }
+    }
+
+    /// Undoes the growth counted when the memory could not be allocated.
+    fn memory_growth_failed(&mut self) {
+        self.memory_bytes -= self.pending_memory_growth;
+        if self.enabled {
+            self.memory_growth_bytes -= self.pending_memory_growth;
+        }
+        self.pending_memory_growth = 0;
+    }
+
+    fn count_table_growth(&mut self, current: u32, desired: u32, maximum: Option<u32>) {
+        self.pending_table_growth = if maximum.is_none_or(|max| desired <= max) {
+            u64::from(desired.saturating_sub(current))
+        } else {
+            0
+        };
+        self.table_elements += self.pending_table_growth;
+    }
+
+    fn table_growth_failed(&mut self) {
+        self.table_elements -= self.pending_table_growth;
+        self.pending_table_growth = 0;
+    }
+
+    /// The [MemoryFootprint] of the memories and tables of the store,
+    /// the number of them is not known by the limiter.
+    pub(crate) fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint::new(self.memory_bytes, self.table_elements)
+    }
+
+    /// The metrics of a call that started with the counters in [before].
+    pub(crate) fn call_metrics(
+        &self,
+        before: StoreCounters,
+        wall_time: Duration,
+        fuel_consumed: Option<u64>,
+    ) -> CallMetrics {
+        CallMetrics {
+            wall_time,
+            fuel_consumed,
+            memory_growth_pages: (self.memory_growth_bytes - before.memory_growth_bytes)
+                / PAGE_SIZE,
+            host_calls: self.host_calls - before.host_calls,
+        }
+    }
+}
+
+#[cfg(feature = "wasmtime")]
+impl wasmtime::ResourceLimiter for StoreCounters {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> Result<bool> {
+        self.count_memory_growth(current, desired, maximum);
+        Ok(true)
+    }
+
+    fn memory_grow_failed(&mut self, _error: anyhow::Error) -> Result<()> {
+        self.memory_growth_failed();
+        Ok(())
+    }
+
+    fn table_growing(&mut self, current: u32, desired: u32, maximum: Option<u32>) -> Result<bool> {
+        self.count_table_growth(current, desired, maximum);
+        Ok(true)
+    }
+
+    fn table_grow_failed(&mut self, _error: anyhow::Error) -> Result<()> {
+        self.table_growth_failed();
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "wasmtime"))]
+impl wasmi::ResourceLimiter for StoreCounters {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> Result<bool, wasmi::errors::MemoryError> {
+        self.count_memory_growth(current, desired, maximum);
+        Ok(true)
+    }
+
+    fn memory_grow_failed(&mut self, _error: &wasmi::errors::MemoryError) {
+        self.memory_growth_failed();
+    }
+
+    fn table_growing(
+        &mut self,
+        current: u32,
+        desired: u32,
+        maximum: Option<u32>,
+    ) -> Result<bool, wasmi::errors::TableError> {
+        self.count_table_growth(current, desired, maximum);
+        Ok(true)
+    }
+
+    fn table_grow_failed(&mut self, _error: &wasmi::errors::TableError) {
+        self.table_growth_failed();
+    }
+}
+
+impl WasmRunModuleId {
+    /// Enables or disables the [CallMetrics] of the calls to the module,
+    /// returned by [WasmRunModuleId::last_call_metrics].
+    /// Disabled by default, the host calls and the growth of the memories
+    /// are not counted while they are disabled.
+    pub fn set_call_metrics(&self, enabled: bool) -> Result<()> {
+        self.with_module_mut(|mut store| store.data_mut().counters.enabled = enabled)
+    }
+
+    /// The [CallMetrics] of the last call to the module.
+    /// Returns None if they are not enabled with
+    /// [WasmRunModuleId::set_call_metrics] or no function was called.
+    pub fn last_call_metrics(&self) -> Result<Option<CallMetrics>> {
+        self.with_module(|store| {
+            let state = store.data();
+            state.last_call.filter(|_| state.counters.enabled)
+        })
+    }
+}
@@ -0,0 +1,210 @@
+//! Compiled modules reused by their [content_hash](crate::hash::content_hash),
+//! with the least recently used entries evicted by count and size.
+
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+
+/// The statistics of a [ModuleCache](crate::api::ModuleCache).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModuleCacheStats {
+    /// The number of modules in the cache.
+    pub entries: u32,
+    /// The sum of the sizes of the binaries of the modules in the cache.
+    pub size_bytes: u64,
+    /// The number of modules returned from the cache without compiling them.
+    pub hits: u64,
+    /// The number of modules compiled, or loaded from disk,
+    /// because they were not in the cache.
+    pub misses: u64,
+    /// The number of modules removed to respect the limits of the cache.
+    pub evictions: u64,
+}
+
+/// The limits of a [ModuleStore]. `None` is unlimited.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ModuleCacheLimits {
+    pub max_entries: Option<u32>,
+    pub max_bytes: Option<u64>,
+}
+
+struct Entry<M> {
+    hash: [u8; 32],
+    module: M,
+    size_bytes: u64,
+}
+
+/// Modules keyed by content hash, from the least to the most recently used.
+pub struct ModuleStore<M> {
+    entries: VecDeque<Entry<M>>,
+    limits: ModuleCacheLimits,
+    stats: ModuleCacheStats,
+}
+
+impl<M: Clone> ModuleStore<M> {
+    pub fn new(limits: ModuleCacheLimits) -> Self {
+        ModuleStore {
+            entries: VecDeque::new(),
+            limits,
+            stats: ModuleCacheStats::default(),
+        }
+    }
+
+    /// Returns the module with [hash], marking it as the most recently used.
+    pub fn get(&mut self, hash: &[u8; 32]) -> Option<M> {
+        let Some(index) = self.entries.iter().position(|e| &e.hash == hash) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        let entry = self.entries.remove(index).unwrap();
+        let module = entry.module.clone();
+        self.entries.push_back(entry);
+        self.stats.hits += 1;
+        Some(module)
+    }
+
+    /// Inserts the [module] compiled from a binary of [size_bytes] and evicts
+    /// the least recently used modules until the limits are respected.
+    /// A module larger than `max_bytes` is not stored.
+    pub fn insert(&mut self, hash: [u8; 32], module: M, size_bytes: u64) {
+        if self.limits.max_bytes.is_some_and(|max| size_bytes > max)
+            || self.limits.max_entries == Some(0)
+        {
+            return;
+        }
+        if let Some(index) = self.entries.iter().position(|e| e.hash == hash) {
+            let previous = self.entries.remove(index).unwrap();
+            self.stats.size_bytes -= previous.size_bytes;
+        }
+        self.entries.push_back(Entry {
+            hash,
+            module,
+            size_bytes,
+        });
+        self.stats.size_bytes += size_bytes;
+        while self.exceeds_limits() {
+            let evicted = self.entries.pop_front().unwrap();
+            self.stats.size_bytes -= evicted.size_bytes;
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Removes all the modules. The hit and miss counts are kept.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.stats.size_bytes = 0;
+    }
+
+    pub fn stats(&self) -> ModuleCacheStats {
+        ModuleCacheStats {
+            entries: self.entries.len() as u32,
+            ..self.stats
+        }
+    }
+
+    fn exceeds_limits(&self) -> bool {
+        self.limits
+            .max_entries
+            .is_some_and(|max| self.entries.len() > max as usize)
+            || self
+                .limits
+                .max_bytes
+                .is_some_and(|max| self.stats.size_bytes > max)
+    }
+}
+
+/// The file name of the serialized artifact of the module with [hash].
+pub fn artifact_file_name(hash: &[u8; 32]) -> String {
+    let hex = hash.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    format!("{hex}.cwasm")
+}
+
+/// Prefixes the serialized [artifact] with its SHA-256, checked by [open_artifact].
+pub fn seal_artifact(artifact: &[u8]) -> Vec<u8> {
+    let mut sealed = Sha256::digest(artifact).to_vec();
+    sealed.extend_from_slice(artifact);
+    sealed
+}
+
+/// Returns the artifact sealed with [seal_artifact] in [sealed],
+/// or None if it was truncated or modified.
+///
+/// The tag only detects corrupted artifacts, anyone that can write to the
+/// directory of the cache can also compute it, so the directory must
+/// only be writable by the app.
+pub fn open_artifact(sealed: &[u8]) -> Option<&[u8]> {
+    if sealed.len() < 32 {
+        return None;
+    }
+    let (tag, artifact) = sealed.split_at(32);
+    (Sha256::digest(artifact).as_slice() == tag).then_some(artifact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_store_eviction() {
+        let mut store = ModuleStore::new(ModuleCacheLimits {
+            max_entries: Some(2),
+            max_bytes: Some(100),
+        });
+        assert_eq!(store.get(&[1; 32]), None);
+        store.insert([1; 32], 1, 10);
+        store.insert([2; 32], 2, 10);
+        assert_eq!(store.get(&[1; 32]), Some(1));
+
+        // [2; 32] is the least recently used
+        store.insert([3; 32], 3, 10);
+        assert_eq!(store.get(&[2; 32]), None);
+        assert_eq!(
+            store.stats(),
+            ModuleCacheStats {
+                entries: 2,
+                size_bytes: 20,
+                hits: 1,
+                misses: 2,
+                evictions: 1,
+            }
+        );
+
+        store.insert([4; 32], 4, 95);
+        assert_eq!(store.get(&[1; 32]), None);
+        assert_eq!(store.get(&[3; 32]), None);
+        assert_eq!(store.get(&[4; 32]), Some(4));
+        assert_eq!(store.stats().size_bytes, 95);
+        assert_eq!(store.stats().evictions, 3);
+
+        // Larger than `max_bytes`
+        store.insert([5; 32], 5, 101);
+        assert_eq!(store.get(&[5; 32]), None);
+        assert_eq!(store.stats().entries, 1);
+
+        store.clear();
+        assert_eq!(store.stats().entries, 0);
+        assert_eq!(store.stats().size_bytes, 0);
+    }
+
+    #[test]
+    fn artifact_file_names() {
+        let mut hash = [0; 32];
+        hash[0] = 0xab;
+        hash[31] = 0x01;
+        let name = artifact_file_name(&hash);
+        assert!(name.starts_with("ab00"));
+        assert!(name.ends_with("01.cwasm"));
+        assert_eq!(name.len(), 64 + ".cwasm".len());
+    }
+
+    #[test]
+    fn sealed_artifacts() {
+        let sealed = seal_artifact(b"artifact");
+        assert_eq!(open_artifact(&sealed), Some(&b"artifact"[..]));
+
+        let mut modified = sealed.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert_eq!(open_artifact(&modified), None);
+        assert_eq!(open_artifact(&sealed[..sealed.len() - 1]), None);
+        assert_eq!(open_artifact(&sealed[..10]), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Lifecycle events of the compilation, instantiation and calls of modules,
+//! for tracing and metrics. No events are created without an [Observer].
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// An event sent to the [Observer] set with [set_observer].
+/// The end events contain the time elapsed since the matching start event
+/// and are sent even if the operation fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    CompileStart {
+        module_bytes: usize,
+    },
+    CompileEnd {
+        duration: Duration,
+    },
+    InstantiateStart {
+        module_id: u32,
+    },
+    InstantiateEnd {
+        module_id: u32,
+        duration: Duration,
+    },
+    /// [name] is the name of the exported function,
+    /// if it is known when calling it.
+    CallStart {
+        module_id: u32,
+        name: Option<String>,
+    },
+    CallEnd {
+        module_id: u32,
+        name: Option<String>,
+        duration: Duration,
+    },
+}
+
+/// Receives the [LifecycleEvent]s of all the modules.
+/// It is called in the thread executing the operation.
+pub trait Observer: Send + Sync {
+    fn on_event(&self, event: LifecycleEvent);
+}
+
+static OBSERVER: RwLock<Option<Arc<dyn Observer>>> = RwLock::new(None);
+/// Whether [OBSERVER] is set, checked before creating any event.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Sets the [Observer] that receives the events of all the modules,
+/// replacing the previous one. `None` removes it, which is the default.
+pub fn set_observer(observer: Option<Arc<dyn Observer>>) {
+    let mut current = OBSERVER.write().unwrap();
+    ENABLED.store(observer.is_some(), Ordering::Release);
+    *current = observer;
+}
+
+fn notify(event: LifecycleEvent) {
+    if let Some(observer) = OBSERVER.read().unwrap().clone() {
+        observer.on_event(event);
+    }
+}
+
+/// Sends the end event of an operation when dropped.
+pub(crate) struct Span {
+    start: Instant,
+    end: Option<Box<dyn FnOnce(Duration) -> LifecycleEvent>>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(end) = self.end.take() {
+            notify(end(self.start.elapsed()));
+        }
+    }
+}
+
+/// Sends the event returned by [start] and returns a guard that sends
+/// the one returned by [end] when the operation finishes.
+fn span(start: LifecycleEvent, end: impl FnOnce(Duration) -> LifecycleEvent + 'static) -> Span {
+    notify(start);
+    Span {
+        start: Instant::now(),
+        end: Some(Box::new(end)),
+    }
+}
+
+/// Sends [LifecycleEvent::CompileStart] and [LifecycleEvent::CompileEnd]
+/// when the returned guard is dropped. Does nothing without an [Observer].
+pub(crate) fn compile_span(module_bytes: usize) -> Option<Span> {
+    if !ENABLED.load(Ordering::Acquire) {
+        return None;
+    }
+    Some(span(
+        LifecycleEvent::CompileStart { module_bytes },
+        |duration| LifecycleEvent::CompileEnd { duration },
+    ))
+}
+
+/// Sends [LifecycleEvent::InstantiateStart] and [LifecycleEvent::InstantiateEnd]
+/// when the returned guard is dropped. Does nothing without an [Observer].
+pub(crate) fn instantiate_span(module_id: u32) -> Option<Span> {
+    if !ENABLED.load(Ordering::Acquire) {
+        return None;
+    }
+    Some(span(
+        LifecycleEvent::InstantiateStart { module_id },
+        move |duration| LifecycleEvent::InstantiateEnd {
+            module_id,
+            duration,
+        },
+    ))
+}
+
+/// Sends [LifecycleEvent::CallStart] and [LifecycleEvent::CallEnd]
+/// when the returned guard is dropped. Does nothing without an [Observer].
+pub(crate) fn call_span(module_id: u32, name: Option<&str>) -> Option<Span> {
+    if !ENABLED.load(Ordering::Acquire) {
+        return None;
+    }
+    let name = name.map(str::to_string);
+    Some(span(
+        LifecycleEvent::CallStart {
+            module_id,
+            name: name.clone(),
+        },
+        move |duration| LifecycleEvent::CallEnd {
+            module_id,
+            name,
+            duration,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{compile_wasm, module_builder};
+    use std::{sync::Mutex, thread::ThreadId};
+
+    /// Records the events sent in the thread of the test,
+    /// other tests may run in parallel.
+    struct Recorder(ThreadId, Mutex<Vec<LifecycleEvent>>);
+
+    impl Observer for Recorder {
+        fn on_event(&self, event: LifecycleEvent) {
+            if std::thread::current().id() == self.0 {
+                self.1.lock().unwrap().push(event);
+            }
+        }
+    }
+
+    #[test]
+    fn events_in_order() -> anyhow::Result<()> {
+        let recorder = Arc::new(Recorder(std::thread::current().id(), Mutex::default()));
+        set_observer(Some(recorder.clone()));
+        let wasm = wat::parse_str(r#"(module (func (export "f")))"#)?;
+        let module_bytes = wasm.len();
+        let module = compile_wasm(wasm, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        module_id.instantiate()?;
+        module_id.call_by_index(0, vec![])?;
+        set_observer(None);
+        module_id.dispose()?;
+
+        let events = recorder.1.lock().unwrap();
+        let id = module_id.0;
+        assert_eq!(events.len(), 6, "{events:?}");
+        assert_eq!(events[0], LifecycleEvent::CompileStart { module_bytes });
+        assert!(matches!(events[1], LifecycleEvent::CompileEnd { .. }));
+        assert_eq!(
+            events[2],
+            LifecycleEvent::InstantiateStart { module_id: id }
+        );
+        assert!(
+            matches!(events[3], LifecycleEvent::InstantiateEnd { module_id, .. } if module_id == id)
+        );
+        let name = Some("f".to_string());
+        assert_eq!(
+            events[4],
+            LifecycleEvent::CallStart {
+                module_id: id,
+                name: name.clone()
+            }
+        );
+        assert!(matches!(&events[5], LifecycleEvent::CallEnd { name: n, .. } if *n == name));
+        Ok(())
+    }
+}
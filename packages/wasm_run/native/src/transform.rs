@@ -0,0 +1,196 @@
+//! Transformations of Wasm module binaries applied before compilation.
+
+use anyhow::Result;
+use wasm_encoder::{BlockType, Encode, Instruction};
+use wasmparser::{Encoding, FunctionBody, Operator, Parser, Payload, StructuralType};
+
+/// Instruments every `i32.add` within the function bodies of [module_wasm]
+/// so that a signed overflow traps with `unreachable` instead of wrapping.
+///
+/// Each function receives three additional `i32` locals used to check
+/// the operands and the result of the addition.
+pub fn trap_on_i32_add_overflow(module_wasm: &[u8]) -> Result<Vec<u8>> {
+    let mut module = wasm_encoder::Module::new();
+    let mut params_by_type = vec![];
+    let mut function_types = vec![];
+    let mut code: Option<(wasm_encoder::CodeSection, u32)> = None;
+
+    for payload in Parser::new(0).parse_all(module_wasm) {
+        let payload = payload?;
+        match &payload {
+            Payload::Version {
+                encoding: Encoding::Component,
+                ..
+            } => return Err(anyhow::anyhow!("Components can not be instrumented.")),
+            Payload::TypeSection(reader) => {
+                for group in reader.clone() {
+                    for ty in group?.into_types() {
+                        params_by_type.push(match ty.structural_type {
+                            StructuralType::Func(f) => f.params().len() as u32,
+                            _ => 0,
+                        });
+                    }
+                }
+            }
+            Payload::FunctionSection(reader) => {
+                for ty in reader.clone() {
+                    function_types.push(ty?);
+                }
+            }
+            Payload::CodeSectionStart { count, .. } => {
+                code = Some((wasm_encoder::CodeSection::new(), *count));
+                continue;
+            }
+            Payload::CodeSectionEntry(body) => {
+                let (section, count) = code.as_mut().unwrap();
+                let params = params_by_type[function_types[section.len() as usize] as usize];
+                let body = instrument_body(module_wasm, body, params)?;
+                section.raw(&body);
+                if section.len() == *count {
+                    module.section(section);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if let Some((id, range)) = payload.as_section() {
+            module.section(&wasm_encoder::RawSection {
+                id,
+                data: &module_wasm[range],
+            });
+        }
+    }
+    Ok(module.finish())
+}
+
+/// Sets the maximum of the memories defined by [module_wasm] to [max_pages],
+/// the declared maximum is kept if it is lower. Imported memories are not
+/// modified, their maximum is constrained by the memory provided as import.
+///
+/// Returns an error if the minimum of a memory exceeds [max_pages].
+pub fn clamp_memory(module_wasm: &[u8], max_pages: u64) -> Result<Vec<u8>> {
+    let mut module = wasm_encoder::Module::new();
+    for payload in Parser::new(0).parse_all(module_wasm) {
+        let payload = payload?;
+        match &payload {
+            Payload::Version {
+                encoding: Encoding::Component,
+                ..
+            } => return Err(anyhow::anyhow!("Components can not be rewritten.")),
+            Payload::MemorySection(reader) => {
+                let mut section = wasm_encoder::MemorySection::new();
+                for ty in reader.clone() {
+                    let ty = ty?;
+                    if ty.initial > max_pages {
+                        return Err(anyhow::anyhow!(
+                            "Memory minimum of {} pages exceeds the maximum of {max_pages} pages.",
+                            ty.initial
+                        ));
+                    }
+                    section.memory(wasm_encoder::MemoryType {
+                        minimum: ty.initial,
+                        maximum: Some(ty.maximum.map_or(max_pages, |m| m.min(max_pages))),
+                        memory64: ty.memory64,
+                        shared: ty.shared,
+                    });
+                }
+                module.section(&section);
+                continue;
+            }
+            _ => {}
+        }
+        if let Some((id, range)) = payload.as_section() {
+            module.section(&wasm_encoder::RawSection {
+                id,
+                data: &module_wasm[range],
+            });
+        }
+    }
+    Ok(module.finish())
+}
+
+/// Returns the bytes of [body] with the overflow checks for `i32.add`.
+/// [params] is the number of parameters of the function.
+fn instrument_body(module_wasm: &[u8], body: &FunctionBody, params: u32) -> Result<Vec<u8>> {
+    let mut locals_reader = body.get_locals_reader()?;
+    let groups = locals_reader.get_count();
+    let locals_start = locals_reader.original_position();
+    let mut locals = params;
+    for _ in 0..groups {
+        locals += locals_reader.read()?.0;
+    }
+    let mut operators = body.get_operators_reader()?;
+    let operators_start = operators.original_position();
+
+    let (a, b, sum) = (locals, locals + 1, locals + 2);
+    let mut output = vec![];
+    (groups + 1).encode(&mut output);
+    output.extend_from_slice(&module_wasm[locals_start..operators_start]);
+    3u32.encode(&mut output);
+    wasm_encoder::ValType::I32.encode(&mut output);
+
+    let mut copied = operators_start;
+    while !operators.eof() {
+        let (op, offset) = operators.read_with_offset()?;
+        if !matches!(op, Operator::I32Add) {
+            continue;
+        }
+        output.extend_from_slice(&module_wasm[copied..offset]);
+        copied = operators.original_position();
+        // The addition overflows if both operands have a different sign than the sum.
+        for instruction in [
+            Instruction::LocalSet(b),
+            Instruction::LocalTee(a),
+            Instruction::LocalGet(b),
+            Instruction::I32Add,
+            Instruction::LocalTee(sum),
+            Instruction::LocalGet(a),
+            Instruction::LocalGet(sum),
+            Instruction::I32Xor,
+            Instruction::LocalGet(b),
+            Instruction::LocalGet(sum),
+            Instruction::I32Xor,
+            Instruction::I32And,
+            Instruction::I32Const(0),
+            Instruction::I32LtS,
+            Instruction::If(BlockType::Empty),
+            Instruction::Unreachable,
+            Instruction::End,
+        ] {
+            instruction.encode(&mut output);
+        }
+    }
+    output.extend_from_slice(&module_wasm[copied..body.range().end]);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instrumented_module_is_valid() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (type (func (param i32 i32) (result i32)))
+                (import "env" "f" (func (type 0)))
+                (memory 1)
+                (func (export "add") (type 0) (local i64)
+                    (i32.add (local.get 0) (i32.add (local.get 1) (i32.const 1)))
+                )
+                (func (export "sub") (type 0)
+                    (i32.sub (local.get 0) (local.get 1))
+                )
+                (data (i32.const 0) "data")
+            )"#,
+        )
+        .unwrap();
+        let output = trap_on_i32_add_overflow(&wasm).unwrap();
+        wasmparser::validate(&output).unwrap();
+        assert!(output.len() > wasm.len());
+
+        let wasm = wat::parse_str("(module (func (export \"f\")))").unwrap();
+        let output = trap_on_i32_add_overflow(&wasm).unwrap();
+        wasmparser::validate(&output).unwrap();
+    }
+}
@@ -0,0 +1,204 @@
+//! Reusable handles to exported functions with statically known Rust types.
+//! The export is looked up and its signature validated once, when the handle
+//! is created, instead of in every call as in
+//! [call_function_handle](WasmRunModuleId::call_function_handle).
+//!
+//! For dynamic values, [WasmRunModuleId::call_into] writes the results
+//! to a buffer reused by the caller.
+
+use crate::api::{default_val, WasmRunModuleId};
+use crate::types::WasmVal;
+use anyhow::Result;
+use std::cell::Cell;
+use wasmtime::{TypedFunc, Val, WasmParams, WasmResults};
+
+thread_local! {
+    /// The arguments and results converted to wasmtime values
+    /// in [WasmRunModuleId::call_into], reused across the calls of a thread.
+    static CALL_BUFFERS: Cell<(Vec<Val>, Vec<Val>)> = const { Cell::new((vec![], vec![])) };
+}
+
+/// An exported function of an instance with [Params] and [Results] Rust types,
+/// for example, `(i32, i32)` and `i32` for `(param i32 i32) (result i32)`.
+/// Created with [WasmRunModuleId::get_typed].
+#[derive(Clone)]
+pub struct TypedFunction<Params, Results> {
+    module: WasmRunModuleId,
+    name: String,
+    func: TypedFunc<Params, Results>,
+}
+
+impl WasmRunModuleId {
+    /// Returns a handle to the exported function [name] that can be called
+    /// many times. Returns an error if the module is not instantiated,
+    /// the export is not a function or its signature does not match
+    /// [Params] and [Results].
+    pub fn get_typed<Params, Results>(&self, name: &str) -> Result<TypedFunction<Params, Results>>
+    where
+        Params: WasmParams,
+        Results: WasmResults,
+    {
+        let func = self.with_instance(|instance, store| {
+            instance.get_typed_func::<Params, Results>(&mut *store, name)
+        })?;
+        Ok(TypedFunction {
+            module: self.clone(),
+            name: name.to_string(),
+            func,
+        })
+    }
+
+    /// Calls the exported function [name] with [args] and writes its results
+    /// to [results], which is cleared first and left empty if the call fails.
+    ///
+    /// Unlike [call_function_handle](WasmRunModuleId::call_function_handle),
+    /// no vector is allocated for the arguments and results, [results] and the
+    /// internal buffers keep their capacity across calls. Useful in hot loops
+    /// where the number of results is known and stable.
+    pub fn call_into(
+        &self,
+        name: &str,
+        args: &[WasmVal],
+        results: &mut Vec<WasmVal>,
+    ) -> Result<()> {
+        let func = self.with_instance(|instance, store| {
+            instance
+                .get_func(&mut *store, name)
+                .ok_or_else(|| anyhow::anyhow!("The module does not export a `{name}` function."))
+        })?;
+        // Taken instead of borrowed, a host function may call it again.
+        let (mut inputs, mut outputs) = CALL_BUFFERS.with(Cell::take);
+        inputs.extend(args.iter().map(|v| v.clone().to_val()));
+        let result = self.call_measured(Some(name), |store| {
+            outputs.extend(func.ty(&*store).results().map(|t| default_val(&t)));
+            func.call(store, &inputs, &mut outputs)
+        });
+        results.clear();
+        if result.is_ok() {
+            results.extend(outputs.drain(..).map(WasmVal::from_val));
+        }
+        inputs.clear();
+        outputs.clear();
+        CALL_BUFFERS.with(|buffers| buffers.set((inputs, outputs)));
+        result
+    }
+}
+
+impl<Params, Results> TypedFunction<Params, Results>
+where
+    Params: WasmParams,
+    Results: WasmResults,
+{
+    /// Calls the function with [params]. The metrics of the call are
+    /// recorded as in [WasmRunModuleId::call_function_handle].
+    pub fn call(&self, params: Params) -> Result<Results> {
+        self.module
+            .call_measured(Some(&self.name), |store| self.func.call(store, params))
+    }
+
+    /// The name of the export.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::*;
+    use crate::types::{ExternalValue, WasmVal};
+    use anyhow::Result;
+
+    const ADD_WAT: &str = r#"(module
+        (func (export "add") (param i32 i32) (result i32)
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (memory (export "memory") 1)
+    )"#;
+
+    fn instantiate(wat: &str) -> Result<(WasmRunModuleId, WasmRunInstanceId)> {
+        let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+        let module_id = module_builder(module, None, None)?.0;
+        let instance = module_id.instantiate()?;
+        Ok((module_id, instance))
+    }
+
+    #[test]
+    fn typed_function_calls() -> Result<()> {
+        let (module_id, _) = instantiate(ADD_WAT)?;
+        let add = module_id.get_typed::<(i32, i32), i32>("add")?;
+        assert_eq!(add.name(), "add");
+        assert_eq!(add.call((3, 4))?, 7);
+        assert_eq!(add.call((i32::MAX, 1))?, i32::MIN);
+        assert!(module_id.last_call_duration_micros()?.0.is_some());
+
+        assert!(module_id.get_typed::<(i64, i64), i64>("add").is_err());
+        assert!(module_id.get_typed::<(), ()>("memory").is_err());
+        assert!(module_id.get_typed::<(), ()>("missing").is_err());
+
+        module_id.dispose()?;
+        assert!(add.call((1, 2)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn call_into_reuses_results() -> Result<()> {
+        let (module_id, _) = instantiate(ADD_WAT)?;
+        let mut results = vec![WasmVal::i64(0), WasmVal::i64(0)];
+        module_id.call_into("add", &[WasmVal::i32(3), WasmVal::i32(4)], &mut results)?;
+        assert!(matches!(results.as_slice(), [WasmVal::i32(7)]));
+
+        let capacity = results.capacity();
+        module_id.call_into("add", &[WasmVal::i32(1), WasmVal::i32(2)], &mut results)?;
+        assert!(matches!(results.as_slice(), [WasmVal::i32(3)]));
+        assert_eq!(results.capacity(), capacity);
+
+        assert!(module_id
+            .call_into("add", &[WasmVal::i32(1)], &mut results)
+            .is_err());
+        assert!(results.is_empty());
+        assert!(module_id.call_into("missing", &[], &mut results).is_err());
+        module_id.dispose()
+    }
+
+    /// Compares the typed and dynamic call paths.
+    /// Run with `cargo test --release typed_function_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn typed_function_benchmark() -> Result<()> {
+        const CALLS: i32 = 100_000;
+        let (module_id, instance) = instantiate(ADD_WAT)?;
+
+        let start = std::time::Instant::now();
+        let add = module_id.get_typed::<(i32, i32), i32>("add")?;
+        for i in 0..CALLS {
+            assert_eq!(add.call((i, 1))?, i + 1);
+        }
+        let typed = start.elapsed();
+
+        let start = std::time::Instant::now();
+        for i in 0..CALLS {
+            let func = instance
+                .exports()
+                .0
+                .into_iter()
+                .find(|e| e.desc.name == "add")
+                .and_then(|e| match e.value {
+                    ExternalValue::Func(func) => Some(func),
+                    _ => None,
+                })
+                .unwrap();
+            let output = module_id
+                .call_function_handle_sync(func, vec![WasmVal::i32(i), WasmVal::i32(1)])?
+                .0;
+            assert!(matches!(output.as_slice(), [WasmVal::i32(v)] if *v == i + 1));
+        }
+        let dynamic = start.elapsed();
+
+        println!(
+            "{CALLS} calls: typed {typed:?} ({:?}/call), dynamic {dynamic:?} ({:?}/call)",
+            typed / CALLS as u32,
+            dynamic / CALLS as u32,
+        );
+        module_id.dispose()
+    }
+}
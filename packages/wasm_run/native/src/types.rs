@@ -9,8 +9,8 @@ use wasmi::{core::ValueType, *};
 #[cfg(not(feature = "wasmtime"))]
 pub use wasmi::{Func, Global, GlobalType, Memory, Mutability, Table};
 
+#[derive(Clone)]
 #[allow(non_camel_case_types)]
-#[derive(Debug)]
 pub enum WasmVal {
     /// Value of 32-bit signed or unsigned integer.
     i32(i32),
@@ -28,22 +28,120 @@ pub enum WasmVal {
     externRef(Option<u32>), // NonZeroU32
 }
 
+/// Formats the value with its type, for example `i32(42)`, `f64(3.14)`,
+/// `v128(0x000000000000000000000000000000ff)` or `funcRef(null)`.
+/// Floats are formatted with the shortest representation that round-trips.
+impl std::fmt::Display for WasmVal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WasmVal::i32(v) => write!(f, "i32({v})"),
+            WasmVal::i64(v) => write!(f, "i64({v})"),
+            WasmVal::f32(v) => write!(f, "f32({v:?})"),
+            WasmVal::f64(v) => write!(f, "f64({v:?})"),
+            WasmVal::v128(v) => write!(f, "v128(0x{:032x})", u128::from_le_bytes(*v)),
+            WasmVal::funcRef(None) => write!(f, "funcRef(null)"),
+            WasmVal::funcRef(Some(_)) => write!(f, "funcRef(func)"),
+            WasmVal::externRef(None) => write!(f, "externRef(null)"),
+            WasmVal::externRef(Some(v)) => write!(f, "externRef({v})"),
+        }
+    }
+}
+
+impl std::fmt::Debug for WasmVal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+macro_rules! wasm_val_conversions {
+    ($($variant:ident),*) => {
+        $(
+            impl From<$variant> for WasmVal {
+                fn from(value: $variant) -> Self {
+                    WasmVal::$variant(value)
+                }
+            }
+
+            /// Returns an error if the value is not of the requested type.
+            impl TryFrom<WasmVal> for $variant {
+                type Error = anyhow::Error;
+
+                fn try_from(value: WasmVal) -> Result<Self, Self::Error> {
+                    match value {
+                        WasmVal::$variant(v) => Ok(v),
+                        _ => Err(anyhow::anyhow!(
+                            "Expected a {} value, found {value}.",
+                            stringify!($variant)
+                        )),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+wasm_val_conversions!(i32, i64, f32, f64);
+
+macro_rules! v128_lanes {
+    ($($from:ident, $as:ident: [$ty:ty; $n:literal]),*) => {
+        impl WasmVal {
+            $(
+                #[doc = concat!("Creates a `v128` value from ", stringify!($n), " `", stringify!($ty), "` lanes.")]
+                /// The first lane is stored in the lowest bytes.
+                pub fn $from(lanes: [$ty; $n]) -> Self {
+                    let mut bytes = [0; 16];
+                    bytes
+                        .chunks_exact_mut(16 / $n)
+                        .zip(lanes)
+                        .for_each(|(chunk, lane)| chunk.copy_from_slice(&lane.to_le_bytes()));
+                    WasmVal::v128(bytes)
+                }
+
+                #[doc = concat!("Returns the ", stringify!($n), " `", stringify!($ty), "` lanes of a `v128` value.")]
+                /// Returns an error if the value is not a `v128`.
+                pub fn $as(&self) -> Result<[$ty; $n]> {
+                    let WasmVal::v128(bytes) = self else {
+                        return Err(anyhow::anyhow!("Expected a v128 value, found {self}."));
+                    };
+                    let mut lanes = [<$ty>::default(); $n];
+                    lanes
+                        .iter_mut()
+                        .zip(bytes.chunks_exact(16 / $n))
+                        .for_each(|(lane, chunk)| {
+                            *lane = <$ty>::from_le_bytes(chunk.try_into().unwrap())
+                        });
+                    Ok(lanes)
+                }
+            )*
+        }
+    };
+}
+
+v128_lanes!(
+    v128_i8x16, as_i8x16: [i8; 16],
+    v128_i16x8, as_i16x8: [i16; 8],
+    v128_i32x4, as_i32x4: [i32; 4],
+    v128_i64x2, as_i64x2: [i64; 2],
+    v128_f32x4, as_f32x4: [f32; 4],
+    v128_f64x2, as_f64x2: [f64; 2]
+);
+
 impl WasmVal {
     #[cfg(not(feature = "wasmtime"))]
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_value(self, ctx: impl AsContextMut) -> Value {
-        match self {
+    pub fn to_value(self, ctx: impl AsContextMut) -> Result<Value> {
+        Ok(match self {
             WasmVal::i32(i) => Value::I32(i),
             WasmVal::i64(i) => Value::I64(i),
             WasmVal::f32(i) => Value::F32(i.to_bits().into()),
             WasmVal::f64(i) => Value::F64(i.to_bits().into()),
-            WasmVal::v128(_i) => panic!("v128 is not supported in wasmi"),
+            WasmVal::v128(_i) => return Err(anyhow::anyhow!("v128 is not supported in wasmi.")),
             WasmVal::funcRef(i) => {
                 let inner = i.map(|f| Func::clone(&f.func_wasmi));
                 Value::FuncRef(FuncRef::new(inner))
             }
             WasmVal::externRef(i) => Value::ExternRef(ExternRef::new::<u32>(ctx, i)),
-        }
+        })
     }
 
     #[cfg(not(feature = "wasmtime"))]
@@ -68,7 +166,7 @@ impl WasmVal {
             WasmVal::i64(i) => wasmtime::Val::I64(i),
             WasmVal::f32(i) => wasmtime::Val::F32(i.to_bits()),
             WasmVal::f64(i) => wasmtime::Val::F64(i.to_bits()),
-            WasmVal::v128(i) => wasmtime::Val::V128(wasmtime::V128::from(u128::from_ne_bytes(i))),
+            WasmVal::v128(i) => wasmtime::Val::V128(wasmtime::V128::from(u128::from_le_bytes(i))),
             WasmVal::funcRef(i) => wasmtime::Val::FuncRef(i.map(|f| f.func_wasmtime)),
             WasmVal::externRef(i) => wasmtime::Val::ExternRef(i.map(wasmtime::ExternRef::new)),
         }
@@ -79,7 +177,7 @@ impl WasmVal {
         match val {
             wasmtime::Val::I32(i) => WasmVal::i32(i),
             wasmtime::Val::I64(i) => WasmVal::i64(i),
-            wasmtime::Val::V128(i) => WasmVal::v128(i.as_u128().to_ne_bytes()),
+            wasmtime::Val::V128(i) => WasmVal::v128(i.as_u128().to_le_bytes()),
             wasmtime::Val::F32(i) => WasmVal::f32(f32::from_bits(i)),
             wasmtime::Val::F64(i) => WasmVal::f64(f64::from_bits(i)),
             wasmtime::Val::FuncRef(i) => WasmVal::funcRef(i.map(|f| RustOpaque::new(f.into()))),
@@ -350,24 +448,30 @@ pub struct FunctionCall {
 pub struct ModuleExportDesc {
     pub name: String,
     pub ty: ExternalType,
+    /// The position of the export in the exports of the module,
+    /// which is the same in the exports of its instances.
+    /// Use it with [WasmRunModuleId::call_by_index].
+    pub index: u32,
 }
 
 #[cfg(not(feature = "wasmtime"))]
-impl From<&ExportType<'_>> for ModuleExportDesc {
-    fn from(export: &ExportType) -> Self {
+impl ModuleExportDesc {
+    pub fn new(index: usize, export: &ExportType) -> Self {
         ModuleExportDesc {
             name: export.name().to_string(),
             ty: export.ty().into(),
+            index: index as u32,
         }
     }
 }
 
 #[cfg(feature = "wasmtime")]
-impl From<&wasmtime::ExportType<'_>> for ModuleExportDesc {
-    fn from(export: &wasmtime::ExportType) -> Self {
+impl ModuleExportDesc {
+    pub fn new(index: usize, export: &wasmtime::ExportType) -> Self {
         ModuleExportDesc {
             name: export.name().to_string(),
             ty: (&export.ty()).into(),
+            index: index as u32,
         }
     }
 }
@@ -380,11 +484,12 @@ pub struct ModuleExportValue {
 
 impl ModuleExportValue {
     #[cfg(not(feature = "wasmtime"))]
-    pub fn from_export<T>(export: Export, store: &Store<T>) -> Self {
+    pub fn from_export<T>(index: usize, export: Export, store: &Store<T>) -> Self {
         ModuleExportValue {
             desc: ModuleExportDesc {
                 name: export.name().to_string(),
                 ty: (&export.ty(store)).into(),
+                index: index as u32,
             },
             value: export.into_extern().into(),
         }
@@ -392,6 +497,7 @@ impl ModuleExportValue {
 
     #[cfg(feature = "wasmtime")]
     pub fn from_export(
+        index: usize,
         export: (String, wasmtime::Extern),
         store: impl wasmtime::AsContext,
     ) -> Self {
@@ -399,6 +505,7 @@ impl ModuleExportValue {
             desc: ModuleExportDesc {
                 name: export.0,
                 ty: (&export.1.ty(store)).into(),
+                index: index as u32,
             },
             value: export.1.into(),
         }
@@ -517,7 +624,7 @@ pub struct TableArgs {
     pub maximum: Option<u32>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MemoryTy {
     /// Whether or not this memory could be shared between multiple processes.
     pub shared: bool,
@@ -569,11 +676,165 @@ impl From<&wasmtime::MemoryType> for MemoryTy {
     }
 }
 
+/// The memory used by the instance of a module, for capacity planning.
+/// The sizes of the memories and tables are tracked by the store as they
+/// are created and grown, they include the ones that are not exported
+/// and the ones created by the host. Only the exported ones are counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryFootprint {
+    /// The number of exported linear memories, including shared memories.
+    pub memories: u32,
+    /// The sum of the current pages of the memories, of 64 KiB each.
+    pub memory_pages: u64,
+    /// The sum of the current sizes in bytes of the memories.
+    pub memory_bytes: u64,
+    /// The number of exported tables.
+    pub tables: u32,
+    /// The sum of the current number of elements of the tables.
+    pub table_elements: u64,
+    /// The number of exported globals.
+    pub globals: u32,
+    /// An estimate of the host memory used by the tables and globals,
+    /// a pointer for each table element and 16 bytes for each global.
+    pub overhead_bytes: u64,
+    /// The sum of [memory_bytes] and [overhead_bytes].
+    pub total_bytes: u64,
+}
+
+impl MemoryFootprint {
+    const PAGE_BYTES: u64 = 65536;
+    const TABLE_ELEMENT_BYTES: u64 = std::mem::size_of::<usize>() as u64;
+    const GLOBAL_BYTES: u64 = 16;
+
+    pub fn new(memory_bytes: u64, table_elements: u64) -> Self {
+        let mut footprint = MemoryFootprint {
+            memory_pages: memory_bytes / Self::PAGE_BYTES,
+            memory_bytes,
+            table_elements,
+            total_bytes: memory_bytes,
+            ..Default::default()
+        };
+        footprint.add_overhead(table_elements * Self::TABLE_ELEMENT_BYTES);
+        footprint
+    }
+
+    pub fn add_memory(&mut self) {
+        self.memories += 1;
+    }
+
+    pub fn add_table(&mut self) {
+        self.tables += 1;
+    }
+
+    pub fn add_global(&mut self) {
+        self.globals += 1;
+        self.add_overhead(Self::GLOBAL_BYTES);
+    }
+
+    fn add_overhead(&mut self, bytes: u64) {
+        self.overhead_bytes += bytes;
+        self.total_bytes += bytes;
+    }
+}
+
 pub struct PointerAndLength {
     pub pointer: usize,
     pub length: usize,
 }
 
+/// The error returned when the start function of a module traps
+/// while instantiating the module.
+#[derive(Debug)]
+pub struct StartTrap {
+    pub trap: anyhow::Error,
+}
+
+impl Display for StartTrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The start function of the module trapped: {}",
+            self.trap.root_cause()
+        )
+    }
+}
+
+impl std::error::Error for StartTrap {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.trap.as_ref())
+    }
+}
+
 pub fn to_anyhow<T: Display>(value: T) -> anyhow::Error {
     anyhow::Error::msg(value.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wasm_val_display() {
+        assert_eq!(WasmVal::i32(42).to_string(), "i32(42)");
+        assert_eq!(WasmVal::i64(-1).to_string(), "i64(-1)");
+        assert_eq!(WasmVal::f64(2.5).to_string(), "f64(2.5)");
+        assert_eq!(WasmVal::f32(1.0).to_string(), "f32(1.0)");
+        let mut bytes = [0; 16];
+        bytes[0] = 0xff;
+        assert_eq!(
+            WasmVal::v128(bytes).to_string(),
+            "v128(0x000000000000000000000000000000ff)"
+        );
+        assert_eq!(WasmVal::funcRef(None).to_string(), "funcRef(null)");
+        assert_eq!(WasmVal::externRef(Some(3)).to_string(), "externRef(3)");
+        assert_eq!(format!("{:?}", WasmVal::i32(42)), "i32(42)");
+    }
+
+    #[test]
+    fn wasm_val_conversions() -> Result<()> {
+        let i: i32 = WasmVal::from(7).try_into()?;
+        assert_eq!(i, 7);
+        assert_eq!(i64::try_from(WasmVal::from(8i64))?, 8);
+        assert_eq!(f32::try_from(WasmVal::from(1.5f32))?, 1.5);
+        assert_eq!(f64::try_from(WasmVal::from(2.5))?, 2.5);
+
+        let err = i32::try_from(WasmVal::from(2.5)).unwrap_err();
+        assert_eq!(err.to_string(), "Expected a i32 value, found f64(2.5).");
+        Ok(())
+    }
+
+    #[test]
+    fn v128_lanes() -> Result<()> {
+        let value = WasmVal::v128_i32x4([1, -2, 3, i32::MAX]);
+        assert_eq!(
+            value.to_string(),
+            "v128(0x7fffffff00000003fffffffe00000001)"
+        );
+        assert_eq!(value.as_i32x4()?, [1, -2, 3, i32::MAX]);
+        assert_eq!(value.as_i64x2()?, [-8589934591, 0x7fffffff_00000003]);
+        assert_eq!(value.as_i8x16()?[..5], [1, 0, 0, 0, -2]);
+
+        let value = WasmVal::v128_f64x2([1.5, -0.25]);
+        assert_eq!(value.as_f64x2()?, [1.5, -0.25]);
+        assert_eq!(WasmVal::v128_f32x4([0.5; 4]).as_f32x4()?, [0.5; 4]);
+        assert_eq!(WasmVal::v128_i16x8([-3; 8]).as_i16x8()?, [-3; 8]);
+
+        let err = WasmVal::i32(1).as_i32x4().unwrap_err();
+        assert_eq!(err.to_string(), "Expected a v128 value, found i32(1).");
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_val_display_floats_round_trip() {
+        for v in [0.1, 1.0 / 3.0, f64::MAX, f64::MIN_POSITIVE, -0.0, 1e-310] {
+            let s = WasmVal::f64(v).to_string();
+            let parsed: f64 = s["f64(".len()..s.len() - 1].parse().unwrap();
+            assert_eq!(parsed.to_bits(), v.to_bits(), "{s}");
+        }
+        for v in [0.1f32, 1.0 / 3.0, f32::MAX, f32::MIN_POSITIVE, 1e-40] {
+            let s = WasmVal::f32(v).to_string();
+            let parsed: f32 = s["f32(".len()..s.len() - 1].parse().unwrap();
+            assert_eq!(parsed.to_bits(), v.to_bits(), "{s}");
+        }
+    }
+}
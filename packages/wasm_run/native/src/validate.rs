@@ -0,0 +1,171 @@
+//! Validation of Wasm module binaries received in chunks.
+
+use crate::config::{ModuleConfig, ModuleTooLarge};
+use anyhow::Result;
+use wasmparser::{Chunk, FuncValidatorAllocations, Parser, ValidPayload, Validator};
+
+/// The [wasmparser::WasmFeatures] of the Wasm features enabled in [config].
+pub(crate) fn parser_features(config: &ModuleConfig) -> wasmparser::WasmFeatures {
+    let f = config.wasm_features();
+    wasmparser::WasmFeatures {
+        mutable_global: f.mutable_global,
+        saturating_float_to_int: f.saturating_float_to_int,
+        sign_extension: f.sign_extension,
+        reference_types: f.reference_types,
+        multi_value: f.multi_value,
+        bulk_memory: f.bulk_memory,
+        simd: f.simd,
+        relaxed_simd: f.relaxed_simd,
+        threads: f.threads,
+        tail_call: f.tail_call,
+        floats: f.floats,
+        multi_memory: f.multi_memory,
+        exceptions: f.exceptions,
+        memory64: f.memory64,
+        extended_const: f.extended_const,
+        component_model: f.component_model,
+        memory_control: f.memory_control,
+        gc: f.garbage_collection,
+        function_references: f.function_references,
+        ..Default::default()
+    }
+}
+
+/// Validates a Wasm module as its bytes arrive, without buffering the whole binary.
+///
+/// Each section is validated as soon as it is complete, so a malformed module
+/// is rejected at the first invalid section. Only the bytes of the section
+/// being received are kept in memory, for the code section, a single function body.
+pub struct StreamingValidator {
+    parser: Parser,
+    validator: Validator,
+    allocations: FuncValidatorAllocations,
+    /// The received bytes that were not parsed yet.
+    buffer: Vec<u8>,
+    size: usize,
+    max_module_bytes: Option<usize>,
+    done: bool,
+}
+
+impl StreamingValidator {
+    /// Creates a validator for the Wasm features enabled in [config].
+    /// Modules larger than [ModuleConfig.max_module_bytes] are rejected
+    /// with [ModuleTooLarge] as soon as the limit is exceeded.
+    pub fn new(config: &ModuleConfig) -> Self {
+        let features = parser_features(config);
+        StreamingValidator {
+            parser: Parser::new(0),
+            validator: Validator::new_with_features(features),
+            allocations: Default::default(),
+            buffer: vec![],
+            size: 0,
+            max_module_bytes: config.max_module_bytes,
+            done: false,
+        }
+    }
+
+    /// Validates the sections completed by the next [chunk] of the module binary.
+    /// Returns an error as soon as an invalid section is received.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<()> {
+        self.size += chunk.len();
+        if let Some(limit) = self.max_module_bytes {
+            if self.size > limit {
+                return Err(ModuleTooLarge {
+                    size: self.size,
+                    limit,
+                }
+                .into());
+            }
+        }
+        if self.done && !chunk.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Unexpected data after the end of the module."
+            ));
+        }
+        self.buffer.extend_from_slice(chunk);
+        self.validate(false)
+    }
+
+    /// Validates the remaining bytes once the whole module binary was fed.
+    /// Returns an error if the module is truncated or invalid.
+    pub fn finish(mut self) -> Result<()> {
+        self.validate(true)?;
+        if !self.done {
+            return Err(anyhow::anyhow!("Unexpected end of the module binary."));
+        }
+        Ok(())
+    }
+
+    /// Parses and validates the payloads in [buffer] until more data is needed.
+    /// [eof] is `true` if no more data will be received.
+    fn validate(&mut self, eof: bool) -> Result<()> {
+        while !self.done {
+            let (consumed, payload) = match self.parser.parse(&self.buffer, eof)? {
+                Chunk::NeedMoreData(_) => return Ok(()),
+                Chunk::Parsed { consumed, payload } => (consumed, payload),
+            };
+            match self.validator.payload(&payload)? {
+                ValidPayload::Func(func, body) => {
+                    let allocations = std::mem::take(&mut self.allocations);
+                    let mut func = func.into_validator(allocations);
+                    func.validate(&body)?;
+                    self.allocations = func.into_allocations();
+                }
+                ValidPayload::Parser(_) => {
+                    return Err(anyhow::anyhow!(
+                        "Nested modules and components are not supported."
+                    ));
+                }
+                ValidPayload::End(_) => self.done = true,
+                ValidPayload::Ok => {}
+            }
+            self.buffer.drain(..consumed);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = r#"(module
+        (memory 1)
+        (func (export "add") (param i32 i32) (result i32)
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (data (i32.const 0) "data")
+    )"#;
+
+    #[test]
+    fn streaming_validation() {
+        let wasm = wat::parse_str(MODULE).unwrap();
+        let mut validator = StreamingValidator::new(&Default::default());
+        for chunk in wasm.chunks(3) {
+            validator.feed(chunk).unwrap();
+        }
+        validator.finish().unwrap();
+
+        // Truncated module
+        let mut validator = StreamingValidator::new(&Default::default());
+        validator.feed(&wasm[..wasm.len() - 5]).unwrap();
+        assert!(validator.finish().is_err());
+
+        // Invalid function body, rejected before receiving the data section
+        let invalid = wat::parse_str(MODULE.replace("i32.add", "i64.add")).unwrap();
+        let mut validator = StreamingValidator::new(&Default::default());
+        let rejected_at = invalid
+            .chunks(4)
+            .position(|chunk| validator.feed(chunk).is_err())
+            .unwrap();
+        assert!(rejected_at < invalid.len() / 4 - 1);
+
+        let config = ModuleConfig {
+            max_module_bytes: Some(wasm.len() - 1),
+            ..Default::default()
+        };
+        let mut validator = StreamingValidator::new(&config);
+        let err = validator.feed(&wasm).err().unwrap();
+        assert!(err.downcast_ref::<ModuleTooLarge>().is_some());
+    }
+}
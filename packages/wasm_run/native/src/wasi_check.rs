@@ -0,0 +1,142 @@
+//! Diagnostics of a [WasiConfigNative] that is likely insufficient for
+//! the WASI imports of a module, before it is instantiated.
+
+use crate::api::CompiledModule;
+use crate::config::WasiConfigNative;
+use crate::types::ModuleImportDesc;
+
+/// The WASI modules of the imports checked by [WasiConfigNative::check_against].
+const WASI_MODULES: [&str; 2] = ["wasi_snapshot_preview1", "wasi_unstable"];
+
+/// A capability used by a module that is not provided by its [WasiConfigNative],
+/// the calls to the imports will probably fail at runtime.
+/// Returned by [WasiConfigNative::check_against].
+///
+/// `#[non_exhaustive]` since more capabilities will be checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WasiRequirement {
+    /// The module accesses the filesystem, but no directory is preopened.
+    FilesystemWithoutPreopens { imports: Vec<String> },
+    /// The module reads its arguments, but none are provided or inherited.
+    ArgsWithoutValues { imports: Vec<String> },
+    /// The module reads its environment variables,
+    /// but none are provided or inherited.
+    EnvWithoutValues { imports: Vec<String> },
+}
+
+impl std::fmt::Display for WasiRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WasiRequirement::FilesystemWithoutPreopens { imports } => write!(
+                f,
+                "The module imports `{}` to access the filesystem, but no directory is preopened. \
+                Add one to `preopened_dirs`.",
+                imports.join("`, `"),
+            ),
+            WasiRequirement::ArgsWithoutValues { imports } => write!(
+                f,
+                "The module imports `{}` to read its arguments, but none are provided. \
+                Set `args` or `inherit_args`.",
+                imports.join("`, `"),
+            ),
+            WasiRequirement::EnvWithoutValues { imports } => write!(
+                f,
+                "The module imports `{}` to read its environment variables, but none are provided. \
+                Set `env` or `inherit_env`.",
+                imports.join("`, `"),
+            ),
+        }
+    }
+}
+
+impl WasiConfigNative {
+    /// Cross-references the WASI imports of [module] with the capabilities
+    /// of this configuration. Returns the capabilities the module uses that
+    /// are not provided, empty if none are missing or the module does not use WASI.
+    ///
+    /// This is a heuristic, a module may import a function and never call it.
+    pub fn check_against(&self, module: &CompiledModule) -> Vec<WasiRequirement> {
+        self.check_imports(&module.get_module_imports().0)
+    }
+
+    #[allow(deprecated)]
+    fn check_imports(&self, imports: &[ModuleImportDesc]) -> Vec<WasiRequirement> {
+        let names = |is_used: fn(&str) -> bool| {
+            let mut names = imports
+                .iter()
+                .filter(|i| WASI_MODULES.contains(&i.module.as_str()) && is_used(&i.name))
+                .map(|i| i.name.clone())
+                .collect::<Vec<_>>();
+            names.dedup();
+            names
+        };
+
+        let mut requirements = vec![];
+        let fs = names(|name| name.starts_with("path_") || name == "fd_readdir");
+        if !fs.is_empty() && self.preopened_dirs.is_empty() {
+            requirements.push(WasiRequirement::FilesystemWithoutPreopens { imports: fs });
+        }
+        let args = names(|name| name == "args_get" || name == "args_sizes_get");
+        if !args.is_empty() && self.args.is_empty() && !self.inherit_args {
+            requirements.push(WasiRequirement::ArgsWithoutValues { imports: args });
+        }
+        let env = names(|name| name == "environ_get" || name == "environ_sizes_get");
+        if !env.is_empty() && self.env.is_empty() && !self.inherit_env {
+            requirements.push(WasiRequirement::EnvWithoutValues { imports: env });
+        }
+        requirements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::*;
+    use crate::config::PreopenedDir;
+    use anyhow::Result;
+
+    const FILES_WAT: &str = r#"(module
+        (import "wasi_snapshot_preview1" "path_open"
+            (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+        (import "wasi_snapshot_preview1" "fd_read"
+            (func $fd_read (param i32 i32 i32 i32) (result i32)))
+        (import "wasi_snapshot_preview1" "args_sizes_get"
+            (func $args_sizes_get (param i32 i32) (result i32)))
+        (memory (export "memory") 1)
+    )"#;
+
+    #[test]
+    #[allow(deprecated)]
+    fn wasi_config_check_against() -> Result<()> {
+        let module = compile_wasm(parse_wat_format(FILES_WAT.to_string())?, Default::default())?;
+
+        let requirements = WasiConfigNative::default().check_against(&module);
+        assert_eq!(
+            requirements,
+            vec![
+                WasiRequirement::FilesystemWithoutPreopens {
+                    imports: vec!["path_open".to_string()]
+                },
+                WasiRequirement::ArgsWithoutValues {
+                    imports: vec!["args_sizes_get".to_string()]
+                },
+            ]
+        );
+        assert!(requirements[0].to_string().contains("`path_open`"));
+
+        let config = WasiConfigNative {
+            args: vec!["program".to_string()],
+            preopened_dirs: vec![PreopenedDir::new("/".to_string(), ".".to_string())],
+            ..Default::default()
+        };
+        assert_eq!(config.check_against(&module), vec![]);
+
+        let no_wasi = compile_wasm(
+            parse_wat_format("(module (memory (export \"memory\") 1))".to_string())?,
+            Default::default(),
+        )?;
+        assert_eq!(WasiConfigNative::default().check_against(&no_wasi), vec![]);
+        Ok(())
+    }
+}
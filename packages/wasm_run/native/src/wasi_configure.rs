@@ -0,0 +1,75 @@
+//! An escape hatch to configure the WASI context of a module with the options
+//! of the runtime that are not exposed in [WasiConfigNative] yet.
+
+use crate::api::{module_builder_with_linker, CompiledModule, WasmRunModuleId};
+use crate::config::{WasiConfigNative, WasiCtxConfigure};
+use anyhow::Result;
+
+/// Creates a module builder for [module] with WASI, as in
+/// [module_builder](crate::api::module_builder), calling [configure] with
+/// the WASI context after the settings of [wasi_config] are applied.
+/// Returns the error of [configure], if any.
+///
+/// [configure] is runtime specific, see [WasiCtxConfigure].
+pub fn module_builder_with_wasi(
+    module: CompiledModule,
+    wasi_config: WasiConfigNative,
+    configure: WasiCtxConfigure,
+) -> Result<WasmRunModuleId> {
+    #[cfg(feature = "wasmtime")]
+    let module_id =
+        module_builder_with_linker(module, None, Some(wasi_config), None, Some(configure))?;
+    #[cfg(not(feature = "wasmtime"))]
+    let module_id = module_builder_with_linker(module, Some(wasi_config), None, Some(configure))?;
+    Ok(module_id.0)
+}
+
+// The hook in the test is specific to the wasi-common version of wasmtime.
+#[cfg(all(test, feature = "wasmtime"))]
+mod tests {
+    use super::*;
+    use crate::api::*;
+
+    const ARGS_WAT: &str = r#"(module
+        (import "wasi_snapshot_preview1" "args_sizes_get"
+            (func $args_sizes_get (param i32 i32) (result i32)))
+        (memory (export "memory") 1)
+        (func (export "args_count") (result i32)
+            (drop (call $args_sizes_get (i32.const 0) (i32.const 4)))
+            (i32.load (i32.const 0))
+        )
+    )"#;
+
+    fn compile() -> Result<CompiledModule> {
+        compile_wasm(parse_wat_format(ARGS_WAT.to_string())?, Default::default())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn wasi_configure_hook() -> Result<()> {
+        let wasi_config = WasiConfigNative {
+            args: vec!["first".to_string()],
+            ..Default::default()
+        };
+        let module_id = module_builder_with_wasi(
+            compile()?,
+            wasi_config,
+            Box::new(|wasi: &mut wasi_common::WasiCtx| {
+                wasi.push_arg("second")?;
+                Ok(())
+            }),
+        )?;
+        module_id.instantiate()?;
+        let args_count = module_id.get_typed::<(), i32>("args_count")?;
+        assert_eq!(args_count.call(())?, 2);
+        module_id.dispose()?;
+
+        let result = module_builder_with_wasi(
+            compile()?,
+            WasiConfigNative::default(),
+            Box::new(|_: &mut wasi_common::WasiCtx| Err(anyhow::anyhow!("configure failed"))),
+        );
+        assert_eq!(result.err().unwrap().to_string(), "configure failed");
+        Ok(())
+    }
+}
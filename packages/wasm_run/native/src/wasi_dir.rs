@@ -0,0 +1,706 @@
+use std::{
+    any::Any,
+    collections::HashSet,
+    io::{IoSlice, IoSliceMut},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use wasi_common::{
+    dir::{OpenResult, ReaddirCursor, ReaddirEntity},
+    file::{FdFlags, FileType, Filestat, OFlags},
+    snapshots::preview_1::types::{Errno, Rights},
+    Error, SystemTimeSpec, WasiDir,
+};
+
+/// A [WasiDir] that only allows the operations within a set of WASI [Rights].
+/// Directories opened from it inherit the same rights.
+pub struct RightsDir {
+    inner: Box<dyn WasiDir>,
+    rights: Rights,
+}
+
+impl RightsDir {
+    pub fn new(inner: Box<dyn WasiDir>, rights: u64) -> Self {
+        RightsDir {
+            inner,
+            rights: Rights::from_bits_truncate(rights),
+        }
+    }
+
+    fn check(&self, rights: Rights) -> Result<(), Error> {
+        if self.rights.contains(rights) {
+            Ok(())
+        } else {
+            Err(Errno::Notcapable.into())
+        }
+    }
+
+    /// Returns the inner directory of [dir] if it is a [RightsDir] with [rights].
+    fn inner_of(dir: &dyn WasiDir, rights: Rights) -> Result<&dyn WasiDir, Error> {
+        match dir.as_any().downcast_ref::<RightsDir>() {
+            Some(dir) => {
+                dir.check(rights)?;
+                Ok(dir.inner.as_ref())
+            }
+            None => Ok(dir),
+        }
+    }
+}
+
+#[wiggle::async_trait]
+impl WasiDir for RightsDir {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn open_file(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        oflags: OFlags,
+        read: bool,
+        write: bool,
+        fdflags: FdFlags,
+    ) -> Result<OpenResult, Error> {
+        self.check(Rights::PATH_OPEN)?;
+        if read {
+            self.check(Rights::FD_READ)?;
+        }
+        if write {
+            self.check(Rights::FD_WRITE)?;
+        }
+        if oflags.contains(OFlags::CREATE) {
+            self.check(Rights::PATH_CREATE_FILE)?;
+        }
+        if oflags.contains(OFlags::TRUNCATE) {
+            self.check(Rights::PATH_FILESTAT_SET_SIZE)?;
+        }
+        let result = self
+            .inner
+            .open_file(symlink_follow, path, oflags, read, write, fdflags)
+            .await?;
+        Ok(match result {
+            OpenResult::Dir(dir) => OpenResult::Dir(Box::new(RightsDir {
+                inner: dir,
+                rights: self.rights,
+            })),
+            file => file,
+        })
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), Error> {
+        self.check(Rights::PATH_CREATE_DIRECTORY)?;
+        self.inner.create_dir(path).await
+    }
+
+    async fn readdir(
+        &self,
+        cursor: ReaddirCursor,
+    ) -> Result<Box<dyn Iterator<Item = Result<ReaddirEntity, Error>> + Send>, Error> {
+        self.check(Rights::FD_READDIR)?;
+        self.inner.readdir(cursor).await
+    }
+
+    async fn symlink(&self, old_path: &str, new_path: &str) -> Result<(), Error> {
+        self.check(Rights::PATH_SYMLINK)?;
+        self.inner.symlink(old_path, new_path).await
+    }
+
+    async fn remove_dir(&self, path: &str) -> Result<(), Error> {
+        self.check(Rights::PATH_REMOVE_DIRECTORY)?;
+        self.inner.remove_dir(path).await
+    }
+
+    async fn unlink_file(&self, path: &str) -> Result<(), Error> {
+        self.check(Rights::PATH_UNLINK_FILE)?;
+        self.inner.unlink_file(path).await
+    }
+
+    async fn read_link(&self, path: &str) -> Result<PathBuf, Error> {
+        self.check(Rights::PATH_READLINK)?;
+        self.inner.read_link(path).await
+    }
+
+    async fn get_filestat(&self) -> Result<Filestat, Error> {
+        self.check(Rights::FD_FILESTAT_GET)?;
+        self.inner.get_filestat().await
+    }
+
+    async fn get_path_filestat(
+        &self,
+        path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Filestat, Error> {
+        self.check(Rights::PATH_FILESTAT_GET)?;
+        self.inner.get_path_filestat(path, follow_symlinks).await
+    }
+
+    async fn rename(
+        &self,
+        path: &str,
+        dest_dir: &dyn WasiDir,
+        dest_path: &str,
+    ) -> Result<(), Error> {
+        self.check(Rights::PATH_RENAME_SOURCE)?;
+        let dest_dir = Self::inner_of(dest_dir, Rights::PATH_RENAME_TARGET)?;
+        self.inner.rename(path, dest_dir, dest_path).await
+    }
+
+    async fn hard_link(
+        &self,
+        path: &str,
+        target_dir: &dyn WasiDir,
+        target_path: &str,
+    ) -> Result<(), Error> {
+        self.check(Rights::PATH_LINK_SOURCE)?;
+        let target_dir = Self::inner_of(target_dir, Rights::PATH_LINK_TARGET)?;
+        self.inner.hard_link(path, target_dir, target_path).await
+    }
+
+    async fn set_times(
+        &self,
+        path: &str,
+        atime: Option<SystemTimeSpec>,
+        mtime: Option<SystemTimeSpec>,
+        follow_symlinks: bool,
+    ) -> Result<(), Error> {
+        self.check(Rights::PATH_FILESTAT_SET_TIMES)?;
+        self.inner
+            .set_times(path, atime, mtime, follow_symlinks)
+            .await
+    }
+}
+
+/// A [WasiDir] with the merged view of directories stacked as layers,
+/// see [crate::config::PreopenedDir::overlay].
+///
+/// Reads fall through the layers from the top one, a path in a higher layer
+/// hides the same path in the lower ones. Directories present in several
+/// layers are merged. Writes go to the top layer: files and directories
+/// are created in it, and a path that only exists in a lower layer is copied
+/// up to the top layer, with the directories containing it, before it is
+/// modified. The paths that exist in a lower layer can not be removed or
+/// renamed, failing with `EROFS`.
+pub struct OverlayDir {
+    /// The layers from the bottom to the top one,
+    /// shared with the directories opened from the overlay.
+    layers: Arc<Vec<Box<dyn WasiDir>>>,
+    /// The path of the directory from the root of the layers,
+    /// empty for the root.
+    prefix: String,
+}
+
+impl OverlayDir {
+    /// Stacks the [layers] from the bottom to the top one,
+    /// [layers] must not be empty.
+    pub fn new(layers: Vec<Box<dyn WasiDir>>) -> Self {
+        assert!(
+            !layers.is_empty(),
+            "An overlay requires at least one layer."
+        );
+        OverlayDir {
+            layers: Arc::new(layers),
+            prefix: String::new(),
+        }
+    }
+
+    fn top(&self) -> &dyn WasiDir {
+        self.layers.last().unwrap().as_ref()
+    }
+
+    /// The path of [path] from the root of the layers.
+    fn full_path(&self, path: &str) -> String {
+        if self.prefix.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}/{path}", self.prefix)
+        }
+    }
+
+    /// Returns the index of the highest layer containing [full_path]
+    /// and its [Filestat].
+    async fn find(
+        &self,
+        full_path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Option<(usize, Filestat)>, Error> {
+        for (index, layer) in self.layers.iter().enumerate().rev() {
+            match layer.get_path_filestat(full_path, follow_symlinks).await {
+                Ok(stat) => return Ok(Some((index, stat))),
+                Err(err) if is_noent(&err) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Fails with `EROFS` if [full_path] exists in a lower layer.
+    async fn check_not_lower(&self, full_path: &str) -> Result<(), Error> {
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if layer.get_path_filestat(full_path, false).await.is_ok() {
+                return Err(Errno::Rofs.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails with `EEXIST` if [full_path] exists in a layer.
+    async fn check_missing(&self, full_path: &str) -> Result<(), Error> {
+        match self.find(full_path, false).await? {
+            Some(_) => Err(Errno::Exist.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Copies the directories containing [full_path] that only exist
+    /// in a lower layer up to the top layer.
+    async fn copy_up_dirs(&self, full_path: &str) -> Result<(), Error> {
+        let mut end = 0;
+        while let Some(offset) = full_path[end..].find('/') {
+            end += offset;
+            if end > 0 {
+                self.copy_up_one(&full_path[..end]).await?;
+            }
+            end += 1;
+        }
+        Ok(())
+    }
+
+    /// Copies [full_path] up to the top layer if it only exists in a lower
+    /// layer, with the directories containing it, so that it can be modified.
+    async fn copy_up(&self, full_path: &str) -> Result<(), Error> {
+        self.copy_up_dirs(full_path).await?;
+        self.copy_up_one(full_path).await
+    }
+
+    async fn copy_up_one(&self, full_path: &str) -> Result<(), Error> {
+        let top = self.top();
+        let (index, stat) = match self.find(full_path, false).await? {
+            Some((index, stat)) if index < self.layers.len() - 1 => (index, stat),
+            _ => return Ok(()),
+        };
+        let lower = self.layers[index].as_ref();
+        match stat.filetype {
+            FileType::Directory => top.create_dir(full_path).await,
+            FileType::RegularFile => copy_file(lower, top, full_path).await,
+            FileType::SymbolicLink => {
+                let target = lower.read_link(full_path).await?;
+                let target = target.to_str().ok_or(Error::from(Errno::Ilseq))?;
+                top.symlink(target, full_path).await
+            }
+            _ => Err(Errno::Rofs.into()),
+        }
+    }
+
+    /// Returns the directory to write [path] of [dir] in and the path
+    /// in it, the top layer if [dir] is an [OverlayDir].
+    async fn top_of<'a>(
+        dir: &'a dyn WasiDir,
+        path: &str,
+    ) -> Result<(&'a dyn WasiDir, String), Error> {
+        match dir.as_any().downcast_ref::<OverlayDir>() {
+            Some(dir) => {
+                let full_path = dir.full_path(path);
+                dir.check_not_lower(&full_path).await?;
+                dir.copy_up_dirs(&full_path).await?;
+                Ok((dir.top(), full_path))
+            }
+            None => Ok((dir, path.to_string())),
+        }
+    }
+}
+
+/// Copies the regular file [path] of [from] to a new file in [to].
+async fn copy_file(from: &dyn WasiDir, to: &dyn WasiDir, path: &str) -> Result<(), Error> {
+    let fdflags = FdFlags::empty();
+    let source = from
+        .open_file(false, path, OFlags::empty(), true, false, fdflags)
+        .await?;
+    let oflags = OFlags::CREATE | OFlags::EXCLUSIVE;
+    let dest = to
+        .open_file(false, path, oflags, false, true, fdflags)
+        .await?;
+    let (OpenResult::File(source), OpenResult::File(dest)) = (source, dest) else {
+        return Err(Errno::Isdir.into());
+    };
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let read = source
+            .read_vectored(&mut [IoSliceMut::new(&mut buf)])
+            .await? as usize;
+        if read == 0 {
+            return Ok(());
+        }
+        let mut written = 0;
+        while written < read {
+            match dest
+                .write_vectored(&[IoSlice::new(&buf[written..read])])
+                .await?
+            {
+                0 => return Err(Errno::Io.into()),
+                n => written += n as usize,
+            }
+        }
+    }
+}
+
+fn errno(err: &Error) -> Option<Errno> {
+    err.downcast_ref().copied()
+}
+
+fn is_noent(err: &Error) -> bool {
+    errno(err) == Some(Errno::Noent)
+}
+
+#[wiggle::async_trait]
+impl WasiDir for OverlayDir {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn open_file(
+        &self,
+        symlink_follow: bool,
+        path: &str,
+        oflags: OFlags,
+        read: bool,
+        write: bool,
+        fdflags: FdFlags,
+    ) -> Result<OpenResult, Error> {
+        let full_path = self.full_path(path);
+        if write || oflags.intersects(OFlags::CREATE | OFlags::TRUNCATE) {
+            if oflags.contains(OFlags::CREATE | OFlags::EXCLUSIVE) {
+                self.check_missing(&full_path).await?;
+            }
+            self.copy_up(&full_path).await?;
+            return self
+                .top()
+                .open_file(symlink_follow, &full_path, oflags, read, write, fdflags)
+                .await;
+        }
+        for layer in self.layers.iter().rev() {
+            let result = layer
+                .open_file(symlink_follow, &full_path, oflags, read, write, fdflags)
+                .await;
+            match result {
+                // The directory is merged from all the layers.
+                Ok(OpenResult::Dir(_)) => {
+                    return Ok(OpenResult::Dir(Box::new(OverlayDir {
+                        layers: self.layers.clone(),
+                        prefix: full_path,
+                    })))
+                }
+                Err(err) if is_noent(&err) => continue,
+                result => return result,
+            }
+        }
+        Err(Errno::Noent.into())
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), Error> {
+        let full_path = self.full_path(path);
+        self.check_missing(&full_path).await?;
+        self.copy_up_dirs(&full_path).await?;
+        self.top().create_dir(&full_path).await
+    }
+
+    async fn readdir(
+        &self,
+        cursor: ReaddirCursor,
+    ) -> Result<Box<dyn Iterator<Item = Result<ReaddirEntity, Error>> + Send>, Error> {
+        let mut names = HashSet::new();
+        let mut entities = vec![];
+        for layer in self.layers.iter().rev() {
+            let entries = if self.prefix.is_empty() {
+                layer.readdir(ReaddirCursor::from(0)).await?
+            } else {
+                let dir = layer
+                    .open_file(
+                        true,
+                        &self.prefix,
+                        OFlags::DIRECTORY,
+                        true,
+                        false,
+                        FdFlags::empty(),
+                    )
+                    .await;
+                match dir {
+                    Ok(OpenResult::Dir(dir)) => dir.readdir(ReaddirCursor::from(0)).await?,
+                    // The directory is missing or hidden by a file in this layer.
+                    Ok(OpenResult::File(_)) => continue,
+                    Err(err) if matches!(errno(&err), Some(Errno::Noent | Errno::Notdir)) => {
+                        continue
+                    }
+                    Err(err) => return Err(err),
+                }
+            };
+            for entity in entries {
+                let entity = entity?;
+                if names.insert(entity.name.clone()) {
+                    entities.push(entity);
+                }
+            }
+        }
+        let start = u64::from(cursor) as usize;
+        let entities = entities
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .map(|(index, mut entity)| {
+                entity.next = ReaddirCursor::from(index as u64 + 1);
+                Ok(entity)
+            })
+            .collect::<Vec<_>>();
+        Ok(Box::new(entities.into_iter()))
+    }
+
+    async fn symlink(&self, old_path: &str, new_path: &str) -> Result<(), Error> {
+        let full_path = self.full_path(new_path);
+        self.check_missing(&full_path).await?;
+        self.copy_up_dirs(&full_path).await?;
+        self.top().symlink(old_path, &full_path).await
+    }
+
+    async fn remove_dir(&self, path: &str) -> Result<(), Error> {
+        let full_path = self.full_path(path);
+        self.check_not_lower(&full_path).await?;
+        self.top().remove_dir(&full_path).await
+    }
+
+    async fn unlink_file(&self, path: &str) -> Result<(), Error> {
+        let full_path = self.full_path(path);
+        self.check_not_lower(&full_path).await?;
+        self.top().unlink_file(&full_path).await
+    }
+
+    async fn read_link(&self, path: &str) -> Result<PathBuf, Error> {
+        let full_path = self.full_path(path);
+        for layer in self.layers.iter().rev() {
+            match layer.read_link(&full_path).await {
+                Err(err) if is_noent(&err) => continue,
+                result => return result,
+            }
+        }
+        Err(Errno::Noent.into())
+    }
+
+    async fn get_filestat(&self) -> Result<Filestat, Error> {
+        if self.prefix.is_empty() {
+            return self.top().get_filestat().await;
+        }
+        match self.find(&self.prefix, true).await? {
+            Some((_, stat)) => Ok(stat),
+            None => Err(Errno::Noent.into()),
+        }
+    }
+
+    async fn get_path_filestat(
+        &self,
+        path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Filestat, Error> {
+        match self.find(&self.full_path(path), follow_symlinks).await? {
+            Some((_, stat)) => Ok(stat),
+            None => Err(Errno::Noent.into()),
+        }
+    }
+
+    async fn rename(
+        &self,
+        path: &str,
+        dest_dir: &dyn WasiDir,
+        dest_path: &str,
+    ) -> Result<(), Error> {
+        let full_path = self.full_path(path);
+        self.check_not_lower(&full_path).await?;
+        let (dest_dir, dest_path) = Self::top_of(dest_dir, dest_path).await?;
+        self.top().rename(&full_path, dest_dir, &dest_path).await
+    }
+
+    async fn hard_link(
+        &self,
+        path: &str,
+        target_dir: &dyn WasiDir,
+        target_path: &str,
+    ) -> Result<(), Error> {
+        let full_path = self.full_path(path);
+        self.copy_up(&full_path).await?;
+        let (target_dir, target_path) = Self::top_of(target_dir, target_path).await?;
+        self.top()
+            .hard_link(&full_path, target_dir, &target_path)
+            .await
+    }
+
+    async fn set_times(
+        &self,
+        path: &str,
+        atime: Option<SystemTimeSpec>,
+        mtime: Option<SystemTimeSpec>,
+        follow_symlinks: bool,
+    ) -> Result<(), Error> {
+        let full_path = self.full_path(path);
+        self.copy_up(&full_path).await?;
+        self.top()
+            .set_times(&full_path, atime, mtime, follow_symlinks)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rights_dir(name: &str, rights: Rights) -> (PathBuf, RightsDir) {
+        let path = std::env::temp_dir().join(format!("wasm_run_rights_dir_{name}"));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        let dir = host_dir(&path);
+        (path, RightsDir::new(dir, rights.bits()))
+    }
+
+    fn host_dir(path: &std::path::Path) -> Box<dyn WasiDir> {
+        let dir =
+            cap_std::fs::Dir::open_ambient_dir(path, wasmtime_wasi::ambient_authority()).unwrap();
+        Box::new(wasmtime_wasi::dir::Dir::from_cap_std(dir))
+    }
+
+    fn errno(result: Result<impl Sized, Error>) -> Option<Errno> {
+        result.err().map(|e| e.downcast().unwrap())
+    }
+
+    #[test]
+    fn rights_dir_denies_operations_outside_rights() {
+        let (path, dir) = rights_dir(
+            "read_only",
+            Rights::FD_READ | Rights::PATH_OPEN | Rights::FD_READDIR,
+        );
+        std::fs::write(path.join("file.txt"), "content").unwrap();
+
+        wiggle::run_in_dummy_executor(async {
+            assert!(dir.readdir(ReaddirCursor::from(0)).await.is_ok());
+            let open = dir
+                .open_file(
+                    false,
+                    "file.txt",
+                    OFlags::empty(),
+                    true,
+                    false,
+                    FdFlags::empty(),
+                )
+                .await;
+            assert!(open.is_ok());
+
+            let open_write = dir
+                .open_file(
+                    false,
+                    "file.txt",
+                    OFlags::empty(),
+                    true,
+                    true,
+                    FdFlags::empty(),
+                )
+                .await;
+            assert_eq!(errno(open_write), Some(Errno::Notcapable));
+            let create = dir
+                .open_file(
+                    false,
+                    "new.txt",
+                    OFlags::CREATE,
+                    false,
+                    true,
+                    FdFlags::empty(),
+                )
+                .await;
+            assert_eq!(errno(create), Some(Errno::Notcapable));
+            assert_eq!(errno(dir.create_dir("sub").await), Some(Errno::Notcapable));
+            assert_eq!(
+                errno(dir.unlink_file("file.txt").await),
+                Some(Errno::Notcapable)
+            );
+        })
+        .unwrap();
+
+        assert!(path.join("file.txt").exists());
+        assert!(!path.join("sub").exists());
+        std::fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn rights_dir_allows_operations_within_rights() {
+        let (path, dir) = rights_dir("create", Rights::PATH_CREATE_DIRECTORY);
+
+        wiggle::run_in_dummy_executor(async {
+            assert!(dir.create_dir("sub").await.is_ok());
+        })
+        .unwrap();
+
+        assert!(path.join("sub").is_dir());
+        std::fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn overlay_dir_reads_through_and_writes_to_top() {
+        let path = std::env::temp_dir().join("wasm_run_overlay_dir");
+        let _ = std::fs::remove_dir_all(&path);
+        let (base, top) = (path.join("base"), path.join("top"));
+        std::fs::create_dir_all(base.join("sub")).unwrap();
+        std::fs::create_dir_all(&top).unwrap();
+        std::fs::write(base.join("base.txt"), "base").unwrap();
+        std::fs::write(base.join("shared.txt"), "base").unwrap();
+        std::fs::write(top.join("shared.txt"), "top layer").unwrap();
+        let dir = OverlayDir::new(vec![host_dir(&base), host_dir(&top)]);
+
+        wiggle::run_in_dummy_executor(async {
+            let size = |path: &'static str| async {
+                dir.get_path_filestat(path, false).await.map(|s| s.size)
+            };
+            // Reads fall through, the top layer hides the lower ones.
+            assert_eq!(size("base.txt").await.unwrap(), 4);
+            assert_eq!(size("shared.txt").await.unwrap(), 9);
+            assert_eq!(errno(size("missing.txt").await), Some(Errno::Noent));
+            let open = |path, oflags, write| {
+                dir.open_file(false, path, oflags, true, write, FdFlags::empty())
+            };
+            assert!(matches!(
+                open("base.txt", OFlags::empty(), false).await,
+                Ok(OpenResult::File(_))
+            ));
+
+            // Writes go to the top layer.
+            assert!(open("new.txt", OFlags::CREATE, true).await.is_ok());
+            assert!(open("shared.txt", OFlags::empty(), true).await.is_ok());
+            assert!(dir.create_dir("created").await.is_ok());
+            // The files of the lower layers are copied up when written.
+            assert!(open("base.txt", OFlags::empty(), true).await.is_ok());
+            assert_eq!(errno(dir.unlink_file("base.txt").await), Some(Errno::Rofs));
+            // `sub` only exists in the base layer.
+            let Ok(OpenResult::Dir(sub)) = open("sub", OFlags::DIRECTORY, false).await else {
+                panic!("`sub` is not a directory");
+            };
+            assert!(sub.create_dir("nested").await.is_ok());
+
+            let names = dir
+                .readdir(ReaddirCursor::from(0))
+                .await
+                .unwrap()
+                .map(|entity| entity.unwrap().name)
+                .filter(|name| name != "." && name != "..")
+                .collect::<HashSet<_>>();
+            let expected = ["base.txt", "shared.txt", "new.txt", "created", "sub"];
+            assert_eq!(names, expected.iter().map(|n| n.to_string()).collect());
+        })
+        .unwrap();
+
+        assert!(top.join("new.txt").exists() && top.join("created").is_dir());
+        assert!(top.join("sub/nested").is_dir());
+        assert!(!base.join("new.txt").exists() && !base.join("sub/nested").exists());
+        for layer in [&base, &top] {
+            assert_eq!(
+                std::fs::read_to_string(layer.join("base.txt")).unwrap(),
+                "base"
+            );
+        }
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
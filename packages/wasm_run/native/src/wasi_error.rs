@@ -0,0 +1,123 @@
+//! Structured errors for the WASI operations that fail in the host.
+
+use std::fmt::Display;
+
+/// The name and description of the common `wasi_snapshot_preview1` errno codes.
+const ERRNOS: &[(u16, &str, &str)] = &[
+    (1, "E2BIG", "Argument list too long"),
+    (2, "EACCES", "Permission denied"),
+    (6, "EAGAIN", "Resource unavailable, try again"),
+    (8, "EBADF", "Bad file descriptor"),
+    (10, "EBUSY", "Device or resource busy"),
+    (20, "EEXIST", "File exists"),
+    (21, "EFAULT", "Bad address"),
+    (22, "EFBIG", "File too large"),
+    (27, "EINTR", "Interrupted function"),
+    (28, "EINVAL", "Invalid argument"),
+    (29, "EIO", "I/O error"),
+    (31, "EISDIR", "Is a directory"),
+    (32, "ELOOP", "Too many levels of symbolic links"),
+    (33, "EMFILE", "File descriptor value too large"),
+    (37, "ENAMETOOLONG", "Filename too long"),
+    (44, "ENOENT", "No such file or directory"),
+    (48, "ENOMEM", "Not enough space"),
+    (51, "ENOSPC", "No space left on device"),
+    (52, "ENOSYS", "Function not supported"),
+    (54, "ENOTDIR", "Not a directory"),
+    (55, "ENOTEMPTY", "Directory not empty"),
+    (58, "ENOTSUP", "Not supported"),
+    (63, "EPERM", "Operation not permitted"),
+    (64, "EPIPE", "Broken pipe"),
+    (69, "EROFS", "Read-only file system"),
+    (70, "ESPIPE", "Invalid seek"),
+    (73, "ETIMEDOUT", "Connection timed out"),
+    (75, "EXDEV", "Cross-device link"),
+    (76, "ENOTCAPABLE", "Extension: Capabilities insufficient"),
+];
+
+/// A failed WASI operation with its `wasi_snapshot_preview1` errno code.
+///
+/// Formatted as `WASI error ENOENT (44): <message>`, so that Dart code
+/// can parse it from the error message with `WasiError.fromException`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasiError {
+    /// The errno code, for example, `44` for `ENOENT`.
+    pub errno: u16,
+    /// The name of the errno code, for example, `ENOENT`.
+    /// `EUNKNOWN` for the codes that are not mapped.
+    pub name: &'static str,
+    /// The description of the failed operation and the errno code.
+    pub message: String,
+}
+
+impl WasiError {
+    /// Creates an error for [errno] with [context] as the prefix of the message.
+    pub fn new(errno: u16, context: impl Display) -> Self {
+        let (name, description) = ERRNOS
+            .iter()
+            .find(|(code, _, _)| *code == errno)
+            .map(|(_, name, description)| (*name, *description))
+            .unwrap_or(("EUNKNOWN", "Unknown error"));
+        WasiError {
+            errno,
+            name,
+            message: format!("{context}: {description}"),
+        }
+    }
+
+    /// Maps an IO error of the host to the errno code returned by WASI.
+    /// Errors without a WASI equivalent are mapped to [unknown_errno],
+    /// or to `EIO` when it is null.
+    #[cfg(any(feature = "wasmtime", feature = "wasi"))]
+    pub fn from_io(err: std::io::Error, unknown_errno: Option<u16>, context: impl Display) -> Self {
+        // wasi-common 14 converts IO errors to a trappable `Error` with the
+        // errno code, wasi-common 2 converts them to the errno directly.
+        #[cfg(feature = "wasmtime")]
+        let errno = wasi_common::Error::from(err).downcast().ok();
+        #[cfg(not(feature = "wasmtime"))]
+        let errno = wasi_common::snapshots::preview_1::types::Errno::try_from(err).ok();
+        let errno = errno
+            .map(|errno| errno as u16)
+            .unwrap_or(unknown_errno.unwrap_or(29));
+        WasiError::new(errno, context)
+    }
+}
+
+impl Display for WasiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WASI error {} ({}): {}",
+            self.name, self.errno, self.message
+        )
+    }
+}
+
+impl std::error::Error for WasiError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errno_names() {
+        let err = WasiError::new(2, "Could not open \"dir\"");
+        assert_eq!(err.name, "EACCES");
+        assert_eq!(
+            err.to_string(),
+            "WASI error EACCES (2): Could not open \"dir\": Permission denied"
+        );
+        assert_eq!(WasiError::new(1000, "op").name, "EUNKNOWN");
+
+        let io = std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert_eq!(WasiError::from_io(io, None, "op").name, "ENOENT");
+    }
+
+    #[test]
+    fn unknown_errno() {
+        let io = || std::io::Error::other("custom");
+        assert_eq!(WasiError::from_io(io(), None, "op").name, "EIO");
+        let err = WasiError::from_io(io(), Some(58), "op");
+        assert_eq!((err.errno, err.name), (58, "ENOTSUP"));
+    }
+}
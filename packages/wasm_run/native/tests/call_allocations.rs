@@ -0,0 +1,102 @@
+//! Benchmark of the allocations of the calls to exported functions.
+//! It is a separate test binary since it replaces the global allocator
+//! to count the allocations.
+#![cfg(feature = "wasmtime")]
+
+use anyhow::Result;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use wasm_run_dart::api::*;
+use wasm_run_dart::types::{ExternalValue, WasmVal};
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Counts the allocations of each thread, see [call_into_benchmark].
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the result of [f] and the number of allocations it made.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, u64) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    (value, ALLOCATIONS.with(Cell::get) - before)
+}
+
+const ADD_WAT: &str = r#"(module
+    (func (export "add") (param i32 i32) (result i32)
+        (i32.add (local.get 0) (local.get 1))
+    )
+)"#;
+
+fn instantiate(wat: &str) -> Result<(WasmRunModuleId, WasmRunInstanceId)> {
+    let module = compile_wasm(parse_wat_format(wat.to_string())?, Default::default())?;
+    let module_id = module_builder(module, None, None)?.0;
+    let instance = module_id.instantiate()?;
+    Ok((module_id, instance))
+}
+
+/// Compares the allocations of [WasmRunModuleId::call_into]
+/// and [WasmRunModuleId::call_function_handle_sync].
+/// Run with `cargo test --release --test call_allocations -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn call_into_benchmark() -> Result<()> {
+    const CALLS: i32 = 1_000_000;
+    let (module_id, instance) = instantiate(ADD_WAT)?;
+    let func = instance
+        .exports()
+        .0
+        .into_iter()
+        .find_map(|e| match e.value {
+            ExternalValue::Func(func) if e.desc.name == "add" => Some(func),
+            _ => None,
+        })
+        .unwrap();
+
+    let mut results = Vec::with_capacity(1);
+    let (into, into_allocations) = count_allocations(|| {
+        let start = std::time::Instant::now();
+        for i in 0..CALLS {
+            module_id.call_into("add", &[WasmVal::i32(i), WasmVal::i32(1)], &mut results)?;
+            assert!(matches!(results.as_slice(), [WasmVal::i32(v)] if *v == i + 1));
+        }
+        Ok::<_, anyhow::Error>(start.elapsed())
+    });
+    let into = into?;
+
+    let (handle, handle_allocations) = count_allocations(|| {
+        let start = std::time::Instant::now();
+        for i in 0..CALLS {
+            let output = module_id
+                .call_function_handle_sync(func.clone(), vec![WasmVal::i32(i), WasmVal::i32(1)])?
+                .0;
+            assert!(matches!(output.as_slice(), [WasmVal::i32(v)] if *v == i + 1));
+        }
+        Ok::<_, anyhow::Error>(start.elapsed())
+    });
+    let handle = handle?;
+
+    println!(
+        "{CALLS} calls: call_into {into:?} ({into_allocations} allocations), \
+        call_function_handle_sync {handle:?} ({handle_allocations} allocations), \
+        {} allocations saved",
+        handle_allocations.saturating_sub(into_allocations),
+    );
+    assert!(into_allocations < handle_allocations);
+    module_id.dispose()
+}
@@ -0,0 +1,197 @@
+//! The Rust API of the crate, for embedders that use it as a dependency
+//! instead of through the Dart bindings.
+
+use anyhow::Result;
+use std::sync::{Arc, Mutex};
+use wasm_run_dart::api::*;
+use wasm_run_dart::compile_error::{CompileError, CompileErrorKind};
+use wasm_run_dart::config::{ModuleConfig, Preset, WasiConfigNative, WasmFeatures};
+use wasm_run_dart::observer::{set_observer, LifecycleEvent, Observer};
+use wasm_run_dart::validate::StreamingValidator;
+use wasm_run_dart::wasi_check::WasiRequirement;
+
+const ADD_WAT: &str = r#"(module
+    (func (export "add") (param i32 i32) (result i32)
+        (i32.add (local.get 0) (local.get 1))
+    )
+)"#;
+
+const ARGS_WAT: &str = r#"(module
+    (import "wasi_snapshot_preview1" "args_sizes_get"
+        (func $args_sizes_get (param i32 i32) (result i32)))
+    (memory (export "memory") 1)
+    (func (export "args_count") (result i32)
+        (drop (call $args_sizes_get (i32.const 0) (i32.const 4)))
+        (i32.load (i32.const 0))
+    )
+)"#;
+
+fn compile(wat: &str) -> Result<CompiledModule> {
+    compile_wasm(parse_wat_format(wat.to_string())?, Default::default())
+}
+
+#[test]
+fn streaming_validation() -> Result<()> {
+    let bytes = parse_wat_format(ADD_WAT.to_string())?;
+    let mut validator = StreamingValidator::new(&ModuleConfig::default());
+    for chunk in bytes.chunks(3) {
+        validator.feed(chunk)?;
+    }
+    validator.finish()?;
+
+    let mut truncated = StreamingValidator::new(&ModuleConfig::default());
+    truncated.feed(&bytes[..bytes.len() - 1])?;
+    assert!(truncated.finish().is_err());
+    Ok(())
+}
+
+#[test]
+fn compile_errors() -> Result<()> {
+    let bytes = b"\0asm\x01\0\0\0\x01";
+    let error = compile_wasm(bytes.to_vec(), Default::default())
+        .err()
+        .unwrap();
+    let error = error.downcast::<CompileError>()?;
+    assert_eq!(error.kind, CompileErrorKind::Invalid);
+    assert!(error.offset.is_some());
+
+    let error = CompileError::new(bytes, Default::default(), anyhow::anyhow!("invalid"));
+    assert_eq!(error.kind, CompileErrorKind::Invalid);
+    Ok(())
+}
+
+#[test]
+fn lifecycle_observer() -> Result<()> {
+    struct Events(Mutex<Vec<LifecycleEvent>>);
+    impl Observer for Events {
+        fn on_event(&self, event: LifecycleEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    let events = Arc::new(Events(Mutex::new(vec![])));
+    set_observer(Some(events.clone()));
+    let bytes = parse_wat_format(ADD_WAT.to_string())?;
+    let module_bytes = bytes.len();
+    let result = compile_wasm(bytes, Default::default());
+    set_observer(None);
+    result?;
+
+    let events = events.0.lock().unwrap();
+    assert!(events.contains(&LifecycleEvent::CompileStart { module_bytes }));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, LifecycleEvent::CompileEnd { .. })));
+    Ok(())
+}
+
+#[test]
+fn feature_presets() {
+    let minimal = WasmFeatures::minimal();
+    assert!(minimal.mutable_global && !minimal.bulk_memory);
+    let modern = WasmFeatures::modern();
+    assert_eq!(modern.bulk_memory, WasmFeatures::supported().bulk_memory);
+    let all = WasmFeatures::all_supported();
+    assert!(!all.nan_canonicalization);
+
+    assert_eq!(minimal.describe_diff(&minimal), "");
+    let required = WasmFeatures {
+        bulk_memory: true,
+        ..WasmFeatures::minimal()
+    };
+    assert_eq!(
+        minimal.describe_diff(&required),
+        "+bulk_memory (required, not available)"
+    );
+
+    let config = ModuleConfig::from_preset(Preset::Minimal);
+    assert_eq!(config.bulk_memory, Some(false));
+    assert!(!config.wasm_features().bulk_memory);
+}
+
+#[test]
+#[allow(deprecated)]
+fn wasi_requirements() -> Result<()> {
+    let module = compile(ARGS_WAT)?;
+    let config = WasiConfigNative::default();
+    assert_eq!(
+        config.check_against(&module),
+        vec![WasiRequirement::ArgsWithoutValues {
+            imports: vec!["args_sizes_get".to_string()],
+        }]
+    );
+
+    let mut config = WasiConfigNative::default();
+    let dir = config.arg_file("/input/data.bin".to_string(), b"data")?;
+    assert_eq!(config.args, vec!["/input/data.bin".to_string()]);
+    assert_eq!(config.preopened_dirs[0].wasm_guest_path, "/input");
+    assert_eq!(std::fs::read(dir.path().join("data.bin"))?, b"data");
+    assert!(config.check_against(&module).is_empty());
+    Ok(())
+}
+
+#[test]
+fn memory_views() -> Result<()> {
+    use wasm_run_dart::types::ExternalValue;
+
+    let wat = r#"(module
+        (memory (export "memory") 1)
+        (data (i32.const 8) "hello world")
+    )"#;
+    let module_id = module_builder(compile(wat)?, None, None)?.0;
+    let export = module_id.instantiate()?.exports().0.remove(0);
+    let ExternalValue::Memory(memory) = export.value else {
+        panic!("{} is not a memory", export.desc.name);
+    };
+
+    let word = module_id.with_memory(&memory, 8, 11, |data| {
+        data.split(|b| *b == b' ').next().map(<[u8]>::to_vec)
+    })?;
+    assert_eq!(word.as_deref(), Some(&b"hello"[..]));
+    assert!(module_id.with_memory(&memory, 65530, 16, |_| ()).is_err());
+
+    let mut buffer = vec![];
+    module_id.read_memory_into_vec(&memory, 14, 5, &mut buffer)?;
+    assert_eq!(buffer, b"world");
+    module_id.dispose()?;
+    Ok(())
+}
+
+#[cfg(feature = "wasmtime")]
+#[test]
+fn typed_calls() -> Result<()> {
+    use wasm_run_dart::types::WasmVal;
+
+    let module_id = module_builder(compile(ADD_WAT)?, None, None)?.0;
+    module_id.instantiate()?;
+    let add = module_id.get_typed::<(i32, i32), i32>("add")?;
+    assert_eq!(add.call((3, 4))?, 7);
+
+    let mut results = vec![];
+    module_id.call_into("add", &[WasmVal::i32(1), WasmVal::i32(2)], &mut results)?;
+    assert!(matches!(results[..], [WasmVal::i32(3)]));
+    module_id.dispose()?;
+    Ok(())
+}
+
+// The hook receives the `WasiCtx` of the wasi-common version of wasmtime.
+#[cfg(all(feature = "wasi", feature = "wasmtime"))]
+#[test]
+fn wasi_configure_hook() -> Result<()> {
+    use wasm_run_dart::types::WasmVal;
+    use wasm_run_dart::wasi_configure::module_builder_with_wasi;
+
+    let module_id = module_builder_with_wasi(
+        compile(ARGS_WAT)?,
+        WasiConfigNative::default(),
+        Box::new(|wasi: &mut wasi_common::WasiCtx| {
+            wasi.push_arg("first")?;
+            Ok(())
+        }),
+    )?;
+    module_id.instantiate()?;
+    let result = module_id.call_export("args_count".to_string(), vec![])?.0;
+    assert!(matches!(result[..], [WasmVal::i32(1)]));
+    module_id.dispose()?;
+    Ok(())
+}
@@ -3,6 +3,19 @@
 #include <stdlib.h>
 typedef struct _Dart_Handle* Dart_Handle;
 
+/**
+ * The maximum number of threads compiling modules for
+ * `compile_wasm_cancelable`, including the threads detached by cancelled
+ * compilations that are still running.
+ */
+#define MAX_COMPILATION_THREADS 4
+
+/**
+ * The maximum length of a line in bytes.
+ * Longer lines are sent in chunks of this length.
+ */
+#define MAX_LINE_BYTES (64 * 1024)
+
 typedef struct DartCObject DartCObject;
 
 typedef int64_t DartPort;
@@ -42,6 +55,8 @@ typedef struct wire_list_env_variable {
 typedef struct wire_PreopenedDir {
   struct wire_uint_8_list *wasm_guest_path;
   struct wire_uint_8_list *host_path;
+  uint64_t *rights;
+  bool overlay;
 } wire_PreopenedDir;
 
 typedef struct wire_list_preopened_dir {
@@ -52,6 +67,7 @@ typedef struct wire_list_preopened_dir {
 typedef struct wire_WasiConfigNative {
   bool capture_stdout;
   bool capture_stderr;
+  bool line_buffered_output;
   bool inherit_stdin;
   bool inherit_env;
   bool inherit_args;
@@ -59,6 +75,10 @@ typedef struct wire_WasiConfigNative {
   struct wire_list_env_variable *env;
   struct wire_StringList *preopened_files;
   struct wire_list_preopened_dir *preopened_dirs;
+  uintptr_t *capture_capacity;
+  int32_t capture_overflow;
+  bool deterministic;
+  uint16_t *unknown_errno;
 } wire_WasiConfigNative;
 
 typedef struct wire_WasiStackLimits {
@@ -76,24 +96,45 @@ typedef struct wire_ModuleConfigWasmi {
   bool *tail_call;
   bool *extended_const;
   bool *floats;
+  int32_t *fuel_consumption_mode;
 } wire_ModuleConfigWasmi;
 
+typedef struct wire_ProposalToggle {
+  struct wire_uint_8_list *name;
+  bool enabled;
+} wire_ProposalToggle;
+
+typedef struct wire_list_proposal_toggle {
+  struct wire_ProposalToggle *ptr;
+  int32_t len;
+} wire_list_proposal_toggle;
+
 typedef struct wire_ModuleConfigWasmtime {
   bool *debug_info;
   bool *wasm_backtrace;
   bool *native_unwind_info;
+  bool *epoch_interruption;
   uintptr_t *max_wasm_stack;
   bool *wasm_threads;
   bool *wasm_simd;
   bool *wasm_relaxed_simd;
   bool *relaxed_simd_deterministic;
+  bool *cranelift_nan_canonicalization;
   bool *wasm_multi_memory;
   bool *wasm_memory64;
+  bool *wasm_tail_call;
+  bool *wasm_function_references;
+  bool *wasm_memory_control;
+  bool *wasm_gc;
   uint64_t *static_memory_maximum_size;
   bool *static_memory_forced;
   uint64_t *static_memory_guard_size;
   bool *parallel_compilation;
+  uintptr_t *compilation_thread_stack_size;
   bool *generate_address_map;
+  bool *disable_guard_pages;
+  bool *macos_use_mach_ports;
+  struct wire_list_proposal_toggle *extra_proposals;
 } wire_ModuleConfigWasmtime;
 
 typedef struct wire_ModuleConfig {
@@ -103,8 +144,24 @@ typedef struct wire_ModuleConfig {
   bool *consume_fuel;
   struct wire_ModuleConfigWasmi *wasmi;
   struct wire_ModuleConfigWasmtime *wasmtime;
+  uintptr_t *max_module_bytes;
+  uint64_t *max_memory_pages;
+  uint32_t *max_memories;
+  uint32_t *max_tables;
+  uint32_t *max_globals;
+  uint32_t *max_functions;
+  bool *trap_on_integer_overflow;
+  bool *deterministic;
 } wire_ModuleConfig;
 
+typedef struct wire_ArcAtomicBool {
+  const void *ptr;
+} wire_ArcAtomicBool;
+
+typedef struct wire_CompileHandle {
+  struct wire_ArcAtomicBool field0;
+} wire_CompileHandle;
+
 typedef struct wire_WasmRunInstanceId {
   uint32_t field0;
 } wire_WasmRunInstanceId;
@@ -113,9 +170,14 @@ typedef struct wire_CallStack {
   const void *ptr;
 } wire_CallStack;
 
+typedef struct wire_StdioCapture {
+  const void *ptr;
+} wire_StdioCapture;
+
 typedef struct wire_WasmRunModuleId {
   uint32_t field0;
   struct wire_CallStack field1;
+  struct wire_StdioCapture field2;
 } wire_WasmRunModuleId;
 
 typedef struct wire_WFunc {
@@ -250,6 +312,30 @@ typedef struct wire_TableArgs {
   uint32_t *maximum;
 } wire_TableArgs;
 
+typedef struct wire_WEngine {
+  const void *ptr;
+} wire_WEngine;
+
+typedef struct wire_WasmEngine {
+  struct wire_WEngine field0;
+} wire_WasmEngine;
+
+typedef struct wire_ArcStdSyncMutexLinkerTemplate {
+  const void *ptr;
+} wire_ArcStdSyncMutexLinkerTemplate;
+
+typedef struct wire_WasmLinker {
+  struct wire_ArcStdSyncMutexLinkerTemplate field0;
+} wire_WasmLinker;
+
+typedef struct wire_ArcStdSyncMutexModuleCacheImpl {
+  const void *ptr;
+} wire_ArcStdSyncMutexModuleCacheImpl;
+
+typedef struct wire_ModuleCache {
+  struct wire_ArcStdSyncMutexModuleCacheImpl field0;
+} wire_ModuleCache;
+
 typedef struct wire_Atomics {
   uintptr_t field0;
 } wire_Atomics;
@@ -270,6 +356,14 @@ WireSyncReturn wire_module_builder(struct wire_CompiledModule *module,
 
 void wire_parse_wat_format(int64_t port_, struct wire_uint_8_list *wat);
 
+WireSyncReturn wire_clamp_memory(struct wire_uint_8_list *module_wasm, uint64_t max_pages);
+
+WireSyncReturn wire_module_has_start(struct wire_uint_8_list *module_wasm);
+
+WireSyncReturn wire_module_content_hash(struct wire_uint_8_list *module_wasm);
+
+WireSyncReturn wire_create_engine(struct wire_ModuleConfig *config);
+
 void wire_compile_wasm(int64_t port_,
                        struct wire_uint_8_list *module_wasm,
                        struct wire_ModuleConfig *config);
@@ -277,6 +371,13 @@ void wire_compile_wasm(int64_t port_,
 WireSyncReturn wire_compile_wasm_sync(struct wire_uint_8_list *module_wasm,
                                       struct wire_ModuleConfig *config);
 
+WireSyncReturn wire_create_compile_handle(void);
+
+void wire_compile_wasm_cancelable(int64_t port_,
+                                  struct wire_uint_8_list *module_wasm,
+                                  struct wire_ModuleConfig *config,
+                                  struct wire_CompileHandle *handle);
+
 WireSyncReturn wire_wasm_features_for_config(struct wire_ModuleConfig *config);
 
 WireSyncReturn wire_wasm_runtime_features(void);
@@ -290,10 +391,33 @@ void wire_instantiate__method__WasmRunModuleId(int64_t port_, struct wire_WasmRu
 WireSyncReturn wire_link_imports__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
                                                           struct wire_list_module_import *imports);
 
+WireSyncReturn wire_link_module__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                         struct wire_uint_8_list *namespace_,
+                                                         struct wire_CompiledModule *module);
+
 void wire_stdio_stream__method__WasmRunModuleId(int64_t port_,
                                                 struct wire_WasmRunModuleId *that,
                                                 int32_t kind);
 
+void wire_wasi_denied_stream__method__WasmRunModuleId(int64_t port_,
+                                                      struct wire_WasmRunModuleId *that);
+
+WireSyncReturn wire_read_stdio__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                        int32_t kind);
+
+void wire_next_stdio_chunk__method__WasmRunModuleId(int64_t port_,
+                                                    struct wire_WasmRunModuleId *that,
+                                                    int32_t kind,
+                                                    uint64_t timeout_millis);
+
+WireSyncReturn wire_stdio_dropped_bytes__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                                 int32_t kind);
+
+WireSyncReturn wire_close_stdio__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                         int32_t kind);
+
+WireSyncReturn wire_close__method__WasmRunModuleId(struct wire_WasmRunModuleId *that);
+
 void wire_dispose__method__WasmRunModuleId(int64_t port_, struct wire_WasmRunModuleId *that);
 
 WireSyncReturn wire_call_function_handle_sync__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
@@ -305,6 +429,37 @@ void wire_call_function_handle__method__WasmRunModuleId(int64_t port_,
                                                         struct wire_WFunc func,
                                                         struct wire_list_wasm_val *args);
 
+WireSyncReturn wire_call_by_index__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                           uint32_t index,
+                                                           struct wire_list_wasm_val *args);
+
+WireSyncReturn wire_call_wasi_start__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                             struct wire_WFunc func);
+
+WireSyncReturn wire_initialize__method__WasmRunModuleId(struct wire_WasmRunModuleId *that);
+
+WireSyncReturn wire_call_export__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                         struct wire_uint_8_list *name,
+                                                         struct wire_list_wasm_val *args);
+
+WireSyncReturn wire_guest_alloc__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                         uint32_t size,
+                                                         struct wire_uint_8_list *alloc_export);
+
+WireSyncReturn wire_guest_free__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                        uint32_t ptr,
+                                                        uint32_t size,
+                                                        struct wire_uint_8_list *free_export);
+
+WireSyncReturn wire_write_guest_string__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                                struct wire_uint_8_list *value,
+                                                                struct wire_uint_8_list *memory_name);
+
+WireSyncReturn wire_read_guest_string__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                               uint32_t ptr,
+                                                               uint32_t len,
+                                                               struct wire_uint_8_list *memory_name);
+
 void wire_call_function_handle_parallel__method__WasmRunModuleId(int64_t port_,
                                                                  struct wire_WasmRunModuleId *that,
                                                                  struct wire_uint_8_list *func_name,
@@ -400,6 +555,12 @@ WireSyncReturn wire_fill_table__method__WasmRunModuleId(struct wire_WasmRunModul
                                                         struct wire_WasmVal *value,
                                                         uint32_t len);
 
+WireSyncReturn wire_set_max_host_call_depth__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                                     uint32_t *max_depth);
+
+WireSyncReturn wire_set_epoch_deadline__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
+                                                                uint64_t *ticks_beyond_current);
+
 WireSyncReturn wire_add_fuel__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
                                                       uint64_t delta);
 
@@ -408,13 +569,74 @@ WireSyncReturn wire_fuel_consumed__method__WasmRunModuleId(struct wire_WasmRunMo
 WireSyncReturn wire_consume_fuel__method__WasmRunModuleId(struct wire_WasmRunModuleId *that,
                                                           uint64_t delta);
 
+WireSyncReturn wire_last_call_fuel_consumed__method__WasmRunModuleId(struct wire_WasmRunModuleId *that);
+
+WireSyncReturn wire_last_call_duration_micros__method__WasmRunModuleId(struct wire_WasmRunModuleId *that);
+
+WireSyncReturn wire_memory_footprint__method__WasmRunModuleId(struct wire_WasmRunModuleId *that);
+
+WireSyncReturn wire_memories__method__WasmRunModuleId(struct wire_WasmRunModuleId *that);
+
+WireSyncReturn wire_instantiate__method__CompiledModule(struct wire_CompiledModule *that,
+                                                        struct wire_WasiConfigNative *wasi_config);
+
 WireSyncReturn wire_create_shared_memory__method__CompiledModule(struct wire_CompiledModule *that,
                                                                  struct wire_MemoryTy *memory_type);
 
 WireSyncReturn wire_get_module_imports__method__CompiledModule(struct wire_CompiledModule *that);
 
+WireSyncReturn wire_wasi_module_kind__method__CompiledModule(struct wire_CompiledModule *that);
+
 WireSyncReturn wire_get_module_exports__method__CompiledModule(struct wire_CompiledModule *that);
 
+void wire_compile_wasm__method__WasmEngine(int64_t port_,
+                                           struct wire_WasmEngine *that,
+                                           struct wire_uint_8_list *module_wasm);
+
+WireSyncReturn wire_compile_wasm_sync__method__WasmEngine(struct wire_WasmEngine *that,
+                                                          struct wire_uint_8_list *module_wasm);
+
+WireSyncReturn wire_increment_epoch__method__WasmEngine(struct wire_WasmEngine *that);
+
+WireSyncReturn wire_create_linker__method__WasmEngine(struct wire_WasmEngine *that, bool wasi);
+
+WireSyncReturn wire_create_module_cache__method__WasmEngine(struct wire_WasmEngine *that,
+                                                            uint32_t *max_entries,
+                                                            uint64_t *max_bytes,
+                                                            struct wire_uint_8_list *directory);
+
+WireSyncReturn wire_define_host_function__method__WasmLinker(struct wire_WasmLinker *that,
+                                                             struct wire_uint_8_list *module,
+                                                             struct wire_uint_8_list *name,
+                                                             uintptr_t function_pointer,
+                                                             uint32_t function_id,
+                                                             struct wire_list_value_ty *param_types,
+                                                             struct wire_list_value_ty *result_types);
+
+WireSyncReturn wire_define_memory__method__WasmLinker(struct wire_WasmLinker *that,
+                                                      struct wire_uint_8_list *module,
+                                                      struct wire_uint_8_list *name,
+                                                      struct wire_MemoryTy *memory_type);
+
+WireSyncReturn wire_module_builder__method__WasmLinker(struct wire_WasmLinker *that,
+                                                       struct wire_CompiledModule *module,
+                                                       struct wire_WasiConfigNative *wasi_config);
+
+void wire_compile_wasm__method__ModuleCache(int64_t port_,
+                                            struct wire_ModuleCache *that,
+                                            struct wire_uint_8_list *module_wasm);
+
+WireSyncReturn wire_compile_wasm_sync__method__ModuleCache(struct wire_ModuleCache *that,
+                                                           struct wire_uint_8_list *module_wasm);
+
+WireSyncReturn wire_stats__method__ModuleCache(struct wire_ModuleCache *that);
+
+WireSyncReturn wire_clear__method__ModuleCache(struct wire_ModuleCache *that);
+
+WireSyncReturn wire_cancel__method__CompileHandle(struct wire_CompileHandle *that);
+
+WireSyncReturn wire_is_cancelled__method__CompileHandle(struct wire_CompileHandle *that);
+
 WireSyncReturn wire_ty__method__WasmRunSharedMemory(struct wire_WasmRunSharedMemory *that);
 
 WireSyncReturn wire_size__method__WasmRunSharedMemory(struct wire_WasmRunSharedMemory *that);
@@ -505,20 +727,30 @@ void wire_xor__method__Atomics(int64_t port_,
                                int64_t val,
                                int32_t order);
 
+struct wire_ArcAtomicBool new_ArcAtomicBool(void);
+
 struct wire_ArcRwLockSharedMemory new_ArcRwLockSharedMemory(void);
 
+struct wire_ArcStdSyncMutexLinkerTemplate new_ArcStdSyncMutexLinkerTemplate(void);
+
 struct wire_ArcStdSyncMutexModule new_ArcStdSyncMutexModule(void);
 
+struct wire_ArcStdSyncMutexModuleCacheImpl new_ArcStdSyncMutexModuleCacheImpl(void);
+
 struct wire_CallStack new_CallStack(void);
 
 struct wire_Global new_Global(void);
 
 struct wire_Memory new_Memory(void);
 
+struct wire_StdioCapture new_StdioCapture(void);
+
 struct wire_StringList *new_StringList_0(int32_t len);
 
 struct wire_Table new_Table(void);
 
+struct wire_WEngine new_WEngine(void);
+
 struct wire_WFunc new_WFunc(void);
 
 struct wire_WFunc *new_box_autoadd_WFunc_0(void);
@@ -527,10 +759,16 @@ struct wire_Atomics *new_box_autoadd_atomics_0(void);
 
 bool *new_box_autoadd_bool_0(bool value);
 
+struct wire_CompileHandle *new_box_autoadd_compile_handle_0(void);
+
 struct wire_CompiledModule *new_box_autoadd_compiled_module_0(void);
 
+int32_t *new_box_autoadd_fuel_consumption_mode_0(int32_t value);
+
 struct wire_MemoryTy *new_box_autoadd_memory_ty_0(void);
 
+struct wire_ModuleCache *new_box_autoadd_module_cache_0(void);
+
 struct wire_ModuleConfig *new_box_autoadd_module_config_0(void);
 
 struct wire_ModuleConfigWasmi *new_box_autoadd_module_config_wasmi_0(void);
@@ -539,6 +777,8 @@ struct wire_ModuleConfigWasmtime *new_box_autoadd_module_config_wasmtime_0(void)
 
 struct wire_TableArgs *new_box_autoadd_table_args_0(void);
 
+uint16_t *new_box_autoadd_u16_0(uint16_t value);
+
 uint32_t *new_box_autoadd_u32_0(uint32_t value);
 
 uint64_t *new_box_autoadd_u64_0(uint64_t value);
@@ -549,6 +789,10 @@ struct wire_WasiConfigNative *new_box_autoadd_wasi_config_native_0(void);
 
 struct wire_WasiStackLimits *new_box_autoadd_wasi_stack_limits_0(void);
 
+struct wire_WasmEngine *new_box_autoadd_wasm_engine_0(void);
+
+struct wire_WasmLinker *new_box_autoadd_wasm_linker_0(void);
+
 struct wire_WasmRunInstanceId *new_box_autoadd_wasm_run_instance_id_0(void);
 
 struct wire_WasmRunModuleId *new_box_autoadd_wasm_run_module_id_0(void);
@@ -563,20 +807,34 @@ struct wire_list_module_import *new_list_module_import_0(int32_t len);
 
 struct wire_list_preopened_dir *new_list_preopened_dir_0(int32_t len);
 
+struct wire_list_proposal_toggle *new_list_proposal_toggle_0(int32_t len);
+
 struct wire_list_value_ty *new_list_value_ty_0(int32_t len);
 
 struct wire_list_wasm_val *new_list_wasm_val_0(int32_t len);
 
 struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
 
+void drop_opaque_ArcAtomicBool(const void *ptr);
+
+const void *share_opaque_ArcAtomicBool(const void *ptr);
+
 void drop_opaque_ArcRwLockSharedMemory(const void *ptr);
 
 const void *share_opaque_ArcRwLockSharedMemory(const void *ptr);
 
+void drop_opaque_ArcStdSyncMutexLinkerTemplate(const void *ptr);
+
+const void *share_opaque_ArcStdSyncMutexLinkerTemplate(const void *ptr);
+
 void drop_opaque_ArcStdSyncMutexModule(const void *ptr);
 
 const void *share_opaque_ArcStdSyncMutexModule(const void *ptr);
 
+void drop_opaque_ArcStdSyncMutexModuleCacheImpl(const void *ptr);
+
+const void *share_opaque_ArcStdSyncMutexModuleCacheImpl(const void *ptr);
+
 void drop_opaque_CallStack(const void *ptr);
 
 const void *share_opaque_CallStack(const void *ptr);
@@ -589,10 +847,18 @@ void drop_opaque_Memory(const void *ptr);
 
 const void *share_opaque_Memory(const void *ptr);
 
+void drop_opaque_StdioCapture(const void *ptr);
+
+const void *share_opaque_StdioCapture(const void *ptr);
+
 void drop_opaque_Table(const void *ptr);
 
 const void *share_opaque_Table(const void *ptr);
 
+void drop_opaque_WEngine(const void *ptr);
+
+const void *share_opaque_WEngine(const void *ptr);
+
 void drop_opaque_WFunc(const void *ptr);
 
 const void *share_opaque_WFunc(const void *ptr);
@@ -627,18 +893,39 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
     dummy_var ^= ((int64_t) (void*) wire_module_builder);
     dummy_var ^= ((int64_t) (void*) wire_parse_wat_format);
+    dummy_var ^= ((int64_t) (void*) wire_clamp_memory);
+    dummy_var ^= ((int64_t) (void*) wire_module_has_start);
+    dummy_var ^= ((int64_t) (void*) wire_module_content_hash);
+    dummy_var ^= ((int64_t) (void*) wire_create_engine);
     dummy_var ^= ((int64_t) (void*) wire_compile_wasm);
     dummy_var ^= ((int64_t) (void*) wire_compile_wasm_sync);
+    dummy_var ^= ((int64_t) (void*) wire_create_compile_handle);
+    dummy_var ^= ((int64_t) (void*) wire_compile_wasm_cancelable);
     dummy_var ^= ((int64_t) (void*) wire_wasm_features_for_config);
     dummy_var ^= ((int64_t) (void*) wire_wasm_runtime_features);
     dummy_var ^= ((int64_t) (void*) wire_exports__method__WasmRunInstanceId);
     dummy_var ^= ((int64_t) (void*) wire_instantiate_sync__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_instantiate__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_link_imports__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_link_module__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_stdio_stream__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_wasi_denied_stream__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_read_stdio__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_next_stdio_chunk__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_stdio_dropped_bytes__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_close_stdio__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_close__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_dispose__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_call_function_handle_sync__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_call_function_handle__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_call_by_index__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_call_wasi_start__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_initialize__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_call_export__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_guest_alloc__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_guest_free__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_write_guest_string__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_read_guest_string__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_call_function_handle_parallel__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_worker_execution__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_get_function_type__method__WasmRunModuleId);
@@ -663,12 +950,34 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_get_table__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_set_table__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_fill_table__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_set_max_host_call_depth__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_set_epoch_deadline__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_add_fuel__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_fuel_consumed__method__WasmRunModuleId);
     dummy_var ^= ((int64_t) (void*) wire_consume_fuel__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_last_call_fuel_consumed__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_last_call_duration_micros__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_memory_footprint__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_memories__method__WasmRunModuleId);
+    dummy_var ^= ((int64_t) (void*) wire_instantiate__method__CompiledModule);
     dummy_var ^= ((int64_t) (void*) wire_create_shared_memory__method__CompiledModule);
     dummy_var ^= ((int64_t) (void*) wire_get_module_imports__method__CompiledModule);
+    dummy_var ^= ((int64_t) (void*) wire_wasi_module_kind__method__CompiledModule);
     dummy_var ^= ((int64_t) (void*) wire_get_module_exports__method__CompiledModule);
+    dummy_var ^= ((int64_t) (void*) wire_compile_wasm__method__WasmEngine);
+    dummy_var ^= ((int64_t) (void*) wire_compile_wasm_sync__method__WasmEngine);
+    dummy_var ^= ((int64_t) (void*) wire_increment_epoch__method__WasmEngine);
+    dummy_var ^= ((int64_t) (void*) wire_create_linker__method__WasmEngine);
+    dummy_var ^= ((int64_t) (void*) wire_create_module_cache__method__WasmEngine);
+    dummy_var ^= ((int64_t) (void*) wire_define_host_function__method__WasmLinker);
+    dummy_var ^= ((int64_t) (void*) wire_define_memory__method__WasmLinker);
+    dummy_var ^= ((int64_t) (void*) wire_module_builder__method__WasmLinker);
+    dummy_var ^= ((int64_t) (void*) wire_compile_wasm__method__ModuleCache);
+    dummy_var ^= ((int64_t) (void*) wire_compile_wasm_sync__method__ModuleCache);
+    dummy_var ^= ((int64_t) (void*) wire_stats__method__ModuleCache);
+    dummy_var ^= ((int64_t) (void*) wire_clear__method__ModuleCache);
+    dummy_var ^= ((int64_t) (void*) wire_cancel__method__CompileHandle);
+    dummy_var ^= ((int64_t) (void*) wire_is_cancelled__method__CompileHandle);
     dummy_var ^= ((int64_t) (void*) wire_ty__method__WasmRunSharedMemory);
     dummy_var ^= ((int64_t) (void*) wire_size__method__WasmRunSharedMemory);
     dummy_var ^= ((int64_t) (void*) wire_data_size__method__WasmRunSharedMemory);
@@ -687,28 +996,39 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_and__method__Atomics);
     dummy_var ^= ((int64_t) (void*) wire_or__method__Atomics);
     dummy_var ^= ((int64_t) (void*) wire_xor__method__Atomics);
+    dummy_var ^= ((int64_t) (void*) new_ArcAtomicBool);
     dummy_var ^= ((int64_t) (void*) new_ArcRwLockSharedMemory);
+    dummy_var ^= ((int64_t) (void*) new_ArcStdSyncMutexLinkerTemplate);
     dummy_var ^= ((int64_t) (void*) new_ArcStdSyncMutexModule);
+    dummy_var ^= ((int64_t) (void*) new_ArcStdSyncMutexModuleCacheImpl);
     dummy_var ^= ((int64_t) (void*) new_CallStack);
     dummy_var ^= ((int64_t) (void*) new_Global);
     dummy_var ^= ((int64_t) (void*) new_Memory);
+    dummy_var ^= ((int64_t) (void*) new_StdioCapture);
     dummy_var ^= ((int64_t) (void*) new_StringList_0);
     dummy_var ^= ((int64_t) (void*) new_Table);
+    dummy_var ^= ((int64_t) (void*) new_WEngine);
     dummy_var ^= ((int64_t) (void*) new_WFunc);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_WFunc_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_atomics_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_bool_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_compile_handle_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_compiled_module_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_fuel_consumption_mode_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_memory_ty_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_module_cache_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_module_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_module_config_wasmi_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_module_config_wasmtime_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_table_args_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_u16_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u32_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u64_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_usize_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasi_config_native_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasi_stack_limits_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasm_engine_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasm_linker_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasm_run_instance_id_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasm_run_module_id_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_wasm_run_shared_memory_0);
@@ -716,21 +1036,32 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) new_list_env_variable_0);
     dummy_var ^= ((int64_t) (void*) new_list_module_import_0);
     dummy_var ^= ((int64_t) (void*) new_list_preopened_dir_0);
+    dummy_var ^= ((int64_t) (void*) new_list_proposal_toggle_0);
     dummy_var ^= ((int64_t) (void*) new_list_value_ty_0);
     dummy_var ^= ((int64_t) (void*) new_list_wasm_val_0);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list_0);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_ArcAtomicBool);
+    dummy_var ^= ((int64_t) (void*) share_opaque_ArcAtomicBool);
     dummy_var ^= ((int64_t) (void*) drop_opaque_ArcRwLockSharedMemory);
     dummy_var ^= ((int64_t) (void*) share_opaque_ArcRwLockSharedMemory);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_ArcStdSyncMutexLinkerTemplate);
+    dummy_var ^= ((int64_t) (void*) share_opaque_ArcStdSyncMutexLinkerTemplate);
     dummy_var ^= ((int64_t) (void*) drop_opaque_ArcStdSyncMutexModule);
     dummy_var ^= ((int64_t) (void*) share_opaque_ArcStdSyncMutexModule);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_ArcStdSyncMutexModuleCacheImpl);
+    dummy_var ^= ((int64_t) (void*) share_opaque_ArcStdSyncMutexModuleCacheImpl);
     dummy_var ^= ((int64_t) (void*) drop_opaque_CallStack);
     dummy_var ^= ((int64_t) (void*) share_opaque_CallStack);
     dummy_var ^= ((int64_t) (void*) drop_opaque_Global);
     dummy_var ^= ((int64_t) (void*) share_opaque_Global);
     dummy_var ^= ((int64_t) (void*) drop_opaque_Memory);
     dummy_var ^= ((int64_t) (void*) share_opaque_Memory);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_StdioCapture);
+    dummy_var ^= ((int64_t) (void*) share_opaque_StdioCapture);
     dummy_var ^= ((int64_t) (void*) drop_opaque_Table);
     dummy_var ^= ((int64_t) (void*) share_opaque_Table);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_WEngine);
+    dummy_var ^= ((int64_t) (void*) share_opaque_WEngine);
     dummy_var ^= ((int64_t) (void*) drop_opaque_WFunc);
     dummy_var ^= ((int64_t) (void*) share_opaque_WFunc);
     dummy_var ^= ((int64_t) (void*) inflate_ExternalValue_Func);